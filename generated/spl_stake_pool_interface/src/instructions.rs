@@ -14,16 +14,26 @@ pub enum SplStakePoolProgramIx {
     Initialize(InitializeIxArgs),
     AddValidatorToPool(AddValidatorToPoolIxArgs),
     RemoveValidatorFromPool,
+    DecreaseValidatorStake(DecreaseValidatorStakeIxArgs),
+    IncreaseValidatorStake(IncreaseValidatorStakeIxArgs),
     SetPreferredValidator(SetPreferredValidatorIxArgs),
     UpdateValidatorListBalance(UpdateValidatorListBalanceIxArgs),
     UpdateStakePoolBalance,
     CleanupRemovedValidatorEntries,
+    DepositStake,
+    WithdrawStake(WithdrawStakeIxArgs),
     SetManager,
     SetFee(SetFeeIxArgs),
     SetStaker,
+    DepositSol(DepositSolIxArgs),
     SetFundingAuthority(SetFundingAuthorityIxArgs),
+    WithdrawSol(WithdrawSolIxArgs),
+    CreateTokenMetadata(CreateTokenMetadataIxArgs),
+    UpdateTokenMetadata(UpdateTokenMetadataIxArgs),
     IncreaseAdditionalValidatorStake(IncreaseAdditionalValidatorStakeIxArgs),
     DecreaseAdditionalValidatorStake(DecreaseAdditionalValidatorStakeIxArgs),
+    DecreaseValidatorStakeWithReserve(DecreaseValidatorStakeWithReserveIxArgs),
+    Redelegate(RedelegateIxArgs),
     DepositStakeWithSlippage(DepositStakeWithSlippageIxArgs),
     WithdrawStakeWithSlippage(WithdrawStakeWithSlippageIxArgs),
     DepositSolWithSlippage(DepositSolWithSlippageIxArgs),
@@ -43,6 +53,12 @@ impl SplStakePoolProgramIx {
                 AddValidatorToPoolIxArgs::deserialize(&mut reader)?,
             )),
             REMOVE_VALIDATOR_FROM_POOL_IX_DISCM => Ok(Self::RemoveValidatorFromPool),
+            DECREASE_VALIDATOR_STAKE_IX_DISCM => Ok(Self::DecreaseValidatorStake(
+                DecreaseValidatorStakeIxArgs::deserialize(&mut reader)?,
+            )),
+            INCREASE_VALIDATOR_STAKE_IX_DISCM => Ok(Self::IncreaseValidatorStake(
+                IncreaseValidatorStakeIxArgs::deserialize(&mut reader)?,
+            )),
             SET_PREFERRED_VALIDATOR_IX_DISCM => Ok(Self::SetPreferredValidator(
                 SetPreferredValidatorIxArgs::deserialize(&mut reader)?,
             )),
@@ -51,12 +67,28 @@ impl SplStakePoolProgramIx {
             )),
             UPDATE_STAKE_POOL_BALANCE_IX_DISCM => Ok(Self::UpdateStakePoolBalance),
             CLEANUP_REMOVED_VALIDATOR_ENTRIES_IX_DISCM => Ok(Self::CleanupRemovedValidatorEntries),
+            DEPOSIT_STAKE_IX_DISCM => Ok(Self::DepositStake),
+            WITHDRAW_STAKE_IX_DISCM => Ok(Self::WithdrawStake(WithdrawStakeIxArgs::deserialize(
+                &mut reader,
+            )?)),
             SET_MANAGER_IX_DISCM => Ok(Self::SetManager),
             SET_FEE_IX_DISCM => Ok(Self::SetFee(SetFeeIxArgs::deserialize(&mut reader)?)),
             SET_STAKER_IX_DISCM => Ok(Self::SetStaker),
+            DEPOSIT_SOL_IX_DISCM => Ok(Self::DepositSol(DepositSolIxArgs::deserialize(
+                &mut reader,
+            )?)),
             SET_FUNDING_AUTHORITY_IX_DISCM => Ok(Self::SetFundingAuthority(
                 SetFundingAuthorityIxArgs::deserialize(&mut reader)?,
             )),
+            WITHDRAW_SOL_IX_DISCM => Ok(Self::WithdrawSol(WithdrawSolIxArgs::deserialize(
+                &mut reader,
+            )?)),
+            CREATE_TOKEN_METADATA_IX_DISCM => Ok(Self::CreateTokenMetadata(
+                CreateTokenMetadataIxArgs::deserialize(&mut reader)?,
+            )),
+            UPDATE_TOKEN_METADATA_IX_DISCM => Ok(Self::UpdateTokenMetadata(
+                UpdateTokenMetadataIxArgs::deserialize(&mut reader)?,
+            )),
             INCREASE_ADDITIONAL_VALIDATOR_STAKE_IX_DISCM => {
                 Ok(Self::IncreaseAdditionalValidatorStake(
                     IncreaseAdditionalValidatorStakeIxArgs::deserialize(&mut reader)?,
@@ -67,6 +99,14 @@ impl SplStakePoolProgramIx {
                     DecreaseAdditionalValidatorStakeIxArgs::deserialize(&mut reader)?,
                 ))
             }
+            DECREASE_VALIDATOR_STAKE_WITH_RESERVE_IX_DISCM => {
+                Ok(Self::DecreaseValidatorStakeWithReserve(
+                    DecreaseValidatorStakeWithReserveIxArgs::deserialize(&mut reader)?,
+                ))
+            }
+            REDELEGATE_IX_DISCM => Ok(Self::Redelegate(RedelegateIxArgs::deserialize(
+                &mut reader,
+            )?)),
             DEPOSIT_STAKE_WITH_SLIPPAGE_IX_DISCM => Ok(Self::DepositStakeWithSlippage(
                 DepositStakeWithSlippageIxArgs::deserialize(&mut reader)?,
             )),
@@ -98,6 +138,14 @@ impl SplStakePoolProgramIx {
             Self::RemoveValidatorFromPool => {
                 writer.write_all(&[REMOVE_VALIDATOR_FROM_POOL_IX_DISCM])
             }
+            Self::DecreaseValidatorStake(args) => {
+                writer.write_all(&[DECREASE_VALIDATOR_STAKE_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::IncreaseValidatorStake(args) => {
+                writer.write_all(&[INCREASE_VALIDATOR_STAKE_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
             Self::SetPreferredValidator(args) => {
                 writer.write_all(&[SET_PREFERRED_VALIDATOR_IX_DISCM])?;
                 args.serialize(&mut writer)
@@ -110,16 +158,37 @@ impl SplStakePoolProgramIx {
             Self::CleanupRemovedValidatorEntries => {
                 writer.write_all(&[CLEANUP_REMOVED_VALIDATOR_ENTRIES_IX_DISCM])
             }
+            Self::DepositStake => writer.write_all(&[DEPOSIT_STAKE_IX_DISCM]),
+            Self::WithdrawStake(args) => {
+                writer.write_all(&[WITHDRAW_STAKE_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
             Self::SetManager => writer.write_all(&[SET_MANAGER_IX_DISCM]),
             Self::SetFee(args) => {
                 writer.write_all(&[SET_FEE_IX_DISCM])?;
                 args.serialize(&mut writer)
             }
             Self::SetStaker => writer.write_all(&[SET_STAKER_IX_DISCM]),
+            Self::DepositSol(args) => {
+                writer.write_all(&[DEPOSIT_SOL_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
             Self::SetFundingAuthority(args) => {
                 writer.write_all(&[SET_FUNDING_AUTHORITY_IX_DISCM])?;
                 args.serialize(&mut writer)
             }
+            Self::WithdrawSol(args) => {
+                writer.write_all(&[WITHDRAW_SOL_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::CreateTokenMetadata(args) => {
+                writer.write_all(&[CREATE_TOKEN_METADATA_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::UpdateTokenMetadata(args) => {
+                writer.write_all(&[UPDATE_TOKEN_METADATA_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
             Self::IncreaseAdditionalValidatorStake(args) => {
                 writer.write_all(&[INCREASE_ADDITIONAL_VALIDATOR_STAKE_IX_DISCM])?;
                 args.serialize(&mut writer)
@@ -128,6 +197,14 @@ impl SplStakePoolProgramIx {
                 writer.write_all(&[DECREASE_ADDITIONAL_VALIDATOR_STAKE_IX_DISCM])?;
                 args.serialize(&mut writer)
             }
+            Self::DecreaseValidatorStakeWithReserve(args) => {
+                writer.write_all(&[DECREASE_VALIDATOR_STAKE_WITH_RESERVE_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::Redelegate(args) => {
+                writer.write_all(&[REDELEGATE_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
             Self::DepositStakeWithSlippage(args) => {
                 writer.write_all(&[DEPOSIT_STAKE_WITH_SLIPPAGE_IX_DISCM])?;
                 args.serialize(&mut writer)
@@ -1109,119 +1186,212 @@ pub fn remove_validator_from_pool_verify_account_privileges<'me, 'info>(
     remove_validator_from_pool_verify_signer_privileges(accounts)?;
     Ok(())
 }
-pub const SET_PREFERRED_VALIDATOR_IX_ACCOUNTS_LEN: usize = 3;
+pub const DECREASE_VALIDATOR_STAKE_IX_ACCOUNTS_LEN: usize = 10;
 #[derive(Copy, Clone, Debug)]
-pub struct SetPreferredValidatorAccounts<'me, 'info> {
+pub struct DecreaseValidatorStakeAccounts<'me, 'info> {
     ///Stake pool
     pub stake_pool: &'me AccountInfo<'info>,
-    ///Staker
+    ///Current staker
     pub staker: &'me AccountInfo<'info>,
+    ///Stake pool withdraw authority
+    pub withdraw_authority: &'me AccountInfo<'info>,
     ///Validator list
     pub validator_list: &'me AccountInfo<'info>,
+    ///Validator stake account to split stake from
+    pub validator_stake_account: &'me AccountInfo<'info>,
+    ///Transient stake account to receive split
+    pub transient_stake_account: &'me AccountInfo<'info>,
+    ///Clock sysvar
+    pub clock: &'me AccountInfo<'info>,
+    ///Rent sysvar
+    pub rent: &'me AccountInfo<'info>,
+    ///System program
+    pub system_program: &'me AccountInfo<'info>,
+    ///Stake program
+    pub stake_program: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug)]
-pub struct SetPreferredValidatorKeys {
+pub struct DecreaseValidatorStakeKeys {
     ///Stake pool
     pub stake_pool: Pubkey,
-    ///Staker
+    ///Current staker
     pub staker: Pubkey,
+    ///Stake pool withdraw authority
+    pub withdraw_authority: Pubkey,
     ///Validator list
     pub validator_list: Pubkey,
+    ///Validator stake account to split stake from
+    pub validator_stake_account: Pubkey,
+    ///Transient stake account to receive split
+    pub transient_stake_account: Pubkey,
+    ///Clock sysvar
+    pub clock: Pubkey,
+    ///Rent sysvar
+    pub rent: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+    ///Stake program
+    pub stake_program: Pubkey,
 }
-impl From<SetPreferredValidatorAccounts<'_, '_>> for SetPreferredValidatorKeys {
-    fn from(accounts: SetPreferredValidatorAccounts) -> Self {
+impl From<DecreaseValidatorStakeAccounts<'_, '_>> for DecreaseValidatorStakeKeys {
+    fn from(accounts: DecreaseValidatorStakeAccounts) -> Self {
         Self {
             stake_pool: *accounts.stake_pool.key,
             staker: *accounts.staker.key,
+            withdraw_authority: *accounts.withdraw_authority.key,
             validator_list: *accounts.validator_list.key,
+            validator_stake_account: *accounts.validator_stake_account.key,
+            transient_stake_account: *accounts.transient_stake_account.key,
+            clock: *accounts.clock.key,
+            rent: *accounts.rent.key,
+            system_program: *accounts.system_program.key,
+            stake_program: *accounts.stake_program.key,
         }
     }
 }
-impl From<SetPreferredValidatorKeys> for [AccountMeta; SET_PREFERRED_VALIDATOR_IX_ACCOUNTS_LEN] {
-    fn from(keys: SetPreferredValidatorKeys) -> Self {
+impl From<DecreaseValidatorStakeKeys> for [AccountMeta; DECREASE_VALIDATOR_STAKE_IX_ACCOUNTS_LEN] {
+    fn from(keys: DecreaseValidatorStakeKeys) -> Self {
         [
             AccountMeta {
                 pubkey: keys.stake_pool,
                 is_signer: false,
-                is_writable: true,
+                is_writable: false,
             },
             AccountMeta {
                 pubkey: keys.staker,
                 is_signer: true,
                 is_writable: false,
             },
+            AccountMeta {
+                pubkey: keys.withdraw_authority,
+                is_signer: false,
+                is_writable: false,
+            },
             AccountMeta {
                 pubkey: keys.validator_list,
                 is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.validator_stake_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.transient_stake_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.clock,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.rent,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.stake_program,
+                is_signer: false,
                 is_writable: false,
             },
         ]
     }
 }
-impl From<[Pubkey; SET_PREFERRED_VALIDATOR_IX_ACCOUNTS_LEN]> for SetPreferredValidatorKeys {
-    fn from(pubkeys: [Pubkey; SET_PREFERRED_VALIDATOR_IX_ACCOUNTS_LEN]) -> Self {
+impl From<[Pubkey; DECREASE_VALIDATOR_STAKE_IX_ACCOUNTS_LEN]> for DecreaseValidatorStakeKeys {
+    fn from(pubkeys: [Pubkey; DECREASE_VALIDATOR_STAKE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             stake_pool: pubkeys[0],
             staker: pubkeys[1],
-            validator_list: pubkeys[2],
+            withdraw_authority: pubkeys[2],
+            validator_list: pubkeys[3],
+            validator_stake_account: pubkeys[4],
+            transient_stake_account: pubkeys[5],
+            clock: pubkeys[6],
+            rent: pubkeys[7],
+            system_program: pubkeys[8],
+            stake_program: pubkeys[9],
         }
     }
 }
-impl<'info> From<SetPreferredValidatorAccounts<'_, 'info>>
-    for [AccountInfo<'info>; SET_PREFERRED_VALIDATOR_IX_ACCOUNTS_LEN]
+impl<'info> From<DecreaseValidatorStakeAccounts<'_, 'info>>
+    for [AccountInfo<'info>; DECREASE_VALIDATOR_STAKE_IX_ACCOUNTS_LEN]
 {
-    fn from(accounts: SetPreferredValidatorAccounts<'_, 'info>) -> Self {
+    fn from(accounts: DecreaseValidatorStakeAccounts<'_, 'info>) -> Self {
         [
             accounts.stake_pool.clone(),
             accounts.staker.clone(),
+            accounts.withdraw_authority.clone(),
             accounts.validator_list.clone(),
+            accounts.validator_stake_account.clone(),
+            accounts.transient_stake_account.clone(),
+            accounts.clock.clone(),
+            accounts.rent.clone(),
+            accounts.system_program.clone(),
+            accounts.stake_program.clone(),
         ]
     }
 }
-impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_PREFERRED_VALIDATOR_IX_ACCOUNTS_LEN]>
-    for SetPreferredValidatorAccounts<'me, 'info>
+impl<'me, 'info> From<&'me [AccountInfo<'info>; DECREASE_VALIDATOR_STAKE_IX_ACCOUNTS_LEN]>
+    for DecreaseValidatorStakeAccounts<'me, 'info>
 {
-    fn from(arr: &'me [AccountInfo<'info>; SET_PREFERRED_VALIDATOR_IX_ACCOUNTS_LEN]) -> Self {
+    fn from(arr: &'me [AccountInfo<'info>; DECREASE_VALIDATOR_STAKE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             stake_pool: &arr[0],
             staker: &arr[1],
-            validator_list: &arr[2],
+            withdraw_authority: &arr[2],
+            validator_list: &arr[3],
+            validator_stake_account: &arr[4],
+            transient_stake_account: &arr[5],
+            clock: &arr[6],
+            rent: &arr[7],
+            system_program: &arr[8],
+            stake_program: &arr[9],
         }
     }
 }
-pub const SET_PREFERRED_VALIDATOR_IX_DISCM: u8 = 5u8;
+pub const DECREASE_VALIDATOR_STAKE_IX_DISCM: u8 = 3u8;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct SetPreferredValidatorIxArgs {
-    pub validator_type: PreferredValidatorType,
-    pub validator_vote_address: Option<Pubkey>,
+pub struct DecreaseValidatorStakeIxArgs {
+    pub lamports: u64,
+    pub transient_stake_seed: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
-pub struct SetPreferredValidatorIxData(pub SetPreferredValidatorIxArgs);
-impl From<SetPreferredValidatorIxArgs> for SetPreferredValidatorIxData {
-    fn from(args: SetPreferredValidatorIxArgs) -> Self {
+pub struct DecreaseValidatorStakeIxData(pub DecreaseValidatorStakeIxArgs);
+impl From<DecreaseValidatorStakeIxArgs> for DecreaseValidatorStakeIxData {
+    fn from(args: DecreaseValidatorStakeIxArgs) -> Self {
         Self(args)
     }
 }
-impl SetPreferredValidatorIxData {
+impl DecreaseValidatorStakeIxData {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
-        if maybe_discm != SET_PREFERRED_VALIDATOR_IX_DISCM {
+        if maybe_discm != DECREASE_VALIDATOR_STAKE_IX_DISCM {
             return Err(std::io::Error::new(
                 std::io::ErrorKind::Other,
                 format!(
                     "discm does not match. Expected: {:?}. Received: {:?}",
-                    SET_PREFERRED_VALIDATOR_IX_DISCM, maybe_discm
+                    DECREASE_VALIDATOR_STAKE_IX_DISCM, maybe_discm
                 ),
             ));
         }
-        Ok(Self(SetPreferredValidatorIxArgs::deserialize(&mut reader)?))
+        Ok(Self(DecreaseValidatorStakeIxArgs::deserialize(
+            &mut reader,
+        )?))
     }
     pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
-        writer.write_all(&[SET_PREFERRED_VALIDATOR_IX_DISCM])?;
+        writer.write_all(&[DECREASE_VALIDATOR_STAKE_IX_DISCM])?;
         self.0.serialize(&mut writer)
     }
     pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
@@ -1230,65 +1400,78 @@ impl SetPreferredValidatorIxData {
         Ok(data)
     }
 }
-pub fn set_preferred_validator_ix_with_program_id(
+pub fn decrease_validator_stake_ix_with_program_id(
     program_id: Pubkey,
-    keys: SetPreferredValidatorKeys,
-    args: SetPreferredValidatorIxArgs,
+    keys: DecreaseValidatorStakeKeys,
+    args: DecreaseValidatorStakeIxArgs,
 ) -> std::io::Result<Instruction> {
-    let metas: [AccountMeta; SET_PREFERRED_VALIDATOR_IX_ACCOUNTS_LEN] = keys.into();
-    let data: SetPreferredValidatorIxData = args.into();
+    let metas: [AccountMeta; DECREASE_VALIDATOR_STAKE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: DecreaseValidatorStakeIxData = args.into();
     Ok(Instruction {
         program_id,
         accounts: Vec::from(metas),
         data: data.try_to_vec()?,
     })
 }
-pub fn set_preferred_validator_ix(
-    keys: SetPreferredValidatorKeys,
-    args: SetPreferredValidatorIxArgs,
+pub fn decrease_validator_stake_ix(
+    keys: DecreaseValidatorStakeKeys,
+    args: DecreaseValidatorStakeIxArgs,
 ) -> std::io::Result<Instruction> {
-    set_preferred_validator_ix_with_program_id(crate::ID, keys, args)
+    decrease_validator_stake_ix_with_program_id(crate::ID, keys, args)
 }
-pub fn set_preferred_validator_invoke_with_program_id(
+pub fn decrease_validator_stake_invoke_with_program_id(
     program_id: Pubkey,
-    accounts: SetPreferredValidatorAccounts<'_, '_>,
-    args: SetPreferredValidatorIxArgs,
+    accounts: DecreaseValidatorStakeAccounts<'_, '_>,
+    args: DecreaseValidatorStakeIxArgs,
 ) -> ProgramResult {
-    let keys: SetPreferredValidatorKeys = accounts.into();
-    let ix = set_preferred_validator_ix_with_program_id(program_id, keys, args)?;
+    let keys: DecreaseValidatorStakeKeys = accounts.into();
+    let ix = decrease_validator_stake_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
-pub fn set_preferred_validator_invoke(
-    accounts: SetPreferredValidatorAccounts<'_, '_>,
-    args: SetPreferredValidatorIxArgs,
+pub fn decrease_validator_stake_invoke(
+    accounts: DecreaseValidatorStakeAccounts<'_, '_>,
+    args: DecreaseValidatorStakeIxArgs,
 ) -> ProgramResult {
-    set_preferred_validator_invoke_with_program_id(crate::ID, accounts, args)
+    decrease_validator_stake_invoke_with_program_id(crate::ID, accounts, args)
 }
-pub fn set_preferred_validator_invoke_signed_with_program_id(
+pub fn decrease_validator_stake_invoke_signed_with_program_id(
     program_id: Pubkey,
-    accounts: SetPreferredValidatorAccounts<'_, '_>,
-    args: SetPreferredValidatorIxArgs,
+    accounts: DecreaseValidatorStakeAccounts<'_, '_>,
+    args: DecreaseValidatorStakeIxArgs,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let keys: SetPreferredValidatorKeys = accounts.into();
-    let ix = set_preferred_validator_ix_with_program_id(program_id, keys, args)?;
+    let keys: DecreaseValidatorStakeKeys = accounts.into();
+    let ix = decrease_validator_stake_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
-pub fn set_preferred_validator_invoke_signed(
-    accounts: SetPreferredValidatorAccounts<'_, '_>,
-    args: SetPreferredValidatorIxArgs,
+pub fn decrease_validator_stake_invoke_signed(
+    accounts: DecreaseValidatorStakeAccounts<'_, '_>,
+    args: DecreaseValidatorStakeIxArgs,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    set_preferred_validator_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+    decrease_validator_stake_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
-pub fn set_preferred_validator_verify_account_keys(
-    accounts: SetPreferredValidatorAccounts<'_, '_>,
-    keys: SetPreferredValidatorKeys,
+pub fn decrease_validator_stake_verify_account_keys(
+    accounts: DecreaseValidatorStakeAccounts<'_, '_>,
+    keys: DecreaseValidatorStakeKeys,
 ) -> Result<(), (Pubkey, Pubkey)> {
     for (actual, expected) in [
         (accounts.stake_pool.key, &keys.stake_pool),
         (accounts.staker.key, &keys.staker),
+        (accounts.withdraw_authority.key, &keys.withdraw_authority),
         (accounts.validator_list.key, &keys.validator_list),
+        (
+            accounts.validator_stake_account.key,
+            &keys.validator_stake_account,
+        ),
+        (
+            accounts.transient_stake_account.key,
+            &keys.transient_stake_account,
+        ),
+        (accounts.clock.key, &keys.clock),
+        (accounts.rent.key, &keys.rent),
+        (accounts.system_program.key, &keys.system_program),
+        (accounts.stake_program.key, &keys.stake_program),
     ] {
         if actual != expected {
             return Err((*actual, *expected));
@@ -1296,18 +1479,22 @@ pub fn set_preferred_validator_verify_account_keys(
     }
     Ok(())
 }
-pub fn set_preferred_validator_verify_writable_privileges<'me, 'info>(
-    accounts: SetPreferredValidatorAccounts<'me, 'info>,
+pub fn decrease_validator_stake_verify_writable_privileges<'me, 'info>(
+    accounts: DecreaseValidatorStakeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
-    for should_be_writable in [accounts.stake_pool] {
+    for should_be_writable in [
+        accounts.validator_list,
+        accounts.validator_stake_account,
+        accounts.transient_stake_account,
+    ] {
         if !should_be_writable.is_writable {
             return Err((should_be_writable, ProgramError::InvalidAccountData));
         }
     }
     Ok(())
 }
-pub fn set_preferred_validator_verify_signer_privileges<'me, 'info>(
-    accounts: SetPreferredValidatorAccounts<'me, 'info>,
+pub fn decrease_validator_stake_verify_signer_privileges<'me, 'info>(
+    accounts: DecreaseValidatorStakeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
     for should_be_signer in [accounts.staker] {
         if !should_be_signer.is_signer {
@@ -1316,71 +1503,109 @@ pub fn set_preferred_validator_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
-pub fn set_preferred_validator_verify_account_privileges<'me, 'info>(
-    accounts: SetPreferredValidatorAccounts<'me, 'info>,
+pub fn decrease_validator_stake_verify_account_privileges<'me, 'info>(
+    accounts: DecreaseValidatorStakeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
-    set_preferred_validator_verify_writable_privileges(accounts)?;
-    set_preferred_validator_verify_signer_privileges(accounts)?;
+    decrease_validator_stake_verify_writable_privileges(accounts)?;
+    decrease_validator_stake_verify_signer_privileges(accounts)?;
     Ok(())
 }
-pub const UPDATE_VALIDATOR_LIST_BALANCE_IX_ACCOUNTS_LEN: usize = 7;
+pub const INCREASE_VALIDATOR_STAKE_IX_ACCOUNTS_LEN: usize = 14;
 #[derive(Copy, Clone, Debug)]
-pub struct UpdateValidatorListBalanceAccounts<'me, 'info> {
+pub struct IncreaseValidatorStakeAccounts<'me, 'info> {
     ///Stake pool
     pub stake_pool: &'me AccountInfo<'info>,
+    ///Current staker
+    pub staker: &'me AccountInfo<'info>,
     ///Stake pool withdraw authority
     pub withdraw_authority: &'me AccountInfo<'info>,
     ///Validator list
     pub validator_list: &'me AccountInfo<'info>,
     ///Reserve stake account
     pub reserve_stake: &'me AccountInfo<'info>,
+    ///Transient stake account
+    pub transient_stake_account: &'me AccountInfo<'info>,
+    ///Validator stake account
+    pub validator_stake_account: &'me AccountInfo<'info>,
+    ///Validator vote account to delegate to
+    pub vote_account: &'me AccountInfo<'info>,
     ///Clock sysvar
     pub clock: &'me AccountInfo<'info>,
+    ///Rent sysvar
+    pub rent: &'me AccountInfo<'info>,
     ///Stake history sysvar
     pub stake_history: &'me AccountInfo<'info>,
-    ///Stake program. N pairs of validator and transient stake accounts follow.
+    ///Stake config sysvar
+    pub stake_config: &'me AccountInfo<'info>,
+    ///System program
+    pub system_program: &'me AccountInfo<'info>,
+    ///Stake program
     pub stake_program: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug)]
-pub struct UpdateValidatorListBalanceKeys {
+pub struct IncreaseValidatorStakeKeys {
     ///Stake pool
     pub stake_pool: Pubkey,
+    ///Current staker
+    pub staker: Pubkey,
     ///Stake pool withdraw authority
     pub withdraw_authority: Pubkey,
     ///Validator list
     pub validator_list: Pubkey,
     ///Reserve stake account
     pub reserve_stake: Pubkey,
+    ///Transient stake account
+    pub transient_stake_account: Pubkey,
+    ///Validator stake account
+    pub validator_stake_account: Pubkey,
+    ///Validator vote account to delegate to
+    pub vote_account: Pubkey,
     ///Clock sysvar
     pub clock: Pubkey,
+    ///Rent sysvar
+    pub rent: Pubkey,
     ///Stake history sysvar
     pub stake_history: Pubkey,
-    ///Stake program. N pairs of validator and transient stake accounts follow.
+    ///Stake config sysvar
+    pub stake_config: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+    ///Stake program
     pub stake_program: Pubkey,
 }
-impl From<UpdateValidatorListBalanceAccounts<'_, '_>> for UpdateValidatorListBalanceKeys {
-    fn from(accounts: UpdateValidatorListBalanceAccounts) -> Self {
+impl From<IncreaseValidatorStakeAccounts<'_, '_>> for IncreaseValidatorStakeKeys {
+    fn from(accounts: IncreaseValidatorStakeAccounts) -> Self {
         Self {
             stake_pool: *accounts.stake_pool.key,
+            staker: *accounts.staker.key,
             withdraw_authority: *accounts.withdraw_authority.key,
             validator_list: *accounts.validator_list.key,
             reserve_stake: *accounts.reserve_stake.key,
+            transient_stake_account: *accounts.transient_stake_account.key,
+            validator_stake_account: *accounts.validator_stake_account.key,
+            vote_account: *accounts.vote_account.key,
             clock: *accounts.clock.key,
+            rent: *accounts.rent.key,
             stake_history: *accounts.stake_history.key,
-            stake_program: *accounts.stake_program.key,
+            stake_config: *accounts.stake_config.key,
+            system_program: *accounts.system_program.key,
+            stake_program: *accounts.stake_program.key,
         }
     }
 }
-impl From<UpdateValidatorListBalanceKeys>
-    for [AccountMeta; UPDATE_VALIDATOR_LIST_BALANCE_IX_ACCOUNTS_LEN]
-{
-    fn from(keys: UpdateValidatorListBalanceKeys) -> Self {
+impl From<IncreaseValidatorStakeKeys> for [AccountMeta; INCREASE_VALIDATOR_STAKE_IX_ACCOUNTS_LEN] {
+    fn from(keys: IncreaseValidatorStakeKeys) -> Self {
         [
             AccountMeta {
                 pubkey: keys.stake_pool,
                 is_signer: false,
                 is_writable: false,
             },
+            AccountMeta {
+                pubkey: keys.staker,
+                is_signer: true,
+                is_writable: false,
+            },
             AccountMeta {
                 pubkey: keys.withdraw_authority,
                 is_signer: false,
@@ -1396,16 +1621,46 @@ impl From<UpdateValidatorListBalanceKeys>
                 is_signer: false,
                 is_writable: true,
             },
+            AccountMeta {
+                pubkey: keys.transient_stake_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.validator_stake_account,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.vote_account,
+                is_signer: false,
+                is_writable: false,
+            },
             AccountMeta {
                 pubkey: keys.clock,
                 is_signer: false,
                 is_writable: false,
             },
+            AccountMeta {
+                pubkey: keys.rent,
+                is_signer: false,
+                is_writable: false,
+            },
             AccountMeta {
                 pubkey: keys.stake_history,
                 is_signer: false,
                 is_writable: false,
             },
+            AccountMeta {
+                pubkey: keys.stake_config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
             AccountMeta {
                 pubkey: keys.stake_program,
                 is_signer: false,
@@ -1414,86 +1669,105 @@ impl From<UpdateValidatorListBalanceKeys>
         ]
     }
 }
-impl From<[Pubkey; UPDATE_VALIDATOR_LIST_BALANCE_IX_ACCOUNTS_LEN]>
-    for UpdateValidatorListBalanceKeys
-{
-    fn from(pubkeys: [Pubkey; UPDATE_VALIDATOR_LIST_BALANCE_IX_ACCOUNTS_LEN]) -> Self {
+impl From<[Pubkey; INCREASE_VALIDATOR_STAKE_IX_ACCOUNTS_LEN]> for IncreaseValidatorStakeKeys {
+    fn from(pubkeys: [Pubkey; INCREASE_VALIDATOR_STAKE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             stake_pool: pubkeys[0],
-            withdraw_authority: pubkeys[1],
-            validator_list: pubkeys[2],
-            reserve_stake: pubkeys[3],
-            clock: pubkeys[4],
-            stake_history: pubkeys[5],
-            stake_program: pubkeys[6],
+            staker: pubkeys[1],
+            withdraw_authority: pubkeys[2],
+            validator_list: pubkeys[3],
+            reserve_stake: pubkeys[4],
+            transient_stake_account: pubkeys[5],
+            validator_stake_account: pubkeys[6],
+            vote_account: pubkeys[7],
+            clock: pubkeys[8],
+            rent: pubkeys[9],
+            stake_history: pubkeys[10],
+            stake_config: pubkeys[11],
+            system_program: pubkeys[12],
+            stake_program: pubkeys[13],
         }
     }
 }
-impl<'info> From<UpdateValidatorListBalanceAccounts<'_, 'info>>
-    for [AccountInfo<'info>; UPDATE_VALIDATOR_LIST_BALANCE_IX_ACCOUNTS_LEN]
+impl<'info> From<IncreaseValidatorStakeAccounts<'_, 'info>>
+    for [AccountInfo<'info>; INCREASE_VALIDATOR_STAKE_IX_ACCOUNTS_LEN]
 {
-    fn from(accounts: UpdateValidatorListBalanceAccounts<'_, 'info>) -> Self {
+    fn from(accounts: IncreaseValidatorStakeAccounts<'_, 'info>) -> Self {
         [
             accounts.stake_pool.clone(),
+            accounts.staker.clone(),
             accounts.withdraw_authority.clone(),
             accounts.validator_list.clone(),
             accounts.reserve_stake.clone(),
+            accounts.transient_stake_account.clone(),
+            accounts.validator_stake_account.clone(),
+            accounts.vote_account.clone(),
             accounts.clock.clone(),
+            accounts.rent.clone(),
             accounts.stake_history.clone(),
+            accounts.stake_config.clone(),
+            accounts.system_program.clone(),
             accounts.stake_program.clone(),
         ]
     }
 }
-impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_VALIDATOR_LIST_BALANCE_IX_ACCOUNTS_LEN]>
-    for UpdateValidatorListBalanceAccounts<'me, 'info>
+impl<'me, 'info> From<&'me [AccountInfo<'info>; INCREASE_VALIDATOR_STAKE_IX_ACCOUNTS_LEN]>
+    for IncreaseValidatorStakeAccounts<'me, 'info>
 {
-    fn from(arr: &'me [AccountInfo<'info>; UPDATE_VALIDATOR_LIST_BALANCE_IX_ACCOUNTS_LEN]) -> Self {
+    fn from(arr: &'me [AccountInfo<'info>; INCREASE_VALIDATOR_STAKE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             stake_pool: &arr[0],
-            withdraw_authority: &arr[1],
-            validator_list: &arr[2],
-            reserve_stake: &arr[3],
-            clock: &arr[4],
-            stake_history: &arr[5],
-            stake_program: &arr[6],
+            staker: &arr[1],
+            withdraw_authority: &arr[2],
+            validator_list: &arr[3],
+            reserve_stake: &arr[4],
+            transient_stake_account: &arr[5],
+            validator_stake_account: &arr[6],
+            vote_account: &arr[7],
+            clock: &arr[8],
+            rent: &arr[9],
+            stake_history: &arr[10],
+            stake_config: &arr[11],
+            system_program: &arr[12],
+            stake_program: &arr[13],
         }
     }
 }
-pub const UPDATE_VALIDATOR_LIST_BALANCE_IX_DISCM: u8 = 6u8;
+pub const INCREASE_VALIDATOR_STAKE_IX_DISCM: u8 = 4u8;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct UpdateValidatorListBalanceIxArgs {
-    pub start_index: u32,
-    pub no_merge: bool,
+pub struct IncreaseValidatorStakeIxArgs {
+    pub lamports: u64,
+    pub transient_stake_seed: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
-pub struct UpdateValidatorListBalanceIxData(pub UpdateValidatorListBalanceIxArgs);
-impl From<UpdateValidatorListBalanceIxArgs> for UpdateValidatorListBalanceIxData {
-    fn from(args: UpdateValidatorListBalanceIxArgs) -> Self {
+pub struct IncreaseValidatorStakeIxData(pub IncreaseValidatorStakeIxArgs);
+impl From<IncreaseValidatorStakeIxArgs> for IncreaseValidatorStakeIxData {
+    fn from(args: IncreaseValidatorStakeIxArgs) -> Self {
         Self(args)
     }
 }
-impl UpdateValidatorListBalanceIxData {
+impl IncreaseValidatorStakeIxData {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
-        if maybe_discm != UPDATE_VALIDATOR_LIST_BALANCE_IX_DISCM {
+        if maybe_discm != INCREASE_VALIDATOR_STAKE_IX_DISCM {
             return Err(std::io::Error::new(
                 std::io::ErrorKind::Other,
                 format!(
                     "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_VALIDATOR_LIST_BALANCE_IX_DISCM, maybe_discm
+                    INCREASE_VALIDATOR_STAKE_IX_DISCM, maybe_discm
                 ),
             ));
         }
-        Ok(Self(UpdateValidatorListBalanceIxArgs::deserialize(
+        Ok(Self(IncreaseValidatorStakeIxArgs::deserialize(
             &mut reader,
         )?))
     }
     pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
-        writer.write_all(&[UPDATE_VALIDATOR_LIST_BALANCE_IX_DISCM])?;
+        writer.write_all(&[INCREASE_VALIDATOR_STAKE_IX_DISCM])?;
         self.0.serialize(&mut writer)
     }
     pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
@@ -1502,68 +1776,81 @@ impl UpdateValidatorListBalanceIxData {
         Ok(data)
     }
 }
-pub fn update_validator_list_balance_ix_with_program_id(
+pub fn increase_validator_stake_ix_with_program_id(
     program_id: Pubkey,
-    keys: UpdateValidatorListBalanceKeys,
-    args: UpdateValidatorListBalanceIxArgs,
+    keys: IncreaseValidatorStakeKeys,
+    args: IncreaseValidatorStakeIxArgs,
 ) -> std::io::Result<Instruction> {
-    let metas: [AccountMeta; UPDATE_VALIDATOR_LIST_BALANCE_IX_ACCOUNTS_LEN] = keys.into();
-    let data: UpdateValidatorListBalanceIxData = args.into();
+    let metas: [AccountMeta; INCREASE_VALIDATOR_STAKE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: IncreaseValidatorStakeIxData = args.into();
     Ok(Instruction {
         program_id,
         accounts: Vec::from(metas),
         data: data.try_to_vec()?,
     })
 }
-pub fn update_validator_list_balance_ix(
-    keys: UpdateValidatorListBalanceKeys,
-    args: UpdateValidatorListBalanceIxArgs,
+pub fn increase_validator_stake_ix(
+    keys: IncreaseValidatorStakeKeys,
+    args: IncreaseValidatorStakeIxArgs,
 ) -> std::io::Result<Instruction> {
-    update_validator_list_balance_ix_with_program_id(crate::ID, keys, args)
+    increase_validator_stake_ix_with_program_id(crate::ID, keys, args)
 }
-pub fn update_validator_list_balance_invoke_with_program_id(
+pub fn increase_validator_stake_invoke_with_program_id(
     program_id: Pubkey,
-    accounts: UpdateValidatorListBalanceAccounts<'_, '_>,
-    args: UpdateValidatorListBalanceIxArgs,
+    accounts: IncreaseValidatorStakeAccounts<'_, '_>,
+    args: IncreaseValidatorStakeIxArgs,
 ) -> ProgramResult {
-    let keys: UpdateValidatorListBalanceKeys = accounts.into();
-    let ix = update_validator_list_balance_ix_with_program_id(program_id, keys, args)?;
+    let keys: IncreaseValidatorStakeKeys = accounts.into();
+    let ix = increase_validator_stake_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
-pub fn update_validator_list_balance_invoke(
-    accounts: UpdateValidatorListBalanceAccounts<'_, '_>,
-    args: UpdateValidatorListBalanceIxArgs,
+pub fn increase_validator_stake_invoke(
+    accounts: IncreaseValidatorStakeAccounts<'_, '_>,
+    args: IncreaseValidatorStakeIxArgs,
 ) -> ProgramResult {
-    update_validator_list_balance_invoke_with_program_id(crate::ID, accounts, args)
+    increase_validator_stake_invoke_with_program_id(crate::ID, accounts, args)
 }
-pub fn update_validator_list_balance_invoke_signed_with_program_id(
+pub fn increase_validator_stake_invoke_signed_with_program_id(
     program_id: Pubkey,
-    accounts: UpdateValidatorListBalanceAccounts<'_, '_>,
-    args: UpdateValidatorListBalanceIxArgs,
+    accounts: IncreaseValidatorStakeAccounts<'_, '_>,
+    args: IncreaseValidatorStakeIxArgs,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let keys: UpdateValidatorListBalanceKeys = accounts.into();
-    let ix = update_validator_list_balance_ix_with_program_id(program_id, keys, args)?;
+    let keys: IncreaseValidatorStakeKeys = accounts.into();
+    let ix = increase_validator_stake_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
-pub fn update_validator_list_balance_invoke_signed(
-    accounts: UpdateValidatorListBalanceAccounts<'_, '_>,
-    args: UpdateValidatorListBalanceIxArgs,
+pub fn increase_validator_stake_invoke_signed(
+    accounts: IncreaseValidatorStakeAccounts<'_, '_>,
+    args: IncreaseValidatorStakeIxArgs,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    update_validator_list_balance_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+    increase_validator_stake_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
-pub fn update_validator_list_balance_verify_account_keys(
-    accounts: UpdateValidatorListBalanceAccounts<'_, '_>,
-    keys: UpdateValidatorListBalanceKeys,
+pub fn increase_validator_stake_verify_account_keys(
+    accounts: IncreaseValidatorStakeAccounts<'_, '_>,
+    keys: IncreaseValidatorStakeKeys,
 ) -> Result<(), (Pubkey, Pubkey)> {
     for (actual, expected) in [
         (accounts.stake_pool.key, &keys.stake_pool),
+        (accounts.staker.key, &keys.staker),
         (accounts.withdraw_authority.key, &keys.withdraw_authority),
         (accounts.validator_list.key, &keys.validator_list),
         (accounts.reserve_stake.key, &keys.reserve_stake),
+        (
+            accounts.transient_stake_account.key,
+            &keys.transient_stake_account,
+        ),
+        (
+            accounts.validator_stake_account.key,
+            &keys.validator_stake_account,
+        ),
+        (accounts.vote_account.key, &keys.vote_account),
         (accounts.clock.key, &keys.clock),
+        (accounts.rent.key, &keys.rent),
         (accounts.stake_history.key, &keys.stake_history),
+        (accounts.stake_config.key, &keys.stake_config),
+        (accounts.system_program.key, &keys.system_program),
         (accounts.stake_program.key, &keys.stake_program),
     ] {
         if actual != expected {
@@ -1572,72 +1859,67 @@ pub fn update_validator_list_balance_verify_account_keys(
     }
     Ok(())
 }
-pub fn update_validator_list_balance_verify_writable_privileges<'me, 'info>(
-    accounts: UpdateValidatorListBalanceAccounts<'me, 'info>,
+pub fn increase_validator_stake_verify_writable_privileges<'me, 'info>(
+    accounts: IncreaseValidatorStakeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
-    for should_be_writable in [accounts.validator_list, accounts.reserve_stake] {
+    for should_be_writable in [
+        accounts.validator_list,
+        accounts.reserve_stake,
+        accounts.transient_stake_account,
+    ] {
         if !should_be_writable.is_writable {
             return Err((should_be_writable, ProgramError::InvalidAccountData));
         }
     }
     Ok(())
 }
-pub fn update_validator_list_balance_verify_account_privileges<'me, 'info>(
-    accounts: UpdateValidatorListBalanceAccounts<'me, 'info>,
+pub fn increase_validator_stake_verify_signer_privileges<'me, 'info>(
+    accounts: IncreaseValidatorStakeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
-    update_validator_list_balance_verify_writable_privileges(accounts)?;
+    for should_be_signer in [accounts.staker] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
     Ok(())
 }
-pub const UPDATE_STAKE_POOL_BALANCE_IX_ACCOUNTS_LEN: usize = 7;
+pub fn increase_validator_stake_verify_account_privileges<'me, 'info>(
+    accounts: IncreaseValidatorStakeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    increase_validator_stake_verify_writable_privileges(accounts)?;
+    increase_validator_stake_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_PREFERRED_VALIDATOR_IX_ACCOUNTS_LEN: usize = 3;
 #[derive(Copy, Clone, Debug)]
-pub struct UpdateStakePoolBalanceAccounts<'me, 'info> {
+pub struct SetPreferredValidatorAccounts<'me, 'info> {
     ///Stake pool
     pub stake_pool: &'me AccountInfo<'info>,
-    ///Stake pool withdraw authority
-    pub withdraw_authority: &'me AccountInfo<'info>,
+    ///Staker
+    pub staker: &'me AccountInfo<'info>,
     ///Validator list
     pub validator_list: &'me AccountInfo<'info>,
-    ///Reserve stake account
-    pub reserve_stake: &'me AccountInfo<'info>,
-    ///Account to receive pool fee tokens
-    pub manager_fee_account: &'me AccountInfo<'info>,
-    ///Pool token mint.
-    pub pool_mint: &'me AccountInfo<'info>,
-    ///Pool token's token program.
-    pub token_program: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug)]
-pub struct UpdateStakePoolBalanceKeys {
+pub struct SetPreferredValidatorKeys {
     ///Stake pool
     pub stake_pool: Pubkey,
-    ///Stake pool withdraw authority
-    pub withdraw_authority: Pubkey,
+    ///Staker
+    pub staker: Pubkey,
     ///Validator list
     pub validator_list: Pubkey,
-    ///Reserve stake account
-    pub reserve_stake: Pubkey,
-    ///Account to receive pool fee tokens
-    pub manager_fee_account: Pubkey,
-    ///Pool token mint.
-    pub pool_mint: Pubkey,
-    ///Pool token's token program.
-    pub token_program: Pubkey,
 }
-impl From<UpdateStakePoolBalanceAccounts<'_, '_>> for UpdateStakePoolBalanceKeys {
-    fn from(accounts: UpdateStakePoolBalanceAccounts) -> Self {
+impl From<SetPreferredValidatorAccounts<'_, '_>> for SetPreferredValidatorKeys {
+    fn from(accounts: SetPreferredValidatorAccounts) -> Self {
         Self {
             stake_pool: *accounts.stake_pool.key,
-            withdraw_authority: *accounts.withdraw_authority.key,
+            staker: *accounts.staker.key,
             validator_list: *accounts.validator_list.key,
-            reserve_stake: *accounts.reserve_stake.key,
-            manager_fee_account: *accounts.manager_fee_account.key,
-            pool_mint: *accounts.pool_mint.key,
-            token_program: *accounts.token_program.key,
         }
     }
 }
-impl From<UpdateStakePoolBalanceKeys> for [AccountMeta; UPDATE_STAKE_POOL_BALANCE_IX_ACCOUNTS_LEN] {
-    fn from(keys: UpdateStakePoolBalanceKeys) -> Self {
+impl From<SetPreferredValidatorKeys> for [AccountMeta; SET_PREFERRED_VALIDATOR_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetPreferredValidatorKeys) -> Self {
         [
             AccountMeta {
                 pubkey: keys.stake_pool,
@@ -1645,103 +1927,83 @@ impl From<UpdateStakePoolBalanceKeys> for [AccountMeta; UPDATE_STAKE_POOL_BALANC
                 is_writable: true,
             },
             AccountMeta {
-                pubkey: keys.withdraw_authority,
-                is_signer: false,
+                pubkey: keys.staker,
+                is_signer: true,
                 is_writable: false,
             },
             AccountMeta {
                 pubkey: keys.validator_list,
                 is_signer: false,
-                is_writable: true,
-            },
-            AccountMeta {
-                pubkey: keys.reserve_stake,
-                is_signer: false,
-                is_writable: false,
-            },
-            AccountMeta {
-                pubkey: keys.manager_fee_account,
-                is_signer: false,
-                is_writable: true,
-            },
-            AccountMeta {
-                pubkey: keys.pool_mint,
-                is_signer: false,
-                is_writable: true,
-            },
-            AccountMeta {
-                pubkey: keys.token_program,
-                is_signer: false,
                 is_writable: false,
             },
         ]
     }
 }
-impl From<[Pubkey; UPDATE_STAKE_POOL_BALANCE_IX_ACCOUNTS_LEN]> for UpdateStakePoolBalanceKeys {
-    fn from(pubkeys: [Pubkey; UPDATE_STAKE_POOL_BALANCE_IX_ACCOUNTS_LEN]) -> Self {
+impl From<[Pubkey; SET_PREFERRED_VALIDATOR_IX_ACCOUNTS_LEN]> for SetPreferredValidatorKeys {
+    fn from(pubkeys: [Pubkey; SET_PREFERRED_VALIDATOR_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             stake_pool: pubkeys[0],
-            withdraw_authority: pubkeys[1],
+            staker: pubkeys[1],
             validator_list: pubkeys[2],
-            reserve_stake: pubkeys[3],
-            manager_fee_account: pubkeys[4],
-            pool_mint: pubkeys[5],
-            token_program: pubkeys[6],
         }
     }
 }
-impl<'info> From<UpdateStakePoolBalanceAccounts<'_, 'info>>
-    for [AccountInfo<'info>; UPDATE_STAKE_POOL_BALANCE_IX_ACCOUNTS_LEN]
+impl<'info> From<SetPreferredValidatorAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_PREFERRED_VALIDATOR_IX_ACCOUNTS_LEN]
 {
-    fn from(accounts: UpdateStakePoolBalanceAccounts<'_, 'info>) -> Self {
+    fn from(accounts: SetPreferredValidatorAccounts<'_, 'info>) -> Self {
         [
             accounts.stake_pool.clone(),
-            accounts.withdraw_authority.clone(),
+            accounts.staker.clone(),
             accounts.validator_list.clone(),
-            accounts.reserve_stake.clone(),
-            accounts.manager_fee_account.clone(),
-            accounts.pool_mint.clone(),
-            accounts.token_program.clone(),
         ]
     }
 }
-impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_STAKE_POOL_BALANCE_IX_ACCOUNTS_LEN]>
-    for UpdateStakePoolBalanceAccounts<'me, 'info>
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_PREFERRED_VALIDATOR_IX_ACCOUNTS_LEN]>
+    for SetPreferredValidatorAccounts<'me, 'info>
 {
-    fn from(arr: &'me [AccountInfo<'info>; UPDATE_STAKE_POOL_BALANCE_IX_ACCOUNTS_LEN]) -> Self {
+    fn from(arr: &'me [AccountInfo<'info>; SET_PREFERRED_VALIDATOR_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             stake_pool: &arr[0],
-            withdraw_authority: &arr[1],
+            staker: &arr[1],
             validator_list: &arr[2],
-            reserve_stake: &arr[3],
-            manager_fee_account: &arr[4],
-            pool_mint: &arr[5],
-            token_program: &arr[6],
         }
     }
 }
-pub const UPDATE_STAKE_POOL_BALANCE_IX_DISCM: u8 = 7u8;
+pub const SET_PREFERRED_VALIDATOR_IX_DISCM: u8 = 5u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetPreferredValidatorIxArgs {
+    pub validator_type: PreferredValidatorType,
+    pub validator_vote_address: Option<Pubkey>,
+}
 #[derive(Clone, Debug, PartialEq)]
-pub struct UpdateStakePoolBalanceIxData;
-impl UpdateStakePoolBalanceIxData {
+pub struct SetPreferredValidatorIxData(pub SetPreferredValidatorIxArgs);
+impl From<SetPreferredValidatorIxArgs> for SetPreferredValidatorIxData {
+    fn from(args: SetPreferredValidatorIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetPreferredValidatorIxData {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
-        if maybe_discm != UPDATE_STAKE_POOL_BALANCE_IX_DISCM {
+        if maybe_discm != SET_PREFERRED_VALIDATOR_IX_DISCM {
             return Err(std::io::Error::new(
                 std::io::ErrorKind::Other,
                 format!(
                     "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_STAKE_POOL_BALANCE_IX_DISCM, maybe_discm
+                    SET_PREFERRED_VALIDATOR_IX_DISCM, maybe_discm
                 ),
             ));
         }
-        Ok(Self)
+        Ok(Self(SetPreferredValidatorIxArgs::deserialize(&mut reader)?))
     }
     pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
-        writer.write_all(&[UPDATE_STAKE_POOL_BALANCE_IX_DISCM])
+        writer.write_all(&[SET_PREFERRED_VALIDATOR_IX_DISCM])?;
+        self.0.serialize(&mut writer)
     }
     pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
         let mut data = Vec::new();
@@ -1749,62 +2011,65 @@ impl UpdateStakePoolBalanceIxData {
         Ok(data)
     }
 }
-pub fn update_stake_pool_balance_ix_with_program_id(
+pub fn set_preferred_validator_ix_with_program_id(
     program_id: Pubkey,
-    keys: UpdateStakePoolBalanceKeys,
+    keys: SetPreferredValidatorKeys,
+    args: SetPreferredValidatorIxArgs,
 ) -> std::io::Result<Instruction> {
-    let metas: [AccountMeta; UPDATE_STAKE_POOL_BALANCE_IX_ACCOUNTS_LEN] = keys.into();
+    let metas: [AccountMeta; SET_PREFERRED_VALIDATOR_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetPreferredValidatorIxData = args.into();
     Ok(Instruction {
         program_id,
         accounts: Vec::from(metas),
-        data: UpdateStakePoolBalanceIxData.try_to_vec()?,
+        data: data.try_to_vec()?,
     })
 }
-pub fn update_stake_pool_balance_ix(
-    keys: UpdateStakePoolBalanceKeys,
+pub fn set_preferred_validator_ix(
+    keys: SetPreferredValidatorKeys,
+    args: SetPreferredValidatorIxArgs,
 ) -> std::io::Result<Instruction> {
-    update_stake_pool_balance_ix_with_program_id(crate::ID, keys)
+    set_preferred_validator_ix_with_program_id(crate::ID, keys, args)
 }
-pub fn update_stake_pool_balance_invoke_with_program_id(
+pub fn set_preferred_validator_invoke_with_program_id(
     program_id: Pubkey,
-    accounts: UpdateStakePoolBalanceAccounts<'_, '_>,
+    accounts: SetPreferredValidatorAccounts<'_, '_>,
+    args: SetPreferredValidatorIxArgs,
 ) -> ProgramResult {
-    let keys: UpdateStakePoolBalanceKeys = accounts.into();
-    let ix = update_stake_pool_balance_ix_with_program_id(program_id, keys)?;
+    let keys: SetPreferredValidatorKeys = accounts.into();
+    let ix = set_preferred_validator_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
-pub fn update_stake_pool_balance_invoke(
-    accounts: UpdateStakePoolBalanceAccounts<'_, '_>,
+pub fn set_preferred_validator_invoke(
+    accounts: SetPreferredValidatorAccounts<'_, '_>,
+    args: SetPreferredValidatorIxArgs,
 ) -> ProgramResult {
-    update_stake_pool_balance_invoke_with_program_id(crate::ID, accounts)
+    set_preferred_validator_invoke_with_program_id(crate::ID, accounts, args)
 }
-pub fn update_stake_pool_balance_invoke_signed_with_program_id(
+pub fn set_preferred_validator_invoke_signed_with_program_id(
     program_id: Pubkey,
-    accounts: UpdateStakePoolBalanceAccounts<'_, '_>,
+    accounts: SetPreferredValidatorAccounts<'_, '_>,
+    args: SetPreferredValidatorIxArgs,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let keys: UpdateStakePoolBalanceKeys = accounts.into();
-    let ix = update_stake_pool_balance_ix_with_program_id(program_id, keys)?;
+    let keys: SetPreferredValidatorKeys = accounts.into();
+    let ix = set_preferred_validator_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
-pub fn update_stake_pool_balance_invoke_signed(
-    accounts: UpdateStakePoolBalanceAccounts<'_, '_>,
+pub fn set_preferred_validator_invoke_signed(
+    accounts: SetPreferredValidatorAccounts<'_, '_>,
+    args: SetPreferredValidatorIxArgs,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    update_stake_pool_balance_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+    set_preferred_validator_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
-pub fn update_stake_pool_balance_verify_account_keys(
-    accounts: UpdateStakePoolBalanceAccounts<'_, '_>,
-    keys: UpdateStakePoolBalanceKeys,
+pub fn set_preferred_validator_verify_account_keys(
+    accounts: SetPreferredValidatorAccounts<'_, '_>,
+    keys: SetPreferredValidatorKeys,
 ) -> Result<(), (Pubkey, Pubkey)> {
     for (actual, expected) in [
         (accounts.stake_pool.key, &keys.stake_pool),
-        (accounts.withdraw_authority.key, &keys.withdraw_authority),
+        (accounts.staker.key, &keys.staker),
         (accounts.validator_list.key, &keys.validator_list),
-        (accounts.reserve_stake.key, &keys.reserve_stake),
-        (accounts.manager_fee_account.key, &keys.manager_fee_account),
-        (accounts.pool_mint.key, &keys.pool_mint),
-        (accounts.token_program.key, &keys.token_program),
     ] {
         if actual != expected {
             return Err((*actual, *expected));
@@ -1812,119 +2077,205 @@ pub fn update_stake_pool_balance_verify_account_keys(
     }
     Ok(())
 }
-pub fn update_stake_pool_balance_verify_writable_privileges<'me, 'info>(
-    accounts: UpdateStakePoolBalanceAccounts<'me, 'info>,
+pub fn set_preferred_validator_verify_writable_privileges<'me, 'info>(
+    accounts: SetPreferredValidatorAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
-    for should_be_writable in [
-        accounts.stake_pool,
-        accounts.validator_list,
-        accounts.manager_fee_account,
-        accounts.pool_mint,
-    ] {
+    for should_be_writable in [accounts.stake_pool] {
         if !should_be_writable.is_writable {
             return Err((should_be_writable, ProgramError::InvalidAccountData));
         }
     }
     Ok(())
 }
-pub fn update_stake_pool_balance_verify_account_privileges<'me, 'info>(
-    accounts: UpdateStakePoolBalanceAccounts<'me, 'info>,
+pub fn set_preferred_validator_verify_signer_privileges<'me, 'info>(
+    accounts: SetPreferredValidatorAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
-    update_stake_pool_balance_verify_writable_privileges(accounts)?;
+    for should_be_signer in [accounts.staker] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
     Ok(())
 }
-pub const CLEANUP_REMOVED_VALIDATOR_ENTRIES_IX_ACCOUNTS_LEN: usize = 2;
+pub fn set_preferred_validator_verify_account_privileges<'me, 'info>(
+    accounts: SetPreferredValidatorAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_preferred_validator_verify_writable_privileges(accounts)?;
+    set_preferred_validator_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const UPDATE_VALIDATOR_LIST_BALANCE_IX_ACCOUNTS_LEN: usize = 7;
 #[derive(Copy, Clone, Debug)]
-pub struct CleanupRemovedValidatorEntriesAccounts<'me, 'info> {
+pub struct UpdateValidatorListBalanceAccounts<'me, 'info> {
     ///Stake pool
     pub stake_pool: &'me AccountInfo<'info>,
+    ///Stake pool withdraw authority
+    pub withdraw_authority: &'me AccountInfo<'info>,
     ///Validator list
     pub validator_list: &'me AccountInfo<'info>,
+    ///Reserve stake account
+    pub reserve_stake: &'me AccountInfo<'info>,
+    ///Clock sysvar
+    pub clock: &'me AccountInfo<'info>,
+    ///Stake history sysvar
+    pub stake_history: &'me AccountInfo<'info>,
+    ///Stake program. N pairs of validator and transient stake accounts follow.
+    pub stake_program: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug)]
-pub struct CleanupRemovedValidatorEntriesKeys {
+pub struct UpdateValidatorListBalanceKeys {
     ///Stake pool
     pub stake_pool: Pubkey,
+    ///Stake pool withdraw authority
+    pub withdraw_authority: Pubkey,
     ///Validator list
     pub validator_list: Pubkey,
+    ///Reserve stake account
+    pub reserve_stake: Pubkey,
+    ///Clock sysvar
+    pub clock: Pubkey,
+    ///Stake history sysvar
+    pub stake_history: Pubkey,
+    ///Stake program. N pairs of validator and transient stake accounts follow.
+    pub stake_program: Pubkey,
 }
-impl From<CleanupRemovedValidatorEntriesAccounts<'_, '_>> for CleanupRemovedValidatorEntriesKeys {
-    fn from(accounts: CleanupRemovedValidatorEntriesAccounts) -> Self {
+impl From<UpdateValidatorListBalanceAccounts<'_, '_>> for UpdateValidatorListBalanceKeys {
+    fn from(accounts: UpdateValidatorListBalanceAccounts) -> Self {
         Self {
             stake_pool: *accounts.stake_pool.key,
+            withdraw_authority: *accounts.withdraw_authority.key,
             validator_list: *accounts.validator_list.key,
+            reserve_stake: *accounts.reserve_stake.key,
+            clock: *accounts.clock.key,
+            stake_history: *accounts.stake_history.key,
+            stake_program: *accounts.stake_program.key,
         }
     }
 }
-impl From<CleanupRemovedValidatorEntriesKeys>
-    for [AccountMeta; CLEANUP_REMOVED_VALIDATOR_ENTRIES_IX_ACCOUNTS_LEN]
+impl From<UpdateValidatorListBalanceKeys>
+    for [AccountMeta; UPDATE_VALIDATOR_LIST_BALANCE_IX_ACCOUNTS_LEN]
 {
-    fn from(keys: CleanupRemovedValidatorEntriesKeys) -> Self {
+    fn from(keys: UpdateValidatorListBalanceKeys) -> Self {
         [
             AccountMeta {
                 pubkey: keys.stake_pool,
                 is_signer: false,
                 is_writable: false,
             },
+            AccountMeta {
+                pubkey: keys.withdraw_authority,
+                is_signer: false,
+                is_writable: false,
+            },
             AccountMeta {
                 pubkey: keys.validator_list,
                 is_signer: false,
                 is_writable: true,
             },
+            AccountMeta {
+                pubkey: keys.reserve_stake,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.clock,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.stake_history,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.stake_program,
+                is_signer: false,
+                is_writable: false,
+            },
         ]
     }
 }
-impl From<[Pubkey; CLEANUP_REMOVED_VALIDATOR_ENTRIES_IX_ACCOUNTS_LEN]>
-    for CleanupRemovedValidatorEntriesKeys
+impl From<[Pubkey; UPDATE_VALIDATOR_LIST_BALANCE_IX_ACCOUNTS_LEN]>
+    for UpdateValidatorListBalanceKeys
 {
-    fn from(pubkeys: [Pubkey; CLEANUP_REMOVED_VALIDATOR_ENTRIES_IX_ACCOUNTS_LEN]) -> Self {
+    fn from(pubkeys: [Pubkey; UPDATE_VALIDATOR_LIST_BALANCE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             stake_pool: pubkeys[0],
-            validator_list: pubkeys[1],
+            withdraw_authority: pubkeys[1],
+            validator_list: pubkeys[2],
+            reserve_stake: pubkeys[3],
+            clock: pubkeys[4],
+            stake_history: pubkeys[5],
+            stake_program: pubkeys[6],
         }
     }
 }
-impl<'info> From<CleanupRemovedValidatorEntriesAccounts<'_, 'info>>
-    for [AccountInfo<'info>; CLEANUP_REMOVED_VALIDATOR_ENTRIES_IX_ACCOUNTS_LEN]
+impl<'info> From<UpdateValidatorListBalanceAccounts<'_, 'info>>
+    for [AccountInfo<'info>; UPDATE_VALIDATOR_LIST_BALANCE_IX_ACCOUNTS_LEN]
 {
-    fn from(accounts: CleanupRemovedValidatorEntriesAccounts<'_, 'info>) -> Self {
-        [accounts.stake_pool.clone(), accounts.validator_list.clone()]
+    fn from(accounts: UpdateValidatorListBalanceAccounts<'_, 'info>) -> Self {
+        [
+            accounts.stake_pool.clone(),
+            accounts.withdraw_authority.clone(),
+            accounts.validator_list.clone(),
+            accounts.reserve_stake.clone(),
+            accounts.clock.clone(),
+            accounts.stake_history.clone(),
+            accounts.stake_program.clone(),
+        ]
     }
 }
-impl<'me, 'info> From<&'me [AccountInfo<'info>; CLEANUP_REMOVED_VALIDATOR_ENTRIES_IX_ACCOUNTS_LEN]>
-    for CleanupRemovedValidatorEntriesAccounts<'me, 'info>
+impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_VALIDATOR_LIST_BALANCE_IX_ACCOUNTS_LEN]>
+    for UpdateValidatorListBalanceAccounts<'me, 'info>
 {
-    fn from(
-        arr: &'me [AccountInfo<'info>; CLEANUP_REMOVED_VALIDATOR_ENTRIES_IX_ACCOUNTS_LEN],
-    ) -> Self {
+    fn from(arr: &'me [AccountInfo<'info>; UPDATE_VALIDATOR_LIST_BALANCE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             stake_pool: &arr[0],
-            validator_list: &arr[1],
+            withdraw_authority: &arr[1],
+            validator_list: &arr[2],
+            reserve_stake: &arr[3],
+            clock: &arr[4],
+            stake_history: &arr[5],
+            stake_program: &arr[6],
         }
     }
 }
-pub const CLEANUP_REMOVED_VALIDATOR_ENTRIES_IX_DISCM: u8 = 8u8;
+pub const UPDATE_VALIDATOR_LIST_BALANCE_IX_DISCM: u8 = 6u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UpdateValidatorListBalanceIxArgs {
+    pub start_index: u32,
+    pub no_merge: bool,
+}
 #[derive(Clone, Debug, PartialEq)]
-pub struct CleanupRemovedValidatorEntriesIxData;
-impl CleanupRemovedValidatorEntriesIxData {
+pub struct UpdateValidatorListBalanceIxData(pub UpdateValidatorListBalanceIxArgs);
+impl From<UpdateValidatorListBalanceIxArgs> for UpdateValidatorListBalanceIxData {
+    fn from(args: UpdateValidatorListBalanceIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl UpdateValidatorListBalanceIxData {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
-        if maybe_discm != CLEANUP_REMOVED_VALIDATOR_ENTRIES_IX_DISCM {
+        if maybe_discm != UPDATE_VALIDATOR_LIST_BALANCE_IX_DISCM {
             return Err(std::io::Error::new(
                 std::io::ErrorKind::Other,
                 format!(
                     "discm does not match. Expected: {:?}. Received: {:?}",
-                    CLEANUP_REMOVED_VALIDATOR_ENTRIES_IX_DISCM, maybe_discm
+                    UPDATE_VALIDATOR_LIST_BALANCE_IX_DISCM, maybe_discm
                 ),
             ));
         }
-        Ok(Self)
+        Ok(Self(UpdateValidatorListBalanceIxArgs::deserialize(
+            &mut reader,
+        )?))
     }
     pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
-        writer.write_all(&[CLEANUP_REMOVED_VALIDATOR_ENTRIES_IX_DISCM])
+        writer.write_all(&[UPDATE_VALIDATOR_LIST_BALANCE_IX_DISCM])?;
+        self.0.serialize(&mut writer)
     }
     pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
         let mut data = Vec::new();
@@ -1932,57 +2283,69 @@ impl CleanupRemovedValidatorEntriesIxData {
         Ok(data)
     }
 }
-pub fn cleanup_removed_validator_entries_ix_with_program_id(
+pub fn update_validator_list_balance_ix_with_program_id(
     program_id: Pubkey,
-    keys: CleanupRemovedValidatorEntriesKeys,
+    keys: UpdateValidatorListBalanceKeys,
+    args: UpdateValidatorListBalanceIxArgs,
 ) -> std::io::Result<Instruction> {
-    let metas: [AccountMeta; CLEANUP_REMOVED_VALIDATOR_ENTRIES_IX_ACCOUNTS_LEN] = keys.into();
+    let metas: [AccountMeta; UPDATE_VALIDATOR_LIST_BALANCE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: UpdateValidatorListBalanceIxData = args.into();
     Ok(Instruction {
         program_id,
         accounts: Vec::from(metas),
-        data: CleanupRemovedValidatorEntriesIxData.try_to_vec()?,
+        data: data.try_to_vec()?,
     })
 }
-pub fn cleanup_removed_validator_entries_ix(
-    keys: CleanupRemovedValidatorEntriesKeys,
+pub fn update_validator_list_balance_ix(
+    keys: UpdateValidatorListBalanceKeys,
+    args: UpdateValidatorListBalanceIxArgs,
 ) -> std::io::Result<Instruction> {
-    cleanup_removed_validator_entries_ix_with_program_id(crate::ID, keys)
+    update_validator_list_balance_ix_with_program_id(crate::ID, keys, args)
 }
-pub fn cleanup_removed_validator_entries_invoke_with_program_id(
+pub fn update_validator_list_balance_invoke_with_program_id(
     program_id: Pubkey,
-    accounts: CleanupRemovedValidatorEntriesAccounts<'_, '_>,
+    accounts: UpdateValidatorListBalanceAccounts<'_, '_>,
+    args: UpdateValidatorListBalanceIxArgs,
 ) -> ProgramResult {
-    let keys: CleanupRemovedValidatorEntriesKeys = accounts.into();
-    let ix = cleanup_removed_validator_entries_ix_with_program_id(program_id, keys)?;
+    let keys: UpdateValidatorListBalanceKeys = accounts.into();
+    let ix = update_validator_list_balance_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
-pub fn cleanup_removed_validator_entries_invoke(
-    accounts: CleanupRemovedValidatorEntriesAccounts<'_, '_>,
+pub fn update_validator_list_balance_invoke(
+    accounts: UpdateValidatorListBalanceAccounts<'_, '_>,
+    args: UpdateValidatorListBalanceIxArgs,
 ) -> ProgramResult {
-    cleanup_removed_validator_entries_invoke_with_program_id(crate::ID, accounts)
+    update_validator_list_balance_invoke_with_program_id(crate::ID, accounts, args)
 }
-pub fn cleanup_removed_validator_entries_invoke_signed_with_program_id(
+pub fn update_validator_list_balance_invoke_signed_with_program_id(
     program_id: Pubkey,
-    accounts: CleanupRemovedValidatorEntriesAccounts<'_, '_>,
+    accounts: UpdateValidatorListBalanceAccounts<'_, '_>,
+    args: UpdateValidatorListBalanceIxArgs,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let keys: CleanupRemovedValidatorEntriesKeys = accounts.into();
-    let ix = cleanup_removed_validator_entries_ix_with_program_id(program_id, keys)?;
+    let keys: UpdateValidatorListBalanceKeys = accounts.into();
+    let ix = update_validator_list_balance_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
-pub fn cleanup_removed_validator_entries_invoke_signed(
-    accounts: CleanupRemovedValidatorEntriesAccounts<'_, '_>,
+pub fn update_validator_list_balance_invoke_signed(
+    accounts: UpdateValidatorListBalanceAccounts<'_, '_>,
+    args: UpdateValidatorListBalanceIxArgs,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    cleanup_removed_validator_entries_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+    update_validator_list_balance_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
-pub fn cleanup_removed_validator_entries_verify_account_keys(
-    accounts: CleanupRemovedValidatorEntriesAccounts<'_, '_>,
-    keys: CleanupRemovedValidatorEntriesKeys,
+pub fn update_validator_list_balance_verify_account_keys(
+    accounts: UpdateValidatorListBalanceAccounts<'_, '_>,
+    keys: UpdateValidatorListBalanceKeys,
 ) -> Result<(), (Pubkey, Pubkey)> {
     for (actual, expected) in [
         (accounts.stake_pool.key, &keys.stake_pool),
+        (accounts.withdraw_authority.key, &keys.withdraw_authority),
         (accounts.validator_list.key, &keys.validator_list),
+        (accounts.reserve_stake.key, &keys.reserve_stake),
+        (accounts.clock.key, &keys.clock),
+        (accounts.stake_history.key, &keys.stake_history),
+        (accounts.stake_program.key, &keys.stake_program),
     ] {
         if actual != expected {
             return Err((*actual, *expected));
@@ -1990,57 +2353,72 @@ pub fn cleanup_removed_validator_entries_verify_account_keys(
     }
     Ok(())
 }
-pub fn cleanup_removed_validator_entries_verify_writable_privileges<'me, 'info>(
-    accounts: CleanupRemovedValidatorEntriesAccounts<'me, 'info>,
+pub fn update_validator_list_balance_verify_writable_privileges<'me, 'info>(
+    accounts: UpdateValidatorListBalanceAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
-    for should_be_writable in [accounts.validator_list] {
+    for should_be_writable in [accounts.validator_list, accounts.reserve_stake] {
         if !should_be_writable.is_writable {
             return Err((should_be_writable, ProgramError::InvalidAccountData));
         }
     }
     Ok(())
 }
-pub fn cleanup_removed_validator_entries_verify_account_privileges<'me, 'info>(
-    accounts: CleanupRemovedValidatorEntriesAccounts<'me, 'info>,
+pub fn update_validator_list_balance_verify_account_privileges<'me, 'info>(
+    accounts: UpdateValidatorListBalanceAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
-    cleanup_removed_validator_entries_verify_writable_privileges(accounts)?;
+    update_validator_list_balance_verify_writable_privileges(accounts)?;
     Ok(())
 }
-pub const SET_MANAGER_IX_ACCOUNTS_LEN: usize = 4;
+pub const UPDATE_STAKE_POOL_BALANCE_IX_ACCOUNTS_LEN: usize = 7;
 #[derive(Copy, Clone, Debug)]
-pub struct SetManagerAccounts<'me, 'info> {
+pub struct UpdateStakePoolBalanceAccounts<'me, 'info> {
     ///Stake pool
     pub stake_pool: &'me AccountInfo<'info>,
-    ///Current manager
-    pub manager: &'me AccountInfo<'info>,
-    ///New manager
-    pub new_manager: &'me AccountInfo<'info>,
-    ///New manager fee account
-    pub new_manager_fee_account: &'me AccountInfo<'info>,
+    ///Stake pool withdraw authority
+    pub withdraw_authority: &'me AccountInfo<'info>,
+    ///Validator list
+    pub validator_list: &'me AccountInfo<'info>,
+    ///Reserve stake account
+    pub reserve_stake: &'me AccountInfo<'info>,
+    ///Account to receive pool fee tokens
+    pub manager_fee_account: &'me AccountInfo<'info>,
+    ///Pool token mint.
+    pub pool_mint: &'me AccountInfo<'info>,
+    ///Pool token's token program.
+    pub token_program: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug)]
-pub struct SetManagerKeys {
+pub struct UpdateStakePoolBalanceKeys {
     ///Stake pool
     pub stake_pool: Pubkey,
-    ///Current manager
-    pub manager: Pubkey,
-    ///New manager
-    pub new_manager: Pubkey,
-    ///New manager fee account
-    pub new_manager_fee_account: Pubkey,
+    ///Stake pool withdraw authority
+    pub withdraw_authority: Pubkey,
+    ///Validator list
+    pub validator_list: Pubkey,
+    ///Reserve stake account
+    pub reserve_stake: Pubkey,
+    ///Account to receive pool fee tokens
+    pub manager_fee_account: Pubkey,
+    ///Pool token mint.
+    pub pool_mint: Pubkey,
+    ///Pool token's token program.
+    pub token_program: Pubkey,
 }
-impl From<SetManagerAccounts<'_, '_>> for SetManagerKeys {
-    fn from(accounts: SetManagerAccounts) -> Self {
+impl From<UpdateStakePoolBalanceAccounts<'_, '_>> for UpdateStakePoolBalanceKeys {
+    fn from(accounts: UpdateStakePoolBalanceAccounts) -> Self {
         Self {
             stake_pool: *accounts.stake_pool.key,
-            manager: *accounts.manager.key,
-            new_manager: *accounts.new_manager.key,
-            new_manager_fee_account: *accounts.new_manager_fee_account.key,
+            withdraw_authority: *accounts.withdraw_authority.key,
+            validator_list: *accounts.validator_list.key,
+            reserve_stake: *accounts.reserve_stake.key,
+            manager_fee_account: *accounts.manager_fee_account.key,
+            pool_mint: *accounts.pool_mint.key,
+            token_program: *accounts.token_program.key,
         }
     }
 }
-impl From<SetManagerKeys> for [AccountMeta; SET_MANAGER_IX_ACCOUNTS_LEN] {
-    fn from(keys: SetManagerKeys) -> Self {
+impl From<UpdateStakePoolBalanceKeys> for [AccountMeta; UPDATE_STAKE_POOL_BALANCE_IX_ACCOUNTS_LEN] {
+    fn from(keys: UpdateStakePoolBalanceKeys) -> Self {
         [
             AccountMeta {
                 pubkey: keys.stake_pool,
@@ -2048,79 +2426,103 @@ impl From<SetManagerKeys> for [AccountMeta; SET_MANAGER_IX_ACCOUNTS_LEN] {
                 is_writable: true,
             },
             AccountMeta {
-                pubkey: keys.manager,
-                is_signer: true,
+                pubkey: keys.withdraw_authority,
+                is_signer: false,
                 is_writable: false,
             },
             AccountMeta {
-                pubkey: keys.new_manager,
-                is_signer: true,
+                pubkey: keys.validator_list,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.reserve_stake,
+                is_signer: false,
                 is_writable: false,
             },
             AccountMeta {
-                pubkey: keys.new_manager_fee_account,
+                pubkey: keys.manager_fee_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.token_program,
                 is_signer: false,
                 is_writable: false,
             },
         ]
     }
 }
-impl From<[Pubkey; SET_MANAGER_IX_ACCOUNTS_LEN]> for SetManagerKeys {
-    fn from(pubkeys: [Pubkey; SET_MANAGER_IX_ACCOUNTS_LEN]) -> Self {
+impl From<[Pubkey; UPDATE_STAKE_POOL_BALANCE_IX_ACCOUNTS_LEN]> for UpdateStakePoolBalanceKeys {
+    fn from(pubkeys: [Pubkey; UPDATE_STAKE_POOL_BALANCE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             stake_pool: pubkeys[0],
-            manager: pubkeys[1],
-            new_manager: pubkeys[2],
-            new_manager_fee_account: pubkeys[3],
+            withdraw_authority: pubkeys[1],
+            validator_list: pubkeys[2],
+            reserve_stake: pubkeys[3],
+            manager_fee_account: pubkeys[4],
+            pool_mint: pubkeys[5],
+            token_program: pubkeys[6],
         }
     }
 }
-impl<'info> From<SetManagerAccounts<'_, 'info>>
-    for [AccountInfo<'info>; SET_MANAGER_IX_ACCOUNTS_LEN]
+impl<'info> From<UpdateStakePoolBalanceAccounts<'_, 'info>>
+    for [AccountInfo<'info>; UPDATE_STAKE_POOL_BALANCE_IX_ACCOUNTS_LEN]
 {
-    fn from(accounts: SetManagerAccounts<'_, 'info>) -> Self {
+    fn from(accounts: UpdateStakePoolBalanceAccounts<'_, 'info>) -> Self {
         [
             accounts.stake_pool.clone(),
-            accounts.manager.clone(),
-            accounts.new_manager.clone(),
-            accounts.new_manager_fee_account.clone(),
+            accounts.withdraw_authority.clone(),
+            accounts.validator_list.clone(),
+            accounts.reserve_stake.clone(),
+            accounts.manager_fee_account.clone(),
+            accounts.pool_mint.clone(),
+            accounts.token_program.clone(),
         ]
     }
 }
-impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_MANAGER_IX_ACCOUNTS_LEN]>
-    for SetManagerAccounts<'me, 'info>
+impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_STAKE_POOL_BALANCE_IX_ACCOUNTS_LEN]>
+    for UpdateStakePoolBalanceAccounts<'me, 'info>
 {
-    fn from(arr: &'me [AccountInfo<'info>; SET_MANAGER_IX_ACCOUNTS_LEN]) -> Self {
+    fn from(arr: &'me [AccountInfo<'info>; UPDATE_STAKE_POOL_BALANCE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             stake_pool: &arr[0],
-            manager: &arr[1],
-            new_manager: &arr[2],
-            new_manager_fee_account: &arr[3],
+            withdraw_authority: &arr[1],
+            validator_list: &arr[2],
+            reserve_stake: &arr[3],
+            manager_fee_account: &arr[4],
+            pool_mint: &arr[5],
+            token_program: &arr[6],
         }
     }
 }
-pub const SET_MANAGER_IX_DISCM: u8 = 11u8;
+pub const UPDATE_STAKE_POOL_BALANCE_IX_DISCM: u8 = 7u8;
 #[derive(Clone, Debug, PartialEq)]
-pub struct SetManagerIxData;
-impl SetManagerIxData {
+pub struct UpdateStakePoolBalanceIxData;
+impl UpdateStakePoolBalanceIxData {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
-        if maybe_discm != SET_MANAGER_IX_DISCM {
+        if maybe_discm != UPDATE_STAKE_POOL_BALANCE_IX_DISCM {
             return Err(std::io::Error::new(
                 std::io::ErrorKind::Other,
                 format!(
                     "discm does not match. Expected: {:?}. Received: {:?}",
-                    SET_MANAGER_IX_DISCM, maybe_discm
+                    UPDATE_STAKE_POOL_BALANCE_IX_DISCM, maybe_discm
                 ),
             ));
         }
         Ok(Self)
     }
     pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
-        writer.write_all(&[SET_MANAGER_IX_DISCM])
+        writer.write_all(&[UPDATE_STAKE_POOL_BALANCE_IX_DISCM])
     }
     pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
         let mut data = Vec::new();
@@ -2128,58 +2530,62 @@ impl SetManagerIxData {
         Ok(data)
     }
 }
-pub fn set_manager_ix_with_program_id(
+pub fn update_stake_pool_balance_ix_with_program_id(
     program_id: Pubkey,
-    keys: SetManagerKeys,
+    keys: UpdateStakePoolBalanceKeys,
 ) -> std::io::Result<Instruction> {
-    let metas: [AccountMeta; SET_MANAGER_IX_ACCOUNTS_LEN] = keys.into();
+    let metas: [AccountMeta; UPDATE_STAKE_POOL_BALANCE_IX_ACCOUNTS_LEN] = keys.into();
     Ok(Instruction {
         program_id,
         accounts: Vec::from(metas),
-        data: SetManagerIxData.try_to_vec()?,
+        data: UpdateStakePoolBalanceIxData.try_to_vec()?,
     })
 }
-pub fn set_manager_ix(keys: SetManagerKeys) -> std::io::Result<Instruction> {
-    set_manager_ix_with_program_id(crate::ID, keys)
+pub fn update_stake_pool_balance_ix(
+    keys: UpdateStakePoolBalanceKeys,
+) -> std::io::Result<Instruction> {
+    update_stake_pool_balance_ix_with_program_id(crate::ID, keys)
 }
-pub fn set_manager_invoke_with_program_id(
+pub fn update_stake_pool_balance_invoke_with_program_id(
     program_id: Pubkey,
-    accounts: SetManagerAccounts<'_, '_>,
+    accounts: UpdateStakePoolBalanceAccounts<'_, '_>,
 ) -> ProgramResult {
-    let keys: SetManagerKeys = accounts.into();
-    let ix = set_manager_ix_with_program_id(program_id, keys)?;
+    let keys: UpdateStakePoolBalanceKeys = accounts.into();
+    let ix = update_stake_pool_balance_ix_with_program_id(program_id, keys)?;
     invoke_instruction(&ix, accounts)
 }
-pub fn set_manager_invoke(accounts: SetManagerAccounts<'_, '_>) -> ProgramResult {
-    set_manager_invoke_with_program_id(crate::ID, accounts)
+pub fn update_stake_pool_balance_invoke(
+    accounts: UpdateStakePoolBalanceAccounts<'_, '_>,
+) -> ProgramResult {
+    update_stake_pool_balance_invoke_with_program_id(crate::ID, accounts)
 }
-pub fn set_manager_invoke_signed_with_program_id(
+pub fn update_stake_pool_balance_invoke_signed_with_program_id(
     program_id: Pubkey,
-    accounts: SetManagerAccounts<'_, '_>,
+    accounts: UpdateStakePoolBalanceAccounts<'_, '_>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let keys: SetManagerKeys = accounts.into();
-    let ix = set_manager_ix_with_program_id(program_id, keys)?;
+    let keys: UpdateStakePoolBalanceKeys = accounts.into();
+    let ix = update_stake_pool_balance_ix_with_program_id(program_id, keys)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
-pub fn set_manager_invoke_signed(
-    accounts: SetManagerAccounts<'_, '_>,
+pub fn update_stake_pool_balance_invoke_signed(
+    accounts: UpdateStakePoolBalanceAccounts<'_, '_>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    set_manager_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+    update_stake_pool_balance_invoke_signed_with_program_id(crate::ID, accounts, seeds)
 }
-pub fn set_manager_verify_account_keys(
-    accounts: SetManagerAccounts<'_, '_>,
-    keys: SetManagerKeys,
+pub fn update_stake_pool_balance_verify_account_keys(
+    accounts: UpdateStakePoolBalanceAccounts<'_, '_>,
+    keys: UpdateStakePoolBalanceKeys,
 ) -> Result<(), (Pubkey, Pubkey)> {
     for (actual, expected) in [
         (accounts.stake_pool.key, &keys.stake_pool),
-        (accounts.manager.key, &keys.manager),
-        (accounts.new_manager.key, &keys.new_manager),
-        (
-            accounts.new_manager_fee_account.key,
-            &keys.new_manager_fee_account,
-        ),
+        (accounts.withdraw_authority.key, &keys.withdraw_authority),
+        (accounts.validator_list.key, &keys.validator_list),
+        (accounts.reserve_stake.key, &keys.reserve_stake),
+        (accounts.manager_fee_account.key, &keys.manager_fee_account),
+        (accounts.pool_mint.key, &keys.pool_mint),
+        (accounts.token_program.key, &keys.token_program),
     ] {
         if actual != expected {
             return Err((*actual, *expected));
@@ -2187,128 +2593,119 @@ pub fn set_manager_verify_account_keys(
     }
     Ok(())
 }
-pub fn set_manager_verify_writable_privileges<'me, 'info>(
-    accounts: SetManagerAccounts<'me, 'info>,
+pub fn update_stake_pool_balance_verify_writable_privileges<'me, 'info>(
+    accounts: UpdateStakePoolBalanceAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
-    for should_be_writable in [accounts.stake_pool] {
+    for should_be_writable in [
+        accounts.stake_pool,
+        accounts.validator_list,
+        accounts.manager_fee_account,
+        accounts.pool_mint,
+    ] {
         if !should_be_writable.is_writable {
             return Err((should_be_writable, ProgramError::InvalidAccountData));
         }
     }
     Ok(())
 }
-pub fn set_manager_verify_signer_privileges<'me, 'info>(
-    accounts: SetManagerAccounts<'me, 'info>,
-) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
-    for should_be_signer in [accounts.manager, accounts.new_manager] {
-        if !should_be_signer.is_signer {
-            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
-        }
-    }
-    Ok(())
-}
-pub fn set_manager_verify_account_privileges<'me, 'info>(
-    accounts: SetManagerAccounts<'me, 'info>,
+pub fn update_stake_pool_balance_verify_account_privileges<'me, 'info>(
+    accounts: UpdateStakePoolBalanceAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
-    set_manager_verify_writable_privileges(accounts)?;
-    set_manager_verify_signer_privileges(accounts)?;
+    update_stake_pool_balance_verify_writable_privileges(accounts)?;
     Ok(())
 }
-pub const SET_FEE_IX_ACCOUNTS_LEN: usize = 2;
+pub const CLEANUP_REMOVED_VALIDATOR_ENTRIES_IX_ACCOUNTS_LEN: usize = 2;
 #[derive(Copy, Clone, Debug)]
-pub struct SetFeeAccounts<'me, 'info> {
+pub struct CleanupRemovedValidatorEntriesAccounts<'me, 'info> {
     ///Stake pool
     pub stake_pool: &'me AccountInfo<'info>,
-    ///Current manager
-    pub manager: &'me AccountInfo<'info>,
+    ///Validator list
+    pub validator_list: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug)]
-pub struct SetFeeKeys {
+pub struct CleanupRemovedValidatorEntriesKeys {
     ///Stake pool
     pub stake_pool: Pubkey,
-    ///Current manager
-    pub manager: Pubkey,
+    ///Validator list
+    pub validator_list: Pubkey,
 }
-impl From<SetFeeAccounts<'_, '_>> for SetFeeKeys {
-    fn from(accounts: SetFeeAccounts) -> Self {
+impl From<CleanupRemovedValidatorEntriesAccounts<'_, '_>> for CleanupRemovedValidatorEntriesKeys {
+    fn from(accounts: CleanupRemovedValidatorEntriesAccounts) -> Self {
         Self {
             stake_pool: *accounts.stake_pool.key,
-            manager: *accounts.manager.key,
+            validator_list: *accounts.validator_list.key,
         }
     }
 }
-impl From<SetFeeKeys> for [AccountMeta; SET_FEE_IX_ACCOUNTS_LEN] {
-    fn from(keys: SetFeeKeys) -> Self {
+impl From<CleanupRemovedValidatorEntriesKeys>
+    for [AccountMeta; CLEANUP_REMOVED_VALIDATOR_ENTRIES_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: CleanupRemovedValidatorEntriesKeys) -> Self {
         [
             AccountMeta {
                 pubkey: keys.stake_pool,
                 is_signer: false,
-                is_writable: true,
+                is_writable: false,
             },
             AccountMeta {
-                pubkey: keys.manager,
-                is_signer: true,
-                is_writable: false,
+                pubkey: keys.validator_list,
+                is_signer: false,
+                is_writable: true,
             },
         ]
     }
 }
-impl From<[Pubkey; SET_FEE_IX_ACCOUNTS_LEN]> for SetFeeKeys {
-    fn from(pubkeys: [Pubkey; SET_FEE_IX_ACCOUNTS_LEN]) -> Self {
+impl From<[Pubkey; CLEANUP_REMOVED_VALIDATOR_ENTRIES_IX_ACCOUNTS_LEN]>
+    for CleanupRemovedValidatorEntriesKeys
+{
+    fn from(pubkeys: [Pubkey; CLEANUP_REMOVED_VALIDATOR_ENTRIES_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             stake_pool: pubkeys[0],
-            manager: pubkeys[1],
+            validator_list: pubkeys[1],
         }
     }
 }
-impl<'info> From<SetFeeAccounts<'_, 'info>> for [AccountInfo<'info>; SET_FEE_IX_ACCOUNTS_LEN] {
-    fn from(accounts: SetFeeAccounts<'_, 'info>) -> Self {
-        [accounts.stake_pool.clone(), accounts.manager.clone()]
+impl<'info> From<CleanupRemovedValidatorEntriesAccounts<'_, 'info>>
+    for [AccountInfo<'info>; CLEANUP_REMOVED_VALIDATOR_ENTRIES_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: CleanupRemovedValidatorEntriesAccounts<'_, 'info>) -> Self {
+        [accounts.stake_pool.clone(), accounts.validator_list.clone()]
     }
 }
-impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_FEE_IX_ACCOUNTS_LEN]>
-    for SetFeeAccounts<'me, 'info>
+impl<'me, 'info> From<&'me [AccountInfo<'info>; CLEANUP_REMOVED_VALIDATOR_ENTRIES_IX_ACCOUNTS_LEN]>
+    for CleanupRemovedValidatorEntriesAccounts<'me, 'info>
 {
-    fn from(arr: &'me [AccountInfo<'info>; SET_FEE_IX_ACCOUNTS_LEN]) -> Self {
+    fn from(
+        arr: &'me [AccountInfo<'info>; CLEANUP_REMOVED_VALIDATOR_ENTRIES_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self {
             stake_pool: &arr[0],
-            manager: &arr[1],
+            validator_list: &arr[1],
         }
     }
 }
-pub const SET_FEE_IX_DISCM: u8 = 12u8;
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct SetFeeIxArgs {
-    pub fee: FeeType,
-}
+pub const CLEANUP_REMOVED_VALIDATOR_ENTRIES_IX_DISCM: u8 = 8u8;
 #[derive(Clone, Debug, PartialEq)]
-pub struct SetFeeIxData(pub SetFeeIxArgs);
-impl From<SetFeeIxArgs> for SetFeeIxData {
-    fn from(args: SetFeeIxArgs) -> Self {
-        Self(args)
-    }
-}
-impl SetFeeIxData {
+pub struct CleanupRemovedValidatorEntriesIxData;
+impl CleanupRemovedValidatorEntriesIxData {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
-        if maybe_discm != SET_FEE_IX_DISCM {
+        if maybe_discm != CLEANUP_REMOVED_VALIDATOR_ENTRIES_IX_DISCM {
             return Err(std::io::Error::new(
                 std::io::ErrorKind::Other,
                 format!(
                     "discm does not match. Expected: {:?}. Received: {:?}",
-                    SET_FEE_IX_DISCM, maybe_discm
+                    CLEANUP_REMOVED_VALIDATOR_ENTRIES_IX_DISCM, maybe_discm
                 ),
             ));
         }
-        Ok(Self(SetFeeIxArgs::deserialize(&mut reader)?))
+        Ok(Self)
     }
     pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
-        writer.write_all(&[SET_FEE_IX_DISCM])?;
-        self.0.serialize(&mut writer)
+        writer.write_all(&[CLEANUP_REMOVED_VALIDATOR_ENTRIES_IX_DISCM])
     }
     pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
         let mut data = Vec::new();
@@ -2316,58 +2713,57 @@ impl SetFeeIxData {
         Ok(data)
     }
 }
-pub fn set_fee_ix_with_program_id(
+pub fn cleanup_removed_validator_entries_ix_with_program_id(
     program_id: Pubkey,
-    keys: SetFeeKeys,
-    args: SetFeeIxArgs,
+    keys: CleanupRemovedValidatorEntriesKeys,
 ) -> std::io::Result<Instruction> {
-    let metas: [AccountMeta; SET_FEE_IX_ACCOUNTS_LEN] = keys.into();
-    let data: SetFeeIxData = args.into();
+    let metas: [AccountMeta; CLEANUP_REMOVED_VALIDATOR_ENTRIES_IX_ACCOUNTS_LEN] = keys.into();
     Ok(Instruction {
         program_id,
         accounts: Vec::from(metas),
-        data: data.try_to_vec()?,
+        data: CleanupRemovedValidatorEntriesIxData.try_to_vec()?,
     })
 }
-pub fn set_fee_ix(keys: SetFeeKeys, args: SetFeeIxArgs) -> std::io::Result<Instruction> {
-    set_fee_ix_with_program_id(crate::ID, keys, args)
+pub fn cleanup_removed_validator_entries_ix(
+    keys: CleanupRemovedValidatorEntriesKeys,
+) -> std::io::Result<Instruction> {
+    cleanup_removed_validator_entries_ix_with_program_id(crate::ID, keys)
 }
-pub fn set_fee_invoke_with_program_id(
+pub fn cleanup_removed_validator_entries_invoke_with_program_id(
     program_id: Pubkey,
-    accounts: SetFeeAccounts<'_, '_>,
-    args: SetFeeIxArgs,
+    accounts: CleanupRemovedValidatorEntriesAccounts<'_, '_>,
 ) -> ProgramResult {
-    let keys: SetFeeKeys = accounts.into();
-    let ix = set_fee_ix_with_program_id(program_id, keys, args)?;
+    let keys: CleanupRemovedValidatorEntriesKeys = accounts.into();
+    let ix = cleanup_removed_validator_entries_ix_with_program_id(program_id, keys)?;
     invoke_instruction(&ix, accounts)
 }
-pub fn set_fee_invoke(accounts: SetFeeAccounts<'_, '_>, args: SetFeeIxArgs) -> ProgramResult {
-    set_fee_invoke_with_program_id(crate::ID, accounts, args)
+pub fn cleanup_removed_validator_entries_invoke(
+    accounts: CleanupRemovedValidatorEntriesAccounts<'_, '_>,
+) -> ProgramResult {
+    cleanup_removed_validator_entries_invoke_with_program_id(crate::ID, accounts)
 }
-pub fn set_fee_invoke_signed_with_program_id(
+pub fn cleanup_removed_validator_entries_invoke_signed_with_program_id(
     program_id: Pubkey,
-    accounts: SetFeeAccounts<'_, '_>,
-    args: SetFeeIxArgs,
+    accounts: CleanupRemovedValidatorEntriesAccounts<'_, '_>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let keys: SetFeeKeys = accounts.into();
-    let ix = set_fee_ix_with_program_id(program_id, keys, args)?;
+    let keys: CleanupRemovedValidatorEntriesKeys = accounts.into();
+    let ix = cleanup_removed_validator_entries_ix_with_program_id(program_id, keys)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
-pub fn set_fee_invoke_signed(
-    accounts: SetFeeAccounts<'_, '_>,
-    args: SetFeeIxArgs,
+pub fn cleanup_removed_validator_entries_invoke_signed(
+    accounts: CleanupRemovedValidatorEntriesAccounts<'_, '_>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    set_fee_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+    cleanup_removed_validator_entries_invoke_signed_with_program_id(crate::ID, accounts, seeds)
 }
-pub fn set_fee_verify_account_keys(
-    accounts: SetFeeAccounts<'_, '_>,
-    keys: SetFeeKeys,
+pub fn cleanup_removed_validator_entries_verify_account_keys(
+    accounts: CleanupRemovedValidatorEntriesAccounts<'_, '_>,
+    keys: CleanupRemovedValidatorEntriesKeys,
 ) -> Result<(), (Pubkey, Pubkey)> {
     for (actual, expected) in [
         (accounts.stake_pool.key, &keys.stake_pool),
-        (accounts.manager.key, &keys.manager),
+        (accounts.validator_list.key, &keys.validator_list),
     ] {
         if actual != expected {
             return Err((*actual, *expected));
@@ -2375,63 +2771,112 @@ pub fn set_fee_verify_account_keys(
     }
     Ok(())
 }
-pub fn set_fee_verify_writable_privileges<'me, 'info>(
-    accounts: SetFeeAccounts<'me, 'info>,
+pub fn cleanup_removed_validator_entries_verify_writable_privileges<'me, 'info>(
+    accounts: CleanupRemovedValidatorEntriesAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
-    for should_be_writable in [accounts.stake_pool] {
+    for should_be_writable in [accounts.validator_list] {
         if !should_be_writable.is_writable {
             return Err((should_be_writable, ProgramError::InvalidAccountData));
         }
     }
     Ok(())
 }
-pub fn set_fee_verify_signer_privileges<'me, 'info>(
-    accounts: SetFeeAccounts<'me, 'info>,
-) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
-    for should_be_signer in [accounts.manager] {
-        if !should_be_signer.is_signer {
-            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
-        }
-    }
-    Ok(())
-}
-pub fn set_fee_verify_account_privileges<'me, 'info>(
-    accounts: SetFeeAccounts<'me, 'info>,
+pub fn cleanup_removed_validator_entries_verify_account_privileges<'me, 'info>(
+    accounts: CleanupRemovedValidatorEntriesAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
-    set_fee_verify_writable_privileges(accounts)?;
-    set_fee_verify_signer_privileges(accounts)?;
+    cleanup_removed_validator_entries_verify_writable_privileges(accounts)?;
     Ok(())
 }
-pub const SET_STAKER_IX_ACCOUNTS_LEN: usize = 3;
+pub const DEPOSIT_STAKE_IX_ACCOUNTS_LEN: usize = 15;
 #[derive(Copy, Clone, Debug)]
-pub struct SetStakerAccounts<'me, 'info> {
+pub struct DepositStakeAccounts<'me, 'info> {
     ///Stake pool
     pub stake_pool: &'me AccountInfo<'info>,
-    ///Current manager or staker
-    pub signer: &'me AccountInfo<'info>,
-    ///New staker pubkey
-    pub new_staker: &'me AccountInfo<'info>,
+    ///Validator list
+    pub validator_list: &'me AccountInfo<'info>,
+    ///Stake pool deposit authority. Must be a signer if not default PDA.
+    pub stake_deposit_authority: &'me AccountInfo<'info>,
+    ///Stake pool withdraw authority
+    pub withdraw_authority: &'me AccountInfo<'info>,
+    ///Stake account to deposit
+    pub stake_depositing: &'me AccountInfo<'info>,
+    ///Validator stake account to merge into
+    pub validator_stake_account: &'me AccountInfo<'info>,
+    ///Stake pool reserve stake
+    pub reserve_stake: &'me AccountInfo<'info>,
+    ///LST token account to mint the new LSTs to
+    pub mint_to: &'me AccountInfo<'info>,
+    ///Manager fee account
+    pub manager_fee_account: &'me AccountInfo<'info>,
+    ///LST token account ro receive referral fees
+    pub referral_fee_dest: &'me AccountInfo<'info>,
+    ///Pool token mint
+    pub pool_mint: &'me AccountInfo<'info>,
+    ///Clock sysvar
+    pub clock: &'me AccountInfo<'info>,
+    ///Stake history sysvar
+    pub stake_history: &'me AccountInfo<'info>,
+    ///Pool token program
+    pub token_program: &'me AccountInfo<'info>,
+    ///Stake program
+    pub stake_program: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug)]
-pub struct SetStakerKeys {
+pub struct DepositStakeKeys {
     ///Stake pool
     pub stake_pool: Pubkey,
-    ///Current manager or staker
-    pub signer: Pubkey,
-    ///New staker pubkey
-    pub new_staker: Pubkey,
-}
-impl From<SetStakerAccounts<'_, '_>> for SetStakerKeys {
-    fn from(accounts: SetStakerAccounts) -> Self {
+    ///Validator list
+    pub validator_list: Pubkey,
+    ///Stake pool deposit authority. Must be a signer if not default PDA.
+    pub stake_deposit_authority: Pubkey,
+    ///Stake pool withdraw authority
+    pub withdraw_authority: Pubkey,
+    ///Stake account to deposit
+    pub stake_depositing: Pubkey,
+    ///Validator stake account to merge into
+    pub validator_stake_account: Pubkey,
+    ///Stake pool reserve stake
+    pub reserve_stake: Pubkey,
+    ///LST token account to mint the new LSTs to
+    pub mint_to: Pubkey,
+    ///Manager fee account
+    pub manager_fee_account: Pubkey,
+    ///LST token account ro receive referral fees
+    pub referral_fee_dest: Pubkey,
+    ///Pool token mint
+    pub pool_mint: Pubkey,
+    ///Clock sysvar
+    pub clock: Pubkey,
+    ///Stake history sysvar
+    pub stake_history: Pubkey,
+    ///Pool token program
+    pub token_program: Pubkey,
+    ///Stake program
+    pub stake_program: Pubkey,
+}
+impl From<DepositStakeAccounts<'_, '_>> for DepositStakeKeys {
+    fn from(accounts: DepositStakeAccounts) -> Self {
         Self {
             stake_pool: *accounts.stake_pool.key,
-            signer: *accounts.signer.key,
-            new_staker: *accounts.new_staker.key,
+            validator_list: *accounts.validator_list.key,
+            stake_deposit_authority: *accounts.stake_deposit_authority.key,
+            withdraw_authority: *accounts.withdraw_authority.key,
+            stake_depositing: *accounts.stake_depositing.key,
+            validator_stake_account: *accounts.validator_stake_account.key,
+            reserve_stake: *accounts.reserve_stake.key,
+            mint_to: *accounts.mint_to.key,
+            manager_fee_account: *accounts.manager_fee_account.key,
+            referral_fee_dest: *accounts.referral_fee_dest.key,
+            pool_mint: *accounts.pool_mint.key,
+            clock: *accounts.clock.key,
+            stake_history: *accounts.stake_history.key,
+            token_program: *accounts.token_program.key,
+            stake_program: *accounts.stake_program.key,
         }
     }
 }
-impl From<SetStakerKeys> for [AccountMeta; SET_STAKER_IX_ACCOUNTS_LEN] {
-    fn from(keys: SetStakerKeys) -> Self {
+impl From<DepositStakeKeys> for [AccountMeta; DEPOSIT_STAKE_IX_ACCOUNTS_LEN] {
+    fn from(keys: DepositStakeKeys) -> Self {
         [
             AccountMeta {
                 pubkey: keys.stake_pool,
@@ -2439,71 +2884,167 @@ impl From<SetStakerKeys> for [AccountMeta; SET_STAKER_IX_ACCOUNTS_LEN] {
                 is_writable: true,
             },
             AccountMeta {
-                pubkey: keys.signer,
-                is_signer: true,
+                pubkey: keys.validator_list,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.stake_deposit_authority,
+                is_signer: false,
                 is_writable: false,
             },
             AccountMeta {
-                pubkey: keys.new_staker,
+                pubkey: keys.withdraw_authority,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.stake_depositing,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.validator_stake_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.reserve_stake,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.mint_to,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.manager_fee_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.referral_fee_dest,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.clock,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.stake_history,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.stake_program,
                 is_signer: false,
                 is_writable: false,
             },
         ]
     }
 }
-impl From<[Pubkey; SET_STAKER_IX_ACCOUNTS_LEN]> for SetStakerKeys {
-    fn from(pubkeys: [Pubkey; SET_STAKER_IX_ACCOUNTS_LEN]) -> Self {
+impl From<[Pubkey; DEPOSIT_STAKE_IX_ACCOUNTS_LEN]> for DepositStakeKeys {
+    fn from(pubkeys: [Pubkey; DEPOSIT_STAKE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             stake_pool: pubkeys[0],
-            signer: pubkeys[1],
-            new_staker: pubkeys[2],
+            validator_list: pubkeys[1],
+            stake_deposit_authority: pubkeys[2],
+            withdraw_authority: pubkeys[3],
+            stake_depositing: pubkeys[4],
+            validator_stake_account: pubkeys[5],
+            reserve_stake: pubkeys[6],
+            mint_to: pubkeys[7],
+            manager_fee_account: pubkeys[8],
+            referral_fee_dest: pubkeys[9],
+            pool_mint: pubkeys[10],
+            clock: pubkeys[11],
+            stake_history: pubkeys[12],
+            token_program: pubkeys[13],
+            stake_program: pubkeys[14],
         }
     }
 }
-impl<'info> From<SetStakerAccounts<'_, 'info>>
-    for [AccountInfo<'info>; SET_STAKER_IX_ACCOUNTS_LEN]
+impl<'info> From<DepositStakeAccounts<'_, 'info>>
+    for [AccountInfo<'info>; DEPOSIT_STAKE_IX_ACCOUNTS_LEN]
 {
-    fn from(accounts: SetStakerAccounts<'_, 'info>) -> Self {
+    fn from(accounts: DepositStakeAccounts<'_, 'info>) -> Self {
         [
             accounts.stake_pool.clone(),
-            accounts.signer.clone(),
-            accounts.new_staker.clone(),
+            accounts.validator_list.clone(),
+            accounts.stake_deposit_authority.clone(),
+            accounts.withdraw_authority.clone(),
+            accounts.stake_depositing.clone(),
+            accounts.validator_stake_account.clone(),
+            accounts.reserve_stake.clone(),
+            accounts.mint_to.clone(),
+            accounts.manager_fee_account.clone(),
+            accounts.referral_fee_dest.clone(),
+            accounts.pool_mint.clone(),
+            accounts.clock.clone(),
+            accounts.stake_history.clone(),
+            accounts.token_program.clone(),
+            accounts.stake_program.clone(),
         ]
     }
 }
-impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_STAKER_IX_ACCOUNTS_LEN]>
-    for SetStakerAccounts<'me, 'info>
+impl<'me, 'info> From<&'me [AccountInfo<'info>; DEPOSIT_STAKE_IX_ACCOUNTS_LEN]>
+    for DepositStakeAccounts<'me, 'info>
 {
-    fn from(arr: &'me [AccountInfo<'info>; SET_STAKER_IX_ACCOUNTS_LEN]) -> Self {
+    fn from(arr: &'me [AccountInfo<'info>; DEPOSIT_STAKE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             stake_pool: &arr[0],
-            signer: &arr[1],
-            new_staker: &arr[2],
+            validator_list: &arr[1],
+            stake_deposit_authority: &arr[2],
+            withdraw_authority: &arr[3],
+            stake_depositing: &arr[4],
+            validator_stake_account: &arr[5],
+            reserve_stake: &arr[6],
+            mint_to: &arr[7],
+            manager_fee_account: &arr[8],
+            referral_fee_dest: &arr[9],
+            pool_mint: &arr[10],
+            clock: &arr[11],
+            stake_history: &arr[12],
+            token_program: &arr[13],
+            stake_program: &arr[14],
         }
     }
 }
-pub const SET_STAKER_IX_DISCM: u8 = 13u8;
+pub const DEPOSIT_STAKE_IX_DISCM: u8 = 9u8;
 #[derive(Clone, Debug, PartialEq)]
-pub struct SetStakerIxData;
-impl SetStakerIxData {
+pub struct DepositStakeIxData;
+impl DepositStakeIxData {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
-        if maybe_discm != SET_STAKER_IX_DISCM {
+        if maybe_discm != DEPOSIT_STAKE_IX_DISCM {
             return Err(std::io::Error::new(
                 std::io::ErrorKind::Other,
                 format!(
                     "discm does not match. Expected: {:?}. Received: {:?}",
-                    SET_STAKER_IX_DISCM, maybe_discm
+                    DEPOSIT_STAKE_IX_DISCM, maybe_discm
                 ),
             ));
         }
         Ok(Self)
     }
     pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
-        writer.write_all(&[SET_STAKER_IX_DISCM])
+        writer.write_all(&[DEPOSIT_STAKE_IX_DISCM])
     }
     pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
         let mut data = Vec::new();
@@ -2511,54 +3052,72 @@ impl SetStakerIxData {
         Ok(data)
     }
 }
-pub fn set_staker_ix_with_program_id(
+pub fn deposit_stake_ix_with_program_id(
     program_id: Pubkey,
-    keys: SetStakerKeys,
+    keys: DepositStakeKeys,
 ) -> std::io::Result<Instruction> {
-    let metas: [AccountMeta; SET_STAKER_IX_ACCOUNTS_LEN] = keys.into();
+    let metas: [AccountMeta; DEPOSIT_STAKE_IX_ACCOUNTS_LEN] = keys.into();
     Ok(Instruction {
         program_id,
         accounts: Vec::from(metas),
-        data: SetStakerIxData.try_to_vec()?,
+        data: DepositStakeIxData.try_to_vec()?,
     })
 }
-pub fn set_staker_ix(keys: SetStakerKeys) -> std::io::Result<Instruction> {
-    set_staker_ix_with_program_id(crate::ID, keys)
+pub fn deposit_stake_ix(keys: DepositStakeKeys) -> std::io::Result<Instruction> {
+    deposit_stake_ix_with_program_id(crate::ID, keys)
 }
-pub fn set_staker_invoke_with_program_id(
+pub fn deposit_stake_invoke_with_program_id(
     program_id: Pubkey,
-    accounts: SetStakerAccounts<'_, '_>,
+    accounts: DepositStakeAccounts<'_, '_>,
 ) -> ProgramResult {
-    let keys: SetStakerKeys = accounts.into();
-    let ix = set_staker_ix_with_program_id(program_id, keys)?;
+    let keys: DepositStakeKeys = accounts.into();
+    let ix = deposit_stake_ix_with_program_id(program_id, keys)?;
     invoke_instruction(&ix, accounts)
 }
-pub fn set_staker_invoke(accounts: SetStakerAccounts<'_, '_>) -> ProgramResult {
-    set_staker_invoke_with_program_id(crate::ID, accounts)
+pub fn deposit_stake_invoke(accounts: DepositStakeAccounts<'_, '_>) -> ProgramResult {
+    deposit_stake_invoke_with_program_id(crate::ID, accounts)
 }
-pub fn set_staker_invoke_signed_with_program_id(
+pub fn deposit_stake_invoke_signed_with_program_id(
     program_id: Pubkey,
-    accounts: SetStakerAccounts<'_, '_>,
+    accounts: DepositStakeAccounts<'_, '_>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let keys: SetStakerKeys = accounts.into();
-    let ix = set_staker_ix_with_program_id(program_id, keys)?;
+    let keys: DepositStakeKeys = accounts.into();
+    let ix = deposit_stake_ix_with_program_id(program_id, keys)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
-pub fn set_staker_invoke_signed(
-    accounts: SetStakerAccounts<'_, '_>,
+pub fn deposit_stake_invoke_signed(
+    accounts: DepositStakeAccounts<'_, '_>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    set_staker_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+    deposit_stake_invoke_signed_with_program_id(crate::ID, accounts, seeds)
 }
-pub fn set_staker_verify_account_keys(
-    accounts: SetStakerAccounts<'_, '_>,
-    keys: SetStakerKeys,
+pub fn deposit_stake_verify_account_keys(
+    accounts: DepositStakeAccounts<'_, '_>,
+    keys: DepositStakeKeys,
 ) -> Result<(), (Pubkey, Pubkey)> {
     for (actual, expected) in [
         (accounts.stake_pool.key, &keys.stake_pool),
-        (accounts.signer.key, &keys.signer),
-        (accounts.new_staker.key, &keys.new_staker),
+        (accounts.validator_list.key, &keys.validator_list),
+        (
+            accounts.stake_deposit_authority.key,
+            &keys.stake_deposit_authority,
+        ),
+        (accounts.withdraw_authority.key, &keys.withdraw_authority),
+        (accounts.stake_depositing.key, &keys.stake_depositing),
+        (
+            accounts.validator_stake_account.key,
+            &keys.validator_stake_account,
+        ),
+        (accounts.reserve_stake.key, &keys.reserve_stake),
+        (accounts.mint_to.key, &keys.mint_to),
+        (accounts.manager_fee_account.key, &keys.manager_fee_account),
+        (accounts.referral_fee_dest.key, &keys.referral_fee_dest),
+        (accounts.pool_mint.key, &keys.pool_mint),
+        (accounts.clock.key, &keys.clock),
+        (accounts.stake_history.key, &keys.stake_history),
+        (accounts.token_program.key, &keys.token_program),
+        (accounts.stake_program.key, &keys.stake_program),
     ] {
         if actual != expected {
             return Err((*actual, *expected));
@@ -2566,63 +3125,112 @@ pub fn set_staker_verify_account_keys(
     }
     Ok(())
 }
-pub fn set_staker_verify_writable_privileges<'me, 'info>(
-    accounts: SetStakerAccounts<'me, 'info>,
+pub fn deposit_stake_verify_writable_privileges<'me, 'info>(
+    accounts: DepositStakeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
-    for should_be_writable in [accounts.stake_pool] {
+    for should_be_writable in [
+        accounts.stake_pool,
+        accounts.validator_list,
+        accounts.stake_depositing,
+        accounts.validator_stake_account,
+        accounts.reserve_stake,
+        accounts.mint_to,
+        accounts.manager_fee_account,
+        accounts.referral_fee_dest,
+        accounts.pool_mint,
+    ] {
         if !should_be_writable.is_writable {
             return Err((should_be_writable, ProgramError::InvalidAccountData));
         }
     }
     Ok(())
 }
-pub fn set_staker_verify_signer_privileges<'me, 'info>(
-    accounts: SetStakerAccounts<'me, 'info>,
-) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
-    for should_be_signer in [accounts.signer] {
-        if !should_be_signer.is_signer {
-            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
-        }
-    }
-    Ok(())
-}
-pub fn set_staker_verify_account_privileges<'me, 'info>(
-    accounts: SetStakerAccounts<'me, 'info>,
+pub fn deposit_stake_verify_account_privileges<'me, 'info>(
+    accounts: DepositStakeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
-    set_staker_verify_writable_privileges(accounts)?;
-    set_staker_verify_signer_privileges(accounts)?;
+    deposit_stake_verify_writable_privileges(accounts)?;
     Ok(())
 }
-pub const SET_FUNDING_AUTHORITY_IX_ACCOUNTS_LEN: usize = 3;
+pub const WITHDRAW_STAKE_IX_ACCOUNTS_LEN: usize = 13;
 #[derive(Copy, Clone, Debug)]
-pub struct SetFundingAuthorityAccounts<'me, 'info> {
+pub struct WithdrawStakeAccounts<'me, 'info> {
     ///Stake pool
     pub stake_pool: &'me AccountInfo<'info>,
-    ///Current manager
-    pub manager: &'me AccountInfo<'info>,
-    ///New funding authority. If omitted, sets it to None
-    pub new_funding_authority: &'me AccountInfo<'info>,
-}
-#[derive(Copy, Clone, Debug)]
-pub struct SetFundingAuthorityKeys {
+    ///Validator list
+    pub validator_list: &'me AccountInfo<'info>,
+    ///Stake pool withdraw authority
+    pub withdraw_authority: &'me AccountInfo<'info>,
+    ///Validator or reserve stake account to split from
+    pub split_from: &'me AccountInfo<'info>,
+    ///Uninitialized stake account to split the withdrawn stake to. Must be rent-exempt.
+    pub split_to: &'me AccountInfo<'info>,
+    ///User account that is given authority over the withdrawn stake
+    pub beneficiary: &'me AccountInfo<'info>,
+    ///LST transfer authority
+    pub transfer_authority: &'me AccountInfo<'info>,
+    ///LST token account to burn the LST from
+    pub burn_from: &'me AccountInfo<'info>,
+    ///Manager fee account
+    pub manager_fee_account: &'me AccountInfo<'info>,
+    ///Pool token mint
+    pub pool_mint: &'me AccountInfo<'info>,
+    ///Clock sysvar
+    pub clock: &'me AccountInfo<'info>,
+    ///Pool token program
+    pub token_program: &'me AccountInfo<'info>,
+    ///Stake program
+    pub stake_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct WithdrawStakeKeys {
     ///Stake pool
     pub stake_pool: Pubkey,
-    ///Current manager
-    pub manager: Pubkey,
-    ///New funding authority. If omitted, sets it to None
-    pub new_funding_authority: Pubkey,
+    ///Validator list
+    pub validator_list: Pubkey,
+    ///Stake pool withdraw authority
+    pub withdraw_authority: Pubkey,
+    ///Validator or reserve stake account to split from
+    pub split_from: Pubkey,
+    ///Uninitialized stake account to split the withdrawn stake to. Must be rent-exempt.
+    pub split_to: Pubkey,
+    ///User account that is given authority over the withdrawn stake
+    pub beneficiary: Pubkey,
+    ///LST transfer authority
+    pub transfer_authority: Pubkey,
+    ///LST token account to burn the LST from
+    pub burn_from: Pubkey,
+    ///Manager fee account
+    pub manager_fee_account: Pubkey,
+    ///Pool token mint
+    pub pool_mint: Pubkey,
+    ///Clock sysvar
+    pub clock: Pubkey,
+    ///Pool token program
+    pub token_program: Pubkey,
+    ///Stake program
+    pub stake_program: Pubkey,
 }
-impl From<SetFundingAuthorityAccounts<'_, '_>> for SetFundingAuthorityKeys {
-    fn from(accounts: SetFundingAuthorityAccounts) -> Self {
+impl From<WithdrawStakeAccounts<'_, '_>> for WithdrawStakeKeys {
+    fn from(accounts: WithdrawStakeAccounts) -> Self {
         Self {
             stake_pool: *accounts.stake_pool.key,
-            manager: *accounts.manager.key,
-            new_funding_authority: *accounts.new_funding_authority.key,
+            validator_list: *accounts.validator_list.key,
+            withdraw_authority: *accounts.withdraw_authority.key,
+            split_from: *accounts.split_from.key,
+            split_to: *accounts.split_to.key,
+            beneficiary: *accounts.beneficiary.key,
+            transfer_authority: *accounts.transfer_authority.key,
+            burn_from: *accounts.burn_from.key,
+            manager_fee_account: *accounts.manager_fee_account.key,
+            pool_mint: *accounts.pool_mint.key,
+            clock: *accounts.clock.key,
+            token_program: *accounts.token_program.key,
+            stake_program: *accounts.stake_program.key,
         }
     }
 }
-impl From<SetFundingAuthorityKeys> for [AccountMeta; SET_FUNDING_AUTHORITY_IX_ACCOUNTS_LEN] {
-    fn from(keys: SetFundingAuthorityKeys) -> Self {
+impl From<WithdrawStakeKeys> for [AccountMeta; WITHDRAW_STAKE_IX_ACCOUNTS_LEN] {
+    fn from(keys: WithdrawStakeKeys) -> Self {
         [
             AccountMeta {
                 pubkey: keys.stake_pool,
@@ -2630,81 +3238,2913 @@ impl From<SetFundingAuthorityKeys> for [AccountMeta; SET_FUNDING_AUTHORITY_IX_AC
                 is_writable: true,
             },
             AccountMeta {
-                pubkey: keys.manager,
+                pubkey: keys.validator_list,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.withdraw_authority,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.split_from,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.split_to,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.beneficiary,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.transfer_authority,
                 is_signer: true,
                 is_writable: false,
             },
             AccountMeta {
-                pubkey: keys.new_funding_authority,
+                pubkey: keys.burn_from,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.manager_fee_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.clock,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.stake_program,
                 is_signer: false,
                 is_writable: false,
             },
         ]
     }
 }
-impl From<[Pubkey; SET_FUNDING_AUTHORITY_IX_ACCOUNTS_LEN]> for SetFundingAuthorityKeys {
-    fn from(pubkeys: [Pubkey; SET_FUNDING_AUTHORITY_IX_ACCOUNTS_LEN]) -> Self {
+impl From<[Pubkey; WITHDRAW_STAKE_IX_ACCOUNTS_LEN]> for WithdrawStakeKeys {
+    fn from(pubkeys: [Pubkey; WITHDRAW_STAKE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             stake_pool: pubkeys[0],
-            manager: pubkeys[1],
-            new_funding_authority: pubkeys[2],
+            validator_list: pubkeys[1],
+            withdraw_authority: pubkeys[2],
+            split_from: pubkeys[3],
+            split_to: pubkeys[4],
+            beneficiary: pubkeys[5],
+            transfer_authority: pubkeys[6],
+            burn_from: pubkeys[7],
+            manager_fee_account: pubkeys[8],
+            pool_mint: pubkeys[9],
+            clock: pubkeys[10],
+            token_program: pubkeys[11],
+            stake_program: pubkeys[12],
+        }
+    }
+}
+impl<'info> From<WithdrawStakeAccounts<'_, 'info>>
+    for [AccountInfo<'info>; WITHDRAW_STAKE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: WithdrawStakeAccounts<'_, 'info>) -> Self {
+        [
+            accounts.stake_pool.clone(),
+            accounts.validator_list.clone(),
+            accounts.withdraw_authority.clone(),
+            accounts.split_from.clone(),
+            accounts.split_to.clone(),
+            accounts.beneficiary.clone(),
+            accounts.transfer_authority.clone(),
+            accounts.burn_from.clone(),
+            accounts.manager_fee_account.clone(),
+            accounts.pool_mint.clone(),
+            accounts.clock.clone(),
+            accounts.token_program.clone(),
+            accounts.stake_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; WITHDRAW_STAKE_IX_ACCOUNTS_LEN]>
+    for WithdrawStakeAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; WITHDRAW_STAKE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            stake_pool: &arr[0],
+            validator_list: &arr[1],
+            withdraw_authority: &arr[2],
+            split_from: &arr[3],
+            split_to: &arr[4],
+            beneficiary: &arr[5],
+            transfer_authority: &arr[6],
+            burn_from: &arr[7],
+            manager_fee_account: &arr[8],
+            pool_mint: &arr[9],
+            clock: &arr[10],
+            token_program: &arr[11],
+            stake_program: &arr[12],
+        }
+    }
+}
+pub const WITHDRAW_STAKE_IX_DISCM: u8 = 10u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WithdrawStakeIxArgs {
+    pub pool_tokens_in: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct WithdrawStakeIxData(pub WithdrawStakeIxArgs);
+impl From<WithdrawStakeIxArgs> for WithdrawStakeIxData {
+    fn from(args: WithdrawStakeIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl WithdrawStakeIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != WITHDRAW_STAKE_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    WITHDRAW_STAKE_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(WithdrawStakeIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[WITHDRAW_STAKE_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn withdraw_stake_ix_with_program_id(
+    program_id: Pubkey,
+    keys: WithdrawStakeKeys,
+    args: WithdrawStakeIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; WITHDRAW_STAKE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: WithdrawStakeIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn withdraw_stake_ix(
+    keys: WithdrawStakeKeys,
+    args: WithdrawStakeIxArgs,
+) -> std::io::Result<Instruction> {
+    withdraw_stake_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn withdraw_stake_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: WithdrawStakeAccounts<'_, '_>,
+    args: WithdrawStakeIxArgs,
+) -> ProgramResult {
+    let keys: WithdrawStakeKeys = accounts.into();
+    let ix = withdraw_stake_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn withdraw_stake_invoke(
+    accounts: WithdrawStakeAccounts<'_, '_>,
+    args: WithdrawStakeIxArgs,
+) -> ProgramResult {
+    withdraw_stake_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn withdraw_stake_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: WithdrawStakeAccounts<'_, '_>,
+    args: WithdrawStakeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: WithdrawStakeKeys = accounts.into();
+    let ix = withdraw_stake_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn withdraw_stake_invoke_signed(
+    accounts: WithdrawStakeAccounts<'_, '_>,
+    args: WithdrawStakeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    withdraw_stake_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn withdraw_stake_verify_account_keys(
+    accounts: WithdrawStakeAccounts<'_, '_>,
+    keys: WithdrawStakeKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.stake_pool.key, &keys.stake_pool),
+        (accounts.validator_list.key, &keys.validator_list),
+        (accounts.withdraw_authority.key, &keys.withdraw_authority),
+        (accounts.split_from.key, &keys.split_from),
+        (accounts.split_to.key, &keys.split_to),
+        (accounts.beneficiary.key, &keys.beneficiary),
+        (accounts.transfer_authority.key, &keys.transfer_authority),
+        (accounts.burn_from.key, &keys.burn_from),
+        (accounts.manager_fee_account.key, &keys.manager_fee_account),
+        (accounts.pool_mint.key, &keys.pool_mint),
+        (accounts.clock.key, &keys.clock),
+        (accounts.token_program.key, &keys.token_program),
+        (accounts.stake_program.key, &keys.stake_program),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn withdraw_stake_verify_writable_privileges<'me, 'info>(
+    accounts: WithdrawStakeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.stake_pool,
+        accounts.validator_list,
+        accounts.split_from,
+        accounts.split_to,
+        accounts.burn_from,
+        accounts.manager_fee_account,
+        accounts.pool_mint,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn withdraw_stake_verify_signer_privileges<'me, 'info>(
+    accounts: WithdrawStakeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.transfer_authority] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn withdraw_stake_verify_account_privileges<'me, 'info>(
+    accounts: WithdrawStakeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    withdraw_stake_verify_writable_privileges(accounts)?;
+    withdraw_stake_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_MANAGER_IX_ACCOUNTS_LEN: usize = 4;
+#[derive(Copy, Clone, Debug)]
+pub struct SetManagerAccounts<'me, 'info> {
+    ///Stake pool
+    pub stake_pool: &'me AccountInfo<'info>,
+    ///Current manager
+    pub manager: &'me AccountInfo<'info>,
+    ///New manager
+    pub new_manager: &'me AccountInfo<'info>,
+    ///New manager fee account
+    pub new_manager_fee_account: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetManagerKeys {
+    ///Stake pool
+    pub stake_pool: Pubkey,
+    ///Current manager
+    pub manager: Pubkey,
+    ///New manager
+    pub new_manager: Pubkey,
+    ///New manager fee account
+    pub new_manager_fee_account: Pubkey,
+}
+impl From<SetManagerAccounts<'_, '_>> for SetManagerKeys {
+    fn from(accounts: SetManagerAccounts) -> Self {
+        Self {
+            stake_pool: *accounts.stake_pool.key,
+            manager: *accounts.manager.key,
+            new_manager: *accounts.new_manager.key,
+            new_manager_fee_account: *accounts.new_manager_fee_account.key,
+        }
+    }
+}
+impl From<SetManagerKeys> for [AccountMeta; SET_MANAGER_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetManagerKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.stake_pool,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.manager,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.new_manager,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.new_manager_fee_account,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_MANAGER_IX_ACCOUNTS_LEN]> for SetManagerKeys {
+    fn from(pubkeys: [Pubkey; SET_MANAGER_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            stake_pool: pubkeys[0],
+            manager: pubkeys[1],
+            new_manager: pubkeys[2],
+            new_manager_fee_account: pubkeys[3],
+        }
+    }
+}
+impl<'info> From<SetManagerAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_MANAGER_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetManagerAccounts<'_, 'info>) -> Self {
+        [
+            accounts.stake_pool.clone(),
+            accounts.manager.clone(),
+            accounts.new_manager.clone(),
+            accounts.new_manager_fee_account.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_MANAGER_IX_ACCOUNTS_LEN]>
+    for SetManagerAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SET_MANAGER_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            stake_pool: &arr[0],
+            manager: &arr[1],
+            new_manager: &arr[2],
+            new_manager_fee_account: &arr[3],
+        }
+    }
+}
+pub const SET_MANAGER_IX_DISCM: u8 = 11u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetManagerIxData;
+impl SetManagerIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_MANAGER_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_MANAGER_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SET_MANAGER_IX_DISCM])
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_manager_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetManagerKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_MANAGER_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: SetManagerIxData.try_to_vec()?,
+    })
+}
+pub fn set_manager_ix(keys: SetManagerKeys) -> std::io::Result<Instruction> {
+    set_manager_ix_with_program_id(crate::ID, keys)
+}
+pub fn set_manager_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetManagerAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: SetManagerKeys = accounts.into();
+    let ix = set_manager_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_manager_invoke(accounts: SetManagerAccounts<'_, '_>) -> ProgramResult {
+    set_manager_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn set_manager_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetManagerAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetManagerKeys = accounts.into();
+    let ix = set_manager_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_manager_invoke_signed(
+    accounts: SetManagerAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_manager_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn set_manager_verify_account_keys(
+    accounts: SetManagerAccounts<'_, '_>,
+    keys: SetManagerKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.stake_pool.key, &keys.stake_pool),
+        (accounts.manager.key, &keys.manager),
+        (accounts.new_manager.key, &keys.new_manager),
+        (
+            accounts.new_manager_fee_account.key,
+            &keys.new_manager_fee_account,
+        ),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_manager_verify_writable_privileges<'me, 'info>(
+    accounts: SetManagerAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.stake_pool] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_manager_verify_signer_privileges<'me, 'info>(
+    accounts: SetManagerAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.manager, accounts.new_manager] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_manager_verify_account_privileges<'me, 'info>(
+    accounts: SetManagerAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_manager_verify_writable_privileges(accounts)?;
+    set_manager_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_FEE_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone, Debug)]
+pub struct SetFeeAccounts<'me, 'info> {
+    ///Stake pool
+    pub stake_pool: &'me AccountInfo<'info>,
+    ///Current manager
+    pub manager: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetFeeKeys {
+    ///Stake pool
+    pub stake_pool: Pubkey,
+    ///Current manager
+    pub manager: Pubkey,
+}
+impl From<SetFeeAccounts<'_, '_>> for SetFeeKeys {
+    fn from(accounts: SetFeeAccounts) -> Self {
+        Self {
+            stake_pool: *accounts.stake_pool.key,
+            manager: *accounts.manager.key,
+        }
+    }
+}
+impl From<SetFeeKeys> for [AccountMeta; SET_FEE_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetFeeKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.stake_pool,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.manager,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_FEE_IX_ACCOUNTS_LEN]> for SetFeeKeys {
+    fn from(pubkeys: [Pubkey; SET_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            stake_pool: pubkeys[0],
+            manager: pubkeys[1],
+        }
+    }
+}
+impl<'info> From<SetFeeAccounts<'_, 'info>> for [AccountInfo<'info>; SET_FEE_IX_ACCOUNTS_LEN] {
+    fn from(accounts: SetFeeAccounts<'_, 'info>) -> Self {
+        [accounts.stake_pool.clone(), accounts.manager.clone()]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_FEE_IX_ACCOUNTS_LEN]>
+    for SetFeeAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SET_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            stake_pool: &arr[0],
+            manager: &arr[1],
+        }
+    }
+}
+pub const SET_FEE_IX_DISCM: u8 = 12u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetFeeIxArgs {
+    pub fee: FeeType,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetFeeIxData(pub SetFeeIxArgs);
+impl From<SetFeeIxArgs> for SetFeeIxData {
+    fn from(args: SetFeeIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetFeeIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_FEE_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_FEE_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SetFeeIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SET_FEE_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_fee_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetFeeKeys,
+    args: SetFeeIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_FEE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetFeeIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_fee_ix(keys: SetFeeKeys, args: SetFeeIxArgs) -> std::io::Result<Instruction> {
+    set_fee_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_fee_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetFeeAccounts<'_, '_>,
+    args: SetFeeIxArgs,
+) -> ProgramResult {
+    let keys: SetFeeKeys = accounts.into();
+    let ix = set_fee_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_fee_invoke(accounts: SetFeeAccounts<'_, '_>, args: SetFeeIxArgs) -> ProgramResult {
+    set_fee_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_fee_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetFeeAccounts<'_, '_>,
+    args: SetFeeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetFeeKeys = accounts.into();
+    let ix = set_fee_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_fee_invoke_signed(
+    accounts: SetFeeAccounts<'_, '_>,
+    args: SetFeeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_fee_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_fee_verify_account_keys(
+    accounts: SetFeeAccounts<'_, '_>,
+    keys: SetFeeKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.stake_pool.key, &keys.stake_pool),
+        (accounts.manager.key, &keys.manager),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_fee_verify_writable_privileges<'me, 'info>(
+    accounts: SetFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.stake_pool] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_fee_verify_signer_privileges<'me, 'info>(
+    accounts: SetFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.manager] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_fee_verify_account_privileges<'me, 'info>(
+    accounts: SetFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_fee_verify_writable_privileges(accounts)?;
+    set_fee_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_STAKER_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone, Debug)]
+pub struct SetStakerAccounts<'me, 'info> {
+    ///Stake pool
+    pub stake_pool: &'me AccountInfo<'info>,
+    ///Current manager or staker
+    pub signer: &'me AccountInfo<'info>,
+    ///New staker pubkey
+    pub new_staker: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetStakerKeys {
+    ///Stake pool
+    pub stake_pool: Pubkey,
+    ///Current manager or staker
+    pub signer: Pubkey,
+    ///New staker pubkey
+    pub new_staker: Pubkey,
+}
+impl From<SetStakerAccounts<'_, '_>> for SetStakerKeys {
+    fn from(accounts: SetStakerAccounts) -> Self {
+        Self {
+            stake_pool: *accounts.stake_pool.key,
+            signer: *accounts.signer.key,
+            new_staker: *accounts.new_staker.key,
+        }
+    }
+}
+impl From<SetStakerKeys> for [AccountMeta; SET_STAKER_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetStakerKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.stake_pool,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.signer,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.new_staker,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_STAKER_IX_ACCOUNTS_LEN]> for SetStakerKeys {
+    fn from(pubkeys: [Pubkey; SET_STAKER_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            stake_pool: pubkeys[0],
+            signer: pubkeys[1],
+            new_staker: pubkeys[2],
+        }
+    }
+}
+impl<'info> From<SetStakerAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_STAKER_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetStakerAccounts<'_, 'info>) -> Self {
+        [
+            accounts.stake_pool.clone(),
+            accounts.signer.clone(),
+            accounts.new_staker.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_STAKER_IX_ACCOUNTS_LEN]>
+    for SetStakerAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SET_STAKER_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            stake_pool: &arr[0],
+            signer: &arr[1],
+            new_staker: &arr[2],
+        }
+    }
+}
+pub const SET_STAKER_IX_DISCM: u8 = 13u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetStakerIxData;
+impl SetStakerIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_STAKER_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_STAKER_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SET_STAKER_IX_DISCM])
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_staker_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetStakerKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_STAKER_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: SetStakerIxData.try_to_vec()?,
+    })
+}
+pub fn set_staker_ix(keys: SetStakerKeys) -> std::io::Result<Instruction> {
+    set_staker_ix_with_program_id(crate::ID, keys)
+}
+pub fn set_staker_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetStakerAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: SetStakerKeys = accounts.into();
+    let ix = set_staker_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_staker_invoke(accounts: SetStakerAccounts<'_, '_>) -> ProgramResult {
+    set_staker_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn set_staker_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetStakerAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetStakerKeys = accounts.into();
+    let ix = set_staker_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_staker_invoke_signed(
+    accounts: SetStakerAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_staker_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn set_staker_verify_account_keys(
+    accounts: SetStakerAccounts<'_, '_>,
+    keys: SetStakerKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.stake_pool.key, &keys.stake_pool),
+        (accounts.signer.key, &keys.signer),
+        (accounts.new_staker.key, &keys.new_staker),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_staker_verify_writable_privileges<'me, 'info>(
+    accounts: SetStakerAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.stake_pool] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_staker_verify_signer_privileges<'me, 'info>(
+    accounts: SetStakerAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.signer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_staker_verify_account_privileges<'me, 'info>(
+    accounts: SetStakerAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_staker_verify_writable_privileges(accounts)?;
+    set_staker_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const DEPOSIT_SOL_IX_ACCOUNTS_LEN: usize = 10;
+#[derive(Copy, Clone, Debug)]
+pub struct DepositSolAccounts<'me, 'info> {
+    ///Stake pool
+    pub stake_pool: &'me AccountInfo<'info>,
+    ///Stake pool withdraw authority
+    pub withdraw_authority: &'me AccountInfo<'info>,
+    ///Stake pool reserve stake
+    pub reserve_stake: &'me AccountInfo<'info>,
+    ///System account depositing the SOL
+    pub deposit_from: &'me AccountInfo<'info>,
+    ///LST token account to mint the new LSTs to
+    pub mint_to: &'me AccountInfo<'info>,
+    ///Manager fee account
+    pub manager_fee_account: &'me AccountInfo<'info>,
+    ///LST token account to receive referral fees
+    pub referral_fee_dest: &'me AccountInfo<'info>,
+    ///Pool token mint
+    pub pool_mint: &'me AccountInfo<'info>,
+    ///System program
+    pub system_program: &'me AccountInfo<'info>,
+    ///Pool token program. The signing SOL deposit authority follows if the pool has one.
+    pub token_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct DepositSolKeys {
+    ///Stake pool
+    pub stake_pool: Pubkey,
+    ///Stake pool withdraw authority
+    pub withdraw_authority: Pubkey,
+    ///Stake pool reserve stake
+    pub reserve_stake: Pubkey,
+    ///System account depositing the SOL
+    pub deposit_from: Pubkey,
+    ///LST token account to mint the new LSTs to
+    pub mint_to: Pubkey,
+    ///Manager fee account
+    pub manager_fee_account: Pubkey,
+    ///LST token account to receive referral fees
+    pub referral_fee_dest: Pubkey,
+    ///Pool token mint
+    pub pool_mint: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+    ///Pool token program. The signing SOL deposit authority follows if the pool has one.
+    pub token_program: Pubkey,
+}
+impl From<DepositSolAccounts<'_, '_>> for DepositSolKeys {
+    fn from(accounts: DepositSolAccounts) -> Self {
+        Self {
+            stake_pool: *accounts.stake_pool.key,
+            withdraw_authority: *accounts.withdraw_authority.key,
+            reserve_stake: *accounts.reserve_stake.key,
+            deposit_from: *accounts.deposit_from.key,
+            mint_to: *accounts.mint_to.key,
+            manager_fee_account: *accounts.manager_fee_account.key,
+            referral_fee_dest: *accounts.referral_fee_dest.key,
+            pool_mint: *accounts.pool_mint.key,
+            system_program: *accounts.system_program.key,
+            token_program: *accounts.token_program.key,
+        }
+    }
+}
+impl From<DepositSolKeys> for [AccountMeta; DEPOSIT_SOL_IX_ACCOUNTS_LEN] {
+    fn from(keys: DepositSolKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.stake_pool,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.withdraw_authority,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.reserve_stake,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.deposit_from,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.mint_to,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.manager_fee_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.referral_fee_dest,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; DEPOSIT_SOL_IX_ACCOUNTS_LEN]> for DepositSolKeys {
+    fn from(pubkeys: [Pubkey; DEPOSIT_SOL_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            stake_pool: pubkeys[0],
+            withdraw_authority: pubkeys[1],
+            reserve_stake: pubkeys[2],
+            deposit_from: pubkeys[3],
+            mint_to: pubkeys[4],
+            manager_fee_account: pubkeys[5],
+            referral_fee_dest: pubkeys[6],
+            pool_mint: pubkeys[7],
+            system_program: pubkeys[8],
+            token_program: pubkeys[9],
+        }
+    }
+}
+impl<'info> From<DepositSolAccounts<'_, 'info>>
+    for [AccountInfo<'info>; DEPOSIT_SOL_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: DepositSolAccounts<'_, 'info>) -> Self {
+        [
+            accounts.stake_pool.clone(),
+            accounts.withdraw_authority.clone(),
+            accounts.reserve_stake.clone(),
+            accounts.deposit_from.clone(),
+            accounts.mint_to.clone(),
+            accounts.manager_fee_account.clone(),
+            accounts.referral_fee_dest.clone(),
+            accounts.pool_mint.clone(),
+            accounts.system_program.clone(),
+            accounts.token_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; DEPOSIT_SOL_IX_ACCOUNTS_LEN]>
+    for DepositSolAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; DEPOSIT_SOL_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            stake_pool: &arr[0],
+            withdraw_authority: &arr[1],
+            reserve_stake: &arr[2],
+            deposit_from: &arr[3],
+            mint_to: &arr[4],
+            manager_fee_account: &arr[5],
+            referral_fee_dest: &arr[6],
+            pool_mint: &arr[7],
+            system_program: &arr[8],
+            token_program: &arr[9],
+        }
+    }
+}
+pub const DEPOSIT_SOL_IX_DISCM: u8 = 14u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DepositSolIxArgs {
+    pub lamports_in: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct DepositSolIxData(pub DepositSolIxArgs);
+impl From<DepositSolIxArgs> for DepositSolIxData {
+    fn from(args: DepositSolIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl DepositSolIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != DEPOSIT_SOL_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    DEPOSIT_SOL_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(DepositSolIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[DEPOSIT_SOL_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn deposit_sol_ix_with_program_id(
+    program_id: Pubkey,
+    keys: DepositSolKeys,
+    args: DepositSolIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; DEPOSIT_SOL_IX_ACCOUNTS_LEN] = keys.into();
+    let data: DepositSolIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn deposit_sol_ix(
+    keys: DepositSolKeys,
+    args: DepositSolIxArgs,
+) -> std::io::Result<Instruction> {
+    deposit_sol_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn deposit_sol_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: DepositSolAccounts<'_, '_>,
+    args: DepositSolIxArgs,
+) -> ProgramResult {
+    let keys: DepositSolKeys = accounts.into();
+    let ix = deposit_sol_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn deposit_sol_invoke(
+    accounts: DepositSolAccounts<'_, '_>,
+    args: DepositSolIxArgs,
+) -> ProgramResult {
+    deposit_sol_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn deposit_sol_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: DepositSolAccounts<'_, '_>,
+    args: DepositSolIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: DepositSolKeys = accounts.into();
+    let ix = deposit_sol_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn deposit_sol_invoke_signed(
+    accounts: DepositSolAccounts<'_, '_>,
+    args: DepositSolIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    deposit_sol_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn deposit_sol_verify_account_keys(
+    accounts: DepositSolAccounts<'_, '_>,
+    keys: DepositSolKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.stake_pool.key, &keys.stake_pool),
+        (accounts.withdraw_authority.key, &keys.withdraw_authority),
+        (accounts.reserve_stake.key, &keys.reserve_stake),
+        (accounts.deposit_from.key, &keys.deposit_from),
+        (accounts.mint_to.key, &keys.mint_to),
+        (accounts.manager_fee_account.key, &keys.manager_fee_account),
+        (accounts.referral_fee_dest.key, &keys.referral_fee_dest),
+        (accounts.pool_mint.key, &keys.pool_mint),
+        (accounts.system_program.key, &keys.system_program),
+        (accounts.token_program.key, &keys.token_program),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn deposit_sol_verify_writable_privileges<'me, 'info>(
+    accounts: DepositSolAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.stake_pool,
+        accounts.reserve_stake,
+        accounts.deposit_from,
+        accounts.mint_to,
+        accounts.manager_fee_account,
+        accounts.referral_fee_dest,
+        accounts.pool_mint,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn deposit_sol_verify_signer_privileges<'me, 'info>(
+    accounts: DepositSolAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.deposit_from] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn deposit_sol_verify_account_privileges<'me, 'info>(
+    accounts: DepositSolAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    deposit_sol_verify_writable_privileges(accounts)?;
+    deposit_sol_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_FUNDING_AUTHORITY_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone, Debug)]
+pub struct SetFundingAuthorityAccounts<'me, 'info> {
+    ///Stake pool
+    pub stake_pool: &'me AccountInfo<'info>,
+    ///Current manager
+    pub manager: &'me AccountInfo<'info>,
+    ///New funding authority. If omitted, sets it to None
+    pub new_funding_authority: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetFundingAuthorityKeys {
+    ///Stake pool
+    pub stake_pool: Pubkey,
+    ///Current manager
+    pub manager: Pubkey,
+    ///New funding authority. If omitted, sets it to None
+    pub new_funding_authority: Pubkey,
+}
+impl From<SetFundingAuthorityAccounts<'_, '_>> for SetFundingAuthorityKeys {
+    fn from(accounts: SetFundingAuthorityAccounts) -> Self {
+        Self {
+            stake_pool: *accounts.stake_pool.key,
+            manager: *accounts.manager.key,
+            new_funding_authority: *accounts.new_funding_authority.key,
+        }
+    }
+}
+impl From<SetFundingAuthorityKeys> for [AccountMeta; SET_FUNDING_AUTHORITY_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetFundingAuthorityKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.stake_pool,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.manager,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.new_funding_authority,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_FUNDING_AUTHORITY_IX_ACCOUNTS_LEN]> for SetFundingAuthorityKeys {
+    fn from(pubkeys: [Pubkey; SET_FUNDING_AUTHORITY_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            stake_pool: pubkeys[0],
+            manager: pubkeys[1],
+            new_funding_authority: pubkeys[2],
+        }
+    }
+}
+impl<'info> From<SetFundingAuthorityAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_FUNDING_AUTHORITY_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetFundingAuthorityAccounts<'_, 'info>) -> Self {
+        [
+            accounts.stake_pool.clone(),
+            accounts.manager.clone(),
+            accounts.new_funding_authority.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_FUNDING_AUTHORITY_IX_ACCOUNTS_LEN]>
+    for SetFundingAuthorityAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SET_FUNDING_AUTHORITY_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            stake_pool: &arr[0],
+            manager: &arr[1],
+            new_funding_authority: &arr[2],
+        }
+    }
+}
+pub const SET_FUNDING_AUTHORITY_IX_DISCM: u8 = 15u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetFundingAuthorityIxArgs {
+    pub auth: FundingType,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetFundingAuthorityIxData(pub SetFundingAuthorityIxArgs);
+impl From<SetFundingAuthorityIxArgs> for SetFundingAuthorityIxData {
+    fn from(args: SetFundingAuthorityIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetFundingAuthorityIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_FUNDING_AUTHORITY_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_FUNDING_AUTHORITY_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SetFundingAuthorityIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SET_FUNDING_AUTHORITY_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_funding_authority_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetFundingAuthorityKeys,
+    args: SetFundingAuthorityIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_FUNDING_AUTHORITY_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetFundingAuthorityIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_funding_authority_ix(
+    keys: SetFundingAuthorityKeys,
+    args: SetFundingAuthorityIxArgs,
+) -> std::io::Result<Instruction> {
+    set_funding_authority_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_funding_authority_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetFundingAuthorityAccounts<'_, '_>,
+    args: SetFundingAuthorityIxArgs,
+) -> ProgramResult {
+    let keys: SetFundingAuthorityKeys = accounts.into();
+    let ix = set_funding_authority_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_funding_authority_invoke(
+    accounts: SetFundingAuthorityAccounts<'_, '_>,
+    args: SetFundingAuthorityIxArgs,
+) -> ProgramResult {
+    set_funding_authority_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_funding_authority_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetFundingAuthorityAccounts<'_, '_>,
+    args: SetFundingAuthorityIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetFundingAuthorityKeys = accounts.into();
+    let ix = set_funding_authority_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_funding_authority_invoke_signed(
+    accounts: SetFundingAuthorityAccounts<'_, '_>,
+    args: SetFundingAuthorityIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_funding_authority_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_funding_authority_verify_account_keys(
+    accounts: SetFundingAuthorityAccounts<'_, '_>,
+    keys: SetFundingAuthorityKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.stake_pool.key, &keys.stake_pool),
+        (accounts.manager.key, &keys.manager),
+        (
+            accounts.new_funding_authority.key,
+            &keys.new_funding_authority,
+        ),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_funding_authority_verify_writable_privileges<'me, 'info>(
+    accounts: SetFundingAuthorityAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.stake_pool] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_funding_authority_verify_signer_privileges<'me, 'info>(
+    accounts: SetFundingAuthorityAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.manager] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_funding_authority_verify_account_privileges<'me, 'info>(
+    accounts: SetFundingAuthorityAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_funding_authority_verify_writable_privileges(accounts)?;
+    set_funding_authority_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const WITHDRAW_SOL_IX_ACCOUNTS_LEN: usize = 12;
+#[derive(Copy, Clone, Debug)]
+pub struct WithdrawSolAccounts<'me, 'info> {
+    ///Stake pool
+    pub stake_pool: &'me AccountInfo<'info>,
+    ///Stake pool withdraw authority
+    pub withdraw_authority: &'me AccountInfo<'info>,
+    ///LST transfer authority
+    pub transfer_authority: &'me AccountInfo<'info>,
+    ///LST token account to burn the LST from
+    pub burn_from: &'me AccountInfo<'info>,
+    ///Stake pool reserve stake
+    pub reserve_stake: &'me AccountInfo<'info>,
+    ///System account to receive the withdrawn SOL
+    pub withdraw_to: &'me AccountInfo<'info>,
+    ///Manager fee account
+    pub manager_fee_account: &'me AccountInfo<'info>,
+    ///Pool token mint
+    pub pool_mint: &'me AccountInfo<'info>,
+    ///Clock sysvar
+    pub clock: &'me AccountInfo<'info>,
+    ///Stake history sysvar
+    pub stake_history: &'me AccountInfo<'info>,
+    ///Stake program
+    pub stake_program: &'me AccountInfo<'info>,
+    ///Pool token program. The signing SOL withdraw authority follows if the pool has one.
+    pub token_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct WithdrawSolKeys {
+    ///Stake pool
+    pub stake_pool: Pubkey,
+    ///Stake pool withdraw authority
+    pub withdraw_authority: Pubkey,
+    ///LST transfer authority
+    pub transfer_authority: Pubkey,
+    ///LST token account to burn the LST from
+    pub burn_from: Pubkey,
+    ///Stake pool reserve stake
+    pub reserve_stake: Pubkey,
+    ///System account to receive the withdrawn SOL
+    pub withdraw_to: Pubkey,
+    ///Manager fee account
+    pub manager_fee_account: Pubkey,
+    ///Pool token mint
+    pub pool_mint: Pubkey,
+    ///Clock sysvar
+    pub clock: Pubkey,
+    ///Stake history sysvar
+    pub stake_history: Pubkey,
+    ///Stake program
+    pub stake_program: Pubkey,
+    ///Pool token program. The signing SOL withdraw authority follows if the pool has one.
+    pub token_program: Pubkey,
+}
+impl From<WithdrawSolAccounts<'_, '_>> for WithdrawSolKeys {
+    fn from(accounts: WithdrawSolAccounts) -> Self {
+        Self {
+            stake_pool: *accounts.stake_pool.key,
+            withdraw_authority: *accounts.withdraw_authority.key,
+            transfer_authority: *accounts.transfer_authority.key,
+            burn_from: *accounts.burn_from.key,
+            reserve_stake: *accounts.reserve_stake.key,
+            withdraw_to: *accounts.withdraw_to.key,
+            manager_fee_account: *accounts.manager_fee_account.key,
+            pool_mint: *accounts.pool_mint.key,
+            clock: *accounts.clock.key,
+            stake_history: *accounts.stake_history.key,
+            stake_program: *accounts.stake_program.key,
+            token_program: *accounts.token_program.key,
+        }
+    }
+}
+impl From<WithdrawSolKeys> for [AccountMeta; WITHDRAW_SOL_IX_ACCOUNTS_LEN] {
+    fn from(keys: WithdrawSolKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.stake_pool,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.withdraw_authority,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.transfer_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.burn_from,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.reserve_stake,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.withdraw_to,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.manager_fee_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.clock,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.stake_history,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.stake_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; WITHDRAW_SOL_IX_ACCOUNTS_LEN]> for WithdrawSolKeys {
+    fn from(pubkeys: [Pubkey; WITHDRAW_SOL_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            stake_pool: pubkeys[0],
+            withdraw_authority: pubkeys[1],
+            transfer_authority: pubkeys[2],
+            burn_from: pubkeys[3],
+            reserve_stake: pubkeys[4],
+            withdraw_to: pubkeys[5],
+            manager_fee_account: pubkeys[6],
+            pool_mint: pubkeys[7],
+            clock: pubkeys[8],
+            stake_history: pubkeys[9],
+            stake_program: pubkeys[10],
+            token_program: pubkeys[11],
+        }
+    }
+}
+impl<'info> From<WithdrawSolAccounts<'_, 'info>>
+    for [AccountInfo<'info>; WITHDRAW_SOL_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: WithdrawSolAccounts<'_, 'info>) -> Self {
+        [
+            accounts.stake_pool.clone(),
+            accounts.withdraw_authority.clone(),
+            accounts.transfer_authority.clone(),
+            accounts.burn_from.clone(),
+            accounts.reserve_stake.clone(),
+            accounts.withdraw_to.clone(),
+            accounts.manager_fee_account.clone(),
+            accounts.pool_mint.clone(),
+            accounts.clock.clone(),
+            accounts.stake_history.clone(),
+            accounts.stake_program.clone(),
+            accounts.token_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; WITHDRAW_SOL_IX_ACCOUNTS_LEN]>
+    for WithdrawSolAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; WITHDRAW_SOL_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            stake_pool: &arr[0],
+            withdraw_authority: &arr[1],
+            transfer_authority: &arr[2],
+            burn_from: &arr[3],
+            reserve_stake: &arr[4],
+            withdraw_to: &arr[5],
+            manager_fee_account: &arr[6],
+            pool_mint: &arr[7],
+            clock: &arr[8],
+            stake_history: &arr[9],
+            stake_program: &arr[10],
+            token_program: &arr[11],
+        }
+    }
+}
+pub const WITHDRAW_SOL_IX_DISCM: u8 = 16u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WithdrawSolIxArgs {
+    pub tokens_in: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct WithdrawSolIxData(pub WithdrawSolIxArgs);
+impl From<WithdrawSolIxArgs> for WithdrawSolIxData {
+    fn from(args: WithdrawSolIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl WithdrawSolIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != WITHDRAW_SOL_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    WITHDRAW_SOL_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(WithdrawSolIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[WITHDRAW_SOL_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn withdraw_sol_ix_with_program_id(
+    program_id: Pubkey,
+    keys: WithdrawSolKeys,
+    args: WithdrawSolIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; WITHDRAW_SOL_IX_ACCOUNTS_LEN] = keys.into();
+    let data: WithdrawSolIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn withdraw_sol_ix(
+    keys: WithdrawSolKeys,
+    args: WithdrawSolIxArgs,
+) -> std::io::Result<Instruction> {
+    withdraw_sol_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn withdraw_sol_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: WithdrawSolAccounts<'_, '_>,
+    args: WithdrawSolIxArgs,
+) -> ProgramResult {
+    let keys: WithdrawSolKeys = accounts.into();
+    let ix = withdraw_sol_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn withdraw_sol_invoke(
+    accounts: WithdrawSolAccounts<'_, '_>,
+    args: WithdrawSolIxArgs,
+) -> ProgramResult {
+    withdraw_sol_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn withdraw_sol_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: WithdrawSolAccounts<'_, '_>,
+    args: WithdrawSolIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: WithdrawSolKeys = accounts.into();
+    let ix = withdraw_sol_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn withdraw_sol_invoke_signed(
+    accounts: WithdrawSolAccounts<'_, '_>,
+    args: WithdrawSolIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    withdraw_sol_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn withdraw_sol_verify_account_keys(
+    accounts: WithdrawSolAccounts<'_, '_>,
+    keys: WithdrawSolKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.stake_pool.key, &keys.stake_pool),
+        (accounts.withdraw_authority.key, &keys.withdraw_authority),
+        (accounts.transfer_authority.key, &keys.transfer_authority),
+        (accounts.burn_from.key, &keys.burn_from),
+        (accounts.reserve_stake.key, &keys.reserve_stake),
+        (accounts.withdraw_to.key, &keys.withdraw_to),
+        (accounts.manager_fee_account.key, &keys.manager_fee_account),
+        (accounts.pool_mint.key, &keys.pool_mint),
+        (accounts.clock.key, &keys.clock),
+        (accounts.stake_history.key, &keys.stake_history),
+        (accounts.stake_program.key, &keys.stake_program),
+        (accounts.token_program.key, &keys.token_program),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn withdraw_sol_verify_writable_privileges<'me, 'info>(
+    accounts: WithdrawSolAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.stake_pool,
+        accounts.burn_from,
+        accounts.reserve_stake,
+        accounts.withdraw_to,
+        accounts.manager_fee_account,
+        accounts.pool_mint,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn withdraw_sol_verify_signer_privileges<'me, 'info>(
+    accounts: WithdrawSolAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.transfer_authority] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn withdraw_sol_verify_account_privileges<'me, 'info>(
+    accounts: WithdrawSolAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    withdraw_sol_verify_writable_privileges(accounts)?;
+    withdraw_sol_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const CREATE_TOKEN_METADATA_IX_ACCOUNTS_LEN: usize = 8;
+#[derive(Copy, Clone, Debug)]
+pub struct CreateTokenMetadataAccounts<'me, 'info> {
+    ///Stake pool
+    pub stake_pool: &'me AccountInfo<'info>,
+    ///Manager
+    pub manager: &'me AccountInfo<'info>,
+    ///Stake pool withdraw authority
+    pub withdraw_authority: &'me AccountInfo<'info>,
+    ///Pool token mint
+    pub pool_mint: &'me AccountInfo<'info>,
+    ///Payer for creation of token metadata account
+    pub payer: &'me AccountInfo<'info>,
+    ///Token metadata account
+    pub token_metadata: &'me AccountInfo<'info>,
+    ///Metaplex token metadata program
+    pub mpl_token_metadata: &'me AccountInfo<'info>,
+    ///System program
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct CreateTokenMetadataKeys {
+    ///Stake pool
+    pub stake_pool: Pubkey,
+    ///Manager
+    pub manager: Pubkey,
+    ///Stake pool withdraw authority
+    pub withdraw_authority: Pubkey,
+    ///Pool token mint
+    pub pool_mint: Pubkey,
+    ///Payer for creation of token metadata account
+    pub payer: Pubkey,
+    ///Token metadata account
+    pub token_metadata: Pubkey,
+    ///Metaplex token metadata program
+    pub mpl_token_metadata: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+}
+impl From<CreateTokenMetadataAccounts<'_, '_>> for CreateTokenMetadataKeys {
+    fn from(accounts: CreateTokenMetadataAccounts) -> Self {
+        Self {
+            stake_pool: *accounts.stake_pool.key,
+            manager: *accounts.manager.key,
+            withdraw_authority: *accounts.withdraw_authority.key,
+            pool_mint: *accounts.pool_mint.key,
+            payer: *accounts.payer.key,
+            token_metadata: *accounts.token_metadata.key,
+            mpl_token_metadata: *accounts.mpl_token_metadata.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<CreateTokenMetadataKeys> for [AccountMeta; CREATE_TOKEN_METADATA_IX_ACCOUNTS_LEN] {
+    fn from(keys: CreateTokenMetadataKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.stake_pool,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.manager,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.withdraw_authority,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.token_metadata,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.mpl_token_metadata,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; CREATE_TOKEN_METADATA_IX_ACCOUNTS_LEN]> for CreateTokenMetadataKeys {
+    fn from(pubkeys: [Pubkey; CREATE_TOKEN_METADATA_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            stake_pool: pubkeys[0],
+            manager: pubkeys[1],
+            withdraw_authority: pubkeys[2],
+            pool_mint: pubkeys[3],
+            payer: pubkeys[4],
+            token_metadata: pubkeys[5],
+            mpl_token_metadata: pubkeys[6],
+            system_program: pubkeys[7],
+        }
+    }
+}
+impl<'info> From<CreateTokenMetadataAccounts<'_, 'info>>
+    for [AccountInfo<'info>; CREATE_TOKEN_METADATA_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: CreateTokenMetadataAccounts<'_, 'info>) -> Self {
+        [
+            accounts.stake_pool.clone(),
+            accounts.manager.clone(),
+            accounts.withdraw_authority.clone(),
+            accounts.pool_mint.clone(),
+            accounts.payer.clone(),
+            accounts.token_metadata.clone(),
+            accounts.mpl_token_metadata.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; CREATE_TOKEN_METADATA_IX_ACCOUNTS_LEN]>
+    for CreateTokenMetadataAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; CREATE_TOKEN_METADATA_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            stake_pool: &arr[0],
+            manager: &arr[1],
+            withdraw_authority: &arr[2],
+            pool_mint: &arr[3],
+            payer: &arr[4],
+            token_metadata: &arr[5],
+            mpl_token_metadata: &arr[6],
+            system_program: &arr[7],
+        }
+    }
+}
+pub const CREATE_TOKEN_METADATA_IX_DISCM: u8 = 17u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateTokenMetadataIxArgs {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreateTokenMetadataIxData(pub CreateTokenMetadataIxArgs);
+impl From<CreateTokenMetadataIxArgs> for CreateTokenMetadataIxData {
+    fn from(args: CreateTokenMetadataIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl CreateTokenMetadataIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != CREATE_TOKEN_METADATA_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    CREATE_TOKEN_METADATA_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(CreateTokenMetadataIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[CREATE_TOKEN_METADATA_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn create_token_metadata_ix_with_program_id(
+    program_id: Pubkey,
+    keys: CreateTokenMetadataKeys,
+    args: CreateTokenMetadataIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; CREATE_TOKEN_METADATA_IX_ACCOUNTS_LEN] = keys.into();
+    let data: CreateTokenMetadataIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn create_token_metadata_ix(
+    keys: CreateTokenMetadataKeys,
+    args: CreateTokenMetadataIxArgs,
+) -> std::io::Result<Instruction> {
+    create_token_metadata_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn create_token_metadata_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: CreateTokenMetadataAccounts<'_, '_>,
+    args: CreateTokenMetadataIxArgs,
+) -> ProgramResult {
+    let keys: CreateTokenMetadataKeys = accounts.into();
+    let ix = create_token_metadata_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn create_token_metadata_invoke(
+    accounts: CreateTokenMetadataAccounts<'_, '_>,
+    args: CreateTokenMetadataIxArgs,
+) -> ProgramResult {
+    create_token_metadata_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn create_token_metadata_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: CreateTokenMetadataAccounts<'_, '_>,
+    args: CreateTokenMetadataIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: CreateTokenMetadataKeys = accounts.into();
+    let ix = create_token_metadata_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn create_token_metadata_invoke_signed(
+    accounts: CreateTokenMetadataAccounts<'_, '_>,
+    args: CreateTokenMetadataIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    create_token_metadata_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn create_token_metadata_verify_account_keys(
+    accounts: CreateTokenMetadataAccounts<'_, '_>,
+    keys: CreateTokenMetadataKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.stake_pool.key, &keys.stake_pool),
+        (accounts.manager.key, &keys.manager),
+        (accounts.withdraw_authority.key, &keys.withdraw_authority),
+        (accounts.pool_mint.key, &keys.pool_mint),
+        (accounts.payer.key, &keys.payer),
+        (accounts.token_metadata.key, &keys.token_metadata),
+        (accounts.mpl_token_metadata.key, &keys.mpl_token_metadata),
+        (accounts.system_program.key, &keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn create_token_metadata_verify_writable_privileges<'me, 'info>(
+    accounts: CreateTokenMetadataAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.payer, accounts.token_metadata] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn create_token_metadata_verify_signer_privileges<'me, 'info>(
+    accounts: CreateTokenMetadataAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.manager, accounts.payer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn create_token_metadata_verify_account_privileges<'me, 'info>(
+    accounts: CreateTokenMetadataAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    create_token_metadata_verify_writable_privileges(accounts)?;
+    create_token_metadata_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const UPDATE_TOKEN_METADATA_IX_ACCOUNTS_LEN: usize = 5;
+#[derive(Copy, Clone, Debug)]
+pub struct UpdateTokenMetadataAccounts<'me, 'info> {
+    ///Stake pool
+    pub stake_pool: &'me AccountInfo<'info>,
+    ///Manager
+    pub manager: &'me AccountInfo<'info>,
+    ///Stake pool withdraw authority
+    pub withdraw_authority: &'me AccountInfo<'info>,
+    ///Token metadata account
+    pub token_metadata: &'me AccountInfo<'info>,
+    ///Metaplex token metadata program
+    pub mpl_token_metadata: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct UpdateTokenMetadataKeys {
+    ///Stake pool
+    pub stake_pool: Pubkey,
+    ///Manager
+    pub manager: Pubkey,
+    ///Stake pool withdraw authority
+    pub withdraw_authority: Pubkey,
+    ///Token metadata account
+    pub token_metadata: Pubkey,
+    ///Metaplex token metadata program
+    pub mpl_token_metadata: Pubkey,
+}
+impl From<UpdateTokenMetadataAccounts<'_, '_>> for UpdateTokenMetadataKeys {
+    fn from(accounts: UpdateTokenMetadataAccounts) -> Self {
+        Self {
+            stake_pool: *accounts.stake_pool.key,
+            manager: *accounts.manager.key,
+            withdraw_authority: *accounts.withdraw_authority.key,
+            token_metadata: *accounts.token_metadata.key,
+            mpl_token_metadata: *accounts.mpl_token_metadata.key,
+        }
+    }
+}
+impl From<UpdateTokenMetadataKeys> for [AccountMeta; UPDATE_TOKEN_METADATA_IX_ACCOUNTS_LEN] {
+    fn from(keys: UpdateTokenMetadataKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.stake_pool,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.manager,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.withdraw_authority,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.token_metadata,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.mpl_token_metadata,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; UPDATE_TOKEN_METADATA_IX_ACCOUNTS_LEN]> for UpdateTokenMetadataKeys {
+    fn from(pubkeys: [Pubkey; UPDATE_TOKEN_METADATA_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            stake_pool: pubkeys[0],
+            manager: pubkeys[1],
+            withdraw_authority: pubkeys[2],
+            token_metadata: pubkeys[3],
+            mpl_token_metadata: pubkeys[4],
+        }
+    }
+}
+impl<'info> From<UpdateTokenMetadataAccounts<'_, 'info>>
+    for [AccountInfo<'info>; UPDATE_TOKEN_METADATA_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: UpdateTokenMetadataAccounts<'_, 'info>) -> Self {
+        [
+            accounts.stake_pool.clone(),
+            accounts.manager.clone(),
+            accounts.withdraw_authority.clone(),
+            accounts.token_metadata.clone(),
+            accounts.mpl_token_metadata.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_TOKEN_METADATA_IX_ACCOUNTS_LEN]>
+    for UpdateTokenMetadataAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; UPDATE_TOKEN_METADATA_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            stake_pool: &arr[0],
+            manager: &arr[1],
+            withdraw_authority: &arr[2],
+            token_metadata: &arr[3],
+            mpl_token_metadata: &arr[4],
+        }
+    }
+}
+pub const UPDATE_TOKEN_METADATA_IX_DISCM: u8 = 18u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UpdateTokenMetadataIxArgs {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct UpdateTokenMetadataIxData(pub UpdateTokenMetadataIxArgs);
+impl From<UpdateTokenMetadataIxArgs> for UpdateTokenMetadataIxData {
+    fn from(args: UpdateTokenMetadataIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl UpdateTokenMetadataIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != UPDATE_TOKEN_METADATA_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    UPDATE_TOKEN_METADATA_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(UpdateTokenMetadataIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[UPDATE_TOKEN_METADATA_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn update_token_metadata_ix_with_program_id(
+    program_id: Pubkey,
+    keys: UpdateTokenMetadataKeys,
+    args: UpdateTokenMetadataIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; UPDATE_TOKEN_METADATA_IX_ACCOUNTS_LEN] = keys.into();
+    let data: UpdateTokenMetadataIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn update_token_metadata_ix(
+    keys: UpdateTokenMetadataKeys,
+    args: UpdateTokenMetadataIxArgs,
+) -> std::io::Result<Instruction> {
+    update_token_metadata_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn update_token_metadata_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: UpdateTokenMetadataAccounts<'_, '_>,
+    args: UpdateTokenMetadataIxArgs,
+) -> ProgramResult {
+    let keys: UpdateTokenMetadataKeys = accounts.into();
+    let ix = update_token_metadata_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn update_token_metadata_invoke(
+    accounts: UpdateTokenMetadataAccounts<'_, '_>,
+    args: UpdateTokenMetadataIxArgs,
+) -> ProgramResult {
+    update_token_metadata_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn update_token_metadata_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: UpdateTokenMetadataAccounts<'_, '_>,
+    args: UpdateTokenMetadataIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: UpdateTokenMetadataKeys = accounts.into();
+    let ix = update_token_metadata_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn update_token_metadata_invoke_signed(
+    accounts: UpdateTokenMetadataAccounts<'_, '_>,
+    args: UpdateTokenMetadataIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    update_token_metadata_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn update_token_metadata_verify_account_keys(
+    accounts: UpdateTokenMetadataAccounts<'_, '_>,
+    keys: UpdateTokenMetadataKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.stake_pool.key, &keys.stake_pool),
+        (accounts.manager.key, &keys.manager),
+        (accounts.withdraw_authority.key, &keys.withdraw_authority),
+        (accounts.token_metadata.key, &keys.token_metadata),
+        (accounts.mpl_token_metadata.key, &keys.mpl_token_metadata),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn update_token_metadata_verify_writable_privileges<'me, 'info>(
+    accounts: UpdateTokenMetadataAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.token_metadata] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn update_token_metadata_verify_signer_privileges<'me, 'info>(
+    accounts: UpdateTokenMetadataAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.manager] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn update_token_metadata_verify_account_privileges<'me, 'info>(
+    accounts: UpdateTokenMetadataAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    update_token_metadata_verify_writable_privileges(accounts)?;
+    update_token_metadata_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const INCREASE_ADDITIONAL_VALIDATOR_STAKE_IX_ACCOUNTS_LEN: usize = 14;
+#[derive(Copy, Clone, Debug)]
+pub struct IncreaseAdditionalValidatorStakeAccounts<'me, 'info> {
+    ///Stake pool
+    pub stake_pool: &'me AccountInfo<'info>,
+    ///Current staker
+    pub staker: &'me AccountInfo<'info>,
+    ///Stake pool withdraw authority
+    pub withdraw_authority: &'me AccountInfo<'info>,
+    ///Validator list
+    pub validator_list: &'me AccountInfo<'info>,
+    ///Reserve stake account
+    pub reserve_stake: &'me AccountInfo<'info>,
+    ///Uninitialized ephemeral stake account to receive stake
+    pub ephemeral_stake_account: &'me AccountInfo<'info>,
+    ///Transient stake account
+    pub transient_stake_account: &'me AccountInfo<'info>,
+    ///Validator stake account
+    pub validator_stake_account: &'me AccountInfo<'info>,
+    ///Validator vote account to delegate to
+    pub vote_account: &'me AccountInfo<'info>,
+    ///Clock sysvar
+    pub clock: &'me AccountInfo<'info>,
+    ///Stake history sysvar
+    pub stake_history: &'me AccountInfo<'info>,
+    ///Stake config sysvar
+    pub stake_config: &'me AccountInfo<'info>,
+    ///System program
+    pub system_program: &'me AccountInfo<'info>,
+    ///Stake program
+    pub stake_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct IncreaseAdditionalValidatorStakeKeys {
+    ///Stake pool
+    pub stake_pool: Pubkey,
+    ///Current staker
+    pub staker: Pubkey,
+    ///Stake pool withdraw authority
+    pub withdraw_authority: Pubkey,
+    ///Validator list
+    pub validator_list: Pubkey,
+    ///Reserve stake account
+    pub reserve_stake: Pubkey,
+    ///Uninitialized ephemeral stake account to receive stake
+    pub ephemeral_stake_account: Pubkey,
+    ///Transient stake account
+    pub transient_stake_account: Pubkey,
+    ///Validator stake account
+    pub validator_stake_account: Pubkey,
+    ///Validator vote account to delegate to
+    pub vote_account: Pubkey,
+    ///Clock sysvar
+    pub clock: Pubkey,
+    ///Stake history sysvar
+    pub stake_history: Pubkey,
+    ///Stake config sysvar
+    pub stake_config: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+    ///Stake program
+    pub stake_program: Pubkey,
+}
+impl From<IncreaseAdditionalValidatorStakeAccounts<'_, '_>>
+    for IncreaseAdditionalValidatorStakeKeys
+{
+    fn from(accounts: IncreaseAdditionalValidatorStakeAccounts) -> Self {
+        Self {
+            stake_pool: *accounts.stake_pool.key,
+            staker: *accounts.staker.key,
+            withdraw_authority: *accounts.withdraw_authority.key,
+            validator_list: *accounts.validator_list.key,
+            reserve_stake: *accounts.reserve_stake.key,
+            ephemeral_stake_account: *accounts.ephemeral_stake_account.key,
+            transient_stake_account: *accounts.transient_stake_account.key,
+            validator_stake_account: *accounts.validator_stake_account.key,
+            vote_account: *accounts.vote_account.key,
+            clock: *accounts.clock.key,
+            stake_history: *accounts.stake_history.key,
+            stake_config: *accounts.stake_config.key,
+            system_program: *accounts.system_program.key,
+            stake_program: *accounts.stake_program.key,
+        }
+    }
+}
+impl From<IncreaseAdditionalValidatorStakeKeys>
+    for [AccountMeta; INCREASE_ADDITIONAL_VALIDATOR_STAKE_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: IncreaseAdditionalValidatorStakeKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.stake_pool,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.staker,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.withdraw_authority,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.validator_list,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.reserve_stake,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.ephemeral_stake_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.transient_stake_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.validator_stake_account,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.vote_account,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.clock,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.stake_history,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.stake_config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.stake_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; INCREASE_ADDITIONAL_VALIDATOR_STAKE_IX_ACCOUNTS_LEN]>
+    for IncreaseAdditionalValidatorStakeKeys
+{
+    fn from(pubkeys: [Pubkey; INCREASE_ADDITIONAL_VALIDATOR_STAKE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            stake_pool: pubkeys[0],
+            staker: pubkeys[1],
+            withdraw_authority: pubkeys[2],
+            validator_list: pubkeys[3],
+            reserve_stake: pubkeys[4],
+            ephemeral_stake_account: pubkeys[5],
+            transient_stake_account: pubkeys[6],
+            validator_stake_account: pubkeys[7],
+            vote_account: pubkeys[8],
+            clock: pubkeys[9],
+            stake_history: pubkeys[10],
+            stake_config: pubkeys[11],
+            system_program: pubkeys[12],
+            stake_program: pubkeys[13],
+        }
+    }
+}
+impl<'info> From<IncreaseAdditionalValidatorStakeAccounts<'_, 'info>>
+    for [AccountInfo<'info>; INCREASE_ADDITIONAL_VALIDATOR_STAKE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: IncreaseAdditionalValidatorStakeAccounts<'_, 'info>) -> Self {
+        [
+            accounts.stake_pool.clone(),
+            accounts.staker.clone(),
+            accounts.withdraw_authority.clone(),
+            accounts.validator_list.clone(),
+            accounts.reserve_stake.clone(),
+            accounts.ephemeral_stake_account.clone(),
+            accounts.transient_stake_account.clone(),
+            accounts.validator_stake_account.clone(),
+            accounts.vote_account.clone(),
+            accounts.clock.clone(),
+            accounts.stake_history.clone(),
+            accounts.stake_config.clone(),
+            accounts.system_program.clone(),
+            accounts.stake_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info>
+    From<&'me [AccountInfo<'info>; INCREASE_ADDITIONAL_VALIDATOR_STAKE_IX_ACCOUNTS_LEN]>
+    for IncreaseAdditionalValidatorStakeAccounts<'me, 'info>
+{
+    fn from(
+        arr: &'me [AccountInfo<'info>; INCREASE_ADDITIONAL_VALIDATOR_STAKE_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            stake_pool: &arr[0],
+            staker: &arr[1],
+            withdraw_authority: &arr[2],
+            validator_list: &arr[3],
+            reserve_stake: &arr[4],
+            ephemeral_stake_account: &arr[5],
+            transient_stake_account: &arr[6],
+            validator_stake_account: &arr[7],
+            vote_account: &arr[8],
+            clock: &arr[9],
+            stake_history: &arr[10],
+            stake_config: &arr[11],
+            system_program: &arr[12],
+            stake_program: &arr[13],
+        }
+    }
+}
+pub const INCREASE_ADDITIONAL_VALIDATOR_STAKE_IX_DISCM: u8 = 19u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IncreaseAdditionalValidatorStakeIxArgs {
+    pub args: AdditionalValidatorStakeArgs,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct IncreaseAdditionalValidatorStakeIxData(pub IncreaseAdditionalValidatorStakeIxArgs);
+impl From<IncreaseAdditionalValidatorStakeIxArgs> for IncreaseAdditionalValidatorStakeIxData {
+    fn from(args: IncreaseAdditionalValidatorStakeIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl IncreaseAdditionalValidatorStakeIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != INCREASE_ADDITIONAL_VALIDATOR_STAKE_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    INCREASE_ADDITIONAL_VALIDATOR_STAKE_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(IncreaseAdditionalValidatorStakeIxArgs::deserialize(
+            &mut reader,
+        )?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[INCREASE_ADDITIONAL_VALIDATOR_STAKE_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn increase_additional_validator_stake_ix_with_program_id(
+    program_id: Pubkey,
+    keys: IncreaseAdditionalValidatorStakeKeys,
+    args: IncreaseAdditionalValidatorStakeIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; INCREASE_ADDITIONAL_VALIDATOR_STAKE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: IncreaseAdditionalValidatorStakeIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn increase_additional_validator_stake_ix(
+    keys: IncreaseAdditionalValidatorStakeKeys,
+    args: IncreaseAdditionalValidatorStakeIxArgs,
+) -> std::io::Result<Instruction> {
+    increase_additional_validator_stake_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn increase_additional_validator_stake_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: IncreaseAdditionalValidatorStakeAccounts<'_, '_>,
+    args: IncreaseAdditionalValidatorStakeIxArgs,
+) -> ProgramResult {
+    let keys: IncreaseAdditionalValidatorStakeKeys = accounts.into();
+    let ix = increase_additional_validator_stake_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn increase_additional_validator_stake_invoke(
+    accounts: IncreaseAdditionalValidatorStakeAccounts<'_, '_>,
+    args: IncreaseAdditionalValidatorStakeIxArgs,
+) -> ProgramResult {
+    increase_additional_validator_stake_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn increase_additional_validator_stake_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: IncreaseAdditionalValidatorStakeAccounts<'_, '_>,
+    args: IncreaseAdditionalValidatorStakeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: IncreaseAdditionalValidatorStakeKeys = accounts.into();
+    let ix = increase_additional_validator_stake_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn increase_additional_validator_stake_invoke_signed(
+    accounts: IncreaseAdditionalValidatorStakeAccounts<'_, '_>,
+    args: IncreaseAdditionalValidatorStakeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    increase_additional_validator_stake_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+        seeds,
+    )
+}
+pub fn increase_additional_validator_stake_verify_account_keys(
+    accounts: IncreaseAdditionalValidatorStakeAccounts<'_, '_>,
+    keys: IncreaseAdditionalValidatorStakeKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.stake_pool.key, &keys.stake_pool),
+        (accounts.staker.key, &keys.staker),
+        (accounts.withdraw_authority.key, &keys.withdraw_authority),
+        (accounts.validator_list.key, &keys.validator_list),
+        (accounts.reserve_stake.key, &keys.reserve_stake),
+        (
+            accounts.ephemeral_stake_account.key,
+            &keys.ephemeral_stake_account,
+        ),
+        (
+            accounts.transient_stake_account.key,
+            &keys.transient_stake_account,
+        ),
+        (
+            accounts.validator_stake_account.key,
+            &keys.validator_stake_account,
+        ),
+        (accounts.vote_account.key, &keys.vote_account),
+        (accounts.clock.key, &keys.clock),
+        (accounts.stake_history.key, &keys.stake_history),
+        (accounts.stake_config.key, &keys.stake_config),
+        (accounts.system_program.key, &keys.system_program),
+        (accounts.stake_program.key, &keys.stake_program),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn increase_additional_validator_stake_verify_writable_privileges<'me, 'info>(
+    accounts: IncreaseAdditionalValidatorStakeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.validator_list,
+        accounts.reserve_stake,
+        accounts.ephemeral_stake_account,
+        accounts.transient_stake_account,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn increase_additional_validator_stake_verify_signer_privileges<'me, 'info>(
+    accounts: IncreaseAdditionalValidatorStakeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.staker] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn increase_additional_validator_stake_verify_account_privileges<'me, 'info>(
+    accounts: IncreaseAdditionalValidatorStakeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    increase_additional_validator_stake_verify_writable_privileges(accounts)?;
+    increase_additional_validator_stake_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const DECREASE_ADDITIONAL_VALIDATOR_STAKE_IX_ACCOUNTS_LEN: usize = 12;
+#[derive(Copy, Clone, Debug)]
+pub struct DecreaseAdditionalValidatorStakeAccounts<'me, 'info> {
+    ///Stake pool
+    pub stake_pool: &'me AccountInfo<'info>,
+    ///Current staker
+    pub staker: &'me AccountInfo<'info>,
+    ///Stake pool withdraw authority
+    pub withdraw_authority: &'me AccountInfo<'info>,
+    ///Validator list
+    pub validator_list: &'me AccountInfo<'info>,
+    ///Reserve stake account
+    pub reserve_stake: &'me AccountInfo<'info>,
+    ///Validator stake account to split stake from
+    pub validator_stake_account: &'me AccountInfo<'info>,
+    ///Uninitialized ephemeral stake account to receive stake
+    pub ephemeral_stake_account: &'me AccountInfo<'info>,
+    ///Transient stake account
+    pub transient_stake_account: &'me AccountInfo<'info>,
+    ///Clock sysvar
+    pub clock: &'me AccountInfo<'info>,
+    ///Stake history sysvar
+    pub stake_history: &'me AccountInfo<'info>,
+    ///System program
+    pub system_program: &'me AccountInfo<'info>,
+    ///Stake program
+    pub stake_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct DecreaseAdditionalValidatorStakeKeys {
+    ///Stake pool
+    pub stake_pool: Pubkey,
+    ///Current staker
+    pub staker: Pubkey,
+    ///Stake pool withdraw authority
+    pub withdraw_authority: Pubkey,
+    ///Validator list
+    pub validator_list: Pubkey,
+    ///Reserve stake account
+    pub reserve_stake: Pubkey,
+    ///Validator stake account to split stake from
+    pub validator_stake_account: Pubkey,
+    ///Uninitialized ephemeral stake account to receive stake
+    pub ephemeral_stake_account: Pubkey,
+    ///Transient stake account
+    pub transient_stake_account: Pubkey,
+    ///Clock sysvar
+    pub clock: Pubkey,
+    ///Stake history sysvar
+    pub stake_history: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+    ///Stake program
+    pub stake_program: Pubkey,
+}
+impl From<DecreaseAdditionalValidatorStakeAccounts<'_, '_>>
+    for DecreaseAdditionalValidatorStakeKeys
+{
+    fn from(accounts: DecreaseAdditionalValidatorStakeAccounts) -> Self {
+        Self {
+            stake_pool: *accounts.stake_pool.key,
+            staker: *accounts.staker.key,
+            withdraw_authority: *accounts.withdraw_authority.key,
+            validator_list: *accounts.validator_list.key,
+            reserve_stake: *accounts.reserve_stake.key,
+            validator_stake_account: *accounts.validator_stake_account.key,
+            ephemeral_stake_account: *accounts.ephemeral_stake_account.key,
+            transient_stake_account: *accounts.transient_stake_account.key,
+            clock: *accounts.clock.key,
+            stake_history: *accounts.stake_history.key,
+            system_program: *accounts.system_program.key,
+            stake_program: *accounts.stake_program.key,
+        }
+    }
+}
+impl From<DecreaseAdditionalValidatorStakeKeys>
+    for [AccountMeta; DECREASE_ADDITIONAL_VALIDATOR_STAKE_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: DecreaseAdditionalValidatorStakeKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.stake_pool,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.staker,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.withdraw_authority,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.validator_list,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.reserve_stake,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.validator_stake_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.ephemeral_stake_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.transient_stake_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.clock,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.stake_history,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.stake_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; DECREASE_ADDITIONAL_VALIDATOR_STAKE_IX_ACCOUNTS_LEN]>
+    for DecreaseAdditionalValidatorStakeKeys
+{
+    fn from(pubkeys: [Pubkey; DECREASE_ADDITIONAL_VALIDATOR_STAKE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            stake_pool: pubkeys[0],
+            staker: pubkeys[1],
+            withdraw_authority: pubkeys[2],
+            validator_list: pubkeys[3],
+            reserve_stake: pubkeys[4],
+            validator_stake_account: pubkeys[5],
+            ephemeral_stake_account: pubkeys[6],
+            transient_stake_account: pubkeys[7],
+            clock: pubkeys[8],
+            stake_history: pubkeys[9],
+            system_program: pubkeys[10],
+            stake_program: pubkeys[11],
         }
     }
 }
-impl<'info> From<SetFundingAuthorityAccounts<'_, 'info>>
-    for [AccountInfo<'info>; SET_FUNDING_AUTHORITY_IX_ACCOUNTS_LEN]
+impl<'info> From<DecreaseAdditionalValidatorStakeAccounts<'_, 'info>>
+    for [AccountInfo<'info>; DECREASE_ADDITIONAL_VALIDATOR_STAKE_IX_ACCOUNTS_LEN]
 {
-    fn from(accounts: SetFundingAuthorityAccounts<'_, 'info>) -> Self {
+    fn from(accounts: DecreaseAdditionalValidatorStakeAccounts<'_, 'info>) -> Self {
         [
             accounts.stake_pool.clone(),
-            accounts.manager.clone(),
-            accounts.new_funding_authority.clone(),
+            accounts.staker.clone(),
+            accounts.withdraw_authority.clone(),
+            accounts.validator_list.clone(),
+            accounts.reserve_stake.clone(),
+            accounts.validator_stake_account.clone(),
+            accounts.ephemeral_stake_account.clone(),
+            accounts.transient_stake_account.clone(),
+            accounts.clock.clone(),
+            accounts.stake_history.clone(),
+            accounts.system_program.clone(),
+            accounts.stake_program.clone(),
         ]
     }
 }
-impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_FUNDING_AUTHORITY_IX_ACCOUNTS_LEN]>
-    for SetFundingAuthorityAccounts<'me, 'info>
+impl<'me, 'info>
+    From<&'me [AccountInfo<'info>; DECREASE_ADDITIONAL_VALIDATOR_STAKE_IX_ACCOUNTS_LEN]>
+    for DecreaseAdditionalValidatorStakeAccounts<'me, 'info>
 {
-    fn from(arr: &'me [AccountInfo<'info>; SET_FUNDING_AUTHORITY_IX_ACCOUNTS_LEN]) -> Self {
+    fn from(
+        arr: &'me [AccountInfo<'info>; DECREASE_ADDITIONAL_VALIDATOR_STAKE_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self {
             stake_pool: &arr[0],
-            manager: &arr[1],
-            new_funding_authority: &arr[2],
+            staker: &arr[1],
+            withdraw_authority: &arr[2],
+            validator_list: &arr[3],
+            reserve_stake: &arr[4],
+            validator_stake_account: &arr[5],
+            ephemeral_stake_account: &arr[6],
+            transient_stake_account: &arr[7],
+            clock: &arr[8],
+            stake_history: &arr[9],
+            system_program: &arr[10],
+            stake_program: &arr[11],
         }
     }
 }
-pub const SET_FUNDING_AUTHORITY_IX_DISCM: u8 = 15u8;
+pub const DECREASE_ADDITIONAL_VALIDATOR_STAKE_IX_DISCM: u8 = 20u8;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct SetFundingAuthorityIxArgs {
-    pub auth: FundingType,
+pub struct DecreaseAdditionalValidatorStakeIxArgs {
+    pub args: AdditionalValidatorStakeArgs,
 }
 #[derive(Clone, Debug, PartialEq)]
-pub struct SetFundingAuthorityIxData(pub SetFundingAuthorityIxArgs);
-impl From<SetFundingAuthorityIxArgs> for SetFundingAuthorityIxData {
-    fn from(args: SetFundingAuthorityIxArgs) -> Self {
+pub struct DecreaseAdditionalValidatorStakeIxData(pub DecreaseAdditionalValidatorStakeIxArgs);
+impl From<DecreaseAdditionalValidatorStakeIxArgs> for DecreaseAdditionalValidatorStakeIxData {
+    fn from(args: DecreaseAdditionalValidatorStakeIxArgs) -> Self {
         Self(args)
     }
 }
-impl SetFundingAuthorityIxData {
+impl DecreaseAdditionalValidatorStakeIxData {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
-        if maybe_discm != SET_FUNDING_AUTHORITY_IX_DISCM {
+        if maybe_discm != DECREASE_ADDITIONAL_VALIDATOR_STAKE_IX_DISCM {
             return Err(std::io::Error::new(
                 std::io::ErrorKind::Other,
                 format!(
                     "discm does not match. Expected: {:?}. Received: {:?}",
-                    SET_FUNDING_AUTHORITY_IX_DISCM, maybe_discm
+                    DECREASE_ADDITIONAL_VALIDATOR_STAKE_IX_DISCM, maybe_discm
                 ),
             ));
         }
-        Ok(Self(SetFundingAuthorityIxArgs::deserialize(&mut reader)?))
+        Ok(Self(DecreaseAdditionalValidatorStakeIxArgs::deserialize(
+            &mut reader,
+        )?))
     }
     pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
-        writer.write_all(&[SET_FUNDING_AUTHORITY_IX_DISCM])?;
+        writer.write_all(&[DECREASE_ADDITIONAL_VALIDATOR_STAKE_IX_DISCM])?;
         self.0.serialize(&mut writer)
     }
     pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
@@ -2713,68 +6153,88 @@ impl SetFundingAuthorityIxData {
         Ok(data)
     }
 }
-pub fn set_funding_authority_ix_with_program_id(
+pub fn decrease_additional_validator_stake_ix_with_program_id(
     program_id: Pubkey,
-    keys: SetFundingAuthorityKeys,
-    args: SetFundingAuthorityIxArgs,
+    keys: DecreaseAdditionalValidatorStakeKeys,
+    args: DecreaseAdditionalValidatorStakeIxArgs,
 ) -> std::io::Result<Instruction> {
-    let metas: [AccountMeta; SET_FUNDING_AUTHORITY_IX_ACCOUNTS_LEN] = keys.into();
-    let data: SetFundingAuthorityIxData = args.into();
+    let metas: [AccountMeta; DECREASE_ADDITIONAL_VALIDATOR_STAKE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: DecreaseAdditionalValidatorStakeIxData = args.into();
     Ok(Instruction {
         program_id,
         accounts: Vec::from(metas),
         data: data.try_to_vec()?,
     })
 }
-pub fn set_funding_authority_ix(
-    keys: SetFundingAuthorityKeys,
-    args: SetFundingAuthorityIxArgs,
+pub fn decrease_additional_validator_stake_ix(
+    keys: DecreaseAdditionalValidatorStakeKeys,
+    args: DecreaseAdditionalValidatorStakeIxArgs,
 ) -> std::io::Result<Instruction> {
-    set_funding_authority_ix_with_program_id(crate::ID, keys, args)
+    decrease_additional_validator_stake_ix_with_program_id(crate::ID, keys, args)
 }
-pub fn set_funding_authority_invoke_with_program_id(
+pub fn decrease_additional_validator_stake_invoke_with_program_id(
     program_id: Pubkey,
-    accounts: SetFundingAuthorityAccounts<'_, '_>,
-    args: SetFundingAuthorityIxArgs,
+    accounts: DecreaseAdditionalValidatorStakeAccounts<'_, '_>,
+    args: DecreaseAdditionalValidatorStakeIxArgs,
 ) -> ProgramResult {
-    let keys: SetFundingAuthorityKeys = accounts.into();
-    let ix = set_funding_authority_ix_with_program_id(program_id, keys, args)?;
+    let keys: DecreaseAdditionalValidatorStakeKeys = accounts.into();
+    let ix = decrease_additional_validator_stake_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
-pub fn set_funding_authority_invoke(
-    accounts: SetFundingAuthorityAccounts<'_, '_>,
-    args: SetFundingAuthorityIxArgs,
+pub fn decrease_additional_validator_stake_invoke(
+    accounts: DecreaseAdditionalValidatorStakeAccounts<'_, '_>,
+    args: DecreaseAdditionalValidatorStakeIxArgs,
 ) -> ProgramResult {
-    set_funding_authority_invoke_with_program_id(crate::ID, accounts, args)
+    decrease_additional_validator_stake_invoke_with_program_id(crate::ID, accounts, args)
 }
-pub fn set_funding_authority_invoke_signed_with_program_id(
+pub fn decrease_additional_validator_stake_invoke_signed_with_program_id(
     program_id: Pubkey,
-    accounts: SetFundingAuthorityAccounts<'_, '_>,
-    args: SetFundingAuthorityIxArgs,
+    accounts: DecreaseAdditionalValidatorStakeAccounts<'_, '_>,
+    args: DecreaseAdditionalValidatorStakeIxArgs,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let keys: SetFundingAuthorityKeys = accounts.into();
-    let ix = set_funding_authority_ix_with_program_id(program_id, keys, args)?;
+    let keys: DecreaseAdditionalValidatorStakeKeys = accounts.into();
+    let ix = decrease_additional_validator_stake_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
-pub fn set_funding_authority_invoke_signed(
-    accounts: SetFundingAuthorityAccounts<'_, '_>,
-    args: SetFundingAuthorityIxArgs,
+pub fn decrease_additional_validator_stake_invoke_signed(
+    accounts: DecreaseAdditionalValidatorStakeAccounts<'_, '_>,
+    args: DecreaseAdditionalValidatorStakeIxArgs,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    set_funding_authority_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+    decrease_additional_validator_stake_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+        seeds,
+    )
 }
-pub fn set_funding_authority_verify_account_keys(
-    accounts: SetFundingAuthorityAccounts<'_, '_>,
-    keys: SetFundingAuthorityKeys,
+pub fn decrease_additional_validator_stake_verify_account_keys(
+    accounts: DecreaseAdditionalValidatorStakeAccounts<'_, '_>,
+    keys: DecreaseAdditionalValidatorStakeKeys,
 ) -> Result<(), (Pubkey, Pubkey)> {
     for (actual, expected) in [
         (accounts.stake_pool.key, &keys.stake_pool),
-        (accounts.manager.key, &keys.manager),
+        (accounts.staker.key, &keys.staker),
+        (accounts.withdraw_authority.key, &keys.withdraw_authority),
+        (accounts.validator_list.key, &keys.validator_list),
+        (accounts.reserve_stake.key, &keys.reserve_stake),
         (
-            accounts.new_funding_authority.key,
-            &keys.new_funding_authority,
+            accounts.validator_stake_account.key,
+            &keys.validator_stake_account,
+        ),
+        (
+            accounts.ephemeral_stake_account.key,
+            &keys.ephemeral_stake_account,
+        ),
+        (
+            accounts.transient_stake_account.key,
+            &keys.transient_stake_account,
         ),
+        (accounts.clock.key, &keys.clock),
+        (accounts.stake_history.key, &keys.stake_history),
+        (accounts.system_program.key, &keys.system_program),
+        (accounts.stake_program.key, &keys.stake_program),
     ] {
         if actual != expected {
             return Err((*actual, *expected));
@@ -2782,36 +6242,42 @@ pub fn set_funding_authority_verify_account_keys(
     }
     Ok(())
 }
-pub fn set_funding_authority_verify_writable_privileges<'me, 'info>(
-    accounts: SetFundingAuthorityAccounts<'me, 'info>,
+pub fn decrease_additional_validator_stake_verify_writable_privileges<'me, 'info>(
+    accounts: DecreaseAdditionalValidatorStakeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
-    for should_be_writable in [accounts.stake_pool] {
+    for should_be_writable in [
+        accounts.validator_list,
+        accounts.reserve_stake,
+        accounts.validator_stake_account,
+        accounts.ephemeral_stake_account,
+        accounts.transient_stake_account,
+    ] {
         if !should_be_writable.is_writable {
             return Err((should_be_writable, ProgramError::InvalidAccountData));
         }
     }
     Ok(())
 }
-pub fn set_funding_authority_verify_signer_privileges<'me, 'info>(
-    accounts: SetFundingAuthorityAccounts<'me, 'info>,
+pub fn decrease_additional_validator_stake_verify_signer_privileges<'me, 'info>(
+    accounts: DecreaseAdditionalValidatorStakeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
-    for should_be_signer in [accounts.manager] {
+    for should_be_signer in [accounts.staker] {
         if !should_be_signer.is_signer {
             return Err((should_be_signer, ProgramError::MissingRequiredSignature));
         }
     }
     Ok(())
 }
-pub fn set_funding_authority_verify_account_privileges<'me, 'info>(
-    accounts: SetFundingAuthorityAccounts<'me, 'info>,
+pub fn decrease_additional_validator_stake_verify_account_privileges<'me, 'info>(
+    accounts: DecreaseAdditionalValidatorStakeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
-    set_funding_authority_verify_writable_privileges(accounts)?;
-    set_funding_authority_verify_signer_privileges(accounts)?;
+    decrease_additional_validator_stake_verify_writable_privileges(accounts)?;
+    decrease_additional_validator_stake_verify_signer_privileges(accounts)?;
     Ok(())
 }
-pub const INCREASE_ADDITIONAL_VALIDATOR_STAKE_IX_ACCOUNTS_LEN: usize = 14;
+pub const DECREASE_VALIDATOR_STAKE_WITH_RESERVE_IX_ACCOUNTS_LEN: usize = 11;
 #[derive(Copy, Clone, Debug)]
-pub struct IncreaseAdditionalValidatorStakeAccounts<'me, 'info> {
+pub struct DecreaseValidatorStakeWithReserveAccounts<'me, 'info> {
     ///Stake pool
     pub stake_pool: &'me AccountInfo<'info>,
     ///Current staker
@@ -2820,29 +6286,23 @@ pub struct IncreaseAdditionalValidatorStakeAccounts<'me, 'info> {
     pub withdraw_authority: &'me AccountInfo<'info>,
     ///Validator list
     pub validator_list: &'me AccountInfo<'info>,
-    ///Reserve stake account
+    ///Reserve stake account, to fund rent exempt reserve
     pub reserve_stake: &'me AccountInfo<'info>,
-    ///Uninitialized ephemeral stake account to receive stake
-    pub ephemeral_stake_account: &'me AccountInfo<'info>,
-    ///Transient stake account
-    pub transient_stake_account: &'me AccountInfo<'info>,
-    ///Validator stake account
+    ///Validator stake account to split stake from
     pub validator_stake_account: &'me AccountInfo<'info>,
-    ///Validator vote account to delegate to
-    pub vote_account: &'me AccountInfo<'info>,
+    ///Transient stake account to receive split
+    pub transient_stake_account: &'me AccountInfo<'info>,
     ///Clock sysvar
     pub clock: &'me AccountInfo<'info>,
     ///Stake history sysvar
     pub stake_history: &'me AccountInfo<'info>,
-    ///Stake config sysvar
-    pub stake_config: &'me AccountInfo<'info>,
     ///System program
     pub system_program: &'me AccountInfo<'info>,
     ///Stake program
     pub stake_program: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug)]
-pub struct IncreaseAdditionalValidatorStakeKeys {
+pub struct DecreaseValidatorStakeWithReserveKeys {
     ///Stake pool
     pub stake_pool: Pubkey,
     ///Current staker
@@ -2851,53 +6311,44 @@ pub struct IncreaseAdditionalValidatorStakeKeys {
     pub withdraw_authority: Pubkey,
     ///Validator list
     pub validator_list: Pubkey,
-    ///Reserve stake account
+    ///Reserve stake account, to fund rent exempt reserve
     pub reserve_stake: Pubkey,
-    ///Uninitialized ephemeral stake account to receive stake
-    pub ephemeral_stake_account: Pubkey,
-    ///Transient stake account
-    pub transient_stake_account: Pubkey,
-    ///Validator stake account
+    ///Validator stake account to split stake from
     pub validator_stake_account: Pubkey,
-    ///Validator vote account to delegate to
-    pub vote_account: Pubkey,
+    ///Transient stake account to receive split
+    pub transient_stake_account: Pubkey,
     ///Clock sysvar
     pub clock: Pubkey,
     ///Stake history sysvar
     pub stake_history: Pubkey,
-    ///Stake config sysvar
-    pub stake_config: Pubkey,
     ///System program
     pub system_program: Pubkey,
     ///Stake program
     pub stake_program: Pubkey,
 }
-impl From<IncreaseAdditionalValidatorStakeAccounts<'_, '_>>
-    for IncreaseAdditionalValidatorStakeKeys
+impl From<DecreaseValidatorStakeWithReserveAccounts<'_, '_>>
+    for DecreaseValidatorStakeWithReserveKeys
 {
-    fn from(accounts: IncreaseAdditionalValidatorStakeAccounts) -> Self {
+    fn from(accounts: DecreaseValidatorStakeWithReserveAccounts) -> Self {
         Self {
             stake_pool: *accounts.stake_pool.key,
             staker: *accounts.staker.key,
             withdraw_authority: *accounts.withdraw_authority.key,
             validator_list: *accounts.validator_list.key,
             reserve_stake: *accounts.reserve_stake.key,
-            ephemeral_stake_account: *accounts.ephemeral_stake_account.key,
-            transient_stake_account: *accounts.transient_stake_account.key,
             validator_stake_account: *accounts.validator_stake_account.key,
-            vote_account: *accounts.vote_account.key,
+            transient_stake_account: *accounts.transient_stake_account.key,
             clock: *accounts.clock.key,
             stake_history: *accounts.stake_history.key,
-            stake_config: *accounts.stake_config.key,
             system_program: *accounts.system_program.key,
             stake_program: *accounts.stake_program.key,
         }
     }
 }
-impl From<IncreaseAdditionalValidatorStakeKeys>
-    for [AccountMeta; INCREASE_ADDITIONAL_VALIDATOR_STAKE_IX_ACCOUNTS_LEN]
+impl From<DecreaseValidatorStakeWithReserveKeys>
+    for [AccountMeta; DECREASE_VALIDATOR_STAKE_WITH_RESERVE_IX_ACCOUNTS_LEN]
 {
-    fn from(keys: IncreaseAdditionalValidatorStakeKeys) -> Self {
+    fn from(keys: DecreaseValidatorStakeWithReserveKeys) -> Self {
         [
             AccountMeta {
                 pubkey: keys.stake_pool,
@@ -2925,7 +6376,7 @@ impl From<IncreaseAdditionalValidatorStakeKeys>
                 is_writable: true,
             },
             AccountMeta {
-                pubkey: keys.ephemeral_stake_account,
+                pubkey: keys.validator_stake_account,
                 is_signer: false,
                 is_writable: true,
             },
@@ -2934,16 +6385,6 @@ impl From<IncreaseAdditionalValidatorStakeKeys>
                 is_signer: false,
                 is_writable: true,
             },
-            AccountMeta {
-                pubkey: keys.validator_stake_account,
-                is_signer: false,
-                is_writable: false,
-            },
-            AccountMeta {
-                pubkey: keys.vote_account,
-                is_signer: false,
-                is_writable: false,
-            },
             AccountMeta {
                 pubkey: keys.clock,
                 is_signer: false,
@@ -2954,11 +6395,6 @@ impl From<IncreaseAdditionalValidatorStakeKeys>
                 is_signer: false,
                 is_writable: false,
             },
-            AccountMeta {
-                pubkey: keys.stake_config,
-                is_signer: false,
-                is_writable: false,
-            },
             AccountMeta {
                 pubkey: keys.system_program,
                 is_signer: false,
@@ -2972,56 +6408,50 @@ impl From<IncreaseAdditionalValidatorStakeKeys>
         ]
     }
 }
-impl From<[Pubkey; INCREASE_ADDITIONAL_VALIDATOR_STAKE_IX_ACCOUNTS_LEN]>
-    for IncreaseAdditionalValidatorStakeKeys
+impl From<[Pubkey; DECREASE_VALIDATOR_STAKE_WITH_RESERVE_IX_ACCOUNTS_LEN]>
+    for DecreaseValidatorStakeWithReserveKeys
 {
-    fn from(pubkeys: [Pubkey; INCREASE_ADDITIONAL_VALIDATOR_STAKE_IX_ACCOUNTS_LEN]) -> Self {
+    fn from(pubkeys: [Pubkey; DECREASE_VALIDATOR_STAKE_WITH_RESERVE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             stake_pool: pubkeys[0],
             staker: pubkeys[1],
             withdraw_authority: pubkeys[2],
             validator_list: pubkeys[3],
             reserve_stake: pubkeys[4],
-            ephemeral_stake_account: pubkeys[5],
-            transient_stake_account: pubkeys[6],
-            validator_stake_account: pubkeys[7],
-            vote_account: pubkeys[8],
-            clock: pubkeys[9],
-            stake_history: pubkeys[10],
-            stake_config: pubkeys[11],
-            system_program: pubkeys[12],
-            stake_program: pubkeys[13],
+            validator_stake_account: pubkeys[5],
+            transient_stake_account: pubkeys[6],
+            clock: pubkeys[7],
+            stake_history: pubkeys[8],
+            system_program: pubkeys[9],
+            stake_program: pubkeys[10],
         }
     }
 }
-impl<'info> From<IncreaseAdditionalValidatorStakeAccounts<'_, 'info>>
-    for [AccountInfo<'info>; INCREASE_ADDITIONAL_VALIDATOR_STAKE_IX_ACCOUNTS_LEN]
+impl<'info> From<DecreaseValidatorStakeWithReserveAccounts<'_, 'info>>
+    for [AccountInfo<'info>; DECREASE_VALIDATOR_STAKE_WITH_RESERVE_IX_ACCOUNTS_LEN]
 {
-    fn from(accounts: IncreaseAdditionalValidatorStakeAccounts<'_, 'info>) -> Self {
+    fn from(accounts: DecreaseValidatorStakeWithReserveAccounts<'_, 'info>) -> Self {
         [
             accounts.stake_pool.clone(),
             accounts.staker.clone(),
             accounts.withdraw_authority.clone(),
             accounts.validator_list.clone(),
             accounts.reserve_stake.clone(),
-            accounts.ephemeral_stake_account.clone(),
-            accounts.transient_stake_account.clone(),
             accounts.validator_stake_account.clone(),
-            accounts.vote_account.clone(),
+            accounts.transient_stake_account.clone(),
             accounts.clock.clone(),
             accounts.stake_history.clone(),
-            accounts.stake_config.clone(),
             accounts.system_program.clone(),
             accounts.stake_program.clone(),
         ]
     }
 }
 impl<'me, 'info>
-    From<&'me [AccountInfo<'info>; INCREASE_ADDITIONAL_VALIDATOR_STAKE_IX_ACCOUNTS_LEN]>
-    for IncreaseAdditionalValidatorStakeAccounts<'me, 'info>
+    From<&'me [AccountInfo<'info>; DECREASE_VALIDATOR_STAKE_WITH_RESERVE_IX_ACCOUNTS_LEN]>
+    for DecreaseValidatorStakeWithReserveAccounts<'me, 'info>
 {
     fn from(
-        arr: &'me [AccountInfo<'info>; INCREASE_ADDITIONAL_VALIDATOR_STAKE_IX_ACCOUNTS_LEN],
+        arr: &'me [AccountInfo<'info>; DECREASE_VALIDATOR_STAKE_WITH_RESERVE_IX_ACCOUNTS_LEN],
     ) -> Self {
         Self {
             stake_pool: &arr[0],
@@ -3029,52 +6459,50 @@ impl<'me, 'info>
             withdraw_authority: &arr[2],
             validator_list: &arr[3],
             reserve_stake: &arr[4],
-            ephemeral_stake_account: &arr[5],
+            validator_stake_account: &arr[5],
             transient_stake_account: &arr[6],
-            validator_stake_account: &arr[7],
-            vote_account: &arr[8],
-            clock: &arr[9],
-            stake_history: &arr[10],
-            stake_config: &arr[11],
-            system_program: &arr[12],
-            stake_program: &arr[13],
+            clock: &arr[7],
+            stake_history: &arr[8],
+            system_program: &arr[9],
+            stake_program: &arr[10],
         }
     }
 }
-pub const INCREASE_ADDITIONAL_VALIDATOR_STAKE_IX_DISCM: u8 = 19u8;
+pub const DECREASE_VALIDATOR_STAKE_WITH_RESERVE_IX_DISCM: u8 = 21u8;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct IncreaseAdditionalValidatorStakeIxArgs {
-    pub args: AdditionalValidatorStakeArgs,
+pub struct DecreaseValidatorStakeWithReserveIxArgs {
+    pub lamports: u64,
+    pub transient_stake_seed: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
-pub struct IncreaseAdditionalValidatorStakeIxData(pub IncreaseAdditionalValidatorStakeIxArgs);
-impl From<IncreaseAdditionalValidatorStakeIxArgs> for IncreaseAdditionalValidatorStakeIxData {
-    fn from(args: IncreaseAdditionalValidatorStakeIxArgs) -> Self {
+pub struct DecreaseValidatorStakeWithReserveIxData(pub DecreaseValidatorStakeWithReserveIxArgs);
+impl From<DecreaseValidatorStakeWithReserveIxArgs> for DecreaseValidatorStakeWithReserveIxData {
+    fn from(args: DecreaseValidatorStakeWithReserveIxArgs) -> Self {
         Self(args)
     }
 }
-impl IncreaseAdditionalValidatorStakeIxData {
+impl DecreaseValidatorStakeWithReserveIxData {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
-        if maybe_discm != INCREASE_ADDITIONAL_VALIDATOR_STAKE_IX_DISCM {
+        if maybe_discm != DECREASE_VALIDATOR_STAKE_WITH_RESERVE_IX_DISCM {
             return Err(std::io::Error::new(
                 std::io::ErrorKind::Other,
                 format!(
                     "discm does not match. Expected: {:?}. Received: {:?}",
-                    INCREASE_ADDITIONAL_VALIDATOR_STAKE_IX_DISCM, maybe_discm
+                    DECREASE_VALIDATOR_STAKE_WITH_RESERVE_IX_DISCM, maybe_discm
                 ),
             ));
         }
-        Ok(Self(IncreaseAdditionalValidatorStakeIxArgs::deserialize(
+        Ok(Self(DecreaseValidatorStakeWithReserveIxArgs::deserialize(
             &mut reader,
         )?))
     }
     pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
-        writer.write_all(&[INCREASE_ADDITIONAL_VALIDATOR_STAKE_IX_DISCM])?;
+        writer.write_all(&[DECREASE_VALIDATOR_STAKE_WITH_RESERVE_IX_DISCM])?;
         self.0.serialize(&mut writer)
     }
     pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
@@ -3083,65 +6511,65 @@ impl IncreaseAdditionalValidatorStakeIxData {
         Ok(data)
     }
 }
-pub fn increase_additional_validator_stake_ix_with_program_id(
+pub fn decrease_validator_stake_with_reserve_ix_with_program_id(
     program_id: Pubkey,
-    keys: IncreaseAdditionalValidatorStakeKeys,
-    args: IncreaseAdditionalValidatorStakeIxArgs,
+    keys: DecreaseValidatorStakeWithReserveKeys,
+    args: DecreaseValidatorStakeWithReserveIxArgs,
 ) -> std::io::Result<Instruction> {
-    let metas: [AccountMeta; INCREASE_ADDITIONAL_VALIDATOR_STAKE_IX_ACCOUNTS_LEN] = keys.into();
-    let data: IncreaseAdditionalValidatorStakeIxData = args.into();
+    let metas: [AccountMeta; DECREASE_VALIDATOR_STAKE_WITH_RESERVE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: DecreaseValidatorStakeWithReserveIxData = args.into();
     Ok(Instruction {
         program_id,
         accounts: Vec::from(metas),
         data: data.try_to_vec()?,
     })
 }
-pub fn increase_additional_validator_stake_ix(
-    keys: IncreaseAdditionalValidatorStakeKeys,
-    args: IncreaseAdditionalValidatorStakeIxArgs,
+pub fn decrease_validator_stake_with_reserve_ix(
+    keys: DecreaseValidatorStakeWithReserveKeys,
+    args: DecreaseValidatorStakeWithReserveIxArgs,
 ) -> std::io::Result<Instruction> {
-    increase_additional_validator_stake_ix_with_program_id(crate::ID, keys, args)
+    decrease_validator_stake_with_reserve_ix_with_program_id(crate::ID, keys, args)
 }
-pub fn increase_additional_validator_stake_invoke_with_program_id(
+pub fn decrease_validator_stake_with_reserve_invoke_with_program_id(
     program_id: Pubkey,
-    accounts: IncreaseAdditionalValidatorStakeAccounts<'_, '_>,
-    args: IncreaseAdditionalValidatorStakeIxArgs,
+    accounts: DecreaseValidatorStakeWithReserveAccounts<'_, '_>,
+    args: DecreaseValidatorStakeWithReserveIxArgs,
 ) -> ProgramResult {
-    let keys: IncreaseAdditionalValidatorStakeKeys = accounts.into();
-    let ix = increase_additional_validator_stake_ix_with_program_id(program_id, keys, args)?;
+    let keys: DecreaseValidatorStakeWithReserveKeys = accounts.into();
+    let ix = decrease_validator_stake_with_reserve_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
-pub fn increase_additional_validator_stake_invoke(
-    accounts: IncreaseAdditionalValidatorStakeAccounts<'_, '_>,
-    args: IncreaseAdditionalValidatorStakeIxArgs,
+pub fn decrease_validator_stake_with_reserve_invoke(
+    accounts: DecreaseValidatorStakeWithReserveAccounts<'_, '_>,
+    args: DecreaseValidatorStakeWithReserveIxArgs,
 ) -> ProgramResult {
-    increase_additional_validator_stake_invoke_with_program_id(crate::ID, accounts, args)
+    decrease_validator_stake_with_reserve_invoke_with_program_id(crate::ID, accounts, args)
 }
-pub fn increase_additional_validator_stake_invoke_signed_with_program_id(
+pub fn decrease_validator_stake_with_reserve_invoke_signed_with_program_id(
     program_id: Pubkey,
-    accounts: IncreaseAdditionalValidatorStakeAccounts<'_, '_>,
-    args: IncreaseAdditionalValidatorStakeIxArgs,
+    accounts: DecreaseValidatorStakeWithReserveAccounts<'_, '_>,
+    args: DecreaseValidatorStakeWithReserveIxArgs,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let keys: IncreaseAdditionalValidatorStakeKeys = accounts.into();
-    let ix = increase_additional_validator_stake_ix_with_program_id(program_id, keys, args)?;
+    let keys: DecreaseValidatorStakeWithReserveKeys = accounts.into();
+    let ix = decrease_validator_stake_with_reserve_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
-pub fn increase_additional_validator_stake_invoke_signed(
-    accounts: IncreaseAdditionalValidatorStakeAccounts<'_, '_>,
-    args: IncreaseAdditionalValidatorStakeIxArgs,
+pub fn decrease_validator_stake_with_reserve_invoke_signed(
+    accounts: DecreaseValidatorStakeWithReserveAccounts<'_, '_>,
+    args: DecreaseValidatorStakeWithReserveIxArgs,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    increase_additional_validator_stake_invoke_signed_with_program_id(
+    decrease_validator_stake_with_reserve_invoke_signed_with_program_id(
         crate::ID,
         accounts,
         args,
         seeds,
     )
 }
-pub fn increase_additional_validator_stake_verify_account_keys(
-    accounts: IncreaseAdditionalValidatorStakeAccounts<'_, '_>,
-    keys: IncreaseAdditionalValidatorStakeKeys,
+pub fn decrease_validator_stake_with_reserve_verify_account_keys(
+    accounts: DecreaseValidatorStakeWithReserveAccounts<'_, '_>,
+    keys: DecreaseValidatorStakeWithReserveKeys,
 ) -> Result<(), (Pubkey, Pubkey)> {
     for (actual, expected) in [
         (accounts.stake_pool.key, &keys.stake_pool),
@@ -3150,21 +6578,15 @@ pub fn increase_additional_validator_stake_verify_account_keys(
         (accounts.validator_list.key, &keys.validator_list),
         (accounts.reserve_stake.key, &keys.reserve_stake),
         (
-            accounts.ephemeral_stake_account.key,
-            &keys.ephemeral_stake_account,
+            accounts.validator_stake_account.key,
+            &keys.validator_stake_account,
         ),
         (
             accounts.transient_stake_account.key,
             &keys.transient_stake_account,
         ),
-        (
-            accounts.validator_stake_account.key,
-            &keys.validator_stake_account,
-        ),
-        (accounts.vote_account.key, &keys.vote_account),
         (accounts.clock.key, &keys.clock),
         (accounts.stake_history.key, &keys.stake_history),
-        (accounts.stake_config.key, &keys.stake_config),
         (accounts.system_program.key, &keys.system_program),
         (accounts.stake_program.key, &keys.stake_program),
     ] {
@@ -3174,13 +6596,13 @@ pub fn increase_additional_validator_stake_verify_account_keys(
     }
     Ok(())
 }
-pub fn increase_additional_validator_stake_verify_writable_privileges<'me, 'info>(
-    accounts: IncreaseAdditionalValidatorStakeAccounts<'me, 'info>,
+pub fn decrease_validator_stake_with_reserve_verify_writable_privileges<'me, 'info>(
+    accounts: DecreaseValidatorStakeWithReserveAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
     for should_be_writable in [
         accounts.validator_list,
         accounts.reserve_stake,
-        accounts.ephemeral_stake_account,
+        accounts.validator_stake_account,
         accounts.transient_stake_account,
     ] {
         if !should_be_writable.is_writable {
@@ -3189,8 +6611,8 @@ pub fn increase_additional_validator_stake_verify_writable_privileges<'me, 'info
     }
     Ok(())
 }
-pub fn increase_additional_validator_stake_verify_signer_privileges<'me, 'info>(
-    accounts: IncreaseAdditionalValidatorStakeAccounts<'me, 'info>,
+pub fn decrease_validator_stake_with_reserve_verify_signer_privileges<'me, 'info>(
+    accounts: DecreaseValidatorStakeWithReserveAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
     for should_be_signer in [accounts.staker] {
         if !should_be_signer.is_signer {
@@ -3199,16 +6621,16 @@ pub fn increase_additional_validator_stake_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
-pub fn increase_additional_validator_stake_verify_account_privileges<'me, 'info>(
-    accounts: IncreaseAdditionalValidatorStakeAccounts<'me, 'info>,
+pub fn decrease_validator_stake_with_reserve_verify_account_privileges<'me, 'info>(
+    accounts: DecreaseValidatorStakeWithReserveAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
-    increase_additional_validator_stake_verify_writable_privileges(accounts)?;
-    increase_additional_validator_stake_verify_signer_privileges(accounts)?;
+    decrease_validator_stake_with_reserve_verify_writable_privileges(accounts)?;
+    decrease_validator_stake_with_reserve_verify_signer_privileges(accounts)?;
     Ok(())
 }
-pub const DECREASE_ADDITIONAL_VALIDATOR_STAKE_IX_ACCOUNTS_LEN: usize = 12;
+pub const REDELEGATE_IX_ACCOUNTS_LEN: usize = 16;
 #[derive(Copy, Clone, Debug)]
-pub struct DecreaseAdditionalValidatorStakeAccounts<'me, 'info> {
+pub struct RedelegateAccounts<'me, 'info> {
     ///Stake pool
     pub stake_pool: &'me AccountInfo<'info>,
     ///Current staker
@@ -3217,25 +6639,33 @@ pub struct DecreaseAdditionalValidatorStakeAccounts<'me, 'info> {
     pub withdraw_authority: &'me AccountInfo<'info>,
     ///Validator list
     pub validator_list: &'me AccountInfo<'info>,
-    ///Reserve stake account
+    ///Reserve stake account, to withdraw rent exempt reserve
     pub reserve_stake: &'me AccountInfo<'info>,
-    ///Validator stake account to split stake from
-    pub validator_stake_account: &'me AccountInfo<'info>,
-    ///Uninitialized ephemeral stake account to receive stake
+    ///Source validator stake account to split from
+    pub source_validator_stake_account: &'me AccountInfo<'info>,
+    ///Source transient stake account to receive split and be redelegated
+    pub source_transient_stake_account: &'me AccountInfo<'info>,
+    ///Uninitialized ephemeral stake account to receive redelegation
     pub ephemeral_stake_account: &'me AccountInfo<'info>,
-    ///Transient stake account
-    pub transient_stake_account: &'me AccountInfo<'info>,
+    ///Destination transient stake account to receive ephemeral stake by merge
+    pub destination_transient_stake_account: &'me AccountInfo<'info>,
+    ///Destination validator stake account to receive transient stake after activation
+    pub destination_validator_stake_account: &'me AccountInfo<'info>,
+    ///Destination validator vote account
+    pub destination_vote_account: &'me AccountInfo<'info>,
     ///Clock sysvar
     pub clock: &'me AccountInfo<'info>,
     ///Stake history sysvar
     pub stake_history: &'me AccountInfo<'info>,
+    ///Stake config sysvar
+    pub stake_config: &'me AccountInfo<'info>,
     ///System program
     pub system_program: &'me AccountInfo<'info>,
     ///Stake program
     pub stake_program: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug)]
-pub struct DecreaseAdditionalValidatorStakeKeys {
+pub struct RedelegateKeys {
     ///Stake pool
     pub stake_pool: Pubkey,
     ///Current staker
@@ -3244,47 +6674,55 @@ pub struct DecreaseAdditionalValidatorStakeKeys {
     pub withdraw_authority: Pubkey,
     ///Validator list
     pub validator_list: Pubkey,
-    ///Reserve stake account
+    ///Reserve stake account, to withdraw rent exempt reserve
     pub reserve_stake: Pubkey,
-    ///Validator stake account to split stake from
-    pub validator_stake_account: Pubkey,
-    ///Uninitialized ephemeral stake account to receive stake
+    ///Source validator stake account to split from
+    pub source_validator_stake_account: Pubkey,
+    ///Source transient stake account to receive split and be redelegated
+    pub source_transient_stake_account: Pubkey,
+    ///Uninitialized ephemeral stake account to receive redelegation
     pub ephemeral_stake_account: Pubkey,
-    ///Transient stake account
-    pub transient_stake_account: Pubkey,
+    ///Destination transient stake account to receive ephemeral stake by merge
+    pub destination_transient_stake_account: Pubkey,
+    ///Destination validator stake account to receive transient stake after activation
+    pub destination_validator_stake_account: Pubkey,
+    ///Destination validator vote account
+    pub destination_vote_account: Pubkey,
     ///Clock sysvar
     pub clock: Pubkey,
     ///Stake history sysvar
     pub stake_history: Pubkey,
+    ///Stake config sysvar
+    pub stake_config: Pubkey,
     ///System program
     pub system_program: Pubkey,
     ///Stake program
     pub stake_program: Pubkey,
 }
-impl From<DecreaseAdditionalValidatorStakeAccounts<'_, '_>>
-    for DecreaseAdditionalValidatorStakeKeys
-{
-    fn from(accounts: DecreaseAdditionalValidatorStakeAccounts) -> Self {
+impl From<RedelegateAccounts<'_, '_>> for RedelegateKeys {
+    fn from(accounts: RedelegateAccounts) -> Self {
         Self {
             stake_pool: *accounts.stake_pool.key,
             staker: *accounts.staker.key,
             withdraw_authority: *accounts.withdraw_authority.key,
             validator_list: *accounts.validator_list.key,
             reserve_stake: *accounts.reserve_stake.key,
-            validator_stake_account: *accounts.validator_stake_account.key,
+            source_validator_stake_account: *accounts.source_validator_stake_account.key,
+            source_transient_stake_account: *accounts.source_transient_stake_account.key,
             ephemeral_stake_account: *accounts.ephemeral_stake_account.key,
-            transient_stake_account: *accounts.transient_stake_account.key,
+            destination_transient_stake_account: *accounts.destination_transient_stake_account.key,
+            destination_validator_stake_account: *accounts.destination_validator_stake_account.key,
+            destination_vote_account: *accounts.destination_vote_account.key,
             clock: *accounts.clock.key,
             stake_history: *accounts.stake_history.key,
+            stake_config: *accounts.stake_config.key,
             system_program: *accounts.system_program.key,
             stake_program: *accounts.stake_program.key,
         }
     }
 }
-impl From<DecreaseAdditionalValidatorStakeKeys>
-    for [AccountMeta; DECREASE_ADDITIONAL_VALIDATOR_STAKE_IX_ACCOUNTS_LEN]
-{
-    fn from(keys: DecreaseAdditionalValidatorStakeKeys) -> Self {
+impl From<RedelegateKeys> for [AccountMeta; REDELEGATE_IX_ACCOUNTS_LEN] {
+    fn from(keys: RedelegateKeys) -> Self {
         [
             AccountMeta {
                 pubkey: keys.stake_pool,
@@ -3312,7 +6750,12 @@ impl From<DecreaseAdditionalValidatorStakeKeys>
                 is_writable: true,
             },
             AccountMeta {
-                pubkey: keys.validator_stake_account,
+                pubkey: keys.source_validator_stake_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.source_transient_stake_account,
                 is_signer: false,
                 is_writable: true,
             },
@@ -3322,10 +6765,20 @@ impl From<DecreaseAdditionalValidatorStakeKeys>
                 is_writable: true,
             },
             AccountMeta {
-                pubkey: keys.transient_stake_account,
+                pubkey: keys.destination_transient_stake_account,
                 is_signer: false,
                 is_writable: true,
             },
+            AccountMeta {
+                pubkey: keys.destination_validator_stake_account,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.destination_vote_account,
+                is_signer: false,
+                is_writable: false,
+            },
             AccountMeta {
                 pubkey: keys.clock,
                 is_signer: false,
@@ -3336,6 +6789,11 @@ impl From<DecreaseAdditionalValidatorStakeKeys>
                 is_signer: false,
                 is_writable: false,
             },
+            AccountMeta {
+                pubkey: keys.stake_config,
+                is_signer: false,
+                is_writable: false,
+            },
             AccountMeta {
                 pubkey: keys.system_program,
                 is_signer: false,
@@ -3349,103 +6807,111 @@ impl From<DecreaseAdditionalValidatorStakeKeys>
         ]
     }
 }
-impl From<[Pubkey; DECREASE_ADDITIONAL_VALIDATOR_STAKE_IX_ACCOUNTS_LEN]>
-    for DecreaseAdditionalValidatorStakeKeys
-{
-    fn from(pubkeys: [Pubkey; DECREASE_ADDITIONAL_VALIDATOR_STAKE_IX_ACCOUNTS_LEN]) -> Self {
+impl From<[Pubkey; REDELEGATE_IX_ACCOUNTS_LEN]> for RedelegateKeys {
+    fn from(pubkeys: [Pubkey; REDELEGATE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             stake_pool: pubkeys[0],
             staker: pubkeys[1],
             withdraw_authority: pubkeys[2],
             validator_list: pubkeys[3],
             reserve_stake: pubkeys[4],
-            validator_stake_account: pubkeys[5],
-            ephemeral_stake_account: pubkeys[6],
-            transient_stake_account: pubkeys[7],
-            clock: pubkeys[8],
-            stake_history: pubkeys[9],
-            system_program: pubkeys[10],
-            stake_program: pubkeys[11],
+            source_validator_stake_account: pubkeys[5],
+            source_transient_stake_account: pubkeys[6],
+            ephemeral_stake_account: pubkeys[7],
+            destination_transient_stake_account: pubkeys[8],
+            destination_validator_stake_account: pubkeys[9],
+            destination_vote_account: pubkeys[10],
+            clock: pubkeys[11],
+            stake_history: pubkeys[12],
+            stake_config: pubkeys[13],
+            system_program: pubkeys[14],
+            stake_program: pubkeys[15],
         }
     }
 }
-impl<'info> From<DecreaseAdditionalValidatorStakeAccounts<'_, 'info>>
-    for [AccountInfo<'info>; DECREASE_ADDITIONAL_VALIDATOR_STAKE_IX_ACCOUNTS_LEN]
+impl<'info> From<RedelegateAccounts<'_, 'info>>
+    for [AccountInfo<'info>; REDELEGATE_IX_ACCOUNTS_LEN]
 {
-    fn from(accounts: DecreaseAdditionalValidatorStakeAccounts<'_, 'info>) -> Self {
+    fn from(accounts: RedelegateAccounts<'_, 'info>) -> Self {
         [
             accounts.stake_pool.clone(),
             accounts.staker.clone(),
             accounts.withdraw_authority.clone(),
             accounts.validator_list.clone(),
             accounts.reserve_stake.clone(),
-            accounts.validator_stake_account.clone(),
+            accounts.source_validator_stake_account.clone(),
+            accounts.source_transient_stake_account.clone(),
             accounts.ephemeral_stake_account.clone(),
-            accounts.transient_stake_account.clone(),
+            accounts.destination_transient_stake_account.clone(),
+            accounts.destination_validator_stake_account.clone(),
+            accounts.destination_vote_account.clone(),
             accounts.clock.clone(),
             accounts.stake_history.clone(),
+            accounts.stake_config.clone(),
             accounts.system_program.clone(),
             accounts.stake_program.clone(),
         ]
     }
 }
-impl<'me, 'info>
-    From<&'me [AccountInfo<'info>; DECREASE_ADDITIONAL_VALIDATOR_STAKE_IX_ACCOUNTS_LEN]>
-    for DecreaseAdditionalValidatorStakeAccounts<'me, 'info>
+impl<'me, 'info> From<&'me [AccountInfo<'info>; REDELEGATE_IX_ACCOUNTS_LEN]>
+    for RedelegateAccounts<'me, 'info>
 {
-    fn from(
-        arr: &'me [AccountInfo<'info>; DECREASE_ADDITIONAL_VALIDATOR_STAKE_IX_ACCOUNTS_LEN],
-    ) -> Self {
+    fn from(arr: &'me [AccountInfo<'info>; REDELEGATE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             stake_pool: &arr[0],
             staker: &arr[1],
             withdraw_authority: &arr[2],
             validator_list: &arr[3],
             reserve_stake: &arr[4],
-            validator_stake_account: &arr[5],
-            ephemeral_stake_account: &arr[6],
-            transient_stake_account: &arr[7],
-            clock: &arr[8],
-            stake_history: &arr[9],
-            system_program: &arr[10],
-            stake_program: &arr[11],
+            source_validator_stake_account: &arr[5],
+            source_transient_stake_account: &arr[6],
+            ephemeral_stake_account: &arr[7],
+            destination_transient_stake_account: &arr[8],
+            destination_validator_stake_account: &arr[9],
+            destination_vote_account: &arr[10],
+            clock: &arr[11],
+            stake_history: &arr[12],
+            stake_config: &arr[13],
+            system_program: &arr[14],
+            stake_program: &arr[15],
         }
     }
 }
-pub const DECREASE_ADDITIONAL_VALIDATOR_STAKE_IX_DISCM: u8 = 20u8;
+pub const REDELEGATE_IX_DISCM: u8 = 22u8;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct DecreaseAdditionalValidatorStakeIxArgs {
-    pub args: AdditionalValidatorStakeArgs,
+pub struct RedelegateIxArgs {
+    pub lamports: u64,
+    pub source_transient_stake_seed: u64,
+    pub ephemeral_stake_seed: u64,
+    pub destination_transient_stake_seed: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
-pub struct DecreaseAdditionalValidatorStakeIxData(pub DecreaseAdditionalValidatorStakeIxArgs);
-impl From<DecreaseAdditionalValidatorStakeIxArgs> for DecreaseAdditionalValidatorStakeIxData {
-    fn from(args: DecreaseAdditionalValidatorStakeIxArgs) -> Self {
+pub struct RedelegateIxData(pub RedelegateIxArgs);
+impl From<RedelegateIxArgs> for RedelegateIxData {
+    fn from(args: RedelegateIxArgs) -> Self {
         Self(args)
     }
 }
-impl DecreaseAdditionalValidatorStakeIxData {
+impl RedelegateIxData {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
-        if maybe_discm != DECREASE_ADDITIONAL_VALIDATOR_STAKE_IX_DISCM {
+        if maybe_discm != REDELEGATE_IX_DISCM {
             return Err(std::io::Error::new(
                 std::io::ErrorKind::Other,
                 format!(
                     "discm does not match. Expected: {:?}. Received: {:?}",
-                    DECREASE_ADDITIONAL_VALIDATOR_STAKE_IX_DISCM, maybe_discm
+                    REDELEGATE_IX_DISCM, maybe_discm
                 ),
             ));
         }
-        Ok(Self(DecreaseAdditionalValidatorStakeIxArgs::deserialize(
-            &mut reader,
-        )?))
+        Ok(Self(RedelegateIxArgs::deserialize(&mut reader)?))
     }
     pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
-        writer.write_all(&[DECREASE_ADDITIONAL_VALIDATOR_STAKE_IX_DISCM])?;
+        writer.write_all(&[REDELEGATE_IX_DISCM])?;
         self.0.serialize(&mut writer)
     }
     pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
@@ -3454,65 +6920,57 @@ impl DecreaseAdditionalValidatorStakeIxData {
         Ok(data)
     }
 }
-pub fn decrease_additional_validator_stake_ix_with_program_id(
+pub fn redelegate_ix_with_program_id(
     program_id: Pubkey,
-    keys: DecreaseAdditionalValidatorStakeKeys,
-    args: DecreaseAdditionalValidatorStakeIxArgs,
+    keys: RedelegateKeys,
+    args: RedelegateIxArgs,
 ) -> std::io::Result<Instruction> {
-    let metas: [AccountMeta; DECREASE_ADDITIONAL_VALIDATOR_STAKE_IX_ACCOUNTS_LEN] = keys.into();
-    let data: DecreaseAdditionalValidatorStakeIxData = args.into();
+    let metas: [AccountMeta; REDELEGATE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: RedelegateIxData = args.into();
     Ok(Instruction {
         program_id,
         accounts: Vec::from(metas),
         data: data.try_to_vec()?,
     })
 }
-pub fn decrease_additional_validator_stake_ix(
-    keys: DecreaseAdditionalValidatorStakeKeys,
-    args: DecreaseAdditionalValidatorStakeIxArgs,
-) -> std::io::Result<Instruction> {
-    decrease_additional_validator_stake_ix_with_program_id(crate::ID, keys, args)
+pub fn redelegate_ix(keys: RedelegateKeys, args: RedelegateIxArgs) -> std::io::Result<Instruction> {
+    redelegate_ix_with_program_id(crate::ID, keys, args)
 }
-pub fn decrease_additional_validator_stake_invoke_with_program_id(
+pub fn redelegate_invoke_with_program_id(
     program_id: Pubkey,
-    accounts: DecreaseAdditionalValidatorStakeAccounts<'_, '_>,
-    args: DecreaseAdditionalValidatorStakeIxArgs,
+    accounts: RedelegateAccounts<'_, '_>,
+    args: RedelegateIxArgs,
 ) -> ProgramResult {
-    let keys: DecreaseAdditionalValidatorStakeKeys = accounts.into();
-    let ix = decrease_additional_validator_stake_ix_with_program_id(program_id, keys, args)?;
+    let keys: RedelegateKeys = accounts.into();
+    let ix = redelegate_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
-pub fn decrease_additional_validator_stake_invoke(
-    accounts: DecreaseAdditionalValidatorStakeAccounts<'_, '_>,
-    args: DecreaseAdditionalValidatorStakeIxArgs,
+pub fn redelegate_invoke(
+    accounts: RedelegateAccounts<'_, '_>,
+    args: RedelegateIxArgs,
 ) -> ProgramResult {
-    decrease_additional_validator_stake_invoke_with_program_id(crate::ID, accounts, args)
+    redelegate_invoke_with_program_id(crate::ID, accounts, args)
 }
-pub fn decrease_additional_validator_stake_invoke_signed_with_program_id(
+pub fn redelegate_invoke_signed_with_program_id(
     program_id: Pubkey,
-    accounts: DecreaseAdditionalValidatorStakeAccounts<'_, '_>,
-    args: DecreaseAdditionalValidatorStakeIxArgs,
+    accounts: RedelegateAccounts<'_, '_>,
+    args: RedelegateIxArgs,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let keys: DecreaseAdditionalValidatorStakeKeys = accounts.into();
-    let ix = decrease_additional_validator_stake_ix_with_program_id(program_id, keys, args)?;
+    let keys: RedelegateKeys = accounts.into();
+    let ix = redelegate_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
-pub fn decrease_additional_validator_stake_invoke_signed(
-    accounts: DecreaseAdditionalValidatorStakeAccounts<'_, '_>,
-    args: DecreaseAdditionalValidatorStakeIxArgs,
+pub fn redelegate_invoke_signed(
+    accounts: RedelegateAccounts<'_, '_>,
+    args: RedelegateIxArgs,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    decrease_additional_validator_stake_invoke_signed_with_program_id(
-        crate::ID,
-        accounts,
-        args,
-        seeds,
-    )
+    redelegate_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
-pub fn decrease_additional_validator_stake_verify_account_keys(
-    accounts: DecreaseAdditionalValidatorStakeAccounts<'_, '_>,
-    keys: DecreaseAdditionalValidatorStakeKeys,
+pub fn redelegate_verify_account_keys(
+    accounts: RedelegateAccounts<'_, '_>,
+    keys: RedelegateKeys,
 ) -> Result<(), (Pubkey, Pubkey)> {
     for (actual, expected) in [
         (accounts.stake_pool.key, &keys.stake_pool),
@@ -3521,19 +6979,32 @@ pub fn decrease_additional_validator_stake_verify_account_keys(
         (accounts.validator_list.key, &keys.validator_list),
         (accounts.reserve_stake.key, &keys.reserve_stake),
         (
-            accounts.validator_stake_account.key,
-            &keys.validator_stake_account,
+            accounts.source_validator_stake_account.key,
+            &keys.source_validator_stake_account,
+        ),
+        (
+            accounts.source_transient_stake_account.key,
+            &keys.source_transient_stake_account,
         ),
         (
             accounts.ephemeral_stake_account.key,
             &keys.ephemeral_stake_account,
         ),
         (
-            accounts.transient_stake_account.key,
-            &keys.transient_stake_account,
+            accounts.destination_transient_stake_account.key,
+            &keys.destination_transient_stake_account,
+        ),
+        (
+            accounts.destination_validator_stake_account.key,
+            &keys.destination_validator_stake_account,
+        ),
+        (
+            accounts.destination_vote_account.key,
+            &keys.destination_vote_account,
         ),
         (accounts.clock.key, &keys.clock),
         (accounts.stake_history.key, &keys.stake_history),
+        (accounts.stake_config.key, &keys.stake_config),
         (accounts.system_program.key, &keys.system_program),
         (accounts.stake_program.key, &keys.stake_program),
     ] {
@@ -3543,15 +7014,16 @@ pub fn decrease_additional_validator_stake_verify_account_keys(
     }
     Ok(())
 }
-pub fn decrease_additional_validator_stake_verify_writable_privileges<'me, 'info>(
-    accounts: DecreaseAdditionalValidatorStakeAccounts<'me, 'info>,
+pub fn redelegate_verify_writable_privileges<'me, 'info>(
+    accounts: RedelegateAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
     for should_be_writable in [
         accounts.validator_list,
         accounts.reserve_stake,
-        accounts.validator_stake_account,
+        accounts.source_validator_stake_account,
+        accounts.source_transient_stake_account,
         accounts.ephemeral_stake_account,
-        accounts.transient_stake_account,
+        accounts.destination_transient_stake_account,
     ] {
         if !should_be_writable.is_writable {
             return Err((should_be_writable, ProgramError::InvalidAccountData));
@@ -3559,8 +7031,8 @@ pub fn decrease_additional_validator_stake_verify_writable_privileges<'me, 'info
     }
     Ok(())
 }
-pub fn decrease_additional_validator_stake_verify_signer_privileges<'me, 'info>(
-    accounts: DecreaseAdditionalValidatorStakeAccounts<'me, 'info>,
+pub fn redelegate_verify_signer_privileges<'me, 'info>(
+    accounts: RedelegateAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
     for should_be_signer in [accounts.staker] {
         if !should_be_signer.is_signer {
@@ -3569,11 +7041,11 @@ pub fn decrease_additional_validator_stake_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
-pub fn decrease_additional_validator_stake_verify_account_privileges<'me, 'info>(
-    accounts: DecreaseAdditionalValidatorStakeAccounts<'me, 'info>,
+pub fn redelegate_verify_account_privileges<'me, 'info>(
+    accounts: RedelegateAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
-    decrease_additional_validator_stake_verify_writable_privileges(accounts)?;
-    decrease_additional_validator_stake_verify_signer_privileges(accounts)?;
+    redelegate_verify_writable_privileges(accounts)?;
+    redelegate_verify_signer_privileges(accounts)?;
     Ok(())
 }
 pub const DEPOSIT_STAKE_WITH_SLIPPAGE_IX_ACCOUNTS_LEN: usize = 15;
@@ -4334,7 +7806,7 @@ pub fn withdraw_stake_with_slippage_verify_account_privileges<'me, 'info>(
     withdraw_stake_with_slippage_verify_signer_privileges(accounts)?;
     Ok(())
 }
-pub const DEPOSIT_SOL_WITH_SLIPPAGE_IX_ACCOUNTS_LEN: usize = 9;
+pub const DEPOSIT_SOL_WITH_SLIPPAGE_IX_ACCOUNTS_LEN: usize = 10;
 #[derive(Copy, Clone, Debug)]
 pub struct DepositSolWithSlippageAccounts<'me, 'info> {
     ///Stake pool
@@ -4349,6 +7821,8 @@ pub struct DepositSolWithSlippageAccounts<'me, 'info> {
     pub mint_to: &'me AccountInfo<'info>,
     ///Manager fee account
     pub manager_fee_account: &'me AccountInfo<'info>,
+    ///LST token account to receive referral fees
+    pub referral_fee_dest: &'me AccountInfo<'info>,
     ///Pool token mint
     pub pool_mint: &'me AccountInfo<'info>,
     ///System program
@@ -4370,6 +7844,8 @@ pub struct DepositSolWithSlippageKeys {
     pub mint_to: Pubkey,
     ///Manager fee account
     pub manager_fee_account: Pubkey,
+    ///LST token account to receive referral fees
+    pub referral_fee_dest: Pubkey,
     ///Pool token mint
     pub pool_mint: Pubkey,
     ///System program
@@ -4386,6 +7862,7 @@ impl From<DepositSolWithSlippageAccounts<'_, '_>> for DepositSolWithSlippageKeys
             deposit_from: *accounts.deposit_from.key,
             mint_to: *accounts.mint_to.key,
             manager_fee_account: *accounts.manager_fee_account.key,
+            referral_fee_dest: *accounts.referral_fee_dest.key,
             pool_mint: *accounts.pool_mint.key,
             system_program: *accounts.system_program.key,
             token_program: *accounts.token_program.key,
@@ -4425,6 +7902,11 @@ impl From<DepositSolWithSlippageKeys> for [AccountMeta; DEPOSIT_SOL_WITH_SLIPPAG
                 is_signer: false,
                 is_writable: true,
             },
+            AccountMeta {
+                pubkey: keys.referral_fee_dest,
+                is_signer: false,
+                is_writable: true,
+            },
             AccountMeta {
                 pubkey: keys.pool_mint,
                 is_signer: false,
@@ -4452,9 +7934,10 @@ impl From<[Pubkey; DEPOSIT_SOL_WITH_SLIPPAGE_IX_ACCOUNTS_LEN]> for DepositSolWit
             deposit_from: pubkeys[3],
             mint_to: pubkeys[4],
             manager_fee_account: pubkeys[5],
-            pool_mint: pubkeys[6],
-            system_program: pubkeys[7],
-            token_program: pubkeys[8],
+            referral_fee_dest: pubkeys[6],
+            pool_mint: pubkeys[7],
+            system_program: pubkeys[8],
+            token_program: pubkeys[9],
         }
     }
 }
@@ -4469,6 +7952,7 @@ impl<'info> From<DepositSolWithSlippageAccounts<'_, 'info>>
             accounts.deposit_from.clone(),
             accounts.mint_to.clone(),
             accounts.manager_fee_account.clone(),
+            accounts.referral_fee_dest.clone(),
             accounts.pool_mint.clone(),
             accounts.system_program.clone(),
             accounts.token_program.clone(),
@@ -4486,9 +7970,10 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; DEPOSIT_SOL_WITH_SLIPPAGE_IX_ACC
             deposit_from: &arr[3],
             mint_to: &arr[4],
             manager_fee_account: &arr[5],
-            pool_mint: &arr[6],
-            system_program: &arr[7],
-            token_program: &arr[8],
+            referral_fee_dest: &arr[6],
+            pool_mint: &arr[7],
+            system_program: &arr[8],
+            token_program: &arr[9],
         }
     }
 }
@@ -4597,6 +8082,7 @@ pub fn deposit_sol_with_slippage_verify_account_keys(
         (accounts.deposit_from.key, &keys.deposit_from),
         (accounts.mint_to.key, &keys.mint_to),
         (accounts.manager_fee_account.key, &keys.manager_fee_account),
+        (accounts.referral_fee_dest.key, &keys.referral_fee_dest),
         (accounts.pool_mint.key, &keys.pool_mint),
         (accounts.system_program.key, &keys.system_program),
         (accounts.token_program.key, &keys.token_program),
@@ -4616,6 +8102,7 @@ pub fn deposit_sol_with_slippage_verify_writable_privileges<'me, 'info>(
         accounts.deposit_from,
         accounts.mint_to,
         accounts.manager_fee_account,
+        accounts.referral_fee_dest,
         accounts.pool_mint,
     ] {
         if !should_be_writable.is_writable {
@@ -0,0 +1,145 @@
+use solana_program::{
+    decode_error::DecodeError,
+    msg,
+    program_error::{PrintProgramError, ProgramError},
+};
+use thiserror::Error;
+#[derive(Clone, Copy, Debug, Eq, Error, num_derive::FromPrimitive, PartialEq)]
+pub enum SplToken2022Error {
+    #[error("Lamport balance below rent-exempt threshold")]
+    NotRentExempt = 0,
+    #[error("Insufficient funds")]
+    InsufficientFunds = 1,
+    #[error("Invalid Mint")]
+    InvalidMint = 2,
+    #[error("Account not associated with this Mint")]
+    MintMismatch = 3,
+    #[error("Owner does not match")]
+    OwnerMismatch = 4,
+    #[error("Fixed supply")]
+    FixedSupply = 5,
+    #[error("Already in use")]
+    AlreadyInUse = 6,
+    #[error("Invalid number of provided signers")]
+    InvalidNumberOfProvidedSigners = 7,
+    #[error("Invalid number of required signers")]
+    InvalidNumberOfRequiredSigners = 8,
+    #[error("State is uninitialized")]
+    UninitializedState = 9,
+    #[error("Instruction does not support native tokens")]
+    NativeNotSupported = 10,
+    #[error("Non-native account can only be closed if its balance is zero")]
+    NonNativeHasBalance = 11,
+    #[error("Invalid instruction")]
+    InvalidInstruction = 12,
+    #[error("State is invalid for requested operation")]
+    InvalidState = 13,
+    #[error("Operation overflowed")]
+    Overflow = 14,
+    #[error("Account does not support specified authority type")]
+    AuthorityTypeNotSupported = 15,
+    #[error("This token mint cannot freeze accounts")]
+    MintCannotFreeze = 16,
+    #[error("Account is frozen")]
+    AccountFrozen = 17,
+    #[error("The provided decimals value different from the Mint decimals")]
+    MintDecimalsMismatch = 18,
+    #[error("Instruction does not support non-native tokens")]
+    NonNativeNotSupported = 19,
+    #[error("Extension type does not match already existing extensions")]
+    ExtensionTypeMismatch = 20,
+    #[error("Extension does not match the base type provided")]
+    ExtensionBaseMismatch = 21,
+    #[error("Extension already initialized on this account")]
+    ExtensionAlreadyInitialized = 22,
+    #[error("An account can only be closed if its confidential balance is zero")]
+    ConfidentialTransferAccountHasBalance = 23,
+    #[error("Account not approved for confidential transfers")]
+    ConfidentialTransferAccountNotApproved = 24,
+    #[error("Account not accepting deposits or transfers")]
+    ConfidentialTransferDepositsAndTransfersDisabled = 25,
+    #[error("ElGamal public key mismatch")]
+    ConfidentialTransferElGamalPubkeyMismatch = 26,
+    #[error("Balance mismatch")]
+    ConfidentialTransferBalanceMismatch = 27,
+    #[error("Mint has non-zero supply. Burn all tokens before closing the mint")]
+    MintHasSupply = 28,
+    #[error("No authority exists to perform the desired operation")]
+    NoAuthorityExists = 29,
+    #[error("Transfer fee exceeds maximum of 10,000 basis points")]
+    TransferFeeExceedsMaximum = 30,
+    #[error("Mint required for this account to transfer tokens, use `transfer_checked` or `transfer_checked_with_fee`")]
+    MintRequiredForTransfer = 31,
+    #[error("Calculated fee does not match expected fee")]
+    FeeMismatch = 32,
+    #[error("The owner authority cannot be changed")]
+    ImmutableOwner = 33,
+    #[error("An account can only be closed if its withheld fee balance is zero, harvest fees to the mint and try again")]
+    AccountHasWithheldTransferFees = 34,
+    #[error("No memo in previous instruction; required for recipient to receive a transfer")]
+    NoMemo = 35,
+    #[error("Transfer is disabled for this mint")]
+    NonTransferable = 36,
+    #[error("Non-transferable tokens can't be minted to an account without immutable ownership")]
+    NonTransferableNeedsImmutableOwnership = 37,
+    #[error("Deposit amount exceeds maximum limit")]
+    MaximumDepositAmountExceeded = 38,
+    #[error("CPI Guard cannot be enabled or disabled in CPI")]
+    CpiGuardSettingsLocked = 39,
+    #[error("CPI Guard is enabled, and a program attempted to transfer user funds via CPI without using a delegate")]
+    CpiGuardTransferBlocked = 40,
+    #[error("CPI Guard is enabled, and a program attempted to close an account via CPI without returning lamports to owner")]
+    CpiGuardCloseAccountBlocked = 41,
+    #[error("CPI Guard is enabled, and a program attempted to approve a delegate via CPI")]
+    CpiGuardApproveBlocked = 42,
+    #[error("Account ownership cannot be changed while CPI Guard is enabled")]
+    CpiGuardOwnerChangeBlocked = 43,
+    #[error("Extension not found in account data")]
+    ExtensionNotFound = 44,
+    #[error("Non-confidential transfers disabled")]
+    NonConfidentialTransfersDisabled = 45,
+    #[error("An account can only be closed if the confidential withheld fee is zero")]
+    ConfidentialTransferFeeAccountHasWithheldFee = 46,
+    #[error("A mint or an account is initialized to an invalid combination of extensions")]
+    InvalidExtensionCombination = 47,
+    #[error("Extension allocation with overwrite must use the same length")]
+    InvalidLengthForAlloc = 48,
+    #[error("Failed to decrypt a confidential transfer account")]
+    AccountDecryption = 49,
+    #[error("Failed to generate proof")]
+    ProofGeneration = 50,
+    #[error("An invalid proof instruction offset was provided")]
+    InvalidProofInstructionOffset = 51,
+    #[error("Harvest of withheld tokens to mint is disabled")]
+    HarvestToMintDisabled = 52,
+    #[error("Split proof context state accounts not supported for instruction")]
+    SplitProofContextStateAccountsNotSupported = 53,
+    #[error("Not enough proof context state accounts provided")]
+    NotEnoughProofContextStateAccounts = 54,
+    #[error("Ciphertext is malformed")]
+    MalformedCiphertext = 55,
+    #[error("Ciphertext arithmetic failed")]
+    CiphertextArithmeticFailed = 56,
+}
+impl From<SplToken2022Error> for ProgramError {
+    fn from(e: SplToken2022Error) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
+impl<T> DecodeError<T> for SplToken2022Error {
+    fn type_of() -> &'static str {
+        "SplToken2022Error"
+    }
+}
+impl PrintProgramError for SplToken2022Error {
+    fn print<E>(&self)
+    where
+        E: 'static
+            + std::error::Error
+            + DecodeError<E>
+            + PrintProgramError
+            + num_traits::FromPrimitive,
+    {
+        msg!(&self.to_string());
+    }
+}
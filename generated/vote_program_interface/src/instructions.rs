@@ -0,0 +1,2164 @@
+use crate::*;
+use serde::{Deserialize, Serialize};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum VoteProgramProgramIx {
+    InitializeAccount(InitializeAccountIxArgs),
+    Authorize(AuthorizeIxArgs),
+    Vote(VoteIxArgs),
+    Withdraw(WithdrawIxArgs),
+    UpdateValidatorIdentity,
+    UpdateCommission(UpdateCommissionIxArgs),
+    VoteSwitch(VoteSwitchIxArgs),
+    AuthorizeChecked(AuthorizeCheckedIxArgs),
+    UpdateVoteState(UpdateVoteStateIxArgs),
+    UpdateVoteStateSwitch(UpdateVoteStateSwitchIxArgs),
+    AuthorizeWithSeed(AuthorizeWithSeedIxArgs),
+    AuthorizeCheckedWithSeed(AuthorizeCheckedWithSeedIxArgs),
+}
+fn invoke_instruction<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
+    ix: &Instruction,
+    accounts: A,
+) -> ProgramResult {
+    let account_info: [AccountInfo<'info>; N] = accounts.into();
+    invoke(ix, &account_info)
+}
+fn invoke_instruction_signed<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
+    ix: &Instruction,
+    accounts: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let account_info: [AccountInfo<'info>; N] = accounts.into();
+    invoke_signed(ix, &account_info, seeds)
+}
+pub const INITIALIZE_ACCOUNT_IX_DISCM: [u8; 4] = [0, 0, 0, 0];
+pub fn initialize_account_ix_with_program_id(
+    program_id: Pubkey,
+    keys: InitializeAccountKeys,
+    args: InitializeAccountIxArgs,
+) -> Instruction {
+    let metas: [AccountMeta; INITIALIZE_ACCOUNT_IX_ACCOUNTS_LEN] = keys.into();
+    Instruction::new_with_bincode(
+        program_id,
+        &VoteProgramProgramIx::InitializeAccount(args),
+        Vec::from(metas),
+    )
+}
+pub fn initialize_account_ix(
+    keys: InitializeAccountKeys,
+    args: InitializeAccountIxArgs,
+) -> Instruction {
+    initialize_account_ix_with_program_id(crate::ID, keys, args)
+}
+pub const INITIALIZE_ACCOUNT_IX_ACCOUNTS_LEN: usize = 4;
+#[derive(Copy, Clone, Debug)]
+pub struct InitializeAccountAccounts<'me, 'info> {
+    ///The uninitialized vote account to initialize
+    pub vote: &'me AccountInfo<'info>,
+    ///Rent sysvar
+    pub rent: &'me AccountInfo<'info>,
+    ///Clock sysvar
+    pub clock: &'me AccountInfo<'info>,
+    ///The new validator identity (node_pubkey). Must be the same as args.node_pubkey
+    pub node: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct InitializeAccountKeys {
+    ///The uninitialized vote account to initialize
+    pub vote: Pubkey,
+    ///Rent sysvar
+    pub rent: Pubkey,
+    ///Clock sysvar
+    pub clock: Pubkey,
+    ///The new validator identity (node_pubkey). Must be the same as args.node_pubkey
+    pub node: Pubkey,
+}
+impl From<InitializeAccountAccounts<'_, '_>> for InitializeAccountKeys {
+    fn from(accounts: InitializeAccountAccounts) -> Self {
+        Self {
+            vote: *accounts.vote.key,
+            rent: *accounts.rent.key,
+            clock: *accounts.clock.key,
+            node: *accounts.node.key,
+        }
+    }
+}
+impl From<InitializeAccountKeys> for [AccountMeta; INITIALIZE_ACCOUNT_IX_ACCOUNTS_LEN] {
+    fn from(keys: InitializeAccountKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.vote,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.rent,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.clock,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.node,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; INITIALIZE_ACCOUNT_IX_ACCOUNTS_LEN]> for InitializeAccountKeys {
+    fn from(pubkeys: [Pubkey; INITIALIZE_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            vote: pubkeys[0],
+            rent: pubkeys[1],
+            clock: pubkeys[2],
+            node: pubkeys[3],
+        }
+    }
+}
+impl<'info> From<InitializeAccountAccounts<'_, 'info>>
+    for [AccountInfo<'info>; INITIALIZE_ACCOUNT_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: InitializeAccountAccounts<'_, 'info>) -> Self {
+        [
+            accounts.vote.clone(),
+            accounts.rent.clone(),
+            accounts.clock.clone(),
+            accounts.node.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_ACCOUNT_IX_ACCOUNTS_LEN]>
+    for InitializeAccountAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; INITIALIZE_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            vote: &arr[0],
+            rent: &arr[1],
+            clock: &arr[2],
+            node: &arr[3],
+        }
+    }
+}
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct InitializeAccountIxArgs {
+    pub node_pubkey: Pubkey,
+    pub authorized_voter: Pubkey,
+    pub authorized_withdrawer: Pubkey,
+    pub commission: u8,
+}
+pub fn initialize_account_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: InitializeAccountAccounts<'_, '_>,
+    args: InitializeAccountIxArgs,
+) -> ProgramResult {
+    let keys: InitializeAccountKeys = accounts.into();
+    let ix = initialize_account_ix_with_program_id(program_id, keys, args);
+    invoke_instruction(&ix, accounts)
+}
+pub fn initialize_account_invoke(
+    accounts: InitializeAccountAccounts<'_, '_>,
+    args: InitializeAccountIxArgs,
+) -> ProgramResult {
+    initialize_account_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn initialize_account_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: InitializeAccountAccounts<'_, '_>,
+    args: InitializeAccountIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: InitializeAccountKeys = accounts.into();
+    let ix = initialize_account_ix_with_program_id(program_id, keys, args);
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn initialize_account_invoke_signed(
+    accounts: InitializeAccountAccounts<'_, '_>,
+    args: InitializeAccountIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    initialize_account_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn initialize_account_verify_account_keys(
+    accounts: InitializeAccountAccounts<'_, '_>,
+    keys: InitializeAccountKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.vote.key, &keys.vote),
+        (accounts.rent.key, &keys.rent),
+        (accounts.clock.key, &keys.clock),
+        (accounts.node.key, &keys.node),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn initialize_account_verify_writable_privileges<'me, 'info>(
+    accounts: InitializeAccountAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.vote] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn initialize_account_verify_signer_privileges<'me, 'info>(
+    accounts: InitializeAccountAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.node] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn initialize_account_verify_account_privileges<'me, 'info>(
+    accounts: InitializeAccountAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    initialize_account_verify_writable_privileges(accounts)?;
+    initialize_account_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const AUTHORIZE_IX_DISCM: [u8; 4] = [1, 0, 0, 0];
+pub fn authorize_ix_with_program_id(
+    program_id: Pubkey,
+    keys: AuthorizeKeys,
+    args: AuthorizeIxArgs,
+) -> Instruction {
+    let metas: [AccountMeta; AUTHORIZE_IX_ACCOUNTS_LEN] = keys.into();
+    Instruction::new_with_bincode(
+        program_id,
+        &VoteProgramProgramIx::Authorize(args),
+        Vec::from(metas),
+    )
+}
+pub fn authorize_ix(keys: AuthorizeKeys, args: AuthorizeIxArgs) -> Instruction {
+    authorize_ix_with_program_id(crate::ID, keys, args)
+}
+pub const AUTHORIZE_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone, Debug)]
+pub struct AuthorizeAccounts<'me, 'info> {
+    ///The vote account to be updated
+    pub vote: &'me AccountInfo<'info>,
+    ///Clock sysvar
+    pub clock: &'me AccountInfo<'info>,
+    ///vote's current authorized voter or withdrawer to change away from. The authorized withdrawer may also change the authorized voter
+    pub authority: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct AuthorizeKeys {
+    ///The vote account to be updated
+    pub vote: Pubkey,
+    ///Clock sysvar
+    pub clock: Pubkey,
+    ///vote's current authorized voter or withdrawer to change away from. The authorized withdrawer may also change the authorized voter
+    pub authority: Pubkey,
+}
+impl From<AuthorizeAccounts<'_, '_>> for AuthorizeKeys {
+    fn from(accounts: AuthorizeAccounts) -> Self {
+        Self {
+            vote: *accounts.vote.key,
+            clock: *accounts.clock.key,
+            authority: *accounts.authority.key,
+        }
+    }
+}
+impl From<AuthorizeKeys> for [AccountMeta; AUTHORIZE_IX_ACCOUNTS_LEN] {
+    fn from(keys: AuthorizeKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.vote,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.clock,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.authority,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; AUTHORIZE_IX_ACCOUNTS_LEN]> for AuthorizeKeys {
+    fn from(pubkeys: [Pubkey; AUTHORIZE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            vote: pubkeys[0],
+            clock: pubkeys[1],
+            authority: pubkeys[2],
+        }
+    }
+}
+impl<'info> From<AuthorizeAccounts<'_, 'info>> for [AccountInfo<'info>; AUTHORIZE_IX_ACCOUNTS_LEN] {
+    fn from(accounts: AuthorizeAccounts<'_, 'info>) -> Self {
+        [
+            accounts.vote.clone(),
+            accounts.clock.clone(),
+            accounts.authority.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; AUTHORIZE_IX_ACCOUNTS_LEN]>
+    for AuthorizeAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; AUTHORIZE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            vote: &arr[0],
+            clock: &arr[1],
+            authority: &arr[2],
+        }
+    }
+}
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct AuthorizeIxArgs {
+    pub new_authority: Pubkey,
+    pub vote_authorize: VoteAuthorize,
+}
+pub fn authorize_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: AuthorizeAccounts<'_, '_>,
+    args: AuthorizeIxArgs,
+) -> ProgramResult {
+    let keys: AuthorizeKeys = accounts.into();
+    let ix = authorize_ix_with_program_id(program_id, keys, args);
+    invoke_instruction(&ix, accounts)
+}
+pub fn authorize_invoke(
+    accounts: AuthorizeAccounts<'_, '_>,
+    args: AuthorizeIxArgs,
+) -> ProgramResult {
+    authorize_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn authorize_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: AuthorizeAccounts<'_, '_>,
+    args: AuthorizeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: AuthorizeKeys = accounts.into();
+    let ix = authorize_ix_with_program_id(program_id, keys, args);
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn authorize_invoke_signed(
+    accounts: AuthorizeAccounts<'_, '_>,
+    args: AuthorizeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    authorize_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn authorize_verify_account_keys(
+    accounts: AuthorizeAccounts<'_, '_>,
+    keys: AuthorizeKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.vote.key, &keys.vote),
+        (accounts.clock.key, &keys.clock),
+        (accounts.authority.key, &keys.authority),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn authorize_verify_writable_privileges<'me, 'info>(
+    accounts: AuthorizeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.vote] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn authorize_verify_signer_privileges<'me, 'info>(
+    accounts: AuthorizeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.authority] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn authorize_verify_account_privileges<'me, 'info>(
+    accounts: AuthorizeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    authorize_verify_writable_privileges(accounts)?;
+    authorize_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const VOTE_IX_DISCM: [u8; 4] = [2, 0, 0, 0];
+pub fn vote_ix_with_program_id(
+    program_id: Pubkey,
+    keys: VoteKeys,
+    args: VoteIxArgs,
+) -> Instruction {
+    let metas: [AccountMeta; VOTE_IX_ACCOUNTS_LEN] = keys.into();
+    Instruction::new_with_bincode(
+        program_id,
+        &VoteProgramProgramIx::Vote(args),
+        Vec::from(metas),
+    )
+}
+pub fn vote_ix(keys: VoteKeys, args: VoteIxArgs) -> Instruction {
+    vote_ix_with_program_id(crate::ID, keys, args)
+}
+pub const VOTE_IX_ACCOUNTS_LEN: usize = 4;
+#[derive(Copy, Clone, Debug)]
+pub struct VoteAccounts<'me, 'info> {
+    ///Vote account to vote with
+    pub vote: &'me AccountInfo<'info>,
+    ///Slot hashes sysvar
+    pub slot_hashes: &'me AccountInfo<'info>,
+    ///Clock sysvar
+    pub clock: &'me AccountInfo<'info>,
+    ///vote's authorized voter
+    pub vote_authority: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct VoteKeys {
+    ///Vote account to vote with
+    pub vote: Pubkey,
+    ///Slot hashes sysvar
+    pub slot_hashes: Pubkey,
+    ///Clock sysvar
+    pub clock: Pubkey,
+    ///vote's authorized voter
+    pub vote_authority: Pubkey,
+}
+impl From<VoteAccounts<'_, '_>> for VoteKeys {
+    fn from(accounts: VoteAccounts) -> Self {
+        Self {
+            vote: *accounts.vote.key,
+            slot_hashes: *accounts.slot_hashes.key,
+            clock: *accounts.clock.key,
+            vote_authority: *accounts.vote_authority.key,
+        }
+    }
+}
+impl From<VoteKeys> for [AccountMeta; VOTE_IX_ACCOUNTS_LEN] {
+    fn from(keys: VoteKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.vote,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.slot_hashes,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.clock,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.vote_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; VOTE_IX_ACCOUNTS_LEN]> for VoteKeys {
+    fn from(pubkeys: [Pubkey; VOTE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            vote: pubkeys[0],
+            slot_hashes: pubkeys[1],
+            clock: pubkeys[2],
+            vote_authority: pubkeys[3],
+        }
+    }
+}
+impl<'info> From<VoteAccounts<'_, 'info>> for [AccountInfo<'info>; VOTE_IX_ACCOUNTS_LEN] {
+    fn from(accounts: VoteAccounts<'_, 'info>) -> Self {
+        [
+            accounts.vote.clone(),
+            accounts.slot_hashes.clone(),
+            accounts.clock.clone(),
+            accounts.vote_authority.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; VOTE_IX_ACCOUNTS_LEN]>
+    for VoteAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; VOTE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            vote: &arr[0],
+            slot_hashes: &arr[1],
+            clock: &arr[2],
+            vote_authority: &arr[3],
+        }
+    }
+}
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct VoteIxArgs {
+    pub slots: Vec<u64>,
+    pub hash: [u8; 32],
+    pub timestamp: Option<i64>,
+}
+pub fn vote_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: VoteAccounts<'_, '_>,
+    args: VoteIxArgs,
+) -> ProgramResult {
+    let keys: VoteKeys = accounts.into();
+    let ix = vote_ix_with_program_id(program_id, keys, args);
+    invoke_instruction(&ix, accounts)
+}
+pub fn vote_invoke(accounts: VoteAccounts<'_, '_>, args: VoteIxArgs) -> ProgramResult {
+    vote_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn vote_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: VoteAccounts<'_, '_>,
+    args: VoteIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: VoteKeys = accounts.into();
+    let ix = vote_ix_with_program_id(program_id, keys, args);
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn vote_invoke_signed(
+    accounts: VoteAccounts<'_, '_>,
+    args: VoteIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    vote_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn vote_verify_account_keys(
+    accounts: VoteAccounts<'_, '_>,
+    keys: VoteKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.vote.key, &keys.vote),
+        (accounts.slot_hashes.key, &keys.slot_hashes),
+        (accounts.clock.key, &keys.clock),
+        (accounts.vote_authority.key, &keys.vote_authority),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn vote_verify_writable_privileges<'me, 'info>(
+    accounts: VoteAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.vote] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn vote_verify_signer_privileges<'me, 'info>(
+    accounts: VoteAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.vote_authority] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn vote_verify_account_privileges<'me, 'info>(
+    accounts: VoteAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    vote_verify_writable_privileges(accounts)?;
+    vote_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const WITHDRAW_IX_DISCM: [u8; 4] = [3, 0, 0, 0];
+pub fn withdraw_ix_with_program_id(
+    program_id: Pubkey,
+    keys: WithdrawKeys,
+    args: WithdrawIxArgs,
+) -> Instruction {
+    let metas: [AccountMeta; WITHDRAW_IX_ACCOUNTS_LEN] = keys.into();
+    Instruction::new_with_bincode(
+        program_id,
+        &VoteProgramProgramIx::Withdraw(args),
+        Vec::from(metas),
+    )
+}
+pub fn withdraw_ix(keys: WithdrawKeys, args: WithdrawIxArgs) -> Instruction {
+    withdraw_ix_with_program_id(crate::ID, keys, args)
+}
+pub const WITHDRAW_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone, Debug)]
+pub struct WithdrawAccounts<'me, 'info> {
+    ///The vote account to withdraw from
+    pub vote: &'me AccountInfo<'info>,
+    ///Recipient account
+    pub to: &'me AccountInfo<'info>,
+    ///vote's authorized withdrawer
+    pub withdraw_authority: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct WithdrawKeys {
+    ///The vote account to withdraw from
+    pub vote: Pubkey,
+    ///Recipient account
+    pub to: Pubkey,
+    ///vote's authorized withdrawer
+    pub withdraw_authority: Pubkey,
+}
+impl From<WithdrawAccounts<'_, '_>> for WithdrawKeys {
+    fn from(accounts: WithdrawAccounts) -> Self {
+        Self {
+            vote: *accounts.vote.key,
+            to: *accounts.to.key,
+            withdraw_authority: *accounts.withdraw_authority.key,
+        }
+    }
+}
+impl From<WithdrawKeys> for [AccountMeta; WITHDRAW_IX_ACCOUNTS_LEN] {
+    fn from(keys: WithdrawKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.vote,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.to,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.withdraw_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; WITHDRAW_IX_ACCOUNTS_LEN]> for WithdrawKeys {
+    fn from(pubkeys: [Pubkey; WITHDRAW_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            vote: pubkeys[0],
+            to: pubkeys[1],
+            withdraw_authority: pubkeys[2],
+        }
+    }
+}
+impl<'info> From<WithdrawAccounts<'_, 'info>> for [AccountInfo<'info>; WITHDRAW_IX_ACCOUNTS_LEN] {
+    fn from(accounts: WithdrawAccounts<'_, 'info>) -> Self {
+        [
+            accounts.vote.clone(),
+            accounts.to.clone(),
+            accounts.withdraw_authority.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; WITHDRAW_IX_ACCOUNTS_LEN]>
+    for WithdrawAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; WITHDRAW_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            vote: &arr[0],
+            to: &arr[1],
+            withdraw_authority: &arr[2],
+        }
+    }
+}
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct WithdrawIxArgs {
+    pub lamports: u64,
+}
+pub fn withdraw_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: WithdrawAccounts<'_, '_>,
+    args: WithdrawIxArgs,
+) -> ProgramResult {
+    let keys: WithdrawKeys = accounts.into();
+    let ix = withdraw_ix_with_program_id(program_id, keys, args);
+    invoke_instruction(&ix, accounts)
+}
+pub fn withdraw_invoke(accounts: WithdrawAccounts<'_, '_>, args: WithdrawIxArgs) -> ProgramResult {
+    withdraw_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn withdraw_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: WithdrawAccounts<'_, '_>,
+    args: WithdrawIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: WithdrawKeys = accounts.into();
+    let ix = withdraw_ix_with_program_id(program_id, keys, args);
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn withdraw_invoke_signed(
+    accounts: WithdrawAccounts<'_, '_>,
+    args: WithdrawIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    withdraw_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn withdraw_verify_account_keys(
+    accounts: WithdrawAccounts<'_, '_>,
+    keys: WithdrawKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.vote.key, &keys.vote),
+        (accounts.to.key, &keys.to),
+        (accounts.withdraw_authority.key, &keys.withdraw_authority),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn withdraw_verify_writable_privileges<'me, 'info>(
+    accounts: WithdrawAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.vote, accounts.to] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn withdraw_verify_signer_privileges<'me, 'info>(
+    accounts: WithdrawAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.withdraw_authority] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn withdraw_verify_account_privileges<'me, 'info>(
+    accounts: WithdrawAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    withdraw_verify_writable_privileges(accounts)?;
+    withdraw_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const UPDATE_VALIDATOR_IDENTITY_IX_DISCM: [u8; 4] = [4, 0, 0, 0];
+pub fn update_validator_identity_ix_with_program_id(
+    program_id: Pubkey,
+    keys: UpdateValidatorIdentityKeys,
+) -> Instruction {
+    let metas: [AccountMeta; UPDATE_VALIDATOR_IDENTITY_IX_ACCOUNTS_LEN] = keys.into();
+    Instruction::new_with_bincode(
+        program_id,
+        &VoteProgramProgramIx::UpdateValidatorIdentity,
+        Vec::from(metas),
+    )
+}
+pub fn update_validator_identity_ix(keys: UpdateValidatorIdentityKeys) -> Instruction {
+    update_validator_identity_ix_with_program_id(crate::ID, keys)
+}
+pub const UPDATE_VALIDATOR_IDENTITY_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone, Debug)]
+pub struct UpdateValidatorIdentityAccounts<'me, 'info> {
+    ///The vote account to be updated
+    pub vote: &'me AccountInfo<'info>,
+    ///The new validator identity (node_pubkey)
+    pub new_node: &'me AccountInfo<'info>,
+    ///vote's authorized withdrawer
+    pub withdraw_authority: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct UpdateValidatorIdentityKeys {
+    ///The vote account to be updated
+    pub vote: Pubkey,
+    ///The new validator identity (node_pubkey)
+    pub new_node: Pubkey,
+    ///vote's authorized withdrawer
+    pub withdraw_authority: Pubkey,
+}
+impl From<UpdateValidatorIdentityAccounts<'_, '_>> for UpdateValidatorIdentityKeys {
+    fn from(accounts: UpdateValidatorIdentityAccounts) -> Self {
+        Self {
+            vote: *accounts.vote.key,
+            new_node: *accounts.new_node.key,
+            withdraw_authority: *accounts.withdraw_authority.key,
+        }
+    }
+}
+impl From<UpdateValidatorIdentityKeys>
+    for [AccountMeta; UPDATE_VALIDATOR_IDENTITY_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: UpdateValidatorIdentityKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.vote,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.new_node,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.withdraw_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; UPDATE_VALIDATOR_IDENTITY_IX_ACCOUNTS_LEN]> for UpdateValidatorIdentityKeys {
+    fn from(pubkeys: [Pubkey; UPDATE_VALIDATOR_IDENTITY_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            vote: pubkeys[0],
+            new_node: pubkeys[1],
+            withdraw_authority: pubkeys[2],
+        }
+    }
+}
+impl<'info> From<UpdateValidatorIdentityAccounts<'_, 'info>>
+    for [AccountInfo<'info>; UPDATE_VALIDATOR_IDENTITY_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: UpdateValidatorIdentityAccounts<'_, 'info>) -> Self {
+        [
+            accounts.vote.clone(),
+            accounts.new_node.clone(),
+            accounts.withdraw_authority.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_VALIDATOR_IDENTITY_IX_ACCOUNTS_LEN]>
+    for UpdateValidatorIdentityAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; UPDATE_VALIDATOR_IDENTITY_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            vote: &arr[0],
+            new_node: &arr[1],
+            withdraw_authority: &arr[2],
+        }
+    }
+}
+pub fn update_validator_identity_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: UpdateValidatorIdentityAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: UpdateValidatorIdentityKeys = accounts.into();
+    let ix = update_validator_identity_ix_with_program_id(program_id, keys);
+    invoke_instruction(&ix, accounts)
+}
+pub fn update_validator_identity_invoke(
+    accounts: UpdateValidatorIdentityAccounts<'_, '_>,
+) -> ProgramResult {
+    update_validator_identity_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn update_validator_identity_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: UpdateValidatorIdentityAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: UpdateValidatorIdentityKeys = accounts.into();
+    let ix = update_validator_identity_ix_with_program_id(program_id, keys);
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn update_validator_identity_invoke_signed(
+    accounts: UpdateValidatorIdentityAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    update_validator_identity_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn update_validator_identity_verify_account_keys(
+    accounts: UpdateValidatorIdentityAccounts<'_, '_>,
+    keys: UpdateValidatorIdentityKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.vote.key, &keys.vote),
+        (accounts.new_node.key, &keys.new_node),
+        (accounts.withdraw_authority.key, &keys.withdraw_authority),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn update_validator_identity_verify_writable_privileges<'me, 'info>(
+    accounts: UpdateValidatorIdentityAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.vote] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn update_validator_identity_verify_signer_privileges<'me, 'info>(
+    accounts: UpdateValidatorIdentityAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.new_node, accounts.withdraw_authority] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn update_validator_identity_verify_account_privileges<'me, 'info>(
+    accounts: UpdateValidatorIdentityAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    update_validator_identity_verify_writable_privileges(accounts)?;
+    update_validator_identity_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const UPDATE_COMMISSION_IX_DISCM: [u8; 4] = [5, 0, 0, 0];
+pub fn update_commission_ix_with_program_id(
+    program_id: Pubkey,
+    keys: UpdateCommissionKeys,
+    args: UpdateCommissionIxArgs,
+) -> Instruction {
+    let metas: [AccountMeta; UPDATE_COMMISSION_IX_ACCOUNTS_LEN] = keys.into();
+    Instruction::new_with_bincode(
+        program_id,
+        &VoteProgramProgramIx::UpdateCommission(args),
+        Vec::from(metas),
+    )
+}
+pub fn update_commission_ix(
+    keys: UpdateCommissionKeys,
+    args: UpdateCommissionIxArgs,
+) -> Instruction {
+    update_commission_ix_with_program_id(crate::ID, keys, args)
+}
+pub const UPDATE_COMMISSION_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone, Debug)]
+pub struct UpdateCommissionAccounts<'me, 'info> {
+    ///The vote account to be updated
+    pub vote: &'me AccountInfo<'info>,
+    ///vote's authorized withdrawer
+    pub withdraw_authority: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct UpdateCommissionKeys {
+    ///The vote account to be updated
+    pub vote: Pubkey,
+    ///vote's authorized withdrawer
+    pub withdraw_authority: Pubkey,
+}
+impl From<UpdateCommissionAccounts<'_, '_>> for UpdateCommissionKeys {
+    fn from(accounts: UpdateCommissionAccounts) -> Self {
+        Self {
+            vote: *accounts.vote.key,
+            withdraw_authority: *accounts.withdraw_authority.key,
+        }
+    }
+}
+impl From<UpdateCommissionKeys> for [AccountMeta; UPDATE_COMMISSION_IX_ACCOUNTS_LEN] {
+    fn from(keys: UpdateCommissionKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.vote,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.withdraw_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; UPDATE_COMMISSION_IX_ACCOUNTS_LEN]> for UpdateCommissionKeys {
+    fn from(pubkeys: [Pubkey; UPDATE_COMMISSION_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            vote: pubkeys[0],
+            withdraw_authority: pubkeys[1],
+        }
+    }
+}
+impl<'info> From<UpdateCommissionAccounts<'_, 'info>>
+    for [AccountInfo<'info>; UPDATE_COMMISSION_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: UpdateCommissionAccounts<'_, 'info>) -> Self {
+        [accounts.vote.clone(), accounts.withdraw_authority.clone()]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_COMMISSION_IX_ACCOUNTS_LEN]>
+    for UpdateCommissionAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; UPDATE_COMMISSION_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            vote: &arr[0],
+            withdraw_authority: &arr[1],
+        }
+    }
+}
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct UpdateCommissionIxArgs {
+    pub commission: u8,
+}
+pub fn update_commission_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: UpdateCommissionAccounts<'_, '_>,
+    args: UpdateCommissionIxArgs,
+) -> ProgramResult {
+    let keys: UpdateCommissionKeys = accounts.into();
+    let ix = update_commission_ix_with_program_id(program_id, keys, args);
+    invoke_instruction(&ix, accounts)
+}
+pub fn update_commission_invoke(
+    accounts: UpdateCommissionAccounts<'_, '_>,
+    args: UpdateCommissionIxArgs,
+) -> ProgramResult {
+    update_commission_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn update_commission_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: UpdateCommissionAccounts<'_, '_>,
+    args: UpdateCommissionIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: UpdateCommissionKeys = accounts.into();
+    let ix = update_commission_ix_with_program_id(program_id, keys, args);
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn update_commission_invoke_signed(
+    accounts: UpdateCommissionAccounts<'_, '_>,
+    args: UpdateCommissionIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    update_commission_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn update_commission_verify_account_keys(
+    accounts: UpdateCommissionAccounts<'_, '_>,
+    keys: UpdateCommissionKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.vote.key, &keys.vote),
+        (accounts.withdraw_authority.key, &keys.withdraw_authority),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn update_commission_verify_writable_privileges<'me, 'info>(
+    accounts: UpdateCommissionAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.vote] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn update_commission_verify_signer_privileges<'me, 'info>(
+    accounts: UpdateCommissionAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.withdraw_authority] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn update_commission_verify_account_privileges<'me, 'info>(
+    accounts: UpdateCommissionAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    update_commission_verify_writable_privileges(accounts)?;
+    update_commission_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const VOTE_SWITCH_IX_DISCM: [u8; 4] = [6, 0, 0, 0];
+pub fn vote_switch_ix_with_program_id(
+    program_id: Pubkey,
+    keys: VoteSwitchKeys,
+    args: VoteSwitchIxArgs,
+) -> Instruction {
+    let metas: [AccountMeta; VOTE_SWITCH_IX_ACCOUNTS_LEN] = keys.into();
+    Instruction::new_with_bincode(
+        program_id,
+        &VoteProgramProgramIx::VoteSwitch(args),
+        Vec::from(metas),
+    )
+}
+pub fn vote_switch_ix(keys: VoteSwitchKeys, args: VoteSwitchIxArgs) -> Instruction {
+    vote_switch_ix_with_program_id(crate::ID, keys, args)
+}
+pub const VOTE_SWITCH_IX_ACCOUNTS_LEN: usize = 4;
+#[derive(Copy, Clone, Debug)]
+pub struct VoteSwitchAccounts<'me, 'info> {
+    ///Vote account to vote with
+    pub vote: &'me AccountInfo<'info>,
+    ///Slot hashes sysvar
+    pub slot_hashes: &'me AccountInfo<'info>,
+    ///Clock sysvar
+    pub clock: &'me AccountInfo<'info>,
+    ///vote's authorized voter
+    pub vote_authority: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct VoteSwitchKeys {
+    ///Vote account to vote with
+    pub vote: Pubkey,
+    ///Slot hashes sysvar
+    pub slot_hashes: Pubkey,
+    ///Clock sysvar
+    pub clock: Pubkey,
+    ///vote's authorized voter
+    pub vote_authority: Pubkey,
+}
+impl From<VoteSwitchAccounts<'_, '_>> for VoteSwitchKeys {
+    fn from(accounts: VoteSwitchAccounts) -> Self {
+        Self {
+            vote: *accounts.vote.key,
+            slot_hashes: *accounts.slot_hashes.key,
+            clock: *accounts.clock.key,
+            vote_authority: *accounts.vote_authority.key,
+        }
+    }
+}
+impl From<VoteSwitchKeys> for [AccountMeta; VOTE_SWITCH_IX_ACCOUNTS_LEN] {
+    fn from(keys: VoteSwitchKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.vote,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.slot_hashes,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.clock,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.vote_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; VOTE_SWITCH_IX_ACCOUNTS_LEN]> for VoteSwitchKeys {
+    fn from(pubkeys: [Pubkey; VOTE_SWITCH_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            vote: pubkeys[0],
+            slot_hashes: pubkeys[1],
+            clock: pubkeys[2],
+            vote_authority: pubkeys[3],
+        }
+    }
+}
+impl<'info> From<VoteSwitchAccounts<'_, 'info>>
+    for [AccountInfo<'info>; VOTE_SWITCH_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: VoteSwitchAccounts<'_, 'info>) -> Self {
+        [
+            accounts.vote.clone(),
+            accounts.slot_hashes.clone(),
+            accounts.clock.clone(),
+            accounts.vote_authority.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; VOTE_SWITCH_IX_ACCOUNTS_LEN]>
+    for VoteSwitchAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; VOTE_SWITCH_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            vote: &arr[0],
+            slot_hashes: &arr[1],
+            clock: &arr[2],
+            vote_authority: &arr[3],
+        }
+    }
+}
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct VoteSwitchIxArgs {
+    pub vote: Vote,
+    pub proof_hash: [u8; 32],
+}
+pub fn vote_switch_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: VoteSwitchAccounts<'_, '_>,
+    args: VoteSwitchIxArgs,
+) -> ProgramResult {
+    let keys: VoteSwitchKeys = accounts.into();
+    let ix = vote_switch_ix_with_program_id(program_id, keys, args);
+    invoke_instruction(&ix, accounts)
+}
+pub fn vote_switch_invoke(
+    accounts: VoteSwitchAccounts<'_, '_>,
+    args: VoteSwitchIxArgs,
+) -> ProgramResult {
+    vote_switch_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn vote_switch_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: VoteSwitchAccounts<'_, '_>,
+    args: VoteSwitchIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: VoteSwitchKeys = accounts.into();
+    let ix = vote_switch_ix_with_program_id(program_id, keys, args);
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn vote_switch_invoke_signed(
+    accounts: VoteSwitchAccounts<'_, '_>,
+    args: VoteSwitchIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    vote_switch_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn vote_switch_verify_account_keys(
+    accounts: VoteSwitchAccounts<'_, '_>,
+    keys: VoteSwitchKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.vote.key, &keys.vote),
+        (accounts.slot_hashes.key, &keys.slot_hashes),
+        (accounts.clock.key, &keys.clock),
+        (accounts.vote_authority.key, &keys.vote_authority),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn vote_switch_verify_writable_privileges<'me, 'info>(
+    accounts: VoteSwitchAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.vote] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn vote_switch_verify_signer_privileges<'me, 'info>(
+    accounts: VoteSwitchAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.vote_authority] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn vote_switch_verify_account_privileges<'me, 'info>(
+    accounts: VoteSwitchAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    vote_switch_verify_writable_privileges(accounts)?;
+    vote_switch_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const AUTHORIZE_CHECKED_IX_DISCM: [u8; 4] = [7, 0, 0, 0];
+pub fn authorize_checked_ix_with_program_id(
+    program_id: Pubkey,
+    keys: AuthorizeCheckedKeys,
+    args: AuthorizeCheckedIxArgs,
+) -> Instruction {
+    let metas: [AccountMeta; AUTHORIZE_CHECKED_IX_ACCOUNTS_LEN] = keys.into();
+    Instruction::new_with_bincode(
+        program_id,
+        &VoteProgramProgramIx::AuthorizeChecked(args),
+        Vec::from(metas),
+    )
+}
+pub fn authorize_checked_ix(
+    keys: AuthorizeCheckedKeys,
+    args: AuthorizeCheckedIxArgs,
+) -> Instruction {
+    authorize_checked_ix_with_program_id(crate::ID, keys, args)
+}
+pub const AUTHORIZE_CHECKED_IX_ACCOUNTS_LEN: usize = 4;
+#[derive(Copy, Clone, Debug)]
+pub struct AuthorizeCheckedAccounts<'me, 'info> {
+    ///The vote account to be updated
+    pub vote: &'me AccountInfo<'info>,
+    ///Clock sysvar
+    pub clock: &'me AccountInfo<'info>,
+    ///vote's current authorized voter or withdrawer to change away from. The authorized withdrawer may also change the authorized voter
+    pub authority: &'me AccountInfo<'info>,
+    ///vote's new authorized voter or withdrawer to change to
+    pub new_authority: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct AuthorizeCheckedKeys {
+    ///The vote account to be updated
+    pub vote: Pubkey,
+    ///Clock sysvar
+    pub clock: Pubkey,
+    ///vote's current authorized voter or withdrawer to change away from. The authorized withdrawer may also change the authorized voter
+    pub authority: Pubkey,
+    ///vote's new authorized voter or withdrawer to change to
+    pub new_authority: Pubkey,
+}
+impl From<AuthorizeCheckedAccounts<'_, '_>> for AuthorizeCheckedKeys {
+    fn from(accounts: AuthorizeCheckedAccounts) -> Self {
+        Self {
+            vote: *accounts.vote.key,
+            clock: *accounts.clock.key,
+            authority: *accounts.authority.key,
+            new_authority: *accounts.new_authority.key,
+        }
+    }
+}
+impl From<AuthorizeCheckedKeys> for [AccountMeta; AUTHORIZE_CHECKED_IX_ACCOUNTS_LEN] {
+    fn from(keys: AuthorizeCheckedKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.vote,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.clock,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.authority,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.new_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; AUTHORIZE_CHECKED_IX_ACCOUNTS_LEN]> for AuthorizeCheckedKeys {
+    fn from(pubkeys: [Pubkey; AUTHORIZE_CHECKED_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            vote: pubkeys[0],
+            clock: pubkeys[1],
+            authority: pubkeys[2],
+            new_authority: pubkeys[3],
+        }
+    }
+}
+impl<'info> From<AuthorizeCheckedAccounts<'_, 'info>>
+    for [AccountInfo<'info>; AUTHORIZE_CHECKED_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: AuthorizeCheckedAccounts<'_, 'info>) -> Self {
+        [
+            accounts.vote.clone(),
+            accounts.clock.clone(),
+            accounts.authority.clone(),
+            accounts.new_authority.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; AUTHORIZE_CHECKED_IX_ACCOUNTS_LEN]>
+    for AuthorizeCheckedAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; AUTHORIZE_CHECKED_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            vote: &arr[0],
+            clock: &arr[1],
+            authority: &arr[2],
+            new_authority: &arr[3],
+        }
+    }
+}
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct AuthorizeCheckedIxArgs {
+    pub vote_authorize: VoteAuthorize,
+}
+pub fn authorize_checked_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: AuthorizeCheckedAccounts<'_, '_>,
+    args: AuthorizeCheckedIxArgs,
+) -> ProgramResult {
+    let keys: AuthorizeCheckedKeys = accounts.into();
+    let ix = authorize_checked_ix_with_program_id(program_id, keys, args);
+    invoke_instruction(&ix, accounts)
+}
+pub fn authorize_checked_invoke(
+    accounts: AuthorizeCheckedAccounts<'_, '_>,
+    args: AuthorizeCheckedIxArgs,
+) -> ProgramResult {
+    authorize_checked_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn authorize_checked_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: AuthorizeCheckedAccounts<'_, '_>,
+    args: AuthorizeCheckedIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: AuthorizeCheckedKeys = accounts.into();
+    let ix = authorize_checked_ix_with_program_id(program_id, keys, args);
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn authorize_checked_invoke_signed(
+    accounts: AuthorizeCheckedAccounts<'_, '_>,
+    args: AuthorizeCheckedIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    authorize_checked_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn authorize_checked_verify_account_keys(
+    accounts: AuthorizeCheckedAccounts<'_, '_>,
+    keys: AuthorizeCheckedKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.vote.key, &keys.vote),
+        (accounts.clock.key, &keys.clock),
+        (accounts.authority.key, &keys.authority),
+        (accounts.new_authority.key, &keys.new_authority),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn authorize_checked_verify_writable_privileges<'me, 'info>(
+    accounts: AuthorizeCheckedAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.vote] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn authorize_checked_verify_signer_privileges<'me, 'info>(
+    accounts: AuthorizeCheckedAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.authority, accounts.new_authority] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn authorize_checked_verify_account_privileges<'me, 'info>(
+    accounts: AuthorizeCheckedAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    authorize_checked_verify_writable_privileges(accounts)?;
+    authorize_checked_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const UPDATE_VOTE_STATE_IX_DISCM: [u8; 4] = [8, 0, 0, 0];
+pub fn update_vote_state_ix_with_program_id(
+    program_id: Pubkey,
+    keys: UpdateVoteStateKeys,
+    args: UpdateVoteStateIxArgs,
+) -> Instruction {
+    let metas: [AccountMeta; UPDATE_VOTE_STATE_IX_ACCOUNTS_LEN] = keys.into();
+    Instruction::new_with_bincode(
+        program_id,
+        &VoteProgramProgramIx::UpdateVoteState(args),
+        Vec::from(metas),
+    )
+}
+pub fn update_vote_state_ix(keys: UpdateVoteStateKeys, args: UpdateVoteStateIxArgs) -> Instruction {
+    update_vote_state_ix_with_program_id(crate::ID, keys, args)
+}
+pub const UPDATE_VOTE_STATE_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone, Debug)]
+pub struct UpdateVoteStateAccounts<'me, 'info> {
+    ///Vote account to vote with
+    pub vote: &'me AccountInfo<'info>,
+    ///vote's authorized voter
+    pub vote_authority: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct UpdateVoteStateKeys {
+    ///Vote account to vote with
+    pub vote: Pubkey,
+    ///vote's authorized voter
+    pub vote_authority: Pubkey,
+}
+impl From<UpdateVoteStateAccounts<'_, '_>> for UpdateVoteStateKeys {
+    fn from(accounts: UpdateVoteStateAccounts) -> Self {
+        Self {
+            vote: *accounts.vote.key,
+            vote_authority: *accounts.vote_authority.key,
+        }
+    }
+}
+impl From<UpdateVoteStateKeys> for [AccountMeta; UPDATE_VOTE_STATE_IX_ACCOUNTS_LEN] {
+    fn from(keys: UpdateVoteStateKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.vote,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.vote_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; UPDATE_VOTE_STATE_IX_ACCOUNTS_LEN]> for UpdateVoteStateKeys {
+    fn from(pubkeys: [Pubkey; UPDATE_VOTE_STATE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            vote: pubkeys[0],
+            vote_authority: pubkeys[1],
+        }
+    }
+}
+impl<'info> From<UpdateVoteStateAccounts<'_, 'info>>
+    for [AccountInfo<'info>; UPDATE_VOTE_STATE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: UpdateVoteStateAccounts<'_, 'info>) -> Self {
+        [accounts.vote.clone(), accounts.vote_authority.clone()]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_VOTE_STATE_IX_ACCOUNTS_LEN]>
+    for UpdateVoteStateAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; UPDATE_VOTE_STATE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            vote: &arr[0],
+            vote_authority: &arr[1],
+        }
+    }
+}
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct UpdateVoteStateIxArgs {
+    pub lockouts: Vec<Lockout>,
+    pub root: Option<u64>,
+    pub hash: [u8; 32],
+    pub timestamp: Option<i64>,
+}
+pub fn update_vote_state_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: UpdateVoteStateAccounts<'_, '_>,
+    args: UpdateVoteStateIxArgs,
+) -> ProgramResult {
+    let keys: UpdateVoteStateKeys = accounts.into();
+    let ix = update_vote_state_ix_with_program_id(program_id, keys, args);
+    invoke_instruction(&ix, accounts)
+}
+pub fn update_vote_state_invoke(
+    accounts: UpdateVoteStateAccounts<'_, '_>,
+    args: UpdateVoteStateIxArgs,
+) -> ProgramResult {
+    update_vote_state_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn update_vote_state_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: UpdateVoteStateAccounts<'_, '_>,
+    args: UpdateVoteStateIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: UpdateVoteStateKeys = accounts.into();
+    let ix = update_vote_state_ix_with_program_id(program_id, keys, args);
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn update_vote_state_invoke_signed(
+    accounts: UpdateVoteStateAccounts<'_, '_>,
+    args: UpdateVoteStateIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    update_vote_state_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn update_vote_state_verify_account_keys(
+    accounts: UpdateVoteStateAccounts<'_, '_>,
+    keys: UpdateVoteStateKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.vote.key, &keys.vote),
+        (accounts.vote_authority.key, &keys.vote_authority),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn update_vote_state_verify_writable_privileges<'me, 'info>(
+    accounts: UpdateVoteStateAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.vote] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn update_vote_state_verify_signer_privileges<'me, 'info>(
+    accounts: UpdateVoteStateAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.vote_authority] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn update_vote_state_verify_account_privileges<'me, 'info>(
+    accounts: UpdateVoteStateAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    update_vote_state_verify_writable_privileges(accounts)?;
+    update_vote_state_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const UPDATE_VOTE_STATE_SWITCH_IX_DISCM: [u8; 4] = [9, 0, 0, 0];
+pub fn update_vote_state_switch_ix_with_program_id(
+    program_id: Pubkey,
+    keys: UpdateVoteStateSwitchKeys,
+    args: UpdateVoteStateSwitchIxArgs,
+) -> Instruction {
+    let metas: [AccountMeta; UPDATE_VOTE_STATE_SWITCH_IX_ACCOUNTS_LEN] = keys.into();
+    Instruction::new_with_bincode(
+        program_id,
+        &VoteProgramProgramIx::UpdateVoteStateSwitch(args),
+        Vec::from(metas),
+    )
+}
+pub fn update_vote_state_switch_ix(
+    keys: UpdateVoteStateSwitchKeys,
+    args: UpdateVoteStateSwitchIxArgs,
+) -> Instruction {
+    update_vote_state_switch_ix_with_program_id(crate::ID, keys, args)
+}
+pub const UPDATE_VOTE_STATE_SWITCH_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone, Debug)]
+pub struct UpdateVoteStateSwitchAccounts<'me, 'info> {
+    ///Vote account to vote with
+    pub vote: &'me AccountInfo<'info>,
+    ///vote's authorized voter
+    pub vote_authority: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct UpdateVoteStateSwitchKeys {
+    ///Vote account to vote with
+    pub vote: Pubkey,
+    ///vote's authorized voter
+    pub vote_authority: Pubkey,
+}
+impl From<UpdateVoteStateSwitchAccounts<'_, '_>> for UpdateVoteStateSwitchKeys {
+    fn from(accounts: UpdateVoteStateSwitchAccounts) -> Self {
+        Self {
+            vote: *accounts.vote.key,
+            vote_authority: *accounts.vote_authority.key,
+        }
+    }
+}
+impl From<UpdateVoteStateSwitchKeys> for [AccountMeta; UPDATE_VOTE_STATE_SWITCH_IX_ACCOUNTS_LEN] {
+    fn from(keys: UpdateVoteStateSwitchKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.vote,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.vote_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; UPDATE_VOTE_STATE_SWITCH_IX_ACCOUNTS_LEN]> for UpdateVoteStateSwitchKeys {
+    fn from(pubkeys: [Pubkey; UPDATE_VOTE_STATE_SWITCH_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            vote: pubkeys[0],
+            vote_authority: pubkeys[1],
+        }
+    }
+}
+impl<'info> From<UpdateVoteStateSwitchAccounts<'_, 'info>>
+    for [AccountInfo<'info>; UPDATE_VOTE_STATE_SWITCH_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: UpdateVoteStateSwitchAccounts<'_, 'info>) -> Self {
+        [accounts.vote.clone(), accounts.vote_authority.clone()]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_VOTE_STATE_SWITCH_IX_ACCOUNTS_LEN]>
+    for UpdateVoteStateSwitchAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; UPDATE_VOTE_STATE_SWITCH_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            vote: &arr[0],
+            vote_authority: &arr[1],
+        }
+    }
+}
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct UpdateVoteStateSwitchIxArgs {
+    pub vote_state_update: VoteStateUpdate,
+    pub proof_hash: [u8; 32],
+}
+pub fn update_vote_state_switch_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: UpdateVoteStateSwitchAccounts<'_, '_>,
+    args: UpdateVoteStateSwitchIxArgs,
+) -> ProgramResult {
+    let keys: UpdateVoteStateSwitchKeys = accounts.into();
+    let ix = update_vote_state_switch_ix_with_program_id(program_id, keys, args);
+    invoke_instruction(&ix, accounts)
+}
+pub fn update_vote_state_switch_invoke(
+    accounts: UpdateVoteStateSwitchAccounts<'_, '_>,
+    args: UpdateVoteStateSwitchIxArgs,
+) -> ProgramResult {
+    update_vote_state_switch_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn update_vote_state_switch_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: UpdateVoteStateSwitchAccounts<'_, '_>,
+    args: UpdateVoteStateSwitchIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: UpdateVoteStateSwitchKeys = accounts.into();
+    let ix = update_vote_state_switch_ix_with_program_id(program_id, keys, args);
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn update_vote_state_switch_invoke_signed(
+    accounts: UpdateVoteStateSwitchAccounts<'_, '_>,
+    args: UpdateVoteStateSwitchIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    update_vote_state_switch_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn update_vote_state_switch_verify_account_keys(
+    accounts: UpdateVoteStateSwitchAccounts<'_, '_>,
+    keys: UpdateVoteStateSwitchKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.vote.key, &keys.vote),
+        (accounts.vote_authority.key, &keys.vote_authority),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn update_vote_state_switch_verify_writable_privileges<'me, 'info>(
+    accounts: UpdateVoteStateSwitchAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.vote] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn update_vote_state_switch_verify_signer_privileges<'me, 'info>(
+    accounts: UpdateVoteStateSwitchAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.vote_authority] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn update_vote_state_switch_verify_account_privileges<'me, 'info>(
+    accounts: UpdateVoteStateSwitchAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    update_vote_state_switch_verify_writable_privileges(accounts)?;
+    update_vote_state_switch_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const AUTHORIZE_WITH_SEED_IX_DISCM: [u8; 4] = [10, 0, 0, 0];
+pub fn authorize_with_seed_ix_with_program_id(
+    program_id: Pubkey,
+    keys: AuthorizeWithSeedKeys,
+    args: AuthorizeWithSeedIxArgs,
+) -> Instruction {
+    let metas: [AccountMeta; AUTHORIZE_WITH_SEED_IX_ACCOUNTS_LEN] = keys.into();
+    Instruction::new_with_bincode(
+        program_id,
+        &VoteProgramProgramIx::AuthorizeWithSeed(args),
+        Vec::from(metas),
+    )
+}
+pub fn authorize_with_seed_ix(
+    keys: AuthorizeWithSeedKeys,
+    args: AuthorizeWithSeedIxArgs,
+) -> Instruction {
+    authorize_with_seed_ix_with_program_id(crate::ID, keys, args)
+}
+pub const AUTHORIZE_WITH_SEED_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone, Debug)]
+pub struct AuthorizeWithSeedAccounts<'me, 'info> {
+    ///The vote account to be updated
+    pub vote: &'me AccountInfo<'info>,
+    ///Clock sysvar
+    pub clock: &'me AccountInfo<'info>,
+    ///Base key of vote's current authorized voter or withdrawer's derived key
+    pub base: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct AuthorizeWithSeedKeys {
+    ///The vote account to be updated
+    pub vote: Pubkey,
+    ///Clock sysvar
+    pub clock: Pubkey,
+    ///Base key of vote's current authorized voter or withdrawer's derived key
+    pub base: Pubkey,
+}
+impl From<AuthorizeWithSeedAccounts<'_, '_>> for AuthorizeWithSeedKeys {
+    fn from(accounts: AuthorizeWithSeedAccounts) -> Self {
+        Self {
+            vote: *accounts.vote.key,
+            clock: *accounts.clock.key,
+            base: *accounts.base.key,
+        }
+    }
+}
+impl From<AuthorizeWithSeedKeys> for [AccountMeta; AUTHORIZE_WITH_SEED_IX_ACCOUNTS_LEN] {
+    fn from(keys: AuthorizeWithSeedKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.vote,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.clock,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.base,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; AUTHORIZE_WITH_SEED_IX_ACCOUNTS_LEN]> for AuthorizeWithSeedKeys {
+    fn from(pubkeys: [Pubkey; AUTHORIZE_WITH_SEED_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            vote: pubkeys[0],
+            clock: pubkeys[1],
+            base: pubkeys[2],
+        }
+    }
+}
+impl<'info> From<AuthorizeWithSeedAccounts<'_, 'info>>
+    for [AccountInfo<'info>; AUTHORIZE_WITH_SEED_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: AuthorizeWithSeedAccounts<'_, 'info>) -> Self {
+        [
+            accounts.vote.clone(),
+            accounts.clock.clone(),
+            accounts.base.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; AUTHORIZE_WITH_SEED_IX_ACCOUNTS_LEN]>
+    for AuthorizeWithSeedAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; AUTHORIZE_WITH_SEED_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            vote: &arr[0],
+            clock: &arr[1],
+            base: &arr[2],
+        }
+    }
+}
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct AuthorizeWithSeedIxArgs {
+    pub vote_authorize: VoteAuthorize,
+    pub current_authority_derived_key_owner: Pubkey,
+    pub current_authority_derived_key_seed: String,
+    pub new_authority: Pubkey,
+}
+pub fn authorize_with_seed_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: AuthorizeWithSeedAccounts<'_, '_>,
+    args: AuthorizeWithSeedIxArgs,
+) -> ProgramResult {
+    let keys: AuthorizeWithSeedKeys = accounts.into();
+    let ix = authorize_with_seed_ix_with_program_id(program_id, keys, args);
+    invoke_instruction(&ix, accounts)
+}
+pub fn authorize_with_seed_invoke(
+    accounts: AuthorizeWithSeedAccounts<'_, '_>,
+    args: AuthorizeWithSeedIxArgs,
+) -> ProgramResult {
+    authorize_with_seed_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn authorize_with_seed_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: AuthorizeWithSeedAccounts<'_, '_>,
+    args: AuthorizeWithSeedIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: AuthorizeWithSeedKeys = accounts.into();
+    let ix = authorize_with_seed_ix_with_program_id(program_id, keys, args);
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn authorize_with_seed_invoke_signed(
+    accounts: AuthorizeWithSeedAccounts<'_, '_>,
+    args: AuthorizeWithSeedIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    authorize_with_seed_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn authorize_with_seed_verify_account_keys(
+    accounts: AuthorizeWithSeedAccounts<'_, '_>,
+    keys: AuthorizeWithSeedKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.vote.key, &keys.vote),
+        (accounts.clock.key, &keys.clock),
+        (accounts.base.key, &keys.base),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn authorize_with_seed_verify_writable_privileges<'me, 'info>(
+    accounts: AuthorizeWithSeedAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.vote] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn authorize_with_seed_verify_signer_privileges<'me, 'info>(
+    accounts: AuthorizeWithSeedAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.base] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn authorize_with_seed_verify_account_privileges<'me, 'info>(
+    accounts: AuthorizeWithSeedAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    authorize_with_seed_verify_writable_privileges(accounts)?;
+    authorize_with_seed_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const AUTHORIZE_CHECKED_WITH_SEED_IX_DISCM: [u8; 4] = [11, 0, 0, 0];
+pub fn authorize_checked_with_seed_ix_with_program_id(
+    program_id: Pubkey,
+    keys: AuthorizeCheckedWithSeedKeys,
+    args: AuthorizeCheckedWithSeedIxArgs,
+) -> Instruction {
+    let metas: [AccountMeta; AUTHORIZE_CHECKED_WITH_SEED_IX_ACCOUNTS_LEN] = keys.into();
+    Instruction::new_with_bincode(
+        program_id,
+        &VoteProgramProgramIx::AuthorizeCheckedWithSeed(args),
+        Vec::from(metas),
+    )
+}
+pub fn authorize_checked_with_seed_ix(
+    keys: AuthorizeCheckedWithSeedKeys,
+    args: AuthorizeCheckedWithSeedIxArgs,
+) -> Instruction {
+    authorize_checked_with_seed_ix_with_program_id(crate::ID, keys, args)
+}
+pub const AUTHORIZE_CHECKED_WITH_SEED_IX_ACCOUNTS_LEN: usize = 4;
+#[derive(Copy, Clone, Debug)]
+pub struct AuthorizeCheckedWithSeedAccounts<'me, 'info> {
+    ///The vote account to be updated
+    pub vote: &'me AccountInfo<'info>,
+    ///Clock sysvar
+    pub clock: &'me AccountInfo<'info>,
+    ///Base key of vote's current authorized voter or withdrawer's derived key
+    pub base: &'me AccountInfo<'info>,
+    ///vote's new authorized voter or withdrawer to change to
+    pub new_authority: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct AuthorizeCheckedWithSeedKeys {
+    ///The vote account to be updated
+    pub vote: Pubkey,
+    ///Clock sysvar
+    pub clock: Pubkey,
+    ///Base key of vote's current authorized voter or withdrawer's derived key
+    pub base: Pubkey,
+    ///vote's new authorized voter or withdrawer to change to
+    pub new_authority: Pubkey,
+}
+impl From<AuthorizeCheckedWithSeedAccounts<'_, '_>> for AuthorizeCheckedWithSeedKeys {
+    fn from(accounts: AuthorizeCheckedWithSeedAccounts) -> Self {
+        Self {
+            vote: *accounts.vote.key,
+            clock: *accounts.clock.key,
+            base: *accounts.base.key,
+            new_authority: *accounts.new_authority.key,
+        }
+    }
+}
+impl From<AuthorizeCheckedWithSeedKeys>
+    for [AccountMeta; AUTHORIZE_CHECKED_WITH_SEED_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: AuthorizeCheckedWithSeedKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.vote,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.clock,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.base,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.new_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; AUTHORIZE_CHECKED_WITH_SEED_IX_ACCOUNTS_LEN]> for AuthorizeCheckedWithSeedKeys {
+    fn from(pubkeys: [Pubkey; AUTHORIZE_CHECKED_WITH_SEED_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            vote: pubkeys[0],
+            clock: pubkeys[1],
+            base: pubkeys[2],
+            new_authority: pubkeys[3],
+        }
+    }
+}
+impl<'info> From<AuthorizeCheckedWithSeedAccounts<'_, 'info>>
+    for [AccountInfo<'info>; AUTHORIZE_CHECKED_WITH_SEED_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: AuthorizeCheckedWithSeedAccounts<'_, 'info>) -> Self {
+        [
+            accounts.vote.clone(),
+            accounts.clock.clone(),
+            accounts.base.clone(),
+            accounts.new_authority.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; AUTHORIZE_CHECKED_WITH_SEED_IX_ACCOUNTS_LEN]>
+    for AuthorizeCheckedWithSeedAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; AUTHORIZE_CHECKED_WITH_SEED_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            vote: &arr[0],
+            clock: &arr[1],
+            base: &arr[2],
+            new_authority: &arr[3],
+        }
+    }
+}
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct AuthorizeCheckedWithSeedIxArgs {
+    pub vote_authorize: VoteAuthorize,
+    pub current_authority_derived_key_owner: Pubkey,
+    pub current_authority_derived_key_seed: String,
+}
+pub fn authorize_checked_with_seed_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: AuthorizeCheckedWithSeedAccounts<'_, '_>,
+    args: AuthorizeCheckedWithSeedIxArgs,
+) -> ProgramResult {
+    let keys: AuthorizeCheckedWithSeedKeys = accounts.into();
+    let ix = authorize_checked_with_seed_ix_with_program_id(program_id, keys, args);
+    invoke_instruction(&ix, accounts)
+}
+pub fn authorize_checked_with_seed_invoke(
+    accounts: AuthorizeCheckedWithSeedAccounts<'_, '_>,
+    args: AuthorizeCheckedWithSeedIxArgs,
+) -> ProgramResult {
+    authorize_checked_with_seed_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn authorize_checked_with_seed_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: AuthorizeCheckedWithSeedAccounts<'_, '_>,
+    args: AuthorizeCheckedWithSeedIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: AuthorizeCheckedWithSeedKeys = accounts.into();
+    let ix = authorize_checked_with_seed_ix_with_program_id(program_id, keys, args);
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn authorize_checked_with_seed_invoke_signed(
+    accounts: AuthorizeCheckedWithSeedAccounts<'_, '_>,
+    args: AuthorizeCheckedWithSeedIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    authorize_checked_with_seed_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn authorize_checked_with_seed_verify_account_keys(
+    accounts: AuthorizeCheckedWithSeedAccounts<'_, '_>,
+    keys: AuthorizeCheckedWithSeedKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.vote.key, &keys.vote),
+        (accounts.clock.key, &keys.clock),
+        (accounts.base.key, &keys.base),
+        (accounts.new_authority.key, &keys.new_authority),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn authorize_checked_with_seed_verify_writable_privileges<'me, 'info>(
+    accounts: AuthorizeCheckedWithSeedAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.vote] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn authorize_checked_with_seed_verify_signer_privileges<'me, 'info>(
+    accounts: AuthorizeCheckedWithSeedAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.base, accounts.new_authority] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn authorize_checked_with_seed_verify_account_privileges<'me, 'info>(
+    accounts: AuthorizeCheckedWithSeedAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    authorize_checked_with_seed_verify_writable_privileges(accounts)?;
+    authorize_checked_with_seed_verify_signer_privileges(accounts)?;
+    Ok(())
+}
@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum VoteAuthorize {
+    Voter,
+    Withdrawer,
+}
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct Vote {
+    pub slots: Vec<u64>,
+    pub hash: [u8; 32],
+    pub timestamp: Option<i64>,
+}
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct Lockout {
+    pub slot: u64,
+    pub confirmation_count: u32,
+}
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct VoteStateUpdate {
+    pub lockouts: Vec<Lockout>,
+    pub root: Option<u64>,
+    pub hash: [u8; 32],
+    pub timestamp: Option<i64>,
+}
@@ -1,6 +1,6 @@
 use solana_program::{pubkey::Pubkey, system_program};
 use solana_readonly_account::{ReadonlyAccountOwnerBytes, ReadonlyAccountPubkeyBytes};
-use spl_associated_token_account_interface::CreateKeys;
+use spl_associated_token_account_interface::{CreateIdempotentKeys, CreateKeys};
 
 use crate::FindAtaAddressArgs;
 
@@ -39,4 +39,30 @@ impl<M: ReadonlyAccountOwnerBytes + ReadonlyAccountPubkeyBytes> CreateFreeArgs<M
             bump,
         )
     }
+
+    /// Same as [`Self::resolve`], for the `CreateIdempotent` instruction
+    pub fn resolve_idempotent(&self) -> (CreateIdempotentKeys, u8) {
+        let (
+            CreateKeys {
+                funding_account,
+                associated_token_account,
+                wallet,
+                mint,
+                system_program,
+                token_program,
+            },
+            bump,
+        ) = self.resolve();
+        (
+            CreateIdempotentKeys {
+                funding_account,
+                associated_token_account,
+                wallet,
+                mint,
+                system_program,
+                token_program,
+            },
+            bump,
+        )
+    }
 }
@@ -1,5 +1,7 @@
 mod account_resolvers;
 mod pda;
+mod wrap_sol;
 
 pub use account_resolvers::*;
 pub use pda::*;
+pub use wrap_sol::*;
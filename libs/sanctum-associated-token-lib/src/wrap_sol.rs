@@ -0,0 +1,267 @@
+use sanctum_token_lib::{
+    native_mint, CloseAccountFreeAccounts, MintWithTokenProgram, ReadonlyTokenAccount,
+    SPL_TOKEN_ACCOUNT_PACKED_LEN,
+};
+use solana_program::{
+    instruction::Instruction, program_error::ProgramError, pubkey::Pubkey, rent::Rent,
+    sysvar::Sysvar,
+};
+use solana_readonly_account::{
+    ReadonlyAccountData, ReadonlyAccountOwnerBytes, ReadonlyAccountPubkeyBytes,
+};
+use spl_associated_token_account_interface::create_idempotent_ix;
+use spl_token_interface::{
+    close_account_ix_with_program_id, initialize_account3_ix_with_program_id,
+    sync_native_ix_with_program_id, InitializeAccount3IxArgs, InitializeAccount3Keys,
+    SplTokenError, SyncNativeKeys,
+};
+use system_program_interface::{
+    create_account_with_seed_ix, transfer_ix, CreateAccountWithSeedIxArgs,
+    CreateAccountWithSeedKeys, TransferIxArgs, TransferKeys,
+};
+
+use crate::{CreateFreeArgs, FindAtaAddressArgs};
+
+/// The wSOL mint of `token_program`.
+///
+/// Errors with [`ProgramError::IncorrectProgramId`] if `token_program`
+/// is neither the original token program nor token-2022.
+fn native_mint_with_token_program(
+    token_program: Pubkey,
+) -> Result<MintWithTokenProgram, ProgramError> {
+    Ok(MintWithTokenProgram {
+        pubkey: native_mint(&token_program).ok_or(ProgramError::IncorrectProgramId)?,
+        token_program,
+    })
+}
+
+/// The wSOL token account to wrap SOL into and how to create it
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum WrapSolDestination {
+    /// `owner`'s wSOL ATA, created with `CreateIdempotent` if it does not exist yet
+    Ata,
+
+    /// A new token account at `Pubkey::create_with_seed(owner, seed, token_program)`,
+    /// created with `CreateAccountWithSeed` + `InitializeAccount3`
+    Seeded { seed: String },
+}
+
+/// Plans the instructions that wrap `lamports` of `owner`'s SOL into wSOL:
+/// - [`WrapSolDestination::Ata`]: `CreateIdempotent`, system `Transfer`, `SyncNative`
+/// - [`WrapSolDestination::Seeded`]: `CreateAccountWithSeed` funded with
+///   the rent-exempt reserve + `lamports`, `InitializeAccount3`
+///
+/// `owner` pays for any account creation and must sign.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct WrapSol {
+    pub owner: Pubkey,
+    pub lamports: u64,
+
+    /// The original token program or token-2022, each with its own wSOL mint
+    pub token_program: Pubkey,
+
+    pub destination: WrapSolDestination,
+}
+
+impl WrapSol {
+    /// The wSOL token account that is wrapped into
+    pub fn token_account(&self) -> Result<Pubkey, ProgramError> {
+        match &self.destination {
+            WrapSolDestination::Ata => Ok(self.find_ata_args()?.find_ata_address().0),
+            WrapSolDestination::Seeded { seed } => Ok(Pubkey::create_with_seed(
+                &self.owner,
+                seed,
+                &self.token_program,
+            )?),
+        }
+    }
+
+    /// `rent_exempt_lamports` is the rent-exempt minimum of a [`SPL_TOKEN_ACCOUNT_PACKED_LEN`] account,
+    /// only used by [`WrapSolDestination::Seeded`].
+    pub fn ixs(&self, rent_exempt_lamports: u64) -> Result<Vec<Instruction>, ProgramError> {
+        let Self {
+            owner,
+            lamports,
+            token_program,
+            destination,
+        } = self;
+        let mint = native_mint_with_token_program(*token_program)?;
+        Ok(match destination {
+            WrapSolDestination::Ata => {
+                let (create_keys, _bump) = CreateFreeArgs {
+                    funding_account: *owner,
+                    wallet: *owner,
+                    mint,
+                }
+                .resolve_idempotent();
+                let token_account = create_keys.associated_token_account;
+                vec![
+                    create_idempotent_ix(create_keys)?,
+                    transfer_ix(
+                        TransferKeys {
+                            from: *owner,
+                            to: token_account,
+                        },
+                        TransferIxArgs {
+                            lamports: *lamports,
+                        },
+                    ),
+                    sync_native_ix_with_program_id(
+                        *token_program,
+                        SyncNativeKeys { token_account },
+                    )?,
+                ]
+            }
+            WrapSolDestination::Seeded { seed } => {
+                let token_account = self.token_account()?;
+                vec![
+                    create_account_with_seed_ix(
+                        CreateAccountWithSeedKeys {
+                            from: *owner,
+                            to: token_account,
+                            base: *owner,
+                        },
+                        CreateAccountWithSeedIxArgs {
+                            base: *owner,
+                            seed: seed.clone(),
+                            lamports: rent_exempt_lamports
+                                .checked_add(*lamports)
+                                .ok_or(ProgramError::ArithmeticOverflow)?,
+                            space: SPL_TOKEN_ACCOUNT_PACKED_LEN as u64,
+                            owner: *token_program,
+                        },
+                    ),
+                    initialize_account3_ix_with_program_id(
+                        *token_program,
+                        InitializeAccount3Keys {
+                            token_account,
+                            mint: mint.pubkey,
+                        },
+                        InitializeAccount3IxArgs { authority: *owner },
+                    )?,
+                ]
+            }
+        })
+    }
+
+    /// [`Self::ixs`] with the rent-exempt reserve from the `Rent` sysvar
+    pub fn onchain_ixs(&self) -> Result<Vec<Instruction>, ProgramError> {
+        self.ixs(Rent::get()?.minimum_balance(SPL_TOKEN_ACCOUNT_PACKED_LEN))
+    }
+
+    fn find_ata_args(&self) -> Result<FindAtaAddressArgs, ProgramError> {
+        Ok(FindAtaAddressArgs {
+            wallet: self.owner,
+            mint: native_mint_with_token_program(self.token_program)?.pubkey,
+            token_program: self.token_program,
+        })
+    }
+}
+
+/// Plans the instructions that unwrap all the wSOL in `token_account` into its owner's SOL:
+/// 1. `CloseAccount`, refunding all of `token_account`'s lamports to the owner
+/// 2. `CreateIdempotent`, recreating the owner's wSOL ATA paid for by the owner, if `keep_ata`
+///
+/// Unlike [`WrapSol`], this takes the wSOL token account instead of its owner,
+/// since accounts from [`WrapSolDestination::Seeded`] cannot be derived from the owner alone.
+/// The owner and token program are read from the token account.
+///
+/// The owner must sign.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct UnwrapSol<A> {
+    pub token_account: A,
+
+    /// `token_account` must be the owner's wSOL ATA if set
+    pub keep_ata: bool,
+}
+
+impl<A: ReadonlyAccountData + ReadonlyAccountOwnerBytes + ReadonlyAccountPubkeyBytes> UnwrapSol<A> {
+    pub fn ixs(&self) -> Result<Vec<Instruction>, ProgramError> {
+        let Self {
+            token_account,
+            keep_ata,
+        } = self;
+        let mint =
+            native_mint_with_token_program(Pubkey::new_from_array(token_account.owner_bytes()))?;
+        let t = ReadonlyTokenAccount(token_account)
+            .try_into_valid()?
+            .try_into_initialized()?;
+        if t.token_account_mint() != mint.pubkey {
+            return Err(SplTokenError::NonNativeNotSupported.into());
+        }
+        let owner = t.token_account_authority();
+
+        let mut res = vec![close_account_ix_with_program_id(
+            mint.token_program,
+            CloseAccountFreeAccounts {
+                token_account,
+                to: owner,
+            }
+            .resolve()?,
+        )?];
+        if *keep_ata {
+            let (create_keys, _bump) = CreateFreeArgs {
+                funding_account: owner,
+                wallet: owner,
+                mint,
+            }
+            .resolve_idempotent();
+            if create_keys.associated_token_account.to_bytes() != token_account.pubkey_bytes() {
+                return Err(ProgramError::InvalidArgument);
+            }
+            res.push(create_idempotent_ix(create_keys)?);
+        }
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::system_program;
+
+    use super::*;
+
+    #[test]
+    fn wrap_sol_ixs_order() {
+        let owner = Pubkey::new_unique();
+        let wrap = WrapSol {
+            owner,
+            lamports: 1_000_000_000,
+            token_program: spl_token_interface::ID,
+            destination: WrapSolDestination::Ata,
+        };
+        let ixs = wrap.ixs(0).unwrap();
+        assert_eq!(
+            ixs.iter().map(|ix| ix.program_id).collect::<Vec<_>>(),
+            [
+                spl_associated_token_account_interface::ID,
+                system_program::ID,
+                spl_token_interface::ID
+            ]
+        );
+        let ata = wrap.token_account().unwrap();
+        assert_eq!(ixs[0].accounts[1].pubkey, ata);
+        assert_eq!(ixs[2].accounts[0].pubkey, ata);
+
+        let wrap = WrapSol {
+            destination: WrapSolDestination::Seeded {
+                seed: "wsol".to_owned(),
+            },
+            ..wrap
+        };
+        let rent_exempt_lamports = 2_039_280;
+        let ixs = wrap.ixs(rent_exempt_lamports).unwrap();
+        assert_eq!(
+            ixs.iter().map(|ix| ix.program_id).collect::<Vec<_>>(),
+            [system_program::ID, spl_token_interface::ID]
+        );
+        assert_eq!(
+            ixs[0].accounts[1].pubkey,
+            Pubkey::create_with_seed(&owner, "wsol", &spl_token_interface::ID).unwrap()
+        );
+        assert_eq!(
+            ixs[0].data[48..56],
+            (rent_exempt_lamports + wrap.lamports).to_le_bytes()
+        );
+    }
+}
@@ -0,0 +1 @@
+mod wrap_sol;
@@ -0,0 +1,230 @@
+use sanctum_associated_token_lib::{UnwrapSol, WrapSol, WrapSolDestination};
+use sanctum_solana_test_utils::{ExtendedBanksClient, ExtendedProgramTest};
+use sanctum_token_lib::{
+    native_mint, ReadonlyTokenAccount, SyncNativeFreeAccounts, SPL_TOKEN_ACCOUNT_PACKED_LEN,
+};
+use solana_program::{
+    native_token::LAMPORTS_PER_SOL, program_option::COption, program_pack::Pack, pubkey::Pubkey,
+};
+use solana_program_test::{BanksClient, ProgramTest, ProgramTestContext};
+use solana_readonly_account::keyed::Keyed;
+use solana_sdk::{
+    account::Account, instruction::Instruction, signature::Keypair, signer::Signer,
+    transaction::Transaction,
+};
+use spl_token_interface::sync_native_ix_with_program_id;
+use system_program_interface::{transfer_ix, TransferIxArgs, TransferKeys};
+
+const OWNER_STARTING_LAMPORTS: u64 = 10 * LAMPORTS_PER_SOL;
+
+const WRAP_LAMPORTS: u64 = 1_234_567_890;
+
+const TOKEN_PROGRAMS: [Pubkey; 2] = [spl_token::ID, spl_token_2022::ID];
+
+/// A [`ProgramTest`] with `owner` funded with [`OWNER_STARTING_LAMPORTS`]
+/// and token-2022's wSOL mint, which unlike the original token program's is not in genesis.
+fn wrap_sol_program_test(owner: Pubkey) -> ProgramTest {
+    let mut native_mint_2022_data = vec![0u8; spl_token_2022::state::Mint::LEN];
+    spl_token_2022::state::Mint {
+        mint_authority: COption::None,
+        supply: 0,
+        decimals: spl_token_2022::native_mint::DECIMALS,
+        is_initialized: true,
+        freeze_authority: COption::None,
+    }
+    .pack_into_slice(&mut native_mint_2022_data);
+    ProgramTest::default()
+        .add_system_account(owner, OWNER_STARTING_LAMPORTS)
+        .add_account_chained(
+            spl_token_2022::native_mint::ID,
+            Account {
+                lamports: LAMPORTS_PER_SOL,
+                data: native_mint_2022_data,
+                owner: spl_token_2022::ID,
+                executable: false,
+                rent_epoch: u64::MAX,
+            },
+        )
+}
+
+async fn exec_owner_tx(ctx: &mut ProgramTestContext, owner: &Keypair, ixs: &[Instruction]) {
+    let mut tx = Transaction::new_with_payer(ixs, Some(&ctx.payer.pubkey()));
+    let blockhash = ctx.get_new_latest_blockhash().await.unwrap();
+    tx.sign(&[&ctx.payer, owner], blockhash);
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+}
+
+async fn fetch_keyed(banks_client: &mut BanksClient, pubkey: Pubkey) -> Keyed<Account> {
+    Keyed {
+        pubkey,
+        account: banks_client.get_account_unwrapped(pubkey).await,
+    }
+}
+
+async fn token_account_rent_exempt_lamports(banks_client: &mut BanksClient) -> u64 {
+    banks_client
+        .get_rent()
+        .await
+        .unwrap()
+        .minimum_balance(SPL_TOKEN_ACCOUNT_PACKED_LEN)
+}
+
+/// Asserts `token_account` is a wSOL account of `token_program` holding `amount`
+/// on top of its rent-exempt reserve, and returns its reserve.
+async fn assert_wsol_balance(
+    banks_client: &mut BanksClient,
+    token_program: Pubkey,
+    token_account: Pubkey,
+    amount: u64,
+) -> u64 {
+    let account = banks_client.get_account_unwrapped(token_account).await;
+    assert_eq!(account.owner, token_program);
+    let t = ReadonlyTokenAccount(&account)
+        .try_into_valid()
+        .unwrap()
+        .try_into_initialized()
+        .unwrap();
+    assert_eq!(t.token_account_mint(), native_mint(&token_program).unwrap());
+    assert_eq!(t.token_account_amount(), amount);
+    let reserve = t.token_account_is_native().unwrap();
+    assert_eq!(account.lamports, reserve + amount);
+    reserve
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn wrap_ata_sync_unwrap() {
+    for token_program in TOKEN_PROGRAMS {
+        let owner = Keypair::new();
+        let mut ctx = wrap_sol_program_test(owner.pubkey())
+            .start_with_context()
+            .await;
+
+        let wrap = WrapSol {
+            owner: owner.pubkey(),
+            lamports: WRAP_LAMPORTS,
+            token_program,
+            destination: WrapSolDestination::Ata,
+        };
+        let ata = wrap.token_account().unwrap();
+        exec_owner_tx(&mut ctx, &owner, &wrap.ixs(0).unwrap()).await;
+        let ata_reserve =
+            assert_wsol_balance(&mut ctx.banks_client, token_program, ata, WRAP_LAMPORTS).await;
+        assert_eq!(
+            ctx.banks_client.get_balance(owner.pubkey()).await.unwrap(),
+            OWNER_STARTING_LAMPORTS - ata_reserve - WRAP_LAMPORTS
+        );
+
+        // wrapping into an existing ATA
+        exec_owner_tx(&mut ctx, &owner, &wrap.ixs(0).unwrap()).await;
+        assert_wsol_balance(&mut ctx.banks_client, token_program, ata, 2 * WRAP_LAMPORTS).await;
+
+        // lamports transferred in directly are only counted after SyncNative
+        let sync_keys = SyncNativeFreeAccounts {
+            token_account: fetch_keyed(&mut ctx.banks_client, ata).await,
+        }
+        .resolve()
+        .unwrap();
+        exec_owner_tx(
+            &mut ctx,
+            &owner,
+            &[
+                transfer_ix(
+                    TransferKeys {
+                        from: owner.pubkey(),
+                        to: ata,
+                    },
+                    TransferIxArgs {
+                        lamports: WRAP_LAMPORTS,
+                    },
+                ),
+                sync_native_ix_with_program_id(token_program, sync_keys).unwrap(),
+            ],
+        )
+        .await;
+        assert_wsol_balance(&mut ctx.banks_client, token_program, ata, 3 * WRAP_LAMPORTS).await;
+        assert_eq!(
+            ctx.banks_client.get_balance(owner.pubkey()).await.unwrap(),
+            OWNER_STARTING_LAMPORTS - ata_reserve - 3 * WRAP_LAMPORTS
+        );
+
+        // closes and recreates the ATA in the same tx
+        let unwrap = UnwrapSol {
+            token_account: fetch_keyed(&mut ctx.banks_client, ata).await,
+            keep_ata: true,
+        };
+        exec_owner_tx(&mut ctx, &owner, &unwrap.ixs().unwrap()).await;
+        assert_wsol_balance(&mut ctx.banks_client, token_program, ata, 0).await;
+        assert_eq!(
+            ctx.banks_client.get_balance(owner.pubkey()).await.unwrap(),
+            OWNER_STARTING_LAMPORTS - ata_reserve
+        );
+
+        exec_owner_tx(&mut ctx, &owner, &wrap.ixs(0).unwrap()).await;
+        let unwrap = UnwrapSol {
+            token_account: fetch_keyed(&mut ctx.banks_client, ata).await,
+            keep_ata: false,
+        };
+        exec_owner_tx(&mut ctx, &owner, &unwrap.ixs().unwrap()).await;
+        ctx.banks_client.assert_account_not_exist(ata).await;
+        assert_eq!(
+            ctx.banks_client.get_balance(owner.pubkey()).await.unwrap(),
+            OWNER_STARTING_LAMPORTS
+        );
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn wrap_seeded_unwrap() {
+    for token_program in TOKEN_PROGRAMS {
+        let owner = Keypair::new();
+        let mut ctx = wrap_sol_program_test(owner.pubkey())
+            .start_with_context()
+            .await;
+        let rent_exempt_lamports = token_account_rent_exempt_lamports(&mut ctx.banks_client).await;
+
+        let wrap = WrapSol {
+            owner: owner.pubkey(),
+            lamports: WRAP_LAMPORTS,
+            token_program,
+            destination: WrapSolDestination::Seeded {
+                seed: "wsol".to_owned(),
+            },
+        };
+        let token_account = wrap.token_account().unwrap();
+        exec_owner_tx(&mut ctx, &owner, &wrap.ixs(rent_exempt_lamports).unwrap()).await;
+        let reserve = assert_wsol_balance(
+            &mut ctx.banks_client,
+            token_program,
+            token_account,
+            WRAP_LAMPORTS,
+        )
+        .await;
+        assert_eq!(reserve, rent_exempt_lamports);
+        assert_eq!(
+            ctx.banks_client.get_balance(owner.pubkey()).await.unwrap(),
+            OWNER_STARTING_LAMPORTS - rent_exempt_lamports - WRAP_LAMPORTS
+        );
+
+        // the ATA can only be kept if it is the account being unwrapped
+        let token_account = fetch_keyed(&mut ctx.banks_client, token_account).await;
+        assert!(UnwrapSol {
+            token_account: &token_account,
+            keep_ata: true,
+        }
+        .ixs()
+        .is_err());
+
+        let unwrap = UnwrapSol {
+            token_account: &token_account,
+            keep_ata: false,
+        };
+        exec_owner_tx(&mut ctx, &owner, &unwrap.ixs().unwrap()).await;
+        ctx.banks_client
+            .assert_account_not_exist(token_account.pubkey)
+            .await;
+        assert_eq!(
+            ctx.banks_client.get_balance(owner.pubkey()).await.unwrap(),
+            OWNER_STARTING_LAMPORTS
+        );
+    }
+}
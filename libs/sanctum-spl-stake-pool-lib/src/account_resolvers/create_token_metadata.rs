@@ -0,0 +1,48 @@
+use solana_program::{program_error::ProgramError, pubkey::Pubkey, system_program};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkeyBytes};
+use spl_stake_pool_interface::{CreateTokenMetadataKeys, StakePool};
+
+use crate::{
+    deserialize_stake_pool_checked, FindTokenMetadataAccount, FindWithdrawAuthority,
+    MPL_TOKEN_METADATA_PROGRAM_ID,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CreateTokenMetadata<P> {
+    pub stake_pool: P,
+    /// Pays for the creation of the token metadata account
+    pub payer: Pubkey,
+}
+
+impl<P: ReadonlyAccountData + ReadonlyAccountPubkeyBytes> CreateTokenMetadata<P> {
+    pub fn resolve_with_withdraw_auth(
+        &self,
+        withdraw_authority: Pubkey,
+    ) -> Result<CreateTokenMetadataKeys, ProgramError> {
+        let StakePool {
+            manager, pool_mint, ..
+        } = deserialize_stake_pool_checked(self.stake_pool.data().as_ref())?;
+        let (token_metadata, _bump) = FindTokenMetadataAccount { mint: pool_mint }.run();
+        Ok(CreateTokenMetadataKeys {
+            stake_pool: Pubkey::new_from_array(self.stake_pool.pubkey_bytes()),
+            manager,
+            withdraw_authority,
+            pool_mint,
+            payer: self.payer,
+            token_metadata,
+            mpl_token_metadata: MPL_TOKEN_METADATA_PROGRAM_ID,
+            system_program: system_program::ID,
+        })
+    }
+
+    pub fn resolve_for_prog(
+        &self,
+        program_id: &Pubkey,
+    ) -> Result<CreateTokenMetadataKeys, ProgramError> {
+        let (withdraw_authority, _bump) = FindWithdrawAuthority {
+            pool: Pubkey::new_from_array(self.stake_pool.pubkey_bytes()),
+        }
+        .run_for_prog(program_id);
+        self.resolve_with_withdraw_auth(withdraw_authority)
+    }
+}
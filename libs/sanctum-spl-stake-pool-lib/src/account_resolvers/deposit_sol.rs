@@ -0,0 +1,91 @@
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program,
+};
+use solana_readonly_account::keyed::Keyed;
+use spl_stake_pool_interface::{
+    deposit_sol_ix_with_program_id, DepositSolIxArgs, DepositSolKeys, StakePool,
+};
+
+use crate::FindWithdrawAuthority;
+
+#[derive(Clone, Copy, Debug)]
+pub struct DepositSol<'a> {
+    pub pool: Keyed<&'a StakePool>,
+    /// System account depositing the SOL. Must sign the transaction.
+    pub deposit_from: Pubkey,
+    pub mint_to: Pubkey,
+    /// LST token account to receive referral fees.
+    /// Defaults to `mint_to` if `None`, which pays the referral fee back to the depositor.
+    pub referral_fee_dest: Option<Pubkey>,
+}
+
+impl<'a> DepositSol<'a> {
+    pub fn compute_withdraw_auth(&self, program_id: &Pubkey) -> Pubkey {
+        let (withdraw_authority_pda, _bump) = FindWithdrawAuthority {
+            pool: self.pool.pubkey,
+        }
+        .run_for_prog(program_id);
+        withdraw_authority_pda
+    }
+
+    pub fn resolve_with_withdraw_auth(&self, withdraw_authority_pda: Pubkey) -> DepositSolKeys {
+        let Self {
+            pool:
+                Keyed {
+                    pubkey: stake_pool,
+                    account:
+                        StakePool {
+                            reserve_stake,
+                            pool_mint,
+                            manager_fee_account,
+                            token_program,
+                            ..
+                        },
+                },
+            deposit_from,
+            mint_to,
+            referral_fee_dest,
+        } = self;
+        DepositSolKeys {
+            stake_pool: *stake_pool,
+            withdraw_authority: withdraw_authority_pda,
+            reserve_stake: *reserve_stake,
+            deposit_from: *deposit_from,
+            mint_to: *mint_to,
+            manager_fee_account: *manager_fee_account,
+            referral_fee_dest: referral_fee_dest.unwrap_or(*mint_to),
+            pool_mint: *pool_mint,
+            system_program: system_program::ID,
+            token_program: *token_program,
+        }
+    }
+
+    pub fn resolve_for_prog(&self, program_id: &Pubkey) -> DepositSolKeys {
+        self.resolve_with_withdraw_auth(self.compute_withdraw_auth(program_id))
+    }
+
+    /// The signing `sol_deposit_authority` account that must follow the
+    /// instruction's accounts if the pool has permissioned SOL deposits
+    pub fn sol_deposit_authority_meta(&self) -> Option<AccountMeta> {
+        self.pool
+            .account
+            .sol_deposit_authority
+            .map(|auth| AccountMeta::new_readonly(auth, true))
+    }
+
+    /// Creates the full instruction, appending the pool's `sol_deposit_authority` if it has one.
+    /// The `sol_deposit_authority` must then also sign the transaction.
+    pub fn full_ix(
+        &self,
+        program_id: &Pubkey,
+        args: DepositSolIxArgs,
+    ) -> Result<Instruction, ProgramError> {
+        let mut ix =
+            deposit_sol_ix_with_program_id(*program_id, self.resolve_for_prog(program_id), args)?;
+        ix.accounts.extend(self.sol_deposit_authority_meta());
+        Ok(ix)
+    }
+}
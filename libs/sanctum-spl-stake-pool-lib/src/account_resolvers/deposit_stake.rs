@@ -0,0 +1,87 @@
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    stake::state::StakeStateV2,
+};
+use solana_readonly_account::keyed::Keyed;
+use spl_stake_pool_interface::{
+    deposit_stake_ix_with_program_id, DepositStakeKeys, StakePool,
+    DEPOSIT_STAKE_WITH_SLIPPAGE_IX_ACCOUNTS_LEN,
+};
+
+use super::{DepositStakeComputedKeys, DepositStakeWithSlippage};
+
+/// Same accounts as [`DepositStakeWithSlippage`], without the slippage check
+#[derive(Clone, Copy, Debug)]
+pub struct DepositStake<'a> {
+    pub pool: Keyed<&'a StakePool>,
+    /// The stake account to deposit. Must have authorities transferred to the pool's
+    /// stake deposit authority beforehand. This can be done using [`Self::stake_authorize_prefix_ixs`]
+    /// or just use [`Self::full_ix_seq`] to get the fully formed instruction sequence
+    pub stake_depositing: Keyed<&'a StakeStateV2>,
+    pub mint_to: Pubkey,
+    pub referral_fee_dest: Pubkey,
+}
+
+impl<'a> From<DepositStake<'a>> for DepositStakeWithSlippage<'a> {
+    fn from(
+        DepositStake {
+            pool,
+            stake_depositing,
+            mint_to,
+            referral_fee_dest,
+        }: DepositStake<'a>,
+    ) -> Self {
+        Self {
+            pool,
+            stake_depositing,
+            mint_to,
+            referral_fee_dest,
+        }
+    }
+}
+
+impl<'a> DepositStake<'a> {
+    pub fn compute_keys(
+        &self,
+        program_id: &Pubkey,
+        vote: Pubkey,
+        validator_seed_suffix: u32, // obtained from ValidatorStakeInfo
+    ) -> DepositStakeComputedKeys {
+        DepositStakeWithSlippage::from(*self).compute_keys(program_id, vote, validator_seed_suffix)
+    }
+
+    pub fn full_ix_seq(
+        &self,
+        program_id: &Pubkey,
+        vote: Pubkey,
+        validator_seed_suffix: u32,
+    ) -> Result<[Instruction; 3], ProgramError> {
+        let computed_keys = self.compute_keys(program_id, vote, validator_seed_suffix);
+        let [stake_auth_staker, stake_auth_withdrawer] = self.stake_authorize_prefix_ixs()?;
+        Ok([
+            stake_auth_staker,
+            stake_auth_withdrawer,
+            deposit_stake_ix_with_program_id(
+                *program_id,
+                self.resolve_with_computed_keys(computed_keys),
+            )?,
+        ])
+    }
+
+    pub fn stake_authorize_prefix_ixs(&self) -> Result<[Instruction; 2], ProgramError> {
+        DepositStakeWithSlippage::from(*self).stake_authorize_prefix_ixs()
+    }
+
+    pub fn resolve_with_computed_keys(
+        &self,
+        computed_keys: DepositStakeComputedKeys,
+    ) -> DepositStakeKeys {
+        let metas: [AccountMeta; DEPOSIT_STAKE_WITH_SLIPPAGE_IX_ACCOUNTS_LEN] =
+            DepositStakeWithSlippage::from(*self)
+                .resolve_with_computed_keys(computed_keys)
+                .into();
+        metas.map(|AccountMeta { pubkey, .. }| pubkey).into()
+    }
+}
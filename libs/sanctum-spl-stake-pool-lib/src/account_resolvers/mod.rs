@@ -2,30 +2,52 @@
 pub mod add_validator_to_pool;
 pub mod additional_validator_stake;
 pub mod cleanup_removed_validator_entries;
+pub mod create_token_metadata;
 pub mod initialize;
+pub mod redelegate;
 pub mod remove_validator_from_pool;
 pub mod set_fee;
 pub mod set_funding_authority;
 pub mod set_manager;
+pub mod set_preferred_validator;
 pub mod set_staker;
 pub mod update_stake_pool_balance;
+pub mod update_token_metadata;
 pub mod update_validator_list_balance;
+pub mod validator_stake;
 
 pub use add_validator_to_pool::*;
 pub use additional_validator_stake::*;
 pub use cleanup_removed_validator_entries::*;
+pub use create_token_metadata::*;
 pub use initialize::*;
+pub use redelegate::*;
 pub use remove_validator_from_pool::*;
 pub use set_fee::*;
 pub use set_funding_authority::*;
 pub use set_manager::*;
+pub use set_preferred_validator::*;
 pub use set_staker::*;
 pub use update_stake_pool_balance::*;
+pub use update_token_metadata::*;
 pub use update_validator_list_balance::*;
+pub use validator_stake::*;
 
 // The resolvers here use the new experimental style of taking &DeserializedAccount as input
+pub mod deposit_sol;
+pub mod deposit_sol_with_slippage;
+pub mod deposit_stake;
 pub mod deposit_stake_with_slippage;
+pub mod withdraw_sol;
+pub mod withdraw_sol_with_slippage;
+pub mod withdraw_stake;
 pub mod withdraw_stake_with_slippage;
 
+pub use deposit_sol::*;
+pub use deposit_sol_with_slippage::*;
+pub use deposit_stake::*;
 pub use deposit_stake_with_slippage::*;
+pub use withdraw_sol::*;
+pub use withdraw_sol_with_slippage::*;
+pub use withdraw_stake::*;
 pub use withdraw_stake_with_slippage::*;
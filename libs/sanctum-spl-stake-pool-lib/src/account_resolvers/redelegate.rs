@@ -0,0 +1,177 @@
+use std::num::NonZeroU32;
+
+use solana_program::{program_error::ProgramError, pubkey::Pubkey, stake, system_program, sysvar};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkeyBytes};
+use spl_stake_pool_interface::{RedelegateIxArgs, RedelegateKeys, StakePool, ValidatorStakeInfo};
+
+use crate::{
+    deserialize_stake_pool_checked, FindEphemeralStakeAccount, FindEphemeralStakeAccountArgs,
+    FindTransientStakeAccount, FindTransientStakeAccountArgs, FindValidatorStakeAccount,
+    FindValidatorStakeAccountArgs, FindWithdrawAuthority,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Redelegate<P> {
+    pub stake_pool: P,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RedelegatePdas {
+    pub withdraw_authority: Pubkey,
+    pub source_validator_stake_account: Pubkey,
+    pub source_transient_stake_account: Pubkey,
+    pub ephemeral_stake_account: Pubkey,
+    pub destination_transient_stake_account: Pubkey,
+    pub destination_validator_stake_account: Pubkey,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RedelegateVotes {
+    pub source: Pubkey,
+    pub destination: Pubkey,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RedelegateSeeds {
+    pub source_validator: Option<NonZeroU32>,
+    pub source_transient: u64,
+    pub ephemeral: u64,
+    pub destination_validator: Option<NonZeroU32>,
+    pub destination_transient: u64,
+}
+
+impl<P: ReadonlyAccountData + ReadonlyAccountPubkeyBytes> Redelegate<P> {
+    pub fn resolve_with_pdas(
+        &self,
+        destination_vote_account: Pubkey,
+        RedelegatePdas {
+            withdraw_authority,
+            source_validator_stake_account,
+            source_transient_stake_account,
+            ephemeral_stake_account,
+            destination_transient_stake_account,
+            destination_validator_stake_account,
+        }: RedelegatePdas,
+    ) -> Result<RedelegateKeys, ProgramError> {
+        let StakePool {
+            staker,
+            validator_list,
+            reserve_stake,
+            ..
+        } = deserialize_stake_pool_checked(self.stake_pool.data().as_ref())?;
+        Ok(RedelegateKeys {
+            stake_pool: Pubkey::new_from_array(self.stake_pool.pubkey_bytes()),
+            staker,
+            withdraw_authority,
+            validator_list,
+            reserve_stake,
+            source_validator_stake_account,
+            source_transient_stake_account,
+            ephemeral_stake_account,
+            destination_transient_stake_account,
+            destination_validator_stake_account,
+            destination_vote_account,
+            clock: sysvar::clock::ID,
+            stake_history: sysvar::stake_history::ID,
+            stake_config: stake::config::ID,
+            system_program: system_program::ID,
+            stake_program: stake::program::ID,
+        })
+    }
+
+    pub fn resolve_for_prog_with_seeds(
+        &self,
+        program_id: &Pubkey,
+        RedelegateVotes {
+            source,
+            destination,
+        }: RedelegateVotes,
+        RedelegateSeeds {
+            source_validator,
+            source_transient,
+            ephemeral,
+            destination_validator,
+            destination_transient,
+        }: RedelegateSeeds,
+    ) -> Result<RedelegateKeys, ProgramError> {
+        let pool = Pubkey::new_from_array(self.stake_pool.pubkey_bytes());
+        let (withdraw_authority, _bump) = FindWithdrawAuthority { pool }.run_for_prog(program_id);
+        let (source_validator_stake_account, _bump) =
+            FindValidatorStakeAccount::new(FindValidatorStakeAccountArgs {
+                pool,
+                vote: source,
+                seed: source_validator,
+            })
+            .run_for_prog(program_id);
+        let (source_transient_stake_account, _bump) =
+            FindTransientStakeAccount::new(FindTransientStakeAccountArgs {
+                pool,
+                vote: source,
+                seed: source_transient,
+            })
+            .run_for_prog(program_id);
+        let (ephemeral_stake_account, _bump) =
+            FindEphemeralStakeAccount::new(FindEphemeralStakeAccountArgs {
+                pool,
+                seed: ephemeral,
+            })
+            .run_for_prog(program_id);
+        let (destination_transient_stake_account, _bump) =
+            FindTransientStakeAccount::new(FindTransientStakeAccountArgs {
+                pool,
+                vote: destination,
+                seed: destination_transient,
+            })
+            .run_for_prog(program_id);
+        let (destination_validator_stake_account, _bump) =
+            FindValidatorStakeAccount::new(FindValidatorStakeAccountArgs {
+                pool,
+                vote: destination,
+                seed: destination_validator,
+            })
+            .run_for_prog(program_id);
+        self.resolve_with_pdas(
+            destination,
+            RedelegatePdas {
+                withdraw_authority,
+                source_validator_stake_account,
+                source_transient_stake_account,
+                ephemeral_stake_account,
+                destination_transient_stake_account,
+                destination_validator_stake_account,
+            },
+        )
+    }
+
+    /// Resolves the keys for the seeds in `args`.
+    ///
+    /// `args.destination_transient_stake_seed` must match `destination.transient_seed_suffix`
+    /// if the destination validator currently has transient stake.
+    pub fn resolve_for_validators(
+        &self,
+        program_id: &Pubkey,
+        source: &ValidatorStakeInfo,
+        destination: &ValidatorStakeInfo,
+        RedelegateIxArgs {
+            source_transient_stake_seed,
+            ephemeral_stake_seed,
+            destination_transient_stake_seed,
+            ..
+        }: &RedelegateIxArgs,
+    ) -> Result<RedelegateKeys, ProgramError> {
+        self.resolve_for_prog_with_seeds(
+            program_id,
+            RedelegateVotes {
+                source: source.vote_account_address,
+                destination: destination.vote_account_address,
+            },
+            RedelegateSeeds {
+                source_validator: NonZeroU32::new(source.validator_seed_suffix),
+                source_transient: *source_transient_stake_seed,
+                ephemeral: *ephemeral_stake_seed,
+                destination_validator: NonZeroU32::new(destination.validator_seed_suffix),
+                destination_transient: *destination_transient_stake_seed,
+            },
+        )
+    }
+}
@@ -0,0 +1,25 @@
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkeyBytes};
+use spl_stake_pool_interface::{SetPreferredValidatorKeys, StakePool};
+
+use crate::deserialize_stake_pool_checked;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SetPreferredValidator<P> {
+    pub stake_pool: P,
+}
+
+impl<P: ReadonlyAccountData + ReadonlyAccountPubkeyBytes> SetPreferredValidator<P> {
+    pub fn resolve(&self) -> Result<SetPreferredValidatorKeys, ProgramError> {
+        let StakePool {
+            staker,
+            validator_list,
+            ..
+        } = deserialize_stake_pool_checked(self.stake_pool.data().as_ref())?;
+        Ok(SetPreferredValidatorKeys {
+            stake_pool: Pubkey::new_from_array(self.stake_pool.pubkey_bytes()),
+            staker,
+            validator_list,
+        })
+    }
+}
@@ -0,0 +1,43 @@
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkeyBytes};
+use spl_stake_pool_interface::{StakePool, UpdateTokenMetadataKeys};
+
+use crate::{
+    deserialize_stake_pool_checked, FindTokenMetadataAccount, FindWithdrawAuthority,
+    MPL_TOKEN_METADATA_PROGRAM_ID,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UpdateTokenMetadata<P> {
+    pub stake_pool: P,
+}
+
+impl<P: ReadonlyAccountData + ReadonlyAccountPubkeyBytes> UpdateTokenMetadata<P> {
+    pub fn resolve_with_withdraw_auth(
+        &self,
+        withdraw_authority: Pubkey,
+    ) -> Result<UpdateTokenMetadataKeys, ProgramError> {
+        let StakePool {
+            manager, pool_mint, ..
+        } = deserialize_stake_pool_checked(self.stake_pool.data().as_ref())?;
+        let (token_metadata, _bump) = FindTokenMetadataAccount { mint: pool_mint }.run();
+        Ok(UpdateTokenMetadataKeys {
+            stake_pool: Pubkey::new_from_array(self.stake_pool.pubkey_bytes()),
+            manager,
+            withdraw_authority,
+            token_metadata,
+            mpl_token_metadata: MPL_TOKEN_METADATA_PROGRAM_ID,
+        })
+    }
+
+    pub fn resolve_for_prog(
+        &self,
+        program_id: &Pubkey,
+    ) -> Result<UpdateTokenMetadataKeys, ProgramError> {
+        let (withdraw_authority, _bump) = FindWithdrawAuthority {
+            pool: Pubkey::new_from_array(self.stake_pool.pubkey_bytes()),
+        }
+        .run_for_prog(program_id);
+        self.resolve_with_withdraw_auth(withdraw_authority)
+    }
+}
@@ -86,6 +86,32 @@ impl<P: ReadonlyAccountData + ReadonlyAccountPubkeyBytes> UpdateValidatorListBal
         })
     }
 
+    /// Returns (validator_stake_account, transient_stake_account) of `validator`
+    pub fn validator_pair_for_prog(
+        &self,
+        program_id: &Pubkey,
+        ValidatorStakeInfo {
+            transient_seed_suffix,
+            validator_seed_suffix,
+            vote_account_address,
+            ..
+        }: &ValidatorStakeInfo,
+    ) -> (Pubkey, Pubkey) {
+        let (vsa, _bump) = FindValidatorStakeAccount::new(FindValidatorStakeAccountArgs {
+            pool: Pubkey::new_from_array(self.stake_pool.pubkey_bytes()),
+            vote: *vote_account_address,
+            seed: NonZeroU32::new(*validator_seed_suffix),
+        })
+        .run_for_prog(program_id);
+        let (tsa, _bump) = FindTransientStakeAccount::new(FindTransientStakeAccountArgs {
+            pool: Pubkey::new_from_array(self.stake_pool.pubkey_bytes()),
+            vote: *vote_account_address,
+            seed: *transient_seed_suffix,
+        })
+        .run_for_prog(program_id);
+        (vsa, tsa)
+    }
+
     /// Creates an ix that updates all validators on `validator_slice`
     pub fn full_ix_from_validator_slice(
         &self,
@@ -95,30 +121,9 @@ impl<P: ReadonlyAccountData + ReadonlyAccountPubkeyBytes> UpdateValidatorListBal
     ) -> Result<Instruction, ProgramError> {
         self.full_ix_with_validator_pairs(
             program_id,
-            validator_slice.iter().map(
-                |ValidatorStakeInfo {
-                     transient_seed_suffix,
-                     validator_seed_suffix,
-                     vote_account_address,
-                     ..
-                 }| {
-                    let (vsa, _bump) =
-                        FindValidatorStakeAccount::new(FindValidatorStakeAccountArgs {
-                            pool: Pubkey::new_from_array(self.stake_pool.pubkey_bytes()),
-                            vote: *vote_account_address,
-                            seed: NonZeroU32::new(*validator_seed_suffix),
-                        })
-                        .run_for_prog(&program_id);
-                    let (tsa, _bump) =
-                        FindTransientStakeAccount::new(FindTransientStakeAccountArgs {
-                            pool: Pubkey::new_from_array(self.stake_pool.pubkey_bytes()),
-                            vote: *vote_account_address,
-                            seed: *transient_seed_suffix,
-                        })
-                        .run_for_prog(&program_id);
-                    (vsa, tsa)
-                },
-            ),
+            validator_slice
+                .iter()
+                .map(|validator| self.validator_pair_for_prog(&program_id, validator)),
             ix_args,
         )
     }
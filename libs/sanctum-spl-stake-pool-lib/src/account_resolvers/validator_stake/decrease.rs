@@ -0,0 +1,107 @@
+use std::num::NonZeroU32;
+
+use solana_program::{program_error::ProgramError, pubkey::Pubkey, stake, system_program, sysvar};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkeyBytes};
+use spl_stake_pool_interface::{DecreaseValidatorStakeKeys, StakePool, ValidatorStakeInfo};
+
+use crate::{
+    account_resolvers::ProgramIdAndVote, deserialize_stake_pool_checked, FindTransientStakeAccount,
+    FindTransientStakeAccountArgs, FindValidatorStakeAccount, FindValidatorStakeAccountArgs,
+    FindWithdrawAuthority,
+};
+
+use super::{ValidatorStakePdas, ValidatorStakeSeeds};
+
+/// Deprecated upstream in favour of [`super::DecreaseValidatorStakeWithReserve`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DecreaseValidatorStake<P> {
+    pub stake_pool: P,
+}
+
+impl<P: ReadonlyAccountData + ReadonlyAccountPubkeyBytes> DecreaseValidatorStake<P> {
+    pub fn resolve_with_pdas(
+        &self,
+        ValidatorStakePdas {
+            withdraw_authority,
+            validator_stake_account,
+            transient_stake_account,
+        }: ValidatorStakePdas,
+    ) -> Result<DecreaseValidatorStakeKeys, ProgramError> {
+        let StakePool {
+            staker,
+            validator_list,
+            ..
+        } = deserialize_stake_pool_checked(self.stake_pool.data().as_ref())?;
+        Ok(DecreaseValidatorStakeKeys {
+            stake_pool: Pubkey::new_from_array(self.stake_pool.pubkey_bytes()),
+            staker,
+            withdraw_authority,
+            validator_list,
+            validator_stake_account,
+            transient_stake_account,
+            clock: sysvar::clock::ID,
+            rent: sysvar::rent::ID,
+            system_program: system_program::ID,
+            stake_program: stake::program::ID,
+        })
+    }
+
+    pub fn resolve_for_prog_with_seeds(
+        &self,
+        ProgramIdAndVote {
+            program_id,
+            vote_account,
+        }: ProgramIdAndVote,
+        ValidatorStakeSeeds {
+            validator,
+            transient,
+        }: ValidatorStakeSeeds,
+    ) -> Result<DecreaseValidatorStakeKeys, ProgramError> {
+        let (withdraw_authority, _bump) = FindWithdrawAuthority {
+            pool: Pubkey::new_from_array(self.stake_pool.pubkey_bytes()),
+        }
+        .run_for_prog(&program_id);
+        let (validator_stake_account, _bump) =
+            FindValidatorStakeAccount::new(FindValidatorStakeAccountArgs {
+                pool: Pubkey::new_from_array(self.stake_pool.pubkey_bytes()),
+                vote: vote_account,
+                seed: validator,
+            })
+            .run_for_prog(&program_id);
+        let (transient_stake_account, _bump) =
+            FindTransientStakeAccount::new(FindTransientStakeAccountArgs {
+                pool: Pubkey::new_from_array(self.stake_pool.pubkey_bytes()),
+                vote: vote_account,
+                seed: transient,
+            })
+            .run_for_prog(&program_id);
+        self.resolve_with_pdas(ValidatorStakePdas {
+            withdraw_authority,
+            validator_stake_account,
+            transient_stake_account,
+        })
+    }
+
+    /// `transient_stake_seed` must be the same seed passed in the instruction args
+    pub fn resolve_for_validator(
+        &self,
+        program_id: Pubkey,
+        ValidatorStakeInfo {
+            validator_seed_suffix,
+            vote_account_address,
+            ..
+        }: &ValidatorStakeInfo,
+        transient_stake_seed: u64,
+    ) -> Result<DecreaseValidatorStakeKeys, ProgramError> {
+        self.resolve_for_prog_with_seeds(
+            ProgramIdAndVote {
+                program_id,
+                vote_account: *vote_account_address,
+            },
+            ValidatorStakeSeeds {
+                validator: NonZeroU32::new(*validator_seed_suffix),
+                transient: transient_stake_seed,
+            },
+        )
+    }
+}
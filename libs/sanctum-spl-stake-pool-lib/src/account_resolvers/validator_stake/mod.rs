@@ -0,0 +1,24 @@
+use std::num::NonZeroU32;
+
+use solana_program::pubkey::Pubkey;
+
+mod decrease;
+mod decrease_with_reserve;
+mod increase;
+
+pub use decrease::*;
+pub use decrease_with_reserve::*;
+pub use increase::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ValidatorStakePdas {
+    pub withdraw_authority: Pubkey,
+    pub validator_stake_account: Pubkey,
+    pub transient_stake_account: Pubkey,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ValidatorStakeSeeds {
+    pub validator: Option<NonZeroU32>,
+    pub transient: u64,
+}
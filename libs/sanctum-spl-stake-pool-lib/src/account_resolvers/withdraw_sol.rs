@@ -0,0 +1,92 @@
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    stake, sysvar,
+};
+use solana_readonly_account::keyed::Keyed;
+use spl_stake_pool_interface::{
+    withdraw_sol_ix_with_program_id, StakePool, WithdrawSolIxArgs, WithdrawSolKeys,
+};
+
+use crate::FindWithdrawAuthority;
+
+#[derive(Clone, Copy, Debug)]
+pub struct WithdrawSol<'a> {
+    pub pool: Keyed<&'a StakePool>,
+    /// Token account authority of `burn_from`
+    pub transfer_authority: Pubkey,
+    pub burn_from: Pubkey,
+    /// System account to receive the withdrawn SOL
+    pub withdraw_to: Pubkey,
+}
+
+impl<'a> WithdrawSol<'a> {
+    pub fn compute_withdraw_auth(&self, program_id: &Pubkey) -> Pubkey {
+        let (withdraw_authority_pda, _bump) = FindWithdrawAuthority {
+            pool: self.pool.pubkey,
+        }
+        .run_for_prog(program_id);
+        withdraw_authority_pda
+    }
+
+    pub fn resolve_with_withdraw_auth(&self, withdraw_authority_pda: Pubkey) -> WithdrawSolKeys {
+        let Self {
+            pool:
+                Keyed {
+                    pubkey: stake_pool,
+                    account:
+                        StakePool {
+                            reserve_stake,
+                            pool_mint,
+                            manager_fee_account,
+                            token_program,
+                            ..
+                        },
+                },
+            transfer_authority,
+            burn_from,
+            withdraw_to,
+        } = self;
+        WithdrawSolKeys {
+            stake_pool: *stake_pool,
+            withdraw_authority: withdraw_authority_pda,
+            transfer_authority: *transfer_authority,
+            burn_from: *burn_from,
+            reserve_stake: *reserve_stake,
+            withdraw_to: *withdraw_to,
+            manager_fee_account: *manager_fee_account,
+            pool_mint: *pool_mint,
+            clock: sysvar::clock::ID,
+            stake_history: sysvar::stake_history::ID,
+            stake_program: stake::program::ID,
+            token_program: *token_program,
+        }
+    }
+
+    pub fn resolve_for_prog(&self, program_id: &Pubkey) -> WithdrawSolKeys {
+        self.resolve_with_withdraw_auth(self.compute_withdraw_auth(program_id))
+    }
+
+    /// The signing `sol_withdraw_authority` account that must follow the
+    /// instruction's accounts if the pool has permissioned SOL withdrawals
+    pub fn sol_withdraw_authority_meta(&self) -> Option<AccountMeta> {
+        self.pool
+            .account
+            .sol_withdraw_authority
+            .map(|auth| AccountMeta::new_readonly(auth, true))
+    }
+
+    /// Creates the full instruction, appending the pool's `sol_withdraw_authority` if it has one.
+    /// The `sol_withdraw_authority` must then also sign the transaction.
+    pub fn full_ix(
+        &self,
+        program_id: &Pubkey,
+        args: WithdrawSolIxArgs,
+    ) -> Result<Instruction, ProgramError> {
+        let mut ix =
+            withdraw_sol_ix_with_program_id(*program_id, self.resolve_for_prog(program_id), args)?;
+        ix.accounts.extend(self.sol_withdraw_authority_meta());
+        Ok(ix)
+    }
+}
@@ -0,0 +1,82 @@
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use solana_readonly_account::keyed::Keyed;
+use spl_stake_pool_interface::{
+    StakePool, WithdrawStakeKeys, WITHDRAW_STAKE_WITH_SLIPPAGE_IX_ACCOUNTS_LEN,
+};
+
+use super::{WithdrawStakeComputedKeys, WithdrawStakeWithSlippage};
+
+/// Same accounts as [`WithdrawStakeWithSlippage`], without the slippage check
+#[derive(Clone, Copy, Debug)]
+pub struct WithdrawStake<'a> {
+    pub pool: Keyed<&'a StakePool>,
+    pub burn_from: Pubkey,
+    /// Token account authority of `burn_from`
+    pub transfer_authority: Pubkey,
+    pub beneficiary: Pubkey,
+    /// Needs to be initialized with 200 bytes of space, owner = stake program, and rent-exempt
+    pub split_to: Pubkey,
+}
+
+impl<'a> From<WithdrawStake<'a>> for WithdrawStakeWithSlippage<'a> {
+    fn from(
+        WithdrawStake {
+            pool,
+            burn_from,
+            transfer_authority,
+            beneficiary,
+            split_to,
+        }: WithdrawStake<'a>,
+    ) -> Self {
+        Self {
+            pool,
+            burn_from,
+            transfer_authority,
+            beneficiary,
+            split_to,
+        }
+    }
+}
+
+impl<'a> WithdrawStake<'a> {
+    pub fn compute_keys_for_reserve(&self, program_id: &Pubkey) -> WithdrawStakeComputedKeys {
+        WithdrawStakeWithSlippage::from(*self).compute_keys_for_reserve(program_id)
+    }
+
+    pub fn compute_keys_for_vsa(
+        &self,
+        program_id: &Pubkey,
+        vote: Pubkey,
+        validator_seed_suffix: u32,
+    ) -> WithdrawStakeComputedKeys {
+        WithdrawStakeWithSlippage::from(*self).compute_keys_for_vsa(
+            program_id,
+            vote,
+            validator_seed_suffix,
+        )
+    }
+
+    pub fn compute_keys_for_tsa(
+        &self,
+        program_id: &Pubkey,
+        vote: Pubkey,
+        transient_seed_suffix: u64,
+    ) -> WithdrawStakeComputedKeys {
+        WithdrawStakeWithSlippage::from(*self).compute_keys_for_tsa(
+            program_id,
+            vote,
+            transient_seed_suffix,
+        )
+    }
+
+    pub fn resolve_with_computed_keys(
+        &self,
+        computed_keys: WithdrawStakeComputedKeys,
+    ) -> WithdrawStakeKeys {
+        let metas: [AccountMeta; WITHDRAW_STAKE_WITH_SLIPPAGE_IX_ACCOUNTS_LEN] =
+            WithdrawStakeWithSlippage::from(*self)
+                .resolve_with_computed_keys(computed_keys)
+                .into();
+        metas.map(|AccountMeta { pubkey, .. }| pubkey).into()
+    }
+}
@@ -1,3 +1,4 @@
+use solana_program::{pubkey, pubkey::Pubkey};
 use spl_stake_pool_interface::Fee;
 
 pub const ZERO_FEE: Fee = Fee {
@@ -22,3 +23,7 @@ pub const MIN_ACTIVE_STAKE: u64 = 1_000_000;
 /// TODO: stake program may change parameters
 /// if min delegation feature is activated
 pub const STAKE_PROG_MIN_DELEGATION: u64 = 1;
+
+/// Metaplex token metadata program, used for the pool token's metadata
+pub const MPL_TOKEN_METADATA_PROGRAM_ID: Pubkey =
+    pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
@@ -1,6 +1,6 @@
 use std::cmp::Ordering;
 
-use sanctum_token_ratio::{CeilDiv, FloorDiv, MathError, U64BpsFee, U64FeeRatio};
+use sanctum_token_ratio::{FloorDiv, MathError, U64BpsFee, U64FeeRatio};
 use spl_stake_pool_interface::{Fee, FeeType};
 
 /// Newtype used to perform fraction comparisons between [`Fee`]s
@@ -121,13 +121,17 @@ impl PartialEq for EqFeeTypeOwned {
 
 impl Eq for EqFeeTypeOwned {}
 
+/// Converts a [`Fee`] into a ratio that rounds the fee charged down.
+///
+/// The onchain program's `Fee::apply` computes `amount * numerator / denominator` with
+/// integer division, so fees are floored.
 pub trait FeeToRatio {
-    fn to_fee_ratio(&self) -> Result<CeilDiv<U64FeeRatio<u64, u64>>, MathError>;
+    fn to_fee_ratio(&self) -> Result<FloorDiv<U64FeeRatio<u64, u64>>, MathError>;
 }
 
 impl FeeToRatio for Fee {
-    fn to_fee_ratio(&self) -> Result<CeilDiv<U64FeeRatio<u64, u64>>, MathError> {
-        Ok(CeilDiv(U64FeeRatio::try_from_fee_num_and_denom(
+    fn to_fee_ratio(&self) -> Result<FloorDiv<U64FeeRatio<u64, u64>>, MathError> {
+        Ok(FloorDiv(U64FeeRatio::try_from_fee_num_and_denom(
             self.numerator,
             self.denominator,
         )?))
@@ -0,0 +1,323 @@
+use sanctum_token_ratio::{FloorDiv, MathError, U64FeeRatio};
+use solana_program::clock::Epoch;
+use spl_stake_pool_interface::{Fee, FutureEpochFee, StakePool};
+
+use crate::{CmpFee, FeeToRatio};
+
+/// The stake pool fees whose changes only take effect after 2 epoch boundaries,
+/// stored in the pool's [`FutureEpochFee`] fields
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FutureFeeKind {
+    Epoch,
+    StakeWithdrawal,
+    SolWithdrawal,
+}
+
+impl FutureFeeKind {
+    pub const ALL: [Self; 3] = [Self::Epoch, Self::StakeWithdrawal, Self::SolWithdrawal];
+
+    pub fn current_fee<'a>(&self, stake_pool: &'a StakePool) -> &'a Fee {
+        match self {
+            Self::Epoch => &stake_pool.epoch_fee,
+            Self::StakeWithdrawal => &stake_pool.stake_withdrawal_fee,
+            Self::SolWithdrawal => &stake_pool.sol_withdrawal_fee,
+        }
+    }
+
+    pub fn next_fee<'a>(&self, stake_pool: &'a StakePool) -> &'a FutureEpochFee {
+        match self {
+            Self::Epoch => &stake_pool.next_epoch_fee,
+            Self::StakeWithdrawal => &stake_pool.next_stake_withdrawal_fee,
+            Self::SolWithdrawal => &stake_pool.next_sol_withdrawal_fee,
+        }
+    }
+
+    fn fee_mut<'a>(&self, stake_pool: &'a mut StakePool) -> (&'a mut Fee, &'a mut FutureEpochFee) {
+        match self {
+            Self::Epoch => (&mut stake_pool.epoch_fee, &mut stake_pool.next_epoch_fee),
+            Self::StakeWithdrawal => (
+                &mut stake_pool.stake_withdrawal_fee,
+                &mut stake_pool.next_stake_withdrawal_fee,
+            ),
+            Self::SolWithdrawal => (
+                &mut stake_pool.sol_withdrawal_fee,
+                &mut stake_pool.next_sol_withdrawal_fee,
+            ),
+        }
+    }
+}
+
+/// A pending fee change
+#[derive(Clone, Debug, PartialEq)]
+pub struct FeeChange {
+    pub kind: FutureFeeKind,
+    pub fee: Fee,
+
+    /// Earliest epoch the new fee applies in,
+    /// reached if `UpdateStakePoolBalance` runs every epoch
+    pub effective_epoch: Epoch,
+}
+
+/// Epoch-aware view of a stake pool's fees.
+///
+/// `FutureEpochFee`s are advanced by a single step on each `UpdateStakePoolBalance`
+/// that runs in a new epoch, with `Two` becoming `One` and `One` being applied:
+/// - if `current_epoch > stake_pool.last_update_epoch`, the pool must be updated before
+///   it can be used again, which advances its fees once in `current_epoch`
+/// - all later epochs are assumed to be updated
+#[derive(Clone, Copy, Debug)]
+pub struct StakePoolFeesAtEpoch<'a> {
+    pub stake_pool: &'a StakePool,
+    pub current_epoch: Epoch,
+}
+
+impl<'a> StakePoolFeesAtEpoch<'a> {
+    /// First epoch, from `current_epoch` onwards, in which the pool's fees will be advanced
+    pub fn next_update_epoch(&self) -> Epoch {
+        if self.current_epoch > self.stake_pool.last_update_epoch {
+            self.current_epoch
+        } else {
+            self.stake_pool.last_update_epoch.saturating_add(1)
+        }
+    }
+
+    /// Number of times the pool's fees will have been advanced by `epoch`
+    fn updates_by(&self, epoch: Epoch) -> u64 {
+        epoch
+            .checked_sub(self.next_update_epoch())
+            .map_or(0, |e| e.saturating_add(1))
+    }
+
+    /// The pending change to `kind`, if any
+    pub fn pending_change(&self, kind: FutureFeeKind) -> Option<FeeChange> {
+        let (fee, updates_required) = match kind.next_fee(self.stake_pool) {
+            FutureEpochFee::None => return None,
+            FutureEpochFee::One { fee } => (fee, 1),
+            FutureEpochFee::Two { fee } => (fee, 2),
+        };
+        Some(FeeChange {
+            kind,
+            fee: fee.clone(),
+            effective_epoch: self
+                .next_update_epoch()
+                .saturating_add(updates_required - 1),
+        })
+    }
+
+    /// The fee of `kind` that applies in `epoch`.
+    /// Epochs before `current_epoch` are treated as `current_epoch`
+    pub fn fee_at_epoch(&self, kind: FutureFeeKind, epoch: Epoch) -> &'a Fee {
+        let updates = self.updates_by(epoch.max(self.current_epoch));
+        match kind.next_fee(self.stake_pool) {
+            FutureEpochFee::One { fee } if updates >= 1 => fee,
+            FutureEpochFee::Two { fee } if updates >= 2 => fee,
+            _ => kind.current_fee(self.stake_pool),
+        }
+    }
+
+    /// The fee of `kind` that applies in `current_epoch`
+    pub fn fee(&self, kind: FutureFeeKind) -> &'a Fee {
+        self.fee_at_epoch(kind, self.current_epoch)
+    }
+
+    /// [`Self::fee_at_epoch`] as a fee ratio
+    pub fn fee_ratio_at_epoch(
+        &self,
+        kind: FutureFeeKind,
+        epoch: Epoch,
+    ) -> Result<FloorDiv<U64FeeRatio<u64, u64>>, MathError> {
+        self.fee_at_epoch(kind, epoch).to_fee_ratio()
+    }
+
+    /// All pending fee changes, ordered by effective epoch.
+    ///
+    /// Changes that would not change the fee's value are still included
+    pub fn timeline(&self) -> Vec<FeeChange> {
+        let mut res: Vec<FeeChange> = FutureFeeKind::ALL
+            .iter()
+            .filter_map(|kind| self.pending_change(*kind))
+            .collect();
+        res.sort_by_key(|c| c.effective_epoch);
+        res
+    }
+
+    /// Returns true if the fee of `kind` will be different in `epoch` from what it is in `current_epoch`
+    pub fn changes_by(&self, kind: FutureFeeKind, epoch: Epoch) -> bool {
+        CmpFee(self.fee(kind)) != CmpFee(self.fee_at_epoch(kind, epoch))
+    }
+
+    /// A copy of the stake pool with its fee fields as they will be in `epoch`,
+    /// which can be used with the quote traits to quote for that epoch.
+    /// All other fields, including `last_update_epoch`, are left unchanged.
+    pub fn stake_pool_at_epoch(&self, epoch: Epoch) -> StakePool {
+        let updates = self.updates_by(epoch.max(self.current_epoch));
+        let mut res = self.stake_pool.clone();
+        for kind in FutureFeeKind::ALL {
+            let (fee, next) = kind.fee_mut(&mut res);
+            for _ in 0..updates.min(2) {
+                advance_future_epoch_fee(fee, next);
+            }
+        }
+        res
+    }
+
+    /// [`Self::stake_pool_at_epoch`] for `current_epoch`
+    pub fn stake_pool(&self) -> StakePool {
+        self.stake_pool_at_epoch(self.current_epoch)
+    }
+}
+
+/// Advances `next` by a single step as `UpdateStakePoolBalance` does in a new epoch,
+/// applying it to `fee` if it takes effect this epoch
+pub(crate) fn advance_future_epoch_fee(fee: &mut Fee, next: &mut FutureEpochFee) {
+    *next = match next {
+        FutureEpochFee::None => FutureEpochFee::None,
+        FutureEpochFee::One { fee: new_fee } => {
+            *fee = new_fee.clone();
+            FutureEpochFee::None
+        }
+        FutureEpochFee::Two { fee } => FutureEpochFee::One { fee: fee.clone() },
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use solana_program::pubkey::Pubkey;
+    use spl_stake_pool::state::{Fee as SplFee, FutureEpoch};
+    use spl_stake_pool_interface::{AccountType, Lockup};
+
+    use crate::ZERO_FEE;
+
+    use super::*;
+
+    fn fee() -> impl Strategy<Value = Fee> {
+        (0..=100u64, 1..=100u64).prop_map(|(numerator, denominator)| Fee {
+            denominator,
+            numerator,
+        })
+    }
+
+    fn future_epoch_fee() -> impl Strategy<Value = FutureEpochFee> {
+        prop_oneof![
+            Just(FutureEpochFee::None),
+            fee().prop_map(|fee| FutureEpochFee::One { fee }),
+            fee().prop_map(|fee| FutureEpochFee::Two { fee }),
+        ]
+    }
+
+    fn stake_pool(
+        [epoch_fee, stake_withdrawal_fee, sol_withdrawal_fee]: [Fee; 3],
+        [next_epoch_fee, next_stake_withdrawal_fee, next_sol_withdrawal_fee]: [FutureEpochFee; 3],
+        last_update_epoch: Epoch,
+    ) -> StakePool {
+        StakePool {
+            account_type: AccountType::StakePool,
+            manager: Pubkey::default(),
+            staker: Pubkey::default(),
+            stake_deposit_authority: Pubkey::default(),
+            stake_withdraw_bump_seed: 0,
+            validator_list: Pubkey::default(),
+            reserve_stake: Pubkey::default(),
+            pool_mint: Pubkey::default(),
+            manager_fee_account: Pubkey::default(),
+            token_program: Pubkey::default(),
+            total_lamports: 0,
+            pool_token_supply: 0,
+            last_update_epoch,
+            lockup: Lockup {
+                unix_timestamp: 0,
+                epoch: 0,
+                custodian: Pubkey::default(),
+            },
+            epoch_fee,
+            next_epoch_fee,
+            preferred_deposit_validator_vote_address: None,
+            preferred_withdraw_validator_vote_address: None,
+            stake_deposit_fee: ZERO_FEE,
+            stake_withdrawal_fee,
+            next_stake_withdrawal_fee,
+            stake_referral_fee: 0,
+            sol_deposit_authority: None,
+            sol_deposit_fee: ZERO_FEE,
+            sol_referral_fee: 0,
+            sol_withdraw_authority: None,
+            sol_withdrawal_fee,
+            next_sol_withdrawal_fee,
+            last_epoch_pool_token_supply: 0,
+            last_epoch_total_lamports: 0,
+        }
+    }
+
+    fn spl_fee(
+        Fee {
+            denominator,
+            numerator,
+        }: &Fee,
+    ) -> SplFee {
+        SplFee {
+            denominator: *denominator,
+            numerator: *numerator,
+        }
+    }
+
+    /// The current and next fee of `kind` as the onchain program's types
+    fn spl_fees(kind: FutureFeeKind, stake_pool: &StakePool) -> (SplFee, FutureEpoch<SplFee>) {
+        let next = match kind.next_fee(stake_pool) {
+            FutureEpochFee::None => FutureEpoch::None,
+            FutureEpochFee::One { fee } => FutureEpoch::One(spl_fee(fee)),
+            FutureEpochFee::Two { fee } => FutureEpoch::Two(spl_fee(fee)),
+        };
+        (spl_fee(kind.current_fee(stake_pool)), next)
+    }
+
+    /// Runs the fee-related parts of `UpdateStakePoolBalance` for a single fee
+    fn update_spl_fees((fee, next): &mut (SplFee, FutureEpoch<SplFee>)) {
+        if let Some(next_fee) = next.get() {
+            *fee = *next_fee;
+        }
+        next.update_epoch();
+    }
+
+    proptest! {
+        #[test]
+        fn fees_at_epoch_match_updating_every_epoch(
+            fees in [fee(), fee(), fee()],
+            next_fees in [future_epoch_fee(), future_epoch_fee(), future_epoch_fee()],
+            last_update_epoch in 0..=10u64,
+            epochs_since_update in 0..=3u64,
+            epochs_ahead in 0..=4u64,
+        ) {
+            let current_epoch = last_update_epoch + epochs_since_update;
+            let stake_pool = stake_pool(fees, next_fees, last_update_epoch);
+            let fees_at_epoch = StakePoolFeesAtEpoch {
+                stake_pool: &stake_pool,
+                current_epoch,
+            };
+
+            let mut expected = FutureFeeKind::ALL.map(|kind| spl_fees(kind, &stake_pool));
+            let mut expected_last_update_epoch = last_update_epoch;
+            for epoch in current_epoch..=current_epoch + epochs_ahead {
+                if expected_last_update_epoch < epoch {
+                    expected.iter_mut().for_each(update_spl_fees);
+                    expected_last_update_epoch = epoch;
+                }
+                let actual = fees_at_epoch.stake_pool_at_epoch(epoch);
+                for (kind, expected) in FutureFeeKind::ALL.into_iter().zip(&expected) {
+                    prop_assert_eq!(&expected.0, &spl_fee(fees_at_epoch.fee_at_epoch(kind, epoch)));
+                    prop_assert_eq!(expected, &spl_fees(kind, &actual));
+                }
+            }
+
+            let timeline = fees_at_epoch.timeline();
+            prop_assert!(timeline.windows(2).all(|w| w[0].effective_epoch <= w[1].effective_epoch));
+            for FeeChange { kind, fee, effective_epoch } in timeline {
+                prop_assert!(effective_epoch >= current_epoch);
+                prop_assert_eq!(&fee, fees_at_epoch.fee_at_epoch(kind, effective_epoch));
+                if effective_epoch > current_epoch {
+                    prop_assert_eq!(kind.current_fee(&stake_pool), fees_at_epoch.fee_at_epoch(kind, effective_epoch - 1));
+                }
+            }
+        }
+    }
+}
@@ -2,14 +2,22 @@ pub mod account_resolvers;
 pub mod account_serde;
 pub mod consts;
 pub mod fee;
+pub mod future_fee;
 pub mod pda;
 pub mod quote;
+pub mod readonly;
+pub mod rebalance;
+pub mod sim;
 pub mod size_utils;
 
 // pub use account_resolvers::*; // dont re-export account_resolvers due to possible name collisions between the many builder structs in there
 pub use account_serde::*;
 pub use consts::*;
 pub use fee::*;
+pub use future_fee::*;
 pub use pda::*;
 pub use quote::*;
+pub use readonly::*;
+pub use rebalance::*;
+pub use sim::*;
 pub use size_utils::*;
@@ -2,6 +2,8 @@ use std::num::NonZeroU32;
 
 use solana_program::pubkey::{Pubkey, PubkeyError};
 
+use crate::MPL_TOKEN_METADATA_PROGRAM_ID;
+
 /// Seed for deposit authority seed
 pub const AUTHORITY_DEPOSIT: &[u8] = b"deposit";
 
@@ -14,6 +16,9 @@ pub const TRANSIENT_STAKE_SEED_PREFIX: &[u8] = b"transient";
 /// Seed for ephemeral stake account
 pub const EPHEMERAL_STAKE_SEED_PREFIX: &[u8] = b"ephemeral";
 
+/// Seed for metaplex token metadata account
+pub const TOKEN_METADATA_SEED_PREFIX: &[u8] = b"metadata";
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct FindDepositAuthority {
     pub pool: Pubkey,
@@ -240,3 +245,22 @@ impl CreatEphemeralStakeAccount {
         Pubkey::create_program_address(&self.signer_seeds(), program_id)
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FindTokenMetadataAccount {
+    pub mint: Pubkey,
+}
+
+impl FindTokenMetadataAccount {
+    pub fn seeds(&self) -> [&[u8]; 3] {
+        [
+            TOKEN_METADATA_SEED_PREFIX,
+            MPL_TOKEN_METADATA_PROGRAM_ID.as_ref(),
+            self.mint.as_ref(),
+        ]
+    }
+
+    pub fn run(&self) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&self.seeds(), &MPL_TOKEN_METADATA_PROGRAM_ID)
+    }
+}
@@ -0,0 +1,141 @@
+use sanctum_token_ratio::{ReversibleFee, ReversibleRatio, U64ValueRange};
+use solana_program::program_error::ProgramError;
+use solana_readonly_account::ReadonlyAccountData;
+use spl_stake_pool_interface::StakePool;
+
+use crate::{QuoteStakePool, ValidStakePoolAccount};
+
+use super::exact_out::{min_input_range, zero_if_too_small};
+
+/// All in terms of newly minted pool tokens
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DepositSolQuote {
+    pub manager: u64,
+    pub referrer: u64,
+    pub user: u64,
+}
+
+pub trait QuoteDepositSol {
+    fn quote_deposit_sol(&self, deposit_lamports: u64) -> Result<DepositSolQuote, ProgramError>;
+}
+
+impl<T: QuoteDepositSol> QuoteDepositSol for &T {
+    fn quote_deposit_sol(&self, deposit_lamports: u64) -> Result<DepositSolQuote, ProgramError> {
+        (*self).quote_deposit_sol(deposit_lamports)
+    }
+}
+
+impl QuoteDepositSol for StakePool {
+    fn quote_deposit_sol(&self, deposit_lamports: u64) -> Result<DepositSolQuote, ProgramError> {
+        quote_deposit_sol(self, deposit_lamports)
+    }
+}
+
+impl<T: ReadonlyAccountData> QuoteDepositSol for ValidStakePoolAccount<T> {
+    fn quote_deposit_sol(&self, deposit_lamports: u64) -> Result<DepositSolQuote, ProgramError> {
+        quote_deposit_sol(self, deposit_lamports)
+    }
+}
+
+fn quote_deposit_sol<P: QuoteStakePool>(
+    pool: &P,
+    deposit_lamports: u64,
+) -> Result<DepositSolQuote, ProgramError> {
+    // copied from
+    // https://github.com/solana-labs/solana-program-library/blob/3e35101763097b5b3d21686191132e5d930f5b23/stake-pool/program/src/processor.rs#L3016-L3044
+
+    let new_pool_tokens = pool.mint_ratio().apply(deposit_lamports)?;
+
+    let deposit_fee = pool.sol_deposit_fee_ratio()?.apply(new_pool_tokens)?;
+    let total_fee = deposit_fee.fee_charged();
+    let user = deposit_fee.amt_after_fee();
+    if user == 0 {
+        // DepositTooSmall
+        return Err(ProgramError::InsufficientFunds);
+    }
+
+    let referrer = pool.sol_referral_bps_fee()?.apply(total_fee)?.fee_charged();
+
+    let manager = total_fee
+        .checked_sub(referrer)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    Ok(DepositSolQuote {
+        manager,
+        referrer,
+        user,
+    })
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DepositSolExactOutQuote {
+    /// Range of lamports that can be deposited to receive `quote.user` pool tokens,
+    /// the smallest attainable amount that is at least the desired amount
+    pub deposit_lamports: U64ValueRange,
+
+    /// Quote for depositing `deposit_lamports.get_min()`
+    pub quote: DepositSolQuote,
+}
+
+pub trait QuoteDepositSolExactOut {
+    fn quote_deposit_sol_exact_out(
+        &self,
+        user_pool_tokens: u64,
+    ) -> Result<DepositSolExactOutQuote, ProgramError>;
+}
+
+impl<T: QuoteDepositSolExactOut> QuoteDepositSolExactOut for &T {
+    fn quote_deposit_sol_exact_out(
+        &self,
+        user_pool_tokens: u64,
+    ) -> Result<DepositSolExactOutQuote, ProgramError> {
+        (*self).quote_deposit_sol_exact_out(user_pool_tokens)
+    }
+}
+
+impl QuoteDepositSolExactOut for StakePool {
+    fn quote_deposit_sol_exact_out(
+        &self,
+        user_pool_tokens: u64,
+    ) -> Result<DepositSolExactOutQuote, ProgramError> {
+        quote_deposit_sol_exact_out(self, user_pool_tokens)
+    }
+}
+
+impl<T: ReadonlyAccountData> QuoteDepositSolExactOut for ValidStakePoolAccount<T> {
+    fn quote_deposit_sol_exact_out(
+        &self,
+        user_pool_tokens: u64,
+    ) -> Result<DepositSolExactOutQuote, ProgramError> {
+        quote_deposit_sol_exact_out(self, user_pool_tokens)
+    }
+}
+
+fn quote_deposit_sol_exact_out<P: QuoteStakePool>(
+    pool: &P,
+    user_pool_tokens: u64,
+) -> Result<DepositSolExactOutQuote, ProgramError> {
+    if user_pool_tokens == 0 {
+        // DepositTooSmall
+        return Err(ProgramError::InsufficientFunds);
+    }
+
+    let new_pool_tokens = pool
+        .sol_deposit_fee_ratio()?
+        .reverse_from_amt_after_fee(user_pool_tokens)?;
+    let mint_ratio = pool.mint_ratio();
+    let candidates = U64ValueRange::try_from_min_max(
+        mint_ratio.reverse(new_pool_tokens.get_min())?.get_min(),
+        mint_ratio.reverse(new_pool_tokens.get_max())?.get_max(),
+    )?;
+
+    let deposit_lamports = min_input_range(candidates, user_pool_tokens, |lamports| {
+        zero_if_too_small(quote_deposit_sol(pool, lamports).map(|q| q.user))
+    })?;
+    let quote = quote_deposit_sol(pool, deposit_lamports.get_min())?;
+
+    Ok(DepositSolExactOutQuote {
+        deposit_lamports,
+        quote,
+    })
+}
@@ -1,8 +1,11 @@
-use sanctum_token_ratio::{ReversibleFee, ReversibleRatio};
+use sanctum_token_ratio::{ReversibleFee, ReversibleRatio, U64ValueRange};
 use solana_program::program_error::ProgramError;
+use solana_readonly_account::ReadonlyAccountData;
 use spl_stake_pool_interface::StakePool;
 
-use crate::{QuoteStakePool, StakeAccountDataForQuoting};
+use crate::{QuoteStakePool, StakeAccountDataForQuoting, ValidStakePoolAccount};
+
+use super::exact_out::{min_input_range, zero_if_too_small};
 
 /// All in terms of newly minted pool tokens
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -31,59 +34,190 @@ impl<T: QuoteDepositStake> QuoteDepositStake for &T {
 impl QuoteDepositStake for StakePool {
     fn quote_deposit_stake(
         &self,
-        StakeAccountDataForQuoting {
-            staked_lamports,
-            unstaked_lamports,
-        }: &StakeAccountDataForQuoting,
+        stake_account: &StakeAccountDataForQuoting,
+    ) -> Result<DepositStakeQuote, ProgramError> {
+        quote_deposit_stake(self, stake_account)
+    }
+}
+
+impl<T: ReadonlyAccountData> QuoteDepositStake for ValidStakePoolAccount<T> {
+    fn quote_deposit_stake(
+        &self,
+        stake_account: &StakeAccountDataForQuoting,
     ) -> Result<DepositStakeQuote, ProgramError> {
-        // copied from
-        // https://github.com/solana-labs/solana-program-library/blob/3e35101763097b5b3d21686191132e5d930f5b23/stake-pool/program/src/processor.rs#L2831-L2874
-
-        let total_deposit_lamports = staked_lamports
-            .checked_add(*unstaked_lamports)
-            .ok_or(ProgramError::ArithmeticOverflow)?;
-
-        let mint_ratio = self.mint_ratio();
-        let new_pool_tokens = mint_ratio.apply(total_deposit_lamports)?;
-        let new_pool_tokens_from_stake = mint_ratio.apply(*staked_lamports)?;
-        let new_pool_tokens_from_sol = new_pool_tokens
-            .checked_sub(new_pool_tokens_from_stake)
-            .ok_or(ProgramError::ArithmeticOverflow)?;
-
-        let stake_deposit_fee = self
-            .stake_deposit_fee_ratio()?
-            .apply(new_pool_tokens_from_stake)?
-            .fee_charged();
-        let sol_deposit_fee = self
-            .sol_deposit_fee_ratio()?
-            .apply(new_pool_tokens_from_sol)?
-            .fee_charged();
-
-        let total_fee = stake_deposit_fee
-            .checked_add(sol_deposit_fee)
-            .ok_or(ProgramError::ArithmeticOverflow)?;
-
-        let user = new_pool_tokens
-            .checked_sub(total_fee)
-            .ok_or(ProgramError::ArithmeticOverflow)?;
-        if user == 0 {
-            // DepositTooSmall
-            return Err(ProgramError::InsufficientFunds);
-        }
-
-        let referrer = self
-            .stake_referral_bps_fee()?
-            .apply(total_fee)?
-            .fee_charged();
-
-        let manager = total_fee
-            .checked_sub(referrer)
-            .ok_or(ProgramError::ArithmeticOverflow)?;
-
-        Ok(DepositStakeQuote {
-            manager,
-            referrer,
-            user,
-        })
+        quote_deposit_stake(self, stake_account)
+    }
+}
+
+fn quote_deposit_stake<P: QuoteStakePool>(
+    pool: &P,
+    StakeAccountDataForQuoting {
+        staked_lamports,
+        unstaked_lamports,
+    }: &StakeAccountDataForQuoting,
+) -> Result<DepositStakeQuote, ProgramError> {
+    // copied from
+    // https://github.com/solana-labs/solana-program-library/blob/3e35101763097b5b3d21686191132e5d930f5b23/stake-pool/program/src/processor.rs#L2831-L2874
+
+    let total_deposit_lamports = staked_lamports
+        .checked_add(*unstaked_lamports)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    let mint_ratio = pool.mint_ratio();
+    let new_pool_tokens = mint_ratio.apply(total_deposit_lamports)?;
+    let new_pool_tokens_from_stake = mint_ratio.apply(*staked_lamports)?;
+    let new_pool_tokens_from_sol = new_pool_tokens
+        .checked_sub(new_pool_tokens_from_stake)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    let stake_deposit_fee = pool
+        .stake_deposit_fee_ratio()?
+        .apply(new_pool_tokens_from_stake)?
+        .fee_charged();
+    let sol_deposit_fee = pool
+        .sol_deposit_fee_ratio()?
+        .apply(new_pool_tokens_from_sol)?
+        .fee_charged();
+
+    let total_fee = stake_deposit_fee
+        .checked_add(sol_deposit_fee)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    let user = new_pool_tokens
+        .checked_sub(total_fee)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    if user == 0 {
+        // DepositTooSmall
+        return Err(ProgramError::InsufficientFunds);
+    }
+
+    let referrer = pool
+        .stake_referral_bps_fee()?
+        .apply(total_fee)?
+        .fee_charged();
+
+    let manager = total_fee
+        .checked_sub(referrer)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    Ok(DepositStakeQuote {
+        manager,
+        referrer,
+        user,
+    })
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DepositStakeExactOutQuote {
+    /// Range of staked lamports the deposited stake account can have
+    /// to receive `quote.user` pool tokens, an amount that is at least the desired amount.
+    ///
+    /// The stake and sol portions of the deposit are rounded separately, so the amount of pool tokens
+    /// received is not strictly nondecreasing in the staked lamports and may dip by 1 pool token.
+    /// `staked_lamports.get_min()` is therefore only guaranteed to be a local minimum,
+    /// and some inputs in this range may result in 1 pool token less than `quote.user`
+    pub staked_lamports: U64ValueRange,
+
+    /// Quote for depositing a stake account with `staked_lamports.get_min()`
+    pub quote: DepositStakeQuote,
+}
+
+pub trait QuoteDepositStakeExactOut {
+    /// `unstaked_lamports` is held constant since it is usually
+    /// just the rent-exempt reserve of the stake account to be deposited
+    fn quote_deposit_stake_exact_out(
+        &self,
+        unstaked_lamports: u64,
+        user_pool_tokens: u64,
+    ) -> Result<DepositStakeExactOutQuote, ProgramError>;
+}
+
+impl<T: QuoteDepositStakeExactOut> QuoteDepositStakeExactOut for &T {
+    fn quote_deposit_stake_exact_out(
+        &self,
+        unstaked_lamports: u64,
+        user_pool_tokens: u64,
+    ) -> Result<DepositStakeExactOutQuote, ProgramError> {
+        (*self).quote_deposit_stake_exact_out(unstaked_lamports, user_pool_tokens)
+    }
+}
+
+impl QuoteDepositStakeExactOut for StakePool {
+    fn quote_deposit_stake_exact_out(
+        &self,
+        unstaked_lamports: u64,
+        user_pool_tokens: u64,
+    ) -> Result<DepositStakeExactOutQuote, ProgramError> {
+        quote_deposit_stake_exact_out(self, unstaked_lamports, user_pool_tokens)
     }
 }
+
+impl<T: ReadonlyAccountData> QuoteDepositStakeExactOut for ValidStakePoolAccount<T> {
+    fn quote_deposit_stake_exact_out(
+        &self,
+        unstaked_lamports: u64,
+        user_pool_tokens: u64,
+    ) -> Result<DepositStakeExactOutQuote, ProgramError> {
+        quote_deposit_stake_exact_out(self, unstaked_lamports, user_pool_tokens)
+    }
+}
+
+fn quote_deposit_stake_exact_out<P: QuoteStakePool>(
+    pool: &P,
+    unstaked_lamports: u64,
+    user_pool_tokens: u64,
+) -> Result<DepositStakeExactOutQuote, ProgramError> {
+    if user_pool_tokens == 0 {
+        // DepositTooSmall
+        return Err(ProgramError::InsufficientFunds);
+    }
+
+    // The stake and sol portions of the deposit are charged different fees,
+    // so new_pool_tokens must lie between the amounts obtained by reversing either fee.
+    // Each portion's fee is rounded separately, so allow for an additional fee token.
+    // Reversing a 100% fee fails, in which case that portion does not bound new_pool_tokens
+    let via_stake_fee = pool
+        .stake_deposit_fee_ratio()?
+        .reverse_from_amt_after_fee(user_pool_tokens)
+        .unwrap_or(U64ValueRange::FULL);
+    let via_sol_fee = pool
+        .sol_deposit_fee_ratio()?
+        .reverse_from_amt_after_fee(user_pool_tokens)
+        .unwrap_or(U64ValueRange::FULL);
+    let min_new_pool_tokens = via_stake_fee.get_min().min(via_sol_fee.get_min());
+    let max_new_pool_tokens = via_stake_fee
+        .get_max()
+        .max(via_sol_fee.get_max())
+        .saturating_add(1);
+
+    let mint_ratio = pool.mint_ratio();
+    let candidates = U64ValueRange::try_from_min_max(
+        mint_ratio
+            .reverse(min_new_pool_tokens)?
+            .get_min()
+            .saturating_sub(unstaked_lamports),
+        mint_ratio
+            .reverse(max_new_pool_tokens)
+            .map_or(u64::MAX, |r| r.get_max())
+            .saturating_sub(unstaked_lamports),
+    )?;
+
+    let quote_for_staked_lamports = |staked_lamports| {
+        quote_deposit_stake(
+            pool,
+            &StakeAccountDataForQuoting {
+                staked_lamports,
+                unstaked_lamports,
+            },
+        )
+    };
+    let staked_lamports = min_input_range(candidates, user_pool_tokens, |staked_lamports| {
+        zero_if_too_small(quote_for_staked_lamports(staked_lamports).map(|q| q.user))
+    })?;
+    let quote = quote_for_staked_lamports(staked_lamports.get_min())?;
+
+    Ok(DepositStakeExactOutQuote {
+        staked_lamports,
+        quote,
+    })
+}
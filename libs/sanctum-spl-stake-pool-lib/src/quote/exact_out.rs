@@ -0,0 +1,242 @@
+use std::cmp::Ordering;
+
+use sanctum_token_ratio::{MathError, U64ValueRange};
+use solana_program::program_error::ProgramError;
+
+/// Maps the `InsufficientFunds` error returned by quotes for amounts that are too small to 0
+/// so that quotes can be used as nondecreasing functions of their input amount
+pub(crate) fn zero_if_too_small(quote_res: Result<u64, ProgramError>) -> Result<u64, ProgramError> {
+    match quote_res {
+        Err(ProgramError::InsufficientFunds) => Ok(0),
+        res => res,
+    }
+}
+
+/// Finds the smallest input `min` in `candidates` such that `quote(min) >= min_output`,
+/// then returns the range of inputs starting at `min` that result in exactly `quote(min)`.
+///
+/// `candidates` must contain all inputs that result in exactly `min_output`.
+/// `quote` should be nondecreasing. Any errors returned by `quote` while searching,
+/// e.g. overflows for large inputs, are treated as outputs greater than what is being searched for.
+///
+/// Errors if no input in `candidates` results in at least `min_output`
+pub(crate) fn min_input_range(
+    candidates: U64ValueRange,
+    min_output: u64,
+    quote: impl Fn(u64) -> Result<u64, ProgramError>,
+) -> Result<U64ValueRange, ProgramError> {
+    let cmp_quote = |x: u64, output: u64| match quote(x) {
+        Ok(out) => out.cmp(&output),
+        Err(_) => Ordering::Greater,
+    };
+
+    // find first x such that quote(x) >= min_output
+    let mut lo = candidates.get_min();
+    let mut hi = candidates.get_max();
+    if cmp_quote(hi, min_output) == Ordering::Less {
+        return Err(MathError.into());
+    }
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        match cmp_quote(mid, min_output) {
+            Ordering::Less => lo = mid + 1,
+            _ => hi = mid,
+        }
+    }
+    let min = lo;
+    let output = quote(min)?;
+
+    // candidates might not contain all inputs that result in `output`
+    // if `min_output` cannot be obtained exactly, so search beyond it
+    let mut hi = candidates.get_max();
+    while hi < u64::MAX && cmp_quote(hi, output) != Ordering::Greater {
+        hi = hi.saturating_add(hi - lo + 1);
+    }
+
+    // find last x such that quote(x) <= output
+    while lo < hi {
+        let mid = hi - (hi - lo) / 2;
+        match cmp_quote(mid, output) {
+            Ordering::Greater => hi = mid - 1,
+            _ => lo = mid,
+        }
+    }
+
+    Ok(U64ValueRange::try_from_min_max(min, lo)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use solana_program::pubkey::Pubkey;
+    use spl_stake_pool_interface::{AccountType, Fee, FutureEpochFee, Lockup, StakePool};
+
+    use crate::{
+        QuoteDepositSol, QuoteDepositSolExactOut, QuoteDepositStake, QuoteDepositStakeExactOut,
+        QuoteWithdrawSol, QuoteWithdrawSolExactOut, QuoteWithdrawStake, QuoteWithdrawStakeExactOut,
+        StakeAccountDataForQuoting,
+    };
+
+    prop_compose! {
+        fn fee()
+            (denominator in 1..=10_000u64)
+            (numerator in 0..=denominator, denominator in Just(denominator)) -> Fee {
+                Fee { denominator, numerator }
+            }
+    }
+
+    prop_compose! {
+        /// pool tokens are worth between 0.5 and 2 SOL
+        fn stake_pool()
+            (total_lamports in 1_000_000_000..=1_000_000_000_000_000_000u64)
+            (
+                pool_token_supply in total_lamports / 2..=total_lamports.saturating_mul(2),
+                total_lamports in Just(total_lamports),
+                stake_deposit_fee in fee(),
+                sol_deposit_fee in fee(),
+                stake_withdrawal_fee in fee(),
+                sol_withdrawal_fee in fee(),
+                stake_referral_fee in 0..=100u8,
+                sol_referral_fee in 0..=100u8,
+            ) -> StakePool {
+                StakePool {
+                    account_type: AccountType::StakePool,
+                    manager: Pubkey::default(),
+                    staker: Pubkey::default(),
+                    stake_deposit_authority: Pubkey::default(),
+                    stake_withdraw_bump_seed: 0,
+                    validator_list: Pubkey::default(),
+                    reserve_stake: Pubkey::default(),
+                    pool_mint: Pubkey::default(),
+                    manager_fee_account: Pubkey::default(),
+                    token_program: Pubkey::default(),
+                    total_lamports,
+                    pool_token_supply,
+                    last_update_epoch: 0,
+                    lockup: Lockup {
+                        unix_timestamp: 0,
+                        epoch: 0,
+                        custodian: Pubkey::default(),
+                    },
+                    epoch_fee: Fee { denominator: 1, numerator: 0 },
+                    next_epoch_fee: FutureEpochFee::None,
+                    preferred_deposit_validator_vote_address: None,
+                    preferred_withdraw_validator_vote_address: None,
+                    stake_deposit_fee,
+                    stake_withdrawal_fee,
+                    next_stake_withdrawal_fee: FutureEpochFee::None,
+                    stake_referral_fee,
+                    sol_deposit_authority: None,
+                    sol_deposit_fee,
+                    sol_referral_fee,
+                    sol_withdraw_authority: None,
+                    sol_withdrawal_fee,
+                    next_sol_withdrawal_fee: FutureEpochFee::None,
+                    last_epoch_pool_token_supply: pool_token_supply,
+                    last_epoch_total_lamports: total_lamports,
+                }
+            }
+    }
+
+    proptest! {
+        #[test]
+        fn deposit_sol_exact_out_round_trip(
+            stake_pool in stake_pool(),
+            deposit_lamports in 0..=1_000_000_000_000_000u64,
+        ) {
+            let user = match stake_pool.quote_deposit_sol(deposit_lamports) {
+                Ok(q) => q.user,
+                Err(_) => return Ok(()),
+            };
+            let r = stake_pool.quote_deposit_sol_exact_out(user).unwrap();
+            let (min, max) = (r.deposit_lamports.get_min(), r.deposit_lamports.get_max());
+            prop_assert!(min <= deposit_lamports && deposit_lamports <= max);
+            prop_assert_eq!(r.quote, stake_pool.quote_deposit_sol(min).unwrap());
+            prop_assert_eq!(r.quote.user, user);
+            prop_assert_eq!(stake_pool.quote_deposit_sol(max).unwrap().user, user);
+            if let Ok(q) = stake_pool.quote_deposit_sol(min - 1) {
+                prop_assert!(q.user < user);
+            }
+            prop_assert!(stake_pool.quote_deposit_sol(max + 1).unwrap().user > user);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn deposit_stake_exact_out_round_trip(
+            stake_pool in stake_pool(),
+            staked_lamports in 0..=1_000_000_000_000_000u64,
+            unstaked_lamports in 0..=1_000_000_000u64,
+        ) {
+            let quote_for_staked_lamports = |staked_lamports| {
+                stake_pool.quote_deposit_stake(&StakeAccountDataForQuoting {
+                    staked_lamports,
+                    unstaked_lamports,
+                })
+            };
+            let user = match quote_for_staked_lamports(staked_lamports) {
+                Ok(q) => q.user,
+                Err(_) => return Ok(()),
+            };
+            let r = stake_pool
+                .quote_deposit_stake_exact_out(unstaked_lamports, user)
+                .unwrap();
+            let (min, max) = (r.staked_lamports.get_min(), r.staked_lamports.get_max());
+            prop_assert_eq!(r.quote, quote_for_staked_lamports(min).unwrap());
+            prop_assert!(r.quote.user >= user);
+            // pool tokens received may dip by 1 due to the stake and sol portions being rounded separately
+            let max_user = quote_for_staked_lamports(max).unwrap().user;
+            prop_assert!(max_user == r.quote.user || max_user + 1 == r.quote.user);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn withdraw_sol_exact_out_round_trip(
+            stake_pool in stake_pool(),
+            pool_tokens in 0..=1_000_000_000_000_000u64,
+        ) {
+            let lamports_out = match stake_pool.quote_withdraw_sol(pool_tokens) {
+                Ok(q) => q.lamports_out,
+                Err(_) => return Ok(()),
+            };
+            let r = stake_pool.quote_withdraw_sol_exact_out(lamports_out).unwrap();
+            let (min, max) = (r.pool_tokens.get_min(), r.pool_tokens.get_max());
+            prop_assert!(min <= pool_tokens && pool_tokens <= max);
+            prop_assert_eq!(r.quote, stake_pool.quote_withdraw_sol(min).unwrap());
+            prop_assert_eq!(r.quote.lamports_out, lamports_out);
+            prop_assert_eq!(stake_pool.quote_withdraw_sol(max).unwrap().lamports_out, lamports_out);
+            if let Ok(q) = stake_pool.quote_withdraw_sol(min - 1) {
+                prop_assert!(q.lamports_out < lamports_out);
+            }
+            if let Ok(q) = stake_pool.quote_withdraw_sol(max + 1) {
+                prop_assert!(q.lamports_out > lamports_out);
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn withdraw_stake_exact_out_round_trip(
+            stake_pool in stake_pool(),
+            pool_tokens in 0..=1_000_000_000_000_000u64,
+        ) {
+            let lamports_out = match stake_pool.quote_withdraw_stake(pool_tokens) {
+                Ok(q) => q.lamports_out,
+                Err(_) => return Ok(()),
+            };
+            let r = stake_pool.quote_withdraw_stake_exact_out(lamports_out).unwrap();
+            let (min, max) = (r.pool_tokens.get_min(), r.pool_tokens.get_max());
+            prop_assert!(min <= pool_tokens && pool_tokens <= max);
+            prop_assert_eq!(r.quote, stake_pool.quote_withdraw_stake(min).unwrap());
+            prop_assert_eq!(r.quote.lamports_out, lamports_out);
+            prop_assert_eq!(stake_pool.quote_withdraw_stake(max).unwrap().lamports_out, lamports_out);
+            if let Ok(q) = stake_pool.quote_withdraw_stake(min - 1) {
+                prop_assert!(q.lamports_out < lamports_out);
+            }
+            if let Ok(q) = stake_pool.quote_withdraw_stake(max + 1) {
+                prop_assert!(q.lamports_out > lamports_out);
+            }
+        }
+    }
+}
@@ -1,7 +1,14 @@
+pub mod deposit_sol;
 pub mod deposit_stake;
+mod exact_out;
 pub mod stake_account;
 pub mod stake_pool;
+pub mod withdraw_sol;
+pub mod withdraw_stake;
 
+pub use deposit_sol::*;
 pub use deposit_stake::*;
 pub use stake_account::*;
 pub use stake_pool::*;
+pub use withdraw_sol::*;
+pub use withdraw_stake::*;
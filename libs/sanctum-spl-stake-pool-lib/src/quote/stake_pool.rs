@@ -1,16 +1,27 @@
-use sanctum_token_ratio::{CeilDiv, FloorDiv, MathError, U64BpsFee, U64FeeRatio, U64Ratio};
+use sanctum_token_ratio::{FloorDiv, MathError, U64BpsFee, U64FeeRatio, U64Ratio};
 use spl_stake_pool_interface::StakePool;
 
 use crate::{FeeToRatio, PctFeeToBpsFee};
 
 pub trait QuoteStakePool {
+    /// lamports -> pool tokens
     fn mint_ratio(&self) -> FloorDiv<U64Ratio<u64, u64>>;
 
-    fn stake_deposit_fee_ratio(&self) -> Result<CeilDiv<U64FeeRatio<u64, u64>>, MathError>;
+    /// pool tokens -> lamports
+    fn withdraw_ratio(&self) -> FloorDiv<U64Ratio<u64, u64>>;
 
-    fn sol_deposit_fee_ratio(&self) -> Result<CeilDiv<U64FeeRatio<u64, u64>>, MathError>;
+    /// Fee ratios are [`FloorDiv`] to match the onchain program, see [`FeeToRatio`]
+    fn stake_deposit_fee_ratio(&self) -> Result<FloorDiv<U64FeeRatio<u64, u64>>, MathError>;
+
+    fn sol_deposit_fee_ratio(&self) -> Result<FloorDiv<U64FeeRatio<u64, u64>>, MathError>;
+
+    fn stake_withdrawal_fee_ratio(&self) -> Result<FloorDiv<U64FeeRatio<u64, u64>>, MathError>;
+
+    fn sol_withdrawal_fee_ratio(&self) -> Result<FloorDiv<U64FeeRatio<u64, u64>>, MathError>;
 
     fn stake_referral_bps_fee(&self) -> Result<FloorDiv<U64BpsFee>, MathError>;
+
+    fn sol_referral_bps_fee(&self) -> Result<FloorDiv<U64BpsFee>, MathError>;
 }
 
 impl<T: QuoteStakePool> QuoteStakePool for &T {
@@ -18,17 +29,33 @@ impl<T: QuoteStakePool> QuoteStakePool for &T {
         (*self).mint_ratio()
     }
 
-    fn stake_deposit_fee_ratio(&self) -> Result<CeilDiv<U64FeeRatio<u64, u64>>, MathError> {
+    fn withdraw_ratio(&self) -> FloorDiv<U64Ratio<u64, u64>> {
+        (*self).withdraw_ratio()
+    }
+
+    fn stake_deposit_fee_ratio(&self) -> Result<FloorDiv<U64FeeRatio<u64, u64>>, MathError> {
         (*self).stake_deposit_fee_ratio()
     }
 
-    fn sol_deposit_fee_ratio(&self) -> Result<CeilDiv<U64FeeRatio<u64, u64>>, MathError> {
+    fn sol_deposit_fee_ratio(&self) -> Result<FloorDiv<U64FeeRatio<u64, u64>>, MathError> {
         (*self).sol_deposit_fee_ratio()
     }
 
+    fn stake_withdrawal_fee_ratio(&self) -> Result<FloorDiv<U64FeeRatio<u64, u64>>, MathError> {
+        (*self).stake_withdrawal_fee_ratio()
+    }
+
+    fn sol_withdrawal_fee_ratio(&self) -> Result<FloorDiv<U64FeeRatio<u64, u64>>, MathError> {
+        (*self).sol_withdrawal_fee_ratio()
+    }
+
     fn stake_referral_bps_fee(&self) -> Result<FloorDiv<U64BpsFee>, MathError> {
         (*self).stake_referral_bps_fee()
     }
+
+    fn sol_referral_bps_fee(&self) -> Result<FloorDiv<U64BpsFee>, MathError> {
+        (*self).sol_referral_bps_fee()
+    }
 }
 
 impl QuoteStakePool for StakePool {
@@ -39,15 +66,34 @@ impl QuoteStakePool for StakePool {
         })
     }
 
-    fn stake_deposit_fee_ratio(&self) -> Result<CeilDiv<U64FeeRatio<u64, u64>>, MathError> {
+    fn withdraw_ratio(&self) -> FloorDiv<U64Ratio<u64, u64>> {
+        FloorDiv(U64Ratio {
+            num: self.total_lamports,
+            denom: self.pool_token_supply,
+        })
+    }
+
+    fn stake_deposit_fee_ratio(&self) -> Result<FloorDiv<U64FeeRatio<u64, u64>>, MathError> {
         self.stake_deposit_fee.to_fee_ratio()
     }
 
-    fn sol_deposit_fee_ratio(&self) -> Result<CeilDiv<U64FeeRatio<u64, u64>>, MathError> {
+    fn sol_deposit_fee_ratio(&self) -> Result<FloorDiv<U64FeeRatio<u64, u64>>, MathError> {
         self.sol_deposit_fee.to_fee_ratio()
     }
 
+    fn stake_withdrawal_fee_ratio(&self) -> Result<FloorDiv<U64FeeRatio<u64, u64>>, MathError> {
+        self.stake_withdrawal_fee.to_fee_ratio()
+    }
+
+    fn sol_withdrawal_fee_ratio(&self) -> Result<FloorDiv<U64FeeRatio<u64, u64>>, MathError> {
+        self.sol_withdrawal_fee.to_fee_ratio()
+    }
+
     fn stake_referral_bps_fee(&self) -> Result<FloorDiv<U64BpsFee>, MathError> {
         self.stake_referral_fee.pct_fee_to_bps_fee()
     }
+
+    fn sol_referral_bps_fee(&self) -> Result<FloorDiv<U64BpsFee>, MathError> {
+        self.sol_referral_fee.pct_fee_to_bps_fee()
+    }
 }
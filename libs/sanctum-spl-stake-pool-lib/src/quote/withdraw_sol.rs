@@ -0,0 +1,143 @@
+use sanctum_token_ratio::{ReversibleFee, ReversibleRatio, U64ValueRange};
+use solana_program::program_error::ProgramError;
+use solana_readonly_account::ReadonlyAccountData;
+use spl_stake_pool_interface::StakePool;
+
+use crate::{QuoteStakePool, ValidStakePoolAccount};
+
+use super::exact_out::{min_input_range, zero_if_too_small};
+
+/// Withdrawals do not pay out any referral fees
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WithdrawSolQuote {
+    /// Total pool tokens the user gives up, including `fee_amount`
+    pub tokens_in: u64,
+
+    /// Lamports withdrawn from the reserve to the user
+    pub lamports_out: u64,
+
+    /// Pool tokens transferred to the manager fee account.
+    ///
+    /// The program waives this if the pool tokens are withdrawn
+    /// from the manager fee account itself
+    pub fee_amount: u64,
+}
+
+pub trait QuoteWithdrawSol {
+    fn quote_withdraw_sol(&self, pool_tokens: u64) -> Result<WithdrawSolQuote, ProgramError>;
+}
+
+impl<T: QuoteWithdrawSol> QuoteWithdrawSol for &T {
+    fn quote_withdraw_sol(&self, pool_tokens: u64) -> Result<WithdrawSolQuote, ProgramError> {
+        (*self).quote_withdraw_sol(pool_tokens)
+    }
+}
+
+impl QuoteWithdrawSol for StakePool {
+    fn quote_withdraw_sol(&self, pool_tokens: u64) -> Result<WithdrawSolQuote, ProgramError> {
+        quote_withdraw_sol(self, pool_tokens)
+    }
+}
+
+impl<T: ReadonlyAccountData> QuoteWithdrawSol for ValidStakePoolAccount<T> {
+    fn quote_withdraw_sol(&self, pool_tokens: u64) -> Result<WithdrawSolQuote, ProgramError> {
+        quote_withdraw_sol(self, pool_tokens)
+    }
+}
+
+fn quote_withdraw_sol<P: QuoteStakePool>(
+    pool: &P,
+    pool_tokens: u64,
+) -> Result<WithdrawSolQuote, ProgramError> {
+    // copied from
+    // https://github.com/solana-labs/solana-program-library/blob/3e35101763097b5b3d21686191132e5d930f5b23/stake-pool/program/src/processor.rs#L3479-L3498
+    //
+    // Does not check that the reserve has enough lamports to fulfill the withdrawal (SolWithdrawalTooLarge)
+
+    let withdrawal_fee = pool.sol_withdrawal_fee_ratio()?.apply(pool_tokens)?;
+    let pool_tokens_burnt = withdrawal_fee.amt_after_fee();
+
+    let lamports_out = pool.withdraw_ratio().apply(pool_tokens_burnt)?;
+    if lamports_out == 0 {
+        // WithdrawalTooSmall
+        return Err(ProgramError::InsufficientFunds);
+    }
+
+    Ok(WithdrawSolQuote {
+        tokens_in: pool_tokens,
+        lamports_out,
+        fee_amount: withdrawal_fee.fee_charged(),
+    })
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WithdrawSolExactOutQuote {
+    /// Range of pool tokens that can be withdrawn to receive `quote.lamports_out` lamports,
+    /// the smallest attainable amount that is at least the desired amount
+    pub pool_tokens: U64ValueRange,
+
+    /// Quote for withdrawing `pool_tokens.get_min()`
+    pub quote: WithdrawSolQuote,
+}
+
+pub trait QuoteWithdrawSolExactOut {
+    fn quote_withdraw_sol_exact_out(
+        &self,
+        lamports_out: u64,
+    ) -> Result<WithdrawSolExactOutQuote, ProgramError>;
+}
+
+impl<T: QuoteWithdrawSolExactOut> QuoteWithdrawSolExactOut for &T {
+    fn quote_withdraw_sol_exact_out(
+        &self,
+        lamports_out: u64,
+    ) -> Result<WithdrawSolExactOutQuote, ProgramError> {
+        (*self).quote_withdraw_sol_exact_out(lamports_out)
+    }
+}
+
+impl QuoteWithdrawSolExactOut for StakePool {
+    fn quote_withdraw_sol_exact_out(
+        &self,
+        lamports_out: u64,
+    ) -> Result<WithdrawSolExactOutQuote, ProgramError> {
+        quote_withdraw_sol_exact_out(self, lamports_out)
+    }
+}
+
+impl<T: ReadonlyAccountData> QuoteWithdrawSolExactOut for ValidStakePoolAccount<T> {
+    fn quote_withdraw_sol_exact_out(
+        &self,
+        lamports_out: u64,
+    ) -> Result<WithdrawSolExactOutQuote, ProgramError> {
+        quote_withdraw_sol_exact_out(self, lamports_out)
+    }
+}
+
+fn quote_withdraw_sol_exact_out<P: QuoteStakePool>(
+    pool: &P,
+    lamports_out: u64,
+) -> Result<WithdrawSolExactOutQuote, ProgramError> {
+    if lamports_out == 0 {
+        // WithdrawalTooSmall
+        return Err(ProgramError::InsufficientFunds);
+    }
+
+    let pool_tokens_burnt = pool.withdraw_ratio().reverse(lamports_out)?;
+    let withdrawal_fee_ratio = pool.sol_withdrawal_fee_ratio()?;
+    let candidates = U64ValueRange::try_from_min_max(
+        withdrawal_fee_ratio
+            .reverse_from_amt_after_fee(pool_tokens_burnt.get_min())?
+            .get_min(),
+        withdrawal_fee_ratio
+            .reverse_from_amt_after_fee(pool_tokens_burnt.get_max())?
+            .get_max(),
+    )?;
+
+    let pool_tokens = min_input_range(candidates, lamports_out, |pool_tokens| {
+        zero_if_too_small(quote_withdraw_sol(pool, pool_tokens).map(|q| q.lamports_out))
+    })?;
+    let quote = quote_withdraw_sol(pool, pool_tokens.get_min())?;
+
+    Ok(WithdrawSolExactOutQuote { pool_tokens, quote })
+}
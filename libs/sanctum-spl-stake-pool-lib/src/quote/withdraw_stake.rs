@@ -0,0 +1,144 @@
+use sanctum_token_ratio::{ReversibleFee, ReversibleRatio, U64ValueRange};
+use solana_program::program_error::ProgramError;
+use solana_readonly_account::ReadonlyAccountData;
+use spl_stake_pool_interface::StakePool;
+
+use crate::{QuoteStakePool, ValidStakePoolAccount};
+
+use super::exact_out::{min_input_range, zero_if_too_small};
+
+/// Withdrawals do not pay out any referral fees
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WithdrawStakeQuote {
+    /// Total pool tokens the user gives up, including `fee_amount`
+    pub tokens_in: u64,
+
+    /// Lamports split off into the user's new stake account.
+    ///
+    /// If withdrawing an entire validator stake account during validator removal,
+    /// the program truncates this to the account's lamports instead
+    pub lamports_out: u64,
+
+    /// Pool tokens transferred to the manager fee account.
+    ///
+    /// The program waives this if the pool tokens are withdrawn
+    /// from the manager fee account itself
+    pub fee_amount: u64,
+}
+
+pub trait QuoteWithdrawStake {
+    fn quote_withdraw_stake(&self, pool_tokens: u64) -> Result<WithdrawStakeQuote, ProgramError>;
+}
+
+impl<T: QuoteWithdrawStake> QuoteWithdrawStake for &T {
+    fn quote_withdraw_stake(&self, pool_tokens: u64) -> Result<WithdrawStakeQuote, ProgramError> {
+        (*self).quote_withdraw_stake(pool_tokens)
+    }
+}
+
+impl QuoteWithdrawStake for StakePool {
+    fn quote_withdraw_stake(&self, pool_tokens: u64) -> Result<WithdrawStakeQuote, ProgramError> {
+        quote_withdraw_stake(self, pool_tokens)
+    }
+}
+
+impl<T: ReadonlyAccountData> QuoteWithdrawStake for ValidStakePoolAccount<T> {
+    fn quote_withdraw_stake(&self, pool_tokens: u64) -> Result<WithdrawStakeQuote, ProgramError> {
+        quote_withdraw_stake(self, pool_tokens)
+    }
+}
+
+fn quote_withdraw_stake<P: QuoteStakePool>(
+    pool: &P,
+    pool_tokens: u64,
+) -> Result<WithdrawStakeQuote, ProgramError> {
+    // copied from
+    // https://github.com/solana-labs/solana-program-library/blob/3e35101763097b5b3d21686191132e5d930f5b23/stake-pool/program/src/processor.rs#L3169-L3188
+
+    let withdrawal_fee = pool.stake_withdrawal_fee_ratio()?.apply(pool_tokens)?;
+    let pool_tokens_burnt = withdrawal_fee.amt_after_fee();
+
+    let lamports_out = pool.withdraw_ratio().apply(pool_tokens_burnt)?;
+    if lamports_out == 0 {
+        // WithdrawalTooSmall
+        return Err(ProgramError::InsufficientFunds);
+    }
+
+    Ok(WithdrawStakeQuote {
+        tokens_in: pool_tokens,
+        lamports_out,
+        fee_amount: withdrawal_fee.fee_charged(),
+    })
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WithdrawStakeExactOutQuote {
+    /// Range of pool tokens that can be withdrawn to receive `quote.lamports_out` lamports,
+    /// the smallest attainable amount that is at least the desired amount
+    pub pool_tokens: U64ValueRange,
+
+    /// Quote for withdrawing `pool_tokens.get_min()`
+    pub quote: WithdrawStakeQuote,
+}
+
+pub trait QuoteWithdrawStakeExactOut {
+    fn quote_withdraw_stake_exact_out(
+        &self,
+        lamports_out: u64,
+    ) -> Result<WithdrawStakeExactOutQuote, ProgramError>;
+}
+
+impl<T: QuoteWithdrawStakeExactOut> QuoteWithdrawStakeExactOut for &T {
+    fn quote_withdraw_stake_exact_out(
+        &self,
+        lamports_out: u64,
+    ) -> Result<WithdrawStakeExactOutQuote, ProgramError> {
+        (*self).quote_withdraw_stake_exact_out(lamports_out)
+    }
+}
+
+impl QuoteWithdrawStakeExactOut for StakePool {
+    fn quote_withdraw_stake_exact_out(
+        &self,
+        lamports_out: u64,
+    ) -> Result<WithdrawStakeExactOutQuote, ProgramError> {
+        quote_withdraw_stake_exact_out(self, lamports_out)
+    }
+}
+
+impl<T: ReadonlyAccountData> QuoteWithdrawStakeExactOut for ValidStakePoolAccount<T> {
+    fn quote_withdraw_stake_exact_out(
+        &self,
+        lamports_out: u64,
+    ) -> Result<WithdrawStakeExactOutQuote, ProgramError> {
+        quote_withdraw_stake_exact_out(self, lamports_out)
+    }
+}
+
+fn quote_withdraw_stake_exact_out<P: QuoteStakePool>(
+    pool: &P,
+    lamports_out: u64,
+) -> Result<WithdrawStakeExactOutQuote, ProgramError> {
+    if lamports_out == 0 {
+        // WithdrawalTooSmall
+        return Err(ProgramError::InsufficientFunds);
+    }
+
+    let pool_tokens_burnt = pool.withdraw_ratio().reverse(lamports_out)?;
+    let withdrawal_fee_ratio = pool.stake_withdrawal_fee_ratio()?;
+    let candidates = U64ValueRange::try_from_min_max(
+        withdrawal_fee_ratio
+            .reverse_from_amt_after_fee(pool_tokens_burnt.get_min())?
+            .get_min(),
+        withdrawal_fee_ratio
+            .reverse_from_amt_after_fee(pool_tokens_burnt.get_max())?
+            .get_max(),
+    )?;
+
+    let pool_tokens = min_input_range(candidates, lamports_out, |pool_tokens| {
+        zero_if_too_small(quote_withdraw_stake(pool, pool_tokens).map(|q| q.lamports_out))
+    })?;
+    let quote = quote_withdraw_stake(pool, pool_tokens.get_min())?;
+
+    Ok(WithdrawStakeExactOutQuote { pool_tokens, quote })
+}
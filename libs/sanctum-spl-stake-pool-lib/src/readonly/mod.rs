@@ -0,0 +1,45 @@
+mod stake_pool;
+mod validator_list;
+
+use solana_program::pubkey::{Pubkey, PUBKEY_BYTES};
+pub use stake_pool::*;
+pub use validator_list::*;
+
+/// Borsh-serialized `AccountType::StakePool`
+pub const ACCOUNT_TYPE_STAKE_POOL_DISCM: u8 = 1;
+
+/// Borsh-serialized `AccountType::ValidatorList`
+pub const ACCOUNT_TYPE_VALIDATOR_LIST_DISCM: u8 = 2;
+
+fn unpack_pubkey(slice: &[u8], offset: usize) -> Pubkey {
+    let b: &[u8; PUBKEY_BYTES] = &slice[offset..offset + PUBKEY_BYTES].try_into().unwrap();
+    Pubkey::new_from_array(*b)
+}
+
+fn unpack_le_u32(slice: &[u8], offset: usize) -> u32 {
+    let b: &[u8; 4] = &slice[offset..offset + 4].try_into().unwrap();
+    u32::from_le_bytes(*b)
+}
+
+fn unpack_le_u64(slice: &[u8], offset: usize) -> u64 {
+    let b: &[u8; 8] = &slice[offset..offset + 8].try_into().unwrap();
+    u64::from_le_bytes(*b)
+}
+
+#[cfg(test)]
+mod test_utils {
+    use solana_readonly_account::ReadonlyAccountData;
+
+    pub struct AccountData<'a>(pub &'a [u8]);
+
+    impl<'a> ReadonlyAccountData for AccountData<'a> {
+        type DataDeref<'d>
+            = &'d [u8]
+        where
+            Self: 'd;
+
+        fn data(&self) -> Self::DataDeref<'_> {
+            self.0
+        }
+    }
+}
@@ -0,0 +1,676 @@
+use sanctum_token_ratio::{FloorDiv, MathError, U64BpsFee, U64FeeRatio, U64Ratio};
+use solana_program::{
+    program_error::ProgramError,
+    pubkey::{Pubkey, PUBKEY_BYTES},
+};
+use solana_readonly_account::ReadonlyAccountData;
+use spl_stake_pool_interface::{Fee, FutureEpochFee, Lockup};
+
+use crate::{FeeToRatio, PctFeeToBpsFee, QuoteStakePool};
+
+use super::{unpack_le_u64, unpack_pubkey, ACCOUNT_TYPE_STAKE_POOL_DISCM};
+
+pub const STAKE_POOL_ACCOUNT_TYPE_OFFSET: usize = 0;
+pub const STAKE_POOL_MANAGER_OFFSET: usize = STAKE_POOL_ACCOUNT_TYPE_OFFSET + 1;
+pub const STAKE_POOL_STAKER_OFFSET: usize = STAKE_POOL_MANAGER_OFFSET + PUBKEY_BYTES;
+pub const STAKE_POOL_STAKE_DEPOSIT_AUTHORITY_OFFSET: usize =
+    STAKE_POOL_STAKER_OFFSET + PUBKEY_BYTES;
+pub const STAKE_POOL_STAKE_WITHDRAW_BUMP_SEED_OFFSET: usize =
+    STAKE_POOL_STAKE_DEPOSIT_AUTHORITY_OFFSET + PUBKEY_BYTES;
+pub const STAKE_POOL_VALIDATOR_LIST_OFFSET: usize = STAKE_POOL_STAKE_WITHDRAW_BUMP_SEED_OFFSET + 1;
+pub const STAKE_POOL_RESERVE_STAKE_OFFSET: usize = STAKE_POOL_VALIDATOR_LIST_OFFSET + PUBKEY_BYTES;
+pub const STAKE_POOL_POOL_MINT_OFFSET: usize = STAKE_POOL_RESERVE_STAKE_OFFSET + PUBKEY_BYTES;
+pub const STAKE_POOL_MANAGER_FEE_ACCOUNT_OFFSET: usize = STAKE_POOL_POOL_MINT_OFFSET + PUBKEY_BYTES;
+pub const STAKE_POOL_TOKEN_PROGRAM_OFFSET: usize =
+    STAKE_POOL_MANAGER_FEE_ACCOUNT_OFFSET + PUBKEY_BYTES;
+pub const STAKE_POOL_TOTAL_LAMPORTS_OFFSET: usize = STAKE_POOL_TOKEN_PROGRAM_OFFSET + PUBKEY_BYTES;
+pub const STAKE_POOL_POOL_TOKEN_SUPPLY_OFFSET: usize = STAKE_POOL_TOTAL_LAMPORTS_OFFSET + 8;
+pub const STAKE_POOL_LAST_UPDATE_EPOCH_OFFSET: usize = STAKE_POOL_POOL_TOKEN_SUPPLY_OFFSET + 8;
+// lockup
+pub const STAKE_POOL_LOCKUP_OFFSET: usize = STAKE_POOL_LAST_UPDATE_EPOCH_OFFSET + 8;
+pub const STAKE_POOL_LOCKUP_UNIX_TIMESTAMP_OFFSET: usize = STAKE_POOL_LOCKUP_OFFSET;
+pub const STAKE_POOL_LOCKUP_EPOCH_OFFSET: usize = STAKE_POOL_LOCKUP_UNIX_TIMESTAMP_OFFSET + 8;
+pub const STAKE_POOL_LOCKUP_CUSTODIAN_OFFSET: usize = STAKE_POOL_LOCKUP_EPOCH_OFFSET + 8;
+// epoch_fee
+pub const STAKE_POOL_EPOCH_FEE_OFFSET: usize = STAKE_POOL_LOCKUP_CUSTODIAN_OFFSET + PUBKEY_BYTES;
+/// Offset of the first variable-length field.
+/// Offsets of all subsequent fields depend on the variants of the
+/// `FutureEpochFee` and `Option<Pubkey>` fields that come before them
+pub const STAKE_POOL_NEXT_EPOCH_FEE_OFFSET: usize = STAKE_POOL_EPOCH_FEE_OFFSET + FEE_LEN;
+
+/// Borsh-serialized [`Fee`]: denominator, then numerator
+pub const FEE_LEN: usize = 16;
+
+pub const FUTURE_EPOCH_FEE_NONE_DISCM: u8 = 0;
+pub const FUTURE_EPOCH_FEE_ONE_DISCM: u8 = 1;
+pub const FUTURE_EPOCH_FEE_TWO_DISCM: u8 = 2;
+
+pub const OPTION_NONE_DISCM: u8 = 0;
+pub const OPTION_SOME_DISCM: u8 = 1;
+
+/// A possible stake pool account
+///
+/// ## Example
+///
+/// ```rust
+/// use sanctum_spl_stake_pool_lib::{QuoteWithdrawSol, ReadonlyStakePoolAccount};
+/// use solana_program::{
+///     account_info::AccountInfo,
+///     entrypoint::ProgramResult
+/// };
+///
+/// pub fn process(account: &AccountInfo, pool_tokens: u64) -> ProgramResult {
+///     let account = ReadonlyStakePoolAccount(account);
+///     let account = account.try_into_valid()?;
+///     solana_program::msg!("{}", account.stake_pool_total_lamports());
+///     let quote = account.quote_withdraw_sol(pool_tokens)?;
+///     solana_program::msg!("{}", quote.lamports_out);
+///     Ok(())
+/// }
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ReadonlyStakePoolAccount<T>(pub T);
+
+impl<T> ReadonlyStakePoolAccount<T> {
+    pub fn as_inner(&self) -> &T {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: ReadonlyAccountData> ReadonlyStakePoolAccount<T> {
+    pub fn stake_pool_data_is_valid(&self) -> bool {
+        self.try_compute_var_offsets().is_some()
+    }
+
+    pub fn try_into_valid(self) -> Result<ValidStakePoolAccount<T>, ProgramError> {
+        match self.try_compute_var_offsets() {
+            Some(var_offsets) => Ok(ValidStakePoolAccount {
+                account: self,
+                var_offsets,
+            }),
+            None => Err(ProgramError::InvalidAccountData),
+        }
+    }
+
+    fn try_compute_var_offsets(&self) -> Option<StakePoolVarOffsets> {
+        let d = self.0.data();
+        if d.get(STAKE_POOL_ACCOUNT_TYPE_OFFSET) != Some(&ACCOUNT_TYPE_STAKE_POOL_DISCM) {
+            return None;
+        }
+        StakePoolVarOffsets::try_compute(&d)
+    }
+}
+
+impl<T> AsRef<T> for ReadonlyStakePoolAccount<T> {
+    fn as_ref(&self) -> &T {
+        self.as_inner()
+    }
+}
+
+// can't impl From<ReadonlyStakePoolAccount<T>> for T due to orphan rules
+
+/// A stake pool account that has been checked to contain valid data.
+///
+/// The only safe way to create this struct is via [`TryFrom<ReadonlyStakePoolAccount>`].
+///
+/// The offsets of the variable-length fields are computed once on creation,
+/// so this must be recreated if the account's data may have changed since, e.g. after a CPI.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ValidStakePoolAccount<T> {
+    account: ReadonlyStakePoolAccount<T>,
+    var_offsets: StakePoolVarOffsets,
+}
+
+impl<T> ValidStakePoolAccount<T> {
+    pub fn as_readonly(&self) -> &ReadonlyStakePoolAccount<T> {
+        &self.account
+    }
+
+    pub fn into_readonly(self) -> ReadonlyStakePoolAccount<T> {
+        self.account
+    }
+}
+
+impl<T: ReadonlyAccountData> ValidStakePoolAccount<T> {
+    pub fn stake_pool_manager(&self) -> Pubkey {
+        self.pubkey_at(STAKE_POOL_MANAGER_OFFSET)
+    }
+
+    pub fn stake_pool_staker(&self) -> Pubkey {
+        self.pubkey_at(STAKE_POOL_STAKER_OFFSET)
+    }
+
+    pub fn stake_pool_stake_deposit_authority(&self) -> Pubkey {
+        self.pubkey_at(STAKE_POOL_STAKE_DEPOSIT_AUTHORITY_OFFSET)
+    }
+
+    pub fn stake_pool_stake_withdraw_bump_seed(&self) -> u8 {
+        let d = self.account.as_inner().data();
+        d[STAKE_POOL_STAKE_WITHDRAW_BUMP_SEED_OFFSET]
+    }
+
+    pub fn stake_pool_validator_list(&self) -> Pubkey {
+        self.pubkey_at(STAKE_POOL_VALIDATOR_LIST_OFFSET)
+    }
+
+    pub fn stake_pool_reserve_stake(&self) -> Pubkey {
+        self.pubkey_at(STAKE_POOL_RESERVE_STAKE_OFFSET)
+    }
+
+    pub fn stake_pool_pool_mint(&self) -> Pubkey {
+        self.pubkey_at(STAKE_POOL_POOL_MINT_OFFSET)
+    }
+
+    pub fn stake_pool_manager_fee_account(&self) -> Pubkey {
+        self.pubkey_at(STAKE_POOL_MANAGER_FEE_ACCOUNT_OFFSET)
+    }
+
+    pub fn stake_pool_token_program(&self) -> Pubkey {
+        self.pubkey_at(STAKE_POOL_TOKEN_PROGRAM_OFFSET)
+    }
+
+    pub fn stake_pool_total_lamports(&self) -> u64 {
+        self.u64_at(STAKE_POOL_TOTAL_LAMPORTS_OFFSET)
+    }
+
+    pub fn stake_pool_pool_token_supply(&self) -> u64 {
+        self.u64_at(STAKE_POOL_POOL_TOKEN_SUPPLY_OFFSET)
+    }
+
+    pub fn stake_pool_last_update_epoch(&self) -> u64 {
+        self.u64_at(STAKE_POOL_LAST_UPDATE_EPOCH_OFFSET)
+    }
+
+    pub fn stake_pool_lockup(&self) -> Lockup {
+        Lockup {
+            unix_timestamp: self.stake_pool_lockup_unix_timestamp(),
+            epoch: self.stake_pool_lockup_epoch(),
+            custodian: self.stake_pool_lockup_custodian(),
+        }
+    }
+
+    pub fn stake_pool_lockup_unix_timestamp(&self) -> i64 {
+        self.u64_at(STAKE_POOL_LOCKUP_UNIX_TIMESTAMP_OFFSET) as i64
+    }
+
+    pub fn stake_pool_lockup_epoch(&self) -> u64 {
+        self.u64_at(STAKE_POOL_LOCKUP_EPOCH_OFFSET)
+    }
+
+    pub fn stake_pool_lockup_custodian(&self) -> Pubkey {
+        self.pubkey_at(STAKE_POOL_LOCKUP_CUSTODIAN_OFFSET)
+    }
+
+    pub fn stake_pool_epoch_fee(&self) -> Fee {
+        self.fee_at(STAKE_POOL_EPOCH_FEE_OFFSET)
+    }
+
+    pub fn stake_pool_next_epoch_fee(&self) -> FutureEpochFee {
+        self.future_epoch_fee_at(STAKE_POOL_NEXT_EPOCH_FEE_OFFSET)
+    }
+
+    pub fn stake_pool_preferred_deposit_validator_vote_address(&self) -> Option<Pubkey> {
+        self.option_pubkey_at(self.var_offsets().preferred_deposit_validator_vote_address)
+    }
+
+    pub fn stake_pool_preferred_withdraw_validator_vote_address(&self) -> Option<Pubkey> {
+        self.option_pubkey_at(self.var_offsets().preferred_withdraw_validator_vote_address)
+    }
+
+    pub fn stake_pool_stake_deposit_fee(&self) -> Fee {
+        self.fee_at(self.var_offsets().stake_deposit_fee)
+    }
+
+    pub fn stake_pool_stake_withdrawal_fee(&self) -> Fee {
+        self.fee_at(self.var_offsets().stake_withdrawal_fee)
+    }
+
+    pub fn stake_pool_next_stake_withdrawal_fee(&self) -> FutureEpochFee {
+        self.future_epoch_fee_at(self.var_offsets().next_stake_withdrawal_fee)
+    }
+
+    pub fn stake_pool_stake_referral_fee(&self) -> u8 {
+        let offset = self.var_offsets().stake_referral_fee;
+        let d = self.account.as_inner().data();
+        d[offset]
+    }
+
+    pub fn stake_pool_sol_deposit_authority(&self) -> Option<Pubkey> {
+        self.option_pubkey_at(self.var_offsets().sol_deposit_authority)
+    }
+
+    pub fn stake_pool_sol_deposit_fee(&self) -> Fee {
+        self.fee_at(self.var_offsets().sol_deposit_fee)
+    }
+
+    pub fn stake_pool_sol_referral_fee(&self) -> u8 {
+        let offset = self.var_offsets().sol_referral_fee;
+        let d = self.account.as_inner().data();
+        d[offset]
+    }
+
+    pub fn stake_pool_sol_withdraw_authority(&self) -> Option<Pubkey> {
+        self.option_pubkey_at(self.var_offsets().sol_withdraw_authority)
+    }
+
+    pub fn stake_pool_sol_withdrawal_fee(&self) -> Fee {
+        self.fee_at(self.var_offsets().sol_withdrawal_fee)
+    }
+
+    pub fn stake_pool_next_sol_withdrawal_fee(&self) -> FutureEpochFee {
+        self.future_epoch_fee_at(self.var_offsets().next_sol_withdrawal_fee)
+    }
+
+    pub fn stake_pool_last_epoch_pool_token_supply(&self) -> u64 {
+        self.u64_at(self.var_offsets().last_epoch_pool_token_supply)
+    }
+
+    pub fn stake_pool_last_epoch_total_lamports(&self) -> u64 {
+        self.u64_at(self.var_offsets().last_epoch_total_lamports)
+    }
+
+    fn var_offsets(&self) -> &StakePoolVarOffsets {
+        &self.var_offsets
+    }
+
+    fn pubkey_at(&self, offset: usize) -> Pubkey {
+        unpack_pubkey(&self.account.as_inner().data(), offset)
+    }
+
+    fn u64_at(&self, offset: usize) -> u64 {
+        unpack_le_u64(&self.account.as_inner().data(), offset)
+    }
+
+    fn fee_at(&self, offset: usize) -> Fee {
+        let d = self.account.as_inner().data();
+        unpack_fee(&d, offset)
+    }
+
+    fn option_pubkey_at(&self, offset: usize) -> Option<Pubkey> {
+        let d = self.account.as_inner().data();
+        match d[offset] {
+            OPTION_NONE_DISCM => None,
+            OPTION_SOME_DISCM => Some(unpack_pubkey(&d, offset + 1)),
+            _ => unreachable!(),
+        }
+    }
+
+    fn future_epoch_fee_at(&self, offset: usize) -> FutureEpochFee {
+        let d = self.account.as_inner().data();
+        match d[offset] {
+            FUTURE_EPOCH_FEE_NONE_DISCM => FutureEpochFee::None,
+            FUTURE_EPOCH_FEE_ONE_DISCM => FutureEpochFee::One {
+                fee: unpack_fee(&d, offset + 1),
+            },
+            FUTURE_EPOCH_FEE_TWO_DISCM => FutureEpochFee::Two {
+                fee: unpack_fee(&d, offset + 1),
+            },
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl<T: ReadonlyAccountData> TryFrom<ReadonlyStakePoolAccount<T>> for ValidStakePoolAccount<T> {
+    type Error = ProgramError;
+
+    fn try_from(value: ReadonlyStakePoolAccount<T>) -> Result<Self, Self::Error> {
+        value.try_into_valid()
+    }
+}
+
+impl<T> AsRef<ReadonlyStakePoolAccount<T>> for ValidStakePoolAccount<T> {
+    fn as_ref(&self) -> &ReadonlyStakePoolAccount<T> {
+        self.as_readonly()
+    }
+}
+
+impl<T> From<ValidStakePoolAccount<T>> for ReadonlyStakePoolAccount<T> {
+    fn from(value: ValidStakePoolAccount<T>) -> Self {
+        value.into_readonly()
+    }
+}
+
+impl<T: ReadonlyAccountData> QuoteStakePool for ValidStakePoolAccount<T> {
+    fn mint_ratio(&self) -> FloorDiv<U64Ratio<u64, u64>> {
+        FloorDiv(U64Ratio {
+            num: self.stake_pool_pool_token_supply(),
+            denom: self.stake_pool_total_lamports(),
+        })
+    }
+
+    fn withdraw_ratio(&self) -> FloorDiv<U64Ratio<u64, u64>> {
+        FloorDiv(U64Ratio {
+            num: self.stake_pool_total_lamports(),
+            denom: self.stake_pool_pool_token_supply(),
+        })
+    }
+
+    fn stake_deposit_fee_ratio(&self) -> Result<FloorDiv<U64FeeRatio<u64, u64>>, MathError> {
+        self.stake_pool_stake_deposit_fee().to_fee_ratio()
+    }
+
+    fn sol_deposit_fee_ratio(&self) -> Result<FloorDiv<U64FeeRatio<u64, u64>>, MathError> {
+        self.stake_pool_sol_deposit_fee().to_fee_ratio()
+    }
+
+    fn stake_withdrawal_fee_ratio(&self) -> Result<FloorDiv<U64FeeRatio<u64, u64>>, MathError> {
+        self.stake_pool_stake_withdrawal_fee().to_fee_ratio()
+    }
+
+    fn sol_withdrawal_fee_ratio(&self) -> Result<FloorDiv<U64FeeRatio<u64, u64>>, MathError> {
+        self.stake_pool_sol_withdrawal_fee().to_fee_ratio()
+    }
+
+    fn stake_referral_bps_fee(&self) -> Result<FloorDiv<U64BpsFee>, MathError> {
+        self.stake_pool_stake_referral_fee().pct_fee_to_bps_fee()
+    }
+
+    fn sol_referral_bps_fee(&self) -> Result<FloorDiv<U64BpsFee>, MathError> {
+        self.stake_pool_sol_referral_fee().pct_fee_to_bps_fee()
+    }
+}
+
+/// Offsets of the fields that come after the first variable-length field
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct StakePoolVarOffsets {
+    preferred_deposit_validator_vote_address: usize,
+    preferred_withdraw_validator_vote_address: usize,
+    stake_deposit_fee: usize,
+    stake_withdrawal_fee: usize,
+    next_stake_withdrawal_fee: usize,
+    stake_referral_fee: usize,
+    sol_deposit_authority: usize,
+    sol_deposit_fee: usize,
+    sol_referral_fee: usize,
+    sol_withdraw_authority: usize,
+    sol_withdrawal_fee: usize,
+    next_sol_withdrawal_fee: usize,
+    last_epoch_pool_token_supply: usize,
+    last_epoch_total_lamports: usize,
+}
+
+impl StakePoolVarOffsets {
+    /// Returns None if any of the discriminants are invalid
+    /// or if `d` is too short to contain the full stake pool
+    fn try_compute(d: &[u8]) -> Option<Self> {
+        let preferred_deposit_validator_vote_address = STAKE_POOL_NEXT_EPOCH_FEE_OFFSET
+            + future_epoch_fee_len(d, STAKE_POOL_NEXT_EPOCH_FEE_OFFSET)?;
+        let preferred_withdraw_validator_vote_address = preferred_deposit_validator_vote_address
+            + option_pubkey_len(d, preferred_deposit_validator_vote_address)?;
+        let stake_deposit_fee = preferred_withdraw_validator_vote_address
+            + option_pubkey_len(d, preferred_withdraw_validator_vote_address)?;
+        let stake_withdrawal_fee = stake_deposit_fee + FEE_LEN;
+        let next_stake_withdrawal_fee = stake_withdrawal_fee + FEE_LEN;
+        let stake_referral_fee =
+            next_stake_withdrawal_fee + future_epoch_fee_len(d, next_stake_withdrawal_fee)?;
+        let sol_deposit_authority = stake_referral_fee + 1;
+        let sol_deposit_fee = sol_deposit_authority + option_pubkey_len(d, sol_deposit_authority)?;
+        let sol_referral_fee = sol_deposit_fee + FEE_LEN;
+        let sol_withdraw_authority = sol_referral_fee + 1;
+        let sol_withdrawal_fee =
+            sol_withdraw_authority + option_pubkey_len(d, sol_withdraw_authority)?;
+        let next_sol_withdrawal_fee = sol_withdrawal_fee + FEE_LEN;
+        let last_epoch_pool_token_supply =
+            next_sol_withdrawal_fee + future_epoch_fee_len(d, next_sol_withdrawal_fee)?;
+        let last_epoch_total_lamports = last_epoch_pool_token_supply + 8;
+        if d.len() < last_epoch_total_lamports + 8 {
+            return None;
+        }
+        Some(Self {
+            preferred_deposit_validator_vote_address,
+            preferred_withdraw_validator_vote_address,
+            stake_deposit_fee,
+            stake_withdrawal_fee,
+            next_stake_withdrawal_fee,
+            stake_referral_fee,
+            sol_deposit_authority,
+            sol_deposit_fee,
+            sol_referral_fee,
+            sol_withdraw_authority,
+            sol_withdrawal_fee,
+            next_sol_withdrawal_fee,
+            last_epoch_pool_token_supply,
+            last_epoch_total_lamports,
+        })
+    }
+}
+
+/// Serialized len of the `FutureEpochFee` at `offset`.
+/// Returns None if out of bounds or the discriminant is invalid
+fn future_epoch_fee_len(d: &[u8], offset: usize) -> Option<usize> {
+    match *d.get(offset)? {
+        FUTURE_EPOCH_FEE_NONE_DISCM => Some(1),
+        FUTURE_EPOCH_FEE_ONE_DISCM | FUTURE_EPOCH_FEE_TWO_DISCM => Some(1 + FEE_LEN),
+        _ => None,
+    }
+}
+
+/// Serialized len of the `Option<Pubkey>` at `offset`.
+/// Returns None if out of bounds or the discriminant is invalid
+fn option_pubkey_len(d: &[u8], offset: usize) -> Option<usize> {
+    match *d.get(offset)? {
+        OPTION_NONE_DISCM => Some(1),
+        OPTION_SOME_DISCM => Some(1 + PUBKEY_BYTES),
+        _ => None,
+    }
+}
+
+fn unpack_fee(d: &[u8], offset: usize) -> Fee {
+    Fee {
+        denominator: unpack_le_u64(d, offset),
+        numerator: unpack_le_u64(d, offset + 8),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::{BorshDeserialize, BorshSerialize};
+    use proptest::{
+        array::{uniform3, uniform4, uniform5, uniform9},
+        collection::vec,
+        option,
+        prelude::*,
+    };
+    use sanctum_solana_test_utils::proptest_utils::pubkey;
+    use spl_stake_pool_interface::{AccountType, StakePool};
+
+    use crate::{
+        readonly::test_utils::AccountData, QuoteDepositSol, QuoteDepositStake, QuoteWithdrawSol,
+        QuoteWithdrawStake, StakeAccountDataForQuoting, STAKE_POOL_SIZE,
+    };
+
+    use super::*;
+
+    prop_compose! {
+        fn fee()(denominator: u64, numerator: u64) -> Fee {
+            Fee { denominator, numerator }
+        }
+    }
+
+    fn future_epoch_fee() -> impl Strategy<Value = FutureEpochFee> {
+        prop_oneof![
+            Just(FutureEpochFee::None),
+            fee().prop_map(|fee| FutureEpochFee::One { fee }),
+            fee().prop_map(|fee| FutureEpochFee::Two { fee }),
+        ]
+    }
+
+    prop_compose! {
+        fn stake_pool()
+            (
+                [
+                    manager,
+                    staker,
+                    stake_deposit_authority,
+                    validator_list,
+                    reserve_stake,
+                    pool_mint,
+                    manager_fee_account,
+                    token_program,
+                    custodian,
+                ] in uniform9(pubkey()),
+                [
+                    total_lamports,
+                    pool_token_supply,
+                    last_update_epoch,
+                    lockup_epoch,
+                    last_epoch_pool_token_supply,
+                    last_epoch_total_lamports,
+                ]: [u64; 6],
+                unix_timestamp: i64,
+                [stake_withdraw_bump_seed, stake_referral_fee, sol_referral_fee]: [u8; 3],
+                [
+                    epoch_fee,
+                    stake_deposit_fee,
+                    stake_withdrawal_fee,
+                    sol_deposit_fee,
+                    sol_withdrawal_fee,
+                ] in uniform5(fee()),
+                [
+                    next_epoch_fee,
+                    next_stake_withdrawal_fee,
+                    next_sol_withdrawal_fee,
+                ] in uniform3(future_epoch_fee()),
+                [
+                    preferred_deposit_validator_vote_address,
+                    preferred_withdraw_validator_vote_address,
+                    sol_deposit_authority,
+                    sol_withdraw_authority,
+                ] in uniform4(option::of(pubkey())),
+            ) -> StakePool {
+                StakePool {
+                    account_type: AccountType::StakePool,
+                    manager,
+                    staker,
+                    stake_deposit_authority,
+                    stake_withdraw_bump_seed,
+                    validator_list,
+                    reserve_stake,
+                    pool_mint,
+                    manager_fee_account,
+                    token_program,
+                    total_lamports,
+                    pool_token_supply,
+                    last_update_epoch,
+                    lockup: Lockup {
+                        unix_timestamp,
+                        epoch: lockup_epoch,
+                        custodian,
+                    },
+                    epoch_fee,
+                    next_epoch_fee,
+                    preferred_deposit_validator_vote_address,
+                    preferred_withdraw_validator_vote_address,
+                    stake_deposit_fee,
+                    stake_withdrawal_fee,
+                    next_stake_withdrawal_fee,
+                    stake_referral_fee,
+                    sol_deposit_authority,
+                    sol_deposit_fee,
+                    sol_referral_fee,
+                    sol_withdraw_authority,
+                    sol_withdrawal_fee,
+                    next_sol_withdrawal_fee,
+                    last_epoch_pool_token_supply,
+                    last_epoch_total_lamports,
+                }
+            }
+    }
+
+    proptest! {
+        #[test]
+        fn stake_pool_readonly_matches_full_deser_invalid(data in vec(any::<u8>(), 0..=STAKE_POOL_SIZE)) {
+            let account = ReadonlyStakePoolAccount(AccountData(&data));
+            let unpack_res = StakePool::deserialize(&mut data.as_slice());
+            match unpack_res {
+                Ok(sp) if sp.account_type == AccountType::StakePool => {
+                    prop_assert!(account.stake_pool_data_is_valid())
+                }
+                _ => prop_assert!(!account.stake_pool_data_is_valid()),
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn stake_pool_readonly_matches_full_deser_valid(expected in stake_pool()) {
+            let mut data = vec![0u8; STAKE_POOL_SIZE];
+            expected.serialize(&mut data.as_mut_slice()).unwrap();
+            let account = ReadonlyStakePoolAccount(AccountData(&data)).try_into_valid().unwrap();
+            prop_assert_eq!(account.mint_ratio(), expected.mint_ratio());
+            prop_assert_eq!(account.withdraw_ratio(), expected.withdraw_ratio());
+            prop_assert_eq!(account.stake_deposit_fee_ratio(), expected.stake_deposit_fee_ratio());
+            prop_assert_eq!(account.sol_deposit_fee_ratio(), expected.sol_deposit_fee_ratio());
+            prop_assert_eq!(account.stake_withdrawal_fee_ratio(), expected.stake_withdrawal_fee_ratio());
+            prop_assert_eq!(account.sol_withdrawal_fee_ratio(), expected.sol_withdrawal_fee_ratio());
+            prop_assert_eq!(account.stake_referral_bps_fee(), expected.stake_referral_bps_fee());
+            prop_assert_eq!(account.sol_referral_bps_fee(), expected.sol_referral_bps_fee());
+
+            prop_assert_eq!(account.stake_pool_manager(), expected.manager);
+            prop_assert_eq!(account.stake_pool_staker(), expected.staker);
+            prop_assert_eq!(account.stake_pool_stake_deposit_authority(), expected.stake_deposit_authority);
+            prop_assert_eq!(account.stake_pool_stake_withdraw_bump_seed(), expected.stake_withdraw_bump_seed);
+            prop_assert_eq!(account.stake_pool_validator_list(), expected.validator_list);
+            prop_assert_eq!(account.stake_pool_reserve_stake(), expected.reserve_stake);
+            prop_assert_eq!(account.stake_pool_pool_mint(), expected.pool_mint);
+            prop_assert_eq!(account.stake_pool_manager_fee_account(), expected.manager_fee_account);
+            prop_assert_eq!(account.stake_pool_token_program(), expected.token_program);
+            prop_assert_eq!(account.stake_pool_total_lamports(), expected.total_lamports);
+            prop_assert_eq!(account.stake_pool_pool_token_supply(), expected.pool_token_supply);
+            prop_assert_eq!(account.stake_pool_last_update_epoch(), expected.last_update_epoch);
+            prop_assert_eq!(account.stake_pool_lockup(), expected.lockup);
+            prop_assert_eq!(account.stake_pool_epoch_fee(), expected.epoch_fee);
+            prop_assert_eq!(account.stake_pool_next_epoch_fee(), expected.next_epoch_fee);
+            prop_assert_eq!(
+                account.stake_pool_preferred_deposit_validator_vote_address(),
+                expected.preferred_deposit_validator_vote_address
+            );
+            prop_assert_eq!(
+                account.stake_pool_preferred_withdraw_validator_vote_address(),
+                expected.preferred_withdraw_validator_vote_address
+            );
+            prop_assert_eq!(account.stake_pool_stake_deposit_fee(), expected.stake_deposit_fee);
+            prop_assert_eq!(account.stake_pool_stake_withdrawal_fee(), expected.stake_withdrawal_fee);
+            prop_assert_eq!(account.stake_pool_next_stake_withdrawal_fee(), expected.next_stake_withdrawal_fee);
+            prop_assert_eq!(account.stake_pool_stake_referral_fee(), expected.stake_referral_fee);
+            prop_assert_eq!(account.stake_pool_sol_deposit_authority(), expected.sol_deposit_authority);
+            prop_assert_eq!(account.stake_pool_sol_deposit_fee(), expected.sol_deposit_fee);
+            prop_assert_eq!(account.stake_pool_sol_referral_fee(), expected.sol_referral_fee);
+            prop_assert_eq!(account.stake_pool_sol_withdraw_authority(), expected.sol_withdraw_authority);
+            prop_assert_eq!(account.stake_pool_sol_withdrawal_fee(), expected.sol_withdrawal_fee);
+            prop_assert_eq!(account.stake_pool_next_sol_withdrawal_fee(), expected.next_sol_withdrawal_fee);
+            prop_assert_eq!(account.stake_pool_last_epoch_pool_token_supply(), expected.last_epoch_pool_token_supply);
+            prop_assert_eq!(account.stake_pool_last_epoch_total_lamports(), expected.last_epoch_total_lamports);
+
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn stake_pool_readonly_quotes_match_full_deser(
+            expected in stake_pool(),
+            amt: u64,
+            unstaked_lamports: u64,
+        ) {
+            let mut data = vec![0u8; STAKE_POOL_SIZE];
+            expected.serialize(&mut data.as_mut_slice()).unwrap();
+            let account = ReadonlyStakePoolAccount(AccountData(&data)).try_into_valid().unwrap();
+            let stake_account = StakeAccountDataForQuoting {
+                staked_lamports: amt,
+                unstaked_lamports,
+            };
+            prop_assert_eq!(account.quote_deposit_sol(amt), expected.quote_deposit_sol(amt));
+            prop_assert_eq!(
+                account.quote_deposit_stake(&stake_account),
+                expected.quote_deposit_stake(&stake_account)
+            );
+            prop_assert_eq!(account.quote_withdraw_sol(amt), expected.quote_withdraw_sol(amt));
+            prop_assert_eq!(account.quote_withdraw_stake(amt), expected.quote_withdraw_stake(amt));
+        }
+    }
+}
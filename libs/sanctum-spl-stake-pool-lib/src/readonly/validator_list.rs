@@ -0,0 +1,452 @@
+use std::cmp::Ordering;
+
+use solana_program::{
+    program_error::ProgramError,
+    pubkey::{Pubkey, PUBKEY_BYTES},
+};
+use solana_readonly_account::ReadonlyAccountData;
+use spl_stake_pool_interface::{AccountType, StakeStatus, ValidatorListHeader, ValidatorStakeInfo};
+
+use super::{unpack_le_u32, unpack_le_u64, unpack_pubkey, ACCOUNT_TYPE_VALIDATOR_LIST_DISCM};
+
+// header
+pub const VALIDATOR_LIST_ACCOUNT_TYPE_OFFSET: usize = 0;
+pub const VALIDATOR_LIST_MAX_VALIDATORS_OFFSET: usize = VALIDATOR_LIST_ACCOUNT_TYPE_OFFSET + 1;
+// validators vec, borsh serializes the len as a u32
+pub const VALIDATOR_LIST_LEN_OFFSET: usize = VALIDATOR_LIST_MAX_VALIDATORS_OFFSET + 4;
+pub const VALIDATOR_LIST_VALIDATORS_OFFSET: usize = VALIDATOR_LIST_LEN_OFFSET + 4;
+
+// offsets of ValidatorStakeInfo fields relative to the start of the ValidatorStakeInfo
+pub const VALIDATOR_STAKE_INFO_ACTIVE_STAKE_LAMPORTS_OFFSET: usize = 0;
+pub const VALIDATOR_STAKE_INFO_TRANSIENT_STAKE_LAMPORTS_OFFSET: usize =
+    VALIDATOR_STAKE_INFO_ACTIVE_STAKE_LAMPORTS_OFFSET + 8;
+pub const VALIDATOR_STAKE_INFO_LAST_UPDATE_EPOCH_OFFSET: usize =
+    VALIDATOR_STAKE_INFO_TRANSIENT_STAKE_LAMPORTS_OFFSET + 8;
+pub const VALIDATOR_STAKE_INFO_TRANSIENT_SEED_SUFFIX_OFFSET: usize =
+    VALIDATOR_STAKE_INFO_LAST_UPDATE_EPOCH_OFFSET + 8;
+pub const VALIDATOR_STAKE_INFO_UNUSED_OFFSET: usize =
+    VALIDATOR_STAKE_INFO_TRANSIENT_SEED_SUFFIX_OFFSET + 8;
+pub const VALIDATOR_STAKE_INFO_VALIDATOR_SEED_SUFFIX_OFFSET: usize =
+    VALIDATOR_STAKE_INFO_UNUSED_OFFSET + 4;
+pub const VALIDATOR_STAKE_INFO_STATUS_OFFSET: usize =
+    VALIDATOR_STAKE_INFO_VALIDATOR_SEED_SUFFIX_OFFSET + 4;
+pub const VALIDATOR_STAKE_INFO_VOTE_ACCOUNT_ADDRESS_OFFSET: usize =
+    VALIDATOR_STAKE_INFO_STATUS_OFFSET + 1;
+pub const VALIDATOR_STAKE_INFO_SIZE: usize =
+    VALIDATOR_STAKE_INFO_VOTE_ACCOUNT_ADDRESS_OFFSET + PUBKEY_BYTES;
+
+/// A possible validator list account
+///
+/// ## Example
+///
+/// ```rust
+/// use sanctum_spl_stake_pool_lib::ReadonlyValidatorList;
+/// use solana_program::{
+///     account_info::AccountInfo,
+///     entrypoint::ProgramResult,
+///     program_error::ProgramError,
+///     pubkey::Pubkey,
+/// };
+///
+/// pub fn process(account: &AccountInfo, vote: &Pubkey) -> ProgramResult {
+///     let account = ReadonlyValidatorList(account);
+///     let account = account.try_into_valid()?;
+///     let index = account
+///         .validator_list_find_by_vote_account(vote)
+///         .ok_or(ProgramError::InvalidArgument)?;
+///     solana_program::msg!("{:?}", account.validator_active_stake_lamports(index));
+///     Ok(())
+/// }
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ReadonlyValidatorList<T>(pub T);
+
+impl<T> ReadonlyValidatorList<T> {
+    pub fn as_inner(&self) -> &T {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: ReadonlyAccountData> ReadonlyValidatorList<T> {
+    /// Checks the account type, that the account is large enough to hold all its validators,
+    /// and that every validator's status is valid.
+    ///
+    /// Does not allocate, but reads the status byte of each validator
+    pub fn validator_list_data_is_valid(&self) -> bool {
+        let d = self.0.data();
+        if d.len() < VALIDATOR_LIST_VALIDATORS_OFFSET
+            || d[VALIDATOR_LIST_ACCOUNT_TYPE_OFFSET] != ACCOUNT_TYPE_VALIDATOR_LIST_DISCM
+        {
+            return false;
+        }
+        let len = unpack_le_u32(&d, VALIDATOR_LIST_LEN_OFFSET) as usize;
+        let validators_end = match len
+            .checked_mul(VALIDATOR_STAKE_INFO_SIZE)
+            .and_then(|l| l.checked_add(VALIDATOR_LIST_VALIDATORS_OFFSET))
+        {
+            Some(e) => e,
+            None => return false,
+        };
+        if d.len() < validators_end {
+            return false;
+        }
+        d[VALIDATOR_LIST_VALIDATORS_OFFSET..validators_end]
+            .chunks_exact(VALIDATOR_STAKE_INFO_SIZE)
+            .all(|v| stake_status_from_byte(v[VALIDATOR_STAKE_INFO_STATUS_OFFSET]).is_some())
+    }
+
+    pub fn try_into_valid(self) -> Result<ValidValidatorList<T>, ProgramError> {
+        match self.validator_list_data_is_valid() {
+            true => Ok(ValidValidatorList(self)),
+            false => Err(ProgramError::InvalidAccountData),
+        }
+    }
+}
+
+impl<T> AsRef<T> for ReadonlyValidatorList<T> {
+    fn as_ref(&self) -> &T {
+        self.as_inner()
+    }
+}
+
+// can't impl From<ReadonlyValidatorList<T>> for T due to orphan rules
+
+/// A validator list account that has been checked to contain valid data.
+///
+/// The only safe way to create this struct is via [`TryFrom<ReadonlyValidatorList>`]
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ValidValidatorList<T>(ReadonlyValidatorList<T>);
+
+impl<T> ValidValidatorList<T> {
+    pub fn as_readonly(&self) -> &ReadonlyValidatorList<T> {
+        &self.0
+    }
+
+    pub fn into_readonly(self) -> ReadonlyValidatorList<T> {
+        self.0
+    }
+}
+
+impl<T: ReadonlyAccountData> ValidValidatorList<T> {
+    pub fn validator_list_header(&self) -> ValidatorListHeader {
+        ValidatorListHeader {
+            account_type: AccountType::ValidatorList,
+            max_validators: self.validator_list_max_validators(),
+        }
+    }
+
+    pub fn validator_list_max_validators(&self) -> u32 {
+        unpack_le_u32(
+            &self.0.as_inner().data(),
+            VALIDATOR_LIST_MAX_VALIDATORS_OFFSET,
+        )
+    }
+
+    /// Number of validators currently in the list
+    pub fn validator_list_len(&self) -> u32 {
+        unpack_le_u32(&self.0.as_inner().data(), VALIDATOR_LIST_LEN_OFFSET)
+    }
+
+    pub fn validator_list_is_empty(&self) -> bool {
+        self.validator_list_len() == 0
+    }
+
+    /// Returns the full [`ValidatorStakeInfo`] at `index`,
+    /// or None if `index` is out of bounds
+    pub fn validator_stake_info(&self, index: usize) -> Option<ValidatorStakeInfo> {
+        let offset = self.validator_offset(index)?;
+        let d = self.0.as_inner().data();
+        Some(ValidatorStakeInfo {
+            active_stake_lamports: unpack_le_u64(
+                &d,
+                offset + VALIDATOR_STAKE_INFO_ACTIVE_STAKE_LAMPORTS_OFFSET,
+            ),
+            transient_stake_lamports: unpack_le_u64(
+                &d,
+                offset + VALIDATOR_STAKE_INFO_TRANSIENT_STAKE_LAMPORTS_OFFSET,
+            ),
+            last_update_epoch: unpack_le_u64(
+                &d,
+                offset + VALIDATOR_STAKE_INFO_LAST_UPDATE_EPOCH_OFFSET,
+            ),
+            transient_seed_suffix: unpack_le_u64(
+                &d,
+                offset + VALIDATOR_STAKE_INFO_TRANSIENT_SEED_SUFFIX_OFFSET,
+            ),
+            unused: unpack_le_u32(&d, offset + VALIDATOR_STAKE_INFO_UNUSED_OFFSET),
+            validator_seed_suffix: unpack_le_u32(
+                &d,
+                offset + VALIDATOR_STAKE_INFO_VALIDATOR_SEED_SUFFIX_OFFSET,
+            ),
+            status: stake_status_from_byte(d[offset + VALIDATOR_STAKE_INFO_STATUS_OFFSET]).unwrap(),
+            vote_account_address: unpack_pubkey(
+                &d,
+                offset + VALIDATOR_STAKE_INFO_VOTE_ACCOUNT_ADDRESS_OFFSET,
+            ),
+        })
+    }
+
+    pub fn validator_active_stake_lamports(&self, index: usize) -> Option<u64> {
+        self.validator_u64(index, VALIDATOR_STAKE_INFO_ACTIVE_STAKE_LAMPORTS_OFFSET)
+    }
+
+    pub fn validator_transient_stake_lamports(&self, index: usize) -> Option<u64> {
+        self.validator_u64(index, VALIDATOR_STAKE_INFO_TRANSIENT_STAKE_LAMPORTS_OFFSET)
+    }
+
+    pub fn validator_last_update_epoch(&self, index: usize) -> Option<u64> {
+        self.validator_u64(index, VALIDATOR_STAKE_INFO_LAST_UPDATE_EPOCH_OFFSET)
+    }
+
+    pub fn validator_transient_seed_suffix(&self, index: usize) -> Option<u64> {
+        self.validator_u64(index, VALIDATOR_STAKE_INFO_TRANSIENT_SEED_SUFFIX_OFFSET)
+    }
+
+    pub fn validator_validator_seed_suffix(&self, index: usize) -> Option<u32> {
+        let offset = self.validator_offset(index)?;
+        Some(unpack_le_u32(
+            &self.0.as_inner().data(),
+            offset + VALIDATOR_STAKE_INFO_VALIDATOR_SEED_SUFFIX_OFFSET,
+        ))
+    }
+
+    pub fn validator_status(&self, index: usize) -> Option<StakeStatus> {
+        let offset = self.validator_offset(index)?;
+        let d = self.0.as_inner().data();
+        Some(stake_status_from_byte(d[offset + VALIDATOR_STAKE_INFO_STATUS_OFFSET]).unwrap())
+    }
+
+    pub fn validator_vote_account_address(&self, index: usize) -> Option<Pubkey> {
+        let offset = self.validator_offset(index)?;
+        Some(unpack_pubkey(
+            &self.0.as_inner().data(),
+            offset + VALIDATOR_STAKE_INFO_VOTE_ACCOUNT_ADDRESS_OFFSET,
+        ))
+    }
+
+    /// Total lamports of the validator, active + transient
+    pub fn validator_stake_lamports(&self, index: usize) -> Option<u64> {
+        self.validator_active_stake_lamports(index)?
+            .checked_add(self.validator_transient_stake_lamports(index)?)
+    }
+
+    /// Returns the index of the validator with vote account `vote` using a linear search.
+    ///
+    /// Validator lists are not sorted by the stake pool program,
+    /// so this is what should be used for lists fetched from chain.
+    pub fn validator_list_find_by_vote_account(&self, vote: &Pubkey) -> Option<usize> {
+        let d = self.0.as_inner().data();
+        (0..self.validator_list_len() as usize)
+            .find(|i| vote_account_address_bytes(&d, *i) == vote.as_ref())
+    }
+
+    /// Binary searches the list for the validator with vote account `vote`,
+    /// with the same semantics as [`slice::binary_search`].
+    ///
+    /// The validator list must be sorted by vote account address, else the result is meaningless.
+    pub fn validator_list_binary_search_by_vote_account(
+        &self,
+        vote: &Pubkey,
+    ) -> Result<usize, usize> {
+        let d = self.0.as_inner().data();
+        let mut lo = 0;
+        let mut hi = self.validator_list_len() as usize;
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            match vote_account_address_bytes(&d, mid).cmp(vote.as_ref()) {
+                Ordering::Less => lo = mid + 1,
+                Ordering::Greater => hi = mid,
+                Ordering::Equal => return Ok(mid),
+            }
+        }
+        Err(lo)
+    }
+
+    /// Returns the byte offset of the validator at `index`, or None if out of bounds
+    fn validator_offset(&self, index: usize) -> Option<usize> {
+        if index >= self.validator_list_len() as usize {
+            return None;
+        }
+        Some(VALIDATOR_LIST_VALIDATORS_OFFSET + index * VALIDATOR_STAKE_INFO_SIZE)
+    }
+
+    fn validator_u64(&self, index: usize, field_offset: usize) -> Option<u64> {
+        let offset = self.validator_offset(index)?;
+        Some(unpack_le_u64(
+            &self.0.as_inner().data(),
+            offset + field_offset,
+        ))
+    }
+}
+
+impl<T: ReadonlyAccountData> TryFrom<ReadonlyValidatorList<T>> for ValidValidatorList<T> {
+    type Error = ProgramError;
+
+    fn try_from(value: ReadonlyValidatorList<T>) -> Result<Self, Self::Error> {
+        value.try_into_valid()
+    }
+}
+
+impl<T> AsRef<ReadonlyValidatorList<T>> for ValidValidatorList<T> {
+    fn as_ref(&self) -> &ReadonlyValidatorList<T> {
+        self.as_readonly()
+    }
+}
+
+impl<T> From<ValidValidatorList<T>> for ReadonlyValidatorList<T> {
+    fn from(value: ValidValidatorList<T>) -> Self {
+        value.into_readonly()
+    }
+}
+
+/// Panics if `index` is out of bounds
+fn vote_account_address_bytes(validator_list_data: &[u8], index: usize) -> &[u8] {
+    let start = VALIDATOR_LIST_VALIDATORS_OFFSET
+        + index * VALIDATOR_STAKE_INFO_SIZE
+        + VALIDATOR_STAKE_INFO_VOTE_ACCOUNT_ADDRESS_OFFSET;
+    &validator_list_data[start..start + PUBKEY_BYTES]
+}
+
+fn stake_status_from_byte(b: u8) -> Option<StakeStatus> {
+    Some(match b {
+        0 => StakeStatus::Active,
+        1 => StakeStatus::DeactivatingTransient,
+        2 => StakeStatus::ReadyForRemoval,
+        3 => StakeStatus::DeactivatingValidator,
+        4 => StakeStatus::DeactivatingAll,
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::{BorshDeserialize, BorshSerialize};
+    use proptest::{collection::vec, prelude::*};
+    use sanctum_solana_test_utils::proptest_utils::pubkey;
+    use spl_stake_pool_interface::ValidatorList;
+
+    use crate::readonly::test_utils::AccountData;
+
+    use super::*;
+
+    fn stake_status() -> impl Strategy<Value = StakeStatus> {
+        (0..=4u8).prop_map(|b| stake_status_from_byte(b).unwrap())
+    }
+
+    prop_compose! {
+        fn validator_stake_info()
+            (
+                active_stake_lamports: u64,
+                transient_stake_lamports: u64,
+                last_update_epoch: u64,
+                transient_seed_suffix: u64,
+                unused: u32,
+                validator_seed_suffix: u32,
+                status in stake_status(),
+                vote_account_address in pubkey(),
+            ) -> ValidatorStakeInfo {
+                ValidatorStakeInfo {
+                    active_stake_lamports,
+                    transient_stake_lamports,
+                    last_update_epoch,
+                    transient_seed_suffix,
+                    unused,
+                    validator_seed_suffix,
+                    status,
+                    vote_account_address,
+                }
+            }
+    }
+
+    prop_compose! {
+        fn validator_list()
+            (validators in vec(validator_stake_info(), 0..=16), extra_capacity in 0..=4usize)
+            -> (ValidatorList, Vec<u8>) {
+                let max_validators = validators.len() + extra_capacity;
+                let validator_list = ValidatorList {
+                    header: ValidatorListHeader {
+                        account_type: AccountType::ValidatorList,
+                        max_validators: max_validators as u32,
+                    },
+                    validators,
+                };
+                let mut data =
+                    vec![0u8; VALIDATOR_LIST_VALIDATORS_OFFSET + max_validators * VALIDATOR_STAKE_INFO_SIZE];
+                validator_list.serialize(&mut data.as_mut_slice()).unwrap();
+                (validator_list, data)
+            }
+    }
+
+    proptest! {
+        #[test]
+        fn validator_list_readonly_matches_full_deser_invalid(
+            data in vec(any::<u8>(), 0..=VALIDATOR_LIST_VALIDATORS_OFFSET + 4 * VALIDATOR_STAKE_INFO_SIZE)
+        ) {
+            let account = ReadonlyValidatorList(AccountData(&data));
+            let unpack_res = ValidatorList::deserialize(&mut data.as_slice());
+            match unpack_res {
+                Ok(vl) if vl.header.account_type == AccountType::ValidatorList => {
+                    prop_assert!(account.validator_list_data_is_valid())
+                }
+                _ => prop_assert!(!account.validator_list_data_is_valid()),
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn validator_list_readonly_matches_full_deser_valid((expected, data) in validator_list()) {
+            let account = ReadonlyValidatorList(AccountData(&data)).try_into_valid().unwrap();
+            prop_assert_eq!(account.validator_list_header(), expected.header.clone());
+            prop_assert_eq!(account.validator_list_max_validators(), expected.header.max_validators);
+            prop_assert_eq!(account.validator_list_len() as usize, expected.validators.len());
+            prop_assert_eq!(account.validator_list_is_empty(), expected.validators.is_empty());
+            for (i, v) in expected.validators.iter().enumerate() {
+                prop_assert_eq!(account.validator_stake_info(i), Some(v.clone()));
+                prop_assert_eq!(account.validator_active_stake_lamports(i), Some(v.active_stake_lamports));
+                prop_assert_eq!(account.validator_transient_stake_lamports(i), Some(v.transient_stake_lamports));
+                prop_assert_eq!(account.validator_last_update_epoch(i), Some(v.last_update_epoch));
+                prop_assert_eq!(account.validator_transient_seed_suffix(i), Some(v.transient_seed_suffix));
+                prop_assert_eq!(account.validator_validator_seed_suffix(i), Some(v.validator_seed_suffix));
+                prop_assert_eq!(account.validator_status(i), Some(v.status.clone()));
+                prop_assert_eq!(account.validator_vote_account_address(i), Some(v.vote_account_address));
+                prop_assert_eq!(
+                    account.validator_stake_lamports(i),
+                    v.active_stake_lamports.checked_add(v.transient_stake_lamports)
+                );
+            }
+            let oob = expected.validators.len();
+            prop_assert_eq!(account.validator_stake_info(oob), None);
+            prop_assert_eq!(account.validator_active_stake_lamports(oob), None);
+            prop_assert_eq!(account.validator_vote_account_address(oob), None);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn validator_list_search_by_vote_account((expected, _data) in validator_list(), absent in pubkey()) {
+            let mut expected = expected;
+            expected.validators.sort_by_key(|v| v.vote_account_address);
+            expected.validators.dedup_by_key(|v| v.vote_account_address);
+            let mut data =
+                vec![0u8; VALIDATOR_LIST_VALIDATORS_OFFSET + expected.validators.len() * VALIDATOR_STAKE_INFO_SIZE];
+            expected.serialize(&mut data.as_mut_slice()).unwrap();
+            let account = ReadonlyValidatorList(AccountData(&data)).try_into_valid().unwrap();
+            for (i, v) in expected.validators.iter().enumerate() {
+                prop_assert_eq!(account.validator_list_binary_search_by_vote_account(&v.vote_account_address), Ok(i));
+                prop_assert_eq!(account.validator_list_find_by_vote_account(&v.vote_account_address), Some(i));
+            }
+            let search_res = expected
+                .validators
+                .binary_search_by_key(&absent, |v| v.vote_account_address);
+            prop_assert_eq!(account.validator_list_binary_search_by_vote_account(&absent), search_res);
+            prop_assert_eq!(account.validator_list_find_by_vote_account(&absent), search_res.ok());
+        }
+    }
+}
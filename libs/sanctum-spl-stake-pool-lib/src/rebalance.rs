@@ -0,0 +1,338 @@
+use std::{cmp::Reverse, collections::HashMap, num::NonZeroU32};
+
+use borsh::BorshDeserialize;
+use solana_program::{
+    clock::Epoch, instruction::Instruction, program_error::ProgramError, pubkey::Pubkey,
+    rent::Rent, stake::state::StakeStateV2,
+};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkeyBytes};
+use spl_stake_pool_interface::{
+    decrease_additional_validator_stake_ix_with_program_id,
+    increase_additional_validator_stake_ix_with_program_id, AdditionalValidatorStakeArgs,
+    DecreaseAdditionalValidatorStakeIxArgs, IncreaseAdditionalValidatorStakeIxArgs, StakeStatus,
+    ValidatorList, ValidatorStakeInfo,
+};
+
+use crate::{
+    account_resolvers::{
+        AdditionalValidatorStakeSeeds, DecreaseAdditionalValidatorStake,
+        IncreaseAdditionalValidatorStake, ProgramIdAndVote, UpdateValidatorListBalance,
+    },
+    deserialize_stake_pool_checked, lamports_for_new_vsa, min_delegation, min_reserve_lamports,
+};
+
+/// Plans the `IncreaseAdditionalValidatorStake` and `DecreaseAdditionalValidatorStake`
+/// instructions that move a stake pool's stake distribution towards target weights in the current epoch.
+///
+/// The target stake of each active validator is its share of the pool's total lamports,
+/// excluding the reserve's minimum balance, by weight.
+/// Validators that are being removed from the pool are left untouched.
+#[derive(Clone, Copy, Debug)]
+pub struct RebalancePlanner<'a, P, A> {
+    pub stake_pool: P,
+    pub validator_list: &'a ValidatorList,
+    pub reserve_lamports: u64,
+
+    /// Target weight of each validator, keyed by vote account.
+    /// Active validators not in this map have a target weight of 0
+    pub target_weights: &'a HashMap<Pubkey, u64>,
+
+    /// Transient stake accounts of the pool.
+    /// Validators with transient stake whose account is not in this slice are left untouched
+    pub transient_stake_accounts: &'a [A],
+
+    pub epoch: Epoch,
+    pub rent: &'a Rent,
+
+    /// Ephemeral stake seed of the first planned instruction.
+    /// Subsequent instructions use consecutive seeds
+    pub ephemeral_seed_start: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RebalanceDirection {
+    Increase,
+    Decrease,
+}
+
+/// A single planned `IncreaseAdditionalValidatorStake` or `DecreaseAdditionalValidatorStake` instruction
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RebalanceStep {
+    pub direction: RebalanceDirection,
+    pub vote_account: Pubkey,
+    pub validator_seed: Option<NonZeroU32>,
+    pub lamports: u64,
+    pub transient_stake_seed: u64,
+    pub ephemeral_stake_seed: u64,
+}
+
+impl RebalanceStep {
+    pub fn args(&self) -> AdditionalValidatorStakeArgs {
+        AdditionalValidatorStakeArgs {
+            lamports: self.lamports,
+            transient_stake_seed: self.transient_stake_seed,
+            ephemeral_stake_seed: self.ephemeral_stake_seed,
+        }
+    }
+}
+
+/// What can be done with a validator's transient stake account this epoch
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TransientStake {
+    None,
+    ActivatingThisEpoch,
+    DeactivatingThisEpoch,
+    /// Created in a previous epoch or not provided.
+    /// Cannot be added to until it is merged away by the epoch crank
+    Locked,
+}
+
+impl TransientStake {
+    fn allows(&self, direction: RebalanceDirection) -> bool {
+        matches!(
+            (self, direction),
+            (Self::None, _)
+                | (Self::ActivatingThisEpoch, RebalanceDirection::Increase)
+                | (Self::DeactivatingThisEpoch, RebalanceDirection::Decrease)
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Candidate<'v> {
+    validator: &'v ValidatorStakeInfo,
+    transient: TransientStake,
+    /// Lamports that will be staked to the validator once its transient stake settles
+    effective: u64,
+    target: u64,
+}
+
+impl<
+        'a,
+        P: ReadonlyAccountData + ReadonlyAccountPubkeyBytes,
+        A: ReadonlyAccountData + ReadonlyAccountPubkeyBytes,
+    > RebalancePlanner<'a, P, A>
+{
+    /// Plans the rebalancing instructions, decreases first, each group ordered by descending amount.
+    ///
+    /// Each validator gets at most one instruction, reusing its existing transient stake seed
+    /// if it has transient stake and bumping it otherwise so that a fresh transient stake account is used.
+    ///
+    /// Errors if the stake pool has not been updated for `epoch`
+    pub fn plan_for_prog(&self, program_id: &Pubkey) -> Result<Vec<RebalanceStep>, ProgramError> {
+        let stake_pool = deserialize_stake_pool_checked(self.stake_pool.data().as_ref())?;
+        if stake_pool.last_update_epoch < self.epoch {
+            return Err(ProgramError::InvalidArgument);
+        }
+        let candidates = self.candidates_for_prog(program_id)?;
+
+        let stake_rent = self.rent.minimum_balance(StakeStateV2::size_of());
+        let min_reserve = min_reserve_lamports(self.rent);
+        let min_vsa_lamports = lamports_for_new_vsa(self.rent);
+        let mut reserve_lamports = self.reserve_lamports;
+        let mut ephemeral_stake_seed = self.ephemeral_seed_start;
+        let mut steps = Vec::new();
+
+        let mut decreases: Vec<(u64, &Candidate)> = candidates
+            .iter()
+            .filter(|c| c.transient.allows(RebalanceDirection::Decrease))
+            .filter_map(|c| {
+                let lamports = c.effective.saturating_sub(c.target).min(
+                    c.validator
+                        .active_stake_lamports
+                        .saturating_sub(min_vsa_lamports),
+                );
+                Some((lamports, c)).filter(|(l, _)| *l >= min_delegation())
+            })
+            .collect();
+        decreases.sort_by_key(|(lamports, _)| Reverse(*lamports));
+        for (lamports, c) in decreases {
+            // the reserve funds the ephemeral stake account's rent
+            match reserve_lamports.checked_sub(stake_rent) {
+                Some(remaining) if remaining >= min_reserve => reserve_lamports = remaining,
+                _ => break,
+            }
+            steps.push(step(
+                RebalanceDirection::Decrease,
+                c,
+                lamports,
+                ephemeral_stake_seed,
+            ));
+            ephemeral_stake_seed = ephemeral_stake_seed.wrapping_add(1);
+        }
+
+        let mut increases: Vec<(u64, &Candidate)> = candidates
+            .iter()
+            .filter(|c| c.transient.allows(RebalanceDirection::Increase))
+            .map(|c| (c.target.saturating_sub(c.effective), c))
+            .filter(|(lamports, _)| *lamports >= min_delegation())
+            .collect();
+        increases.sort_by_key(|(lamports, _)| Reverse(*lamports));
+        for (deficit, c) in increases {
+            // the reserve must retain its minimum balance after funding
+            // both the transferred lamports and the ephemeral stake account's rent
+            let lamports = deficit.min(
+                reserve_lamports
+                    .saturating_sub(stake_rent)
+                    .saturating_sub(min_reserve),
+            );
+            if lamports < min_delegation() {
+                continue;
+            }
+            reserve_lamports -= lamports + stake_rent;
+            steps.push(step(
+                RebalanceDirection::Increase,
+                c,
+                lamports,
+                ephemeral_stake_seed,
+            ));
+            ephemeral_stake_seed = ephemeral_stake_seed.wrapping_add(1);
+        }
+
+        Ok(steps)
+    }
+
+    /// [`Self::plan_for_prog`], but resolved into instructions.
+    /// The pool's staker must sign the transaction(s) containing them
+    pub fn ixs_for_prog(&self, program_id: &Pubkey) -> Result<Vec<Instruction>, ProgramError> {
+        self.plan_for_prog(program_id)?
+            .iter()
+            .map(|s| self.step_ix_for_prog(program_id, s))
+            .collect()
+    }
+
+    pub fn step_ix_for_prog(
+        &self,
+        program_id: &Pubkey,
+        step: &RebalanceStep,
+    ) -> Result<Instruction, ProgramError> {
+        let program_id_and_vote = ProgramIdAndVote {
+            program_id: *program_id,
+            vote_account: step.vote_account,
+        };
+        let seeds = AdditionalValidatorStakeSeeds {
+            validator: step.validator_seed,
+            transient: step.transient_stake_seed,
+            ephemeral: step.ephemeral_stake_seed,
+        };
+        let ix = match step.direction {
+            RebalanceDirection::Increase => increase_additional_validator_stake_ix_with_program_id(
+                *program_id,
+                IncreaseAdditionalValidatorStake {
+                    stake_pool: &self.stake_pool,
+                }
+                .resolve_for_prog_with_seeds(program_id_and_vote, seeds)?,
+                IncreaseAdditionalValidatorStakeIxArgs { args: step.args() },
+            ),
+            RebalanceDirection::Decrease => decrease_additional_validator_stake_ix_with_program_id(
+                *program_id,
+                DecreaseAdditionalValidatorStake {
+                    stake_pool: &self.stake_pool,
+                }
+                .resolve_for_prog_with_seeds(program_id_and_vote, seeds)?,
+                DecreaseAdditionalValidatorStakeIxArgs { args: step.args() },
+            ),
+        }?;
+        Ok(ix)
+    }
+
+    fn candidates_for_prog(&self, program_id: &Pubkey) -> Result<Vec<Candidate<'a>>, ProgramError> {
+        let resolver = UpdateValidatorListBalance {
+            stake_pool: &self.stake_pool,
+        };
+        let transient_stake_states: HashMap<Pubkey, StakeStateV2> = self
+            .transient_stake_accounts
+            .iter()
+            .filter_map(|a| {
+                StakeStateV2::deserialize(&mut a.data().as_ref())
+                    .ok()
+                    .map(|state| (Pubkey::new_from_array(a.pubkey_bytes()), state))
+            })
+            .collect();
+
+        let mut total_lamports = self.reserve_lamports;
+        for v in self.validator_list.validators.iter() {
+            total_lamports = total_lamports
+                .checked_add(v.active_stake_lamports)
+                .and_then(|t| t.checked_add(v.transient_stake_lamports))
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+        }
+        let distributable = total_lamports.saturating_sub(min_reserve_lamports(self.rent));
+
+        let active = || {
+            self.validator_list
+                .validators
+                .iter()
+                .filter(|v| v.status == StakeStatus::Active)
+        };
+        let weight = |v: &ValidatorStakeInfo| {
+            self.target_weights
+                .get(&v.vote_account_address)
+                .copied()
+                .unwrap_or(0)
+        };
+        let total_weight: u128 = active().map(|v| u128::from(weight(v))).sum();
+
+        active()
+            .map(|validator| {
+                let transient = if validator.transient_stake_lamports == 0 {
+                    TransientStake::None
+                } else {
+                    let (_vsa, tsa) = resolver.validator_pair_for_prog(program_id, validator);
+                    match transient_stake_states.get(&tsa) {
+                        Some(StakeStateV2::Stake(_, stake, _))
+                            if stake.delegation.deactivation_epoch == Epoch::MAX
+                                && stake.delegation.activation_epoch == self.epoch =>
+                        {
+                            TransientStake::ActivatingThisEpoch
+                        }
+                        Some(StakeStateV2::Stake(_, stake, _))
+                            if stake.delegation.deactivation_epoch == self.epoch =>
+                        {
+                            TransientStake::DeactivatingThisEpoch
+                        }
+                        _ => TransientStake::Locked,
+                    }
+                };
+                let effective = match transient {
+                    TransientStake::DeactivatingThisEpoch => validator.active_stake_lamports,
+                    _ => validator.active_stake_lamports + validator.transient_stake_lamports,
+                };
+                // weight <= total_weight so this is <= distributable.
+                // All targets are 0 if all weights are 0
+                let target = (u128::from(distributable) * u128::from(weight(validator)))
+                    .checked_div(total_weight)
+                    .unwrap_or(0)
+                    .try_into()
+                    .map_err(|_e| ProgramError::ArithmeticOverflow)?;
+                Ok(Candidate {
+                    validator,
+                    transient,
+                    effective,
+                    target,
+                })
+            })
+            .collect()
+    }
+}
+
+fn step(
+    direction: RebalanceDirection,
+    Candidate { validator, .. }: &Candidate,
+    lamports: u64,
+    ephemeral_stake_seed: u64,
+) -> RebalanceStep {
+    let transient_stake_seed = if validator.transient_stake_lamports == 0 {
+        validator.transient_seed_suffix.wrapping_add(1)
+    } else {
+        validator.transient_seed_suffix
+    };
+    RebalanceStep {
+        direction,
+        vote_account: validator.vote_account_address,
+        validator_seed: NonZeroU32::new(validator.validator_seed_suffix),
+        lamports,
+        transient_stake_seed,
+        ephemeral_stake_seed,
+    }
+}
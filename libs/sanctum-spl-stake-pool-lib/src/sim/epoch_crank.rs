@@ -0,0 +1,335 @@
+use std::collections::HashMap;
+
+use solana_program::{
+    clock::{Clock, Epoch},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    stake::state::{Lockup, StakeStateV2},
+    stake_history::StakeHistory,
+};
+use solana_readonly_account::{
+    ReadonlyAccountData, ReadonlyAccountLamports, ReadonlyAccountPubkeyBytes,
+};
+use spl_stake_pool_interface::{
+    StakePool, StakeStatus, UpdateStakePoolBalanceKeys, UpdateValidatorListBalanceKeys,
+    ValidatorList,
+};
+
+use crate::{
+    account_resolvers::{UpdateStakePoolBalance, UpdateValidatorListBalance},
+    deserialize_stake_pool_checked,
+    future_fee::advance_future_epoch_fee,
+    MIN_RESERVE_BALANCE_EXCLUDE_RENT,
+};
+
+use super::stake_account::{
+    merge, stake_is_inactive_without_history, stake_is_usable_by_pool, withdraw, SimStakeAccount,
+    StakeActivationCtx,
+};
+
+/// Simulates cranking a stake pool for the epoch
+/// by running UpdateValidatorListBalance over the entire validator list
+/// followed by UpdateStakePoolBalance
+#[derive(Clone, Copy, Debug)]
+pub struct EpochCrankSim<'a, P, A> {
+    pub stake_pool: P,
+    pub validator_list: &'a ValidatorList,
+    pub reserve_stake: &'a A,
+
+    /// Validator and transient stake accounts of the pool.
+    /// Accounts not in this slice are treated as nonexistent
+    pub stake_accounts: &'a [A],
+
+    pub clock: &'a Clock,
+    pub stake_history: &'a StakeHistory,
+
+    /// Epoch at which the stake program's reduced warmup/cooldown rate takes effect, if any
+    pub new_rate_activation_epoch: Option<Epoch>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UpdateValidatorListBalanceSimResult {
+    pub validator_list: ValidatorList,
+
+    /// Lamports in the reserve after merges and withdrawals into it
+    pub reserve_lamports: u64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct EpochCrankSimResult {
+    pub stake_pool: StakePool,
+    pub validator_list: ValidatorList,
+
+    /// Pool tokens minted to the manager fee account as epoch fees
+    pub epoch_fee: u64,
+}
+
+impl<
+        'a,
+        P: ReadonlyAccountData + ReadonlyAccountPubkeyBytes,
+        A: ReadonlyAccountData + ReadonlyAccountLamports + ReadonlyAccountPubkeyBytes,
+    > EpochCrankSim<'a, P, A>
+{
+    /// Simulates UpdateValidatorListBalance over the entire validator list in a single instruction.
+    ///
+    /// Errors if a merge the instruction would attempt fails
+    pub fn update_validator_list_balance_for_prog(
+        &self,
+        program_id: &Pubkey,
+        no_merge: bool,
+    ) -> Result<UpdateValidatorListBalanceSimResult, ProgramError> {
+        let stake_pool = deserialize_stake_pool_checked(self.stake_pool.data().as_ref())?;
+        let resolver = UpdateValidatorListBalance {
+            stake_pool: &self.stake_pool,
+        };
+        let UpdateValidatorListBalanceKeys {
+            withdraw_authority,
+            reserve_stake,
+            ..
+        } = resolver.resolve_for_prog(program_id)?;
+        self.check_reserve_stake(&reserve_stake)?;
+
+        let ctx = StakeActivationCtx {
+            epoch: self.clock.epoch,
+            stake_history: self.stake_history,
+            new_rate_activation_epoch: self.new_rate_activation_epoch,
+        };
+        let mut stake_accounts: HashMap<Pubkey, SimStakeAccount> = self
+            .stake_accounts
+            .iter()
+            .map(|a| {
+                (
+                    Pubkey::new_from_array(a.pubkey_bytes()),
+                    SimStakeAccount::from_account(a),
+                )
+            })
+            .collect();
+        let mut reserve = SimStakeAccount::from_account(self.reserve_stake);
+        let mut validator_list = self.validator_list.clone();
+        let lockup = stake_pool_lockup(&stake_pool);
+
+        for validator in validator_list.validators.iter_mut() {
+            let (vsa_addr, tsa_addr) = resolver.validator_pair_for_prog(program_id, validator);
+            let mut vsa = stake_accounts
+                .remove(&vsa_addr)
+                .unwrap_or(SimStakeAccount::NONEXISTENT);
+            let mut tsa = stake_accounts
+                .remove(&tsa_addr)
+                .unwrap_or(SimStakeAccount::NONEXISTENT);
+            let is_usable = |meta| stake_is_usable_by_pool(meta, &withdraw_authority, &lockup);
+
+            let mut active_stake_lamports = 0;
+            let mut transient_stake_lamports = 0;
+
+            // copied from
+            // https://github.com/solana-labs/solana-program-library/blob/3e35101763097b5b3d21686191132e5d930f5b23/stake-pool/program/src/processor.rs#L2333-L2525
+            let transient_stake_state = tsa.state;
+            match transient_stake_state {
+                Some(StakeStateV2::Initialized(meta)) if is_usable(&meta) => {
+                    if no_merge {
+                        transient_stake_lamports = tsa.lamports;
+                    } else {
+                        merge(&mut reserve, &mut tsa, &ctx)?;
+                        validator.status = status_after_removing_transient_stake(&validator.status);
+                    }
+                }
+                Some(StakeStateV2::Stake(meta, stake, _)) if is_usable(&meta) => {
+                    if no_merge {
+                        transient_stake_lamports = tsa.lamports;
+                    } else if stake_is_inactive_without_history(&stake, ctx.epoch) {
+                        merge(&mut reserve, &mut tsa, &ctx)?;
+                        validator.status = status_after_removing_transient_stake(&validator.status);
+                    } else if stake.delegation.activation_epoch < ctx.epoch {
+                        match vsa.state {
+                            Some(StakeStateV2::Stake(_, validator_stake, _))
+                                if validator_stake.delegation.activation_epoch < ctx.epoch =>
+                            {
+                                merge(&mut vsa, &mut tsa, &ctx)?;
+                            }
+                            _ => transient_stake_lamports = tsa.lamports,
+                        }
+                    } else {
+                        transient_stake_lamports = tsa.lamports;
+                    }
+                }
+                _ => (),
+            }
+
+            let validator_stake_state = vsa.state;
+            match validator_stake_state {
+                Some(StakeStateV2::Stake(meta, stake, _)) => {
+                    let additional_lamports = vsa
+                        .lamports
+                        .saturating_sub(stake.delegation.stake)
+                        .saturating_sub(meta.rent_exempt_reserve);
+                    if additional_lamports > 0 && is_usable(&meta) {
+                        withdraw(&mut vsa, &mut reserve, additional_lamports)?;
+                    }
+                    match validator.status {
+                        StakeStatus::Active => active_stake_lamports = vsa.lamports,
+                        StakeStatus::DeactivatingValidator | StakeStatus::DeactivatingAll => {
+                            if no_merge {
+                                active_stake_lamports = vsa.lamports;
+                            } else if is_usable(&meta)
+                                && stake_is_inactive_without_history(&stake, ctx.epoch)
+                            {
+                                merge(&mut reserve, &mut vsa, &ctx)?;
+                                validator.status =
+                                    status_after_removing_validator_stake(&validator.status);
+                            }
+                        }
+                        StakeStatus::DeactivatingTransient | StakeStatus::ReadyForRemoval => (),
+                    }
+                }
+                Some(StakeStateV2::Initialized(meta)) if is_usable(&meta) => {
+                    merge(&mut reserve, &mut vsa, &ctx)?;
+                    validator.status = status_after_removing_validator_stake(&validator.status);
+                }
+                _ => (),
+            }
+
+            validator.last_update_epoch = ctx.epoch;
+            validator.active_stake_lamports = active_stake_lamports;
+            validator.transient_stake_lamports = transient_stake_lamports;
+        }
+
+        Ok(UpdateValidatorListBalanceSimResult {
+            validator_list,
+            reserve_lamports: reserve.lamports,
+        })
+    }
+
+    /// Simulates the full epoch crank.
+    ///
+    /// Assumes that the pool mint's supply is equal to the stake pool's pool_token_supply
+    /// and that the manager fee account is valid
+    pub fn simulate_for_prog(
+        &self,
+        program_id: &Pubkey,
+    ) -> Result<EpochCrankSimResult, ProgramError> {
+        let UpdateValidatorListBalanceSimResult {
+            validator_list,
+            reserve_lamports,
+        } = self.update_validator_list_balance_for_prog(program_id, false)?;
+
+        let mut stake_pool = deserialize_stake_pool_checked(self.stake_pool.data().as_ref())?;
+        let UpdateStakePoolBalanceKeys { reserve_stake, .. } = UpdateStakePoolBalance {
+            stake_pool: &self.stake_pool,
+        }
+        .resolve_for_prog(program_id)?;
+        self.check_reserve_stake(&reserve_stake)?;
+
+        // copied from
+        // https://github.com/solana-labs/solana-program-library/blob/3e35101763097b5b3d21686191132e5d930f5b23/stake-pool/program/src/processor.rs#L2585-L2661
+        let epoch = self.clock.epoch;
+        let previous_lamports = stake_pool.total_lamports;
+        let previous_pool_token_supply = stake_pool.pool_token_supply;
+        let mut total_lamports = match SimStakeAccount::from_account(self.reserve_stake).state {
+            Some(StakeStateV2::Initialized(meta)) => reserve_lamports
+                .checked_sub(
+                    meta.rent_exempt_reserve
+                        .saturating_add(MIN_RESERVE_BALANCE_EXCLUDE_RENT),
+                )
+                .ok_or(ProgramError::ArithmeticOverflow)?,
+            // WrongStakeStake
+            _ => return Err(ProgramError::InvalidAccountData),
+        };
+        for validator in validator_list.validators.iter() {
+            if validator.last_update_epoch < epoch {
+                // StakeListOutOfDate
+                return Err(ProgramError::InvalidAccountData);
+            }
+            total_lamports = total_lamports
+                .checked_add(validator.active_stake_lamports)
+                .and_then(|t| t.checked_add(validator.transient_stake_lamports))
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+        }
+
+        let reward_lamports = total_lamports.saturating_sub(previous_lamports);
+        let epoch_fee = calc_epoch_fee_amount(&stake_pool, reward_lamports)?;
+
+        if stake_pool.last_update_epoch < epoch {
+            advance_future_epoch_fee(&mut stake_pool.epoch_fee, &mut stake_pool.next_epoch_fee);
+            advance_future_epoch_fee(
+                &mut stake_pool.stake_withdrawal_fee,
+                &mut stake_pool.next_stake_withdrawal_fee,
+            );
+            advance_future_epoch_fee(
+                &mut stake_pool.sol_withdrawal_fee,
+                &mut stake_pool.next_sol_withdrawal_fee,
+            );
+
+            stake_pool.last_update_epoch = epoch;
+            stake_pool.last_epoch_total_lamports = previous_lamports;
+            stake_pool.last_epoch_pool_token_supply = previous_pool_token_supply;
+        }
+        stake_pool.total_lamports = total_lamports;
+        stake_pool.pool_token_supply = previous_pool_token_supply
+            .checked_add(epoch_fee)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        Ok(EpochCrankSimResult {
+            stake_pool,
+            validator_list,
+            epoch_fee,
+        })
+    }
+
+    fn check_reserve_stake(&self, expected: &Pubkey) -> Result<(), ProgramError> {
+        if self.reserve_stake.pubkey_bytes() != expected.to_bytes() {
+            return Err(ProgramError::InvalidArgument);
+        }
+        Ok(())
+    }
+}
+
+fn stake_pool_lockup(stake_pool: &StakePool) -> Lockup {
+    Lockup {
+        unix_timestamp: stake_pool.lockup.unix_timestamp,
+        epoch: stake_pool.lockup.epoch,
+        custodian: stake_pool.lockup.custodian,
+    }
+}
+
+fn status_after_removing_transient_stake(status: &StakeStatus) -> StakeStatus {
+    match status {
+        StakeStatus::DeactivatingAll => StakeStatus::DeactivatingValidator,
+        StakeStatus::DeactivatingTransient => StakeStatus::ReadyForRemoval,
+        s => s.clone(),
+    }
+}
+
+fn status_after_removing_validator_stake(status: &StakeStatus) -> StakeStatus {
+    match status {
+        StakeStatus::DeactivatingAll => StakeStatus::DeactivatingTransient,
+        StakeStatus::DeactivatingValidator => StakeStatus::ReadyForRemoval,
+        s => s.clone(),
+    }
+}
+
+/// copied from
+/// https://github.com/solana-labs/solana-program-library/blob/3e35101763097b5b3d21686191132e5d930f5b23/stake-pool/program/src/state.rs#L243-L259
+fn calc_epoch_fee_amount(
+    stake_pool: &StakePool,
+    reward_lamports: u64,
+) -> Result<u64, ProgramError> {
+    if reward_lamports == 0 {
+        return Ok(0);
+    }
+    let total_lamports = u128::from(stake_pool.total_lamports) + u128::from(reward_lamports);
+    let fee_lamports = if stake_pool.epoch_fee.denominator == 0 {
+        0
+    } else {
+        u128::from(reward_lamports) * u128::from(stake_pool.epoch_fee.numerator)
+            / u128::from(stake_pool.epoch_fee.denominator)
+    };
+    if total_lamports == fee_lamports || stake_pool.pool_token_supply == 0 {
+        Ok(reward_lamports)
+    } else {
+        u128::from(stake_pool.pool_token_supply)
+            .checked_mul(fee_lamports)
+            .and_then(|n| n.checked_div(total_lamports.checked_sub(fee_lamports)?))
+            .and_then(|fee| u64::try_from(fee).ok())
+            .ok_or(ProgramError::ArithmeticOverflow)
+    }
+}
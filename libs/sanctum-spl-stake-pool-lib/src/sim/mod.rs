@@ -0,0 +1,4 @@
+pub mod epoch_crank;
+mod stake_account;
+
+pub use epoch_crank::*;
@@ -0,0 +1,177 @@
+use borsh::BorshDeserialize;
+use solana_program::{
+    clock::Epoch,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    stake::{
+        instruction::StakeError,
+        state::{Lockup, Meta, Stake, StakeStateV2},
+    },
+    stake_history::{StakeHistory, StakeHistoryEntry},
+};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountLamports};
+
+/// A stake account whose lamports and state are modified over the course of a simulation
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct SimStakeAccount {
+    pub lamports: u64,
+
+    /// None if the account data is not a valid stake account
+    pub state: Option<StakeStateV2>,
+}
+
+impl SimStakeAccount {
+    pub const NONEXISTENT: Self = Self {
+        lamports: 0,
+        state: None,
+    };
+
+    pub fn from_account<A: ReadonlyAccountData + ReadonlyAccountLamports>(account: &A) -> Self {
+        Self {
+            lamports: account.lamports(),
+            state: StakeStateV2::deserialize(&mut account.data().as_ref()).ok(),
+        }
+    }
+}
+
+/// Params required to determine stake activation status
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct StakeActivationCtx<'a> {
+    pub epoch: Epoch,
+    pub stake_history: &'a StakeHistory,
+    pub new_rate_activation_epoch: Option<Epoch>,
+}
+
+pub(crate) fn stake_is_usable_by_pool(
+    meta: &Meta,
+    expected_authority: &Pubkey,
+    expected_lockup: &Lockup,
+) -> bool {
+    meta.authorized.staker == *expected_authority
+        && meta.authorized.withdrawer == *expected_authority
+        && meta.lockup == *expected_lockup
+}
+
+/// Checks if a stake account is inactive, without taking into account cooldowns
+pub(crate) fn stake_is_inactive_without_history(stake: &Stake, epoch: Epoch) -> bool {
+    stake.delegation.deactivation_epoch < epoch
+        || (stake.delegation.activation_epoch == epoch
+            && stake.delegation.deactivation_epoch == epoch)
+}
+
+enum MergeKind {
+    Inactive,
+    ActivationEpoch(Meta, Stake),
+    FullyActive(Stake),
+}
+
+impl MergeKind {
+    fn of(state: &Option<StakeStateV2>, ctx: &StakeActivationCtx) -> Result<Self, ProgramError> {
+        match state {
+            Some(StakeStateV2::Initialized(_)) => Ok(Self::Inactive),
+            Some(StakeStateV2::Stake(meta, stake, _)) => {
+                let StakeHistoryEntry {
+                    effective,
+                    activating,
+                    deactivating,
+                } = stake.delegation.stake_activating_and_deactivating(
+                    ctx.epoch,
+                    ctx.stake_history,
+                    ctx.new_rate_activation_epoch,
+                );
+                match (effective, activating, deactivating) {
+                    (0, 0, 0) => Ok(Self::Inactive),
+                    (0, _, _) => Ok(Self::ActivationEpoch(*meta, *stake)),
+                    (_, 0, 0) => Ok(Self::FullyActive(*stake)),
+                    _ => Err(ProgramError::Custom(StakeError::MergeTransientStake as u32)),
+                }
+            }
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+}
+
+/// Simulates the stake program merging `source` into `destination`.
+///
+/// Does not check that authorities and lockups match, since this is only used
+/// for stake accounts that are all owned by the same stake pool.
+/// Does not update the destination's credits observed.
+pub(crate) fn merge(
+    destination: &mut SimStakeAccount,
+    source: &mut SimStakeAccount,
+    ctx: &StakeActivationCtx,
+) -> Result<(), ProgramError> {
+    let merged_stake = match (
+        MergeKind::of(&destination.state, ctx)?,
+        MergeKind::of(&source.state, ctx)?,
+    ) {
+        (MergeKind::Inactive, MergeKind::Inactive)
+        | (MergeKind::Inactive, MergeKind::ActivationEpoch(..)) => None,
+        (MergeKind::ActivationEpoch(_, mut stake), MergeKind::Inactive) => {
+            stake.delegation.stake = stake
+                .delegation
+                .stake
+                .checked_add(source.lamports)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+            Some(stake)
+        }
+        (
+            MergeKind::ActivationEpoch(_, mut stake),
+            MergeKind::ActivationEpoch(source_meta, source_stake),
+        ) => {
+            stake.delegation.stake = source_meta
+                .rent_exempt_reserve
+                .checked_add(source_stake.delegation.stake)
+                .and_then(|source_lamports| stake.delegation.stake.checked_add(source_lamports))
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+            Some(stake)
+        }
+        (MergeKind::FullyActive(mut stake), MergeKind::FullyActive(source_stake)) => {
+            if stake.delegation.voter_pubkey != source_stake.delegation.voter_pubkey {
+                return Err(ProgramError::Custom(StakeError::MergeMismatch as u32));
+            }
+            // source's rent_exempt_reserve is not staked and
+            // becomes withdrawable lamports of destination instead
+            stake.delegation.stake = stake
+                .delegation
+                .stake
+                .checked_add(source_stake.delegation.stake)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+            Some(stake)
+        }
+        _ => return Err(ProgramError::Custom(StakeError::MergeMismatch as u32)),
+    };
+    if let (Some(merged_stake), Some(StakeStateV2::Stake(_, stake, _))) =
+        (merged_stake, destination.state.as_mut())
+    {
+        *stake = merged_stake;
+    }
+    destination.lamports = destination
+        .lamports
+        .checked_add(source.lamports)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    *source = SimStakeAccount {
+        lamports: 0,
+        state: Some(StakeStateV2::Uninitialized),
+    };
+    Ok(())
+}
+
+/// Simulates the stake program withdrawing `lamports` from `from` to `to`.
+///
+/// Assumes that `from` has at least `lamports` that are withdrawable
+pub(crate) fn withdraw(
+    from: &mut SimStakeAccount,
+    to: &mut SimStakeAccount,
+    lamports: u64,
+) -> Result<(), ProgramError> {
+    from.lamports = from
+        .lamports
+        .checked_sub(lamports)
+        .ok_or(ProgramError::InsufficientFunds)?;
+    to.lamports = to
+        .lamports
+        .checked_add(lamports)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    Ok(())
+}
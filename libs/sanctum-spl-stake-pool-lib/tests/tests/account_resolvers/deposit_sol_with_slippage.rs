@@ -0,0 +1,170 @@
+use sanctum_solana_test_utils::{
+    stake_pool::{
+        mock_stake_pool, mock_validator_list, MockStakePoolArgs, MockStakePoolKeys,
+        StakePoolProgramTest,
+    },
+    token::{tokenkeg::TokenkegProgramTest, MockTokenAccountArgs},
+    ExtendedProgramTest,
+};
+use sanctum_spl_stake_pool_lib::{account_resolvers::DepositSolWithSlippage, QuoteDepositSol};
+use solana_program::{native_token::LAMPORTS_PER_SOL, pubkey::Pubkey};
+use solana_readonly_account::keyed::Keyed;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+use spl_stake_pool_interface::{DepositSolWithSlippageIxArgs, Fee};
+
+use crate::tests::common::{stake_pool_program_test, token_balance};
+
+const RESERVE_LAMPORTS: u64 = 10 * LAMPORTS_PER_SOL;
+
+#[test]
+fn deposit_sol_with_slippage_ix_matches_spl() {
+    let keys = MockStakePoolKeys::new_unique();
+    let mut stake_pool = mock_stake_pool(&MockStakePoolArgs {
+        keys,
+        reserve_lamports: RESERVE_LAMPORTS,
+        pool_token_supply: RESERVE_LAMPORTS,
+        last_update_epoch: 0,
+        validators: vec![],
+    });
+    let deposit_from = Pubkey::new_unique();
+    let mint_to = Pubkey::new_unique();
+    let referrer = Pubkey::new_unique();
+    let args = DepositSolWithSlippageIxArgs {
+        lamports_in: LAMPORTS_PER_SOL,
+        min_tokens_out: LAMPORTS_PER_SOL - 1,
+    };
+
+    let ix = DepositSolWithSlippage {
+        pool: Keyed {
+            pubkey: keys.stake_pool,
+            account: &stake_pool,
+        },
+        deposit_from,
+        mint_to,
+        referral_fee_dest: Some(referrer),
+    }
+    .full_ix(&spl_stake_pool::ID, args.clone())
+    .unwrap();
+    let expected = spl_stake_pool::instruction::deposit_sol_with_slippage(
+        &spl_stake_pool::ID,
+        &keys.stake_pool,
+        &keys.withdraw_authority,
+        &keys.reserve_stake,
+        &deposit_from,
+        &mint_to,
+        &keys.manager_fee_account,
+        &referrer,
+        &keys.pool_mint,
+        &spl_token::ID,
+        args.lamports_in,
+        args.min_tokens_out,
+    );
+    assert_eq!(ix, expected);
+
+    let sol_deposit_authority = Pubkey::new_unique();
+    stake_pool.sol_deposit_authority = Some(sol_deposit_authority);
+    let ix = DepositSolWithSlippage {
+        pool: Keyed {
+            pubkey: keys.stake_pool,
+            account: &stake_pool,
+        },
+        deposit_from,
+        mint_to,
+        referral_fee_dest: None,
+    }
+    .full_ix(&spl_stake_pool::ID, args.clone())
+    .unwrap();
+    let expected = spl_stake_pool::instruction::deposit_sol_with_authority_and_slippage(
+        &spl_stake_pool::ID,
+        &keys.stake_pool,
+        &sol_deposit_authority,
+        &keys.withdraw_authority,
+        &keys.reserve_stake,
+        &deposit_from,
+        &mint_to,
+        &keys.manager_fee_account,
+        &mint_to,
+        &keys.pool_mint,
+        &spl_token::ID,
+        args.lamports_in,
+        args.min_tokens_out,
+    );
+    assert_eq!(ix, expected);
+}
+
+#[tokio::test]
+async fn deposit_sol_with_slippage_pays_referrer_onchain() {
+    let keys = MockStakePoolKeys::new_unique();
+    let args = MockStakePoolArgs {
+        keys,
+        reserve_lamports: RESERVE_LAMPORTS,
+        pool_token_supply: RESERVE_LAMPORTS,
+        last_update_epoch: 0,
+        validators: vec![],
+    };
+    let mut stake_pool = mock_stake_pool(&args);
+    stake_pool.sol_deposit_fee = Fee {
+        denominator: 100,
+        numerator: 1,
+    };
+    stake_pool.sol_referral_fee = 50;
+    let deposit_lamports = LAMPORTS_PER_SOL;
+    let quote = stake_pool.quote_deposit_sol(deposit_lamports).unwrap();
+
+    let user = Keypair::new();
+    let user_pool_tokens = Pubkey::new_unique();
+    let referrer_pool_tokens = Pubkey::new_unique();
+    let pt = [user_pool_tokens, referrer_pool_tokens]
+        .into_iter()
+        .fold(
+            stake_pool_program_test().add_stake_pool_accounts(
+                &keys,
+                &stake_pool,
+                &mock_validator_list(&args),
+                RESERVE_LAMPORTS,
+            ),
+            |pt, addr| {
+                pt.add_tokenkeg_account_from_args(
+                    addr,
+                    MockTokenAccountArgs {
+                        mint: keys.pool_mint,
+                        authority: Pubkey::new_unique(),
+                        amount: 0,
+                    },
+                )
+            },
+        )
+        .add_system_account(user.pubkey(), deposit_lamports + LAMPORTS_PER_SOL);
+    let (mut banks_client, payer, last_blockhash) = pt.start().await;
+
+    let ix = DepositSolWithSlippage {
+        pool: Keyed {
+            pubkey: keys.stake_pool,
+            account: &stake_pool,
+        },
+        deposit_from: user.pubkey(),
+        mint_to: user_pool_tokens,
+        referral_fee_dest: Some(referrer_pool_tokens),
+    }
+    .full_ix(
+        &spl_stake_pool::ID,
+        DepositSolWithSlippageIxArgs {
+            lamports_in: deposit_lamports,
+            min_tokens_out: quote.user,
+        },
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &user], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    assert_eq!(
+        token_balance(&mut banks_client, user_pool_tokens).await,
+        quote.user
+    );
+    assert_eq!(
+        token_balance(&mut banks_client, referrer_pool_tokens).await,
+        quote.referrer
+    );
+    assert!(quote.referrer > 0);
+}
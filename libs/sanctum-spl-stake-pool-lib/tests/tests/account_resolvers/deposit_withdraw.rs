@@ -0,0 +1,226 @@
+use sanctum_solana_test_utils::stake_pool::{
+    mock_stake_pool, MockStakePoolArgs, MockStakePoolKeys,
+};
+use sanctum_spl_stake_pool_lib::account_resolvers::{
+    DepositSol, DepositStake, WithdrawSol, WithdrawStake,
+};
+use solana_program::{
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    stake::state::{Authorized, Meta, StakeStateV2},
+};
+use solana_readonly_account::keyed::Keyed;
+use spl_stake_pool::find_stake_program_address;
+use spl_stake_pool_interface::{
+    withdraw_stake_ix_with_program_id, DepositSolIxArgs, WithdrawSolIxArgs, WithdrawStakeIxArgs,
+};
+
+const RESERVE_LAMPORTS: u64 = 10 * LAMPORTS_PER_SOL;
+
+#[test]
+fn deposit_stake_ixs_match_spl() {
+    let keys = MockStakePoolKeys::new_unique();
+    let stake_pool = mock_stake_pool(&MockStakePoolArgs {
+        keys,
+        reserve_lamports: RESERVE_LAMPORTS,
+        pool_token_supply: RESERVE_LAMPORTS,
+        last_update_epoch: 0,
+        validators: vec![],
+    });
+    let user = Pubkey::new_unique();
+    let stake_depositing = StakeStateV2::Initialized(Meta {
+        authorized: Authorized {
+            staker: user,
+            withdrawer: user,
+        },
+        ..Default::default()
+    });
+    let stake_depositing_addr = Pubkey::new_unique();
+    let mint_to = Pubkey::new_unique();
+    let referral_fee_dest = Pubkey::new_unique();
+    let vote = Pubkey::new_unique();
+    let validator_seed_suffix = 5;
+
+    let ixs = DepositStake {
+        pool: Keyed {
+            pubkey: keys.stake_pool,
+            account: &stake_pool,
+        },
+        stake_depositing: Keyed {
+            pubkey: stake_depositing_addr,
+            account: &stake_depositing,
+        },
+        mint_to,
+        referral_fee_dest,
+    }
+    .full_ix_seq(&spl_stake_pool::ID, vote, validator_seed_suffix)
+    .unwrap();
+    let expected = spl_stake_pool::instruction::deposit_stake(
+        &spl_stake_pool::ID,
+        &keys.stake_pool,
+        &keys.validator_list,
+        &keys.withdraw_authority,
+        &stake_depositing_addr,
+        &user,
+        &find_stake_program_address(
+            &spl_stake_pool::ID,
+            &vote,
+            &keys.stake_pool,
+            validator_seed_suffix.try_into().ok(),
+        )
+        .0,
+        &keys.reserve_stake,
+        &mint_to,
+        &keys.manager_fee_account,
+        &referral_fee_dest,
+        &keys.pool_mint,
+        &spl_token::ID,
+    );
+    assert_eq!(Vec::from(ixs), expected);
+}
+
+#[test]
+fn withdraw_stake_ix_matches_spl() {
+    let keys = MockStakePoolKeys::new_unique();
+    let stake_pool = mock_stake_pool(&MockStakePoolArgs {
+        keys,
+        reserve_lamports: RESERVE_LAMPORTS,
+        pool_token_supply: RESERVE_LAMPORTS,
+        last_update_epoch: 0,
+        validators: vec![],
+    });
+    let burn_from = Pubkey::new_unique();
+    let transfer_authority = Pubkey::new_unique();
+    let beneficiary = Pubkey::new_unique();
+    let split_to = Pubkey::new_unique();
+    let resolver = WithdrawStake {
+        pool: Keyed {
+            pubkey: keys.stake_pool,
+            account: &stake_pool,
+        },
+        burn_from,
+        transfer_authority,
+        beneficiary,
+        split_to,
+    };
+
+    let ix = withdraw_stake_ix_with_program_id(
+        spl_stake_pool::ID,
+        resolver.resolve_with_computed_keys(resolver.compute_keys_for_reserve(&spl_stake_pool::ID)),
+        WithdrawStakeIxArgs {
+            pool_tokens_in: LAMPORTS_PER_SOL,
+        },
+    )
+    .unwrap();
+    let expected = spl_stake_pool::instruction::withdraw_stake(
+        &spl_stake_pool::ID,
+        &keys.stake_pool,
+        &keys.validator_list,
+        &keys.withdraw_authority,
+        &keys.reserve_stake,
+        &split_to,
+        &beneficiary,
+        &transfer_authority,
+        &burn_from,
+        &keys.manager_fee_account,
+        &keys.pool_mint,
+        &spl_token::ID,
+        LAMPORTS_PER_SOL,
+    );
+    assert_eq!(ix, expected);
+}
+
+#[test]
+fn deposit_sol_ix_matches_spl() {
+    let keys = MockStakePoolKeys::new_unique();
+    let mut stake_pool = mock_stake_pool(&MockStakePoolArgs {
+        keys,
+        reserve_lamports: RESERVE_LAMPORTS,
+        pool_token_supply: RESERVE_LAMPORTS,
+        last_update_epoch: 0,
+        validators: vec![],
+    });
+    let sol_deposit_authority = Pubkey::new_unique();
+    stake_pool.sol_deposit_authority = Some(sol_deposit_authority);
+    let deposit_from = Pubkey::new_unique();
+    let mint_to = Pubkey::new_unique();
+    let referrer = Pubkey::new_unique();
+
+    let ix = DepositSol {
+        pool: Keyed {
+            pubkey: keys.stake_pool,
+            account: &stake_pool,
+        },
+        deposit_from,
+        mint_to,
+        referral_fee_dest: Some(referrer),
+    }
+    .full_ix(
+        &spl_stake_pool::ID,
+        DepositSolIxArgs {
+            lamports_in: LAMPORTS_PER_SOL,
+        },
+    )
+    .unwrap();
+    let expected = spl_stake_pool::instruction::deposit_sol_with_authority(
+        &spl_stake_pool::ID,
+        &keys.stake_pool,
+        &sol_deposit_authority,
+        &keys.withdraw_authority,
+        &keys.reserve_stake,
+        &deposit_from,
+        &mint_to,
+        &keys.manager_fee_account,
+        &referrer,
+        &keys.pool_mint,
+        &spl_token::ID,
+        LAMPORTS_PER_SOL,
+    );
+    assert_eq!(ix, expected);
+}
+
+#[test]
+fn withdraw_sol_ix_matches_spl() {
+    let keys = MockStakePoolKeys::new_unique();
+    let stake_pool = mock_stake_pool(&MockStakePoolArgs {
+        keys,
+        reserve_lamports: RESERVE_LAMPORTS,
+        pool_token_supply: RESERVE_LAMPORTS,
+        last_update_epoch: 0,
+        validators: vec![],
+    });
+    let transfer_authority = Pubkey::new_unique();
+    let burn_from = Pubkey::new_unique();
+    let withdraw_to = Pubkey::new_unique();
+
+    let ix = WithdrawSol {
+        pool: Keyed {
+            pubkey: keys.stake_pool,
+            account: &stake_pool,
+        },
+        transfer_authority,
+        burn_from,
+        withdraw_to,
+    }
+    .full_ix(
+        &spl_stake_pool::ID,
+        WithdrawSolIxArgs {
+            tokens_in: LAMPORTS_PER_SOL,
+        },
+    )
+    .unwrap();
+    let expected = spl_stake_pool::instruction::withdraw_sol(
+        &spl_stake_pool::ID,
+        &keys.stake_pool,
+        &keys.withdraw_authority,
+        &transfer_authority,
+        &burn_from,
+        &keys.reserve_stake,
+        &withdraw_to,
+        &keys.manager_fee_account,
+        &keys.pool_mint,
+        &spl_token::ID,
+        LAMPORTS_PER_SOL,
+    );
+    assert_eq!(ix, expected);
+}
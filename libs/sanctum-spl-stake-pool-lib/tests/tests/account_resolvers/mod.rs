@@ -0,0 +1,5 @@
+mod deposit_sol_with_slippage;
+mod deposit_withdraw;
+mod staker;
+mod token_metadata;
+mod withdraw_sol_with_slippage;
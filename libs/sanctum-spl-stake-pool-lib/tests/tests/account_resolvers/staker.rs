@@ -0,0 +1,262 @@
+use std::num::NonZeroU32;
+
+use sanctum_solana_test_utils::stake_pool::{
+    mock_stake_pool, stake_pool_account, MockStakePoolArgs, MockStakePoolKeys,
+};
+use sanctum_spl_stake_pool_lib::account_resolvers::{
+    DecreaseValidatorStake, DecreaseValidatorStakeWithReserve, IncreaseValidatorStake, Redelegate,
+    SetPreferredValidator,
+};
+use solana_program::{native_token::LAMPORTS_PER_SOL, pubkey::Pubkey};
+use solana_readonly_account::keyed::Keyed;
+use spl_stake_pool::{
+    find_ephemeral_stake_program_address, find_stake_program_address,
+    find_transient_stake_program_address,
+};
+use spl_stake_pool_interface::{
+    decrease_validator_stake_ix_with_program_id,
+    decrease_validator_stake_with_reserve_ix_with_program_id,
+    increase_validator_stake_ix_with_program_id, redelegate_ix_with_program_id,
+    set_preferred_validator_ix_with_program_id, DecreaseValidatorStakeIxArgs,
+    DecreaseValidatorStakeWithReserveIxArgs, IncreaseValidatorStakeIxArgs, PreferredValidatorType,
+    RedelegateIxArgs, SetPreferredValidatorIxArgs, StakeStatus, ValidatorStakeInfo,
+};
+
+const LAMPORTS: u64 = 2 * LAMPORTS_PER_SOL;
+
+const TRANSIENT_STAKE_SEED: u64 = 69;
+
+fn validator(validator_seed_suffix: u32, transient_seed_suffix: u64) -> ValidatorStakeInfo {
+    ValidatorStakeInfo {
+        active_stake_lamports: 0,
+        transient_stake_lamports: 0,
+        last_update_epoch: 0,
+        transient_seed_suffix,
+        unused: 0,
+        validator_seed_suffix,
+        status: StakeStatus::Active,
+        vote_account_address: Pubkey::new_unique(),
+    }
+}
+
+fn vsa(keys: &MockStakePoolKeys, v: &ValidatorStakeInfo) -> Pubkey {
+    find_stake_program_address(
+        &spl_stake_pool::ID,
+        &v.vote_account_address,
+        &keys.stake_pool,
+        NonZeroU32::new(v.validator_seed_suffix),
+    )
+    .0
+}
+
+fn tsa(keys: &MockStakePoolKeys, v: &ValidatorStakeInfo, seed: u64) -> Pubkey {
+    find_transient_stake_program_address(
+        &spl_stake_pool::ID,
+        &v.vote_account_address,
+        &keys.stake_pool,
+        seed,
+    )
+    .0
+}
+
+#[test]
+fn set_preferred_validator_ix_matches_spl() {
+    let keys = MockStakePoolKeys::new_unique();
+    let stake_pool = mock_stake_pool(&MockStakePoolArgs {
+        keys,
+        reserve_lamports: LAMPORTS,
+        pool_token_supply: LAMPORTS,
+        last_update_epoch: 0,
+        validators: vec![],
+    });
+    let vote = Pubkey::new_unique();
+    let ix = set_preferred_validator_ix_with_program_id(
+        spl_stake_pool::ID,
+        SetPreferredValidator {
+            stake_pool: Keyed {
+                pubkey: keys.stake_pool,
+                account: stake_pool_account(&stake_pool, keys.program_id),
+            },
+        }
+        .resolve()
+        .unwrap(),
+        SetPreferredValidatorIxArgs {
+            validator_type: PreferredValidatorType::Withdraw,
+            validator_vote_address: Some(vote),
+        },
+    )
+    .unwrap();
+    let expected = spl_stake_pool::instruction::set_preferred_validator(
+        &spl_stake_pool::ID,
+        &keys.stake_pool,
+        &stake_pool.staker,
+        &keys.validator_list,
+        spl_stake_pool::instruction::PreferredValidatorType::Withdraw,
+        Some(vote),
+    );
+    assert_eq!(ix, expected);
+}
+
+#[test]
+fn validator_stake_ixs_match_spl() {
+    let keys = MockStakePoolKeys::new_unique();
+    let stake_pool = mock_stake_pool(&MockStakePoolArgs {
+        keys,
+        reserve_lamports: LAMPORTS,
+        pool_token_supply: LAMPORTS,
+        last_update_epoch: 0,
+        validators: vec![],
+    });
+    let v = validator(3, 0);
+    let vsa = vsa(&keys, &v);
+    let tsa = tsa(&keys, &v, TRANSIENT_STAKE_SEED);
+
+    let ix = increase_validator_stake_ix_with_program_id(
+        spl_stake_pool::ID,
+        IncreaseValidatorStake {
+            stake_pool: Keyed {
+                pubkey: keys.stake_pool,
+                account: stake_pool_account(&stake_pool, keys.program_id),
+            },
+        }
+        .resolve_for_validator(spl_stake_pool::ID, &v, TRANSIENT_STAKE_SEED)
+        .unwrap(),
+        IncreaseValidatorStakeIxArgs {
+            lamports: LAMPORTS,
+            transient_stake_seed: TRANSIENT_STAKE_SEED,
+        },
+    )
+    .unwrap();
+    let expected = spl_stake_pool::instruction::increase_validator_stake(
+        &spl_stake_pool::ID,
+        &keys.stake_pool,
+        &stake_pool.staker,
+        &keys.withdraw_authority,
+        &keys.validator_list,
+        &keys.reserve_stake,
+        &tsa,
+        &vsa,
+        &v.vote_account_address,
+        LAMPORTS,
+        TRANSIENT_STAKE_SEED,
+    );
+    assert_eq!(ix, expected);
+
+    let ix = decrease_validator_stake_ix_with_program_id(
+        spl_stake_pool::ID,
+        DecreaseValidatorStake {
+            stake_pool: Keyed {
+                pubkey: keys.stake_pool,
+                account: stake_pool_account(&stake_pool, keys.program_id),
+            },
+        }
+        .resolve_for_validator(spl_stake_pool::ID, &v, TRANSIENT_STAKE_SEED)
+        .unwrap(),
+        DecreaseValidatorStakeIxArgs {
+            lamports: LAMPORTS,
+            transient_stake_seed: TRANSIENT_STAKE_SEED,
+        },
+    )
+    .unwrap();
+    #[allow(deprecated)]
+    let expected = spl_stake_pool::instruction::decrease_validator_stake(
+        &spl_stake_pool::ID,
+        &keys.stake_pool,
+        &stake_pool.staker,
+        &keys.withdraw_authority,
+        &keys.validator_list,
+        &vsa,
+        &tsa,
+        LAMPORTS,
+        TRANSIENT_STAKE_SEED,
+    );
+    assert_eq!(ix, expected);
+
+    let ix = decrease_validator_stake_with_reserve_ix_with_program_id(
+        spl_stake_pool::ID,
+        DecreaseValidatorStakeWithReserve {
+            stake_pool: Keyed {
+                pubkey: keys.stake_pool,
+                account: stake_pool_account(&stake_pool, keys.program_id),
+            },
+        }
+        .resolve_for_validator(spl_stake_pool::ID, &v, TRANSIENT_STAKE_SEED)
+        .unwrap(),
+        DecreaseValidatorStakeWithReserveIxArgs {
+            lamports: LAMPORTS,
+            transient_stake_seed: TRANSIENT_STAKE_SEED,
+        },
+    )
+    .unwrap();
+    let expected = spl_stake_pool::instruction::decrease_validator_stake_with_reserve(
+        &spl_stake_pool::ID,
+        &keys.stake_pool,
+        &stake_pool.staker,
+        &keys.withdraw_authority,
+        &keys.validator_list,
+        &keys.reserve_stake,
+        &vsa,
+        &tsa,
+        LAMPORTS,
+        TRANSIENT_STAKE_SEED,
+    );
+    assert_eq!(ix, expected);
+}
+
+#[test]
+fn redelegate_ix_matches_spl() {
+    let keys = MockStakePoolKeys::new_unique();
+    let stake_pool = mock_stake_pool(&MockStakePoolArgs {
+        keys,
+        reserve_lamports: LAMPORTS,
+        pool_token_supply: LAMPORTS,
+        last_update_epoch: 0,
+        validators: vec![],
+    });
+    let source = validator(0, 1);
+    let destination = validator(7, 8);
+    let args = RedelegateIxArgs {
+        lamports: LAMPORTS,
+        source_transient_stake_seed: 2,
+        ephemeral_stake_seed: 3,
+        destination_transient_stake_seed: destination.transient_seed_suffix,
+    };
+
+    let ix = redelegate_ix_with_program_id(
+        spl_stake_pool::ID,
+        Redelegate {
+            stake_pool: Keyed {
+                pubkey: keys.stake_pool,
+                account: stake_pool_account(&stake_pool, keys.program_id),
+            },
+        }
+        .resolve_for_validators(&spl_stake_pool::ID, &source, &destination, &args)
+        .unwrap(),
+        args.clone(),
+    )
+    .unwrap();
+    let expected = spl_stake_pool::instruction::redelegate(
+        &spl_stake_pool::ID,
+        &keys.stake_pool,
+        &stake_pool.staker,
+        &keys.withdraw_authority,
+        &keys.validator_list,
+        &keys.reserve_stake,
+        &vsa(&keys, &source),
+        &tsa(&keys, &source, args.source_transient_stake_seed),
+        &find_ephemeral_stake_program_address(
+            &spl_stake_pool::ID,
+            &keys.stake_pool,
+            args.ephemeral_stake_seed,
+        )
+        .0,
+        &tsa(&keys, &destination, args.destination_transient_stake_seed),
+        &vsa(&keys, &destination),
+        &destination.vote_account_address,
+        args.lamports,
+        args.source_transient_stake_seed,
+        args.ephemeral_stake_seed,
+        args.destination_transient_stake_seed,
+    );
+    assert_eq!(ix, expected);
+}
@@ -0,0 +1,97 @@
+use sanctum_solana_test_utils::stake_pool::{
+    mock_stake_pool, stake_pool_account, MockStakePoolArgs, MockStakePoolKeys,
+};
+use sanctum_spl_stake_pool_lib::account_resolvers::{CreateTokenMetadata, UpdateTokenMetadata};
+use solana_program::{native_token::LAMPORTS_PER_SOL, pubkey::Pubkey};
+use solana_readonly_account::keyed::Keyed;
+use spl_stake_pool_interface::{
+    create_token_metadata_ix_with_program_id, update_token_metadata_ix_with_program_id,
+    CreateTokenMetadataIxArgs, UpdateTokenMetadataIxArgs,
+};
+
+const NAME: &str = "Test Staked SOL";
+
+const SYMBOL: &str = "tSOL";
+
+const URI: &str = "https://example.com/tsol.json";
+
+#[test]
+fn create_token_metadata_ix_matches_spl() {
+    let keys = MockStakePoolKeys::new_unique();
+    let stake_pool = mock_stake_pool(&MockStakePoolArgs {
+        keys,
+        reserve_lamports: LAMPORTS_PER_SOL,
+        pool_token_supply: LAMPORTS_PER_SOL,
+        last_update_epoch: 0,
+        validators: vec![],
+    });
+    let payer = Pubkey::new_unique();
+    let ix = create_token_metadata_ix_with_program_id(
+        spl_stake_pool::ID,
+        CreateTokenMetadata {
+            stake_pool: Keyed {
+                pubkey: keys.stake_pool,
+                account: stake_pool_account(&stake_pool, keys.program_id),
+            },
+            payer,
+        }
+        .resolve_for_prog(&spl_stake_pool::ID)
+        .unwrap(),
+        CreateTokenMetadataIxArgs {
+            name: NAME.to_owned(),
+            symbol: SYMBOL.to_owned(),
+            uri: URI.to_owned(),
+        },
+    )
+    .unwrap();
+    let expected = spl_stake_pool::instruction::create_token_metadata(
+        &spl_stake_pool::ID,
+        &keys.stake_pool,
+        &keys.manager,
+        &keys.pool_mint,
+        &payer,
+        NAME.to_owned(),
+        SYMBOL.to_owned(),
+        URI.to_owned(),
+    );
+    assert_eq!(ix, expected);
+}
+
+#[test]
+fn update_token_metadata_ix_matches_spl() {
+    let keys = MockStakePoolKeys::new_unique();
+    let stake_pool = mock_stake_pool(&MockStakePoolArgs {
+        keys,
+        reserve_lamports: LAMPORTS_PER_SOL,
+        pool_token_supply: LAMPORTS_PER_SOL,
+        last_update_epoch: 0,
+        validators: vec![],
+    });
+    let ix = update_token_metadata_ix_with_program_id(
+        spl_stake_pool::ID,
+        UpdateTokenMetadata {
+            stake_pool: Keyed {
+                pubkey: keys.stake_pool,
+                account: stake_pool_account(&stake_pool, keys.program_id),
+            },
+        }
+        .resolve_for_prog(&spl_stake_pool::ID)
+        .unwrap(),
+        UpdateTokenMetadataIxArgs {
+            name: NAME.to_owned(),
+            symbol: SYMBOL.to_owned(),
+            uri: URI.to_owned(),
+        },
+    )
+    .unwrap();
+    let expected = spl_stake_pool::instruction::update_token_metadata(
+        &spl_stake_pool::ID,
+        &keys.stake_pool,
+        &keys.manager,
+        &keys.pool_mint,
+        NAME.to_owned(),
+        SYMBOL.to_owned(),
+        URI.to_owned(),
+    );
+    assert_eq!(ix, expected);
+}
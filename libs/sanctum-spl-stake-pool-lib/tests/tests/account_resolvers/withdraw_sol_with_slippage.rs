@@ -0,0 +1,85 @@
+use sanctum_solana_test_utils::stake_pool::{
+    mock_stake_pool, MockStakePoolArgs, MockStakePoolKeys,
+};
+use sanctum_spl_stake_pool_lib::account_resolvers::WithdrawSolWithSlippage;
+use solana_program::{native_token::LAMPORTS_PER_SOL, pubkey::Pubkey};
+use solana_readonly_account::keyed::Keyed;
+use spl_stake_pool_interface::WithdrawSolWithSlippageIxArgs;
+
+const RESERVE_LAMPORTS: u64 = 10 * LAMPORTS_PER_SOL;
+
+#[test]
+fn withdraw_sol_with_slippage_ix_matches_spl() {
+    let keys = MockStakePoolKeys::new_unique();
+    let mut stake_pool = mock_stake_pool(&MockStakePoolArgs {
+        keys,
+        reserve_lamports: RESERVE_LAMPORTS,
+        pool_token_supply: RESERVE_LAMPORTS,
+        last_update_epoch: 0,
+        validators: vec![],
+    });
+    let transfer_authority = Pubkey::new_unique();
+    let burn_from = Pubkey::new_unique();
+    let withdraw_to = Pubkey::new_unique();
+    let args = WithdrawSolWithSlippageIxArgs {
+        tokens_in: LAMPORTS_PER_SOL,
+        min_lamports_out: LAMPORTS_PER_SOL - 1,
+    };
+
+    let ix = WithdrawSolWithSlippage {
+        pool: Keyed {
+            pubkey: keys.stake_pool,
+            account: &stake_pool,
+        },
+        transfer_authority,
+        burn_from,
+        withdraw_to,
+    }
+    .full_ix(&spl_stake_pool::ID, args.clone())
+    .unwrap();
+    let expected = spl_stake_pool::instruction::withdraw_sol_with_slippage(
+        &spl_stake_pool::ID,
+        &keys.stake_pool,
+        &keys.withdraw_authority,
+        &transfer_authority,
+        &burn_from,
+        &keys.reserve_stake,
+        &withdraw_to,
+        &keys.manager_fee_account,
+        &keys.pool_mint,
+        &spl_token::ID,
+        args.tokens_in,
+        args.min_lamports_out,
+    );
+    assert_eq!(ix, expected);
+
+    let sol_withdraw_authority = Pubkey::new_unique();
+    stake_pool.sol_withdraw_authority = Some(sol_withdraw_authority);
+    let ix = WithdrawSolWithSlippage {
+        pool: Keyed {
+            pubkey: keys.stake_pool,
+            account: &stake_pool,
+        },
+        transfer_authority,
+        burn_from,
+        withdraw_to,
+    }
+    .full_ix(&spl_stake_pool::ID, args.clone())
+    .unwrap();
+    let expected = spl_stake_pool::instruction::withdraw_sol_with_authority_and_slippage(
+        &spl_stake_pool::ID,
+        &keys.stake_pool,
+        &sol_withdraw_authority,
+        &keys.withdraw_authority,
+        &transfer_authority,
+        &burn_from,
+        &keys.reserve_stake,
+        &withdraw_to,
+        &keys.manager_fee_account,
+        &keys.pool_mint,
+        &spl_token::ID,
+        args.tokens_in,
+        args.min_lamports_out,
+    );
+    assert_eq!(ix, expected);
+}
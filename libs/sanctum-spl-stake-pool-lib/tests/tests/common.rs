@@ -0,0 +1,28 @@
+use solana_program::{program_pack::Pack, pubkey::Pubkey};
+use solana_program_test::{processor, BanksClient, ProgramTest};
+
+/// A [`ProgramTest`] with only the stake pool program added.
+///
+/// Add pools with [`sanctum_solana_test_utils::stake_pool::StakePoolProgramTest`]
+pub fn stake_pool_program_test() -> ProgramTest {
+    ProgramTest::new(
+        "spl_stake_pool",
+        spl_stake_pool::ID,
+        processor!(spl_stake_pool::processor::Processor::process),
+    )
+}
+
+pub async fn token_balance(banks_client: &mut BanksClient, token_account: Pubkey) -> u64 {
+    let account = banks_client
+        .get_account(token_account)
+        .await
+        .unwrap()
+        .unwrap();
+    spl_token::state::Account::unpack(&account.data)
+        .unwrap()
+        .amount
+}
+
+pub async fn lamports_balance(banks_client: &mut BanksClient, account: Pubkey) -> u64 {
+    banks_client.get_balance(account).await.unwrap()
+}
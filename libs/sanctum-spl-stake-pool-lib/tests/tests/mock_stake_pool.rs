@@ -0,0 +1,191 @@
+use borsh::BorshSerialize;
+use proptest::prelude::*;
+use sanctum_solana_test_utils::stake_pool::{
+    mock_stake_pool, mock_validator_list, proptest_utils::mock_stake_pool_args,
+    validator_list_account_len, MockStakePoolArgs, MockStakePoolKeys, MockValidatorArgs,
+    StakePoolProgramTest, STAKE_POOL_ACCOUNT_LEN,
+};
+use sanctum_spl_stake_pool_lib::{
+    account_resolvers::{UpdateStakePoolBalance, UpdateValidatorListBalance},
+    deserialize_stake_pool_checked, deserialize_validator_list_checked, FindDepositAuthority,
+    FindWithdrawAuthority, STAKE_POOL_SIZE,
+};
+use solana_program::{native_token::LAMPORTS_PER_SOL, pubkey::Pubkey};
+use solana_program_test::{processor, BanksClient, ProgramTest};
+use solana_readonly_account::keyed::Keyed;
+use solana_sdk::{account::Account, signer::Signer, transaction::Transaction};
+use spl_stake_pool_interface::{
+    update_stake_pool_balance_ix_with_program_id, UpdateValidatorListBalanceIxArgs,
+};
+
+const WARP_TO_EPOCH: u64 = 2;
+
+async fn fetch_keyed(banks_client: &mut BanksClient, pubkey: Pubkey) -> Keyed<Account> {
+    Keyed {
+        pubkey,
+        account: banks_client.get_account(pubkey).await.unwrap().unwrap(),
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn mock_stake_pool_cranks_onchain() {
+    let keys = MockStakePoolKeys::new_unique();
+    let args = MockStakePoolArgs {
+        keys,
+        reserve_lamports: 10 * LAMPORTS_PER_SOL,
+        pool_token_supply: 40 * LAMPORTS_PER_SOL,
+        last_update_epoch: 0,
+        validators: vec![
+            MockValidatorArgs {
+                vote: Pubkey::new_unique(),
+                validator_seed_suffix: 0,
+                transient_seed_suffix: 0,
+                staked_lamports: 10 * LAMPORTS_PER_SOL,
+                transient_staked_lamports: 0,
+            },
+            MockValidatorArgs {
+                vote: Pubkey::new_unique(),
+                validator_seed_suffix: 1,
+                transient_seed_suffix: 2,
+                staked_lamports: 10 * LAMPORTS_PER_SOL,
+                transient_staked_lamports: 5 * LAMPORTS_PER_SOL,
+            },
+        ],
+    };
+    let stake_pool = mock_stake_pool(&args);
+    let validator_list = mock_validator_list(&args);
+    assert_eq!(
+        FindWithdrawAuthority {
+            pool: keys.stake_pool
+        }
+        .run_for_prog(&keys.program_id),
+        (keys.withdraw_authority, keys.withdraw_authority_bump)
+    );
+    assert_eq!(
+        FindDepositAuthority {
+            pool: keys.stake_pool
+        }
+        .run_for_prog(&keys.program_id)
+        .0,
+        stake_pool.stake_deposit_authority
+    );
+    let update_vlb = UpdateValidatorListBalance {
+        stake_pool: Keyed {
+            pubkey: keys.stake_pool,
+            account: Account {
+                data: borsh::to_vec(&stake_pool).unwrap(),
+                ..Default::default()
+            },
+        },
+    };
+    for (v, info) in args.validators.iter().zip(validator_list.validators.iter()) {
+        assert_eq!(
+            update_vlb.validator_pair_for_prog(&keys.program_id, info),
+            (
+                v.validator_stake_account(&keys),
+                v.transient_stake_account(&keys)
+            )
+        );
+    }
+
+    let pt = ProgramTest::new(
+        "spl_stake_pool",
+        spl_stake_pool::ID,
+        processor!(spl_stake_pool::processor::Processor::process),
+    )
+    .add_stake_pool_from_args(&args);
+    let mut ctx = pt.start_with_context().await;
+    ctx.warp_to_epoch(WARP_TO_EPOCH).unwrap();
+    // stake accounts cannot be written to during the epoch rewards distribution period
+    ctx.warp_forward_force_reward_interval_end().unwrap();
+
+    let stake_pool_acc = fetch_keyed(&mut ctx.banks_client, keys.stake_pool).await;
+    let update_vlb_ix = UpdateValidatorListBalance {
+        stake_pool: &stake_pool_acc,
+    }
+    .full_ix_from_validator_slice(
+        keys.program_id,
+        &validator_list.validators,
+        UpdateValidatorListBalanceIxArgs {
+            start_index: 0,
+            no_merge: false,
+        },
+    )
+    .unwrap();
+    let update_spb_ix = update_stake_pool_balance_ix_with_program_id(
+        keys.program_id,
+        UpdateStakePoolBalance {
+            stake_pool: &stake_pool_acc,
+        }
+        .resolve_for_prog(&keys.program_id)
+        .unwrap(),
+    )
+    .unwrap();
+    let mut tx =
+        Transaction::new_with_payer(&[update_vlb_ix, update_spb_ix], Some(&ctx.payer.pubkey()));
+    let blockhash = ctx.get_new_latest_blockhash().await.unwrap();
+    tx.sign(&[&ctx.payer], blockhash);
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let onchain_stake_pool = deserialize_stake_pool_checked(
+        &fetch_keyed(&mut ctx.banks_client, keys.stake_pool)
+            .await
+            .account
+            .data,
+    )
+    .unwrap();
+    let onchain_validator_list = deserialize_validator_list_checked(
+        &fetch_keyed(&mut ctx.banks_client, keys.validator_list)
+            .await
+            .account
+            .data,
+    )
+    .unwrap();
+
+    // no rewards in ProgramTest, so only the epoch changes
+    assert_eq!(onchain_stake_pool.last_update_epoch, WARP_TO_EPOCH);
+    assert_eq!(onchain_stake_pool.total_lamports, args.total_lamports());
+    assert_eq!(onchain_stake_pool.pool_token_supply, args.pool_token_supply);
+    // activated transient stake merged into the validator stake account,
+    // with the excess rent-exempt reserve withdrawn to the reserve
+    let [v0, v1] = &onchain_validator_list.validators[..] else {
+        panic!("unexpected validator list {onchain_validator_list:?}")
+    };
+    assert_eq!(
+        v0.active_stake_lamports,
+        args.validators[0].validator_stake_lamports()
+    );
+    assert_eq!(
+        v1.active_stake_lamports,
+        MockValidatorArgs {
+            staked_lamports: 15 * LAMPORTS_PER_SOL,
+            transient_staked_lamports: 0,
+            ..args.validators[1]
+        }
+        .validator_stake_lamports()
+    );
+    assert_eq!(v1.transient_stake_lamports, 0);
+}
+
+proptest! {
+    #[test]
+    fn mock_stake_pool_args_consistent(args in mock_stake_pool_args(8)) {
+        assert_eq!(STAKE_POOL_ACCOUNT_LEN, STAKE_POOL_SIZE);
+        let stake_pool = mock_stake_pool(&args);
+        let mut data = vec![0u8; STAKE_POOL_SIZE];
+        stake_pool.serialize(&mut data.as_mut_slice()).unwrap();
+        prop_assert_eq!(deserialize_stake_pool_checked(&data).unwrap(), stake_pool.clone());
+
+        let validator_list = mock_validator_list(&args);
+        let mut data = vec![0u8; validator_list_account_len(args.validators.len().max(1))];
+        validator_list.serialize(&mut data.as_mut_slice()).unwrap();
+        prop_assert_eq!(deserialize_validator_list_checked(&data).unwrap(), validator_list.clone());
+
+        let validator_lamports: u64 = validator_list
+            .validators
+            .iter()
+            .map(|v| v.active_stake_lamports + v.transient_stake_lamports)
+            .sum();
+        prop_assert_eq!(stake_pool.total_lamports, validator_lamports + args.reserve_lamports);
+    }
+}
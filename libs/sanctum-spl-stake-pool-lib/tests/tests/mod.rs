@@ -0,0 +1,6 @@
+mod account_resolvers;
+mod common;
+mod mock_stake_pool;
+mod quote;
+mod rebalance;
+mod sim;
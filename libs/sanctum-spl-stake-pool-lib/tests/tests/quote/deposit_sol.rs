@@ -0,0 +1,139 @@
+use sanctum_solana_test_utils::{
+    stake_pool::{
+        mock_stake_pool, mock_validator_list, MockStakePoolArgs, MockStakePoolKeys,
+        StakePoolProgramTest,
+    },
+    token::{tokenkeg::TokenkegProgramTest, MockTokenAccountArgs},
+    ExtendedProgramTest,
+};
+use sanctum_spl_stake_pool_lib::QuoteDepositSol;
+use solana_program::{native_token::LAMPORTS_PER_SOL, pubkey::Pubkey};
+use solana_sdk::{signer::Signer, transaction::Transaction};
+use spl_stake_pool_interface::Fee;
+
+use crate::tests::common::{stake_pool_program_test, token_balance};
+
+const RESERVE_LAMPORTS: u64 = 10 * LAMPORTS_PER_SOL;
+
+async fn assert_quote_matches_onchain(
+    sol_deposit_fee: Fee,
+    sol_referral_fee: u8,
+    total_lamports: u64,
+    pool_token_supply: u64,
+    deposit_lamports: u64,
+) {
+    let keys = MockStakePoolKeys::new_unique();
+    let args = MockStakePoolArgs {
+        keys,
+        reserve_lamports: RESERVE_LAMPORTS,
+        pool_token_supply,
+        last_update_epoch: 0,
+        validators: vec![],
+    };
+    let mut stake_pool = mock_stake_pool(&args);
+    stake_pool.total_lamports = total_lamports;
+    stake_pool.last_epoch_total_lamports = total_lamports;
+    stake_pool.sol_deposit_fee = sol_deposit_fee;
+    stake_pool.sol_referral_fee = sol_referral_fee;
+
+    let quote = stake_pool.quote_deposit_sol(deposit_lamports).unwrap();
+
+    let user_pool_tokens = Pubkey::new_unique();
+    let referrer_pool_tokens = Pubkey::new_unique();
+    let pt = [user_pool_tokens, referrer_pool_tokens].into_iter().fold(
+        stake_pool_program_test().add_stake_pool_accounts(
+            &keys,
+            &stake_pool,
+            &mock_validator_list(&args),
+            RESERVE_LAMPORTS,
+        ),
+        |pt, addr| {
+            pt.add_tokenkeg_account_from_args(
+                addr,
+                MockTokenAccountArgs {
+                    mint: keys.pool_mint,
+                    authority: Pubkey::new_unique(),
+                    amount: 0,
+                },
+            )
+        },
+    );
+    let user = solana_sdk::signature::Keypair::new();
+    let pt = pt.add_system_account(user.pubkey(), deposit_lamports + LAMPORTS_PER_SOL);
+    let (mut banks_client, payer, last_blockhash) = pt.start().await;
+
+    let ix = spl_stake_pool::instruction::deposit_sol(
+        &spl_stake_pool::ID,
+        &keys.stake_pool,
+        &keys.withdraw_authority,
+        &keys.reserve_stake,
+        &user.pubkey(),
+        &user_pool_tokens,
+        &keys.manager_fee_account,
+        &referrer_pool_tokens,
+        &keys.pool_mint,
+        &spl_token::ID,
+        deposit_lamports,
+    );
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &user], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    assert_eq!(
+        token_balance(&mut banks_client, user_pool_tokens).await,
+        quote.user
+    );
+    assert_eq!(
+        token_balance(&mut banks_client, referrer_pool_tokens).await,
+        quote.referrer
+    );
+    assert_eq!(
+        token_balance(&mut banks_client, keys.manager_fee_account).await,
+        quote.manager
+    );
+}
+
+#[tokio::test]
+async fn deposit_sol_no_fees_matches_onchain() {
+    assert_quote_matches_onchain(
+        Fee {
+            denominator: 1,
+            numerator: 0,
+        },
+        0,
+        RESERVE_LAMPORTS,
+        RESERVE_LAMPORTS,
+        LAMPORTS_PER_SOL,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn deposit_sol_with_fees_and_referral_matches_onchain() {
+    assert_quote_matches_onchain(
+        Fee {
+            denominator: 1_000,
+            numerator: 3,
+        },
+        50,
+        RESERVE_LAMPORTS + 123_456_789,
+        RESERVE_LAMPORTS - 987_654,
+        1_234_567_891,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn deposit_sol_full_referral_matches_onchain() {
+    assert_quote_matches_onchain(
+        Fee {
+            denominator: 10_000,
+            numerator: 7,
+        },
+        100,
+        RESERVE_LAMPORTS + 1,
+        RESERVE_LAMPORTS - 3,
+        999_999_999,
+    )
+    .await;
+}
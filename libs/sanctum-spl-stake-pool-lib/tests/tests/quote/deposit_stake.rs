@@ -0,0 +1,216 @@
+use borsh::BorshDeserialize;
+use sanctum_solana_test_utils::{
+    stake::{LiveStakeAccountParams, StakeProgramTest},
+    stake_pool::{
+        mock_stake_pool, mock_validator_list, MockStakePoolArgs, MockStakePoolKeys,
+        MockValidatorArgs, StakePoolProgramTest,
+    },
+    token::{tokenkeg::TokenkegProgramTest, MockTokenAccountArgs},
+};
+use sanctum_spl_stake_pool_lib::{
+    account_resolvers::{DepositStake, UpdateStakePoolBalance, UpdateValidatorListBalance},
+    deserialize_stake_pool_checked, QuoteDepositStake, StakeAccountDataForQuoting,
+};
+use solana_program::{
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    stake::state::{Authorized, StakeStateV2},
+};
+use solana_program_test::BanksClient;
+use solana_readonly_account::keyed::Keyed;
+use solana_sdk::{account::Account, signature::Keypair, signer::Signer, transaction::Transaction};
+use spl_stake_pool_interface::{
+    update_stake_pool_balance_ix_with_program_id, Fee, UpdateValidatorListBalanceIxArgs,
+};
+
+use crate::tests::common::{stake_pool_program_test, token_balance};
+
+const WARP_TO_EPOCH: u64 = 2;
+
+async fn fetch_keyed(banks_client: &mut BanksClient, pubkey: Pubkey) -> Keyed<Account> {
+    Keyed {
+        pubkey,
+        account: banks_client.get_account(pubkey).await.unwrap().unwrap(),
+    }
+}
+
+async fn assert_quote_matches_onchain(
+    stake_deposit_fee: Fee,
+    sol_deposit_fee: Fee,
+    stake_referral_fee: u8,
+    pool_token_supply: u64,
+    deposit_staked_lamports: u64,
+) {
+    let keys = MockStakePoolKeys::new_unique();
+    let validator = MockValidatorArgs {
+        vote: Pubkey::new_unique(),
+        validator_seed_suffix: 0,
+        transient_seed_suffix: 0,
+        staked_lamports: 10 * LAMPORTS_PER_SOL,
+        transient_staked_lamports: 0,
+    };
+    let args = MockStakePoolArgs {
+        keys,
+        reserve_lamports: 10 * LAMPORTS_PER_SOL,
+        pool_token_supply,
+        last_update_epoch: 0,
+        validators: vec![validator],
+    };
+    let mut stake_pool = mock_stake_pool(&args);
+    stake_pool.stake_deposit_fee = stake_deposit_fee;
+    stake_pool.sol_deposit_fee = sol_deposit_fee;
+    stake_pool.stake_referral_fee = stake_referral_fee;
+
+    let user = Keypair::new();
+    let stake_depositing = Pubkey::new_unique();
+    let user_pool_tokens = Pubkey::new_unique();
+    let referrer_pool_tokens = Pubkey::new_unique();
+    // the later stake pool account overrides the unmodified one added by add_stake_pool_from_args
+    let pt = [user_pool_tokens, referrer_pool_tokens]
+        .into_iter()
+        .fold(
+            stake_pool_program_test()
+                .add_stake_pool_from_args(&args)
+                .add_stake_pool_account(keys.stake_pool, &stake_pool, keys.program_id),
+            |pt, addr| {
+                pt.add_tokenkeg_account_from_args(
+                    addr,
+                    MockTokenAccountArgs {
+                        mint: keys.pool_mint,
+                        authority: Pubkey::new_unique(),
+                        amount: 0,
+                    },
+                )
+            },
+        )
+        .add_live_stake_account(
+            stake_depositing,
+            LiveStakeAccountParams {
+                staked_lamports: deposit_staked_lamports,
+                voter: validator.vote,
+                authorized: Authorized::auto(&user.pubkey()),
+                activation_epoch: 0,
+                deactivation_epoch: u64::MAX,
+                lockup: Default::default(),
+                credits_observed: 0,
+            },
+        );
+    let mut ctx = pt.start_with_context().await;
+    ctx.warp_to_epoch(WARP_TO_EPOCH).unwrap();
+    // stake accounts cannot be written to during the epoch rewards distribution period
+    ctx.warp_forward_force_reward_interval_end().unwrap();
+
+    let stake_pool_acc = fetch_keyed(&mut ctx.banks_client, keys.stake_pool).await;
+    let update_vlb_ix = UpdateValidatorListBalance {
+        stake_pool: &stake_pool_acc,
+    }
+    .full_ix_from_validator_slice(
+        keys.program_id,
+        &mock_validator_list(&args).validators,
+        UpdateValidatorListBalanceIxArgs {
+            start_index: 0,
+            no_merge: false,
+        },
+    )
+    .unwrap();
+    let update_spb_ix = update_stake_pool_balance_ix_with_program_id(
+        keys.program_id,
+        UpdateStakePoolBalance {
+            stake_pool: &stake_pool_acc,
+        }
+        .resolve_for_prog(&keys.program_id)
+        .unwrap(),
+    )
+    .unwrap();
+    let mut tx =
+        Transaction::new_with_payer(&[update_vlb_ix, update_spb_ix], Some(&ctx.payer.pubkey()));
+    let blockhash = ctx.get_new_latest_blockhash().await.unwrap();
+    tx.sign(&[&ctx.payer], blockhash);
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let stake_pool = deserialize_stake_pool_checked(
+        &fetch_keyed(&mut ctx.banks_client, keys.stake_pool)
+            .await
+            .account
+            .data,
+    )
+    .unwrap();
+    let stake_depositing_acc = fetch_keyed(&mut ctx.banks_client, stake_depositing).await;
+    let stake_account_data =
+        StakeAccountDataForQuoting::from_stake_account(&stake_depositing_acc.account).unwrap();
+    let quote = stake_pool.quote_deposit_stake(&stake_account_data).unwrap();
+
+    let stake_state =
+        StakeStateV2::deserialize(&mut stake_depositing_acc.account.data.as_slice()).unwrap();
+    let ixs = DepositStake {
+        pool: Keyed {
+            pubkey: keys.stake_pool,
+            account: &stake_pool,
+        },
+        stake_depositing: Keyed {
+            pubkey: stake_depositing,
+            account: &stake_state,
+        },
+        mint_to: user_pool_tokens,
+        referral_fee_dest: referrer_pool_tokens,
+    }
+    .full_ix_seq(
+        &keys.program_id,
+        validator.vote,
+        validator.validator_seed_suffix,
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&ixs, Some(&ctx.payer.pubkey()));
+    let blockhash = ctx.get_new_latest_blockhash().await.unwrap();
+    tx.sign(&[&ctx.payer, &user], blockhash);
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    assert_eq!(
+        token_balance(&mut ctx.banks_client, user_pool_tokens).await,
+        quote.user
+    );
+    assert_eq!(
+        token_balance(&mut ctx.banks_client, referrer_pool_tokens).await,
+        quote.referrer
+    );
+    assert_eq!(
+        token_balance(&mut ctx.banks_client, keys.manager_fee_account).await,
+        quote.manager
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn deposit_stake_with_fees_and_referral_matches_onchain() {
+    assert_quote_matches_onchain(
+        Fee {
+            denominator: 1_000,
+            numerator: 3,
+        },
+        Fee {
+            denominator: 10_000,
+            numerator: 7,
+        },
+        50,
+        20 * LAMPORTS_PER_SOL - 987_654,
+        1_234_567_891,
+    )
+    .await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn deposit_stake_inexact_fee_matches_onchain() {
+    assert_quote_matches_onchain(
+        Fee {
+            denominator: 3,
+            numerator: 1,
+        },
+        Fee {
+            denominator: 1,
+            numerator: 0,
+        },
+        0,
+        20 * LAMPORTS_PER_SOL + 1,
+        999_999_998,
+    )
+    .await;
+}
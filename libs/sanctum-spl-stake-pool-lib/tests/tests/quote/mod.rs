@@ -0,0 +1,4 @@
+mod deposit_sol;
+mod deposit_stake;
+mod withdraw_sol;
+mod withdraw_stake;
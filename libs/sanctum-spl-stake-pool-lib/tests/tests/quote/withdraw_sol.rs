@@ -0,0 +1,117 @@
+use sanctum_solana_test_utils::{
+    stake_pool::{
+        mock_stake_pool, mock_validator_list, MockStakePoolArgs, MockStakePoolKeys,
+        StakePoolProgramTest,
+    },
+    token::{tokenkeg::TokenkegProgramTest, MockTokenAccountArgs},
+    ExtendedProgramTest,
+};
+use sanctum_spl_stake_pool_lib::QuoteWithdrawSol;
+use solana_program::{native_token::LAMPORTS_PER_SOL, pubkey::Pubkey};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+use spl_stake_pool_interface::Fee;
+
+use crate::tests::common::{lamports_balance, stake_pool_program_test, token_balance};
+
+const RESERVE_LAMPORTS: u64 = 10 * LAMPORTS_PER_SOL;
+
+async fn assert_quote_matches_onchain(
+    sol_withdrawal_fee: Fee,
+    total_lamports: u64,
+    pool_token_supply: u64,
+    pool_tokens: u64,
+) {
+    let keys = MockStakePoolKeys::new_unique();
+    let args = MockStakePoolArgs {
+        keys,
+        reserve_lamports: RESERVE_LAMPORTS,
+        pool_token_supply,
+        last_update_epoch: 0,
+        validators: vec![],
+    };
+    let mut stake_pool = mock_stake_pool(&args);
+    stake_pool.total_lamports = total_lamports;
+    stake_pool.last_epoch_total_lamports = total_lamports;
+    stake_pool.sol_withdrawal_fee = sol_withdrawal_fee;
+
+    let quote = stake_pool.quote_withdraw_sol(pool_tokens).unwrap();
+
+    let user = Keypair::new();
+    let user_pool_tokens = Pubkey::new_unique();
+    let lamports_to = Pubkey::new_unique();
+    let pt = stake_pool_program_test()
+        .add_stake_pool_accounts(
+            &keys,
+            &stake_pool,
+            &mock_validator_list(&args),
+            RESERVE_LAMPORTS,
+        )
+        .add_tokenkeg_account_from_args(
+            user_pool_tokens,
+            MockTokenAccountArgs {
+                mint: keys.pool_mint,
+                authority: user.pubkey(),
+                amount: pool_tokens,
+            },
+        )
+        .add_system_account(lamports_to, LAMPORTS_PER_SOL);
+    let (mut banks_client, payer, last_blockhash) = pt.start().await;
+
+    let ix = spl_stake_pool::instruction::withdraw_sol(
+        &spl_stake_pool::ID,
+        &keys.stake_pool,
+        &keys.withdraw_authority,
+        &user.pubkey(),
+        &user_pool_tokens,
+        &keys.reserve_stake,
+        &lamports_to,
+        &keys.manager_fee_account,
+        &keys.pool_mint,
+        &spl_token::ID,
+        pool_tokens,
+    );
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &user], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    assert_eq!(
+        pool_tokens - token_balance(&mut banks_client, user_pool_tokens).await,
+        quote.tokens_in
+    );
+    assert_eq!(
+        lamports_balance(&mut banks_client, lamports_to).await - LAMPORTS_PER_SOL,
+        quote.lamports_out
+    );
+    assert_eq!(
+        token_balance(&mut banks_client, keys.manager_fee_account).await,
+        quote.fee_amount
+    );
+}
+
+#[tokio::test]
+async fn withdraw_sol_no_fees_matches_onchain() {
+    assert_quote_matches_onchain(
+        Fee {
+            denominator: 1,
+            numerator: 0,
+        },
+        RESERVE_LAMPORTS,
+        RESERVE_LAMPORTS,
+        LAMPORTS_PER_SOL,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn withdraw_sol_with_fees_matches_onchain() {
+    assert_quote_matches_onchain(
+        Fee {
+            denominator: 1_000,
+            numerator: 3,
+        },
+        RESERVE_LAMPORTS - 1_234_567,
+        RESERVE_LAMPORTS - 7_654_321,
+        1_234_567_891,
+    )
+    .await;
+}
@@ -0,0 +1,127 @@
+use sanctum_solana_test_utils::{
+    est_rent_exempt_lamports,
+    stake::{StakeProgramTest, StakeStateAndLamports},
+    stake_pool::{
+        mock_stake_pool, mock_validator_list, MockStakePoolArgs, MockStakePoolKeys,
+        StakePoolProgramTest,
+    },
+    token::{tokenkeg::TokenkegProgramTest, MockTokenAccountArgs},
+};
+use sanctum_spl_stake_pool_lib::QuoteWithdrawStake;
+use solana_program::{native_token::LAMPORTS_PER_SOL, pubkey::Pubkey, stake::state::StakeStateV2};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+use spl_stake_pool_interface::Fee;
+
+use crate::tests::common::{lamports_balance, stake_pool_program_test, token_balance};
+
+const RESERVE_LAMPORTS: u64 = 10 * LAMPORTS_PER_SOL;
+
+async fn assert_quote_matches_onchain(
+    stake_withdrawal_fee: Fee,
+    total_lamports: u64,
+    pool_token_supply: u64,
+    pool_tokens: u64,
+) {
+    let keys = MockStakePoolKeys::new_unique();
+    let args = MockStakePoolArgs {
+        keys,
+        reserve_lamports: RESERVE_LAMPORTS,
+        pool_token_supply,
+        last_update_epoch: 0,
+        validators: vec![],
+    };
+    let mut stake_pool = mock_stake_pool(&args);
+    stake_pool.total_lamports = total_lamports;
+    stake_pool.last_epoch_total_lamports = total_lamports;
+    stake_pool.stake_withdrawal_fee = stake_withdrawal_fee;
+
+    let quote = stake_pool.quote_withdraw_stake(pool_tokens).unwrap();
+
+    let user = Keypair::new();
+    let user_pool_tokens = Pubkey::new_unique();
+    let split_to = Pubkey::new_unique();
+    let split_to_starting_lamports = est_rent_exempt_lamports(StakeStateV2::size_of());
+    let pt = stake_pool_program_test()
+        .add_stake_pool_accounts(
+            &keys,
+            &stake_pool,
+            &mock_validator_list(&args),
+            RESERVE_LAMPORTS,
+        )
+        .add_tokenkeg_account_from_args(
+            user_pool_tokens,
+            MockTokenAccountArgs {
+                mint: keys.pool_mint,
+                authority: user.pubkey(),
+                amount: pool_tokens,
+            },
+        )
+        .add_stake_account(
+            split_to,
+            StakeStateAndLamports {
+                stake_state: StakeStateV2::Uninitialized,
+                total_lamports: split_to_starting_lamports,
+            },
+        );
+    let (mut banks_client, payer, last_blockhash) = pt.start().await;
+
+    let ix = spl_stake_pool::instruction::withdraw_stake(
+        &spl_stake_pool::ID,
+        &keys.stake_pool,
+        &keys.validator_list,
+        &keys.withdraw_authority,
+        &keys.reserve_stake,
+        &split_to,
+        &user.pubkey(),
+        &user.pubkey(),
+        &user_pool_tokens,
+        &keys.manager_fee_account,
+        &keys.pool_mint,
+        &spl_token::ID,
+        pool_tokens,
+    );
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &user], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    assert_eq!(
+        pool_tokens - token_balance(&mut banks_client, user_pool_tokens).await,
+        quote.tokens_in
+    );
+    assert_eq!(
+        lamports_balance(&mut banks_client, split_to).await - split_to_starting_lamports,
+        quote.lamports_out
+    );
+    assert_eq!(
+        token_balance(&mut banks_client, keys.manager_fee_account).await,
+        quote.fee_amount
+    );
+}
+
+#[tokio::test]
+async fn withdraw_stake_no_fees_matches_onchain() {
+    assert_quote_matches_onchain(
+        Fee {
+            denominator: 1,
+            numerator: 0,
+        },
+        RESERVE_LAMPORTS,
+        RESERVE_LAMPORTS,
+        LAMPORTS_PER_SOL,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn withdraw_stake_with_fees_matches_onchain() {
+    assert_quote_matches_onchain(
+        Fee {
+            denominator: 10_000,
+            numerator: 15,
+        },
+        RESERVE_LAMPORTS - 1_234_567,
+        RESERVE_LAMPORTS - 7_654_321,
+        2_345_678_901,
+    )
+    .await;
+}
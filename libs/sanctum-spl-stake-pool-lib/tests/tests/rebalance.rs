@@ -0,0 +1,276 @@
+use std::collections::HashMap;
+
+use sanctum_solana_test_utils::{
+    est_rent_exempt_lamports,
+    stake::{LiveStakeAccountParams, SingleAuthorityAuthorized, StakeProgramTest},
+    stake_pool::{
+        mock_stake_pool, stake_pool_account, MockStakePoolArgs, MockStakePoolKeys,
+        StakePoolProgramTest,
+    },
+    ExtendedProgramTest,
+};
+use sanctum_spl_stake_pool_lib::{
+    account_resolvers::UpdateValidatorListBalance, deserialize_validator_list_checked,
+    min_delegation, min_reserve_lamports, RebalanceDirection, RebalancePlanner, RebalanceStep,
+};
+use solana_program::{
+    clock::Clock,
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    stake::state::StakeStateV2,
+    vote::{
+        self,
+        state::{VoteInit, VoteState, VoteStateVersions},
+    },
+};
+use solana_readonly_account::keyed::Keyed;
+use solana_sdk::{
+    account::Account, feature_set, signature::Keypair, signer::Signer, transaction::Transaction,
+};
+use spl_stake_pool_interface::{
+    AccountType, StakeStatus, ValidatorList, ValidatorListHeader, ValidatorStakeInfo,
+};
+
+use crate::tests::common::{lamports_balance, stake_pool_program_test};
+
+const RESERVE_LAMPORTS: u64 = 30 * LAMPORTS_PER_SOL;
+
+const VALIDATOR_STAKE_LAMPORTS: u64 = 10 * LAMPORTS_PER_SOL;
+
+const TRANSIENT_STAKE_LAMPORTS: u64 = LAMPORTS_PER_SOL;
+
+const WARP_TO_EPOCH: u64 = 3;
+
+fn vote_account(node: Pubkey) -> Account {
+    let mut data = vec![0u8; VoteState::size_of()];
+    VoteState::serialize(
+        &VoteStateVersions::new_current(VoteState::new(
+            &VoteInit {
+                node_pubkey: node,
+                authorized_voter: node,
+                authorized_withdrawer: node,
+                commission: 0,
+            },
+            &Clock::default(),
+        )),
+        &mut data,
+    )
+    .unwrap();
+    Account {
+        lamports: est_rent_exempt_lamports(data.len()),
+        data,
+        owner: vote::program::ID,
+        executable: false,
+        rent_epoch: u64::MAX,
+    }
+}
+
+fn live_stake_params(
+    keys: &MockStakePoolKeys,
+    voter: Pubkey,
+    staked_lamports: u64,
+    activation_epoch: u64,
+) -> LiveStakeAccountParams {
+    LiveStakeAccountParams {
+        staked_lamports,
+        voter,
+        authorized: SingleAuthorityAuthorized(keys.withdraw_authority).into(),
+        activation_epoch,
+        deactivation_epoch: u64::MAX,
+        lockup: Default::default(),
+        credits_observed: 0,
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn rebalance_plan_executes_onchain() {
+    let keys = MockStakePoolKeys::new_unique();
+    let staker = Keypair::new();
+    let stake_rent = est_rent_exempt_lamports(StakeStateV2::size_of());
+    // reserve stake account balance, including its rent-exempt reserve
+    let reserve_lamports = RESERVE_LAMPORTS + stake_rent;
+    let vsa_lamports = VALIDATOR_STAKE_LAMPORTS + stake_rent;
+    let tsa_lamports = TRANSIENT_STAKE_LAMPORTS + stake_rent;
+
+    // - weight 1, no transient stake: increased with a fresh transient stake account
+    // - weight 1, transient stake activated this epoch: increased into the existing transient stake account
+    // - weight 0, no transient stake: decreased down to the minimum
+    // - weight 0, transient stake activated in a previous epoch: left untouched
+    let transient_stake_lamports = [0, tsa_lamports, 0, tsa_lamports];
+    let validators: Vec<ValidatorStakeInfo> = transient_stake_lamports
+        .iter()
+        .map(|transient_stake_lamports| ValidatorStakeInfo {
+            active_stake_lamports: vsa_lamports,
+            transient_stake_lamports: *transient_stake_lamports,
+            last_update_epoch: WARP_TO_EPOCH,
+            transient_seed_suffix: 0,
+            unused: 0,
+            validator_seed_suffix: 0,
+            status: StakeStatus::Active,
+            vote_account_address: Pubkey::new_unique(),
+        })
+        .collect();
+    let votes: Vec<Pubkey> = validators.iter().map(|v| v.vote_account_address).collect();
+    let total_lamports = RESERVE_LAMPORTS
+        + validators
+            .iter()
+            .map(|v| v.active_stake_lamports + v.transient_stake_lamports)
+            .sum::<u64>();
+    let mut stake_pool = mock_stake_pool(&MockStakePoolArgs {
+        keys,
+        reserve_lamports: RESERVE_LAMPORTS,
+        pool_token_supply: total_lamports,
+        last_update_epoch: WARP_TO_EPOCH,
+        validators: vec![],
+    });
+    stake_pool.total_lamports = total_lamports;
+    stake_pool.last_epoch_total_lamports = total_lamports;
+    stake_pool.staker = staker.pubkey();
+    let stake_pool_acc = Keyed {
+        pubkey: keys.stake_pool,
+        account: stake_pool_account(&stake_pool, keys.program_id),
+    };
+
+    let update_vlb = UpdateValidatorListBalance {
+        stake_pool: &stake_pool_acc,
+    };
+    let pairs: Vec<(Pubkey, Pubkey)> = validators
+        .iter()
+        .map(|v| update_vlb.validator_pair_for_prog(&spl_stake_pool::ID, v))
+        .collect();
+
+    let validator_list = ValidatorList {
+        header: ValidatorListHeader {
+            account_type: AccountType::ValidatorList,
+            max_validators: validators.len().try_into().unwrap(),
+        },
+        validators: validators.clone(),
+    };
+
+    let mut pt = stake_pool_program_test().add_stake_pool_accounts(
+        &keys,
+        &stake_pool,
+        &validator_list,
+        RESERVE_LAMPORTS,
+    );
+    for (vote, (vsa, _tsa)) in votes.iter().zip(pairs.iter()) {
+        pt = pt
+            .add_keyed_account(Keyed {
+                pubkey: *vote,
+                account: vote_account(Pubkey::new_unique()),
+            })
+            .add_live_stake_account(
+                *vsa,
+                live_stake_params(&keys, *vote, VALIDATOR_STAKE_LAMPORTS, 0),
+            );
+    }
+    pt = pt
+        .add_live_stake_account(
+            pairs[1].1,
+            live_stake_params(&keys, votes[1], TRANSIENT_STAKE_LAMPORTS, WARP_TO_EPOCH),
+        )
+        .add_live_stake_account(
+            pairs[3].1,
+            live_stake_params(&keys, votes[3], TRANSIENT_STAKE_LAMPORTS, WARP_TO_EPOCH - 1),
+        );
+
+    // crate consts follow mainnet, where the stake program's minimum delegation has not been raised
+    pt.deactivate_feature(feature_set::stake_raise_minimum_delegation_to_1_sol::id());
+
+    let mut ctx = pt.start_with_context().await;
+    ctx.warp_to_epoch(WARP_TO_EPOCH).unwrap();
+    // stake accounts cannot be written to during the epoch rewards distribution period
+    ctx.warp_forward_force_reward_interval_end().unwrap();
+
+    let mut transient_stake_accounts = vec![];
+    for i in [1, 3] {
+        let pubkey = pairs[i].1;
+        let account = ctx.banks_client.get_account(pubkey).await.unwrap().unwrap();
+        transient_stake_accounts.push(Keyed { pubkey, account });
+    }
+    let rent = ctx.banks_client.get_rent().await.unwrap();
+    let target_weights = HashMap::from([(votes[0], 1), (votes[1], 1)]);
+
+    let planner = RebalancePlanner {
+        stake_pool: &stake_pool_acc,
+        validator_list: &deserialize_validator_list_checked(
+            &ctx.banks_client
+                .get_account(keys.validator_list)
+                .await
+                .unwrap()
+                .unwrap()
+                .data,
+        )
+        .unwrap(),
+        reserve_lamports,
+        target_weights: &target_weights,
+        transient_stake_accounts: &transient_stake_accounts,
+        epoch: WARP_TO_EPOCH,
+        rent: &rent,
+        ephemeral_seed_start: 0,
+    };
+    let steps = planner.plan_for_prog(&spl_stake_pool::ID).unwrap();
+
+    let [decrease, increase_fresh, increase_existing] = steps[..] else {
+        panic!("unexpected plan {steps:#?}")
+    };
+    assert_eq!(
+        decrease,
+        RebalanceStep {
+            direction: RebalanceDirection::Decrease,
+            vote_account: votes[2],
+            validator_seed: None,
+            lamports: VALIDATOR_STAKE_LAMPORTS - min_delegation(),
+            transient_stake_seed: 1,
+            ephemeral_stake_seed: 0,
+        }
+    );
+    // larger deficit goes first
+    assert_eq!(increase_fresh.direction, RebalanceDirection::Increase);
+    assert_eq!(increase_fresh.vote_account, votes[0]);
+    assert_eq!(increase_fresh.transient_stake_seed, 1);
+    assert_eq!(increase_fresh.ephemeral_stake_seed, 1);
+    assert_eq!(increase_existing.direction, RebalanceDirection::Increase);
+    assert_eq!(increase_existing.vote_account, votes[1]);
+    assert_eq!(increase_existing.transient_stake_seed, 0);
+    assert_eq!(increase_existing.ephemeral_stake_seed, 2);
+    // reserve is drained down to its minimum
+    assert_eq!(
+        increase_fresh.lamports + increase_existing.lamports + 3 * stake_rent,
+        reserve_lamports - min_reserve_lamports(&rent)
+    );
+
+    for ix in planner.ixs_for_prog(&spl_stake_pool::ID).unwrap() {
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&ctx.payer.pubkey()));
+        let blockhash = ctx.get_new_latest_blockhash().await.unwrap();
+        tx.sign(&[&ctx.payer, &staker], blockhash);
+        ctx.banks_client.process_transaction(tx).await.unwrap();
+    }
+
+    let onchain_validator_list = deserialize_validator_list_checked(
+        &ctx.banks_client
+            .get_account(keys.validator_list)
+            .await
+            .unwrap()
+            .unwrap()
+            .data,
+    )
+    .unwrap();
+    let [v0, v1, v2, v3] = &onchain_validator_list.validators[..] else {
+        unreachable!()
+    };
+    assert_eq!(
+        v0.transient_stake_lamports,
+        increase_fresh.lamports + stake_rent
+    );
+    assert_eq!(
+        v1.transient_stake_lamports,
+        tsa_lamports + increase_existing.lamports + stake_rent
+    );
+    assert_eq!(v2.active_stake_lamports, vsa_lamports - decrease.lamports);
+    assert_eq!(*v3, validators[3]);
+    assert_eq!(
+        lamports_balance(&mut ctx.banks_client, keys.reserve_stake).await,
+        min_reserve_lamports(&rent)
+    );
+}
@@ -0,0 +1,297 @@
+use sanctum_solana_test_utils::{
+    est_rent_exempt_lamports,
+    stake::{LiveStakeAccountParams, SingleAuthorityAuthorized, StakeProgramTest},
+    stake_pool::{mock_stake_pool, MockStakePoolArgs, MockStakePoolKeys, StakePoolProgramTest},
+};
+use sanctum_spl_stake_pool_lib::{
+    account_resolvers::{UpdateStakePoolBalance, UpdateValidatorListBalance},
+    deserialize_stake_pool_checked, deserialize_validator_list_checked, EpochCrankSim,
+};
+use solana_program::{
+    clock::Clock, native_token::LAMPORTS_PER_SOL, pubkey::Pubkey, stake::state::StakeStateV2,
+    stake_history::StakeHistory,
+};
+use solana_program_test::BanksClient;
+use solana_readonly_account::keyed::Keyed;
+use solana_sdk::{account::Account, signer::Signer, transaction::Transaction};
+use spl_stake_pool_interface::{
+    update_stake_pool_balance_ix_with_program_id, AccountType, Fee, FutureEpochFee, StakeStatus,
+    UpdateValidatorListBalanceIxArgs, ValidatorList, ValidatorListHeader, ValidatorStakeInfo,
+};
+
+use crate::tests::common::{stake_pool_program_test, token_balance};
+
+const RESERVE_LAMPORTS: u64 = 10 * LAMPORTS_PER_SOL;
+
+const VALIDATOR_STAKE_LAMPORTS: u64 = 10 * LAMPORTS_PER_SOL;
+
+const TRANSIENT_STAKE_LAMPORTS: u64 = 5 * LAMPORTS_PER_SOL;
+
+const WARP_TO_EPOCH: u64 = 3;
+
+fn validator(vote_account_address: Pubkey, status: StakeStatus) -> ValidatorStakeInfo {
+    ValidatorStakeInfo {
+        active_stake_lamports: 0,
+        transient_stake_lamports: 0,
+        last_update_epoch: 0,
+        transient_seed_suffix: 0,
+        unused: 0,
+        validator_seed_suffix: 0,
+        status,
+        vote_account_address,
+    }
+}
+
+fn live_stake_params(
+    keys: &MockStakePoolKeys,
+    voter: Pubkey,
+    staked_lamports: u64,
+    deactivation_epoch: u64,
+) -> LiveStakeAccountParams {
+    LiveStakeAccountParams {
+        staked_lamports,
+        voter,
+        authorized: SingleAuthorityAuthorized(keys.withdraw_authority).into(),
+        activation_epoch: 0,
+        deactivation_epoch,
+        lockup: Default::default(),
+        credits_observed: 0,
+    }
+}
+
+async fn fetch_keyed(banks_client: &mut BanksClient, pubkey: Pubkey) -> Option<Keyed<Account>> {
+    banks_client
+        .get_account(pubkey)
+        .await
+        .unwrap()
+        .map(|account| Keyed { pubkey, account })
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn epoch_crank_sim_matches_onchain() {
+    let keys = MockStakePoolKeys::new_unique();
+    let stake_rent = est_rent_exempt_lamports(StakeStateV2::size_of());
+    let mut stake_pool = mock_stake_pool(&MockStakePoolArgs {
+        keys,
+        reserve_lamports: RESERVE_LAMPORTS,
+        pool_token_supply: 18 * LAMPORTS_PER_SOL,
+        last_update_epoch: 0,
+        validators: vec![],
+    });
+    stake_pool.total_lamports = 20 * LAMPORTS_PER_SOL;
+    stake_pool.last_epoch_total_lamports = 20 * LAMPORTS_PER_SOL;
+    stake_pool.epoch_fee = Fee {
+        denominator: 100,
+        numerator: 7,
+    };
+    stake_pool.next_epoch_fee = FutureEpochFee::Two {
+        fee: Fee {
+            denominator: 100,
+            numerator: 5,
+        },
+    };
+    stake_pool.next_sol_withdrawal_fee = FutureEpochFee::One {
+        fee: Fee {
+            denominator: 1000,
+            numerator: 3,
+        },
+    };
+
+    // - active validator with an activated transient stake to merge and extra lamports to withdraw
+    // - active validator with a deactivated transient stake to merge into the reserve
+    // - removed validator with a deactivated validator stake to merge into the reserve
+    // - removed validator with an initialized transient stake to merge into the reserve
+    let validators = vec![
+        validator(Pubkey::new_unique(), StakeStatus::Active),
+        validator(Pubkey::new_unique(), StakeStatus::Active),
+        validator(Pubkey::new_unique(), StakeStatus::DeactivatingValidator),
+        validator(Pubkey::new_unique(), StakeStatus::DeactivatingTransient),
+    ];
+    let update_vlb = UpdateValidatorListBalance {
+        stake_pool: Keyed {
+            pubkey: keys.stake_pool,
+            account: Account {
+                data: borsh::to_vec(&stake_pool).unwrap(),
+                ..Default::default()
+            },
+        },
+    };
+    let pairs: Vec<(Pubkey, Pubkey)> = validators
+        .iter()
+        .map(|v| update_vlb.validator_pair_for_prog(&spl_stake_pool::ID, v))
+        .collect();
+
+    let validator_list = ValidatorList {
+        header: ValidatorListHeader {
+            account_type: AccountType::ValidatorList,
+            max_validators: validators.len().try_into().unwrap(),
+        },
+        validators: validators.clone(),
+    };
+
+    let mut pt = stake_pool_program_test().add_stake_pool_accounts(
+        &keys,
+        &stake_pool,
+        &validator_list,
+        RESERVE_LAMPORTS,
+    );
+    let [(vsa_0, tsa_0), (vsa_1, tsa_1), (vsa_2, _), (_, tsa_3)] = pairs[..] else {
+        unreachable!()
+    };
+    let voter_0 = validators[0].vote_account_address;
+    pt = pt
+        .add_live_stake_account(
+            vsa_0,
+            live_stake_params(&keys, voter_0, VALIDATOR_STAKE_LAMPORTS, u64::MAX),
+        )
+        .add_live_stake_account(
+            tsa_0,
+            live_stake_params(&keys, voter_0, TRANSIENT_STAKE_LAMPORTS, u64::MAX),
+        );
+    let voter_1 = validators[1].vote_account_address;
+    pt = pt
+        .add_live_stake_account(
+            vsa_1,
+            live_stake_params(&keys, voter_1, VALIDATOR_STAKE_LAMPORTS, u64::MAX),
+        )
+        .add_live_stake_account(
+            tsa_1,
+            live_stake_params(&keys, voter_1, TRANSIENT_STAKE_LAMPORTS, 0),
+        )
+        .add_live_stake_account(
+            vsa_2,
+            live_stake_params(
+                &keys,
+                validators[2].vote_account_address,
+                VALIDATOR_STAKE_LAMPORTS,
+                0,
+            ),
+        )
+        .add_fresh_inactive_stake_account(
+            tsa_3,
+            TRANSIENT_STAKE_LAMPORTS + stake_rent,
+            SingleAuthorityAuthorized(keys.withdraw_authority).into(),
+        );
+
+    let mut ctx = pt.start_with_context().await;
+    ctx.warp_to_epoch(WARP_TO_EPOCH).unwrap();
+    // stake accounts cannot be written to during the epoch rewards distribution period
+    ctx.warp_forward_force_reward_interval_end().unwrap();
+
+    // simulate rewards accrued on validator 0's stake account
+    let mut vsa_0_account = ctx.banks_client.get_account(vsa_0).await.unwrap().unwrap();
+    vsa_0_account.lamports += LAMPORTS_PER_SOL;
+    ctx.set_account(&vsa_0, &vsa_0_account.into());
+
+    let stake_pool_acc = fetch_keyed(&mut ctx.banks_client, keys.stake_pool)
+        .await
+        .unwrap();
+    let validator_list_acc = fetch_keyed(&mut ctx.banks_client, keys.validator_list)
+        .await
+        .unwrap();
+    let validator_list =
+        deserialize_validator_list_checked(&validator_list_acc.account.data).unwrap();
+    let reserve_acc = fetch_keyed(&mut ctx.banks_client, keys.reserve_stake)
+        .await
+        .unwrap();
+    let mut stake_accounts = vec![];
+    for (vsa, tsa) in pairs.iter() {
+        for pk in [*vsa, *tsa] {
+            if let Some(acc) = fetch_keyed(&mut ctx.banks_client, pk).await {
+                stake_accounts.push(acc);
+            }
+        }
+    }
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let stake_history: StakeHistory = ctx.banks_client.get_sysvar().await.unwrap();
+    assert_eq!(clock.epoch, WARP_TO_EPOCH);
+
+    let sim = EpochCrankSim {
+        stake_pool: &stake_pool_acc,
+        validator_list: &validator_list,
+        reserve_stake: &reserve_acc,
+        stake_accounts: &stake_accounts,
+        clock: &clock,
+        stake_history: &stake_history,
+        // all features, including reduce_stake_warmup_cooldown, are activated at genesis in ProgramTest
+        new_rate_activation_epoch: Some(0),
+    };
+    let update_vlb_res = sim
+        .update_validator_list_balance_for_prog(&spl_stake_pool::ID, false)
+        .unwrap();
+    let res = sim.simulate_for_prog(&spl_stake_pool::ID).unwrap();
+    assert_eq!(update_vlb_res.validator_list, res.validator_list);
+
+    let update_vlb_ix = UpdateValidatorListBalance {
+        stake_pool: &stake_pool_acc,
+    }
+    .full_ix_from_validator_slice(
+        spl_stake_pool::ID,
+        &validator_list.validators,
+        UpdateValidatorListBalanceIxArgs {
+            start_index: 0,
+            no_merge: false,
+        },
+    )
+    .unwrap();
+    let update_spb_ix = update_stake_pool_balance_ix_with_program_id(
+        spl_stake_pool::ID,
+        UpdateStakePoolBalance {
+            stake_pool: &stake_pool_acc,
+        }
+        .resolve_for_prog(&spl_stake_pool::ID)
+        .unwrap(),
+    )
+    .unwrap();
+    let mut tx =
+        Transaction::new_with_payer(&[update_vlb_ix, update_spb_ix], Some(&ctx.payer.pubkey()));
+    let blockhash = ctx.get_new_latest_blockhash().await.unwrap();
+    tx.sign(&[&ctx.payer], blockhash);
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let onchain_stake_pool = deserialize_stake_pool_checked(
+        &ctx.banks_client
+            .get_account(keys.stake_pool)
+            .await
+            .unwrap()
+            .unwrap()
+            .data,
+    )
+    .unwrap();
+    let onchain_validator_list = deserialize_validator_list_checked(
+        &ctx.banks_client
+            .get_account(keys.validator_list)
+            .await
+            .unwrap()
+            .unwrap()
+            .data,
+    )
+    .unwrap();
+    let reserve_lamports = ctx
+        .banks_client
+        .get_balance(keys.reserve_stake)
+        .await
+        .unwrap();
+
+    assert_eq!(res.stake_pool, onchain_stake_pool);
+    assert_eq!(res.validator_list, onchain_validator_list);
+    assert_eq!(update_vlb_res.reserve_lamports, reserve_lamports);
+    assert_eq!(
+        res.epoch_fee,
+        token_balance(&mut ctx.banks_client, keys.manager_fee_account).await
+    );
+    assert!(res.epoch_fee > 0);
+    assert!(res
+        .validator_list
+        .validators
+        .iter()
+        .all(|v| v.transient_stake_lamports == 0));
+    assert_eq!(
+        res.validator_list.validators[2].status,
+        StakeStatus::ReadyForRemoval
+    );
+    assert_eq!(
+        res.validator_list.validators[3].status,
+        StakeStatus::ReadyForRemoval
+    );
+}
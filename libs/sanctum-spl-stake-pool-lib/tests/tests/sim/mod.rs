@@ -0,0 +1 @@
+mod epoch_crank;
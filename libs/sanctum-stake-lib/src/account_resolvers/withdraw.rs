@@ -1,8 +1,15 @@
-use solana_program::{program_error::ProgramError, pubkey::Pubkey, sysvar};
-use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkeyBytes};
+use solana_program::{
+    clock::{Clock, Epoch},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar,
+};
+use solana_readonly_account::{
+    ReadonlyAccountData, ReadonlyAccountLamports, ReadonlyAccountPubkeyBytes,
+};
 use stake_program_interface::WithdrawKeys;
 
-use crate::ReadonlyStakeAccount;
+use crate::{stake_withdrawable_lamports, ReadonlyStakeAccount, StakeHistoryGetEntry};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct WithdrawFreeAccounts<S> {
@@ -29,6 +36,58 @@ impl<S: ReadonlyAccountData + ReadonlyAccountPubkeyBytes> WithdrawFreeAccounts<S
     }
 }
 
+impl<S: ReadonlyAccountData + ReadonlyAccountLamports + ReadonlyAccountPubkeyBytes>
+    WithdrawFreeAccounts<S>
+{
+    /// [`Self::resolve`], but also checks that `lamports` can be withdrawn at `clock`,
+    /// failing with the same error the stake program would.
+    ///
+    /// `custodian` is the lockup custodian that will sign the instruction, if any.
+    pub fn resolve_checked<H: StakeHistoryGetEntry>(
+        &self,
+        lamports: u64,
+        clock: &Clock,
+        stake_history: H,
+        new_rate_activation_epoch: Option<Epoch>,
+        custodian: Option<Pubkey>,
+    ) -> Result<WithdrawKeys, ProgramError> {
+        self.resolve_to_free_keys_checked(
+            lamports,
+            clock,
+            stake_history,
+            new_rate_activation_epoch,
+            custodian,
+        )
+        .map(Into::into)
+    }
+
+    /// [`Self::resolve_to_free_keys`], but also checks that `lamports` can be withdrawn at `clock`,
+    /// failing with the same error the stake program would.
+    ///
+    /// `custodian` is the lockup custodian that will sign the instruction, if any.
+    pub fn resolve_to_free_keys_checked<H: StakeHistoryGetEntry>(
+        &self,
+        lamports: u64,
+        clock: &Clock,
+        stake_history: H,
+        new_rate_activation_epoch: Option<Epoch>,
+        custodian: Option<Pubkey>,
+    ) -> Result<WithdrawFreeKeys, ProgramError> {
+        let free_keys = self.resolve_to_free_keys()?;
+        let s = ReadonlyStakeAccount(&self.from);
+        let s = s.try_into_valid()?;
+        stake_withdrawable_lamports(
+            s,
+            clock,
+            stake_history,
+            new_rate_activation_epoch,
+            custodian,
+        )?
+        .check_withdraw(lamports)?;
+        Ok(free_keys)
+    }
+}
+
 impl<S: ReadonlyAccountData + ReadonlyAccountPubkeyBytes> TryFrom<WithdrawFreeAccounts<S>>
     for WithdrawKeys
 {
@@ -0,0 +1,325 @@
+//! Stake activation and deactivation calculations.
+//!
+//! Copied from `Delegation::stake_activating_and_deactivating`,
+//! generic over the stake history source, allocation-free and `core`-only
+//! so that it can be used onchain with readonly stake history accessors.
+
+use core::cmp::Ordering;
+
+use solana_program::{
+    clock::Epoch,
+    stake::state::{warmup_cooldown_rate, Delegation, StakeActivationStatus},
+    stake_history::{StakeHistory, StakeHistoryEntry},
+};
+use solana_readonly_account::ReadonlyAccountData;
+
+use crate::StakeStakeAccount;
+
+/// Lookup of a stake history entry by epoch
+pub trait StakeHistoryGetEntry {
+    fn get_entry(&self, epoch: Epoch) -> Option<StakeHistoryEntry>;
+}
+
+impl<T: StakeHistoryGetEntry> StakeHistoryGetEntry for &T {
+    fn get_entry(&self, epoch: Epoch) -> Option<StakeHistoryEntry> {
+        (*self).get_entry(epoch)
+    }
+}
+
+impl StakeHistoryGetEntry for StakeHistory {
+    fn get_entry(&self, epoch: Epoch) -> Option<StakeHistoryEntry> {
+        self.get(epoch).cloned()
+    }
+}
+
+/// The subset of [`Delegation`] fields that determine its activation status
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DelegationEpochs {
+    pub stake: u64,
+    pub activation_epoch: Epoch,
+    pub deactivation_epoch: Epoch,
+}
+
+impl DelegationEpochs {
+    pub fn is_bootstrap(&self) -> bool {
+        self.activation_epoch == Epoch::MAX
+    }
+
+    /// Returns the `effective`, `activating` and `deactivating` stake of this delegation at `target_epoch`.
+    ///
+    /// `new_rate_activation_epoch` is the epoch at which the `reduce_stake_warmup_cooldown` feature was activated, if any.
+    pub fn stake_activating_and_deactivating<H: StakeHistoryGetEntry>(
+        &self,
+        target_epoch: Epoch,
+        history: H,
+        new_rate_activation_epoch: Option<Epoch>,
+    ) -> StakeActivationStatus {
+        let (effective_stake, activating_stake) =
+            self.stake_and_activating(target_epoch, &history, new_rate_activation_epoch);
+
+        match target_epoch.cmp(&self.deactivation_epoch) {
+            Ordering::Less => {
+                if activating_stake == 0 {
+                    StakeActivationStatus::with_effective(effective_stake)
+                } else {
+                    StakeActivationStatus::with_effective_and_activating(
+                        effective_stake,
+                        activating_stake,
+                    )
+                }
+            }
+            // can only deactivate what's activated
+            Ordering::Equal => StakeActivationStatus::with_deactivating(effective_stake),
+            Ordering::Greater => {
+                let mut prev_epoch = self.deactivation_epoch;
+                let mut prev_cluster_stake = match history.get_entry(prev_epoch) {
+                    Some(e) => e,
+                    // no history or dropped out of history, so assume fully deactivated
+                    None => return StakeActivationStatus::default(),
+                };
+                let mut current_effective_stake = effective_stake;
+                loop {
+                    let current_epoch = prev_epoch + 1;
+                    // no deactivating stake at prev epoch means fully undelegated by now
+                    if prev_cluster_stake.deactivating == 0 {
+                        break;
+                    }
+                    let weight =
+                        current_effective_stake as f64 / prev_cluster_stake.deactivating as f64;
+                    let rate = warmup_cooldown_rate(current_epoch, new_rate_activation_epoch);
+                    let newly_not_effective_cluster_stake =
+                        prev_cluster_stake.effective as f64 * rate;
+                    let newly_not_effective_stake =
+                        ((weight * newly_not_effective_cluster_stake) as u64).max(1);
+
+                    current_effective_stake =
+                        current_effective_stake.saturating_sub(newly_not_effective_stake);
+                    if current_effective_stake == 0 || current_epoch >= target_epoch {
+                        break;
+                    }
+                    match history.get_entry(current_epoch) {
+                        Some(e) => {
+                            prev_epoch = current_epoch;
+                            prev_cluster_stake = e;
+                        }
+                        None => break,
+                    }
+                }
+                StakeActivationStatus::with_deactivating(current_effective_stake)
+            }
+        }
+    }
+
+    /// Returns (effective, activating)
+    fn stake_and_activating<H: StakeHistoryGetEntry>(
+        &self,
+        target_epoch: Epoch,
+        history: &H,
+        new_rate_activation_epoch: Option<Epoch>,
+    ) -> (u64, u64) {
+        let delegated_stake = self.stake;
+
+        if self.is_bootstrap() {
+            // fully effective immediately
+            return (delegated_stake, 0);
+        }
+        if self.activation_epoch == self.deactivation_epoch {
+            // activated but instantly deactivated, no stake at all regardless of target_epoch
+            return (0, 0);
+        }
+        match target_epoch.cmp(&self.activation_epoch) {
+            Ordering::Equal => return (0, delegated_stake),
+            Ordering::Less => return (0, 0),
+            Ordering::Greater => (),
+        }
+
+        let mut prev_epoch = self.activation_epoch;
+        let mut prev_cluster_stake = match history.get_entry(prev_epoch) {
+            Some(e) => e,
+            // no history or dropped out of history, so assume fully effective
+            None => return (delegated_stake, 0),
+        };
+        let mut current_effective_stake = 0;
+        loop {
+            let current_epoch = prev_epoch + 1;
+            // no activating stake at prev epoch means fully effective by now
+            if prev_cluster_stake.activating == 0 {
+                break;
+            }
+            let remaining_activating_stake = delegated_stake - current_effective_stake;
+            let weight = remaining_activating_stake as f64 / prev_cluster_stake.activating as f64;
+            let rate = warmup_cooldown_rate(current_epoch, new_rate_activation_epoch);
+            let newly_effective_cluster_stake = prev_cluster_stake.effective as f64 * rate;
+            let newly_effective_stake = ((weight * newly_effective_cluster_stake) as u64).max(1);
+
+            current_effective_stake += newly_effective_stake;
+            if current_effective_stake >= delegated_stake {
+                current_effective_stake = delegated_stake;
+                break;
+            }
+            if current_epoch >= target_epoch || current_epoch >= self.deactivation_epoch {
+                break;
+            }
+            match history.get_entry(current_epoch) {
+                Some(e) => {
+                    prev_epoch = current_epoch;
+                    prev_cluster_stake = e;
+                }
+                None => break,
+            }
+        }
+        (
+            current_effective_stake,
+            delegated_stake - current_effective_stake,
+        )
+    }
+}
+
+impl From<&Delegation> for DelegationEpochs {
+    fn from(
+        Delegation {
+            stake,
+            activation_epoch,
+            deactivation_epoch,
+            ..
+        }: &Delegation,
+    ) -> Self {
+        Self {
+            stake: *stake,
+            activation_epoch: *activation_epoch,
+            deactivation_epoch: *deactivation_epoch,
+        }
+    }
+}
+
+impl From<Delegation> for DelegationEpochs {
+    fn from(value: Delegation) -> Self {
+        (&value).into()
+    }
+}
+
+impl<T: ReadonlyAccountData> StakeStakeAccount<T> {
+    pub fn stake_delegation_epochs(&self) -> DelegationEpochs {
+        DelegationEpochs {
+            stake: self.stake_stake_delegation_stake(),
+            activation_epoch: self.stake_stake_delegation_activation_epoch(),
+            deactivation_epoch: self.stake_stake_delegation_deactivation_epoch(),
+        }
+    }
+
+    /// See [`DelegationEpochs::stake_activating_and_deactivating`]
+    pub fn stake_activating_and_deactivating<H: StakeHistoryGetEntry>(
+        &self,
+        target_epoch: Epoch,
+        history: H,
+        new_rate_activation_epoch: Option<Epoch>,
+    ) -> StakeActivationStatus {
+        self.stake_delegation_epochs()
+            .stake_activating_and_deactivating(target_epoch, history, new_rate_activation_epoch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshSerialize;
+    use proptest::{collection::vec, prelude::*};
+    use sanctum_solana_test_utils::stake::proptest_utils::{delegation, meta};
+    use solana_program::stake::{stake_flags::StakeFlags, state::Stake, state::StakeStateV2};
+
+    use crate::ReadonlyStakeAccount;
+
+    use super::*;
+
+    const MAX_EPOCH: Epoch = 64;
+
+    struct AccountData<'a>(pub &'a [u8]);
+
+    impl<'a> ReadonlyAccountData for AccountData<'a> {
+        type DataDeref<'d>
+            = &'d [u8]
+        where
+            Self: 'd;
+
+        fn data(&self) -> Self::DataDeref<'_> {
+            self.0
+        }
+    }
+
+    fn epoch() -> impl Strategy<Value = Epoch> {
+        prop_oneof![
+            8 => 0..=MAX_EPOCH,
+            1 => Just(Epoch::MAX),
+        ]
+    }
+
+    /// Sparse stake history with cluster stake in a realistic range
+    fn stake_history() -> impl Strategy<Value = StakeHistory> {
+        vec(
+            (
+                0..=MAX_EPOCH,
+                0..=1_000_000_000_000_000u64,
+                0..=1_000_000_000_000_000u64,
+                0..=1_000_000_000_000_000u64,
+            ),
+            0..=MAX_EPOCH as usize,
+        )
+        .prop_map(|entries| {
+            let mut res = StakeHistory::default();
+            for (epoch, effective, activating, deactivating) in entries {
+                res.add(
+                    epoch,
+                    StakeHistoryEntry {
+                        effective,
+                        activating,
+                        deactivating,
+                    },
+                );
+            }
+            res
+        })
+    }
+
+    prop_compose! {
+        fn realistic_delegation()
+            (
+                delegation in delegation(),
+                stake in 0..=1_000_000_000_000_000u64,
+                activation_epoch in epoch(),
+                deactivation_epoch in epoch(),
+            ) -> Delegation {
+                Delegation { stake, activation_epoch, deactivation_epoch, ..delegation }
+            }
+    }
+
+    proptest! {
+        #[test]
+        fn stake_activating_and_deactivating_matches_delegation(
+            meta in meta(),
+            delegation in realistic_delegation(),
+            credits_observed: u64,
+            history in stake_history(),
+            target_epoch in 0..=MAX_EPOCH + 2,
+            new_rate_activation_epoch in proptest::option::of(0..=MAX_EPOCH),
+        ) {
+            let expected = delegation.stake_activating_and_deactivating(target_epoch, &history, new_rate_activation_epoch);
+            prop_assert_eq!(
+                &DelegationEpochs::from(&delegation).stake_activating_and_deactivating(target_epoch, &history, new_rate_activation_epoch),
+                &expected
+            );
+
+            let mut data = vec![0u8; StakeStateV2::size_of()];
+            StakeStateV2::Stake(meta, Stake { delegation, credits_observed }, StakeFlags::empty())
+                .serialize(&mut data.as_mut_slice())
+                .unwrap();
+            let account = ReadonlyStakeAccount(AccountData(&data))
+                .try_into_valid()
+                .unwrap()
+                .try_into_stake()
+                .unwrap();
+            prop_assert_eq!(
+                account.stake_activating_and_deactivating(target_epoch, &history, new_rate_activation_epoch),
+                expected
+            );
+        }
+    }
+}
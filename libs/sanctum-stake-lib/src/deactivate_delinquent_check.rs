@@ -0,0 +1,55 @@
+use sanctum_vote_lib::ReadonlyVoteAccount;
+use solana_program::{
+    clock::Epoch, program_error::ProgramError, pubkey::Pubkey, stake::instruction::StakeError, vote,
+};
+use solana_readonly_account::{
+    ReadonlyAccountData, ReadonlyAccountOwnerBytes, ReadonlyAccountPubkeyBytes,
+};
+
+use crate::ReadonlyStakeAccount;
+
+/// Checks if `stake` can be deactivated with `DeactivateDelinquent`,
+/// returning the error the stake program would.
+///
+/// Does not check if the stake's flags require it to be fully activated before deactivating.
+pub fn deactivate_delinquent_check<S, D, R>(
+    stake: S,
+    delinquent_vote: D,
+    reference_vote: R,
+    current_epoch: Epoch,
+) -> Result<(), ProgramError>
+where
+    S: ReadonlyAccountData,
+    D: ReadonlyAccountData + ReadonlyAccountPubkeyBytes + ReadonlyAccountOwnerBytes,
+    R: ReadonlyAccountData + ReadonlyAccountOwnerBytes,
+{
+    if delinquent_vote.owner_bytes() != vote::program::ID.to_bytes() {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let delinquent_vote_pubkey = Pubkey::new_from_array(delinquent_vote.pubkey_bytes());
+    let delinquent_vote = ReadonlyVoteAccount(delinquent_vote).try_into_valid()?;
+    if reference_vote.owner_bytes() != vote::program::ID.to_bytes() {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let reference_vote = ReadonlyVoteAccount(reference_vote).try_into_valid()?;
+    if !reference_vote.vote_is_acceptable_reference(current_epoch) {
+        return Err(ProgramError::Custom(
+            StakeError::InsufficientReferenceVotes as u32,
+        ));
+    }
+    let stake = ReadonlyStakeAccount(stake)
+        .try_into_valid()?
+        .try_into_stake()?;
+    if stake.stake_stake_delegation_voter_pubkey() != delinquent_vote_pubkey {
+        return Err(ProgramError::Custom(StakeError::VoteAddressMismatch as u32));
+    }
+    if !delinquent_vote.vote_is_delinquent(current_epoch) {
+        return Err(ProgramError::Custom(
+            StakeError::MinimumDelinquentEpochsForDeactivationNotMet as u32,
+        ));
+    }
+    if stake.stake_stake_delegation_deactivation_epoch() != Epoch::MAX {
+        return Err(ProgramError::Custom(StakeError::AlreadyDeactivated as u32));
+    }
+    Ok(())
+}
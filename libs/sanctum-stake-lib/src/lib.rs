@@ -1,14 +1,33 @@
 mod account_resolvers;
+mod activation;
+mod deactivate_delinquent_check;
 mod instructions;
+mod lifecycle;
+mod merge_check;
 mod readonly;
+mod split_plan;
+mod stake_history;
 mod typeconv;
 mod utils;
+mod withdrawable;
 
 pub use account_resolvers::*;
+pub use activation::*;
+pub use deactivate_delinquent_check::*;
 pub use instructions::*;
+pub use lifecycle::*;
+pub use merge_check::*;
 pub use readonly::*;
+pub use split_plan::*;
+pub use stake_history::*;
 pub use typeconv::*;
 pub use utils::*;
+pub use withdrawable::*;
+
+// vote account accessors live in sanctum-vote-lib, re-exported for reading delegated vote accounts
+pub use sanctum_vote_lib::{
+    ReadonlyVoteAccount, ValidVoteAccount, VoteEpochCreditsIter, VoteStateMarker,
+};
 
 // This const is available in StakeState::size_of() and StakeStateV2::size_of(),
 // but 1.17 will make using StakeState::size_of() a deprecation warning,
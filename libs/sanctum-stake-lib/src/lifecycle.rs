@@ -0,0 +1,487 @@
+//! Classify a stake account into its lifecycle status and the stake instructions it currently accepts.
+//!
+//! Mirrors the state checks of the stake program's instruction processors,
+//! but does not check signers or instruction-specific amounts other than `Withdraw`'s.
+
+use solana_program::{
+    clock::{Clock, Epoch},
+    pubkey::Pubkey,
+    stake::state::StakeActivationStatus,
+};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountLamports};
+
+use crate::{StakeHistoryGetEntry, StakeStateMarker, StakeWithdrawableLamports, ValidStakeAccount};
+
+/// Bit of [`solana_program::stake::stake_flags::StakeFlags`], whose bits are private
+const MUST_FULLY_ACTIVATE_BEFORE_DEACTIVATION_IS_PERMITTED: u8 = 0b0000_0001;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum StakeLifecycleStatus {
+    Uninitialized,
+
+    /// Initialized but never delegated
+    Initialized,
+
+    /// Delegated, with stake still warming up. May already have some effective stake
+    Activating,
+
+    /// Delegated, with fully effective stake
+    Active,
+
+    /// Deactivated, with stake still cooling down
+    Deactivating,
+
+    /// Delegated, but with no effective, activating or deactivating stake,
+    /// e.g. after deactivation has fully cooled down
+    Inactive,
+
+    RewardsPool,
+}
+
+/// Which stake instructions a stake account currently accepts
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct StakeActions {
+    pub initialize: bool,
+    pub authorize_staker: bool,
+
+    /// False if lockup is in force and the custodian does not sign
+    pub authorize_withdrawer: bool,
+
+    /// The withdraw authority signs if lockup is not in force, the custodian otherwise
+    pub set_lockup: bool,
+
+    /// Delegate to any vote account
+    pub delegate: bool,
+
+    /// Delegate to the same vote account to cancel a deactivation in its deactivation epoch.
+    ///
+    /// Requires the account's lamports less `rent_exempt_reserve` to still cover the delegated stake,
+    /// which is not the case for the source account of a `Redelegate`
+    pub rescind_deactivation: bool,
+
+    pub deactivate: bool,
+
+    /// `max_withdrawable_lamports > 0`
+    pub withdraw: bool,
+
+    /// See [`StakeWithdrawableLamports::max_withdrawable_lamports`].
+    ///
+    /// Not every amount up to this is valid: if this is the account's lamports,
+    /// the account can only be closed by withdrawing exactly this amount, and a partial withdrawal
+    /// must leave `rent_exempt_reserve` in the account, i.e. it is at most
+    /// [`StakeWithdrawableLamports::free`]. Use [`crate::stake_withdrawable_lamports`]
+    /// and [`StakeWithdrawableLamports::check_withdraw`] to check a specific amount
+    pub max_withdrawable_lamports: u64,
+
+    /// Can be either side of a `Merge`, given a compatible counterpart.
+    /// See [`crate::check_stake_merge`] to check a specific pair of accounts
+    pub merge: bool,
+
+    pub split: bool,
+    pub redelegate: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StakeLifecycle {
+    pub status: StakeLifecycleStatus,
+
+    /// All zeros if the account is not delegated
+    pub activation_status: StakeActivationStatus,
+
+    pub actions: StakeActions,
+}
+
+/// Classifies `stake` at `clock`.
+///
+/// `new_rate_activation_epoch` is the epoch at which the `reduce_stake_warmup_cooldown` feature was activated, if any.
+///
+/// `custodian` is the lockup custodian that will sign the instruction, if any.
+pub fn stake_lifecycle<T, H>(
+    stake: ValidStakeAccount<T>,
+    clock: &Clock,
+    stake_history: H,
+    new_rate_activation_epoch: Option<Epoch>,
+    custodian: Option<Pubkey>,
+) -> StakeLifecycle
+where
+    T: ReadonlyAccountData + ReadonlyAccountLamports,
+    H: StakeHistoryGetEntry,
+{
+    let lamports = stake.as_readonly().as_inner().lamports();
+    match stake.stake_state_marker() {
+        StakeStateMarker::Uninitialized => {
+            return StakeLifecycle {
+                status: StakeLifecycleStatus::Uninitialized,
+                activation_status: StakeActivationStatus::default(),
+                actions: StakeActions {
+                    initialize: true,
+                    // both require the stake account to sign
+                    withdraw: lamports > 0,
+                    max_withdrawable_lamports: lamports,
+                    split: true,
+                    ..Default::default()
+                },
+            };
+        }
+        StakeStateMarker::RewardsPool => {
+            return StakeLifecycle {
+                status: StakeLifecycleStatus::RewardsPool,
+                activation_status: StakeActivationStatus::default(),
+                actions: StakeActions::default(),
+            }
+        }
+        StakeStateMarker::Initialized | StakeStateMarker::Stake => (),
+    }
+
+    // checked Initialized or Stake above
+    let stake = stake.try_into_stake_or_initialized().unwrap();
+    let lockup_in_force = stake.stake_lockup_is_in_force(clock)
+        && custodian != Some(stake.stake_meta_lockup_custodian());
+    let meta_actions = StakeActions {
+        authorize_staker: true,
+        authorize_withdrawer: !lockup_in_force,
+        set_lockup: true,
+        split: true,
+        merge: true,
+        ..Default::default()
+    };
+    let rent_exempt_reserve = stake.stake_meta_rent_exempt_reserve();
+
+    if stake.as_valid().stake_state_marker() == StakeStateMarker::Initialized {
+        let max_withdrawable_lamports =
+            StakeWithdrawableLamports::new(lamports, 0, rent_exempt_reserve, lockup_in_force)
+                .max_withdrawable_lamports();
+        return StakeLifecycle {
+            status: StakeLifecycleStatus::Initialized,
+            activation_status: StakeActivationStatus::default(),
+            actions: StakeActions {
+                delegate: true,
+                withdraw: max_withdrawable_lamports > 0,
+                max_withdrawable_lamports,
+                ..meta_actions
+            },
+        };
+    }
+
+    let stake = stake.try_into_stake().unwrap();
+    let activation_status = stake.stake_activating_and_deactivating(
+        clock.epoch,
+        stake_history,
+        new_rate_activation_epoch,
+    );
+    let StakeActivationStatus {
+        effective,
+        activating,
+        deactivating,
+    } = activation_status;
+    let status = if deactivating > 0 {
+        StakeLifecycleStatus::Deactivating
+    } else if activating > 0 {
+        StakeLifecycleStatus::Activating
+    } else if effective > 0 {
+        StakeLifecycleStatus::Active
+    } else {
+        StakeLifecycleStatus::Inactive
+    };
+    let deactivation_epoch = stake.stake_stake_delegation_deactivation_epoch();
+
+    // withdraw()
+    let staked = if clock.epoch >= deactivation_epoch {
+        effective
+    } else {
+        // assume full stake if not yet deactivated,
+        // effective stake may be less than delegated stake during warmup
+        stake.stake_stake_delegation_stake()
+    };
+    let max_withdrawable_lamports =
+        StakeWithdrawableLamports::new(lamports, staked, rent_exempt_reserve, lockup_in_force)
+            .max_withdrawable_lamports();
+
+    let must_fully_activate =
+        stake.stake_stake_flags() & MUST_FULLY_ACTIVATE_BEFORE_DEACTIVATION_IS_PERMITTED != 0;
+    let can_deactivate =
+        deactivation_epoch == Epoch::MAX && (!must_fully_activate || activating == 0);
+
+    StakeLifecycle {
+        status,
+        activation_status,
+        actions: StakeActions {
+            // redelegate_stake()
+            delegate: effective == 0,
+            // lamports of stake moved out by `Redelegate` cannot be rescinded
+            rescind_deactivation: effective != 0
+                && clock.epoch == deactivation_epoch
+                && lamports.saturating_sub(rent_exempt_reserve)
+                    >= stake.stake_stake_delegation_stake(),
+            // deactivate_stake()
+            deactivate: can_deactivate,
+            withdraw: max_withdrawable_lamports > 0,
+            max_withdrawable_lamports,
+            // MergeKind::get_if_mergeable(), only activating or deactivating with effective stake is transient
+            merge: matches!((effective, activating, deactivating), (0, _, _) | (_, 0, 0)),
+            // redelegate(), only fully active stake can be redelegated
+            redelegate: effective != 0 && activating == 0 && deactivating == 0,
+            ..meta_actions
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshSerialize;
+    use solana_program::{
+        stake::{
+            stake_flags::StakeFlags,
+            state::{Delegation, Lockup, Meta, Stake, StakeStateV2},
+        },
+        stake_history::StakeHistory,
+    };
+
+    use crate::ReadonlyStakeAccount;
+
+    use super::*;
+
+    const EPOCH: Epoch = 10;
+
+    const STAKE: u64 = 1_000_000_000;
+
+    const RENT_EXEMPT_RESERVE: u64 = 2_282_880;
+
+    const LAMPORTS: u64 = 2 * STAKE + RENT_EXEMPT_RESERVE;
+
+    struct Account {
+        lamports: u64,
+        data: Vec<u8>,
+    }
+
+    impl ReadonlyAccountLamports for &Account {
+        fn lamports(&self) -> u64 {
+            self.lamports
+        }
+    }
+
+    impl ReadonlyAccountData for &Account {
+        type DataDeref<'d>
+            = &'d [u8]
+        where
+            Self: 'd;
+
+        fn data(&self) -> Self::DataDeref<'_> {
+            &self.data
+        }
+    }
+
+    fn account(state: StakeStateV2) -> Account {
+        let mut data = vec![0u8; StakeStateV2::size_of()];
+        state.serialize(&mut data.as_mut_slice()).unwrap();
+        Account {
+            lamports: LAMPORTS,
+            data,
+        }
+    }
+
+    fn meta() -> Meta {
+        Meta {
+            rent_exempt_reserve: RENT_EXEMPT_RESERVE,
+            ..Default::default()
+        }
+    }
+
+    fn stake_account(meta: Meta, activation_epoch: Epoch, deactivation_epoch: Epoch) -> Account {
+        stake_account_with_flags(
+            meta,
+            activation_epoch,
+            deactivation_epoch,
+            StakeFlags::empty(),
+        )
+    }
+
+    fn stake_account_with_flags(
+        meta: Meta,
+        activation_epoch: Epoch,
+        deactivation_epoch: Epoch,
+        flags: StakeFlags,
+    ) -> Account {
+        account(StakeStateV2::Stake(
+            meta,
+            Stake {
+                delegation: Delegation {
+                    voter_pubkey: Pubkey::new_unique(),
+                    stake: STAKE,
+                    activation_epoch,
+                    deactivation_epoch,
+                    ..Default::default()
+                },
+                credits_observed: 0,
+            },
+            flags,
+        ))
+    }
+
+    /// With empty stake history, delegations are fully
+    /// active or inactive the epoch after their (de)activation epoch
+    fn lifecycle(account: &Account, custodian: Option<Pubkey>) -> StakeLifecycle {
+        stake_lifecycle(
+            ReadonlyStakeAccount(account).try_into_valid().unwrap(),
+            &Clock {
+                epoch: EPOCH,
+                ..Default::default()
+            },
+            StakeHistory::default(),
+            None,
+            custodian,
+        )
+    }
+
+    #[test]
+    fn lifecycle_statuses() {
+        let meta = meta();
+        for (account, expected) in [
+            (
+                account(StakeStateV2::Uninitialized),
+                StakeLifecycleStatus::Uninitialized,
+            ),
+            (
+                account(StakeStateV2::Initialized(meta)),
+                StakeLifecycleStatus::Initialized,
+            ),
+            (
+                stake_account(meta, EPOCH, Epoch::MAX),
+                StakeLifecycleStatus::Activating,
+            ),
+            (
+                stake_account(meta, 0, Epoch::MAX),
+                StakeLifecycleStatus::Active,
+            ),
+            (
+                stake_account(meta, 0, EPOCH),
+                StakeLifecycleStatus::Deactivating,
+            ),
+            (
+                stake_account(meta, 0, EPOCH - 1),
+                StakeLifecycleStatus::Inactive,
+            ),
+            (
+                account(StakeStateV2::RewardsPool),
+                StakeLifecycleStatus::RewardsPool,
+            ),
+        ] {
+            assert_eq!(lifecycle(&account, None).status, expected);
+        }
+    }
+
+    #[test]
+    fn lifecycle_actions() {
+        let meta = meta();
+
+        let active = lifecycle(&stake_account(meta, 0, Epoch::MAX), None).actions;
+        assert!(!active.delegate);
+        assert!(active.deactivate);
+        assert!(active.redelegate);
+        assert!(active.merge);
+        assert_eq!(
+            active.max_withdrawable_lamports,
+            LAMPORTS - STAKE - RENT_EXEMPT_RESERVE
+        );
+
+        let activating = lifecycle(&stake_account(meta, EPOCH, Epoch::MAX), None).actions;
+        assert!(activating.delegate);
+        assert!(activating.deactivate);
+        assert!(!activating.redelegate);
+        assert!(activating.merge);
+        // effective stake is 0 but delegated stake is not yet deactivated
+        assert_eq!(
+            activating.max_withdrawable_lamports,
+            LAMPORTS - STAKE - RENT_EXEMPT_RESERVE
+        );
+
+        let redelegated = stake_account_with_flags(
+            meta,
+            EPOCH,
+            Epoch::MAX,
+            StakeFlags::MUST_FULLY_ACTIVATE_BEFORE_DEACTIVATION_IS_PERMITTED,
+        );
+        assert!(!lifecycle(&redelegated, None).actions.deactivate);
+
+        let deactivating = lifecycle(&stake_account(meta, 0, EPOCH), None).actions;
+        assert!(!deactivating.delegate);
+        assert!(deactivating.rescind_deactivation);
+        assert!(!deactivating.deactivate);
+        assert!(!deactivating.redelegate);
+        assert!(!deactivating.merge);
+        assert_eq!(
+            deactivating.max_withdrawable_lamports,
+            LAMPORTS - STAKE - RENT_EXEMPT_RESERVE
+        );
+
+        let mut redelegate_source = stake_account(meta, 0, EPOCH);
+        redelegate_source.lamports = RENT_EXEMPT_RESERVE;
+        let redelegate_source = lifecycle(&redelegate_source, None).actions;
+        assert!(!redelegate_source.rescind_deactivation);
+        assert!(!redelegate_source.delegate);
+
+        let mut barely_covered = stake_account(meta, 0, EPOCH);
+        barely_covered.lamports = STAKE + RENT_EXEMPT_RESERVE;
+        assert!(
+            lifecycle(&barely_covered, None)
+                .actions
+                .rescind_deactivation
+        );
+        barely_covered.lamports -= 1;
+        assert!(
+            !lifecycle(&barely_covered, None)
+                .actions
+                .rescind_deactivation
+        );
+
+        let inactive = lifecycle(&stake_account(meta, 0, EPOCH - 1), None).actions;
+        assert!(inactive.delegate);
+        assert!(!inactive.rescind_deactivation);
+        assert!(!inactive.deactivate);
+        assert!(inactive.merge);
+        assert_eq!(inactive.max_withdrawable_lamports, LAMPORTS);
+
+        let initialized = lifecycle(&account(StakeStateV2::Initialized(meta)), None).actions;
+        assert!(initialized.delegate);
+        assert!(!initialized.deactivate);
+        assert_eq!(initialized.max_withdrawable_lamports, LAMPORTS);
+
+        let uninitialized = lifecycle(&account(StakeStateV2::Uninitialized), None).actions;
+        assert!(uninitialized.initialize);
+        assert!(!uninitialized.delegate);
+        assert!(!uninitialized.authorize_staker);
+
+        assert_eq!(
+            lifecycle(&account(StakeStateV2::RewardsPool), None).actions,
+            StakeActions::default()
+        );
+    }
+
+    #[test]
+    fn lifecycle_lockup() {
+        let custodian = Pubkey::new_unique();
+        let meta = Meta {
+            lockup: Lockup {
+                epoch: EPOCH + 1,
+                custodian,
+                ..Default::default()
+            },
+            ..meta()
+        };
+        let inactive = stake_account(meta, 0, EPOCH - 1);
+
+        let locked = lifecycle(&inactive, None).actions;
+        assert!(!locked.withdraw);
+        assert_eq!(locked.max_withdrawable_lamports, 0);
+        assert!(!locked.authorize_withdrawer);
+        assert!(locked.authorize_staker);
+
+        let not_custodian = lifecycle(&inactive, Some(Pubkey::new_unique())).actions;
+        assert_eq!(not_custodian, locked);
+
+        let custodian_signed = lifecycle(&inactive, Some(custodian)).actions;
+        assert!(custodian_signed.withdraw);
+        assert_eq!(custodian_signed.max_withdrawable_lamports, LAMPORTS);
+        assert!(custodian_signed.authorize_withdrawer);
+    }
+}
@@ -0,0 +1,608 @@
+//! Check if two stake accounts can be merged before submitting a `Merge` instruction.
+//!
+//! Mirrors the stake program's `MergeKind::get_if_mergeable()` and `MergeKind::merge()`,
+//! but reports exactly which rule failed instead of a catch-all `MergeMismatch`.
+
+use solana_program::{
+    clock::{Clock, Epoch},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    stake::{instruction::StakeError, state::StakeActivationStatus},
+};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountLamports};
+
+use crate::{
+    StakeHistoryGetEntry, StakeOrInitializedStakeAccount, StakeStateMarker, ValidStakeAccount,
+};
+
+/// The stake program's classification of a stake account for merging
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum StakeMergeKind {
+    /// Initialized, or delegated with no effective, activating or deactivating stake
+    Inactive,
+
+    /// Delegated, activating, with no effective stake
+    ActivationEpoch,
+
+    /// Delegated, fully effective, with no activating or deactivating stake
+    FullyActive,
+}
+
+/// The merge kinds of a pair of stake accounts that can be merged
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MergeableStakeKinds {
+    pub destination: StakeMergeKind,
+    pub source: StakeMergeKind,
+}
+
+/// Which of the stake program's merge rules a pair of stake accounts fails
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MergeCheckError {
+    /// Either account is not in the `Initialized` or `Stake` state
+    InvalidState,
+
+    /// Either account is activating or deactivating with nonzero effective stake
+    TransientStake,
+
+    /// `meta.authorized` differs
+    AuthoritiesMismatch,
+
+    /// `meta.lockup` differs and at least one of them is still in force
+    LockupMismatch,
+
+    /// Both accounts have active stake delegated to different vote accounts
+    VoterMismatch,
+
+    /// Both accounts have active stake but at least one of them has been deactivated
+    Deactivated,
+
+    /// The accounts' merge kinds cannot be merged,
+    /// e.g. a fully active destination and an inactive source
+    KindMismatch {
+        destination: StakeMergeKind,
+        source: StakeMergeKind,
+    },
+
+    /// The merged delegation's stake-weighted `credits_observed` cannot be calculated without overflowing
+    CreditsObservedOverflow,
+
+    /// The merged delegation's stake overflows
+    StakeOverflow,
+}
+
+impl From<MergeCheckError> for ProgramError {
+    /// Maps to the error the stake program would return
+    fn from(value: MergeCheckError) -> Self {
+        match value {
+            MergeCheckError::InvalidState => Self::InvalidAccountData,
+            MergeCheckError::TransientStake => Self::Custom(StakeError::MergeTransientStake as u32),
+            MergeCheckError::AuthoritiesMismatch
+            | MergeCheckError::LockupMismatch
+            | MergeCheckError::VoterMismatch
+            | MergeCheckError::Deactivated
+            | MergeCheckError::KindMismatch { .. } => {
+                Self::Custom(StakeError::MergeMismatch as u32)
+            }
+            MergeCheckError::CreditsObservedOverflow | MergeCheckError::StakeOverflow => {
+                Self::ArithmeticOverflow
+            }
+        }
+    }
+}
+
+/// Checks if `source` can be merged into `destination` at `clock.epoch`.
+///
+/// `new_rate_activation_epoch` is the epoch at which the `reduce_stake_warmup_cooldown` feature was activated, if any.
+///
+/// Does not check that `destination` and `source` are different accounts
+/// or that the destination's staker signed.
+pub fn check_stake_merge<D, S, H>(
+    destination: ValidStakeAccount<D>,
+    source: ValidStakeAccount<S>,
+    clock: &Clock,
+    stake_history: H,
+    new_rate_activation_epoch: Option<Epoch>,
+) -> Result<MergeableStakeKinds, MergeCheckError>
+where
+    D: ReadonlyAccountData + ReadonlyAccountLamports,
+    S: ReadonlyAccountData + ReadonlyAccountLamports,
+    H: StakeHistoryGetEntry,
+{
+    let destination = MergeCandidate::of(
+        destination,
+        clock,
+        &stake_history,
+        new_rate_activation_epoch,
+    )?;
+    let source = MergeCandidate::of(source, clock, &stake_history, new_rate_activation_epoch)?;
+
+    // metas_can_merge()
+    let dst_meta = &destination.account;
+    let src_meta = &source.account;
+    if dst_meta.stake_meta_authorized() != src_meta.stake_meta_authorized() {
+        return Err(MergeCheckError::AuthoritiesMismatch);
+    }
+    // lockups may mismatch so long as both have expired
+    if dst_meta.stake_meta_lockup() != src_meta.stake_meta_lockup()
+        && (dst_meta.stake_lockup_is_in_force(clock) || src_meta.stake_lockup_is_in_force(clock))
+    {
+        return Err(MergeCheckError::LockupMismatch);
+    }
+
+    // active_delegations_can_merge()
+    if let (Some(dst), Some(src)) = (destination.active_stake(), source.active_stake()) {
+        if dst.voter != src.voter {
+            return Err(MergeCheckError::VoterMismatch);
+        }
+        if dst.deactivation_epoch != Epoch::MAX || src.deactivation_epoch != Epoch::MAX {
+            return Err(MergeCheckError::Deactivated);
+        }
+    }
+
+    match (destination.kind, source.kind) {
+        (MergeCandidateKind::Inactive, MergeCandidateKind::Inactive)
+        | (MergeCandidateKind::Inactive, MergeCandidateKind::ActivationEpoch(_)) => (),
+        (MergeCandidateKind::ActivationEpoch(dst), MergeCandidateKind::Inactive) => {
+            dst.stake
+                .checked_add(source.lamports)
+                .ok_or(MergeCheckError::StakeOverflow)?;
+        }
+        (MergeCandidateKind::ActivationEpoch(dst), MergeCandidateKind::ActivationEpoch(src)) => {
+            let source_lamports = source
+                .account
+                .stake_meta_rent_exempt_reserve()
+                .checked_add(src.stake)
+                .ok_or(MergeCheckError::StakeOverflow)?;
+            dst.check_absorb(source_lamports, src.credits_observed)?;
+        }
+        (MergeCandidateKind::FullyActive(dst), MergeCandidateKind::FullyActive(src)) => {
+            // source's rent_exempt_reserve is not staked
+            dst.check_absorb(src.stake, src.credits_observed)?;
+        }
+        (dst, src) => {
+            return Err(MergeCheckError::KindMismatch {
+                destination: dst.marker(),
+                source: src.marker(),
+            })
+        }
+    }
+
+    Ok(MergeableStakeKinds {
+        destination: destination.kind.marker(),
+        source: source.kind.marker(),
+    })
+}
+
+struct MergeCandidate<T> {
+    account: StakeOrInitializedStakeAccount<T>,
+    lamports: u64,
+    kind: MergeCandidateKind,
+}
+
+impl<T: ReadonlyAccountData + ReadonlyAccountLamports> MergeCandidate<T> {
+    /// MergeKind::get_if_mergeable()
+    fn of<H: StakeHistoryGetEntry>(
+        account: ValidStakeAccount<T>,
+        clock: &Clock,
+        stake_history: &H,
+        new_rate_activation_epoch: Option<Epoch>,
+    ) -> Result<Self, MergeCheckError> {
+        let account = account
+            .try_into_stake_or_initialized()
+            .map_err(|_e| MergeCheckError::InvalidState)?;
+        let lamports = account.as_valid().as_readonly().as_inner().lamports();
+        if account.as_valid().stake_state_marker() == StakeStateMarker::Initialized {
+            return Ok(Self {
+                account,
+                lamports,
+                kind: MergeCandidateKind::Inactive,
+            });
+        }
+        // checked Initialized or Stake above
+        let stake = account.try_into_stake().unwrap();
+        let StakeActivationStatus {
+            effective,
+            activating,
+            deactivating,
+        } = stake.stake_activating_and_deactivating(
+            clock.epoch,
+            stake_history,
+            new_rate_activation_epoch,
+        );
+        let active = ActiveStake {
+            voter: stake.stake_stake_delegation_voter_pubkey(),
+            stake: stake.stake_stake_delegation_stake(),
+            deactivation_epoch: stake.stake_stake_delegation_deactivation_epoch(),
+            credits_observed: stake.stake_stake_credits_observed(),
+        };
+        let kind = match (effective, activating, deactivating) {
+            (0, 0, 0) => MergeCandidateKind::Inactive,
+            (0, _, _) => MergeCandidateKind::ActivationEpoch(active),
+            (_, 0, 0) => MergeCandidateKind::FullyActive(active),
+            _ => return Err(MergeCheckError::TransientStake),
+        };
+        Ok(Self {
+            account: stake.into_stake_or_initialized(),
+            lamports,
+            kind,
+        })
+    }
+
+    fn active_stake(&self) -> Option<&ActiveStake> {
+        match &self.kind {
+            MergeCandidateKind::Inactive => None,
+            MergeCandidateKind::ActivationEpoch(s) | MergeCandidateKind::FullyActive(s) => Some(s),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum MergeCandidateKind {
+    Inactive,
+    ActivationEpoch(ActiveStake),
+    FullyActive(ActiveStake),
+}
+
+impl MergeCandidateKind {
+    fn marker(&self) -> StakeMergeKind {
+        match self {
+            Self::Inactive => StakeMergeKind::Inactive,
+            Self::ActivationEpoch(_) => StakeMergeKind::ActivationEpoch,
+            Self::FullyActive(_) => StakeMergeKind::FullyActive,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct ActiveStake {
+    voter: Pubkey,
+    stake: u64,
+    deactivation_epoch: Epoch,
+    credits_observed: u64,
+}
+
+impl ActiveStake {
+    /// merge_delegation_stake_and_credits_observed()
+    fn check_absorb(
+        &self,
+        absorbed_lamports: u64,
+        absorbed_credits_observed: u64,
+    ) -> Result<(), MergeCheckError> {
+        self.stake_weighted_credits_observed(absorbed_lamports, absorbed_credits_observed)
+            .ok_or(MergeCheckError::CreditsObservedOverflow)?;
+        self.stake
+            .checked_add(absorbed_lamports)
+            .ok_or(MergeCheckError::StakeOverflow)?;
+        Ok(())
+    }
+
+    /// stake_weighted_credits_observed()
+    fn stake_weighted_credits_observed(
+        &self,
+        absorbed_lamports: u64,
+        absorbed_credits_observed: u64,
+    ) -> Option<u64> {
+        if self.credits_observed == absorbed_credits_observed {
+            return Some(self.credits_observed);
+        }
+        let total_stake = u128::from(self.stake.checked_add(absorbed_lamports)?);
+        let stake_weighted_credits =
+            u128::from(self.credits_observed).checked_mul(u128::from(self.stake))?;
+        let absorbed_weighted_credits =
+            u128::from(absorbed_credits_observed).checked_mul(u128::from(absorbed_lamports))?;
+        // ceil div
+        let total_weighted_credits = stake_weighted_credits
+            .checked_add(absorbed_weighted_credits)?
+            .checked_add(total_stake)?
+            .checked_sub(1)?;
+        u64::try_from(total_weighted_credits.checked_div(total_stake)?).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshSerialize;
+    use proptest::prelude::*;
+    use sanctum_solana_test_utils::{
+        proptest_utils::clock,
+        stake::proptest_utils::{delegation, meta},
+    };
+    use solana_program::{
+        stake::{
+            stake_flags::StakeFlags,
+            state::{Delegation, Meta, Stake, StakeStateV2},
+        },
+        stake_history::{StakeHistory, StakeHistoryEntry},
+    };
+
+    use crate::ReadonlyStakeAccount;
+
+    use super::*;
+
+    const EPOCH: Epoch = 10;
+
+    const STAKE: u64 = 1_000_000_000;
+
+    struct Account {
+        lamports: u64,
+        data: Vec<u8>,
+    }
+
+    impl ReadonlyAccountLamports for &Account {
+        fn lamports(&self) -> u64 {
+            self.lamports
+        }
+    }
+
+    impl ReadonlyAccountData for &Account {
+        type DataDeref<'d>
+            = &'d [u8]
+        where
+            Self: 'd;
+
+        fn data(&self) -> Self::DataDeref<'_> {
+            &self.data
+        }
+    }
+
+    fn account(state: StakeStateV2) -> Account {
+        let mut data = vec![0u8; StakeStateV2::size_of()];
+        state.serialize(&mut data.as_mut_slice()).unwrap();
+        Account {
+            lamports: STAKE * 2,
+            data,
+        }
+    }
+
+    fn stake_account(meta: Meta, delegation: Delegation, credits_observed: u64) -> Account {
+        account(StakeStateV2::Stake(
+            meta,
+            Stake {
+                delegation,
+                credits_observed,
+            },
+            StakeFlags::empty(),
+        ))
+    }
+
+    fn delegation_to(voter_pubkey: Pubkey, activation_epoch: Epoch) -> Delegation {
+        Delegation {
+            voter_pubkey,
+            stake: STAKE,
+            activation_epoch,
+            deactivation_epoch: Epoch::MAX,
+            ..Default::default()
+        }
+    }
+
+    fn check_with_history(
+        destination: &Account,
+        source: &Account,
+        clock: &Clock,
+        stake_history: &StakeHistory,
+    ) -> Result<MergeableStakeKinds, MergeCheckError> {
+        check_stake_merge(
+            ReadonlyStakeAccount(destination).try_into_valid().unwrap(),
+            ReadonlyStakeAccount(source).try_into_valid().unwrap(),
+            clock,
+            stake_history,
+            None,
+        )
+    }
+
+    /// With empty stake history, delegations activated before
+    /// `EPOCH` are fully active at `EPOCH`
+    fn check(
+        destination: &Account,
+        source: &Account,
+    ) -> Result<MergeableStakeKinds, MergeCheckError> {
+        check_with_history(
+            destination,
+            source,
+            &Clock {
+                epoch: EPOCH,
+                ..Default::default()
+            },
+            &StakeHistory::default(),
+        )
+    }
+
+    #[test]
+    fn merge_check_kinds() {
+        let meta = Meta::default();
+        let voter = Pubkey::new_unique();
+        let initialized = account(StakeStateV2::Initialized(meta));
+        let active = stake_account(meta, delegation_to(voter, 0), 5);
+        let activating = stake_account(meta, delegation_to(voter, EPOCH), 5);
+        let deactivated = stake_account(
+            meta,
+            Delegation {
+                deactivation_epoch: EPOCH - 1,
+                ..delegation_to(voter, 0)
+            },
+            5,
+        );
+
+        assert_eq!(
+            check(&active, &stake_account(meta, delegation_to(voter, 1), 6)),
+            Ok(MergeableStakeKinds {
+                destination: StakeMergeKind::FullyActive,
+                source: StakeMergeKind::FullyActive,
+            })
+        );
+        assert_eq!(
+            check(&initialized, &activating),
+            Ok(MergeableStakeKinds {
+                destination: StakeMergeKind::Inactive,
+                source: StakeMergeKind::ActivationEpoch,
+            })
+        );
+        assert_eq!(
+            check(&activating, &deactivated),
+            Ok(MergeableStakeKinds {
+                destination: StakeMergeKind::ActivationEpoch,
+                source: StakeMergeKind::Inactive,
+            })
+        );
+        assert_eq!(
+            check(&activating, &activating),
+            Ok(MergeableStakeKinds {
+                destination: StakeMergeKind::ActivationEpoch,
+                source: StakeMergeKind::ActivationEpoch,
+            })
+        );
+        assert_eq!(
+            check(&active, &initialized),
+            Err(MergeCheckError::KindMismatch {
+                destination: StakeMergeKind::FullyActive,
+                source: StakeMergeKind::Inactive,
+            })
+        );
+        assert_eq!(
+            check(&deactivated, &active),
+            Err(MergeCheckError::KindMismatch {
+                destination: StakeMergeKind::Inactive,
+                source: StakeMergeKind::FullyActive,
+            })
+        );
+        assert_eq!(
+            check(&account(StakeStateV2::Uninitialized), &active),
+            Err(MergeCheckError::InvalidState)
+        );
+    }
+
+    #[test]
+    fn merge_check_delegations() {
+        let meta = Meta::default();
+        let voter = Pubkey::new_unique();
+        let active = stake_account(meta, delegation_to(voter, 0), 0);
+
+        assert_eq!(
+            check(
+                &active,
+                &stake_account(meta, delegation_to(Pubkey::new_unique(), 0), 0)
+            ),
+            Err(MergeCheckError::VoterMismatch)
+        );
+        // deactivation scheduled for a future epoch leaves the stake fully active,
+        // but the stake program still refuses to merge it
+        assert_eq!(
+            check(
+                &active,
+                &stake_account(
+                    meta,
+                    Delegation {
+                        deactivation_epoch: EPOCH + 1,
+                        ..delegation_to(voter, 0)
+                    },
+                    0
+                )
+            ),
+            Err(MergeCheckError::Deactivated)
+        );
+        assert_eq!(
+            check(
+                &stake_account(
+                    meta,
+                    Delegation {
+                        stake: u64::MAX - 1,
+                        ..delegation_to(voter, 0)
+                    },
+                    0
+                ),
+                &stake_account(meta, delegation_to(voter, 0), u64::MAX)
+            ),
+            Err(MergeCheckError::CreditsObservedOverflow)
+        );
+        assert_eq!(
+            check(
+                &stake_account(
+                    meta,
+                    Delegation {
+                        stake: u64::MAX,
+                        ..delegation_to(voter, 0)
+                    },
+                    0
+                ),
+                &active
+            ),
+            Err(MergeCheckError::StakeOverflow)
+        );
+
+        let mut history = StakeHistory::default();
+        history.add(
+            EPOCH - 1,
+            StakeHistoryEntry {
+                effective: 1_000 * STAKE,
+                activating: 1_000 * STAKE,
+                deactivating: 0,
+            },
+        );
+        assert_eq!(
+            check_with_history(
+                &active,
+                &stake_account(meta, delegation_to(voter, EPOCH - 1), 0),
+                &Clock {
+                    epoch: EPOCH,
+                    ..Default::default()
+                },
+                &history
+            ),
+            Err(MergeCheckError::TransientStake)
+        );
+    }
+
+    #[test]
+    fn merge_check_errors_match_stake_program() {
+        assert_eq!(
+            ProgramError::from(MergeCheckError::TransientStake),
+            ProgramError::Custom(StakeError::MergeTransientStake as u32)
+        );
+        assert_eq!(
+            ProgramError::from(MergeCheckError::VoterMismatch),
+            ProgramError::Custom(StakeError::MergeMismatch as u32)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn merge_check_metas(
+            dst_meta in meta(),
+            src_meta in meta(),
+            same_authorized: bool,
+            same_lockup: bool,
+            dst_delegation in delegation(),
+            clock in clock(),
+        ) {
+            let src_meta = Meta {
+                authorized: if same_authorized { dst_meta.authorized } else { src_meta.authorized },
+                lockup: if same_lockup { dst_meta.lockup } else { src_meta.lockup },
+                ..src_meta
+            };
+            // bootstrap delegations are always fully active
+            let delegation = Delegation {
+                stake: dst_delegation.stake / 2,
+                activation_epoch: Epoch::MAX,
+                deactivation_epoch: Epoch::MAX,
+                ..dst_delegation
+            };
+            let dst = stake_account(dst_meta, delegation, 0);
+            let src = stake_account(src_meta, delegation, 0);
+
+            let res = check_with_history(&dst, &src, &clock, &StakeHistory::default());
+            let expected = if src_meta.authorized != dst_meta.authorized {
+                Err(MergeCheckError::AuthoritiesMismatch)
+            } else if src_meta.lockup != dst_meta.lockup
+                && (dst_meta.lockup.is_in_force(&clock, None) || src_meta.lockup.is_in_force(&clock, None))
+            {
+                Err(MergeCheckError::LockupMismatch)
+            } else {
+                Ok(MergeableStakeKinds {
+                    destination: StakeMergeKind::FullyActive,
+                    source: StakeMergeKind::FullyActive,
+                })
+            };
+            prop_assert_eq!(res, expected);
+        }
+    }
+}
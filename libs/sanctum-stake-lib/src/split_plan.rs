@@ -0,0 +1,292 @@
+use solana_program::{
+    instruction::Instruction,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    stake::{self, instruction::StakeError},
+};
+use solana_readonly_account::{
+    ReadonlyAccountData, ReadonlyAccountLamports, ReadonlyAccountPubkeyBytes,
+};
+use stake_program_interface::{
+    authorize_ix, deactivate_ix, split_ix, AuthorizeIxArgs, SplitIxArgs, StakeAuthorize,
+};
+use system_program_interface::{
+    allocate_ix, assign_ix, create_account_with_seed_ix, transfer_ix, AllocateIxArgs, AllocateKeys,
+    AssignIxArgs, AssignKeys, CreateAccountWithSeedIxArgs, CreateAccountWithSeedKeys,
+    TransferIxArgs, TransferKeys,
+};
+
+use crate::{
+    onchain_rent_exempt_lamports_for_stake_account, AuthorizeFreeAccounts, AuthorizeFreeKeys,
+    DeactivateFreeAccounts, DeactivateFreeKeys, ReadonlyStakeAccount, SplitFreeAccounts,
+    StakeStateMarker, STAKE_ACCOUNT_LEN,
+};
+
+/// The new stake account to split into and how to create it
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SplitDestination {
+    /// A new account at `Pubkey::create_with_seed(base, seed, stake program)`,
+    /// created with `CreateAccountWithSeed`. `base` must sign.
+    Seeded { base: Pubkey, seed: String },
+
+    /// An account that signs for itself, created with `Allocate` + `Assign`
+    /// and topped up to rent-exemption with a `Transfer` if required.
+    ///
+    /// Unlike `CreateAccount`, this works even if the account already holds `lamports`.
+    Keypair { pubkey: Pubkey, lamports: u64 },
+}
+
+impl SplitDestination {
+    pub fn pubkey(&self) -> Result<Pubkey, ProgramError> {
+        match self {
+            Self::Seeded { base, seed } => {
+                Ok(Pubkey::create_with_seed(base, seed, &stake::program::ID)?)
+            }
+            Self::Keypair { pubkey, .. } => Ok(*pubkey),
+        }
+    }
+
+    /// Lamports the account holds before it is created
+    pub fn lamports(&self) -> u64 {
+        match self {
+            Self::Seeded { .. } => 0,
+            Self::Keypair { lamports, .. } => *lamports,
+        }
+    }
+}
+
+/// Plans the instructions that carve `lamports` off `stake` into a new stake account:
+/// 1. create the new 200-byte stake account, with its rent-exempt reserve paid for by `payer`
+/// 2. `Split`
+/// 3. `Deactivate`, if `deactivate`
+/// 4. `Authorize`, for each of `new_staker` and `new_withdrawer` that is set
+///
+/// The new account is always prefunded with its rent-exempt reserve,
+/// as required for splitting active stake, so all of `lamports` is delegated.
+///
+/// Deactivation comes before authorizing so that it is still signed by the current staker.
+#[derive(Clone, Debug)]
+pub struct SplitPlanner<S> {
+    pub stake: S,
+    pub destination: SplitDestination,
+
+    /// Pays for the new account's rent-exempt reserve
+    pub payer: Pubkey,
+
+    /// Lamports to split off `stake` into the new account
+    pub lamports: u64,
+
+    /// The stake program's current minimum delegation
+    pub minimum_delegation: u64,
+
+    pub deactivate: bool,
+    pub new_staker: Option<Pubkey>,
+    pub new_withdrawer: Option<Pubkey>,
+}
+
+/// Balances resulting from a valid [`SplitPlanner`] plan
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SplitAmounts {
+    /// Lamports `payer` transfers to the new account to make it rent-exempt
+    pub rent_lamports: u64,
+
+    /// Delegated stake of the new account. 0 if `stake` is not delegated
+    pub split_stake: u64,
+
+    /// Lamports left in `stake` after the split. 0 if all of it is split off
+    pub remaining_lamports: u64,
+}
+
+impl<S: ReadonlyAccountData + ReadonlyAccountLamports + ReadonlyAccountPubkeyBytes>
+    SplitPlanner<S>
+{
+    /// Checks the split against the stake program's rent-exempt reserve, minimum delegation
+    /// and remaining balance constraints, returning the same errors it would.
+    ///
+    /// `rent_exempt_lamports` is the rent-exempt minimum of a [`STAKE_ACCOUNT_LEN`] account.
+    pub fn split_amounts(&self, rent_exempt_lamports: u64) -> Result<SplitAmounts, ProgramError> {
+        let s = ReadonlyStakeAccount(&self.stake);
+        let s = s.try_into_valid()?;
+        let is_delegated = s.stake_state_marker() == StakeStateMarker::Stake;
+        let s = s.try_into_stake_or_initialized()?;
+        let source_lamports = self.stake.lamports();
+        if self.lamports == 0 || self.lamports > source_lamports {
+            return Err(ProgramError::InsufficientFunds);
+        }
+        let remaining_lamports = source_lamports - self.lamports;
+        let additional_required_lamports = if is_delegated {
+            self.minimum_delegation
+        } else {
+            0
+        };
+        if remaining_lamports != 0
+            && remaining_lamports
+                < s.stake_meta_rent_exempt_reserve()
+                    .saturating_add(additional_required_lamports)
+        {
+            return Err(ProgramError::InsufficientFunds);
+        }
+
+        let rent_lamports = rent_exempt_lamports.saturating_sub(self.destination.lamports());
+        let destination_lamports = self.destination.lamports().saturating_add(rent_lamports);
+        let destination_deficit = rent_exempt_lamports
+            .saturating_add(additional_required_lamports)
+            .saturating_sub(destination_lamports);
+        if self.lamports < destination_deficit {
+            return Err(ProgramError::InsufficientFunds);
+        }
+
+        if !is_delegated {
+            if self.deactivate {
+                return Err(ProgramError::InvalidAccountData);
+            }
+            return Ok(SplitAmounts {
+                rent_lamports,
+                split_stake: 0,
+                remaining_lamports,
+            });
+        }
+
+        let s = s.try_into_stake()?;
+        let delegated_stake = s.stake_stake_delegation_stake();
+        let (remaining_stake_delta, split_stake) = if remaining_lamports == 0 {
+            // full split, source's rent_exempt_reserve is not staked
+            let d = self
+                .lamports
+                .saturating_sub(s.as_stake_or_initialized().stake_meta_rent_exempt_reserve());
+            (d, d)
+        } else {
+            if delegated_stake.saturating_sub(self.lamports) < self.minimum_delegation {
+                return Err(ProgramError::Custom(
+                    StakeError::InsufficientDelegation as u32,
+                ));
+            }
+            (self.lamports, self.lamports)
+        };
+        if split_stake < self.minimum_delegation {
+            return Err(ProgramError::Custom(
+                StakeError::InsufficientDelegation as u32,
+            ));
+        }
+        if remaining_stake_delta > delegated_stake {
+            return Err(ProgramError::Custom(StakeError::InsufficientStake as u32));
+        }
+        Ok(SplitAmounts {
+            rent_lamports,
+            split_stake,
+            remaining_lamports,
+        })
+    }
+
+    /// [`Self::split_amounts`] with the rent-exempt reserve from the `Rent` sysvar
+    pub fn onchain_split_amounts(&self) -> Result<SplitAmounts, ProgramError> {
+        self.split_amounts(onchain_rent_exempt_lamports_for_stake_account()?)
+    }
+
+    /// `rent_exempt_lamports` is the rent-exempt minimum of a [`STAKE_ACCOUNT_LEN`] account.
+    pub fn ixs(&self, rent_exempt_lamports: u64) -> Result<Vec<Instruction>, ProgramError> {
+        let SplitAmounts { rent_lamports, .. } = self.split_amounts(rent_exempt_lamports)?;
+        let to = self.destination.pubkey()?;
+        let space = STAKE_ACCOUNT_LEN as u64;
+
+        let mut res = match &self.destination {
+            SplitDestination::Seeded { base, seed } => vec![create_account_with_seed_ix(
+                CreateAccountWithSeedKeys {
+                    from: self.payer,
+                    to,
+                    base: *base,
+                },
+                CreateAccountWithSeedIxArgs {
+                    base: *base,
+                    seed: seed.clone(),
+                    lamports: rent_lamports,
+                    space,
+                    owner: stake::program::ID,
+                },
+            )],
+            SplitDestination::Keypair { .. } => {
+                let mut ixs = Vec::with_capacity(3);
+                if rent_lamports > 0 {
+                    ixs.push(transfer_ix(
+                        TransferKeys {
+                            from: self.payer,
+                            to,
+                        },
+                        TransferIxArgs {
+                            lamports: rent_lamports,
+                        },
+                    ));
+                }
+                ixs.push(allocate_ix(
+                    AllocateKeys { allocate: to },
+                    AllocateIxArgs { space },
+                ));
+                ixs.push(assign_ix(
+                    AssignKeys { assign: to },
+                    AssignIxArgs {
+                        owner: stake::program::ID,
+                    },
+                ));
+                ixs
+            }
+        };
+
+        res.push(split_ix(
+            SplitFreeAccounts {
+                from: &self.stake,
+                to,
+            }
+            .resolve()?,
+            SplitIxArgs {
+                lamports: self.lamports,
+            },
+        ));
+
+        // the new account has the same authorities as the source
+        if self.deactivate {
+            let DeactivateFreeKeys {
+                stake_authority, ..
+            } = DeactivateFreeAccounts { stake: &self.stake }.resolve_to_free_keys()?;
+            res.push(deactivate_ix(
+                DeactivateFreeKeys {
+                    stake: to,
+                    stake_authority,
+                }
+                .resolve(),
+            ));
+        }
+        let authorize = AuthorizeFreeAccounts { stake: &self.stake };
+        for (new_authority, stake_authorize, keys) in [
+            (
+                self.new_staker,
+                StakeAuthorize::Staker,
+                authorize.resolve_to_free_keys_staker()?,
+            ),
+            (
+                self.new_withdrawer,
+                StakeAuthorize::Withdrawer,
+                authorize.resolve_to_free_keys_withdrawer()?,
+            ),
+        ] {
+            if let Some(new_authority) = new_authority {
+                res.push(authorize_ix(
+                    AuthorizeFreeKeys {
+                        stake: to,
+                        authority: keys.authority,
+                    }
+                    .resolve(),
+                    AuthorizeIxArgs {
+                        new_authority,
+                        stake_authorize,
+                    },
+                ));
+            }
+        }
+        Ok(res)
+    }
+
+    /// [`Self::ixs`] with the rent-exempt reserve from the `Rent` sysvar
+    pub fn onchain_ixs(&self) -> Result<Vec<Instruction>, ProgramError> {
+        self.ixs(onchain_rent_exempt_lamports_for_stake_account()?)
+    }
+}
@@ -0,0 +1,315 @@
+use core::{cmp::Ordering, iter::FusedIterator};
+
+use solana_program::{clock::Epoch, program_error::ProgramError, stake_history::StakeHistoryEntry};
+use solana_readonly_account::ReadonlyAccountData;
+
+use crate::StakeHistoryGetEntry;
+
+// bincode-serialized Vec<(Epoch, StakeHistoryEntry)>, ordered by descending epoch
+pub const STAKE_HISTORY_LEN_OFFSET: usize = 0;
+pub const STAKE_HISTORY_ENTRIES_OFFSET: usize = STAKE_HISTORY_LEN_OFFSET + 8;
+pub const STAKE_HISTORY_ENTRY_LEN: usize = 32;
+// offsets within each entry
+pub const STAKE_HISTORY_ENTRY_EPOCH_OFFSET: usize = 0;
+pub const STAKE_HISTORY_ENTRY_EFFECTIVE_OFFSET: usize = STAKE_HISTORY_ENTRY_EPOCH_OFFSET + 8;
+pub const STAKE_HISTORY_ENTRY_ACTIVATING_OFFSET: usize = STAKE_HISTORY_ENTRY_EFFECTIVE_OFFSET + 8;
+pub const STAKE_HISTORY_ENTRY_DEACTIVATING_OFFSET: usize =
+    STAKE_HISTORY_ENTRY_ACTIVATING_OFFSET + 8;
+
+/// A possible stake history sysvar account
+///
+/// ## Example
+///
+/// ```rust
+/// use sanctum_stake_lib::{ReadonlyStakeHistory, StakeHistoryGetEntry};
+/// use solana_program::{
+///     account_info::AccountInfo,
+///     entrypoint::ProgramResult
+/// };
+///
+/// pub fn process(account: &AccountInfo) -> ProgramResult {
+///     let account = ReadonlyStakeHistory(account);
+///     let account = account.try_into_valid()?;
+///     if let Some(entry) = account.get_entry(500) {
+///         solana_program::msg!("{}", entry.effective);
+///     }
+///     Ok(())
+/// }
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ReadonlyStakeHistory<T>(pub T);
+
+impl<T> ReadonlyStakeHistory<T> {
+    pub fn as_inner(&self) -> &T {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: ReadonlyAccountData> ReadonlyStakeHistory<T> {
+    /// Checks that the data is long enough to contain all the entries in its encoded length.
+    /// Does not check that the entries are sorted
+    pub fn stake_history_data_is_valid(&self) -> bool {
+        let d = self.0.data();
+        if d.len() < STAKE_HISTORY_ENTRIES_OFFSET {
+            return false;
+        }
+        let len = deser_u64_le_unchecked(&d, STAKE_HISTORY_LEN_OFFSET);
+        usize::try_from(len)
+            .ok()
+            .and_then(|len| len.checked_mul(STAKE_HISTORY_ENTRY_LEN))
+            .and_then(|entries_len| entries_len.checked_add(STAKE_HISTORY_ENTRIES_OFFSET))
+            .is_some_and(|required_len| required_len <= d.len())
+    }
+
+    pub fn try_into_valid(self) -> Result<ValidStakeHistory<T>, ProgramError> {
+        if !self.stake_history_data_is_valid() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(ValidStakeHistory(self))
+    }
+}
+
+impl<T> AsRef<T> for ReadonlyStakeHistory<T> {
+    fn as_ref(&self) -> &T {
+        self.as_inner()
+    }
+}
+
+/// A stake history sysvar account that has been checked to contain valid data.
+///
+/// The only safe way to create this struct is via [`TryFrom<ReadonlyStakeHistory>`]
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ValidStakeHistory<T>(ReadonlyStakeHistory<T>);
+
+impl<T> ValidStakeHistory<T> {
+    pub fn as_readonly(&self) -> &ReadonlyStakeHistory<T> {
+        &self.0
+    }
+
+    pub fn into_readonly(self) -> ReadonlyStakeHistory<T> {
+        self.0
+    }
+}
+
+impl<T: ReadonlyAccountData> ValidStakeHistory<T> {
+    pub fn stake_history_len(&self) -> usize {
+        // valid data guarantees this fits in usize
+        deser_u64_le_unchecked(&self.0.as_inner().data(), STAKE_HISTORY_LEN_OFFSET) as usize
+    }
+
+    pub fn stake_history_is_empty(&self) -> bool {
+        self.stake_history_len() == 0
+    }
+
+    /// Returns the `index`-th entry, entries being ordered by descending epoch
+    pub fn stake_history_entry_at(&self, index: usize) -> Option<(Epoch, StakeHistoryEntry)> {
+        if index >= self.stake_history_len() {
+            return None;
+        }
+        Some(entry_at_unchecked(&self.0.as_inner().data(), index))
+    }
+
+    /// Binary searches for the entry of `epoch`
+    pub fn stake_history_get(&self, epoch: Epoch) -> Option<StakeHistoryEntry> {
+        let d = self.0.as_inner().data();
+        let mut lo = 0;
+        let mut hi = self.stake_history_len();
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            let mid_epoch =
+                deser_u64_le_unchecked(&d, entry_offset(mid) + STAKE_HISTORY_ENTRY_EPOCH_OFFSET);
+            // entries are in descending epoch order
+            match epoch.cmp(&mid_epoch) {
+                Ordering::Equal => return Some(entry_at_unchecked(&d, mid).1),
+                Ordering::Greater => hi = mid,
+                Ordering::Less => lo = mid + 1,
+            }
+        }
+        None
+    }
+
+    /// Iterates through all entries in descending epoch order
+    pub fn stake_history_iter(&self) -> StakeHistoryIter<'_, T> {
+        StakeHistoryIter {
+            stake_history: self,
+            front: 0,
+            back: self.stake_history_len(),
+        }
+    }
+}
+
+impl<T: ReadonlyAccountData> StakeHistoryGetEntry for ValidStakeHistory<T> {
+    fn get_entry(&self, epoch: Epoch) -> Option<StakeHistoryEntry> {
+        self.stake_history_get(epoch)
+    }
+}
+
+impl<T: ReadonlyAccountData> TryFrom<ReadonlyStakeHistory<T>> for ValidStakeHistory<T> {
+    type Error = ProgramError;
+
+    fn try_from(value: ReadonlyStakeHistory<T>) -> Result<Self, Self::Error> {
+        value.try_into_valid()
+    }
+}
+
+impl<T> AsRef<ReadonlyStakeHistory<T>> for ValidStakeHistory<T> {
+    fn as_ref(&self) -> &ReadonlyStakeHistory<T> {
+        self.as_readonly()
+    }
+}
+
+impl<T> From<ValidStakeHistory<T>> for ReadonlyStakeHistory<T> {
+    fn from(value: ValidStakeHistory<T>) -> Self {
+        value.into_readonly()
+    }
+}
+
+/// Iterator over a [`ValidStakeHistory`]'s `(epoch, entry)`s, in descending epoch order
+#[derive(Clone, Copy, Debug)]
+pub struct StakeHistoryIter<'a, T> {
+    stake_history: &'a ValidStakeHistory<T>,
+    front: usize,
+    back: usize,
+}
+
+impl<'a, T: ReadonlyAccountData> Iterator for StakeHistoryIter<'a, T> {
+    type Item = (Epoch, StakeHistoryEntry);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        let res = self.stake_history.stake_history_entry_at(self.front);
+        self.front += 1;
+        res
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T: ReadonlyAccountData> DoubleEndedIterator for StakeHistoryIter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        self.stake_history.stake_history_entry_at(self.back)
+    }
+}
+
+impl<'a, T: ReadonlyAccountData> ExactSizeIterator for StakeHistoryIter<'a, T> {}
+
+impl<'a, T: ReadonlyAccountData> FusedIterator for StakeHistoryIter<'a, T> {}
+
+fn entry_offset(index: usize) -> usize {
+    STAKE_HISTORY_ENTRIES_OFFSET + index * STAKE_HISTORY_ENTRY_LEN
+}
+
+fn entry_at_unchecked(d: &[u8], index: usize) -> (Epoch, StakeHistoryEntry) {
+    let offset = entry_offset(index);
+    (
+        deser_u64_le_unchecked(d, offset + STAKE_HISTORY_ENTRY_EPOCH_OFFSET),
+        StakeHistoryEntry {
+            effective: deser_u64_le_unchecked(d, offset + STAKE_HISTORY_ENTRY_EFFECTIVE_OFFSET),
+            activating: deser_u64_le_unchecked(d, offset + STAKE_HISTORY_ENTRY_ACTIVATING_OFFSET),
+            deactivating: deser_u64_le_unchecked(
+                d,
+                offset + STAKE_HISTORY_ENTRY_DEACTIVATING_OFFSET,
+            ),
+        },
+    )
+}
+
+fn deser_u64_le_unchecked(d: &[u8], offset: usize) -> u64 {
+    let b: &[u8; 8] = d[offset..offset + 8].try_into().unwrap();
+    u64::from_le_bytes(*b)
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{collection::vec, prelude::*};
+    use solana_program::{
+        stake_history::{StakeHistory, MAX_ENTRIES},
+        sysvar::Sysvar,
+    };
+
+    use super::*;
+
+    struct AccountData<'a>(pub &'a [u8]);
+
+    impl<'a> ReadonlyAccountData for AccountData<'a> {
+        type DataDeref<'d>
+            = &'d [u8]
+        where
+            Self: 'd;
+
+        fn data(&self) -> Self::DataDeref<'_> {
+            self.0
+        }
+    }
+
+    fn stake_history() -> impl Strategy<Value = StakeHistory> {
+        vec(
+            (any::<u64>(), any::<u64>(), any::<u64>(), any::<u64>()),
+            0..=MAX_ENTRIES + 1,
+        )
+        .prop_map(|entries| {
+            let mut res = StakeHistory::default();
+            for (epoch, effective, activating, deactivating) in entries {
+                res.add(
+                    epoch,
+                    StakeHistoryEntry {
+                        effective,
+                        activating,
+                        deactivating,
+                    },
+                );
+            }
+            res
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn stake_history_readonly_matches_full_deser(stake_history in stake_history(), search_epochs in vec(any::<u64>(), 0..=8)) {
+            let mut data = vec![0u8; StakeHistory::size_of()];
+            bincode::serialize_into(data.as_mut_slice(), &stake_history).unwrap();
+            let expected: StakeHistory = bincode::deserialize(&data).unwrap();
+
+            let account = ReadonlyStakeHistory(AccountData(&data));
+            prop_assert!(account.stake_history_data_is_valid());
+            let account = account.try_into_valid().unwrap();
+
+            prop_assert_eq!(account.stake_history_len(), expected.len());
+            prop_assert_eq!(account.stake_history_is_empty(), expected.is_empty());
+            prop_assert!(account.stake_history_iter().eq(expected.iter().cloned()));
+            prop_assert!(account.stake_history_iter().rev().eq(expected.iter().rev().cloned()));
+            prop_assert_eq!(account.stake_history_entry_at(expected.len()), None);
+            for (epoch, entry) in expected.iter() {
+                prop_assert_eq!(account.get_entry(*epoch), Some(entry.clone()));
+            }
+            for epoch in search_epochs {
+                prop_assert_eq!(account.get_entry(epoch), expected.get(epoch).cloned());
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn stake_history_readonly_rejects_truncated(stake_history in stake_history(), truncate_by in 1..=STAKE_HISTORY_ENTRY_LEN) {
+            prop_assume!(!stake_history.is_empty());
+            let data = bincode::serialize(&stake_history).unwrap();
+            let data = &data[..data.len() - truncate_by];
+            prop_assert!(!ReadonlyStakeHistory(AccountData(data)).stake_history_data_is_valid());
+        }
+    }
+}
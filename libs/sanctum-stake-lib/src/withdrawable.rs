@@ -0,0 +1,300 @@
+use solana_program::{
+    clock::{Clock, Epoch},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    stake::instruction::StakeError,
+};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountLamports};
+
+use crate::{StakeHistoryGetEntry, StakeStateMarker, ValidStakeAccount};
+
+/// Breakdown of a stake account's lamports for the `Withdraw` instruction.
+///
+/// `locked + staked + reserve + free - shortfall` is always the account's lamports.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct StakeWithdrawableLamports {
+    /// Lamports that could otherwise be withdrawn but are held by a lockup in force
+    pub locked: u64,
+
+    /// Lamports the stake program counts as staked: the delegated stake before deactivation,
+    /// the effective stake from the deactivation epoch onwards
+    pub staked: u64,
+
+    /// `rent_exempt_reserve`. Can only be withdrawn by closing the account,
+    /// which is only possible if `staked == 0`.
+    /// Counted in `locked` instead if `staked == 0` and lockup is in force
+    pub reserve: u64,
+
+    /// Lamports that can be withdrawn without closing the account
+    pub free: u64,
+
+    /// Lamports the account is short of `staked + reserve`.
+    /// If nonzero, every `Withdraw` that does not close the account fails, even of 0 lamports
+    pub shortfall: u64,
+
+    /// If set, the stake program fails every `Withdraw`, even of 0 lamports, with `LockupInForce`
+    pub lockup_in_force: bool,
+}
+
+impl StakeWithdrawableLamports {
+    /// `staked` is the staked lamports as computed by the stake program's `withdraw()`
+    pub(crate) fn new(
+        lamports: u64,
+        staked: u64,
+        rent_exempt_reserve: u64,
+        lockup_in_force: bool,
+    ) -> Self {
+        let required = staked.saturating_add(rent_exempt_reserve);
+        let rest = lamports.saturating_sub(required);
+        let shortfall = required.saturating_sub(lamports);
+        match (lockup_in_force, staked) {
+            (false, _) => Self {
+                locked: 0,
+                staked,
+                reserve: rent_exempt_reserve,
+                free: rest,
+                shortfall,
+                lockup_in_force,
+            },
+            (true, 0) => Self {
+                locked: lamports,
+                staked,
+                reserve: 0,
+                free: 0,
+                shortfall: 0,
+                lockup_in_force,
+            },
+            (true, _) => Self {
+                locked: rest,
+                staked,
+                reserve: rent_exempt_reserve,
+                free: 0,
+                shortfall,
+                lockup_in_force,
+            },
+        }
+    }
+
+    /// Whether the account can be closed by withdrawing exactly [`Self::total_lamports`]
+    pub const fn is_closable(&self) -> bool {
+        !self.lockup_in_force && self.staked == 0
+    }
+
+    /// Max lamports that can be withdrawn right now.
+    ///
+    /// Equal to the account's lamports if the account can be closed,
+    /// but the only valid amounts are then `0..=free` and exactly the account's lamports:
+    /// a partial withdrawal must leave `reserve` in the account,
+    /// so an account with nonzero `shortfall` can only be closed.
+    /// See [`Self::check_withdraw`]
+    pub const fn max_withdrawable_lamports(&self) -> u64 {
+        if self.is_closable() {
+            self.total_lamports()
+        } else {
+            self.free
+        }
+    }
+
+    pub const fn total_lamports(&self) -> u64 {
+        self.locked + self.free + (self.staked.saturating_add(self.reserve) - self.shortfall)
+    }
+
+    /// Returns the error the stake program would fail a `Withdraw` of `lamports` with, if any.
+    ///
+    /// Lockup is checked before balance, so this is `LockupInForce` for every amount if lockup is in force.
+    pub fn check_withdraw(&self, lamports: u64) -> Result<(), ProgramError> {
+        if self.lockup_in_force {
+            return Err(ProgramError::Custom(StakeError::LockupInForce as u32));
+        }
+        let total = self.total_lamports();
+        let lamports_and_reserve = self
+            .staked
+            .checked_add(self.reserve)
+            .and_then(|reserve| reserve.checked_add(lamports))
+            .ok_or(ProgramError::InsufficientFunds)?;
+        if lamports_and_reserve <= total || (self.is_closable() && lamports == total) {
+            Ok(())
+        } else {
+            Err(ProgramError::InsufficientFunds)
+        }
+    }
+}
+
+/// Computes the [`StakeWithdrawableLamports`] of `stake` at `clock`.
+///
+/// `custodian` is the lockup custodian that will sign the `Withdraw` instruction, if any.
+///
+/// Errors with `InvalidAccountData` if `stake` is a rewards pool.
+pub fn stake_withdrawable_lamports<T, H>(
+    stake: ValidStakeAccount<T>,
+    clock: &Clock,
+    stake_history: H,
+    new_rate_activation_epoch: Option<Epoch>,
+    custodian: Option<Pubkey>,
+) -> Result<StakeWithdrawableLamports, ProgramError>
+where
+    T: ReadonlyAccountData + ReadonlyAccountLamports,
+    H: StakeHistoryGetEntry,
+{
+    let lamports = stake.as_readonly().as_inner().lamports();
+    match stake.stake_state_marker() {
+        // withdraw authority is the stake account itself
+        StakeStateMarker::Uninitialized => {
+            return Ok(StakeWithdrawableLamports {
+                free: lamports,
+                ..Default::default()
+            })
+        }
+        StakeStateMarker::RewardsPool => return Err(ProgramError::InvalidAccountData),
+        StakeStateMarker::Initialized | StakeStateMarker::Stake => (),
+    }
+
+    // checked Initialized or Stake above
+    let stake = stake.try_into_stake_or_initialized().unwrap();
+    let lockup_in_force = stake.stake_lockup_is_in_force(clock)
+        && custodian != Some(stake.stake_meta_lockup_custodian());
+    let rent_exempt_reserve = stake.stake_meta_rent_exempt_reserve();
+
+    let staked = if stake.as_valid().stake_state_marker() == StakeStateMarker::Initialized {
+        0
+    } else {
+        let stake = stake.try_into_stake().unwrap();
+        if clock.epoch >= stake.stake_stake_delegation_deactivation_epoch() {
+            stake
+                .stake_activating_and_deactivating(
+                    clock.epoch,
+                    stake_history,
+                    new_rate_activation_epoch,
+                )
+                .effective
+        } else {
+            // assume full stake if not yet deactivated,
+            // effective stake may be less than delegated stake during warmup
+            stake.stake_stake_delegation_stake()
+        }
+    };
+
+    Ok(StakeWithdrawableLamports::new(
+        lamports,
+        staked,
+        rent_exempt_reserve,
+        lockup_in_force,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    /// The balance checks of the stake program's `withdraw()`
+    fn onchain_withdraw(
+        lamports: u64,
+        staked: u64,
+        rent_exempt_reserve: u64,
+        lockup_in_force: bool,
+        withdraw_lamports: u64,
+    ) -> Result<(), ProgramError> {
+        if lockup_in_force {
+            return Err(ProgramError::Custom(StakeError::LockupInForce as u32));
+        }
+        let reserve = staked + rent_exempt_reserve;
+        let lamports_and_reserve = withdraw_lamports + reserve;
+        if staked != 0 && lamports_and_reserve > lamports {
+            return Err(ProgramError::InsufficientFunds);
+        }
+        if withdraw_lamports != lamports && lamports_and_reserve > lamports {
+            return Err(ProgramError::InsufficientFunds);
+        }
+        if withdraw_lamports > lamports {
+            return Err(ProgramError::InsufficientFunds);
+        }
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn breakdown_partitions_lamports(
+            lamports: u64,
+            staked: u64,
+            rent_exempt_reserve: u64,
+            lockup_in_force: bool,
+        ) {
+            let w = StakeWithdrawableLamports::new(lamports, staked, rent_exempt_reserve, lockup_in_force);
+            prop_assert_eq!(w.total_lamports(), lamports);
+            let max = w.max_withdrawable_lamports();
+            if lockup_in_force {
+                prop_assert_eq!(max, 0);
+            } else if staked == 0 {
+                prop_assert_eq!(max, lamports);
+            } else {
+                prop_assert_eq!(max, lamports.saturating_sub(staked.saturating_add(rent_exempt_reserve)));
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn check_withdraw_matches_onchain(
+            lamports in 0..=u64::MAX / 4,
+            staked in 0..=u64::MAX / 4,
+            rent_exempt_reserve in 0..=u64::MAX / 4,
+            lockup_in_force: bool,
+            withdraw_lamports in 0..=u64::MAX / 4,
+        ) {
+            let w = StakeWithdrawableLamports::new(lamports, staked, rent_exempt_reserve, lockup_in_force);
+            let free = w.free;
+            let total = w.total_lamports();
+            let cases = [
+                withdraw_lamports,
+                0,
+                free,
+                free + 1,
+                total.saturating_sub(1),
+                total,
+                total + 1,
+            ];
+            for withdraw_lamports in cases {
+                prop_assert_eq!(
+                    w.check_withdraw(withdraw_lamports),
+                    onchain_withdraw(lamports, staked, rent_exempt_reserve, lockup_in_force, withdraw_lamports),
+                    "{}", withdraw_lamports
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn inactive_partial_withdraw_must_leave_reserve() {
+        let w = StakeWithdrawableLamports::new(10, 0, 3, false);
+        assert_eq!(w.max_withdrawable_lamports(), 10);
+        assert_eq!(w.check_withdraw(7), Ok(()));
+        assert_eq!(w.check_withdraw(8), Err(ProgramError::InsufficientFunds));
+        assert_eq!(w.check_withdraw(9), Err(ProgramError::InsufficientFunds));
+        assert_eq!(w.check_withdraw(10), Ok(()));
+    }
+
+    #[test]
+    fn underfunded_inactive_can_only_close() {
+        let w = StakeWithdrawableLamports::new(2, 0, 3, false);
+        assert_eq!(w.shortfall, 1);
+        assert_eq!(w.max_withdrawable_lamports(), 2);
+        assert_eq!(w.check_withdraw(0), Err(ProgramError::InsufficientFunds));
+        assert_eq!(w.check_withdraw(1), Err(ProgramError::InsufficientFunds));
+        assert_eq!(w.check_withdraw(2), Ok(()));
+    }
+
+    #[test]
+    fn lockup_in_force_fails_every_withdraw() {
+        // staked + reserve only, nothing locked
+        let w = StakeWithdrawableLamports::new(10, 7, 3, true);
+        assert_eq!(w.locked, 0);
+        for lamports in [0, 1, 10] {
+            assert_eq!(
+                w.check_withdraw(lamports),
+                Err(ProgramError::Custom(StakeError::LockupInForce as u32))
+            );
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use sanctum_solana_test_utils::{
+    est_rent_exempt_lamports,
+    stake::{LiveStakeAccountParams, SingleAuthorityAuthorized, StakeProgramTest},
+    ExtendedProgramTest,
+};
+use sanctum_stake_lib::deactivate_delinquent_check;
+use solana_program::{
+    clock::Epoch,
+    instruction::InstructionError,
+    native_token::LAMPORTS_PER_SOL,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    stake::instruction::StakeError,
+    vote::{
+        self,
+        state::{VoteState, VoteStateVersions},
+    },
+};
+use solana_program_test::{BanksClient, ProgramTest};
+use solana_readonly_account::keyed::Keyed;
+use solana_sdk::{
+    account::Account, signer::Signer, transaction::Transaction, transaction::TransactionError,
+};
+use stake_program_interface::{deactivate_delinquent_ix, DeactivateDelinquentKeys};
+
+const WARP_TO_EPOCH: Epoch = 10;
+
+fn vote_account(epoch_credits_epochs: impl IntoIterator<Item = Epoch>) -> Account {
+    let mut vote_state = VoteState::default();
+    vote_state.node_pubkey = Pubkey::new_unique();
+    vote_state.epoch_credits = epoch_credits_epochs
+        .into_iter()
+        .scan(0, |credits, epoch| {
+            let prev_credits = *credits;
+            *credits += 1;
+            Some((epoch, *credits, prev_credits))
+        })
+        .collect();
+    let mut data = vec![0u8; VoteState::size_of()];
+    VoteState::serialize(&VoteStateVersions::new_current(vote_state), &mut data).unwrap();
+    Account {
+        lamports: est_rent_exempt_lamports(data.len()),
+        data,
+        owner: vote::program::ID,
+        executable: false,
+        rent_epoch: u64::MAX,
+    }
+}
+
+fn add_stake_delegated_to(pt: ProgramTest, stake: Pubkey, voter: Pubkey) -> ProgramTest {
+    pt.add_live_stake_account(
+        stake,
+        LiveStakeAccountParams {
+            staked_lamports: LAMPORTS_PER_SOL,
+            voter,
+            authorized: SingleAuthorityAuthorized(Pubkey::new_unique()).into(),
+            activation_epoch: 0,
+            deactivation_epoch: u64::MAX,
+            lockup: Default::default(),
+            credits_observed: 0,
+        },
+    )
+}
+
+async fn keyed_account(banks_client: &mut BanksClient, pubkey: Pubkey) -> Keyed<Account> {
+    Keyed {
+        pubkey,
+        account: banks_client.get_account(pubkey).await.unwrap().unwrap(),
+    }
+}
+
+fn stake_err(err: StakeError) -> ProgramError {
+    ProgramError::Custom(err as u32)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn deactivate_delinquent_check_matches_stake_program() {
+    let delinquent_vote = Pubkey::new_unique();
+    let healthy_vote = Pubkey::new_unique();
+    let reference_vote = Pubkey::new_unique();
+    let bad_reference_vote = Pubkey::new_unique();
+    let not_vote = Pubkey::new_unique();
+    let delinquent_stake = Pubkey::new_unique();
+    let healthy_stake = Pubkey::new_unique();
+
+    let pt = ProgramTest::default()
+        // last voted MINIMUM_DELINQUENT_EPOCHS_FOR_DEACTIVATION epochs ago
+        .add_account_chained(delinquent_vote, vote_account(0..=WARP_TO_EPOCH - 5))
+        .add_account_chained(healthy_vote, vote_account(0..=WARP_TO_EPOCH - 4))
+        .add_account_chained(reference_vote, vote_account(0..=WARP_TO_EPOCH))
+        // missed an epoch in the last MINIMUM_DELINQUENT_EPOCHS_FOR_DEACTIVATION epochs
+        .add_account_chained(
+            bad_reference_vote,
+            vote_account((0..=WARP_TO_EPOCH).filter(|e| *e != WARP_TO_EPOCH - 2)),
+        )
+        .add_system_account(not_vote, LAMPORTS_PER_SOL);
+    let pt = add_stake_delegated_to(pt, delinquent_stake, delinquent_vote);
+    let pt = add_stake_delegated_to(pt, healthy_stake, healthy_vote);
+    let mut ctx = pt.start_with_context().await;
+    ctx.warp_to_epoch(WARP_TO_EPOCH).unwrap();
+    // stake accounts cannot be written to during the epoch rewards distribution period
+    ctx.warp_forward_force_reward_interval_end().unwrap();
+
+    for (stake, vote, reference_vote, expected) in [
+        (
+            healthy_stake,
+            healthy_vote,
+            reference_vote,
+            Err(stake_err(
+                StakeError::MinimumDelinquentEpochsForDeactivationNotMet,
+            )),
+        ),
+        (
+            delinquent_stake,
+            delinquent_vote,
+            bad_reference_vote,
+            Err(stake_err(StakeError::InsufficientReferenceVotes)),
+        ),
+        (
+            healthy_stake,
+            delinquent_vote,
+            reference_vote,
+            Err(stake_err(StakeError::VoteAddressMismatch)),
+        ),
+        (
+            delinquent_stake,
+            delinquent_vote,
+            not_vote,
+            Err(ProgramError::IncorrectProgramId),
+        ),
+        (delinquent_stake, delinquent_vote, reference_vote, Ok(())),
+        (
+            delinquent_stake,
+            delinquent_vote,
+            reference_vote,
+            Err(stake_err(StakeError::AlreadyDeactivated)),
+        ),
+    ] {
+        let check = deactivate_delinquent_check(
+            keyed_account(&mut ctx.banks_client, stake).await,
+            keyed_account(&mut ctx.banks_client, vote).await,
+            keyed_account(&mut ctx.banks_client, reference_vote).await,
+            WARP_TO_EPOCH,
+        );
+        assert_eq!(check, expected);
+
+        let ix = deactivate_delinquent_ix(DeactivateDelinquentKeys {
+            stake,
+            vote,
+            reference_vote,
+        });
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&ctx.payer.pubkey()));
+        let blockhash = ctx.get_new_latest_blockhash().await.unwrap();
+        tx.sign(&[&ctx.payer], blockhash);
+        let res = ctx.banks_client.process_transaction(tx).await;
+        match expected {
+            Ok(()) => res.unwrap(),
+            Err(e) => assert_eq!(
+                res.unwrap_err().unwrap(),
+                TransactionError::InstructionError(0, InstructionError::from(u64::from(e)))
+            ),
+        }
+    }
+}
@@ -0,0 +1,3 @@
+mod deactivate_delinquent;
+mod split_plan;
+mod withdraw;
@@ -0,0 +1,279 @@
+use sanctum_solana_test_utils::{
+    est_rent_exempt_lamports,
+    stake::{
+        LiveStakeAccountParams, SingleAuthorityAuthorized, StakeProgramTest, StakeStateAndLamports,
+    },
+    ExtendedProgramTest, IntoAccount,
+};
+use sanctum_stake_lib::{SplitAmounts, SplitDestination, SplitPlanner, STAKE_ACCOUNT_LEN};
+use solana_program::{
+    native_token::LAMPORTS_PER_SOL,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    stake::{
+        instruction::StakeError,
+        stake_flags::StakeFlags,
+        state::{Delegation, Meta, Stake, StakeStateV2},
+    },
+};
+use solana_program_test::{BanksClient, ProgramTest, ProgramTestContext};
+use solana_readonly_account::keyed::Keyed;
+use solana_sdk::{account::Account, signature::Keypair, signer::Signer, transaction::Transaction};
+
+const STAKED_LAMPORTS: u64 = 10 * LAMPORTS_PER_SOL;
+
+const SPLIT_LAMPORTS: u64 = 3 * LAMPORTS_PER_SOL;
+
+const WARP_TO_EPOCH: u64 = 2;
+
+// ProgramTest raises the stake program's minimum delegation to 1 SOL
+const MINIMUM_DELEGATION: u64 = LAMPORTS_PER_SOL;
+
+async fn program_test_with_active_stake(
+    stake: Pubkey,
+    authority: Pubkey,
+    pt: ProgramTest,
+) -> ProgramTestContext {
+    let pt = pt.add_live_stake_account(
+        stake,
+        LiveStakeAccountParams {
+            staked_lamports: STAKED_LAMPORTS,
+            voter: Pubkey::new_unique(),
+            authorized: SingleAuthorityAuthorized(authority).into(),
+            activation_epoch: 0,
+            deactivation_epoch: u64::MAX,
+            lockup: Default::default(),
+            credits_observed: 0,
+        },
+    );
+    let mut ctx = pt.start_with_context().await;
+    ctx.warp_to_epoch(WARP_TO_EPOCH).unwrap();
+    // stake accounts cannot be written to during the epoch rewards distribution period
+    ctx.warp_forward_force_reward_interval_end().unwrap();
+    ctx
+}
+
+async fn keyed_account(banks_client: &mut BanksClient, pubkey: Pubkey) -> Keyed<Account> {
+    Keyed {
+        pubkey,
+        account: banks_client.get_account(pubkey).await.unwrap().unwrap(),
+    }
+}
+
+async fn stake_state(banks_client: &mut BanksClient, pubkey: Pubkey) -> StakeStateV2 {
+    bincode::deserialize(&keyed_account(banks_client, pubkey).await.account.data).unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn split_to_seeded_deactivate_and_authorize() {
+    let stake = Pubkey::new_unique();
+    let authority = Keypair::new();
+    let new_staker = Pubkey::new_unique();
+    let mut ctx =
+        program_test_with_active_stake(stake, authority.pubkey(), ProgramTest::default()).await;
+    let rent_exempt_lamports = ctx
+        .banks_client
+        .get_rent()
+        .await
+        .unwrap()
+        .minimum_balance(STAKE_ACCOUNT_LEN);
+
+    let planner = SplitPlanner {
+        stake: keyed_account(&mut ctx.banks_client, stake).await,
+        destination: SplitDestination::Seeded {
+            base: ctx.payer.pubkey(),
+            seed: "split".to_owned(),
+        },
+        payer: ctx.payer.pubkey(),
+        lamports: SPLIT_LAMPORTS,
+        minimum_delegation: MINIMUM_DELEGATION,
+        deactivate: true,
+        new_staker: Some(new_staker),
+        new_withdrawer: None,
+    };
+    let stake_rent = est_rent_exempt_lamports(StakeStateV2::size_of());
+    assert_eq!(
+        planner.split_amounts(rent_exempt_lamports).unwrap(),
+        SplitAmounts {
+            rent_lamports: rent_exempt_lamports,
+            split_stake: SPLIT_LAMPORTS,
+            remaining_lamports: STAKED_LAMPORTS + stake_rent - SPLIT_LAMPORTS,
+        }
+    );
+    let ixs = planner.ixs(rent_exempt_lamports).unwrap();
+    assert_eq!(ixs.len(), 4);
+
+    let mut tx = Transaction::new_with_payer(&ixs, Some(&ctx.payer.pubkey()));
+    let blockhash = ctx.get_new_latest_blockhash().await.unwrap();
+    tx.sign(&[&ctx.payer, &authority], blockhash);
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let new_stake = planner.destination.pubkey().unwrap();
+    let StakeStateV2::Stake(meta, new_stake_stake, _) =
+        stake_state(&mut ctx.banks_client, new_stake).await
+    else {
+        panic!("new stake account not delegated")
+    };
+    assert_eq!(new_stake_stake.delegation.stake, SPLIT_LAMPORTS);
+    assert_eq!(new_stake_stake.delegation.deactivation_epoch, WARP_TO_EPOCH);
+    assert_eq!(meta.authorized.staker, new_staker);
+    assert_eq!(meta.authorized.withdrawer, authority.pubkey());
+    assert_eq!(meta.rent_exempt_reserve, rent_exempt_lamports);
+
+    let StakeStateV2::Stake(_, source_stake, _) = stake_state(&mut ctx.banks_client, stake).await
+    else {
+        panic!("source stake account not delegated")
+    };
+    assert_eq!(
+        source_stake.delegation.stake,
+        STAKED_LAMPORTS - SPLIT_LAMPORTS
+    );
+    assert_eq!(source_stake.delegation.deactivation_epoch, u64::MAX);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn split_to_prefunded_keypair() {
+    const PREFUNDED_LAMPORTS: u64 = 1_000_000;
+
+    let stake = Pubkey::new_unique();
+    let authority = Keypair::new();
+    let new_stake = Keypair::new();
+    let new_withdrawer = Pubkey::new_unique();
+    let pt = ProgramTest::default().add_system_account(new_stake.pubkey(), PREFUNDED_LAMPORTS);
+    let mut ctx = program_test_with_active_stake(stake, authority.pubkey(), pt).await;
+    let rent_exempt_lamports = ctx
+        .banks_client
+        .get_rent()
+        .await
+        .unwrap()
+        .minimum_balance(STAKE_ACCOUNT_LEN);
+
+    let planner = SplitPlanner {
+        stake: keyed_account(&mut ctx.banks_client, stake).await,
+        destination: SplitDestination::Keypair {
+            pubkey: new_stake.pubkey(),
+            lamports: PREFUNDED_LAMPORTS,
+        },
+        payer: ctx.payer.pubkey(),
+        lamports: SPLIT_LAMPORTS,
+        minimum_delegation: MINIMUM_DELEGATION,
+        deactivate: false,
+        new_staker: None,
+        new_withdrawer: Some(new_withdrawer),
+    };
+    let SplitAmounts { rent_lamports, .. } = planner.split_amounts(rent_exempt_lamports).unwrap();
+    assert_eq!(rent_lamports, rent_exempt_lamports - PREFUNDED_LAMPORTS);
+
+    let ixs = planner.ixs(rent_exempt_lamports).unwrap();
+    let mut tx = Transaction::new_with_payer(&ixs, Some(&ctx.payer.pubkey()));
+    let blockhash = ctx.get_new_latest_blockhash().await.unwrap();
+    tx.sign(&[&ctx.payer, &authority, &new_stake], blockhash);
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let new_stake_account = keyed_account(&mut ctx.banks_client, new_stake.pubkey()).await;
+    assert_eq!(
+        new_stake_account.account.lamports,
+        rent_exempt_lamports + SPLIT_LAMPORTS
+    );
+    let StakeStateV2::Stake(meta, new_stake_stake, _) =
+        bincode::deserialize(&new_stake_account.account.data).unwrap()
+    else {
+        panic!("new stake account not delegated")
+    };
+    assert_eq!(new_stake_stake.delegation.stake, SPLIT_LAMPORTS);
+    assert_eq!(new_stake_stake.delegation.deactivation_epoch, u64::MAX);
+    assert_eq!(meta.authorized.staker, authority.pubkey());
+    assert_eq!(meta.authorized.withdrawer, new_withdrawer);
+}
+
+fn offchain_stake_account(staked_lamports: u64, total_lamports: u64) -> Keyed<Account> {
+    let stake_rent = est_rent_exempt_lamports(StakeStateV2::size_of());
+    Keyed {
+        pubkey: Pubkey::new_unique(),
+        account: StakeStateAndLamports {
+            stake_state: StakeStateV2::Stake(
+                Meta {
+                    rent_exempt_reserve: stake_rent,
+                    authorized: SingleAuthorityAuthorized(Pubkey::new_unique()).into(),
+                    lockup: Default::default(),
+                },
+                Stake {
+                    delegation: Delegation {
+                        voter_pubkey: Pubkey::new_unique(),
+                        stake: staked_lamports,
+                        ..Default::default()
+                    },
+                    credits_observed: 0,
+                },
+                StakeFlags::empty(),
+            ),
+            total_lamports,
+        }
+        .into_account(),
+    }
+}
+
+#[test]
+fn split_plan_rejects_invalid_amounts() {
+    let stake_rent = est_rent_exempt_lamports(StakeStateV2::size_of());
+    let stake = offchain_stake_account(STAKED_LAMPORTS, STAKED_LAMPORTS + stake_rent);
+    let planner = |stake, lamports| SplitPlanner {
+        stake,
+        destination: SplitDestination::Keypair {
+            pubkey: Pubkey::new_unique(),
+            lamports: 0,
+        },
+        payer: Pubkey::new_unique(),
+        lamports,
+        minimum_delegation: MINIMUM_DELEGATION,
+        deactivate: false,
+        new_staker: None,
+        new_withdrawer: None,
+    };
+
+    for (lamports, expected) in [
+        (0, Err(ProgramError::InsufficientFunds)),
+        (
+            STAKED_LAMPORTS + stake_rent + 1,
+            Err(ProgramError::InsufficientFunds),
+        ),
+        // new account below minimum delegation
+        (MINIMUM_DELEGATION - 1, Err(ProgramError::InsufficientFunds)),
+        // remainder below minimum delegation
+        (
+            STAKED_LAMPORTS - MINIMUM_DELEGATION + 1,
+            Err(ProgramError::InsufficientFunds),
+        ),
+        (
+            STAKED_LAMPORTS - MINIMUM_DELEGATION,
+            Ok(SplitAmounts {
+                rent_lamports: stake_rent,
+                split_stake: STAKED_LAMPORTS - MINIMUM_DELEGATION,
+                remaining_lamports: MINIMUM_DELEGATION + stake_rent,
+            }),
+        ),
+        // full split
+        (
+            STAKED_LAMPORTS + stake_rent,
+            Ok(SplitAmounts {
+                rent_lamports: stake_rent,
+                split_stake: STAKED_LAMPORTS,
+                remaining_lamports: 0,
+            }),
+        ),
+    ] {
+        assert_eq!(
+            planner(&stake, lamports).split_amounts(stake_rent),
+            expected
+        );
+    }
+
+    // enough lamports remaining, but from undelegated excess lamports
+    let excess_lamports = offchain_stake_account(MINIMUM_DELEGATION, STAKED_LAMPORTS + stake_rent);
+    assert_eq!(
+        planner(&excess_lamports, MINIMUM_DELEGATION).split_amounts(stake_rent),
+        Err(ProgramError::Custom(
+            StakeError::InsufficientDelegation as u32
+        ))
+    );
+}
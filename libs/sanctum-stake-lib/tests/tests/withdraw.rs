@@ -0,0 +1,343 @@
+use sanctum_solana_test_utils::{
+    est_rent_exempt_lamports,
+    stake::{SingleAuthorityAuthorized, StakeProgramTest, StakeStateAndLamports},
+    ExtendedProgramTest,
+};
+use sanctum_stake_lib::{
+    stake_withdrawable_lamports, withdraw_by_custodian_ix, ReadonlyStakeAccount,
+    StakeWithdrawableLamports, WithdrawFreeAccounts,
+};
+use solana_program::{
+    clock::Clock,
+    instruction::{Instruction, InstructionError},
+    native_token::LAMPORTS_PER_SOL,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    stake::{
+        instruction::StakeError,
+        stake_flags::StakeFlags,
+        state::{Delegation, Lockup, Meta, Stake, StakeStateV2},
+    },
+    stake_history::StakeHistory,
+};
+use solana_program_test::{BanksClient, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_readonly_account::keyed::Keyed;
+use solana_sdk::{
+    account::Account,
+    signature::Keypair,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use stake_program_interface::{withdraw_ix, WithdrawIxArgs};
+
+const STAKED_LAMPORTS: u64 = 10 * LAMPORTS_PER_SOL;
+
+const EXTRA_LAMPORTS: u64 = LAMPORTS_PER_SOL;
+
+const WARP_TO_EPOCH: u64 = 2;
+
+struct Fixture {
+    ctx: ProgramTestContext,
+    stake: Pubkey,
+    withdrawer: Keypair,
+    custodian: Keypair,
+    to: Pubkey,
+}
+
+/// Active stake account with `EXTRA_LAMPORTS` above its stake and rent-exempt reserve,
+/// locked up until an epoch far after `WARP_TO_EPOCH`
+async fn locked_active_stake_fixture() -> Fixture {
+    stake_fixture(100, u64::MAX).await
+}
+
+/// Stake account with `EXTRA_LAMPORTS` above its stake and rent-exempt reserve,
+/// deactivated in its activation epoch and without lockup
+async fn inactive_stake_fixture() -> Fixture {
+    stake_fixture(0, 0).await
+}
+
+async fn stake_fixture(lockup_epoch: u64, deactivation_epoch: u64) -> Fixture {
+    let stake = Pubkey::new_unique();
+    let withdrawer = Keypair::new();
+    let custodian = Keypair::new();
+    let to = Pubkey::new_unique();
+    let rent_exempt_reserve = est_rent_exempt_lamports(StakeStateV2::size_of());
+    let pt = ProgramTest::default()
+        .add_system_account(to, 0)
+        .add_stake_account(
+            stake,
+            StakeStateAndLamports {
+                stake_state: StakeStateV2::Stake(
+                    Meta {
+                        rent_exempt_reserve,
+                        authorized: SingleAuthorityAuthorized(withdrawer.pubkey()).into(),
+                        lockup: Lockup {
+                            unix_timestamp: 0,
+                            epoch: lockup_epoch,
+                            custodian: custodian.pubkey(),
+                        },
+                    },
+                    Stake {
+                        delegation: Delegation {
+                            voter_pubkey: Pubkey::new_unique(),
+                            stake: STAKED_LAMPORTS,
+                            activation_epoch: 0,
+                            deactivation_epoch,
+                            ..Default::default()
+                        },
+                        credits_observed: 0,
+                    },
+                    StakeFlags::empty(),
+                ),
+                total_lamports: STAKED_LAMPORTS + rent_exempt_reserve + EXTRA_LAMPORTS,
+            },
+        );
+    let mut ctx = pt.start_with_context().await;
+    ctx.warp_to_epoch(WARP_TO_EPOCH).unwrap();
+    // stake accounts cannot be written to during the epoch rewards distribution period
+    ctx.warp_forward_force_reward_interval_end().unwrap();
+    Fixture {
+        ctx,
+        stake,
+        withdrawer,
+        custodian,
+        to,
+    }
+}
+
+async fn keyed_account(banks_client: &mut BanksClient, pubkey: Pubkey) -> Keyed<Account> {
+    Keyed {
+        pubkey,
+        account: banks_client.get_account(pubkey).await.unwrap().unwrap(),
+    }
+}
+
+async fn exec(
+    ctx: &mut ProgramTestContext,
+    ix: Instruction,
+    signers: &[&Keypair],
+) -> Result<(), InstructionError> {
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&ctx.payer.pubkey()));
+    let blockhash = ctx.get_new_latest_blockhash().await.unwrap();
+    let mut all_signers = vec![&ctx.payer];
+    all_signers.extend_from_slice(signers);
+    tx.sign(&all_signers, blockhash);
+    match ctx.banks_client.process_transaction(tx).await {
+        Ok(()) => Ok(()),
+        Err(BanksClientError::TransactionError(TransactionError::InstructionError(0, e))) => Err(e),
+        Err(e) => panic!("unexpected error {e}"),
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn withdraw_checked_matches_onchain() {
+    let Fixture {
+        mut ctx,
+        stake,
+        withdrawer,
+        custodian,
+        to,
+    } = locked_active_stake_fixture().await;
+    let rent_exempt_reserve = est_rent_exempt_lamports(StakeStateV2::size_of());
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let stake_history: StakeHistory = ctx.banks_client.get_sysvar().await.unwrap();
+    let stake_account = keyed_account(&mut ctx.banks_client, stake).await;
+
+    let withdrawable = |custodian| {
+        stake_withdrawable_lamports(
+            ReadonlyStakeAccount(&stake_account)
+                .try_into_valid()
+                .unwrap(),
+            &clock,
+            &stake_history,
+            None,
+            custodian,
+        )
+        .unwrap()
+    };
+    assert_eq!(
+        withdrawable(None),
+        StakeWithdrawableLamports {
+            locked: EXTRA_LAMPORTS,
+            staked: STAKED_LAMPORTS,
+            reserve: rent_exempt_reserve,
+            free: 0,
+            shortfall: 0,
+            lockup_in_force: true,
+        }
+    );
+    assert_eq!(
+        withdrawable(Some(custodian.pubkey())),
+        StakeWithdrawableLamports {
+            locked: 0,
+            staked: STAKED_LAMPORTS,
+            reserve: rent_exempt_reserve,
+            free: EXTRA_LAMPORTS,
+            shortfall: 0,
+            lockup_in_force: false,
+        }
+    );
+
+    let free_accounts = WithdrawFreeAccounts {
+        from: &stake_account,
+        to,
+    };
+    let resolve = |lamports, custodian| {
+        free_accounts.resolve_to_free_keys_checked(
+            lamports,
+            &clock,
+            &stake_history,
+            None,
+            custodian,
+        )
+    };
+
+    assert_eq!(
+        resolve(EXTRA_LAMPORTS, None),
+        Err(ProgramError::Custom(StakeError::LockupInForce as u32))
+    );
+    let free_keys = free_accounts.resolve_to_free_keys().unwrap();
+    let keys = free_keys.resolve();
+    assert_eq!(keys.withdraw_authority, withdrawer.pubkey());
+    assert_eq!(
+        exec(
+            &mut ctx,
+            withdraw_ix(
+                keys,
+                WithdrawIxArgs {
+                    lamports: EXTRA_LAMPORTS
+                }
+            ),
+            &[&withdrawer]
+        )
+        .await,
+        Err(InstructionError::Custom(StakeError::LockupInForce as u32))
+    );
+
+    assert_eq!(
+        resolve(EXTRA_LAMPORTS + 1, Some(custodian.pubkey())),
+        Err(ProgramError::InsufficientFunds)
+    );
+    assert_eq!(
+        exec(
+            &mut ctx,
+            withdraw_by_custodian_ix(
+                keys,
+                WithdrawIxArgs {
+                    lamports: EXTRA_LAMPORTS + 1
+                },
+                custodian.pubkey()
+            ),
+            &[&withdrawer, &custodian]
+        )
+        .await,
+        Err(InstructionError::InsufficientFunds)
+    );
+
+    assert_eq!(
+        resolve(EXTRA_LAMPORTS, Some(custodian.pubkey())),
+        Ok(free_keys)
+    );
+    assert_eq!(
+        exec(
+            &mut ctx,
+            withdraw_by_custodian_ix(
+                keys,
+                WithdrawIxArgs {
+                    lamports: EXTRA_LAMPORTS
+                },
+                custodian.pubkey()
+            ),
+            &[&withdrawer, &custodian]
+        )
+        .await,
+        Ok(())
+    );
+    let stake_account = keyed_account(&mut ctx.banks_client, stake).await;
+    assert_eq!(
+        stake_account.account.lamports,
+        STAKED_LAMPORTS + rent_exempt_reserve
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn withdraw_checked_inactive_partial_must_leave_reserve() {
+    let Fixture {
+        mut ctx,
+        stake,
+        withdrawer,
+        to,
+        ..
+    } = inactive_stake_fixture().await;
+    let rent_exempt_reserve = est_rent_exempt_lamports(StakeStateV2::size_of());
+    let total_lamports = STAKED_LAMPORTS + rent_exempt_reserve + EXTRA_LAMPORTS;
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let stake_history: StakeHistory = ctx.banks_client.get_sysvar().await.unwrap();
+    let stake_account = keyed_account(&mut ctx.banks_client, stake).await;
+
+    let withdrawable = stake_withdrawable_lamports(
+        ReadonlyStakeAccount(&stake_account)
+            .try_into_valid()
+            .unwrap(),
+        &clock,
+        &stake_history,
+        None,
+        None,
+    )
+    .unwrap();
+    assert_eq!(
+        withdrawable,
+        StakeWithdrawableLamports {
+            locked: 0,
+            staked: 0,
+            reserve: rent_exempt_reserve,
+            free: STAKED_LAMPORTS + EXTRA_LAMPORTS,
+            shortfall: 0,
+            lockup_in_force: false,
+        }
+    );
+    assert_eq!(withdrawable.max_withdrawable_lamports(), total_lamports);
+
+    let free_accounts = WithdrawFreeAccounts {
+        from: &stake_account,
+        to,
+    };
+    let free_keys = free_accounts.resolve_to_free_keys().unwrap();
+    let keys = free_keys.resolve();
+    let resolve = |lamports| {
+        free_accounts.resolve_to_free_keys_checked(lamports, &clock, &stake_history, None, None)
+    };
+
+    assert_eq!(
+        resolve(total_lamports - 1),
+        Err(ProgramError::InsufficientFunds)
+    );
+    assert_eq!(
+        exec(
+            &mut ctx,
+            withdraw_ix(
+                keys,
+                WithdrawIxArgs {
+                    lamports: total_lamports - 1
+                }
+            ),
+            &[&withdrawer]
+        )
+        .await,
+        Err(InstructionError::InsufficientFunds)
+    );
+
+    let partial = STAKED_LAMPORTS + EXTRA_LAMPORTS;
+    assert_eq!(resolve(partial), Ok(free_keys));
+    assert_eq!(
+        exec(
+            &mut ctx,
+            withdraw_ix(keys, WithdrawIxArgs { lamports: partial }),
+            &[&withdrawer]
+        )
+        .await,
+        Ok(())
+    );
+    let stake_account = keyed_account(&mut ctx.banks_client, stake).await;
+    assert_eq!(stake_account.account.lamports, rent_exempt_reserve);
+}
@@ -0,0 +1,59 @@
+use crate::{
+    extension_instructions::{DisableCpiGuardKeys, EnableCpiGuardKeys},
+    ReadonlyTokenAccount,
+};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkeyBytes};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CpiGuardFreeAccounts<A> {
+    pub token_account: A,
+}
+
+impl<A: ReadonlyAccountData + ReadonlyAccountPubkeyBytes> CpiGuardFreeAccounts<A> {
+    pub fn resolve_enable(&self) -> Result<EnableCpiGuardKeys, ProgramError> {
+        let (token_account, authority) = self.token_account_and_owner()?;
+        Ok(EnableCpiGuardKeys {
+            token_account,
+            authority,
+        })
+    }
+
+    pub fn resolve_disable(&self) -> Result<DisableCpiGuardKeys, ProgramError> {
+        let (token_account, authority) = self.token_account_and_owner()?;
+        Ok(DisableCpiGuardKeys {
+            token_account,
+            authority,
+        })
+    }
+
+    fn token_account_and_owner(&self) -> Result<(Pubkey, Pubkey), ProgramError> {
+        let t = ReadonlyTokenAccount(&self.token_account)
+            .try_into_valid()?
+            .try_into_initialized()?;
+        Ok((
+            Pubkey::new_from_array(self.token_account.pubkey_bytes()),
+            t.token_account_authority(),
+        ))
+    }
+}
+
+impl<A: ReadonlyAccountData + ReadonlyAccountPubkeyBytes> TryFrom<CpiGuardFreeAccounts<A>>
+    for EnableCpiGuardKeys
+{
+    type Error = ProgramError;
+
+    fn try_from(value: CpiGuardFreeAccounts<A>) -> Result<Self, Self::Error> {
+        value.resolve_enable()
+    }
+}
+
+impl<A: ReadonlyAccountData + ReadonlyAccountPubkeyBytes> TryFrom<CpiGuardFreeAccounts<A>>
+    for DisableCpiGuardKeys
+{
+    type Error = ProgramError;
+
+    fn try_from(value: CpiGuardFreeAccounts<A>) -> Result<Self, Self::Error> {
+        value.resolve_disable()
+    }
+}
@@ -1,25 +1,43 @@
 mod approve;
 mod burn;
 mod close_account;
+mod cpi_guard;
 mod freeze_thaw_account;
 mod initialize_account;
 mod initialize_account2;
 mod initialize_mint;
 mod initialize_multisig;
 mod mint_to;
+mod reallocate;
+mod required_transfer_memos;
 mod revoke;
 mod set_authority;
+mod set_transfer_fee;
+mod sync_native;
 mod transfer_checked;
+mod transfer_checked_hook;
+mod update_interest_bearing_mint_rate;
+mod update_transfer_hook;
+mod withdraw_withheld_tokens;
 
 pub use approve::*;
 pub use burn::*;
 pub use close_account::*;
+pub use cpi_guard::*;
 pub use freeze_thaw_account::*;
 pub use initialize_account::*;
 pub use initialize_account2::*;
 pub use initialize_mint::*;
 pub use initialize_multisig::*;
 pub use mint_to::*;
+pub use reallocate::*;
+pub use required_transfer_memos::*;
 pub use revoke::*;
 pub use set_authority::*;
+pub use set_transfer_fee::*;
+pub use sync_native::*;
 pub use transfer_checked::*;
+pub use transfer_checked_hook::*;
+pub use update_interest_bearing_mint_rate::*;
+pub use update_transfer_hook::*;
+pub use withdraw_withheld_tokens::*;
@@ -0,0 +1,39 @@
+use solana_program::{program_error::ProgramError, pubkey::Pubkey, system_program};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkeyBytes};
+use spl_token_2022_interface::ReallocateKeys;
+
+use crate::ReadonlyTokenAccount;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ReallocateFreeAccounts<A> {
+    pub token_account: A,
+    pub payer: Pubkey,
+}
+
+impl<A: ReadonlyAccountData + ReadonlyAccountPubkeyBytes> ReallocateFreeAccounts<A> {
+    pub fn resolve(&self) -> Result<ReallocateKeys, ProgramError> {
+        let Self {
+            token_account,
+            payer,
+        } = self;
+        let t = ReadonlyTokenAccount(token_account)
+            .try_into_valid()?
+            .try_into_initialized()?;
+        Ok(ReallocateKeys {
+            token_account: Pubkey::new_from_array(token_account.pubkey_bytes()),
+            payer: *payer,
+            system_program: system_program::ID,
+            authority: t.token_account_authority(),
+        })
+    }
+}
+
+impl<A: ReadonlyAccountData + ReadonlyAccountPubkeyBytes> TryFrom<ReallocateFreeAccounts<A>>
+    for ReallocateKeys
+{
+    type Error = ProgramError;
+
+    fn try_from(value: ReallocateFreeAccounts<A>) -> Result<Self, Self::Error> {
+        value.resolve()
+    }
+}
@@ -0,0 +1,59 @@
+use crate::{
+    extension_instructions::{DisableRequiredTransferMemosKeys, EnableRequiredTransferMemosKeys},
+    ReadonlyTokenAccount,
+};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkeyBytes};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RequiredTransferMemosFreeAccounts<A> {
+    pub token_account: A,
+}
+
+impl<A: ReadonlyAccountData + ReadonlyAccountPubkeyBytes> RequiredTransferMemosFreeAccounts<A> {
+    pub fn resolve_enable(&self) -> Result<EnableRequiredTransferMemosKeys, ProgramError> {
+        let (token_account, authority) = self.token_account_and_owner()?;
+        Ok(EnableRequiredTransferMemosKeys {
+            token_account,
+            authority,
+        })
+    }
+
+    pub fn resolve_disable(&self) -> Result<DisableRequiredTransferMemosKeys, ProgramError> {
+        let (token_account, authority) = self.token_account_and_owner()?;
+        Ok(DisableRequiredTransferMemosKeys {
+            token_account,
+            authority,
+        })
+    }
+
+    fn token_account_and_owner(&self) -> Result<(Pubkey, Pubkey), ProgramError> {
+        let t = ReadonlyTokenAccount(&self.token_account)
+            .try_into_valid()?
+            .try_into_initialized()?;
+        Ok((
+            Pubkey::new_from_array(self.token_account.pubkey_bytes()),
+            t.token_account_authority(),
+        ))
+    }
+}
+
+impl<A: ReadonlyAccountData + ReadonlyAccountPubkeyBytes>
+    TryFrom<RequiredTransferMemosFreeAccounts<A>> for EnableRequiredTransferMemosKeys
+{
+    type Error = ProgramError;
+
+    fn try_from(value: RequiredTransferMemosFreeAccounts<A>) -> Result<Self, Self::Error> {
+        value.resolve_enable()
+    }
+}
+
+impl<A: ReadonlyAccountData + ReadonlyAccountPubkeyBytes>
+    TryFrom<RequiredTransferMemosFreeAccounts<A>> for DisableRequiredTransferMemosKeys
+{
+    type Error = ProgramError;
+
+    fn try_from(value: RequiredTransferMemosFreeAccounts<A>) -> Result<Self, Self::Error> {
+        value.resolve_disable()
+    }
+}
@@ -0,0 +1,39 @@
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkeyBytes};
+use spl_token_2022_interface::SplToken2022Error;
+
+use crate::{extension_instructions::SetTransferFeeKeys, ReadonlyMintAccount};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SetTransferFeeFreeAccounts<M> {
+    pub mint: M,
+}
+
+impl<M: ReadonlyAccountData + ReadonlyAccountPubkeyBytes> SetTransferFeeFreeAccounts<M> {
+    pub fn resolve(&self) -> Result<SetTransferFeeKeys, ProgramError> {
+        let Self { mint } = self;
+        let m = ReadonlyMintAccount(mint)
+            .try_into_valid()?
+            .try_into_initialized()?;
+        let config = m
+            .mint_transfer_fee_config()?
+            .ok_or(SplToken2022Error::ExtensionNotFound)?;
+        let authority = config
+            .transfer_fee_config_authority
+            .ok_or(SplToken2022Error::NoAuthorityExists)?;
+        Ok(SetTransferFeeKeys {
+            mint: Pubkey::new_from_array(mint.pubkey_bytes()),
+            authority,
+        })
+    }
+}
+
+impl<M: ReadonlyAccountData + ReadonlyAccountPubkeyBytes> TryFrom<SetTransferFeeFreeAccounts<M>>
+    for SetTransferFeeKeys
+{
+    type Error = ProgramError;
+
+    fn try_from(value: SetTransferFeeFreeAccounts<M>) -> Result<Self, Self::Error> {
+        value.resolve()
+    }
+}
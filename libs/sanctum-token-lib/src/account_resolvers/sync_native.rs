@@ -0,0 +1,35 @@
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkeyBytes};
+use spl_token_interface::{SplTokenError, SyncNativeKeys};
+
+use crate::ReadonlyTokenAccount;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SyncNativeFreeAccounts<A> {
+    pub token_account: A,
+}
+
+impl<A: ReadonlyAccountData + ReadonlyAccountPubkeyBytes> SyncNativeFreeAccounts<A> {
+    pub fn resolve(&self) -> Result<SyncNativeKeys, ProgramError> {
+        let Self { token_account } = self;
+        let t = ReadonlyTokenAccount(token_account)
+            .try_into_valid()?
+            .try_into_initialized()?;
+        if t.token_account_is_native().is_none() {
+            return Err(SplTokenError::NonNativeNotSupported.into());
+        }
+        Ok(SyncNativeKeys {
+            token_account: Pubkey::new_from_array(token_account.pubkey_bytes()),
+        })
+    }
+}
+
+impl<A: ReadonlyAccountData + ReadonlyAccountPubkeyBytes> TryFrom<SyncNativeFreeAccounts<A>>
+    for SyncNativeKeys
+{
+    type Error = ProgramError;
+
+    fn try_from(value: SyncNativeFreeAccounts<A>) -> Result<Self, Self::Error> {
+        value.resolve()
+    }
+}
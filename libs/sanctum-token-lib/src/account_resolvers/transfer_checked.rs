@@ -2,7 +2,7 @@ use solana_program::{program_error::ProgramError, pubkey::Pubkey};
 use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkeyBytes};
 use spl_token_interface::TransferCheckedKeys;
 
-use crate::ReadonlyTokenAccount;
+use crate::{extension_instructions::TransferCheckedWithFeeKeys, ReadonlyTokenAccount};
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct TransferCheckedFreeAccounts<A> {
@@ -28,6 +28,21 @@ impl<A: ReadonlyAccountPubkeyBytes + ReadonlyAccountData> TransferCheckedFreeAcc
             authority,
         })
     }
+
+    pub fn resolve_with_fee(&self) -> Result<TransferCheckedWithFeeKeys, ProgramError> {
+        let TransferCheckedKeys {
+            from,
+            mint,
+            to,
+            authority,
+        } = self.resolve()?;
+        Ok(TransferCheckedWithFeeKeys {
+            from,
+            mint,
+            to,
+            authority,
+        })
+    }
 }
 
 impl<A: ReadonlyAccountPubkeyBytes + ReadonlyAccountData> TryFrom<TransferCheckedFreeAccounts<A>>
@@ -39,3 +54,13 @@ impl<A: ReadonlyAccountPubkeyBytes + ReadonlyAccountData> TryFrom<TransferChecke
         value.resolve()
     }
 }
+
+impl<A: ReadonlyAccountPubkeyBytes + ReadonlyAccountData> TryFrom<TransferCheckedFreeAccounts<A>>
+    for TransferCheckedWithFeeKeys
+{
+    type Error = ProgramError;
+
+    fn try_from(value: TransferCheckedFreeAccounts<A>) -> Result<Self, Self::Error> {
+        value.resolve_with_fee()
+    }
+}
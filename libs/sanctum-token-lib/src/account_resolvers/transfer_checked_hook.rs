@@ -0,0 +1,558 @@
+//! Resolution of the extra accounts a transfer hook program requires,
+//! reimplemented here instead of depending on spl-tlv-account-resolution
+//! so that it works with any [`ReadonlyAccountData`] and does not need std
+
+use solana_program::{
+    instruction::AccountMeta,
+    program_error::ProgramError,
+    pubkey::{Pubkey, MAX_SEEDS, MAX_SEED_LEN},
+};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkeyBytes};
+use spl_token_interface::{TransferCheckedKeys, TRANSFER_CHECKED_IX_ACCOUNTS_LEN};
+
+pub const EXTRA_ACCOUNT_META_LIST_SEED: &[u8] = b"extra-account-metas";
+
+/// First 8 bytes of sha256("spl-transfer-hook-interface:execute")
+pub const TRANSFER_HOOK_EXECUTE_DISCM: [u8; 8] = [0x69, 0x25, 0x65, 0xc5, 0x4b, 0xfb, 0x66, 0x1a];
+
+pub const TRANSFER_HOOK_EXECUTE_IX_DATA_LEN: usize = 16;
+
+/// source, mint, destination, authority, extra account meta list
+pub const TRANSFER_HOOK_EXECUTE_IX_ACCOUNTS_LEN: usize = 5;
+
+pub const EXTRA_ACCOUNT_META_LEN: usize = 35;
+
+const TLV_TYPE_LEN: usize = 8;
+const TLV_LENGTH_LEN: usize = 4;
+const POD_SLICE_LENGTH_LEN: usize = 4;
+
+const EXTRA_ACCOUNT_META_DISCM_LITERAL: u8 = 0;
+const EXTRA_ACCOUNT_META_DISCM_PDA: u8 = 1;
+const EXTRA_ACCOUNT_META_DISCM_PUBKEY_DATA: u8 = 2;
+/// Discriminators >= this are PDAs of the program at account index `discm - this`
+const EXTRA_ACCOUNT_META_DISCM_EXTERNAL_PDA_START: u8 = 128;
+
+const SEED_DISCM_UNINITIALIZED: u8 = 0;
+const SEED_DISCM_LITERAL: u8 = 1;
+const SEED_DISCM_INSTRUCTION_DATA: u8 = 2;
+const SEED_DISCM_ACCOUNT_KEY: u8 = 3;
+const SEED_DISCM_ACCOUNT_DATA: u8 = 4;
+
+const PUBKEY_DATA_DISCM_INSTRUCTION_DATA: u8 = 1;
+const PUBKEY_DATA_DISCM_ACCOUNT_DATA: u8 = 2;
+
+pub fn find_extra_account_meta_list_address(
+    mint: &Pubkey,
+    hook_program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[EXTRA_ACCOUNT_META_LIST_SEED, mint.as_ref()],
+        hook_program_id,
+    )
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct TransferCheckedHookFreeAccounts<'a, L, A> {
+    pub transfer_checked_keys: TransferCheckedKeys,
+
+    /// Transfer amount, which seeds of the instruction-data kind read from
+    pub amount: u64,
+
+    pub hook_program_id: Pubkey,
+
+    /// The mint's `ExtraAccountMetaList` PDA
+    pub extra_account_meta_list: L,
+
+    /// Accounts that seeds of the account-data kind may read from,
+    /// looked up by pubkey. Only needs to contain the accounts actually
+    /// referenced by the hook's seeds, e.g. the source or destination token
+    /// account, or a previously resolved extra account.
+    pub accounts: &'a [A],
+}
+
+impl<
+        L: ReadonlyAccountData + ReadonlyAccountPubkeyBytes,
+        A: ReadonlyAccountData + ReadonlyAccountPubkeyBytes,
+    > TransferCheckedHookFreeAccounts<'_, L, A>
+{
+    /// Returns the full list of account metas for a `transfer_checked` instruction:
+    /// the base transfer accounts, followed by [`Self::resolve_extra_account_metas`].
+    ///
+    /// Replace the `accounts` of the instruction returned by `transfer_checked_ix`
+    /// with this. For a multisig authority, use [`Self::resolve_extra_account_metas`]
+    /// and append them after the signatories instead.
+    pub fn resolve(&self) -> Result<Vec<AccountMeta>, ProgramError> {
+        let mut res = Vec::from(<[AccountMeta; TRANSFER_CHECKED_IX_ACCOUNTS_LEN]>::from(
+            self.transfer_checked_keys,
+        ));
+        res.extend(self.resolve_extra_account_metas()?);
+        Ok(res)
+    }
+
+    /// Returns the account metas to append to a `transfer_checked` instruction:
+    /// the resolved extra accounts, then the hook program, then the `ExtraAccountMetaList` PDA
+    pub fn resolve_extra_account_metas(&self) -> Result<Vec<AccountMeta>, ProgramError> {
+        let TransferCheckedKeys {
+            from,
+            mint,
+            to,
+            authority,
+        } = self.transfer_checked_keys;
+        let list_pubkey = Pubkey::new_from_array(self.extra_account_meta_list.pubkey_bytes());
+        let (expected_list_pubkey, _bump) =
+            find_extra_account_meta_list_address(&mint, &self.hook_program_id);
+        if list_pubkey != expected_list_pubkey {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        let list_data = self.extra_account_meta_list.data();
+        let extra_metas = extra_account_metas_slice(&list_data)?;
+
+        let mut ix_data = [0u8; TRANSFER_HOOK_EXECUTE_IX_DATA_LEN];
+        ix_data[..TRANSFER_HOOK_EXECUTE_DISCM.len()].copy_from_slice(&TRANSFER_HOOK_EXECUTE_DISCM);
+        ix_data[TRANSFER_HOOK_EXECUTE_DISCM.len()..].copy_from_slice(&self.amount.to_le_bytes());
+
+        // accounts of the execute instruction the hook program receives,
+        // which are all readonly and non-signer
+        let mut execute_metas: Vec<AccountMeta> = [from, mint, to, authority, list_pubkey]
+            .into_iter()
+            .map(|pubkey| AccountMeta::new_readonly(pubkey, false))
+            .collect();
+        for extra_meta in extra_metas.chunks_exact(EXTRA_ACCOUNT_META_LEN) {
+            let mut meta = self.resolve_extra_account_meta(extra_meta, &ix_data, &execute_metas)?;
+            de_escalate(&mut meta, &execute_metas);
+            execute_metas.push(meta);
+        }
+
+        let mut res = execute_metas.split_off(TRANSFER_HOOK_EXECUTE_IX_ACCOUNTS_LEN);
+        res.push(AccountMeta::new_readonly(self.hook_program_id, false));
+        res.push(AccountMeta::new_readonly(list_pubkey, false));
+        Ok(res)
+    }
+
+    fn resolve_extra_account_meta(
+        &self,
+        extra_meta: &[u8],
+        ix_data: &[u8],
+        execute_metas: &[AccountMeta],
+    ) -> Result<AccountMeta, ProgramError> {
+        let discm = extra_meta[0];
+        let address_config = &extra_meta[1..33];
+        let is_signer = extra_meta[33] != 0;
+        let is_writable = extra_meta[34] != 0;
+        let pubkey = match discm {
+            EXTRA_ACCOUNT_META_DISCM_LITERAL => {
+                Pubkey::new_from_array(address_config.try_into().unwrap())
+            }
+            EXTRA_ACCOUNT_META_DISCM_PUBKEY_DATA => {
+                self.resolve_pubkey_data(address_config, ix_data, execute_metas)?
+            }
+            EXTRA_ACCOUNT_META_DISCM_PDA => self.resolve_pda(
+                address_config,
+                ix_data,
+                execute_metas,
+                &self.hook_program_id,
+            )?,
+            d if d >= EXTRA_ACCOUNT_META_DISCM_EXTERNAL_PDA_START => {
+                let program_id = account_key(
+                    execute_metas,
+                    d - EXTRA_ACCOUNT_META_DISCM_EXTERNAL_PDA_START,
+                )?;
+                self.resolve_pda(address_config, ix_data, execute_metas, &program_id)?
+            }
+            _ => return Err(ProgramError::InvalidAccountData),
+        };
+        Ok(AccountMeta {
+            pubkey,
+            is_signer,
+            is_writable,
+        })
+    }
+
+    fn resolve_pubkey_data(
+        &self,
+        address_config: &[u8],
+        ix_data: &[u8],
+        execute_metas: &[AccountMeta],
+    ) -> Result<Pubkey, ProgramError> {
+        let mut pubkey = [0u8; 32];
+        match address_config[0] {
+            PUBKEY_DATA_DISCM_INSTRUCTION_DATA => {
+                let index = usize::from(address_config[1]);
+                let src = ix_data
+                    .get(index..index + 32)
+                    .ok_or(ProgramError::InvalidInstructionData)?;
+                pubkey.copy_from_slice(src);
+            }
+            PUBKEY_DATA_DISCM_ACCOUNT_DATA => {
+                let account_key = account_key(execute_metas, address_config[1])?;
+                let index = usize::from(address_config[2]);
+                self.copy_account_data(&account_key, index, &mut pubkey)?;
+            }
+            _ => return Err(ProgramError::InvalidAccountData),
+        }
+        Ok(Pubkey::new_from_array(pubkey))
+    }
+
+    fn resolve_pda(
+        &self,
+        address_config: &[u8],
+        ix_data: &[u8],
+        execute_metas: &[AccountMeta],
+        program_id: &Pubkey,
+    ) -> Result<Pubkey, ProgramError> {
+        let mut seed_bufs = [[0u8; MAX_SEED_LEN]; MAX_SEEDS];
+        let mut seed_lens = [0usize; MAX_SEEDS];
+        let mut n_seeds = 0;
+        let mut rem = address_config;
+        while let Some((&seed_discm, rest)) = rem.split_first() {
+            if seed_discm == SEED_DISCM_UNINITIALIZED {
+                break;
+            }
+            let buf = seed_bufs
+                .get_mut(n_seeds)
+                .ok_or(ProgramError::InvalidSeeds)?;
+            let (len, rest) = match seed_discm {
+                SEED_DISCM_LITERAL => {
+                    let (len, rest) = split_u8(rest)?;
+                    let len = usize::from(len);
+                    let bytes = rest.get(..len).ok_or(ProgramError::InvalidAccountData)?;
+                    seed_slot(buf, len)?.copy_from_slice(bytes);
+                    (len, &rest[len..])
+                }
+                SEED_DISCM_INSTRUCTION_DATA => {
+                    let (index, rest) = split_u8(rest)?;
+                    let (len, rest) = split_u8(rest)?;
+                    let (index, len) = (usize::from(index), usize::from(len));
+                    let bytes = ix_data
+                        .get(index..index + len)
+                        .ok_or(ProgramError::InvalidInstructionData)?;
+                    seed_slot(buf, len)?.copy_from_slice(bytes);
+                    (len, rest)
+                }
+                SEED_DISCM_ACCOUNT_KEY => {
+                    let (index, rest) = split_u8(rest)?;
+                    let key = account_key(execute_metas, index)?;
+                    seed_slot(buf, 32)?.copy_from_slice(key.as_ref());
+                    (32, rest)
+                }
+                SEED_DISCM_ACCOUNT_DATA => {
+                    let (account_index, rest) = split_u8(rest)?;
+                    let (data_index, rest) = split_u8(rest)?;
+                    let (len, rest) = split_u8(rest)?;
+                    let len = usize::from(len);
+                    let key = account_key(execute_metas, account_index)?;
+                    self.copy_account_data(&key, usize::from(data_index), seed_slot(buf, len)?)?;
+                    (len, rest)
+                }
+                _ => return Err(ProgramError::InvalidAccountData),
+            };
+            seed_lens[n_seeds] = len;
+            n_seeds += 1;
+            rem = rest;
+        }
+
+        let mut seeds: [&[u8]; MAX_SEEDS] = [&[]; MAX_SEEDS];
+        for (seed, (buf, len)) in seeds.iter_mut().zip(seed_bufs.iter().zip(seed_lens)) {
+            *seed = &buf[..len];
+        }
+        let (pda, _bump) = Pubkey::find_program_address(&seeds[..n_seeds], program_id);
+        Ok(pda)
+    }
+
+    /// Copies `dst.len()` bytes of `account`'s data starting at `index` into `dst`
+    fn copy_account_data(
+        &self,
+        account: &Pubkey,
+        index: usize,
+        dst: &mut [u8],
+    ) -> Result<(), ProgramError> {
+        if account.to_bytes() == self.extra_account_meta_list.pubkey_bytes() {
+            return copy_data(&self.extra_account_meta_list.data(), index, dst);
+        }
+        let a = self
+            .accounts
+            .iter()
+            .find(|a| a.pubkey_bytes() == account.to_bytes())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        copy_data(&a.data(), index, dst)
+    }
+}
+
+impl<
+        L: ReadonlyAccountData + ReadonlyAccountPubkeyBytes,
+        A: ReadonlyAccountData + ReadonlyAccountPubkeyBytes,
+    > TryFrom<TransferCheckedHookFreeAccounts<'_, L, A>> for Vec<AccountMeta>
+{
+    type Error = ProgramError;
+
+    fn try_from(value: TransferCheckedHookFreeAccounts<'_, L, A>) -> Result<Self, Self::Error> {
+        value.resolve()
+    }
+}
+
+/// Returns the packed `ExtraAccountMeta`s for the execute instruction
+/// stored in an `ExtraAccountMetaList` account's TLV data
+fn extra_account_metas_slice(tlv_data: &[u8]) -> Result<&[u8], ProgramError> {
+    let mut rem = tlv_data;
+    while !rem.is_empty() {
+        let value_start = TLV_TYPE_LEN + TLV_LENGTH_LEN;
+        if rem.len() < value_start {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let (discm, rest) = rem.split_at(TLV_TYPE_LEN);
+        let (len, rest) = rest.split_at(TLV_LENGTH_LEN);
+        if discm.iter().all(|b| *b == 0) {
+            // nothing is written after an uninitialized entry
+            break;
+        }
+        let len = usize::try_from(u32::from_le_bytes(len.try_into().unwrap()))
+            .map_err(|_e| ProgramError::InvalidAccountData)?;
+        let value = rest.get(..len).ok_or(ProgramError::InvalidAccountData)?;
+        if discm == TRANSFER_HOOK_EXECUTE_DISCM {
+            if value.len() < POD_SLICE_LENGTH_LEN {
+                return Err(ProgramError::InvalidAccountData);
+            }
+            let (count, metas) = value.split_at(POD_SLICE_LENGTH_LEN);
+            let count = usize::try_from(u32::from_le_bytes(count.try_into().unwrap()))
+                .map_err(|_e| ProgramError::InvalidAccountData)?;
+            return count
+                .checked_mul(EXTRA_ACCOUNT_META_LEN)
+                .and_then(|n| metas.get(..n))
+                .ok_or(ProgramError::InvalidAccountData);
+        }
+        rem = &rest[len..];
+    }
+    Err(ProgramError::InvalidAccountData)
+}
+
+/// If `meta` is already in `metas` without signer/writable privileges,
+/// drop them from `meta` too
+fn de_escalate(meta: &mut AccountMeta, metas: &[AccountMeta]) {
+    let existing = metas
+        .iter()
+        .filter(|m| m.pubkey == meta.pubkey)
+        .map(|m| (m.is_signer, m.is_writable))
+        .reduce(|(s1, w1), (s2, w2)| (s1 || s2, w1 || w2));
+    if let Some((is_signer, is_writable)) = existing {
+        meta.is_signer &= is_signer;
+        meta.is_writable &= is_writable;
+    }
+}
+
+fn copy_data(data: &[u8], index: usize, dst: &mut [u8]) -> Result<(), ProgramError> {
+    let src = data
+        .get(index..index + dst.len())
+        .ok_or(ProgramError::AccountDataTooSmall)?;
+    dst.copy_from_slice(src);
+    Ok(())
+}
+
+fn account_key(execute_metas: &[AccountMeta], index: u8) -> Result<Pubkey, ProgramError> {
+    execute_metas
+        .get(usize::from(index))
+        .map(|m| m.pubkey)
+        .ok_or(ProgramError::NotEnoughAccountKeys)
+}
+
+fn split_u8(bytes: &[u8]) -> Result<(u8, &[u8]), ProgramError> {
+    bytes
+        .split_first()
+        .map(|(b, rest)| (*b, rest))
+        .ok_or(ProgramError::InvalidAccountData)
+}
+
+fn seed_slot(buf: &mut [u8; MAX_SEED_LEN], len: usize) -> Result<&mut [u8], ProgramError> {
+    buf.get_mut(..len)
+        .ok_or(ProgramError::MaxSeedLengthExceeded)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        future::Future,
+        pin::pin,
+        task::{Context, Poll, Waker},
+    };
+
+    use spl_tlv_account_resolution::{
+        account::ExtraAccountMeta, pubkey_data::PubkeyData, seeds::Seed,
+        state::ExtraAccountMetaList,
+    };
+    use spl_token_interface::{transfer_checked_ix, CheckedOpArgs, TransferCheckedIxArgs};
+    use spl_transfer_hook_interface::{
+        get_extra_account_metas_address, instruction::ExecuteInstruction,
+        offchain::add_extra_account_metas_for_execute,
+    };
+
+    use crate::SPL_TOKEN_ACCOUNT_PACKED_LEN;
+
+    use super::*;
+
+    struct TestAccount {
+        pubkey: Pubkey,
+        data: Vec<u8>,
+    }
+
+    impl ReadonlyAccountPubkeyBytes for TestAccount {
+        fn pubkey_bytes(&self) -> [u8; 32] {
+            self.pubkey.to_bytes()
+        }
+    }
+
+    impl ReadonlyAccountData for TestAccount {
+        type DataDeref<'d>
+            = &'d [u8]
+        where
+            Self: 'd;
+
+        fn data(&self) -> Self::DataDeref<'_> {
+            &self.data
+        }
+    }
+
+    /// The spl futures used here never actually wait on anything
+    fn poll_ready<F: Future>(f: F) -> F::Output {
+        match pin!(f).poll(&mut Context::from_waker(Waker::noop())) {
+            Poll::Ready(res) => res,
+            Poll::Pending => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn transfer_checked_hook_matches_spl_offchain() {
+        let hook_program_id = Pubkey::new_unique();
+        let keys = TransferCheckedKeys {
+            from: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            to: Pubkey::new_unique(),
+            authority: Pubkey::new_unique(),
+        };
+        let amount = 1_234_567_890;
+        let literal = Pubkey::new_unique();
+        let external_program = Pubkey::new_unique();
+
+        let mut to_data = vec![0u8; SPL_TOKEN_ACCOUNT_PACKED_LEN];
+        to_data[32..64].copy_from_slice(Pubkey::new_unique().as_ref());
+        let to = TestAccount {
+            pubkey: keys.to,
+            data: to_data,
+        };
+
+        let extra_metas = [
+            ExtraAccountMeta::new_with_pubkey(&literal, false, true).unwrap(),
+            ExtraAccountMeta::new_with_pubkey(&external_program, false, false).unwrap(),
+            ExtraAccountMeta::new_with_seeds(
+                &[
+                    Seed::Literal {
+                        bytes: b"prefix".to_vec(),
+                    },
+                    Seed::InstructionData {
+                        index: 8,
+                        length: 8,
+                    },
+                    Seed::AccountKey { index: 2 },
+                ],
+                false,
+                true,
+            )
+            .unwrap(),
+            ExtraAccountMeta::new_with_seeds(
+                &[Seed::AccountData {
+                    account_index: 2,
+                    data_index: 32,
+                    length: 32,
+                }],
+                false,
+                false,
+            )
+            .unwrap(),
+            ExtraAccountMeta::new_external_pda_with_seeds(
+                6,
+                &[Seed::AccountKey { index: 0 }, Seed::AccountKey { index: 7 }],
+                false,
+                true,
+            )
+            .unwrap(),
+            ExtraAccountMeta::new_with_pubkey_data(
+                &PubkeyData::AccountData {
+                    account_index: 2,
+                    data_index: 32,
+                },
+                false,
+                false,
+            )
+            .unwrap(),
+            // de-escalated to readonly
+            ExtraAccountMeta::new_with_pubkey(&keys.from, false, true).unwrap(),
+        ];
+        let mut list_data = vec![0u8; ExtraAccountMetaList::size_of(extra_metas.len()).unwrap()];
+        ExtraAccountMetaList::init::<ExecuteInstruction>(&mut list_data, &extra_metas).unwrap();
+        assert_eq!(list_data[..8], TRANSFER_HOOK_EXECUTE_DISCM);
+        let list = TestAccount {
+            pubkey: get_extra_account_metas_address(&keys.mint, &hook_program_id),
+            data: list_data,
+        };
+
+        let actual = TransferCheckedHookFreeAccounts {
+            transfer_checked_keys: keys,
+            amount,
+            hook_program_id,
+            extra_account_meta_list: &list,
+            accounts: &[&to],
+        }
+        .resolve()
+        .unwrap();
+
+        let mut expected = transfer_checked_ix(
+            keys,
+            TransferCheckedIxArgs {
+                args: CheckedOpArgs {
+                    amount,
+                    decimals: 9,
+                },
+            },
+        )
+        .unwrap();
+        poll_ready(add_extra_account_metas_for_execute(
+            &mut expected,
+            &hook_program_id,
+            &keys.from,
+            &keys.mint,
+            &keys.to,
+            &keys.authority,
+            amount,
+            |pubkey| {
+                let data = [&list, &to]
+                    .into_iter()
+                    .find(|a| a.pubkey == pubkey)
+                    .map(|a| a.data.clone());
+                async move { Ok(data) }
+            },
+        ))
+        .unwrap();
+        assert_eq!(actual, expected.accounts);
+    }
+
+    #[test]
+    fn transfer_checked_hook_rejects_wrong_list_address() {
+        let list = TestAccount {
+            pubkey: Pubkey::new_unique(),
+            data: vec![0u8; ExtraAccountMetaList::size_of(0).unwrap()],
+        };
+        let res = TransferCheckedHookFreeAccounts {
+            transfer_checked_keys: TransferCheckedKeys {
+                from: Pubkey::new_unique(),
+                mint: Pubkey::new_unique(),
+                to: Pubkey::new_unique(),
+                authority: Pubkey::new_unique(),
+            },
+            amount: 1,
+            hook_program_id: Pubkey::new_unique(),
+            extra_account_meta_list: &list,
+            accounts: &[] as &[&TestAccount],
+        }
+        .resolve();
+        assert_eq!(res.unwrap_err(), ProgramError::InvalidSeeds);
+    }
+}
@@ -0,0 +1,41 @@
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkeyBytes};
+use spl_token_2022_interface::SplToken2022Error;
+
+use crate::{extension_instructions::UpdateInterestBearingMintRateKeys, ReadonlyMintAccount};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UpdateInterestBearingMintRateFreeAccounts<M> {
+    pub mint: M,
+}
+
+impl<M: ReadonlyAccountData + ReadonlyAccountPubkeyBytes>
+    UpdateInterestBearingMintRateFreeAccounts<M>
+{
+    pub fn resolve(&self) -> Result<UpdateInterestBearingMintRateKeys, ProgramError> {
+        let Self { mint } = self;
+        let m = ReadonlyMintAccount(mint)
+            .try_into_valid()?
+            .try_into_initialized()?;
+        let interest_bearing_config = m
+            .mint_interest_bearing_config()?
+            .ok_or(SplToken2022Error::ExtensionNotFound)?;
+        let authority = interest_bearing_config
+            .rate_authority
+            .ok_or(SplToken2022Error::NoAuthorityExists)?;
+        Ok(UpdateInterestBearingMintRateKeys {
+            mint: Pubkey::new_from_array(mint.pubkey_bytes()),
+            authority,
+        })
+    }
+}
+
+impl<M: ReadonlyAccountData + ReadonlyAccountPubkeyBytes>
+    TryFrom<UpdateInterestBearingMintRateFreeAccounts<M>> for UpdateInterestBearingMintRateKeys
+{
+    type Error = ProgramError;
+
+    fn try_from(value: UpdateInterestBearingMintRateFreeAccounts<M>) -> Result<Self, Self::Error> {
+        value.resolve()
+    }
+}
@@ -0,0 +1,39 @@
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkeyBytes};
+use spl_token_2022_interface::SplToken2022Error;
+
+use crate::{extension_instructions::UpdateTransferHookKeys, ReadonlyMintAccount};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UpdateTransferHookFreeAccounts<M> {
+    pub mint: M,
+}
+
+impl<M: ReadonlyAccountData + ReadonlyAccountPubkeyBytes> UpdateTransferHookFreeAccounts<M> {
+    pub fn resolve(&self) -> Result<UpdateTransferHookKeys, ProgramError> {
+        let Self { mint } = self;
+        let m = ReadonlyMintAccount(mint)
+            .try_into_valid()?
+            .try_into_initialized()?;
+        let transfer_hook = m
+            .mint_transfer_hook()?
+            .ok_or(SplToken2022Error::ExtensionNotFound)?;
+        let authority = transfer_hook
+            .authority
+            .ok_or(SplToken2022Error::NoAuthorityExists)?;
+        Ok(UpdateTransferHookKeys {
+            mint: Pubkey::new_from_array(mint.pubkey_bytes()),
+            authority,
+        })
+    }
+}
+
+impl<M: ReadonlyAccountData + ReadonlyAccountPubkeyBytes> TryFrom<UpdateTransferHookFreeAccounts<M>>
+    for UpdateTransferHookKeys
+{
+    type Error = ProgramError;
+
+    fn try_from(value: UpdateTransferHookFreeAccounts<M>) -> Result<Self, Self::Error> {
+        value.resolve()
+    }
+}
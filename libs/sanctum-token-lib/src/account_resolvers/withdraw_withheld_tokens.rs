@@ -0,0 +1,70 @@
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkeyBytes};
+use spl_token_2022_interface::SplToken2022Error;
+
+use crate::{
+    extension_instructions::{
+        WithdrawWithheldTokensFromAccountsKeys, WithdrawWithheldTokensFromMintKeys,
+    },
+    ReadonlyMintAccount,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WithdrawWithheldTokensFreeAccounts<M> {
+    pub mint: M,
+    pub to: Pubkey,
+}
+
+impl<M: ReadonlyAccountData + ReadonlyAccountPubkeyBytes> WithdrawWithheldTokensFreeAccounts<M> {
+    pub fn resolve_from_mint(&self) -> Result<WithdrawWithheldTokensFromMintKeys, ProgramError> {
+        let authority = self.withdraw_withheld_authority()?;
+        Ok(WithdrawWithheldTokensFromMintKeys {
+            mint: Pubkey::new_from_array(self.mint.pubkey_bytes()),
+            to: self.to,
+            authority,
+        })
+    }
+
+    pub fn resolve_from_accounts(
+        &self,
+    ) -> Result<WithdrawWithheldTokensFromAccountsKeys, ProgramError> {
+        let authority = self.withdraw_withheld_authority()?;
+        Ok(WithdrawWithheldTokensFromAccountsKeys {
+            mint: Pubkey::new_from_array(self.mint.pubkey_bytes()),
+            to: self.to,
+            authority,
+        })
+    }
+
+    fn withdraw_withheld_authority(&self) -> Result<Pubkey, ProgramError> {
+        let m = ReadonlyMintAccount(&self.mint)
+            .try_into_valid()?
+            .try_into_initialized()?;
+        let config = m
+            .mint_transfer_fee_config()?
+            .ok_or(SplToken2022Error::ExtensionNotFound)?;
+        Ok(config
+            .withdraw_withheld_authority
+            .ok_or(SplToken2022Error::NoAuthorityExists)?)
+    }
+}
+
+impl<M: ReadonlyAccountData + ReadonlyAccountPubkeyBytes>
+    TryFrom<WithdrawWithheldTokensFreeAccounts<M>> for WithdrawWithheldTokensFromMintKeys
+{
+    type Error = ProgramError;
+
+    fn try_from(value: WithdrawWithheldTokensFreeAccounts<M>) -> Result<Self, Self::Error> {
+        value.resolve_from_mint()
+    }
+}
+
+impl<M: ReadonlyAccountData + ReadonlyAccountPubkeyBytes>
+    TryFrom<WithdrawWithheldTokensFreeAccounts<M>> for WithdrawWithheldTokensFromAccountsKeys
+{
+    type Error = ProgramError;
+
+    fn try_from(value: WithdrawWithheldTokensFreeAccounts<M>) -> Result<Self, Self::Error> {
+        value.resolve_from_accounts()
+    }
+}
@@ -0,0 +1,3036 @@
+//! Token-2022 extension instructions.
+//!
+//! These have 2-byte discriminants, which solores does not support, so they cannot be generated into
+//! `spl_token_2022_interface` and are handwritten here instead, following the layout of its generated code.
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use std::io::Read;
+
+pub use spl_token_2022_interface::{CheckedOpArgs, ID};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum SplToken2022ExtensionIx {
+    InitializeTransferFeeConfig(InitializeTransferFeeConfigIxArgs),
+    TransferCheckedWithFee(TransferCheckedWithFeeIxArgs),
+    WithdrawWithheldTokensFromMint,
+    WithdrawWithheldTokensFromAccounts(WithdrawWithheldTokensFromAccountsIxArgs),
+    HarvestWithheldTokensToMint,
+    SetTransferFee(SetTransferFeeIxArgs),
+    EnableRequiredTransferMemos,
+    DisableRequiredTransferMemos,
+    InitializeInterestBearingMint(InitializeInterestBearingMintIxArgs),
+    UpdateInterestBearingMintRate(UpdateInterestBearingMintRateIxArgs),
+    EnableCpiGuard,
+    DisableCpiGuard,
+    InitializeTransferHook(InitializeTransferHookIxArgs),
+    UpdateTransferHook(UpdateTransferHookIxArgs),
+}
+impl SplToken2022ExtensionIx {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 2];
+        reader.read_exact(&mut maybe_discm)?;
+        match maybe_discm {
+            INITIALIZE_TRANSFER_FEE_CONFIG_IX_DISCM => Ok(Self::InitializeTransferFeeConfig(
+                InitializeTransferFeeConfigIxArgs::deserialize(&mut reader)?,
+            )),
+            TRANSFER_CHECKED_WITH_FEE_IX_DISCM => Ok(Self::TransferCheckedWithFee(
+                TransferCheckedWithFeeIxArgs::deserialize(&mut reader)?,
+            )),
+            WITHDRAW_WITHHELD_TOKENS_FROM_MINT_IX_DISCM => Ok(Self::WithdrawWithheldTokensFromMint),
+            WITHDRAW_WITHHELD_TOKENS_FROM_ACCOUNTS_IX_DISCM => {
+                Ok(Self::WithdrawWithheldTokensFromAccounts(
+                    WithdrawWithheldTokensFromAccountsIxArgs::deserialize(&mut reader)?,
+                ))
+            }
+            HARVEST_WITHHELD_TOKENS_TO_MINT_IX_DISCM => Ok(Self::HarvestWithheldTokensToMint),
+            SET_TRANSFER_FEE_IX_DISCM => Ok(Self::SetTransferFee(
+                SetTransferFeeIxArgs::deserialize(&mut reader)?,
+            )),
+            ENABLE_REQUIRED_TRANSFER_MEMOS_IX_DISCM => Ok(Self::EnableRequiredTransferMemos),
+            DISABLE_REQUIRED_TRANSFER_MEMOS_IX_DISCM => Ok(Self::DisableRequiredTransferMemos),
+            INITIALIZE_INTEREST_BEARING_MINT_IX_DISCM => Ok(Self::InitializeInterestBearingMint(
+                InitializeInterestBearingMintIxArgs::deserialize(&mut reader)?,
+            )),
+            UPDATE_INTEREST_BEARING_MINT_RATE_IX_DISCM => Ok(Self::UpdateInterestBearingMintRate(
+                UpdateInterestBearingMintRateIxArgs::deserialize(&mut reader)?,
+            )),
+            ENABLE_CPI_GUARD_IX_DISCM => Ok(Self::EnableCpiGuard),
+            DISABLE_CPI_GUARD_IX_DISCM => Ok(Self::DisableCpiGuard),
+            INITIALIZE_TRANSFER_HOOK_IX_DISCM => Ok(Self::InitializeTransferHook(
+                InitializeTransferHookIxArgs::deserialize(&mut reader)?,
+            )),
+            UPDATE_TRANSFER_HOOK_IX_DISCM => Ok(Self::UpdateTransferHook(
+                UpdateTransferHookIxArgs::deserialize(&mut reader)?,
+            )),
+            _ => Err(std::io::Error::other(format!(
+                "discm {:?} not found",
+                maybe_discm
+            ))),
+        }
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        match self {
+            Self::InitializeTransferFeeConfig(args) => {
+                writer.write_all(&INITIALIZE_TRANSFER_FEE_CONFIG_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::TransferCheckedWithFee(args) => {
+                writer.write_all(&TRANSFER_CHECKED_WITH_FEE_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::WithdrawWithheldTokensFromMint => {
+                writer.write_all(&WITHDRAW_WITHHELD_TOKENS_FROM_MINT_IX_DISCM)
+            }
+            Self::WithdrawWithheldTokensFromAccounts(args) => {
+                writer.write_all(&WITHDRAW_WITHHELD_TOKENS_FROM_ACCOUNTS_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::HarvestWithheldTokensToMint => {
+                writer.write_all(&HARVEST_WITHHELD_TOKENS_TO_MINT_IX_DISCM)
+            }
+            Self::SetTransferFee(args) => {
+                writer.write_all(&SET_TRANSFER_FEE_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::EnableRequiredTransferMemos => {
+                writer.write_all(&ENABLE_REQUIRED_TRANSFER_MEMOS_IX_DISCM)
+            }
+            Self::DisableRequiredTransferMemos => {
+                writer.write_all(&DISABLE_REQUIRED_TRANSFER_MEMOS_IX_DISCM)
+            }
+            Self::InitializeInterestBearingMint(args) => {
+                writer.write_all(&INITIALIZE_INTEREST_BEARING_MINT_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::UpdateInterestBearingMintRate(args) => {
+                writer.write_all(&UPDATE_INTEREST_BEARING_MINT_RATE_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::EnableCpiGuard => writer.write_all(&ENABLE_CPI_GUARD_IX_DISCM),
+            Self::DisableCpiGuard => writer.write_all(&DISABLE_CPI_GUARD_IX_DISCM),
+            Self::InitializeTransferHook(args) => {
+                writer.write_all(&INITIALIZE_TRANSFER_HOOK_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::UpdateTransferHook(args) => {
+                writer.write_all(&UPDATE_TRANSFER_HOOK_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+        }
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+fn invoke_instruction<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
+    ix: &Instruction,
+    accounts: A,
+) -> ProgramResult {
+    let account_info: [AccountInfo<'info>; N] = accounts.into();
+    invoke(ix, &account_info)
+}
+fn invoke_instruction_signed<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
+    ix: &Instruction,
+    accounts: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let account_info: [AccountInfo<'info>; N] = accounts.into();
+    invoke_signed(ix, &account_info, seeds)
+}
+pub const INITIALIZE_TRANSFER_FEE_CONFIG_IX_ACCOUNTS_LEN: usize = 1;
+#[derive(Copy, Clone, Debug)]
+pub struct InitializeTransferFeeConfigAccounts<'me, 'info> {
+    ///The mint to initialize
+    pub mint: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct InitializeTransferFeeConfigKeys {
+    ///The mint to initialize
+    pub mint: Pubkey,
+}
+impl From<InitializeTransferFeeConfigAccounts<'_, '_>> for InitializeTransferFeeConfigKeys {
+    fn from(accounts: InitializeTransferFeeConfigAccounts) -> Self {
+        Self {
+            mint: *accounts.mint.key,
+        }
+    }
+}
+impl From<InitializeTransferFeeConfigKeys>
+    for [AccountMeta; INITIALIZE_TRANSFER_FEE_CONFIG_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: InitializeTransferFeeConfigKeys) -> Self {
+        [AccountMeta {
+            pubkey: keys.mint,
+            is_signer: false,
+            is_writable: true,
+        }]
+    }
+}
+impl From<[Pubkey; INITIALIZE_TRANSFER_FEE_CONFIG_IX_ACCOUNTS_LEN]>
+    for InitializeTransferFeeConfigKeys
+{
+    fn from(pubkeys: [Pubkey; INITIALIZE_TRANSFER_FEE_CONFIG_IX_ACCOUNTS_LEN]) -> Self {
+        Self { mint: pubkeys[0] }
+    }
+}
+impl<'info> From<InitializeTransferFeeConfigAccounts<'_, 'info>>
+    for [AccountInfo<'info>; INITIALIZE_TRANSFER_FEE_CONFIG_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: InitializeTransferFeeConfigAccounts<'_, 'info>) -> Self {
+        [accounts.mint.clone()]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_TRANSFER_FEE_CONFIG_IX_ACCOUNTS_LEN]>
+    for InitializeTransferFeeConfigAccounts<'me, 'info>
+{
+    fn from(
+        arr: &'me [AccountInfo<'info>; INITIALIZE_TRANSFER_FEE_CONFIG_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self { mint: &arr[0] }
+    }
+}
+pub const INITIALIZE_TRANSFER_FEE_CONFIG_IX_DISCM: [u8; 2] = [26u8, 0u8];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct InitializeTransferFeeConfigIxArgs {
+    pub transfer_fee_config_authority: Option<Pubkey>,
+    pub withdraw_withheld_authority: Option<Pubkey>,
+    pub transfer_fee_basis_points: u16,
+    pub maximum_fee: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct InitializeTransferFeeConfigIxData(pub InitializeTransferFeeConfigIxArgs);
+impl From<InitializeTransferFeeConfigIxArgs> for InitializeTransferFeeConfigIxData {
+    fn from(args: InitializeTransferFeeConfigIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl InitializeTransferFeeConfigIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 2];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf;
+        if maybe_discm != INITIALIZE_TRANSFER_FEE_CONFIG_IX_DISCM {
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                INITIALIZE_TRANSFER_FEE_CONFIG_IX_DISCM, maybe_discm
+            )));
+        }
+        Ok(Self(InitializeTransferFeeConfigIxArgs::deserialize(
+            &mut reader,
+        )?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&INITIALIZE_TRANSFER_FEE_CONFIG_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn initialize_transfer_fee_config_ix_with_program_id(
+    program_id: Pubkey,
+    keys: InitializeTransferFeeConfigKeys,
+    args: InitializeTransferFeeConfigIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; INITIALIZE_TRANSFER_FEE_CONFIG_IX_ACCOUNTS_LEN] = keys.into();
+    let data: InitializeTransferFeeConfigIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn initialize_transfer_fee_config_ix(
+    keys: InitializeTransferFeeConfigKeys,
+    args: InitializeTransferFeeConfigIxArgs,
+) -> std::io::Result<Instruction> {
+    initialize_transfer_fee_config_ix_with_program_id(ID, keys, args)
+}
+pub fn initialize_transfer_fee_config_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: InitializeTransferFeeConfigAccounts<'_, '_>,
+    args: InitializeTransferFeeConfigIxArgs,
+) -> ProgramResult {
+    let keys: InitializeTransferFeeConfigKeys = accounts.into();
+    let ix = initialize_transfer_fee_config_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn initialize_transfer_fee_config_invoke(
+    accounts: InitializeTransferFeeConfigAccounts<'_, '_>,
+    args: InitializeTransferFeeConfigIxArgs,
+) -> ProgramResult {
+    initialize_transfer_fee_config_invoke_with_program_id(ID, accounts, args)
+}
+pub fn initialize_transfer_fee_config_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: InitializeTransferFeeConfigAccounts<'_, '_>,
+    args: InitializeTransferFeeConfigIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: InitializeTransferFeeConfigKeys = accounts.into();
+    let ix = initialize_transfer_fee_config_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn initialize_transfer_fee_config_invoke_signed(
+    accounts: InitializeTransferFeeConfigAccounts<'_, '_>,
+    args: InitializeTransferFeeConfigIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    initialize_transfer_fee_config_invoke_signed_with_program_id(ID, accounts, args, seeds)
+}
+pub fn initialize_transfer_fee_config_verify_account_keys(
+    accounts: InitializeTransferFeeConfigAccounts<'_, '_>,
+    keys: InitializeTransferFeeConfigKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [(accounts.mint.key, &keys.mint)] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn initialize_transfer_fee_config_verify_writable_privileges<'me, 'info>(
+    accounts: InitializeTransferFeeConfigAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.mint] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn initialize_transfer_fee_config_verify_account_privileges<'me, 'info>(
+    accounts: InitializeTransferFeeConfigAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    initialize_transfer_fee_config_verify_writable_privileges(accounts)?;
+    Ok(())
+}
+pub const TRANSFER_CHECKED_WITH_FEE_IX_ACCOUNTS_LEN: usize = 4;
+#[derive(Copy, Clone, Debug)]
+pub struct TransferCheckedWithFeeAccounts<'me, 'info> {
+    ///The source token account to transfer from
+    pub from: &'me AccountInfo<'info>,
+    ///The token mint. Must include the TransferFeeConfig extension
+    pub mint: &'me AccountInfo<'info>,
+    ///The destination token account to transfer to
+    pub to: &'me AccountInfo<'info>,
+    ///from's authority/delegate. If multisig, this account is not a signer and the signing signatories must follow.
+    pub authority: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct TransferCheckedWithFeeKeys {
+    ///The source token account to transfer from
+    pub from: Pubkey,
+    ///The token mint. Must include the TransferFeeConfig extension
+    pub mint: Pubkey,
+    ///The destination token account to transfer to
+    pub to: Pubkey,
+    ///from's authority/delegate. If multisig, this account is not a signer and the signing signatories must follow.
+    pub authority: Pubkey,
+}
+impl From<TransferCheckedWithFeeAccounts<'_, '_>> for TransferCheckedWithFeeKeys {
+    fn from(accounts: TransferCheckedWithFeeAccounts) -> Self {
+        Self {
+            from: *accounts.from.key,
+            mint: *accounts.mint.key,
+            to: *accounts.to.key,
+            authority: *accounts.authority.key,
+        }
+    }
+}
+impl From<TransferCheckedWithFeeKeys> for [AccountMeta; TRANSFER_CHECKED_WITH_FEE_IX_ACCOUNTS_LEN] {
+    fn from(keys: TransferCheckedWithFeeKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.from,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.to,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.authority,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; TRANSFER_CHECKED_WITH_FEE_IX_ACCOUNTS_LEN]> for TransferCheckedWithFeeKeys {
+    fn from(pubkeys: [Pubkey; TRANSFER_CHECKED_WITH_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            from: pubkeys[0],
+            mint: pubkeys[1],
+            to: pubkeys[2],
+            authority: pubkeys[3],
+        }
+    }
+}
+impl<'info> From<TransferCheckedWithFeeAccounts<'_, 'info>>
+    for [AccountInfo<'info>; TRANSFER_CHECKED_WITH_FEE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: TransferCheckedWithFeeAccounts<'_, 'info>) -> Self {
+        [
+            accounts.from.clone(),
+            accounts.mint.clone(),
+            accounts.to.clone(),
+            accounts.authority.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; TRANSFER_CHECKED_WITH_FEE_IX_ACCOUNTS_LEN]>
+    for TransferCheckedWithFeeAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; TRANSFER_CHECKED_WITH_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            from: &arr[0],
+            mint: &arr[1],
+            to: &arr[2],
+            authority: &arr[3],
+        }
+    }
+}
+pub const TRANSFER_CHECKED_WITH_FEE_IX_DISCM: [u8; 2] = [26u8, 1u8];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct TransferCheckedWithFeeIxArgs {
+    pub args: CheckedOpArgs,
+    pub fee: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct TransferCheckedWithFeeIxData(pub TransferCheckedWithFeeIxArgs);
+impl From<TransferCheckedWithFeeIxArgs> for TransferCheckedWithFeeIxData {
+    fn from(args: TransferCheckedWithFeeIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl TransferCheckedWithFeeIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 2];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf;
+        if maybe_discm != TRANSFER_CHECKED_WITH_FEE_IX_DISCM {
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                TRANSFER_CHECKED_WITH_FEE_IX_DISCM, maybe_discm
+            )));
+        }
+        Ok(Self(TransferCheckedWithFeeIxArgs::deserialize(
+            &mut reader,
+        )?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&TRANSFER_CHECKED_WITH_FEE_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn transfer_checked_with_fee_ix_with_program_id(
+    program_id: Pubkey,
+    keys: TransferCheckedWithFeeKeys,
+    args: TransferCheckedWithFeeIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; TRANSFER_CHECKED_WITH_FEE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: TransferCheckedWithFeeIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn transfer_checked_with_fee_ix(
+    keys: TransferCheckedWithFeeKeys,
+    args: TransferCheckedWithFeeIxArgs,
+) -> std::io::Result<Instruction> {
+    transfer_checked_with_fee_ix_with_program_id(ID, keys, args)
+}
+pub fn transfer_checked_with_fee_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: TransferCheckedWithFeeAccounts<'_, '_>,
+    args: TransferCheckedWithFeeIxArgs,
+) -> ProgramResult {
+    let keys: TransferCheckedWithFeeKeys = accounts.into();
+    let ix = transfer_checked_with_fee_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn transfer_checked_with_fee_invoke(
+    accounts: TransferCheckedWithFeeAccounts<'_, '_>,
+    args: TransferCheckedWithFeeIxArgs,
+) -> ProgramResult {
+    transfer_checked_with_fee_invoke_with_program_id(ID, accounts, args)
+}
+pub fn transfer_checked_with_fee_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: TransferCheckedWithFeeAccounts<'_, '_>,
+    args: TransferCheckedWithFeeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: TransferCheckedWithFeeKeys = accounts.into();
+    let ix = transfer_checked_with_fee_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn transfer_checked_with_fee_invoke_signed(
+    accounts: TransferCheckedWithFeeAccounts<'_, '_>,
+    args: TransferCheckedWithFeeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    transfer_checked_with_fee_invoke_signed_with_program_id(ID, accounts, args, seeds)
+}
+pub fn transfer_checked_with_fee_verify_account_keys(
+    accounts: TransferCheckedWithFeeAccounts<'_, '_>,
+    keys: TransferCheckedWithFeeKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.from.key, &keys.from),
+        (accounts.mint.key, &keys.mint),
+        (accounts.to.key, &keys.to),
+        (accounts.authority.key, &keys.authority),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn transfer_checked_with_fee_verify_writable_privileges<'me, 'info>(
+    accounts: TransferCheckedWithFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.from, accounts.to] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn transfer_checked_with_fee_verify_signer_privileges<'me, 'info>(
+    accounts: TransferCheckedWithFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.authority] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn transfer_checked_with_fee_verify_account_privileges<'me, 'info>(
+    accounts: TransferCheckedWithFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    transfer_checked_with_fee_verify_writable_privileges(accounts)?;
+    transfer_checked_with_fee_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const WITHDRAW_WITHHELD_TOKENS_FROM_MINT_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone, Debug)]
+pub struct WithdrawWithheldTokensFromMintAccounts<'me, 'info> {
+    ///The token mint. Must include the TransferFeeConfig extension
+    pub mint: &'me AccountInfo<'info>,
+    ///The token account to withdraw the withheld tokens to
+    pub to: &'me AccountInfo<'info>,
+    ///The mint's withdraw_withheld_authority. If multisig, this account is not a signer and the signing signatories must follow.
+    pub authority: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct WithdrawWithheldTokensFromMintKeys {
+    ///The token mint. Must include the TransferFeeConfig extension
+    pub mint: Pubkey,
+    ///The token account to withdraw the withheld tokens to
+    pub to: Pubkey,
+    ///The mint's withdraw_withheld_authority. If multisig, this account is not a signer and the signing signatories must follow.
+    pub authority: Pubkey,
+}
+impl From<WithdrawWithheldTokensFromMintAccounts<'_, '_>> for WithdrawWithheldTokensFromMintKeys {
+    fn from(accounts: WithdrawWithheldTokensFromMintAccounts) -> Self {
+        Self {
+            mint: *accounts.mint.key,
+            to: *accounts.to.key,
+            authority: *accounts.authority.key,
+        }
+    }
+}
+impl From<WithdrawWithheldTokensFromMintKeys>
+    for [AccountMeta; WITHDRAW_WITHHELD_TOKENS_FROM_MINT_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: WithdrawWithheldTokensFromMintKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.to,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.authority,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; WITHDRAW_WITHHELD_TOKENS_FROM_MINT_IX_ACCOUNTS_LEN]>
+    for WithdrawWithheldTokensFromMintKeys
+{
+    fn from(pubkeys: [Pubkey; WITHDRAW_WITHHELD_TOKENS_FROM_MINT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            mint: pubkeys[0],
+            to: pubkeys[1],
+            authority: pubkeys[2],
+        }
+    }
+}
+impl<'info> From<WithdrawWithheldTokensFromMintAccounts<'_, 'info>>
+    for [AccountInfo<'info>; WITHDRAW_WITHHELD_TOKENS_FROM_MINT_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: WithdrawWithheldTokensFromMintAccounts<'_, 'info>) -> Self {
+        [
+            accounts.mint.clone(),
+            accounts.to.clone(),
+            accounts.authority.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; WITHDRAW_WITHHELD_TOKENS_FROM_MINT_IX_ACCOUNTS_LEN]>
+    for WithdrawWithheldTokensFromMintAccounts<'me, 'info>
+{
+    fn from(
+        arr: &'me [AccountInfo<'info>; WITHDRAW_WITHHELD_TOKENS_FROM_MINT_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            mint: &arr[0],
+            to: &arr[1],
+            authority: &arr[2],
+        }
+    }
+}
+pub const WITHDRAW_WITHHELD_TOKENS_FROM_MINT_IX_DISCM: [u8; 2] = [26u8, 2u8];
+#[derive(Clone, Debug, PartialEq)]
+pub struct WithdrawWithheldTokensFromMintIxData;
+impl WithdrawWithheldTokensFromMintIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 2];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf;
+        if maybe_discm != WITHDRAW_WITHHELD_TOKENS_FROM_MINT_IX_DISCM {
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                WITHDRAW_WITHHELD_TOKENS_FROM_MINT_IX_DISCM, maybe_discm
+            )));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&WITHDRAW_WITHHELD_TOKENS_FROM_MINT_IX_DISCM)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn withdraw_withheld_tokens_from_mint_ix_with_program_id(
+    program_id: Pubkey,
+    keys: WithdrawWithheldTokensFromMintKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; WITHDRAW_WITHHELD_TOKENS_FROM_MINT_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: WithdrawWithheldTokensFromMintIxData.try_to_vec()?,
+    })
+}
+pub fn withdraw_withheld_tokens_from_mint_ix(
+    keys: WithdrawWithheldTokensFromMintKeys,
+) -> std::io::Result<Instruction> {
+    withdraw_withheld_tokens_from_mint_ix_with_program_id(ID, keys)
+}
+pub fn withdraw_withheld_tokens_from_mint_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: WithdrawWithheldTokensFromMintAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: WithdrawWithheldTokensFromMintKeys = accounts.into();
+    let ix = withdraw_withheld_tokens_from_mint_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn withdraw_withheld_tokens_from_mint_invoke(
+    accounts: WithdrawWithheldTokensFromMintAccounts<'_, '_>,
+) -> ProgramResult {
+    withdraw_withheld_tokens_from_mint_invoke_with_program_id(ID, accounts)
+}
+pub fn withdraw_withheld_tokens_from_mint_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: WithdrawWithheldTokensFromMintAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: WithdrawWithheldTokensFromMintKeys = accounts.into();
+    let ix = withdraw_withheld_tokens_from_mint_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn withdraw_withheld_tokens_from_mint_invoke_signed(
+    accounts: WithdrawWithheldTokensFromMintAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    withdraw_withheld_tokens_from_mint_invoke_signed_with_program_id(ID, accounts, seeds)
+}
+pub fn withdraw_withheld_tokens_from_mint_verify_account_keys(
+    accounts: WithdrawWithheldTokensFromMintAccounts<'_, '_>,
+    keys: WithdrawWithheldTokensFromMintKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.mint.key, &keys.mint),
+        (accounts.to.key, &keys.to),
+        (accounts.authority.key, &keys.authority),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn withdraw_withheld_tokens_from_mint_verify_writable_privileges<'me, 'info>(
+    accounts: WithdrawWithheldTokensFromMintAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.mint, accounts.to] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn withdraw_withheld_tokens_from_mint_verify_signer_privileges<'me, 'info>(
+    accounts: WithdrawWithheldTokensFromMintAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.authority] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn withdraw_withheld_tokens_from_mint_verify_account_privileges<'me, 'info>(
+    accounts: WithdrawWithheldTokensFromMintAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    withdraw_withheld_tokens_from_mint_verify_writable_privileges(accounts)?;
+    withdraw_withheld_tokens_from_mint_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const WITHDRAW_WITHHELD_TOKENS_FROM_ACCOUNTS_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone, Debug)]
+pub struct WithdrawWithheldTokensFromAccountsAccounts<'me, 'info> {
+    ///The token mint. Must include the TransferFeeConfig extension
+    pub mint: &'me AccountInfo<'info>,
+    ///The token account to withdraw the withheld tokens to
+    pub to: &'me AccountInfo<'info>,
+    ///The mint's withdraw_withheld_authority. If multisig, this account is not a signer and the signing signatories must follow. The writable source token accounts to withdraw from follow after.
+    pub authority: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct WithdrawWithheldTokensFromAccountsKeys {
+    ///The token mint. Must include the TransferFeeConfig extension
+    pub mint: Pubkey,
+    ///The token account to withdraw the withheld tokens to
+    pub to: Pubkey,
+    ///The mint's withdraw_withheld_authority. If multisig, this account is not a signer and the signing signatories must follow. The writable source token accounts to withdraw from follow after.
+    pub authority: Pubkey,
+}
+impl From<WithdrawWithheldTokensFromAccountsAccounts<'_, '_>>
+    for WithdrawWithheldTokensFromAccountsKeys
+{
+    fn from(accounts: WithdrawWithheldTokensFromAccountsAccounts) -> Self {
+        Self {
+            mint: *accounts.mint.key,
+            to: *accounts.to.key,
+            authority: *accounts.authority.key,
+        }
+    }
+}
+impl From<WithdrawWithheldTokensFromAccountsKeys>
+    for [AccountMeta; WITHDRAW_WITHHELD_TOKENS_FROM_ACCOUNTS_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: WithdrawWithheldTokensFromAccountsKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.to,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.authority,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; WITHDRAW_WITHHELD_TOKENS_FROM_ACCOUNTS_IX_ACCOUNTS_LEN]>
+    for WithdrawWithheldTokensFromAccountsKeys
+{
+    fn from(pubkeys: [Pubkey; WITHDRAW_WITHHELD_TOKENS_FROM_ACCOUNTS_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            mint: pubkeys[0],
+            to: pubkeys[1],
+            authority: pubkeys[2],
+        }
+    }
+}
+impl<'info> From<WithdrawWithheldTokensFromAccountsAccounts<'_, 'info>>
+    for [AccountInfo<'info>; WITHDRAW_WITHHELD_TOKENS_FROM_ACCOUNTS_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: WithdrawWithheldTokensFromAccountsAccounts<'_, 'info>) -> Self {
+        [
+            accounts.mint.clone(),
+            accounts.to.clone(),
+            accounts.authority.clone(),
+        ]
+    }
+}
+impl<'me, 'info>
+    From<&'me [AccountInfo<'info>; WITHDRAW_WITHHELD_TOKENS_FROM_ACCOUNTS_IX_ACCOUNTS_LEN]>
+    for WithdrawWithheldTokensFromAccountsAccounts<'me, 'info>
+{
+    fn from(
+        arr: &'me [AccountInfo<'info>; WITHDRAW_WITHHELD_TOKENS_FROM_ACCOUNTS_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            mint: &arr[0],
+            to: &arr[1],
+            authority: &arr[2],
+        }
+    }
+}
+pub const WITHDRAW_WITHHELD_TOKENS_FROM_ACCOUNTS_IX_DISCM: [u8; 2] = [26u8, 3u8];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct WithdrawWithheldTokensFromAccountsIxArgs {
+    pub num_token_accounts: u8,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct WithdrawWithheldTokensFromAccountsIxData(pub WithdrawWithheldTokensFromAccountsIxArgs);
+impl From<WithdrawWithheldTokensFromAccountsIxArgs> for WithdrawWithheldTokensFromAccountsIxData {
+    fn from(args: WithdrawWithheldTokensFromAccountsIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl WithdrawWithheldTokensFromAccountsIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 2];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf;
+        if maybe_discm != WITHDRAW_WITHHELD_TOKENS_FROM_ACCOUNTS_IX_DISCM {
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                WITHDRAW_WITHHELD_TOKENS_FROM_ACCOUNTS_IX_DISCM, maybe_discm
+            )));
+        }
+        Ok(Self(WithdrawWithheldTokensFromAccountsIxArgs::deserialize(
+            &mut reader,
+        )?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&WITHDRAW_WITHHELD_TOKENS_FROM_ACCOUNTS_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn withdraw_withheld_tokens_from_accounts_ix_with_program_id(
+    program_id: Pubkey,
+    keys: WithdrawWithheldTokensFromAccountsKeys,
+    args: WithdrawWithheldTokensFromAccountsIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; WITHDRAW_WITHHELD_TOKENS_FROM_ACCOUNTS_IX_ACCOUNTS_LEN] = keys.into();
+    let data: WithdrawWithheldTokensFromAccountsIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn withdraw_withheld_tokens_from_accounts_ix(
+    keys: WithdrawWithheldTokensFromAccountsKeys,
+    args: WithdrawWithheldTokensFromAccountsIxArgs,
+) -> std::io::Result<Instruction> {
+    withdraw_withheld_tokens_from_accounts_ix_with_program_id(ID, keys, args)
+}
+pub fn withdraw_withheld_tokens_from_accounts_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: WithdrawWithheldTokensFromAccountsAccounts<'_, '_>,
+    args: WithdrawWithheldTokensFromAccountsIxArgs,
+) -> ProgramResult {
+    let keys: WithdrawWithheldTokensFromAccountsKeys = accounts.into();
+    let ix = withdraw_withheld_tokens_from_accounts_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn withdraw_withheld_tokens_from_accounts_invoke(
+    accounts: WithdrawWithheldTokensFromAccountsAccounts<'_, '_>,
+    args: WithdrawWithheldTokensFromAccountsIxArgs,
+) -> ProgramResult {
+    withdraw_withheld_tokens_from_accounts_invoke_with_program_id(ID, accounts, args)
+}
+pub fn withdraw_withheld_tokens_from_accounts_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: WithdrawWithheldTokensFromAccountsAccounts<'_, '_>,
+    args: WithdrawWithheldTokensFromAccountsIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: WithdrawWithheldTokensFromAccountsKeys = accounts.into();
+    let ix = withdraw_withheld_tokens_from_accounts_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn withdraw_withheld_tokens_from_accounts_invoke_signed(
+    accounts: WithdrawWithheldTokensFromAccountsAccounts<'_, '_>,
+    args: WithdrawWithheldTokensFromAccountsIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    withdraw_withheld_tokens_from_accounts_invoke_signed_with_program_id(ID, accounts, args, seeds)
+}
+pub fn withdraw_withheld_tokens_from_accounts_verify_account_keys(
+    accounts: WithdrawWithheldTokensFromAccountsAccounts<'_, '_>,
+    keys: WithdrawWithheldTokensFromAccountsKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.mint.key, &keys.mint),
+        (accounts.to.key, &keys.to),
+        (accounts.authority.key, &keys.authority),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn withdraw_withheld_tokens_from_accounts_verify_writable_privileges<'me, 'info>(
+    accounts: WithdrawWithheldTokensFromAccountsAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.to] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn withdraw_withheld_tokens_from_accounts_verify_signer_privileges<'me, 'info>(
+    accounts: WithdrawWithheldTokensFromAccountsAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.authority] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn withdraw_withheld_tokens_from_accounts_verify_account_privileges<'me, 'info>(
+    accounts: WithdrawWithheldTokensFromAccountsAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    withdraw_withheld_tokens_from_accounts_verify_writable_privileges(accounts)?;
+    withdraw_withheld_tokens_from_accounts_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const HARVEST_WITHHELD_TOKENS_TO_MINT_IX_ACCOUNTS_LEN: usize = 1;
+#[derive(Copy, Clone, Debug)]
+pub struct HarvestWithheldTokensToMintAccounts<'me, 'info> {
+    ///The token mint. The writable source token accounts to harvest from follow after.
+    pub mint: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct HarvestWithheldTokensToMintKeys {
+    ///The token mint. The writable source token accounts to harvest from follow after.
+    pub mint: Pubkey,
+}
+impl From<HarvestWithheldTokensToMintAccounts<'_, '_>> for HarvestWithheldTokensToMintKeys {
+    fn from(accounts: HarvestWithheldTokensToMintAccounts) -> Self {
+        Self {
+            mint: *accounts.mint.key,
+        }
+    }
+}
+impl From<HarvestWithheldTokensToMintKeys>
+    for [AccountMeta; HARVEST_WITHHELD_TOKENS_TO_MINT_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: HarvestWithheldTokensToMintKeys) -> Self {
+        [AccountMeta {
+            pubkey: keys.mint,
+            is_signer: false,
+            is_writable: true,
+        }]
+    }
+}
+impl From<[Pubkey; HARVEST_WITHHELD_TOKENS_TO_MINT_IX_ACCOUNTS_LEN]>
+    for HarvestWithheldTokensToMintKeys
+{
+    fn from(pubkeys: [Pubkey; HARVEST_WITHHELD_TOKENS_TO_MINT_IX_ACCOUNTS_LEN]) -> Self {
+        Self { mint: pubkeys[0] }
+    }
+}
+impl<'info> From<HarvestWithheldTokensToMintAccounts<'_, 'info>>
+    for [AccountInfo<'info>; HARVEST_WITHHELD_TOKENS_TO_MINT_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: HarvestWithheldTokensToMintAccounts<'_, 'info>) -> Self {
+        [accounts.mint.clone()]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; HARVEST_WITHHELD_TOKENS_TO_MINT_IX_ACCOUNTS_LEN]>
+    for HarvestWithheldTokensToMintAccounts<'me, 'info>
+{
+    fn from(
+        arr: &'me [AccountInfo<'info>; HARVEST_WITHHELD_TOKENS_TO_MINT_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self { mint: &arr[0] }
+    }
+}
+pub const HARVEST_WITHHELD_TOKENS_TO_MINT_IX_DISCM: [u8; 2] = [26u8, 4u8];
+#[derive(Clone, Debug, PartialEq)]
+pub struct HarvestWithheldTokensToMintIxData;
+impl HarvestWithheldTokensToMintIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 2];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf;
+        if maybe_discm != HARVEST_WITHHELD_TOKENS_TO_MINT_IX_DISCM {
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                HARVEST_WITHHELD_TOKENS_TO_MINT_IX_DISCM, maybe_discm
+            )));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&HARVEST_WITHHELD_TOKENS_TO_MINT_IX_DISCM)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn harvest_withheld_tokens_to_mint_ix_with_program_id(
+    program_id: Pubkey,
+    keys: HarvestWithheldTokensToMintKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; HARVEST_WITHHELD_TOKENS_TO_MINT_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: HarvestWithheldTokensToMintIxData.try_to_vec()?,
+    })
+}
+pub fn harvest_withheld_tokens_to_mint_ix(
+    keys: HarvestWithheldTokensToMintKeys,
+) -> std::io::Result<Instruction> {
+    harvest_withheld_tokens_to_mint_ix_with_program_id(ID, keys)
+}
+pub fn harvest_withheld_tokens_to_mint_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: HarvestWithheldTokensToMintAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: HarvestWithheldTokensToMintKeys = accounts.into();
+    let ix = harvest_withheld_tokens_to_mint_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn harvest_withheld_tokens_to_mint_invoke(
+    accounts: HarvestWithheldTokensToMintAccounts<'_, '_>,
+) -> ProgramResult {
+    harvest_withheld_tokens_to_mint_invoke_with_program_id(ID, accounts)
+}
+pub fn harvest_withheld_tokens_to_mint_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: HarvestWithheldTokensToMintAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: HarvestWithheldTokensToMintKeys = accounts.into();
+    let ix = harvest_withheld_tokens_to_mint_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn harvest_withheld_tokens_to_mint_invoke_signed(
+    accounts: HarvestWithheldTokensToMintAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    harvest_withheld_tokens_to_mint_invoke_signed_with_program_id(ID, accounts, seeds)
+}
+pub fn harvest_withheld_tokens_to_mint_verify_account_keys(
+    accounts: HarvestWithheldTokensToMintAccounts<'_, '_>,
+    keys: HarvestWithheldTokensToMintKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [(accounts.mint.key, &keys.mint)] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn harvest_withheld_tokens_to_mint_verify_writable_privileges<'me, 'info>(
+    accounts: HarvestWithheldTokensToMintAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.mint] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn harvest_withheld_tokens_to_mint_verify_account_privileges<'me, 'info>(
+    accounts: HarvestWithheldTokensToMintAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    harvest_withheld_tokens_to_mint_verify_writable_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_TRANSFER_FEE_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone, Debug)]
+pub struct SetTransferFeeAccounts<'me, 'info> {
+    ///The token mint
+    pub mint: &'me AccountInfo<'info>,
+    ///The mint's transfer_fee_config_authority. If multisig, this account is not a signer and the signing signatories must follow.
+    pub authority: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetTransferFeeKeys {
+    ///The token mint
+    pub mint: Pubkey,
+    ///The mint's transfer_fee_config_authority. If multisig, this account is not a signer and the signing signatories must follow.
+    pub authority: Pubkey,
+}
+impl From<SetTransferFeeAccounts<'_, '_>> for SetTransferFeeKeys {
+    fn from(accounts: SetTransferFeeAccounts) -> Self {
+        Self {
+            mint: *accounts.mint.key,
+            authority: *accounts.authority.key,
+        }
+    }
+}
+impl From<SetTransferFeeKeys> for [AccountMeta; SET_TRANSFER_FEE_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetTransferFeeKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.authority,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_TRANSFER_FEE_IX_ACCOUNTS_LEN]> for SetTransferFeeKeys {
+    fn from(pubkeys: [Pubkey; SET_TRANSFER_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            mint: pubkeys[0],
+            authority: pubkeys[1],
+        }
+    }
+}
+impl<'info> From<SetTransferFeeAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_TRANSFER_FEE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetTransferFeeAccounts<'_, 'info>) -> Self {
+        [accounts.mint.clone(), accounts.authority.clone()]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_TRANSFER_FEE_IX_ACCOUNTS_LEN]>
+    for SetTransferFeeAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SET_TRANSFER_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            mint: &arr[0],
+            authority: &arr[1],
+        }
+    }
+}
+pub const SET_TRANSFER_FEE_IX_DISCM: [u8; 2] = [26u8, 5u8];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct SetTransferFeeIxArgs {
+    pub transfer_fee_basis_points: u16,
+    pub maximum_fee: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetTransferFeeIxData(pub SetTransferFeeIxArgs);
+impl From<SetTransferFeeIxArgs> for SetTransferFeeIxData {
+    fn from(args: SetTransferFeeIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetTransferFeeIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 2];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf;
+        if maybe_discm != SET_TRANSFER_FEE_IX_DISCM {
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                SET_TRANSFER_FEE_IX_DISCM, maybe_discm
+            )));
+        }
+        Ok(Self(SetTransferFeeIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&SET_TRANSFER_FEE_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_transfer_fee_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetTransferFeeKeys,
+    args: SetTransferFeeIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_TRANSFER_FEE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetTransferFeeIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_transfer_fee_ix(
+    keys: SetTransferFeeKeys,
+    args: SetTransferFeeIxArgs,
+) -> std::io::Result<Instruction> {
+    set_transfer_fee_ix_with_program_id(ID, keys, args)
+}
+pub fn set_transfer_fee_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetTransferFeeAccounts<'_, '_>,
+    args: SetTransferFeeIxArgs,
+) -> ProgramResult {
+    let keys: SetTransferFeeKeys = accounts.into();
+    let ix = set_transfer_fee_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_transfer_fee_invoke(
+    accounts: SetTransferFeeAccounts<'_, '_>,
+    args: SetTransferFeeIxArgs,
+) -> ProgramResult {
+    set_transfer_fee_invoke_with_program_id(ID, accounts, args)
+}
+pub fn set_transfer_fee_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetTransferFeeAccounts<'_, '_>,
+    args: SetTransferFeeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetTransferFeeKeys = accounts.into();
+    let ix = set_transfer_fee_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_transfer_fee_invoke_signed(
+    accounts: SetTransferFeeAccounts<'_, '_>,
+    args: SetTransferFeeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_transfer_fee_invoke_signed_with_program_id(ID, accounts, args, seeds)
+}
+pub fn set_transfer_fee_verify_account_keys(
+    accounts: SetTransferFeeAccounts<'_, '_>,
+    keys: SetTransferFeeKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.mint.key, &keys.mint),
+        (accounts.authority.key, &keys.authority),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_transfer_fee_verify_writable_privileges<'me, 'info>(
+    accounts: SetTransferFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.mint] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_transfer_fee_verify_signer_privileges<'me, 'info>(
+    accounts: SetTransferFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.authority] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_transfer_fee_verify_account_privileges<'me, 'info>(
+    accounts: SetTransferFeeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_transfer_fee_verify_writable_privileges(accounts)?;
+    set_transfer_fee_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const ENABLE_REQUIRED_TRANSFER_MEMOS_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone, Debug)]
+pub struct EnableRequiredTransferMemosAccounts<'me, 'info> {
+    ///The token account to update
+    pub token_account: &'me AccountInfo<'info>,
+    ///The token account's owner. If multisig, this account is not a signer and the signing signatories must follow.
+    pub authority: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct EnableRequiredTransferMemosKeys {
+    ///The token account to update
+    pub token_account: Pubkey,
+    ///The token account's owner. If multisig, this account is not a signer and the signing signatories must follow.
+    pub authority: Pubkey,
+}
+impl From<EnableRequiredTransferMemosAccounts<'_, '_>> for EnableRequiredTransferMemosKeys {
+    fn from(accounts: EnableRequiredTransferMemosAccounts) -> Self {
+        Self {
+            token_account: *accounts.token_account.key,
+            authority: *accounts.authority.key,
+        }
+    }
+}
+impl From<EnableRequiredTransferMemosKeys>
+    for [AccountMeta; ENABLE_REQUIRED_TRANSFER_MEMOS_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: EnableRequiredTransferMemosKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.token_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.authority,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; ENABLE_REQUIRED_TRANSFER_MEMOS_IX_ACCOUNTS_LEN]>
+    for EnableRequiredTransferMemosKeys
+{
+    fn from(pubkeys: [Pubkey; ENABLE_REQUIRED_TRANSFER_MEMOS_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            token_account: pubkeys[0],
+            authority: pubkeys[1],
+        }
+    }
+}
+impl<'info> From<EnableRequiredTransferMemosAccounts<'_, 'info>>
+    for [AccountInfo<'info>; ENABLE_REQUIRED_TRANSFER_MEMOS_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: EnableRequiredTransferMemosAccounts<'_, 'info>) -> Self {
+        [accounts.token_account.clone(), accounts.authority.clone()]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; ENABLE_REQUIRED_TRANSFER_MEMOS_IX_ACCOUNTS_LEN]>
+    for EnableRequiredTransferMemosAccounts<'me, 'info>
+{
+    fn from(
+        arr: &'me [AccountInfo<'info>; ENABLE_REQUIRED_TRANSFER_MEMOS_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            token_account: &arr[0],
+            authority: &arr[1],
+        }
+    }
+}
+pub const ENABLE_REQUIRED_TRANSFER_MEMOS_IX_DISCM: [u8; 2] = [30u8, 0u8];
+#[derive(Clone, Debug, PartialEq)]
+pub struct EnableRequiredTransferMemosIxData;
+impl EnableRequiredTransferMemosIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 2];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf;
+        if maybe_discm != ENABLE_REQUIRED_TRANSFER_MEMOS_IX_DISCM {
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                ENABLE_REQUIRED_TRANSFER_MEMOS_IX_DISCM, maybe_discm
+            )));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&ENABLE_REQUIRED_TRANSFER_MEMOS_IX_DISCM)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn enable_required_transfer_memos_ix_with_program_id(
+    program_id: Pubkey,
+    keys: EnableRequiredTransferMemosKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; ENABLE_REQUIRED_TRANSFER_MEMOS_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: EnableRequiredTransferMemosIxData.try_to_vec()?,
+    })
+}
+pub fn enable_required_transfer_memos_ix(
+    keys: EnableRequiredTransferMemosKeys,
+) -> std::io::Result<Instruction> {
+    enable_required_transfer_memos_ix_with_program_id(ID, keys)
+}
+pub fn enable_required_transfer_memos_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: EnableRequiredTransferMemosAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: EnableRequiredTransferMemosKeys = accounts.into();
+    let ix = enable_required_transfer_memos_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn enable_required_transfer_memos_invoke(
+    accounts: EnableRequiredTransferMemosAccounts<'_, '_>,
+) -> ProgramResult {
+    enable_required_transfer_memos_invoke_with_program_id(ID, accounts)
+}
+pub fn enable_required_transfer_memos_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: EnableRequiredTransferMemosAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: EnableRequiredTransferMemosKeys = accounts.into();
+    let ix = enable_required_transfer_memos_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn enable_required_transfer_memos_invoke_signed(
+    accounts: EnableRequiredTransferMemosAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    enable_required_transfer_memos_invoke_signed_with_program_id(ID, accounts, seeds)
+}
+pub fn enable_required_transfer_memos_verify_account_keys(
+    accounts: EnableRequiredTransferMemosAccounts<'_, '_>,
+    keys: EnableRequiredTransferMemosKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.token_account.key, &keys.token_account),
+        (accounts.authority.key, &keys.authority),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn enable_required_transfer_memos_verify_writable_privileges<'me, 'info>(
+    accounts: EnableRequiredTransferMemosAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.token_account] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn enable_required_transfer_memos_verify_signer_privileges<'me, 'info>(
+    accounts: EnableRequiredTransferMemosAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.authority] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn enable_required_transfer_memos_verify_account_privileges<'me, 'info>(
+    accounts: EnableRequiredTransferMemosAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    enable_required_transfer_memos_verify_writable_privileges(accounts)?;
+    enable_required_transfer_memos_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const DISABLE_REQUIRED_TRANSFER_MEMOS_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone, Debug)]
+pub struct DisableRequiredTransferMemosAccounts<'me, 'info> {
+    ///The token account to update
+    pub token_account: &'me AccountInfo<'info>,
+    ///The token account's owner. If multisig, this account is not a signer and the signing signatories must follow.
+    pub authority: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct DisableRequiredTransferMemosKeys {
+    ///The token account to update
+    pub token_account: Pubkey,
+    ///The token account's owner. If multisig, this account is not a signer and the signing signatories must follow.
+    pub authority: Pubkey,
+}
+impl From<DisableRequiredTransferMemosAccounts<'_, '_>> for DisableRequiredTransferMemosKeys {
+    fn from(accounts: DisableRequiredTransferMemosAccounts) -> Self {
+        Self {
+            token_account: *accounts.token_account.key,
+            authority: *accounts.authority.key,
+        }
+    }
+}
+impl From<DisableRequiredTransferMemosKeys>
+    for [AccountMeta; DISABLE_REQUIRED_TRANSFER_MEMOS_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: DisableRequiredTransferMemosKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.token_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.authority,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; DISABLE_REQUIRED_TRANSFER_MEMOS_IX_ACCOUNTS_LEN]>
+    for DisableRequiredTransferMemosKeys
+{
+    fn from(pubkeys: [Pubkey; DISABLE_REQUIRED_TRANSFER_MEMOS_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            token_account: pubkeys[0],
+            authority: pubkeys[1],
+        }
+    }
+}
+impl<'info> From<DisableRequiredTransferMemosAccounts<'_, 'info>>
+    for [AccountInfo<'info>; DISABLE_REQUIRED_TRANSFER_MEMOS_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: DisableRequiredTransferMemosAccounts<'_, 'info>) -> Self {
+        [accounts.token_account.clone(), accounts.authority.clone()]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; DISABLE_REQUIRED_TRANSFER_MEMOS_IX_ACCOUNTS_LEN]>
+    for DisableRequiredTransferMemosAccounts<'me, 'info>
+{
+    fn from(
+        arr: &'me [AccountInfo<'info>; DISABLE_REQUIRED_TRANSFER_MEMOS_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            token_account: &arr[0],
+            authority: &arr[1],
+        }
+    }
+}
+pub const DISABLE_REQUIRED_TRANSFER_MEMOS_IX_DISCM: [u8; 2] = [30u8, 1u8];
+#[derive(Clone, Debug, PartialEq)]
+pub struct DisableRequiredTransferMemosIxData;
+impl DisableRequiredTransferMemosIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 2];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf;
+        if maybe_discm != DISABLE_REQUIRED_TRANSFER_MEMOS_IX_DISCM {
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                DISABLE_REQUIRED_TRANSFER_MEMOS_IX_DISCM, maybe_discm
+            )));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&DISABLE_REQUIRED_TRANSFER_MEMOS_IX_DISCM)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn disable_required_transfer_memos_ix_with_program_id(
+    program_id: Pubkey,
+    keys: DisableRequiredTransferMemosKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; DISABLE_REQUIRED_TRANSFER_MEMOS_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: DisableRequiredTransferMemosIxData.try_to_vec()?,
+    })
+}
+pub fn disable_required_transfer_memos_ix(
+    keys: DisableRequiredTransferMemosKeys,
+) -> std::io::Result<Instruction> {
+    disable_required_transfer_memos_ix_with_program_id(ID, keys)
+}
+pub fn disable_required_transfer_memos_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: DisableRequiredTransferMemosAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: DisableRequiredTransferMemosKeys = accounts.into();
+    let ix = disable_required_transfer_memos_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn disable_required_transfer_memos_invoke(
+    accounts: DisableRequiredTransferMemosAccounts<'_, '_>,
+) -> ProgramResult {
+    disable_required_transfer_memos_invoke_with_program_id(ID, accounts)
+}
+pub fn disable_required_transfer_memos_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: DisableRequiredTransferMemosAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: DisableRequiredTransferMemosKeys = accounts.into();
+    let ix = disable_required_transfer_memos_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn disable_required_transfer_memos_invoke_signed(
+    accounts: DisableRequiredTransferMemosAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    disable_required_transfer_memos_invoke_signed_with_program_id(ID, accounts, seeds)
+}
+pub fn disable_required_transfer_memos_verify_account_keys(
+    accounts: DisableRequiredTransferMemosAccounts<'_, '_>,
+    keys: DisableRequiredTransferMemosKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.token_account.key, &keys.token_account),
+        (accounts.authority.key, &keys.authority),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn disable_required_transfer_memos_verify_writable_privileges<'me, 'info>(
+    accounts: DisableRequiredTransferMemosAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.token_account] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn disable_required_transfer_memos_verify_signer_privileges<'me, 'info>(
+    accounts: DisableRequiredTransferMemosAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.authority] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn disable_required_transfer_memos_verify_account_privileges<'me, 'info>(
+    accounts: DisableRequiredTransferMemosAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    disable_required_transfer_memos_verify_writable_privileges(accounts)?;
+    disable_required_transfer_memos_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const INITIALIZE_INTEREST_BEARING_MINT_IX_ACCOUNTS_LEN: usize = 1;
+#[derive(Copy, Clone, Debug)]
+pub struct InitializeInterestBearingMintAccounts<'me, 'info> {
+    ///The mint to initialize
+    pub mint: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct InitializeInterestBearingMintKeys {
+    ///The mint to initialize
+    pub mint: Pubkey,
+}
+impl From<InitializeInterestBearingMintAccounts<'_, '_>> for InitializeInterestBearingMintKeys {
+    fn from(accounts: InitializeInterestBearingMintAccounts) -> Self {
+        Self {
+            mint: *accounts.mint.key,
+        }
+    }
+}
+impl From<InitializeInterestBearingMintKeys>
+    for [AccountMeta; INITIALIZE_INTEREST_BEARING_MINT_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: InitializeInterestBearingMintKeys) -> Self {
+        [AccountMeta {
+            pubkey: keys.mint,
+            is_signer: false,
+            is_writable: true,
+        }]
+    }
+}
+impl From<[Pubkey; INITIALIZE_INTEREST_BEARING_MINT_IX_ACCOUNTS_LEN]>
+    for InitializeInterestBearingMintKeys
+{
+    fn from(pubkeys: [Pubkey; INITIALIZE_INTEREST_BEARING_MINT_IX_ACCOUNTS_LEN]) -> Self {
+        Self { mint: pubkeys[0] }
+    }
+}
+impl<'info> From<InitializeInterestBearingMintAccounts<'_, 'info>>
+    for [AccountInfo<'info>; INITIALIZE_INTEREST_BEARING_MINT_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: InitializeInterestBearingMintAccounts<'_, 'info>) -> Self {
+        [accounts.mint.clone()]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_INTEREST_BEARING_MINT_IX_ACCOUNTS_LEN]>
+    for InitializeInterestBearingMintAccounts<'me, 'info>
+{
+    fn from(
+        arr: &'me [AccountInfo<'info>; INITIALIZE_INTEREST_BEARING_MINT_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self { mint: &arr[0] }
+    }
+}
+pub const INITIALIZE_INTEREST_BEARING_MINT_IX_DISCM: [u8; 2] = [33u8, 0u8];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct InitializeInterestBearingMintIxArgs {
+    pub rate_authority: Pubkey,
+    pub rate: i16,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct InitializeInterestBearingMintIxData(pub InitializeInterestBearingMintIxArgs);
+impl From<InitializeInterestBearingMintIxArgs> for InitializeInterestBearingMintIxData {
+    fn from(args: InitializeInterestBearingMintIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl InitializeInterestBearingMintIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 2];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf;
+        if maybe_discm != INITIALIZE_INTEREST_BEARING_MINT_IX_DISCM {
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                INITIALIZE_INTEREST_BEARING_MINT_IX_DISCM, maybe_discm
+            )));
+        }
+        Ok(Self(InitializeInterestBearingMintIxArgs::deserialize(
+            &mut reader,
+        )?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&INITIALIZE_INTEREST_BEARING_MINT_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn initialize_interest_bearing_mint_ix_with_program_id(
+    program_id: Pubkey,
+    keys: InitializeInterestBearingMintKeys,
+    args: InitializeInterestBearingMintIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; INITIALIZE_INTEREST_BEARING_MINT_IX_ACCOUNTS_LEN] = keys.into();
+    let data: InitializeInterestBearingMintIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn initialize_interest_bearing_mint_ix(
+    keys: InitializeInterestBearingMintKeys,
+    args: InitializeInterestBearingMintIxArgs,
+) -> std::io::Result<Instruction> {
+    initialize_interest_bearing_mint_ix_with_program_id(ID, keys, args)
+}
+pub fn initialize_interest_bearing_mint_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: InitializeInterestBearingMintAccounts<'_, '_>,
+    args: InitializeInterestBearingMintIxArgs,
+) -> ProgramResult {
+    let keys: InitializeInterestBearingMintKeys = accounts.into();
+    let ix = initialize_interest_bearing_mint_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn initialize_interest_bearing_mint_invoke(
+    accounts: InitializeInterestBearingMintAccounts<'_, '_>,
+    args: InitializeInterestBearingMintIxArgs,
+) -> ProgramResult {
+    initialize_interest_bearing_mint_invoke_with_program_id(ID, accounts, args)
+}
+pub fn initialize_interest_bearing_mint_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: InitializeInterestBearingMintAccounts<'_, '_>,
+    args: InitializeInterestBearingMintIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: InitializeInterestBearingMintKeys = accounts.into();
+    let ix = initialize_interest_bearing_mint_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn initialize_interest_bearing_mint_invoke_signed(
+    accounts: InitializeInterestBearingMintAccounts<'_, '_>,
+    args: InitializeInterestBearingMintIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    initialize_interest_bearing_mint_invoke_signed_with_program_id(ID, accounts, args, seeds)
+}
+pub fn initialize_interest_bearing_mint_verify_account_keys(
+    accounts: InitializeInterestBearingMintAccounts<'_, '_>,
+    keys: InitializeInterestBearingMintKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [(accounts.mint.key, &keys.mint)] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn initialize_interest_bearing_mint_verify_writable_privileges<'me, 'info>(
+    accounts: InitializeInterestBearingMintAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.mint] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn initialize_interest_bearing_mint_verify_account_privileges<'me, 'info>(
+    accounts: InitializeInterestBearingMintAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    initialize_interest_bearing_mint_verify_writable_privileges(accounts)?;
+    Ok(())
+}
+pub const UPDATE_INTEREST_BEARING_MINT_RATE_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone, Debug)]
+pub struct UpdateInterestBearingMintRateAccounts<'me, 'info> {
+    ///The token mint
+    pub mint: &'me AccountInfo<'info>,
+    ///The mint's rate authority. If multisig, this account is not a signer and the signing signatories must follow.
+    pub authority: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct UpdateInterestBearingMintRateKeys {
+    ///The token mint
+    pub mint: Pubkey,
+    ///The mint's rate authority. If multisig, this account is not a signer and the signing signatories must follow.
+    pub authority: Pubkey,
+}
+impl From<UpdateInterestBearingMintRateAccounts<'_, '_>> for UpdateInterestBearingMintRateKeys {
+    fn from(accounts: UpdateInterestBearingMintRateAccounts) -> Self {
+        Self {
+            mint: *accounts.mint.key,
+            authority: *accounts.authority.key,
+        }
+    }
+}
+impl From<UpdateInterestBearingMintRateKeys>
+    for [AccountMeta; UPDATE_INTEREST_BEARING_MINT_RATE_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: UpdateInterestBearingMintRateKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.authority,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; UPDATE_INTEREST_BEARING_MINT_RATE_IX_ACCOUNTS_LEN]>
+    for UpdateInterestBearingMintRateKeys
+{
+    fn from(pubkeys: [Pubkey; UPDATE_INTEREST_BEARING_MINT_RATE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            mint: pubkeys[0],
+            authority: pubkeys[1],
+        }
+    }
+}
+impl<'info> From<UpdateInterestBearingMintRateAccounts<'_, 'info>>
+    for [AccountInfo<'info>; UPDATE_INTEREST_BEARING_MINT_RATE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: UpdateInterestBearingMintRateAccounts<'_, 'info>) -> Self {
+        [accounts.mint.clone(), accounts.authority.clone()]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_INTEREST_BEARING_MINT_RATE_IX_ACCOUNTS_LEN]>
+    for UpdateInterestBearingMintRateAccounts<'me, 'info>
+{
+    fn from(
+        arr: &'me [AccountInfo<'info>; UPDATE_INTEREST_BEARING_MINT_RATE_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            mint: &arr[0],
+            authority: &arr[1],
+        }
+    }
+}
+pub const UPDATE_INTEREST_BEARING_MINT_RATE_IX_DISCM: [u8; 2] = [33u8, 1u8];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateInterestBearingMintRateIxArgs {
+    pub rate: i16,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct UpdateInterestBearingMintRateIxData(pub UpdateInterestBearingMintRateIxArgs);
+impl From<UpdateInterestBearingMintRateIxArgs> for UpdateInterestBearingMintRateIxData {
+    fn from(args: UpdateInterestBearingMintRateIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl UpdateInterestBearingMintRateIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 2];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf;
+        if maybe_discm != UPDATE_INTEREST_BEARING_MINT_RATE_IX_DISCM {
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_INTEREST_BEARING_MINT_RATE_IX_DISCM, maybe_discm
+            )));
+        }
+        Ok(Self(UpdateInterestBearingMintRateIxArgs::deserialize(
+            &mut reader,
+        )?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&UPDATE_INTEREST_BEARING_MINT_RATE_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn update_interest_bearing_mint_rate_ix_with_program_id(
+    program_id: Pubkey,
+    keys: UpdateInterestBearingMintRateKeys,
+    args: UpdateInterestBearingMintRateIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; UPDATE_INTEREST_BEARING_MINT_RATE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: UpdateInterestBearingMintRateIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn update_interest_bearing_mint_rate_ix(
+    keys: UpdateInterestBearingMintRateKeys,
+    args: UpdateInterestBearingMintRateIxArgs,
+) -> std::io::Result<Instruction> {
+    update_interest_bearing_mint_rate_ix_with_program_id(ID, keys, args)
+}
+pub fn update_interest_bearing_mint_rate_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: UpdateInterestBearingMintRateAccounts<'_, '_>,
+    args: UpdateInterestBearingMintRateIxArgs,
+) -> ProgramResult {
+    let keys: UpdateInterestBearingMintRateKeys = accounts.into();
+    let ix = update_interest_bearing_mint_rate_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn update_interest_bearing_mint_rate_invoke(
+    accounts: UpdateInterestBearingMintRateAccounts<'_, '_>,
+    args: UpdateInterestBearingMintRateIxArgs,
+) -> ProgramResult {
+    update_interest_bearing_mint_rate_invoke_with_program_id(ID, accounts, args)
+}
+pub fn update_interest_bearing_mint_rate_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: UpdateInterestBearingMintRateAccounts<'_, '_>,
+    args: UpdateInterestBearingMintRateIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: UpdateInterestBearingMintRateKeys = accounts.into();
+    let ix = update_interest_bearing_mint_rate_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn update_interest_bearing_mint_rate_invoke_signed(
+    accounts: UpdateInterestBearingMintRateAccounts<'_, '_>,
+    args: UpdateInterestBearingMintRateIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    update_interest_bearing_mint_rate_invoke_signed_with_program_id(ID, accounts, args, seeds)
+}
+pub fn update_interest_bearing_mint_rate_verify_account_keys(
+    accounts: UpdateInterestBearingMintRateAccounts<'_, '_>,
+    keys: UpdateInterestBearingMintRateKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.mint.key, &keys.mint),
+        (accounts.authority.key, &keys.authority),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn update_interest_bearing_mint_rate_verify_writable_privileges<'me, 'info>(
+    accounts: UpdateInterestBearingMintRateAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.mint] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn update_interest_bearing_mint_rate_verify_signer_privileges<'me, 'info>(
+    accounts: UpdateInterestBearingMintRateAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.authority] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn update_interest_bearing_mint_rate_verify_account_privileges<'me, 'info>(
+    accounts: UpdateInterestBearingMintRateAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    update_interest_bearing_mint_rate_verify_writable_privileges(accounts)?;
+    update_interest_bearing_mint_rate_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const ENABLE_CPI_GUARD_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone, Debug)]
+pub struct EnableCpiGuardAccounts<'me, 'info> {
+    ///The token account to update
+    pub token_account: &'me AccountInfo<'info>,
+    ///The token account's owner. If multisig, this account is not a signer and the signing signatories must follow.
+    pub authority: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct EnableCpiGuardKeys {
+    ///The token account to update
+    pub token_account: Pubkey,
+    ///The token account's owner. If multisig, this account is not a signer and the signing signatories must follow.
+    pub authority: Pubkey,
+}
+impl From<EnableCpiGuardAccounts<'_, '_>> for EnableCpiGuardKeys {
+    fn from(accounts: EnableCpiGuardAccounts) -> Self {
+        Self {
+            token_account: *accounts.token_account.key,
+            authority: *accounts.authority.key,
+        }
+    }
+}
+impl From<EnableCpiGuardKeys> for [AccountMeta; ENABLE_CPI_GUARD_IX_ACCOUNTS_LEN] {
+    fn from(keys: EnableCpiGuardKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.token_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.authority,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; ENABLE_CPI_GUARD_IX_ACCOUNTS_LEN]> for EnableCpiGuardKeys {
+    fn from(pubkeys: [Pubkey; ENABLE_CPI_GUARD_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            token_account: pubkeys[0],
+            authority: pubkeys[1],
+        }
+    }
+}
+impl<'info> From<EnableCpiGuardAccounts<'_, 'info>>
+    for [AccountInfo<'info>; ENABLE_CPI_GUARD_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: EnableCpiGuardAccounts<'_, 'info>) -> Self {
+        [accounts.token_account.clone(), accounts.authority.clone()]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; ENABLE_CPI_GUARD_IX_ACCOUNTS_LEN]>
+    for EnableCpiGuardAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; ENABLE_CPI_GUARD_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            token_account: &arr[0],
+            authority: &arr[1],
+        }
+    }
+}
+pub const ENABLE_CPI_GUARD_IX_DISCM: [u8; 2] = [34u8, 0u8];
+#[derive(Clone, Debug, PartialEq)]
+pub struct EnableCpiGuardIxData;
+impl EnableCpiGuardIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 2];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf;
+        if maybe_discm != ENABLE_CPI_GUARD_IX_DISCM {
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                ENABLE_CPI_GUARD_IX_DISCM, maybe_discm
+            )));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&ENABLE_CPI_GUARD_IX_DISCM)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn enable_cpi_guard_ix_with_program_id(
+    program_id: Pubkey,
+    keys: EnableCpiGuardKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; ENABLE_CPI_GUARD_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: EnableCpiGuardIxData.try_to_vec()?,
+    })
+}
+pub fn enable_cpi_guard_ix(keys: EnableCpiGuardKeys) -> std::io::Result<Instruction> {
+    enable_cpi_guard_ix_with_program_id(ID, keys)
+}
+pub fn enable_cpi_guard_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: EnableCpiGuardAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: EnableCpiGuardKeys = accounts.into();
+    let ix = enable_cpi_guard_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn enable_cpi_guard_invoke(accounts: EnableCpiGuardAccounts<'_, '_>) -> ProgramResult {
+    enable_cpi_guard_invoke_with_program_id(ID, accounts)
+}
+pub fn enable_cpi_guard_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: EnableCpiGuardAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: EnableCpiGuardKeys = accounts.into();
+    let ix = enable_cpi_guard_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn enable_cpi_guard_invoke_signed(
+    accounts: EnableCpiGuardAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    enable_cpi_guard_invoke_signed_with_program_id(ID, accounts, seeds)
+}
+pub fn enable_cpi_guard_verify_account_keys(
+    accounts: EnableCpiGuardAccounts<'_, '_>,
+    keys: EnableCpiGuardKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.token_account.key, &keys.token_account),
+        (accounts.authority.key, &keys.authority),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn enable_cpi_guard_verify_writable_privileges<'me, 'info>(
+    accounts: EnableCpiGuardAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.token_account] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn enable_cpi_guard_verify_signer_privileges<'me, 'info>(
+    accounts: EnableCpiGuardAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.authority] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn enable_cpi_guard_verify_account_privileges<'me, 'info>(
+    accounts: EnableCpiGuardAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    enable_cpi_guard_verify_writable_privileges(accounts)?;
+    enable_cpi_guard_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const DISABLE_CPI_GUARD_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone, Debug)]
+pub struct DisableCpiGuardAccounts<'me, 'info> {
+    ///The token account to update
+    pub token_account: &'me AccountInfo<'info>,
+    ///The token account's owner. If multisig, this account is not a signer and the signing signatories must follow.
+    pub authority: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct DisableCpiGuardKeys {
+    ///The token account to update
+    pub token_account: Pubkey,
+    ///The token account's owner. If multisig, this account is not a signer and the signing signatories must follow.
+    pub authority: Pubkey,
+}
+impl From<DisableCpiGuardAccounts<'_, '_>> for DisableCpiGuardKeys {
+    fn from(accounts: DisableCpiGuardAccounts) -> Self {
+        Self {
+            token_account: *accounts.token_account.key,
+            authority: *accounts.authority.key,
+        }
+    }
+}
+impl From<DisableCpiGuardKeys> for [AccountMeta; DISABLE_CPI_GUARD_IX_ACCOUNTS_LEN] {
+    fn from(keys: DisableCpiGuardKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.token_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.authority,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; DISABLE_CPI_GUARD_IX_ACCOUNTS_LEN]> for DisableCpiGuardKeys {
+    fn from(pubkeys: [Pubkey; DISABLE_CPI_GUARD_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            token_account: pubkeys[0],
+            authority: pubkeys[1],
+        }
+    }
+}
+impl<'info> From<DisableCpiGuardAccounts<'_, 'info>>
+    for [AccountInfo<'info>; DISABLE_CPI_GUARD_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: DisableCpiGuardAccounts<'_, 'info>) -> Self {
+        [accounts.token_account.clone(), accounts.authority.clone()]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; DISABLE_CPI_GUARD_IX_ACCOUNTS_LEN]>
+    for DisableCpiGuardAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; DISABLE_CPI_GUARD_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            token_account: &arr[0],
+            authority: &arr[1],
+        }
+    }
+}
+pub const DISABLE_CPI_GUARD_IX_DISCM: [u8; 2] = [34u8, 1u8];
+#[derive(Clone, Debug, PartialEq)]
+pub struct DisableCpiGuardIxData;
+impl DisableCpiGuardIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 2];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf;
+        if maybe_discm != DISABLE_CPI_GUARD_IX_DISCM {
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                DISABLE_CPI_GUARD_IX_DISCM, maybe_discm
+            )));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&DISABLE_CPI_GUARD_IX_DISCM)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn disable_cpi_guard_ix_with_program_id(
+    program_id: Pubkey,
+    keys: DisableCpiGuardKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; DISABLE_CPI_GUARD_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: DisableCpiGuardIxData.try_to_vec()?,
+    })
+}
+pub fn disable_cpi_guard_ix(keys: DisableCpiGuardKeys) -> std::io::Result<Instruction> {
+    disable_cpi_guard_ix_with_program_id(ID, keys)
+}
+pub fn disable_cpi_guard_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: DisableCpiGuardAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: DisableCpiGuardKeys = accounts.into();
+    let ix = disable_cpi_guard_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn disable_cpi_guard_invoke(accounts: DisableCpiGuardAccounts<'_, '_>) -> ProgramResult {
+    disable_cpi_guard_invoke_with_program_id(ID, accounts)
+}
+pub fn disable_cpi_guard_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: DisableCpiGuardAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: DisableCpiGuardKeys = accounts.into();
+    let ix = disable_cpi_guard_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn disable_cpi_guard_invoke_signed(
+    accounts: DisableCpiGuardAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    disable_cpi_guard_invoke_signed_with_program_id(ID, accounts, seeds)
+}
+pub fn disable_cpi_guard_verify_account_keys(
+    accounts: DisableCpiGuardAccounts<'_, '_>,
+    keys: DisableCpiGuardKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.token_account.key, &keys.token_account),
+        (accounts.authority.key, &keys.authority),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn disable_cpi_guard_verify_writable_privileges<'me, 'info>(
+    accounts: DisableCpiGuardAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.token_account] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn disable_cpi_guard_verify_signer_privileges<'me, 'info>(
+    accounts: DisableCpiGuardAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.authority] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn disable_cpi_guard_verify_account_privileges<'me, 'info>(
+    accounts: DisableCpiGuardAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    disable_cpi_guard_verify_writable_privileges(accounts)?;
+    disable_cpi_guard_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const INITIALIZE_TRANSFER_HOOK_IX_ACCOUNTS_LEN: usize = 1;
+#[derive(Copy, Clone, Debug)]
+pub struct InitializeTransferHookAccounts<'me, 'info> {
+    ///The mint to initialize
+    pub mint: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct InitializeTransferHookKeys {
+    ///The mint to initialize
+    pub mint: Pubkey,
+}
+impl From<InitializeTransferHookAccounts<'_, '_>> for InitializeTransferHookKeys {
+    fn from(accounts: InitializeTransferHookAccounts) -> Self {
+        Self {
+            mint: *accounts.mint.key,
+        }
+    }
+}
+impl From<InitializeTransferHookKeys> for [AccountMeta; INITIALIZE_TRANSFER_HOOK_IX_ACCOUNTS_LEN] {
+    fn from(keys: InitializeTransferHookKeys) -> Self {
+        [AccountMeta {
+            pubkey: keys.mint,
+            is_signer: false,
+            is_writable: true,
+        }]
+    }
+}
+impl From<[Pubkey; INITIALIZE_TRANSFER_HOOK_IX_ACCOUNTS_LEN]> for InitializeTransferHookKeys {
+    fn from(pubkeys: [Pubkey; INITIALIZE_TRANSFER_HOOK_IX_ACCOUNTS_LEN]) -> Self {
+        Self { mint: pubkeys[0] }
+    }
+}
+impl<'info> From<InitializeTransferHookAccounts<'_, 'info>>
+    for [AccountInfo<'info>; INITIALIZE_TRANSFER_HOOK_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: InitializeTransferHookAccounts<'_, 'info>) -> Self {
+        [accounts.mint.clone()]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_TRANSFER_HOOK_IX_ACCOUNTS_LEN]>
+    for InitializeTransferHookAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; INITIALIZE_TRANSFER_HOOK_IX_ACCOUNTS_LEN]) -> Self {
+        Self { mint: &arr[0] }
+    }
+}
+pub const INITIALIZE_TRANSFER_HOOK_IX_DISCM: [u8; 2] = [36u8, 0u8];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct InitializeTransferHookIxArgs {
+    pub authority: Pubkey,
+    pub program_id: Pubkey,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct InitializeTransferHookIxData(pub InitializeTransferHookIxArgs);
+impl From<InitializeTransferHookIxArgs> for InitializeTransferHookIxData {
+    fn from(args: InitializeTransferHookIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl InitializeTransferHookIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 2];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf;
+        if maybe_discm != INITIALIZE_TRANSFER_HOOK_IX_DISCM {
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                INITIALIZE_TRANSFER_HOOK_IX_DISCM, maybe_discm
+            )));
+        }
+        Ok(Self(InitializeTransferHookIxArgs::deserialize(
+            &mut reader,
+        )?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&INITIALIZE_TRANSFER_HOOK_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn initialize_transfer_hook_ix_with_program_id(
+    program_id: Pubkey,
+    keys: InitializeTransferHookKeys,
+    args: InitializeTransferHookIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; INITIALIZE_TRANSFER_HOOK_IX_ACCOUNTS_LEN] = keys.into();
+    let data: InitializeTransferHookIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn initialize_transfer_hook_ix(
+    keys: InitializeTransferHookKeys,
+    args: InitializeTransferHookIxArgs,
+) -> std::io::Result<Instruction> {
+    initialize_transfer_hook_ix_with_program_id(ID, keys, args)
+}
+pub fn initialize_transfer_hook_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: InitializeTransferHookAccounts<'_, '_>,
+    args: InitializeTransferHookIxArgs,
+) -> ProgramResult {
+    let keys: InitializeTransferHookKeys = accounts.into();
+    let ix = initialize_transfer_hook_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn initialize_transfer_hook_invoke(
+    accounts: InitializeTransferHookAccounts<'_, '_>,
+    args: InitializeTransferHookIxArgs,
+) -> ProgramResult {
+    initialize_transfer_hook_invoke_with_program_id(ID, accounts, args)
+}
+pub fn initialize_transfer_hook_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: InitializeTransferHookAccounts<'_, '_>,
+    args: InitializeTransferHookIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: InitializeTransferHookKeys = accounts.into();
+    let ix = initialize_transfer_hook_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn initialize_transfer_hook_invoke_signed(
+    accounts: InitializeTransferHookAccounts<'_, '_>,
+    args: InitializeTransferHookIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    initialize_transfer_hook_invoke_signed_with_program_id(ID, accounts, args, seeds)
+}
+pub fn initialize_transfer_hook_verify_account_keys(
+    accounts: InitializeTransferHookAccounts<'_, '_>,
+    keys: InitializeTransferHookKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [(accounts.mint.key, &keys.mint)] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn initialize_transfer_hook_verify_writable_privileges<'me, 'info>(
+    accounts: InitializeTransferHookAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.mint] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn initialize_transfer_hook_verify_account_privileges<'me, 'info>(
+    accounts: InitializeTransferHookAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    initialize_transfer_hook_verify_writable_privileges(accounts)?;
+    Ok(())
+}
+pub const UPDATE_TRANSFER_HOOK_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone, Debug)]
+pub struct UpdateTransferHookAccounts<'me, 'info> {
+    ///The token mint
+    pub mint: &'me AccountInfo<'info>,
+    ///The mint's transfer hook authority. If multisig, this account is not a signer and the signing signatories must follow.
+    pub authority: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct UpdateTransferHookKeys {
+    ///The token mint
+    pub mint: Pubkey,
+    ///The mint's transfer hook authority. If multisig, this account is not a signer and the signing signatories must follow.
+    pub authority: Pubkey,
+}
+impl From<UpdateTransferHookAccounts<'_, '_>> for UpdateTransferHookKeys {
+    fn from(accounts: UpdateTransferHookAccounts) -> Self {
+        Self {
+            mint: *accounts.mint.key,
+            authority: *accounts.authority.key,
+        }
+    }
+}
+impl From<UpdateTransferHookKeys> for [AccountMeta; UPDATE_TRANSFER_HOOK_IX_ACCOUNTS_LEN] {
+    fn from(keys: UpdateTransferHookKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.authority,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; UPDATE_TRANSFER_HOOK_IX_ACCOUNTS_LEN]> for UpdateTransferHookKeys {
+    fn from(pubkeys: [Pubkey; UPDATE_TRANSFER_HOOK_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            mint: pubkeys[0],
+            authority: pubkeys[1],
+        }
+    }
+}
+impl<'info> From<UpdateTransferHookAccounts<'_, 'info>>
+    for [AccountInfo<'info>; UPDATE_TRANSFER_HOOK_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: UpdateTransferHookAccounts<'_, 'info>) -> Self {
+        [accounts.mint.clone(), accounts.authority.clone()]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_TRANSFER_HOOK_IX_ACCOUNTS_LEN]>
+    for UpdateTransferHookAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; UPDATE_TRANSFER_HOOK_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            mint: &arr[0],
+            authority: &arr[1],
+        }
+    }
+}
+pub const UPDATE_TRANSFER_HOOK_IX_DISCM: [u8; 2] = [36u8, 1u8];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct UpdateTransferHookIxArgs {
+    pub program_id: Pubkey,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct UpdateTransferHookIxData(pub UpdateTransferHookIxArgs);
+impl From<UpdateTransferHookIxArgs> for UpdateTransferHookIxData {
+    fn from(args: UpdateTransferHookIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl UpdateTransferHookIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 2];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf;
+        if maybe_discm != UPDATE_TRANSFER_HOOK_IX_DISCM {
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_TRANSFER_HOOK_IX_DISCM, maybe_discm
+            )));
+        }
+        Ok(Self(UpdateTransferHookIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&UPDATE_TRANSFER_HOOK_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn update_transfer_hook_ix_with_program_id(
+    program_id: Pubkey,
+    keys: UpdateTransferHookKeys,
+    args: UpdateTransferHookIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; UPDATE_TRANSFER_HOOK_IX_ACCOUNTS_LEN] = keys.into();
+    let data: UpdateTransferHookIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn update_transfer_hook_ix(
+    keys: UpdateTransferHookKeys,
+    args: UpdateTransferHookIxArgs,
+) -> std::io::Result<Instruction> {
+    update_transfer_hook_ix_with_program_id(ID, keys, args)
+}
+pub fn update_transfer_hook_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: UpdateTransferHookAccounts<'_, '_>,
+    args: UpdateTransferHookIxArgs,
+) -> ProgramResult {
+    let keys: UpdateTransferHookKeys = accounts.into();
+    let ix = update_transfer_hook_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn update_transfer_hook_invoke(
+    accounts: UpdateTransferHookAccounts<'_, '_>,
+    args: UpdateTransferHookIxArgs,
+) -> ProgramResult {
+    update_transfer_hook_invoke_with_program_id(ID, accounts, args)
+}
+pub fn update_transfer_hook_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: UpdateTransferHookAccounts<'_, '_>,
+    args: UpdateTransferHookIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: UpdateTransferHookKeys = accounts.into();
+    let ix = update_transfer_hook_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn update_transfer_hook_invoke_signed(
+    accounts: UpdateTransferHookAccounts<'_, '_>,
+    args: UpdateTransferHookIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    update_transfer_hook_invoke_signed_with_program_id(ID, accounts, args, seeds)
+}
+pub fn update_transfer_hook_verify_account_keys(
+    accounts: UpdateTransferHookAccounts<'_, '_>,
+    keys: UpdateTransferHookKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.mint.key, &keys.mint),
+        (accounts.authority.key, &keys.authority),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn update_transfer_hook_verify_writable_privileges<'me, 'info>(
+    accounts: UpdateTransferHookAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.mint] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn update_transfer_hook_verify_signer_privileges<'me, 'info>(
+    accounts: UpdateTransferHookAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.authority] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn update_transfer_hook_verify_account_privileges<'me, 'info>(
+    accounts: UpdateTransferHookAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    update_transfer_hook_verify_writable_privileges(accounts)?;
+    update_transfer_hook_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use spl_token_2022::extension::{
+        cpi_guard, interest_bearing_mint, memo_transfer, transfer_fee, transfer_hook,
+    };
+
+    use super::*;
+
+    #[test]
+    fn transfer_fee_ixs_match_spl() {
+        let mint = Pubkey::new_unique();
+        let from = Pubkey::new_unique();
+        let to = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let transfer_fee_basis_points = 123;
+        let maximum_fee = 4_567;
+
+        for (transfer_fee_config_authority, withdraw_withheld_authority) in [
+            (Some(Pubkey::new_unique()), Some(Pubkey::new_unique())),
+            (None, Some(Pubkey::new_unique())),
+            (Some(Pubkey::new_unique()), None),
+            (None, None),
+        ] {
+            let actual = initialize_transfer_fee_config_ix(
+                InitializeTransferFeeConfigKeys { mint },
+                InitializeTransferFeeConfigIxArgs {
+                    transfer_fee_config_authority,
+                    withdraw_withheld_authority,
+                    transfer_fee_basis_points,
+                    maximum_fee,
+                },
+            )
+            .unwrap();
+            let expected = transfer_fee::instruction::initialize_transfer_fee_config(
+                &spl_token_2022::ID,
+                &mint,
+                transfer_fee_config_authority.as_ref(),
+                withdraw_withheld_authority.as_ref(),
+                transfer_fee_basis_points,
+                maximum_fee,
+            )
+            .unwrap();
+            assert_eq!(actual, expected);
+        }
+
+        let actual = transfer_checked_with_fee_ix(
+            TransferCheckedWithFeeKeys {
+                from,
+                mint,
+                to,
+                authority,
+            },
+            TransferCheckedWithFeeIxArgs {
+                args: CheckedOpArgs {
+                    amount: 69,
+                    decimals: 9,
+                },
+                fee: 420,
+            },
+        )
+        .unwrap();
+        let expected = transfer_fee::instruction::transfer_checked_with_fee(
+            &spl_token_2022::ID,
+            &from,
+            &mint,
+            &to,
+            &authority,
+            &[],
+            69,
+            9,
+            420,
+        )
+        .unwrap();
+        assert_eq!(actual, expected);
+
+        let actual = withdraw_withheld_tokens_from_mint_ix(WithdrawWithheldTokensFromMintKeys {
+            mint,
+            to,
+            authority,
+        })
+        .unwrap();
+        let expected = transfer_fee::instruction::withdraw_withheld_tokens_from_mint(
+            &spl_token_2022::ID,
+            &mint,
+            &to,
+            &authority,
+            &[],
+        )
+        .unwrap();
+        assert_eq!(actual, expected);
+
+        // token accounts to withdraw from are appended by withdraw_withheld_tokens_from_accounts_full_ix
+        let actual = withdraw_withheld_tokens_from_accounts_ix(
+            WithdrawWithheldTokensFromAccountsKeys {
+                mint,
+                to,
+                authority,
+            },
+            WithdrawWithheldTokensFromAccountsIxArgs {
+                num_token_accounts: 0,
+            },
+        )
+        .unwrap();
+        let expected = transfer_fee::instruction::withdraw_withheld_tokens_from_accounts(
+            &spl_token_2022::ID,
+            &mint,
+            &to,
+            &authority,
+            &[],
+            &[],
+        )
+        .unwrap();
+        assert_eq!(actual, expected);
+
+        // token accounts to harvest from are appended by harvest_withheld_tokens_to_mint_full_ix
+        let actual =
+            harvest_withheld_tokens_to_mint_ix(HarvestWithheldTokensToMintKeys { mint }).unwrap();
+        let expected = transfer_fee::instruction::harvest_withheld_tokens_to_mint(
+            &spl_token_2022::ID,
+            &mint,
+            &[],
+        )
+        .unwrap();
+        assert_eq!(actual, expected);
+
+        let actual = set_transfer_fee_ix(
+            SetTransferFeeKeys { mint, authority },
+            SetTransferFeeIxArgs {
+                transfer_fee_basis_points,
+                maximum_fee,
+            },
+        )
+        .unwrap();
+        let expected = transfer_fee::instruction::set_transfer_fee(
+            &spl_token_2022::ID,
+            &mint,
+            &authority,
+            &[],
+            transfer_fee_basis_points,
+            maximum_fee,
+        )
+        .unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn required_transfer_memos_ixs_match_spl() {
+        let token_account = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+
+        let actual = enable_required_transfer_memos_ix(EnableRequiredTransferMemosKeys {
+            token_account,
+            authority,
+        })
+        .unwrap();
+        let expected = memo_transfer::instruction::enable_required_transfer_memos(
+            &spl_token_2022::ID,
+            &token_account,
+            &authority,
+            &[],
+        )
+        .unwrap();
+        assert_eq!(actual, expected);
+
+        let actual = disable_required_transfer_memos_ix(DisableRequiredTransferMemosKeys {
+            token_account,
+            authority,
+        })
+        .unwrap();
+        let expected = memo_transfer::instruction::disable_required_transfer_memos(
+            &spl_token_2022::ID,
+            &token_account,
+            &authority,
+            &[],
+        )
+        .unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn interest_bearing_mint_ixs_match_spl() {
+        let mint = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let rate = -1_234;
+
+        for rate_authority in [Some(authority), None] {
+            let actual = initialize_interest_bearing_mint_ix(
+                InitializeInterestBearingMintKeys { mint },
+                InitializeInterestBearingMintIxArgs {
+                    rate_authority: rate_authority.unwrap_or_default(),
+                    rate,
+                },
+            )
+            .unwrap();
+            let expected = interest_bearing_mint::instruction::initialize(
+                &spl_token_2022::ID,
+                &mint,
+                rate_authority,
+                rate,
+            )
+            .unwrap();
+            assert_eq!(actual, expected);
+        }
+
+        let actual = update_interest_bearing_mint_rate_ix(
+            UpdateInterestBearingMintRateKeys { mint, authority },
+            UpdateInterestBearingMintRateIxArgs { rate },
+        )
+        .unwrap();
+        let expected = interest_bearing_mint::instruction::update_rate(
+            &spl_token_2022::ID,
+            &mint,
+            &authority,
+            &[],
+            rate,
+        )
+        .unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn cpi_guard_ixs_match_spl() {
+        let token_account = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+
+        let actual = enable_cpi_guard_ix(EnableCpiGuardKeys {
+            token_account,
+            authority,
+        })
+        .unwrap();
+        let expected = cpi_guard::instruction::enable_cpi_guard(
+            &spl_token_2022::ID,
+            &token_account,
+            &authority,
+            &[],
+        )
+        .unwrap();
+        assert_eq!(actual, expected);
+
+        let actual = disable_cpi_guard_ix(DisableCpiGuardKeys {
+            token_account,
+            authority,
+        })
+        .unwrap();
+        let expected = cpi_guard::instruction::disable_cpi_guard(
+            &spl_token_2022::ID,
+            &token_account,
+            &authority,
+            &[],
+        )
+        .unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn transfer_hook_ixs_match_spl() {
+        let mint = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+
+        for (init_authority, program_id) in [
+            (Some(authority), Some(Pubkey::new_unique())),
+            (None, Some(Pubkey::new_unique())),
+            (Some(authority), None),
+            (None, None),
+        ] {
+            let actual = initialize_transfer_hook_ix(
+                InitializeTransferHookKeys { mint },
+                InitializeTransferHookIxArgs {
+                    authority: init_authority.unwrap_or_default(),
+                    program_id: program_id.unwrap_or_default(),
+                },
+            )
+            .unwrap();
+            let expected = transfer_hook::instruction::initialize(
+                &spl_token_2022::ID,
+                &mint,
+                init_authority,
+                program_id,
+            )
+            .unwrap();
+            assert_eq!(actual, expected);
+
+            let actual = update_transfer_hook_ix(
+                UpdateTransferHookKeys { mint, authority },
+                UpdateTransferHookIxArgs {
+                    program_id: program_id.unwrap_or_default(),
+                },
+            )
+            .unwrap();
+            let expected = transfer_hook::instruction::update(
+                &spl_token_2022::ID,
+                &mint,
+                &authority,
+                &[],
+                program_id,
+            )
+            .unwrap();
+            assert_eq!(actual, expected);
+        }
+    }
+}
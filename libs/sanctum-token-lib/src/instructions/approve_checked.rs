@@ -1,4 +1,5 @@
 super::multisig_impl::multisig_impl!(
+    spl_token_interface,
     approve_checked_multisig_ix,
     approve_checked_multisig_invoke,
     approve_checked_multisig_invoke_signed,
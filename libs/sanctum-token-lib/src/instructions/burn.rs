@@ -1,4 +1,5 @@
 super::multisig_impl::multisig_impl!(
+    spl_token_interface,
     burn_multisig_ix,
     burn_multisig_invoke,
     burn_multisig_invoke_signed,
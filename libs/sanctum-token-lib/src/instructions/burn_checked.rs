@@ -1,4 +1,5 @@
 super::multisig_impl::multisig_impl!(
+    spl_token_interface,
     burn_checked_multisig_ix,
     burn_checked_multisig_invoke,
     burn_checked_multisig_invoke_signed,
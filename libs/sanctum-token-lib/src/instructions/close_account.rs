@@ -1,4 +1,5 @@
 super::multisig_impl::multisig_impl_no_ix_args!(
+    spl_token_interface,
     close_account_multisig_ix,
     close_account_multisig_invoke,
     close_account_multisig_invoke_signed,
@@ -1,4 +1,4 @@
-use crate::ReadonlyMintAccount;
+use crate::{extension_instructions, ReadonlyMintAccount};
 use solana_program::{
     account_info::AccountInfo,
     entrypoint::ProgramResult,
@@ -8,7 +8,6 @@ use solana_program::{
     pubkey::Pubkey,
 };
 use solana_readonly_account::ReadonlyAccountData;
-use spl_token_interface::CheckedOpArgs;
 
 fn mint_decimals_checked<M: ReadonlyAccountData>(mint: M) -> Result<u8, ProgramError> {
     let mint = ReadonlyMintAccount(mint)
@@ -19,6 +18,8 @@ fn mint_decimals_checked<M: ReadonlyAccountData>(mint: M) -> Result<u8, ProgramE
 
 macro_rules! impl_decimal_agnostic {
     (
+        $interface: ident,
+
         $ix_fn: ident,
         $invoke_fn: ident,
         $invoke_signed_fn: ident,
@@ -37,33 +38,41 @@ macro_rules! impl_decimal_agnostic {
         $keys: ident,
         $ix_args: ident,
         $accounts_len: ident,
+        $($extra_arg: ident: $extra_ty: ty,)*
     ) => {
-        use spl_token_interface::{$accounts, $accounts_len, $ix_args, $ix_with_program_id, $keys};
+        use $interface::{$accounts, $accounts_len, $ix_args, $ix_with_program_id, $keys};
 
         pub fn $ix_fn<M: ReadonlyAccountData>(
             keys: $keys,
             amount: u64,
+            $($extra_arg: $extra_ty,)*
             mint: M,
         ) -> Result<Instruction, ProgramError> {
-            $ix_fn_with_program_id(spl_token_interface::ID, keys, amount, mint)
+            $ix_fn_with_program_id($interface::ID, keys, amount, $($extra_arg,)* mint)
         }
 
-        pub fn $invoke_fn(accounts: $accounts, amount: u64) -> ProgramResult {
-            $invoke_fn_with_program_id(spl_token_interface::ID, accounts, amount)
+        pub fn $invoke_fn(
+            accounts: $accounts,
+            amount: u64,
+            $($extra_arg: $extra_ty,)*
+        ) -> ProgramResult {
+            $invoke_fn_with_program_id($interface::ID, accounts, amount, $($extra_arg,)*)
         }
 
         pub fn $invoke_signed_fn(
             accounts: $accounts,
             amount: u64,
+            $($extra_arg: $extra_ty,)*
             seeds: &[&[&[u8]]],
         ) -> ProgramResult {
-            $invoke_signed_fn_with_program_id(spl_token_interface::ID, accounts, amount, seeds)
+            $invoke_signed_fn_with_program_id($interface::ID, accounts, amount, $($extra_arg,)* seeds)
         }
 
         pub fn $ix_fn_with_program_id<M: ReadonlyAccountData>(
             program_id: Pubkey,
             keys: $keys,
             amount: u64,
+            $($extra_arg: $extra_ty,)*
             mint: M,
         ) -> Result<Instruction, ProgramError> {
             let decimals = mint_decimals_checked(mint)?;
@@ -71,7 +80,8 @@ macro_rules! impl_decimal_agnostic {
                 program_id,
                 keys,
                 $ix_args {
-                    args: CheckedOpArgs { amount, decimals },
+                    args: $interface::CheckedOpArgs { amount, decimals },
+                    $($extra_arg,)*
                 },
             )?)
         }
@@ -80,8 +90,15 @@ macro_rules! impl_decimal_agnostic {
             program_id: Pubkey,
             accounts: $accounts,
             amount: u64,
+            $($extra_arg: $extra_ty,)*
         ) -> ProgramResult {
-            let ix = $ix_fn_with_program_id(program_id, accounts.into(), amount, accounts.mint)?;
+            let ix = $ix_fn_with_program_id(
+                program_id,
+                accounts.into(),
+                amount,
+                $($extra_arg,)*
+                accounts.mint,
+            )?;
             let account_infos: [AccountInfo<'_>; $accounts_len] = accounts.into();
             invoke(&ix, &account_infos)
         }
@@ -90,9 +107,16 @@ macro_rules! impl_decimal_agnostic {
             program_id: Pubkey,
             accounts: $accounts,
             amount: u64,
+            $($extra_arg: $extra_ty,)*
             seeds: &[&[&[u8]]],
         ) -> ProgramResult {
-            let ix = $ix_fn_with_program_id(program_id, accounts.into(), amount, accounts.mint)?;
+            let ix = $ix_fn_with_program_id(
+                program_id,
+                accounts.into(),
+                amount,
+                $($extra_arg,)*
+                accounts.mint,
+            )?;
             let account_infos: [AccountInfo<'_>; $accounts_len] = accounts.into();
             invoke_signed(&ix, &account_infos, seeds)
         }
@@ -100,13 +124,15 @@ macro_rules! impl_decimal_agnostic {
         pub fn $multisig_ix_fn<M: ReadonlyAccountData>(
             keys: $keys,
             amount: u64,
+            $($extra_arg: $extra_ty,)*
             mint: M,
             signatories: impl Iterator<Item = Pubkey>,
         ) -> Result<Instruction, ProgramError> {
             $multisig_ix_fn_with_program_id(
-                spl_token_interface::ID,
+                $interface::ID,
                 keys,
                 amount,
+                $($extra_arg,)*
                 mint,
                 signatories,
             )
@@ -115,12 +141,14 @@ macro_rules! impl_decimal_agnostic {
         pub fn $multisig_invoke_fn<'a, 'info>(
             accounts: $accounts<'a, 'info>,
             amount: u64,
+            $($extra_arg: $extra_ty,)*
             signatories: &'a [AccountInfo<'info>],
         ) -> ProgramResult {
             $multisig_invoke_fn_with_program_id(
-                spl_token_interface::ID,
+                $interface::ID,
                 accounts,
                 amount,
+                $($extra_arg,)*
                 signatories,
             )
         }
@@ -128,13 +156,15 @@ macro_rules! impl_decimal_agnostic {
         pub fn $multisig_invoke_signed_fn<'a, 'info>(
             accounts: $accounts<'a, 'info>,
             amount: u64,
+            $($extra_arg: $extra_ty,)*
             signatories: &'a [AccountInfo<'info>],
             seeds: &[&[&[u8]]],
         ) -> ProgramResult {
             $multisig_invoke_signed_fn_with_program_id(
-                spl_token_interface::ID,
+                $interface::ID,
                 accounts,
                 amount,
+                $($extra_arg,)*
                 signatories,
                 seeds,
             )
@@ -144,10 +174,11 @@ macro_rules! impl_decimal_agnostic {
             program_id: Pubkey,
             keys: $keys,
             amount: u64,
+            $($extra_arg: $extra_ty,)*
             mint: M,
             signatories: impl Iterator<Item = Pubkey>,
         ) -> Result<Instruction, ProgramError> {
-            let mut ix = $ix_fn_with_program_id(program_id, keys, amount, mint)?;
+            let mut ix = $ix_fn_with_program_id(program_id, keys, amount, $($extra_arg,)* mint)?;
             ix.accounts.last_mut().unwrap().is_signer = false;
             ix.accounts.extend(signatories.map(|pubkey| AccountMeta {
                 pubkey,
@@ -161,12 +192,14 @@ macro_rules! impl_decimal_agnostic {
             program_id: Pubkey,
             accounts: $accounts<'a, 'info>,
             amount: u64,
+            $($extra_arg: $extra_ty,)*
             signatories: &'a [AccountInfo<'info>],
         ) -> ProgramResult {
             let ix = $multisig_ix_fn_with_program_id(
                 program_id,
                 accounts.into(),
                 amount,
+                $($extra_arg,)*
                 accounts.mint,
                 signatories.iter().map(|a| *a.key),
             )?;
@@ -179,6 +212,7 @@ macro_rules! impl_decimal_agnostic {
             program_id: Pubkey,
             accounts: $accounts<'a, 'info>,
             amount: u64,
+            $($extra_arg: $extra_ty,)*
             signatories: &'a [AccountInfo<'info>],
             seeds: &[&[&[u8]]],
         ) -> ProgramResult {
@@ -186,6 +220,7 @@ macro_rules! impl_decimal_agnostic {
                 program_id,
                 accounts.into(),
                 amount,
+                $($extra_arg,)*
                 accounts.mint,
                 signatories.iter().map(|a| *a.key),
             )?;
@@ -197,6 +232,7 @@ macro_rules! impl_decimal_agnostic {
 }
 
 impl_decimal_agnostic!(
+    spl_token_interface,
     transfer_checked_decimal_agnostic_ix,
     transfer_checked_decimal_agnostic_invoke,
     transfer_checked_decimal_agnostic_invoke_signed,
@@ -217,6 +253,7 @@ impl_decimal_agnostic!(
 );
 
 impl_decimal_agnostic!(
+    spl_token_interface,
     burn_checked_decimal_agnostic_ix,
     burn_checked_decimal_agnostic_invoke,
     burn_checked_decimal_agnostic_invoke_signed,
@@ -237,6 +274,7 @@ impl_decimal_agnostic!(
 );
 
 impl_decimal_agnostic!(
+    spl_token_interface,
     approve_checked_decimal_agnostic_ix,
     approve_checked_decimal_agnostic_invoke,
     approve_checked_decimal_agnostic_invoke_signed,
@@ -257,6 +295,7 @@ impl_decimal_agnostic!(
 );
 
 impl_decimal_agnostic!(
+    spl_token_interface,
     mint_to_checked_decimal_agnostic_ix,
     mint_to_checked_decimal_agnostic_invoke,
     mint_to_checked_decimal_agnostic_invoke_signed,
@@ -275,3 +314,25 @@ impl_decimal_agnostic!(
     MintToCheckedIxArgs,
     MINT_TO_CHECKED_IX_ACCOUNTS_LEN,
 );
+
+impl_decimal_agnostic!(
+    extension_instructions,
+    transfer_checked_with_fee_decimal_agnostic_ix,
+    transfer_checked_with_fee_decimal_agnostic_invoke,
+    transfer_checked_with_fee_decimal_agnostic_invoke_signed,
+    transfer_checked_with_fee_decimal_agnostic_ix_with_program_id,
+    transfer_checked_with_fee_decimal_agnostic_invoke_with_program_id,
+    transfer_checked_with_fee_decimal_agnostic_invoke_signed_with_program_id,
+    transfer_checked_with_fee_decimal_agnostic_multisig_ix,
+    transfer_checked_with_fee_decimal_agnostic_multisig_invoke,
+    transfer_checked_with_fee_decimal_agnostic_multisig_invoke_signed,
+    transfer_checked_with_fee_decimal_agnostic_multisig_ix_with_program_id,
+    transfer_checked_with_fee_decimal_agnostic_multisig_invoke_with_program_id,
+    transfer_checked_with_fee_decimal_agnostic_multisig_invoke_signed_with_program_id,
+    transfer_checked_with_fee_ix_with_program_id,
+    TransferCheckedWithFeeAccounts,
+    TransferCheckedWithFeeKeys,
+    TransferCheckedWithFeeIxArgs,
+    TRANSFER_CHECKED_WITH_FEE_IX_ACCOUNTS_LEN,
+    fee: u64,
+);
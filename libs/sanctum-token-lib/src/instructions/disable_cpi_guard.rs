@@ -0,0 +1,15 @@
+use crate::extension_instructions;
+
+super::multisig_impl::multisig_impl_no_ix_args!(
+    extension_instructions,
+    disable_cpi_guard_multisig_ix,
+    disable_cpi_guard_multisig_invoke,
+    disable_cpi_guard_multisig_invoke_signed,
+    disable_cpi_guard_multisig_ix_with_program_id,
+    disable_cpi_guard_multisig_invoke_with_program_id,
+    disable_cpi_guard_multisig_invoke_signed_with_program_id,
+    disable_cpi_guard_ix_with_program_id,
+    DisableCpiGuardAccounts,
+    DisableCpiGuardKeys,
+    DISABLE_CPI_GUARD_IX_ACCOUNTS_LEN,
+);
@@ -0,0 +1,15 @@
+use crate::extension_instructions;
+
+super::multisig_impl::multisig_impl_no_ix_args!(
+    extension_instructions,
+    disable_required_transfer_memos_multisig_ix,
+    disable_required_transfer_memos_multisig_invoke,
+    disable_required_transfer_memos_multisig_invoke_signed,
+    disable_required_transfer_memos_multisig_ix_with_program_id,
+    disable_required_transfer_memos_multisig_invoke_with_program_id,
+    disable_required_transfer_memos_multisig_invoke_signed_with_program_id,
+    disable_required_transfer_memos_ix_with_program_id,
+    DisableRequiredTransferMemosAccounts,
+    DisableRequiredTransferMemosKeys,
+    DISABLE_REQUIRED_TRANSFER_MEMOS_IX_ACCOUNTS_LEN,
+);
@@ -0,0 +1,15 @@
+use crate::extension_instructions;
+
+super::multisig_impl::multisig_impl_no_ix_args!(
+    extension_instructions,
+    enable_cpi_guard_multisig_ix,
+    enable_cpi_guard_multisig_invoke,
+    enable_cpi_guard_multisig_invoke_signed,
+    enable_cpi_guard_multisig_ix_with_program_id,
+    enable_cpi_guard_multisig_invoke_with_program_id,
+    enable_cpi_guard_multisig_invoke_signed_with_program_id,
+    enable_cpi_guard_ix_with_program_id,
+    EnableCpiGuardAccounts,
+    EnableCpiGuardKeys,
+    ENABLE_CPI_GUARD_IX_ACCOUNTS_LEN,
+);
@@ -0,0 +1,15 @@
+use crate::extension_instructions;
+
+super::multisig_impl::multisig_impl_no_ix_args!(
+    extension_instructions,
+    enable_required_transfer_memos_multisig_ix,
+    enable_required_transfer_memos_multisig_invoke,
+    enable_required_transfer_memos_multisig_invoke_signed,
+    enable_required_transfer_memos_multisig_ix_with_program_id,
+    enable_required_transfer_memos_multisig_invoke_with_program_id,
+    enable_required_transfer_memos_multisig_invoke_signed_with_program_id,
+    enable_required_transfer_memos_ix_with_program_id,
+    EnableRequiredTransferMemosAccounts,
+    EnableRequiredTransferMemosKeys,
+    ENABLE_REQUIRED_TRANSFER_MEMOS_IX_ACCOUNTS_LEN,
+);
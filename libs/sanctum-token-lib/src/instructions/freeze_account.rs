@@ -1,4 +1,5 @@
 super::multisig_impl::multisig_impl_no_ix_args!(
+    spl_token_interface,
     freeze_account_multisig_ix,
     freeze_account_multisig_invoke,
     freeze_account_multisig_invoke_signed,
@@ -0,0 +1,95 @@
+use crate::extension_instructions::{
+    harvest_withheld_tokens_to_mint_ix_with_program_id, HarvestWithheldTokensToMintAccounts,
+    HarvestWithheldTokensToMintKeys, HARVEST_WITHHELD_TOKENS_TO_MINT_IX_ACCOUNTS_LEN,
+};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::{invoke, invoke_signed},
+    pubkey::Pubkey,
+};
+
+pub fn harvest_withheld_tokens_to_mint_full_ix(
+    keys: HarvestWithheldTokensToMintKeys,
+    token_accounts: impl Iterator<Item = Pubkey>,
+) -> std::io::Result<Instruction> {
+    harvest_withheld_tokens_to_mint_full_ix_with_program_id(
+        spl_token_2022_interface::ID,
+        keys,
+        token_accounts,
+    )
+}
+
+pub fn harvest_withheld_tokens_to_mint_full_invoke<'a, 'info>(
+    accounts: HarvestWithheldTokensToMintAccounts<'a, 'info>,
+    token_accounts: &'a [AccountInfo<'info>],
+) -> ProgramResult {
+    harvest_withheld_tokens_to_mint_full_invoke_with_program_id(
+        spl_token_2022_interface::ID,
+        accounts,
+        token_accounts,
+    )
+}
+
+pub fn harvest_withheld_tokens_to_mint_full_invoke_signed<'a, 'info>(
+    accounts: HarvestWithheldTokensToMintAccounts<'a, 'info>,
+    token_accounts: &'a [AccountInfo<'info>],
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    harvest_withheld_tokens_to_mint_full_invoke_signed_with_program_id(
+        spl_token_2022_interface::ID,
+        accounts,
+        token_accounts,
+        seeds,
+    )
+}
+
+pub fn harvest_withheld_tokens_to_mint_full_ix_with_program_id(
+    program_id: Pubkey,
+    keys: HarvestWithheldTokensToMintKeys,
+    token_accounts: impl Iterator<Item = Pubkey>,
+) -> std::io::Result<Instruction> {
+    let mut ix = harvest_withheld_tokens_to_mint_ix_with_program_id(program_id, keys)?;
+    ix.accounts.extend(token_accounts.map(|pubkey| AccountMeta {
+        pubkey,
+        is_signer: false,
+        is_writable: true,
+    }));
+    Ok(ix)
+}
+
+pub fn harvest_withheld_tokens_to_mint_full_invoke_with_program_id<'a, 'info>(
+    program_id: Pubkey,
+    accounts: HarvestWithheldTokensToMintAccounts<'a, 'info>,
+    token_accounts: &'a [AccountInfo<'info>],
+) -> ProgramResult {
+    let ix = harvest_withheld_tokens_to_mint_full_ix_with_program_id(
+        program_id,
+        accounts.into(),
+        token_accounts.iter().map(|a| *a.key),
+    )?;
+    let mut accounts = Vec::from(Into::<
+        [AccountInfo; HARVEST_WITHHELD_TOKENS_TO_MINT_IX_ACCOUNTS_LEN],
+    >::into(accounts));
+    accounts.extend(token_accounts.iter().cloned());
+    invoke(&ix, &accounts)
+}
+
+pub fn harvest_withheld_tokens_to_mint_full_invoke_signed_with_program_id<'a, 'info>(
+    program_id: Pubkey,
+    accounts: HarvestWithheldTokensToMintAccounts<'a, 'info>,
+    token_accounts: &'a [AccountInfo<'info>],
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = harvest_withheld_tokens_to_mint_full_ix_with_program_id(
+        program_id,
+        accounts.into(),
+        token_accounts.iter().map(|a| *a.key),
+    )?;
+    let mut accounts = Vec::from(Into::<
+        [AccountInfo; HARVEST_WITHHELD_TOKENS_TO_MINT_IX_ACCOUNTS_LEN],
+    >::into(accounts));
+    accounts.extend(token_accounts.iter().cloned());
+    invoke_signed(&ix, &accounts, seeds)
+}
@@ -1,4 +1,5 @@
 super::multisig_impl::multisig_impl!(
+    spl_token_interface,
     mint_to_multisig_ix,
     mint_to_multisig_invoke,
     mint_to_multisig_invoke_signed,
@@ -4,15 +4,27 @@ mod burn;
 mod burn_checked;
 mod close_account;
 mod decimal_agnostic;
+mod disable_cpi_guard;
+mod disable_required_transfer_memos;
+mod enable_cpi_guard;
+mod enable_required_transfer_memos;
 mod freeze_account;
+mod harvest_withheld_tokens_to_mint;
 mod initialize_multisig;
 mod mint_to;
 mod mint_to_checked;
 mod multisig_impl;
+mod reallocate;
 mod revoke;
 mod set_authority;
+mod set_transfer_fee;
 mod thaw_account;
 mod transfer_checked;
+mod transfer_checked_with_fee;
+mod update_interest_bearing_mint_rate;
+mod update_transfer_hook;
+mod withdraw_withheld_tokens_from_accounts;
+mod withdraw_withheld_tokens_from_mint;
 
 pub use approve::*;
 pub use approve_checked::*;
@@ -20,11 +32,23 @@ pub use burn::*;
 pub use burn_checked::*;
 pub use close_account::*;
 pub use decimal_agnostic::*;
+pub use disable_cpi_guard::*;
+pub use disable_required_transfer_memos::*;
+pub use enable_cpi_guard::*;
+pub use enable_required_transfer_memos::*;
 pub use freeze_account::*;
+pub use harvest_withheld_tokens_to_mint::*;
 pub use initialize_multisig::*;
 pub use mint_to::*;
 pub use mint_to_checked::*;
+pub use reallocate::*;
 pub use revoke::*;
 pub use set_authority::*;
+pub use set_transfer_fee::*;
 pub use thaw_account::*;
 pub use transfer_checked::*;
+pub use transfer_checked_with_fee::*;
+pub use update_interest_bearing_mint_rate::*;
+pub use update_transfer_hook::*;
+pub use withdraw_withheld_tokens_from_accounts::*;
+pub use withdraw_withheld_tokens_from_mint::*;
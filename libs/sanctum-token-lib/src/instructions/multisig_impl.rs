@@ -1,5 +1,7 @@
 macro_rules! multisig_impl {
     (
+        $interface: ident,
+
         $ix_fn: ident,
         $invoke_fn: ident,
         $invoke_signed_fn: ident,
@@ -20,14 +22,14 @@ macro_rules! multisig_impl {
             program::{invoke, invoke_signed},
             pubkey::Pubkey,
         };
-        use spl_token_interface::{$accounts, $accounts_len, $ix_args, $ix_with_program_id, $keys};
+        use $interface::{$accounts, $accounts_len, $ix_args, $ix_with_program_id, $keys};
 
         pub fn $ix_fn(
             keys: $keys,
             args: $ix_args,
             signatories: impl Iterator<Item = Pubkey>,
         ) -> std::io::Result<Instruction> {
-            $ix_fn_with_program_id(spl_token_interface::ID, keys, args, signatories)
+            $ix_fn_with_program_id($interface::ID, keys, args, signatories)
         }
 
         pub fn $invoke_fn<'a, 'info>(
@@ -35,7 +37,7 @@ macro_rules! multisig_impl {
             args: $ix_args,
             signatories: &'a [AccountInfo<'info>],
         ) -> ProgramResult {
-            $invoke_fn_with_program_id(spl_token_interface::ID, accounts, args, signatories)
+            $invoke_fn_with_program_id($interface::ID, accounts, args, signatories)
         }
 
         pub fn $invoke_signed_fn<'a, 'info>(
@@ -44,13 +46,7 @@ macro_rules! multisig_impl {
             signatories: &'a [AccountInfo<'info>],
             seeds: &[&[&[u8]]],
         ) -> ProgramResult {
-            $invoke_signed_fn_with_program_id(
-                spl_token_interface::ID,
-                accounts,
-                args,
-                signatories,
-                seeds,
-            )
+            $invoke_signed_fn_with_program_id($interface::ID, accounts, args, signatories, seeds)
         }
 
         pub fn $ix_fn_with_program_id(
@@ -108,6 +104,8 @@ macro_rules! multisig_impl {
 
 macro_rules! multisig_impl_no_ix_args {
     (
+        $interface: ident,
+
         $ix_fn: ident,
         $invoke_fn: ident,
         $invoke_signed_fn: ident,
@@ -127,20 +125,20 @@ macro_rules! multisig_impl_no_ix_args {
             program::{invoke, invoke_signed},
             pubkey::Pubkey,
         };
-        use spl_token_interface::{$accounts, $accounts_len, $ix_with_program_id, $keys};
+        use $interface::{$accounts, $accounts_len, $ix_with_program_id, $keys};
 
         pub fn $ix_fn(
             keys: $keys,
             signatories: impl Iterator<Item = Pubkey>,
         ) -> std::io::Result<Instruction> {
-            $ix_fn_with_program_id(spl_token_interface::ID, keys, signatories)
+            $ix_fn_with_program_id($interface::ID, keys, signatories)
         }
 
         pub fn $invoke_fn<'a, 'info>(
             accounts: $accounts<'a, 'info>,
             signatories: &'a [AccountInfo<'info>],
         ) -> ProgramResult {
-            $invoke_fn_with_program_id(spl_token_interface::ID, accounts, signatories)
+            $invoke_fn_with_program_id($interface::ID, accounts, signatories)
         }
 
         pub fn $invoke_signed_fn<'a, 'info>(
@@ -148,7 +146,7 @@ macro_rules! multisig_impl_no_ix_args {
             signatories: &'a [AccountInfo<'info>],
             seeds: &[&[&[u8]]],
         ) -> ProgramResult {
-            $invoke_signed_fn_with_program_id(spl_token_interface::ID, accounts, signatories, seeds)
+            $invoke_signed_fn_with_program_id($interface::ID, accounts, signatories, seeds)
         }
 
         pub fn $ix_fn_with_program_id(
@@ -0,0 +1,62 @@
+super::multisig_impl::multisig_impl!(
+    spl_token_2022_interface,
+    reallocate_multisig_ix,
+    reallocate_multisig_invoke,
+    reallocate_multisig_invoke_signed,
+    reallocate_multisig_ix_with_program_id,
+    reallocate_multisig_invoke_with_program_id,
+    reallocate_multisig_invoke_signed_with_program_id,
+    reallocate_ix_with_program_id,
+    ReallocateAccounts,
+    ReallocateKeys,
+    ReallocateIxArgs,
+    REALLOCATE_IX_ACCOUNTS_LEN,
+);
+
+#[cfg(test)]
+mod tests {
+    use solana_program::system_program;
+    use spl_token_2022::{extension::ExtensionType, instruction::reallocate};
+    use spl_token_2022_interface::{ReallocateIxArgs, ReallocateKeys, SplToken2022ProgramIx};
+
+    use super::*;
+
+    #[test]
+    fn reallocate_multisig_serde() {
+        let token_account = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let signatories = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let signatories_ref: [&Pubkey; 2] = [&signatories[0], &signatories[1]];
+        let extension_types = [ExtensionType::MemoTransfer, ExtensionType::CpiGuard];
+
+        let args = ReallocateIxArgs {
+            extension_types: extension_types.iter().map(|t| u16::from(*t)).collect(),
+        };
+        let actual = reallocate_multisig_ix(
+            ReallocateKeys {
+                token_account,
+                payer,
+                system_program: system_program::ID,
+                authority,
+            },
+            args.clone(),
+            signatories.iter().copied(),
+        )
+        .unwrap();
+        let expected = reallocate(
+            &spl_token_2022::ID,
+            &token_account,
+            &payer,
+            &authority,
+            &signatories_ref,
+            &extension_types,
+        )
+        .unwrap();
+        assert_eq!(actual, expected);
+        assert_eq!(
+            SplToken2022ProgramIx::deserialize(&actual.data).unwrap(),
+            SplToken2022ProgramIx::Reallocate(args)
+        );
+    }
+}
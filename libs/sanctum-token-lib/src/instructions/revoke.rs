@@ -1,4 +1,5 @@
 super::multisig_impl::multisig_impl_no_ix_args!(
+    spl_token_interface,
     revoke_multisig_ix,
     revoke_multisig_invoke,
     revoke_multisig_invoke_signed,
@@ -1,4 +1,5 @@
 super::multisig_impl::multisig_impl!(
+    spl_token_interface,
     set_authority_multisig_ix,
     set_authority_multisig_invoke,
     set_authority_multisig_invoke_signed,
@@ -0,0 +1,16 @@
+use crate::extension_instructions;
+
+super::multisig_impl::multisig_impl!(
+    extension_instructions,
+    set_transfer_fee_multisig_ix,
+    set_transfer_fee_multisig_invoke,
+    set_transfer_fee_multisig_invoke_signed,
+    set_transfer_fee_multisig_ix_with_program_id,
+    set_transfer_fee_multisig_invoke_with_program_id,
+    set_transfer_fee_multisig_invoke_signed_with_program_id,
+    set_transfer_fee_ix_with_program_id,
+    SetTransferFeeAccounts,
+    SetTransferFeeKeys,
+    SetTransferFeeIxArgs,
+    SET_TRANSFER_FEE_IX_ACCOUNTS_LEN,
+);
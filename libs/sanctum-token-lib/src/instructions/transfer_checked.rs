@@ -1,4 +1,5 @@
 super::multisig_impl::multisig_impl!(
+    spl_token_interface,
     transfer_checked_multisig_ix,
     transfer_checked_multisig_invoke,
     transfer_checked_multisig_invoke_signed,
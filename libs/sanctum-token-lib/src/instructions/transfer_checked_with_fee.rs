@@ -0,0 +1,65 @@
+use crate::extension_instructions;
+
+super::multisig_impl::multisig_impl!(
+    extension_instructions,
+    transfer_checked_with_fee_multisig_ix,
+    transfer_checked_with_fee_multisig_invoke,
+    transfer_checked_with_fee_multisig_invoke_signed,
+    transfer_checked_with_fee_multisig_ix_with_program_id,
+    transfer_checked_with_fee_multisig_invoke_with_program_id,
+    transfer_checked_with_fee_multisig_invoke_signed_with_program_id,
+    transfer_checked_with_fee_ix_with_program_id,
+    TransferCheckedWithFeeAccounts,
+    TransferCheckedWithFeeKeys,
+    TransferCheckedWithFeeIxArgs,
+    TRANSFER_CHECKED_WITH_FEE_IX_ACCOUNTS_LEN,
+);
+
+#[cfg(test)]
+mod tests {
+    use spl_token_2022::extension::transfer_fee::instruction::transfer_checked_with_fee;
+    use spl_token_2022_interface::CheckedOpArgs;
+
+    use super::*;
+
+    #[test]
+    fn transfer_checked_with_fee_multisig_serde() {
+        let amount = 69;
+        let decimals = 9;
+        let fee = 420;
+        let from = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let to = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let signatories = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let signatories_ref: [&Pubkey; 2] = [&signatories[0], &signatories[1]];
+
+        let actual = transfer_checked_with_fee_multisig_ix(
+            TransferCheckedWithFeeKeys {
+                from,
+                mint,
+                to,
+                authority,
+            },
+            TransferCheckedWithFeeIxArgs {
+                args: CheckedOpArgs { amount, decimals },
+                fee,
+            },
+            signatories.iter().copied(),
+        )
+        .unwrap();
+        let expected = transfer_checked_with_fee(
+            &spl_token_2022::ID,
+            &from,
+            &mint,
+            &to,
+            &authority,
+            &signatories_ref,
+            amount,
+            decimals,
+            fee,
+        )
+        .unwrap();
+        assert_eq!(actual, expected);
+    }
+}
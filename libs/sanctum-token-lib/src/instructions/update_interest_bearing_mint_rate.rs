@@ -0,0 +1,16 @@
+use crate::extension_instructions;
+
+super::multisig_impl::multisig_impl!(
+    extension_instructions,
+    update_interest_bearing_mint_rate_multisig_ix,
+    update_interest_bearing_mint_rate_multisig_invoke,
+    update_interest_bearing_mint_rate_multisig_invoke_signed,
+    update_interest_bearing_mint_rate_multisig_ix_with_program_id,
+    update_interest_bearing_mint_rate_multisig_invoke_with_program_id,
+    update_interest_bearing_mint_rate_multisig_invoke_signed_with_program_id,
+    update_interest_bearing_mint_rate_ix_with_program_id,
+    UpdateInterestBearingMintRateAccounts,
+    UpdateInterestBearingMintRateKeys,
+    UpdateInterestBearingMintRateIxArgs,
+    UPDATE_INTEREST_BEARING_MINT_RATE_IX_ACCOUNTS_LEN,
+);
@@ -0,0 +1,16 @@
+use crate::extension_instructions;
+
+super::multisig_impl::multisig_impl!(
+    extension_instructions,
+    update_transfer_hook_multisig_ix,
+    update_transfer_hook_multisig_invoke,
+    update_transfer_hook_multisig_invoke_signed,
+    update_transfer_hook_multisig_ix_with_program_id,
+    update_transfer_hook_multisig_invoke_with_program_id,
+    update_transfer_hook_multisig_invoke_signed_with_program_id,
+    update_transfer_hook_ix_with_program_id,
+    UpdateTransferHookAccounts,
+    UpdateTransferHookKeys,
+    UpdateTransferHookIxArgs,
+    UPDATE_TRANSFER_HOOK_IX_ACCOUNTS_LEN,
+);
@@ -0,0 +1,282 @@
+//! Don't use super::multisig_impl::multisig_impl! here because
+//! the token accounts to withdraw from must follow the signatories
+//! and `num_token_accounts` is derived from them
+
+use std::iter::Empty;
+
+use crate::extension_instructions::{
+    withdraw_withheld_tokens_from_accounts_ix_with_program_id,
+    WithdrawWithheldTokensFromAccountsAccounts, WithdrawWithheldTokensFromAccountsIxArgs,
+    WithdrawWithheldTokensFromAccountsKeys, WITHDRAW_WITHHELD_TOKENS_FROM_ACCOUNTS_IX_ACCOUNTS_LEN,
+};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+pub fn withdraw_withheld_tokens_from_accounts_full_ix(
+    keys: WithdrawWithheldTokensFromAccountsKeys,
+    token_accounts: impl Iterator<Item = Pubkey>,
+) -> Result<Instruction, ProgramError> {
+    withdraw_withheld_tokens_from_accounts_full_ix_with_program_id(
+        spl_token_2022_interface::ID,
+        keys,
+        token_accounts,
+    )
+}
+
+pub fn withdraw_withheld_tokens_from_accounts_full_invoke<'a, 'info>(
+    accounts: WithdrawWithheldTokensFromAccountsAccounts<'a, 'info>,
+    token_accounts: &'a [AccountInfo<'info>],
+) -> ProgramResult {
+    withdraw_withheld_tokens_from_accounts_full_invoke_with_program_id(
+        spl_token_2022_interface::ID,
+        accounts,
+        token_accounts,
+    )
+}
+
+pub fn withdraw_withheld_tokens_from_accounts_full_invoke_signed<'a, 'info>(
+    accounts: WithdrawWithheldTokensFromAccountsAccounts<'a, 'info>,
+    token_accounts: &'a [AccountInfo<'info>],
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    withdraw_withheld_tokens_from_accounts_full_invoke_signed_with_program_id(
+        spl_token_2022_interface::ID,
+        accounts,
+        token_accounts,
+        seeds,
+    )
+}
+
+pub fn withdraw_withheld_tokens_from_accounts_full_ix_with_program_id(
+    program_id: Pubkey,
+    keys: WithdrawWithheldTokensFromAccountsKeys,
+    token_accounts: impl Iterator<Item = Pubkey>,
+) -> Result<Instruction, ProgramError> {
+    withdraw_ix(program_id, keys, None::<Empty<Pubkey>>, token_accounts)
+}
+
+pub fn withdraw_withheld_tokens_from_accounts_full_invoke_with_program_id<'a, 'info>(
+    program_id: Pubkey,
+    accounts: WithdrawWithheldTokensFromAccountsAccounts<'a, 'info>,
+    token_accounts: &'a [AccountInfo<'info>],
+) -> ProgramResult {
+    let ix = withdraw_withheld_tokens_from_accounts_full_ix_with_program_id(
+        program_id,
+        accounts.into(),
+        token_accounts.iter().map(|a| *a.key),
+    )?;
+    let accounts = withdraw_account_infos(accounts, &[], token_accounts);
+    invoke(&ix, &accounts)
+}
+
+pub fn withdraw_withheld_tokens_from_accounts_full_invoke_signed_with_program_id<'a, 'info>(
+    program_id: Pubkey,
+    accounts: WithdrawWithheldTokensFromAccountsAccounts<'a, 'info>,
+    token_accounts: &'a [AccountInfo<'info>],
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = withdraw_withheld_tokens_from_accounts_full_ix_with_program_id(
+        program_id,
+        accounts.into(),
+        token_accounts.iter().map(|a| *a.key),
+    )?;
+    let accounts = withdraw_account_infos(accounts, &[], token_accounts);
+    invoke_signed(&ix, &accounts, seeds)
+}
+
+pub fn withdraw_withheld_tokens_from_accounts_multisig_full_ix(
+    keys: WithdrawWithheldTokensFromAccountsKeys,
+    signatories: impl Iterator<Item = Pubkey>,
+    token_accounts: impl Iterator<Item = Pubkey>,
+) -> Result<Instruction, ProgramError> {
+    withdraw_withheld_tokens_from_accounts_multisig_full_ix_with_program_id(
+        spl_token_2022_interface::ID,
+        keys,
+        signatories,
+        token_accounts,
+    )
+}
+
+pub fn withdraw_withheld_tokens_from_accounts_multisig_full_invoke<'a, 'info>(
+    accounts: WithdrawWithheldTokensFromAccountsAccounts<'a, 'info>,
+    signatories: &'a [AccountInfo<'info>],
+    token_accounts: &'a [AccountInfo<'info>],
+) -> ProgramResult {
+    withdraw_withheld_tokens_from_accounts_multisig_full_invoke_with_program_id(
+        spl_token_2022_interface::ID,
+        accounts,
+        signatories,
+        token_accounts,
+    )
+}
+
+pub fn withdraw_withheld_tokens_from_accounts_multisig_full_invoke_signed<'a, 'info>(
+    accounts: WithdrawWithheldTokensFromAccountsAccounts<'a, 'info>,
+    signatories: &'a [AccountInfo<'info>],
+    token_accounts: &'a [AccountInfo<'info>],
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    withdraw_withheld_tokens_from_accounts_multisig_full_invoke_signed_with_program_id(
+        spl_token_2022_interface::ID,
+        accounts,
+        signatories,
+        token_accounts,
+        seeds,
+    )
+}
+
+pub fn withdraw_withheld_tokens_from_accounts_multisig_full_ix_with_program_id(
+    program_id: Pubkey,
+    keys: WithdrawWithheldTokensFromAccountsKeys,
+    signatories: impl Iterator<Item = Pubkey>,
+    token_accounts: impl Iterator<Item = Pubkey>,
+) -> Result<Instruction, ProgramError> {
+    withdraw_ix(program_id, keys, Some(signatories), token_accounts)
+}
+
+pub fn withdraw_withheld_tokens_from_accounts_multisig_full_invoke_with_program_id<'a, 'info>(
+    program_id: Pubkey,
+    accounts: WithdrawWithheldTokensFromAccountsAccounts<'a, 'info>,
+    signatories: &'a [AccountInfo<'info>],
+    token_accounts: &'a [AccountInfo<'info>],
+) -> ProgramResult {
+    let ix = withdraw_withheld_tokens_from_accounts_multisig_full_ix_with_program_id(
+        program_id,
+        accounts.into(),
+        signatories.iter().map(|a| *a.key),
+        token_accounts.iter().map(|a| *a.key),
+    )?;
+    let accounts = withdraw_account_infos(accounts, signatories, token_accounts);
+    invoke(&ix, &accounts)
+}
+
+pub fn withdraw_withheld_tokens_from_accounts_multisig_full_invoke_signed_with_program_id<
+    'a,
+    'info,
+>(
+    program_id: Pubkey,
+    accounts: WithdrawWithheldTokensFromAccountsAccounts<'a, 'info>,
+    signatories: &'a [AccountInfo<'info>],
+    token_accounts: &'a [AccountInfo<'info>],
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = withdraw_withheld_tokens_from_accounts_multisig_full_ix_with_program_id(
+        program_id,
+        accounts.into(),
+        signatories.iter().map(|a| *a.key),
+        token_accounts.iter().map(|a| *a.key),
+    )?;
+    let accounts = withdraw_account_infos(accounts, signatories, token_accounts);
+    invoke_signed(&ix, &accounts, seeds)
+}
+
+/// `signatories` is None for a single authority
+fn withdraw_ix(
+    program_id: Pubkey,
+    keys: WithdrawWithheldTokensFromAccountsKeys,
+    signatories: Option<impl Iterator<Item = Pubkey>>,
+    token_accounts: impl Iterator<Item = Pubkey>,
+) -> Result<Instruction, ProgramError> {
+    let token_accounts: Vec<AccountMeta> = token_accounts
+        .map(|pubkey| AccountMeta {
+            pubkey,
+            is_signer: false,
+            is_writable: true,
+        })
+        .collect();
+    let num_token_accounts =
+        u8::try_from(token_accounts.len()).map_err(|_e| ProgramError::InvalidInstructionData)?;
+    let mut ix = withdraw_withheld_tokens_from_accounts_ix_with_program_id(
+        program_id,
+        keys,
+        WithdrawWithheldTokensFromAccountsIxArgs { num_token_accounts },
+    )?;
+    if let Some(signatories) = signatories {
+        ix.accounts.last_mut().unwrap().is_signer = false;
+        ix.accounts.extend(signatories.map(|pubkey| AccountMeta {
+            pubkey,
+            is_signer: true,
+            is_writable: false,
+        }));
+    }
+    ix.accounts.extend(token_accounts);
+    Ok(ix)
+}
+
+fn withdraw_account_infos<'info>(
+    accounts: WithdrawWithheldTokensFromAccountsAccounts<'_, 'info>,
+    signatories: &[AccountInfo<'info>],
+    token_accounts: &[AccountInfo<'info>],
+) -> Vec<AccountInfo<'info>> {
+    let mut res = Vec::from(Into::<
+        [AccountInfo; WITHDRAW_WITHHELD_TOKENS_FROM_ACCOUNTS_IX_ACCOUNTS_LEN],
+    >::into(accounts));
+    res.extend(signatories.iter().cloned());
+    res.extend(token_accounts.iter().cloned());
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use spl_token_2022::extension::transfer_fee::instruction::withdraw_withheld_tokens_from_accounts;
+
+    use super::*;
+
+    #[test]
+    fn withdraw_withheld_tokens_from_accounts_multisig_full_serde() {
+        let mint = Pubkey::new_unique();
+        let to = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let signatories = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let signatories_ref: [&Pubkey; 2] = [&signatories[0], &signatories[1]];
+        let token_accounts = [
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+        let token_accounts_ref: [&Pubkey; 3] =
+            [&token_accounts[0], &token_accounts[1], &token_accounts[2]];
+        let keys = WithdrawWithheldTokensFromAccountsKeys {
+            mint,
+            to,
+            authority,
+        };
+
+        let actual = withdraw_withheld_tokens_from_accounts_multisig_full_ix(
+            keys,
+            signatories.iter().copied(),
+            token_accounts.iter().copied(),
+        )
+        .unwrap();
+        let expected = withdraw_withheld_tokens_from_accounts(
+            &spl_token_2022::ID,
+            &mint,
+            &to,
+            &authority,
+            &signatories_ref,
+            &token_accounts_ref,
+        )
+        .unwrap();
+        assert_eq!(actual, expected);
+
+        let actual =
+            withdraw_withheld_tokens_from_accounts_full_ix(keys, token_accounts.iter().copied())
+                .unwrap();
+        let expected = withdraw_withheld_tokens_from_accounts(
+            &spl_token_2022::ID,
+            &mint,
+            &to,
+            &authority,
+            &[],
+            &token_accounts_ref,
+        )
+        .unwrap();
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,15 @@
+use crate::extension_instructions;
+
+super::multisig_impl::multisig_impl_no_ix_args!(
+    extension_instructions,
+    withdraw_withheld_tokens_from_mint_multisig_ix,
+    withdraw_withheld_tokens_from_mint_multisig_invoke,
+    withdraw_withheld_tokens_from_mint_multisig_invoke_signed,
+    withdraw_withheld_tokens_from_mint_multisig_ix_with_program_id,
+    withdraw_withheld_tokens_from_mint_multisig_invoke_with_program_id,
+    withdraw_withheld_tokens_from_mint_multisig_invoke_signed_with_program_id,
+    withdraw_withheld_tokens_from_mint_ix_with_program_id,
+    WithdrawWithheldTokensFromMintAccounts,
+    WithdrawWithheldTokensFromMintKeys,
+    WITHDRAW_WITHHELD_TOKENS_FROM_MINT_IX_ACCOUNTS_LEN,
+);
@@ -1,12 +1,19 @@
 mod account_resolvers;
+pub mod extension_instructions;
 mod instructions;
 mod mint_with_token_program;
 mod readonly;
+mod transfer_fee;
+mod ui_amount;
 
 pub use account_resolvers::*;
+// pub use extension_instructions::*; // dont re-export extension_instructions so that its Token-2022 program ID doesn't end up as this crate's ID
 pub use instructions::*;
 pub use mint_with_token_program::*;
 pub use readonly::*;
+pub use ui_amount::*;
+
+use solana_program::{pubkey, pubkey::Pubkey};
 
 // These consts are `<Account/Mint as Packed>::LEN`,
 // but just redefine them here so that we dont need to depend on spl-token
@@ -14,3 +21,23 @@ pub use readonly::*;
 pub const SPL_TOKEN_ACCOUNT_PACKED_LEN: usize = 165;
 
 pub const SPL_MINT_ACCOUNT_PACKED_LEN: usize = 82;
+
+pub const SPL_MULTISIG_ACCOUNT_PACKED_LEN: usize = 355;
+
+/// Mint of wrapped SOL (wSOL) token accounts of the original token program
+pub const NATIVE_MINT: Pubkey = pubkey!("So11111111111111111111111111111111111111112");
+
+/// Mint of wrapped SOL (wSOL) token accounts of token-2022
+pub const NATIVE_MINT_2022: Pubkey = pubkey!("9pan9bMn5HatX4EJdBwg9VgCa7Uz5HL8N1m5D3NdXejP");
+
+/// The wSOL mint of `token_program`, or `None` if it is neither
+/// the original token program nor token-2022
+pub fn native_mint(token_program: &Pubkey) -> Option<Pubkey> {
+    if *token_program == spl_token_interface::ID {
+        Some(NATIVE_MINT)
+    } else if *token_program == spl_token_2022_interface::ID {
+        Some(NATIVE_MINT_2022)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,661 @@
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use solana_readonly_account::ReadonlyAccountData;
+use spl_token_interface::AccountState;
+
+use crate::{
+    readonly::unpack_le_u64, InitializedMintAccount, SPL_MINT_ACCOUNT_PACKED_LEN,
+    SPL_TOKEN_ACCOUNT_STATE_FROZEN_DISCM, SPL_TOKEN_ACCOUNT_STATE_INITIALIZED_DISCM,
+    SPL_TOKEN_ACCOUNT_STATE_UNINITIALIZED_DISCM,
+};
+
+use super::{
+    find_extension_value, tlv_data_offset, unpack_le_i16, unpack_le_i64, unpack_le_u16,
+    unpack_optional_non_zero_pubkey, ExtensionType, ExtensionTypeIter,
+    TOKEN_2022_ACCOUNT_TYPE_MINT_DISCM,
+};
+
+pub const TRANSFER_FEE_EPOCH_OFFSET: usize = 0;
+pub const TRANSFER_FEE_MAXIMUM_FEE_OFFSET: usize = TRANSFER_FEE_EPOCH_OFFSET + 8;
+pub const TRANSFER_FEE_TRANSFER_FEE_BASIS_POINTS_OFFSET: usize =
+    TRANSFER_FEE_MAXIMUM_FEE_OFFSET + 8;
+pub const TRANSFER_FEE_LEN: usize = TRANSFER_FEE_TRANSFER_FEE_BASIS_POINTS_OFFSET + 2;
+
+pub const TRANSFER_FEE_CONFIG_TRANSFER_FEE_CONFIG_AUTHORITY_OFFSET: usize = 0;
+pub const TRANSFER_FEE_CONFIG_WITHDRAW_WITHHELD_AUTHORITY_OFFSET: usize =
+    TRANSFER_FEE_CONFIG_TRANSFER_FEE_CONFIG_AUTHORITY_OFFSET + 32;
+pub const TRANSFER_FEE_CONFIG_WITHHELD_AMOUNT_OFFSET: usize =
+    TRANSFER_FEE_CONFIG_WITHDRAW_WITHHELD_AUTHORITY_OFFSET + 32;
+pub const TRANSFER_FEE_CONFIG_OLDER_TRANSFER_FEE_OFFSET: usize =
+    TRANSFER_FEE_CONFIG_WITHHELD_AMOUNT_OFFSET + 8;
+pub const TRANSFER_FEE_CONFIG_NEWER_TRANSFER_FEE_OFFSET: usize =
+    TRANSFER_FEE_CONFIG_OLDER_TRANSFER_FEE_OFFSET + TRANSFER_FEE_LEN;
+pub const TRANSFER_FEE_CONFIG_LEN: usize =
+    TRANSFER_FEE_CONFIG_NEWER_TRANSFER_FEE_OFFSET + TRANSFER_FEE_LEN;
+
+pub const INTEREST_BEARING_CONFIG_RATE_AUTHORITY_OFFSET: usize = 0;
+pub const INTEREST_BEARING_CONFIG_INITIALIZATION_TIMESTAMP_OFFSET: usize =
+    INTEREST_BEARING_CONFIG_RATE_AUTHORITY_OFFSET + 32;
+pub const INTEREST_BEARING_CONFIG_PRE_UPDATE_AVERAGE_RATE_OFFSET: usize =
+    INTEREST_BEARING_CONFIG_INITIALIZATION_TIMESTAMP_OFFSET + 8;
+pub const INTEREST_BEARING_CONFIG_LAST_UPDATE_TIMESTAMP_OFFSET: usize =
+    INTEREST_BEARING_CONFIG_PRE_UPDATE_AVERAGE_RATE_OFFSET + 2;
+pub const INTEREST_BEARING_CONFIG_CURRENT_RATE_OFFSET: usize =
+    INTEREST_BEARING_CONFIG_LAST_UPDATE_TIMESTAMP_OFFSET + 8;
+pub const INTEREST_BEARING_CONFIG_LEN: usize = INTEREST_BEARING_CONFIG_CURRENT_RATE_OFFSET + 2;
+
+pub const MINT_CLOSE_AUTHORITY_LEN: usize = 32;
+
+pub const PERMANENT_DELEGATE_LEN: usize = 32;
+
+pub const TRANSFER_HOOK_AUTHORITY_OFFSET: usize = 0;
+pub const TRANSFER_HOOK_PROGRAM_ID_OFFSET: usize = TRANSFER_HOOK_AUTHORITY_OFFSET + 32;
+pub const TRANSFER_HOOK_LEN: usize = TRANSFER_HOOK_PROGRAM_ID_OFFSET + 32;
+
+pub const METADATA_POINTER_AUTHORITY_OFFSET: usize = 0;
+pub const METADATA_POINTER_METADATA_ADDRESS_OFFSET: usize = METADATA_POINTER_AUTHORITY_OFFSET + 32;
+pub const METADATA_POINTER_LEN: usize = METADATA_POINTER_METADATA_ADDRESS_OFFSET + 32;
+
+pub const DEFAULT_ACCOUNT_STATE_LEN: usize = 1;
+
+pub const NON_TRANSFERABLE_LEN: usize = 0;
+
+/// A transfer fee that applies from `epoch` onwards
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TransferFee {
+    /// First epoch where the transfer fee takes effect
+    pub epoch: u64,
+
+    /// Maximum fee assessed on transfers, in token atomics
+    pub maximum_fee: u64,
+
+    /// Amount of transfer collected as fees, expressed as basis points of the transfer amount
+    pub transfer_fee_basis_points: u16,
+}
+
+impl TransferFee {
+    fn unpack(slice: &[u8], offset: usize) -> Self {
+        Self {
+            epoch: unpack_le_u64(slice, offset + TRANSFER_FEE_EPOCH_OFFSET),
+            maximum_fee: unpack_le_u64(slice, offset + TRANSFER_FEE_MAXIMUM_FEE_OFFSET),
+            transfer_fee_basis_points: unpack_le_u16(
+                slice,
+                offset + TRANSFER_FEE_TRANSFER_FEE_BASIS_POINTS_OFFSET,
+            ),
+        }
+    }
+}
+
+/// Mint extension
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TransferFeeConfig {
+    pub transfer_fee_config_authority: Option<Pubkey>,
+
+    pub withdraw_withheld_authority: Option<Pubkey>,
+
+    /// Withheld transfer fee tokens that have been moved to the mint for withdrawal
+    pub withheld_amount: u64,
+
+    /// Older transfer fee, used if the current epoch < `newer_transfer_fee.epoch`
+    pub older_transfer_fee: TransferFee,
+
+    /// Newer transfer fee, used if the current epoch >= `newer_transfer_fee.epoch`
+    pub newer_transfer_fee: TransferFee,
+}
+
+impl TransferFeeConfig {
+    fn unpack(value: &[u8]) -> Self {
+        Self {
+            transfer_fee_config_authority: unpack_optional_non_zero_pubkey(
+                value,
+                TRANSFER_FEE_CONFIG_TRANSFER_FEE_CONFIG_AUTHORITY_OFFSET,
+            ),
+            withdraw_withheld_authority: unpack_optional_non_zero_pubkey(
+                value,
+                TRANSFER_FEE_CONFIG_WITHDRAW_WITHHELD_AUTHORITY_OFFSET,
+            ),
+            withheld_amount: unpack_le_u64(value, TRANSFER_FEE_CONFIG_WITHHELD_AMOUNT_OFFSET),
+            older_transfer_fee: TransferFee::unpack(
+                value,
+                TRANSFER_FEE_CONFIG_OLDER_TRANSFER_FEE_OFFSET,
+            ),
+            newer_transfer_fee: TransferFee::unpack(
+                value,
+                TRANSFER_FEE_CONFIG_NEWER_TRANSFER_FEE_OFFSET,
+            ),
+        }
+    }
+}
+
+/// Mint extension
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct InterestBearingConfig {
+    pub rate_authority: Option<Pubkey>,
+
+    pub initialization_timestamp: i64,
+
+    /// Average rate from initialization until the last update, in basis points
+    pub pre_update_average_rate: i16,
+
+    pub last_update_timestamp: i64,
+
+    /// Current rate since the last update, in basis points
+    pub current_rate: i16,
+}
+
+impl InterestBearingConfig {
+    fn unpack(value: &[u8]) -> Self {
+        Self {
+            rate_authority: unpack_optional_non_zero_pubkey(
+                value,
+                INTEREST_BEARING_CONFIG_RATE_AUTHORITY_OFFSET,
+            ),
+            initialization_timestamp: unpack_le_i64(
+                value,
+                INTEREST_BEARING_CONFIG_INITIALIZATION_TIMESTAMP_OFFSET,
+            ),
+            pre_update_average_rate: unpack_le_i16(
+                value,
+                INTEREST_BEARING_CONFIG_PRE_UPDATE_AVERAGE_RATE_OFFSET,
+            ),
+            last_update_timestamp: unpack_le_i64(
+                value,
+                INTEREST_BEARING_CONFIG_LAST_UPDATE_TIMESTAMP_OFFSET,
+            ),
+            current_rate: unpack_le_i16(value, INTEREST_BEARING_CONFIG_CURRENT_RATE_OFFSET),
+        }
+    }
+}
+
+/// Mint extension
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TransferHook {
+    pub authority: Option<Pubkey>,
+
+    /// The program to CPI into on every transfer
+    pub program_id: Option<Pubkey>,
+}
+
+/// Mint extension
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct MetadataPointer {
+    pub authority: Option<Pubkey>,
+
+    /// The account that holds the token metadata
+    pub metadata_address: Option<Pubkey>,
+}
+
+impl<T: ReadonlyAccountData> InitializedMintAccount<T> {
+    /// Errors if the Token-2022 account type or the TLV data before the returned extension is malformed
+    fn mint_extension_value<R>(
+        &self,
+        extension_type: ExtensionType,
+        value_len: usize,
+        f: impl FnOnce(&[u8]) -> R,
+    ) -> Result<Option<R>, ProgramError> {
+        let d = self.as_valid().as_readonly().as_inner().data();
+        let offset = tlv_data_offset(
+            &d,
+            SPL_MINT_ACCOUNT_PACKED_LEN,
+            TOKEN_2022_ACCOUNT_TYPE_MINT_DISCM,
+        )?;
+        Ok(find_extension_value(&d, offset, extension_type, value_len)?.map(f))
+    }
+
+    /// Errors if the Token-2022 account type is malformed.
+    /// Empty for mints without extensions, including all tokenkeg mints
+    pub fn mint_extension_types(
+        &self,
+    ) -> Result<ExtensionTypeIter<T::DataDeref<'_>>, ProgramError> {
+        let data = self.as_valid().as_readonly().as_inner().data();
+        let offset = tlv_data_offset(
+            &data,
+            SPL_MINT_ACCOUNT_PACKED_LEN,
+            TOKEN_2022_ACCOUNT_TYPE_MINT_DISCM,
+        )?;
+        Ok(ExtensionTypeIter {
+            data,
+            offset,
+            is_done: false,
+        })
+    }
+
+    pub fn mint_transfer_fee_config(&self) -> Result<Option<TransferFeeConfig>, ProgramError> {
+        self.mint_extension_value(
+            ExtensionType::TransferFeeConfig,
+            TRANSFER_FEE_CONFIG_LEN,
+            TransferFeeConfig::unpack,
+        )
+    }
+
+    pub fn mint_interest_bearing_config(
+        &self,
+    ) -> Result<Option<InterestBearingConfig>, ProgramError> {
+        self.mint_extension_value(
+            ExtensionType::InterestBearingConfig,
+            INTEREST_BEARING_CONFIG_LEN,
+            InterestBearingConfig::unpack,
+        )
+    }
+
+    /// Returns:
+    /// - `None` if the mint does not have the `MintCloseAuthority` extension
+    /// - `Some(None)` if the extension's close authority is not set
+    pub fn mint_mint_close_authority(&self) -> Result<Option<Option<Pubkey>>, ProgramError> {
+        self.mint_extension_value(
+            ExtensionType::MintCloseAuthority,
+            MINT_CLOSE_AUTHORITY_LEN,
+            |v| unpack_optional_non_zero_pubkey(v, 0),
+        )
+    }
+
+    /// Returns:
+    /// - `None` if the mint does not have the `PermanentDelegate` extension
+    /// - `Some(None)` if the extension's delegate is not set
+    pub fn mint_permanent_delegate(&self) -> Result<Option<Option<Pubkey>>, ProgramError> {
+        self.mint_extension_value(
+            ExtensionType::PermanentDelegate,
+            PERMANENT_DELEGATE_LEN,
+            |v| unpack_optional_non_zero_pubkey(v, 0),
+        )
+    }
+
+    pub fn mint_transfer_hook(&self) -> Result<Option<TransferHook>, ProgramError> {
+        self.mint_extension_value(ExtensionType::TransferHook, TRANSFER_HOOK_LEN, |v| {
+            TransferHook {
+                authority: unpack_optional_non_zero_pubkey(v, TRANSFER_HOOK_AUTHORITY_OFFSET),
+                program_id: unpack_optional_non_zero_pubkey(v, TRANSFER_HOOK_PROGRAM_ID_OFFSET),
+            }
+        })
+    }
+
+    pub fn mint_metadata_pointer(&self) -> Result<Option<MetadataPointer>, ProgramError> {
+        self.mint_extension_value(ExtensionType::MetadataPointer, METADATA_POINTER_LEN, |v| {
+            MetadataPointer {
+                authority: unpack_optional_non_zero_pubkey(v, METADATA_POINTER_AUTHORITY_OFFSET),
+                metadata_address: unpack_optional_non_zero_pubkey(
+                    v,
+                    METADATA_POINTER_METADATA_ADDRESS_OFFSET,
+                ),
+            }
+        })
+    }
+
+    /// The state new token accounts of this mint are initialized with
+    pub fn mint_default_account_state(&self) -> Result<Option<AccountState>, ProgramError> {
+        self.mint_extension_value(
+            ExtensionType::DefaultAccountState,
+            DEFAULT_ACCOUNT_STATE_LEN,
+            |v| match v[0] {
+                SPL_TOKEN_ACCOUNT_STATE_UNINITIALIZED_DISCM => Ok(AccountState::Uninitialized),
+                SPL_TOKEN_ACCOUNT_STATE_INITIALIZED_DISCM => Ok(AccountState::Initialized),
+                SPL_TOKEN_ACCOUNT_STATE_FROZEN_DISCM => Ok(AccountState::Frozen),
+                _ => Err(ProgramError::InvalidAccountData),
+            },
+        )?
+        .transpose()
+    }
+
+    pub fn mint_is_non_transferable(&self) -> Result<bool, ProgramError> {
+        self.mint_extension_value(ExtensionType::NonTransferable, NON_TRANSFERABLE_LEN, |_| ())
+            .map(|o| o.is_some())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{collection::vec, option, prelude::*};
+    use sanctum_solana_test_utils::token::proptest_utils::token_2022::token22_mint_no_extensions;
+    use solana_program::program_pack::Pack;
+    use spl_token_2022::{
+        extension::{
+            BaseStateWithExtensions, BaseStateWithExtensionsMut, StateWithExtensions,
+            StateWithExtensionsMut,
+        },
+        state::Mint,
+    };
+
+    use crate::{
+        readonly::test_utils::{optional_non_zero_pubkey, AccountData},
+        ReadonlyMintAccount,
+    };
+
+    use super::{
+        super::{TlvExtensionType, TOKEN_2022_ACCOUNT_TYPE_OFFSET, TOKEN_2022_TLV_DATA_OFFSET},
+        *,
+    };
+
+    #[derive(Clone, Debug, Default)]
+    struct MintExtensions {
+        transfer_fee_config: Option<TransferFeeConfig>,
+        interest_bearing_config: Option<InterestBearingConfig>,
+        mint_close_authority: Option<Option<Pubkey>>,
+        permanent_delegate: Option<Option<Pubkey>>,
+        transfer_hook: Option<TransferHook>,
+        metadata_pointer: Option<MetadataPointer>,
+        default_account_state: Option<AccountState>,
+        non_transferable: bool,
+    }
+
+    prop_compose! {
+        fn transfer_fee()
+            (epoch: u64, maximum_fee: u64, transfer_fee_basis_points: u16) -> TransferFee {
+                TransferFee { epoch, maximum_fee, transfer_fee_basis_points }
+            }
+    }
+
+    prop_compose! {
+        fn transfer_fee_config()
+            (
+                transfer_fee_config_authority in optional_non_zero_pubkey(),
+                withdraw_withheld_authority in optional_non_zero_pubkey(),
+                withheld_amount: u64,
+                older_transfer_fee in transfer_fee(),
+                newer_transfer_fee in transfer_fee(),
+            ) -> TransferFeeConfig {
+                TransferFeeConfig {
+                    transfer_fee_config_authority,
+                    withdraw_withheld_authority,
+                    withheld_amount,
+                    older_transfer_fee,
+                    newer_transfer_fee,
+                }
+            }
+    }
+
+    prop_compose! {
+        fn interest_bearing_config()
+            (
+                rate_authority in optional_non_zero_pubkey(),
+                initialization_timestamp: i64,
+                pre_update_average_rate: i16,
+                last_update_timestamp: i64,
+                current_rate: i16,
+            ) -> InterestBearingConfig {
+                InterestBearingConfig {
+                    rate_authority,
+                    initialization_timestamp,
+                    pre_update_average_rate,
+                    last_update_timestamp,
+                    current_rate,
+                }
+            }
+    }
+
+    fn account_state() -> impl Strategy<Value = AccountState> {
+        prop_oneof![
+            Just(AccountState::Uninitialized),
+            Just(AccountState::Initialized),
+            Just(AccountState::Frozen),
+        ]
+    }
+
+    prop_compose! {
+        fn mint_extensions()
+            (
+                transfer_fee_config in option::of(transfer_fee_config()),
+                interest_bearing_config in option::of(interest_bearing_config()),
+                mint_close_authority in option::of(optional_non_zero_pubkey()),
+                permanent_delegate in option::of(optional_non_zero_pubkey()),
+                transfer_hook in option::of(
+                    (optional_non_zero_pubkey(), optional_non_zero_pubkey())
+                        .prop_map(|(authority, program_id)| TransferHook { authority, program_id })
+                ),
+                metadata_pointer in option::of(
+                    (optional_non_zero_pubkey(), optional_non_zero_pubkey())
+                        .prop_map(|(authority, metadata_address)| MetadataPointer { authority, metadata_address })
+                ),
+                default_account_state in option::of(account_state()),
+                non_transferable: bool,
+            ) -> MintExtensions {
+                MintExtensions {
+                    transfer_fee_config,
+                    interest_bearing_config,
+                    mint_close_authority,
+                    permanent_delegate,
+                    transfer_hook,
+                    metadata_pointer,
+                    default_account_state,
+                    non_transferable,
+                }
+            }
+    }
+
+    fn conv_transfer_fee(fee: TransferFee) -> spl_token_2022::extension::transfer_fee::TransferFee {
+        spl_token_2022::extension::transfer_fee::TransferFee {
+            epoch: fee.epoch.into(),
+            maximum_fee: fee.maximum_fee.into(),
+            transfer_fee_basis_points: fee.transfer_fee_basis_points.into(),
+        }
+    }
+
+    /// Returns the packed mint and the expected extension types in TLV order
+    fn pack_mint_with_extensions(
+        mint: Mint,
+        exts: &MintExtensions,
+    ) -> (Vec<u8>, Vec<spl_token_2022::extension::ExtensionType>) {
+        use spl_token_2022::extension::{
+            default_account_state, interest_bearing_mint, metadata_pointer, mint_close_authority,
+            non_transferable, permanent_delegate, transfer_fee, transfer_hook,
+            ExtensionType as SplExtensionType,
+        };
+
+        let mut types = vec![];
+        if exts.transfer_fee_config.is_some() {
+            types.push(SplExtensionType::TransferFeeConfig);
+        }
+        if exts.interest_bearing_config.is_some() {
+            types.push(SplExtensionType::InterestBearingConfig);
+        }
+        if exts.mint_close_authority.is_some() {
+            types.push(SplExtensionType::MintCloseAuthority);
+        }
+        if exts.permanent_delegate.is_some() {
+            types.push(SplExtensionType::PermanentDelegate);
+        }
+        if exts.transfer_hook.is_some() {
+            types.push(SplExtensionType::TransferHook);
+        }
+        if exts.metadata_pointer.is_some() {
+            types.push(SplExtensionType::MetadataPointer);
+        }
+        if exts.default_account_state.is_some() {
+            types.push(SplExtensionType::DefaultAccountState);
+        }
+        if exts.non_transferable {
+            types.push(SplExtensionType::NonTransferable);
+        }
+
+        let len = SplExtensionType::try_calculate_account_len::<Mint>(&types).unwrap();
+        let mut data = vec![0u8; len];
+        let mut state = StateWithExtensionsMut::<Mint>::unpack_uninitialized(&mut data).unwrap();
+        if let Some(c) = exts.transfer_fee_config {
+            let e = state
+                .init_extension::<transfer_fee::TransferFeeConfig>(true)
+                .unwrap();
+            e.transfer_fee_config_authority = c.transfer_fee_config_authority.try_into().unwrap();
+            e.withdraw_withheld_authority = c.withdraw_withheld_authority.try_into().unwrap();
+            e.withheld_amount = c.withheld_amount.into();
+            e.older_transfer_fee = conv_transfer_fee(c.older_transfer_fee);
+            e.newer_transfer_fee = conv_transfer_fee(c.newer_transfer_fee);
+        }
+        if let Some(c) = exts.interest_bearing_config {
+            let e = state
+                .init_extension::<interest_bearing_mint::InterestBearingConfig>(true)
+                .unwrap();
+            e.rate_authority = c.rate_authority.try_into().unwrap();
+            e.initialization_timestamp = c.initialization_timestamp.into();
+            e.pre_update_average_rate = c.pre_update_average_rate.into();
+            e.last_update_timestamp = c.last_update_timestamp.into();
+            e.current_rate = c.current_rate.into();
+        }
+        if let Some(close_authority) = exts.mint_close_authority {
+            let e = state
+                .init_extension::<mint_close_authority::MintCloseAuthority>(true)
+                .unwrap();
+            e.close_authority = close_authority.try_into().unwrap();
+        }
+        if let Some(delegate) = exts.permanent_delegate {
+            let e = state
+                .init_extension::<permanent_delegate::PermanentDelegate>(true)
+                .unwrap();
+            e.delegate = delegate.try_into().unwrap();
+        }
+        if let Some(c) = exts.transfer_hook {
+            let e = state
+                .init_extension::<transfer_hook::TransferHook>(true)
+                .unwrap();
+            e.authority = c.authority.try_into().unwrap();
+            e.program_id = c.program_id.try_into().unwrap();
+        }
+        if let Some(c) = exts.metadata_pointer {
+            let e = state
+                .init_extension::<metadata_pointer::MetadataPointer>(true)
+                .unwrap();
+            e.authority = c.authority.try_into().unwrap();
+            e.metadata_address = c.metadata_address.try_into().unwrap();
+        }
+        if let Some(s) = &exts.default_account_state {
+            let e = state
+                .init_extension::<default_account_state::DefaultAccountState>(true)
+                .unwrap();
+            e.state = s.clone() as u8;
+        }
+        if exts.non_transferable {
+            state
+                .init_extension::<non_transferable::NonTransferable>(true)
+                .unwrap();
+        }
+        state.base = mint;
+        state.pack_base();
+        state.init_account_type().unwrap();
+        (data, types)
+    }
+
+    proptest! {
+        #[test]
+        fn mint_extensions_match_full_deser(
+            mint in token22_mint_no_extensions(),
+            exts in mint_extensions(),
+        ) {
+            let mint = Mint { is_initialized: true, ..mint };
+            let (data, expected_types) = pack_mint_with_extensions(mint, &exts);
+            prop_assert_eq!(
+                StateWithExtensions::<Mint>::unpack(&data).unwrap().get_extension_types().unwrap(),
+                expected_types.clone()
+            );
+            let account = ReadonlyMintAccount(AccountData(&data))
+                .try_into_valid()
+                .unwrap()
+                .try_into_initialized()
+                .unwrap();
+            let types: Vec<u16> = account
+                .mint_extension_types()
+                .unwrap()
+                .map(|t| u16::from(t.unwrap()))
+                .collect();
+            let expected_types: Vec<u16> = expected_types.into_iter().map(u16::from).collect();
+            prop_assert_eq!(types, expected_types);
+            prop_assert_eq!(account.mint_supply(), mint.supply);
+            prop_assert_eq!(account.mint_transfer_fee_config().unwrap(), exts.transfer_fee_config);
+            prop_assert_eq!(account.mint_interest_bearing_config().unwrap(), exts.interest_bearing_config);
+            prop_assert_eq!(account.mint_mint_close_authority().unwrap(), exts.mint_close_authority);
+            prop_assert_eq!(account.mint_permanent_delegate().unwrap(), exts.permanent_delegate);
+            prop_assert_eq!(account.mint_transfer_hook().unwrap(), exts.transfer_hook);
+            prop_assert_eq!(account.mint_metadata_pointer().unwrap(), exts.metadata_pointer);
+            prop_assert_eq!(account.mint_default_account_state().unwrap(), exts.default_account_state.clone());
+            prop_assert_eq!(account.mint_is_non_transferable().unwrap(), exts.non_transferable);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn mint_extension_types_matches_full_deser_invalid(
+            mint in token22_mint_no_extensions(),
+            rest in vec(any::<u8>(), 0..=256),
+            is_padding_zeroed: bool,
+            account_type in 0..=3u8,
+        ) {
+            let mint = Mint { is_initialized: true, ..mint };
+            let mut data = vec![0u8; SPL_MINT_ACCOUNT_PACKED_LEN];
+            mint.pack_into_slice(&mut data);
+            if !rest.is_empty() {
+                if !is_padding_zeroed {
+                    data.extend_from_slice(&rest[..SPL_MINT_ACCOUNT_PACKED_LEN.min(rest.len())]);
+                }
+                data.resize(TOKEN_2022_ACCOUNT_TYPE_OFFSET, 0);
+                data.push(account_type);
+                data.extend_from_slice(&rest);
+            }
+            let expected = StateWithExtensions::<Mint>::unpack(&data)
+                .and_then(|s| s.get_extension_types())
+                .map(|types| types.into_iter().map(u16::from).collect::<Vec<_>>());
+            let account = ReadonlyMintAccount(AccountData(&data))
+                .try_into_valid()
+                .unwrap()
+                .try_into_initialized()
+                .unwrap();
+            let actual = account
+                .mint_extension_types()
+                .and_then(|iter| iter.collect::<Result<Vec<_>, _>>());
+            match actual {
+                // spl_token_2022 fails on extension types it does not know, we skip them
+                Ok(actual) if actual.iter().any(|t| matches!(t, TlvExtensionType::Unknown(_))) => {
+                    prop_assert!(expected.is_err());
+                }
+                Ok(actual) => {
+                    prop_assert_eq!(Ok(actual.into_iter().map(u16::from).collect()), expected);
+                }
+                Err(_) => prop_assert!(expected.is_err()),
+            }
+        }
+    }
+
+    #[test]
+    fn mint_extension_skips_unknown_tlv_entry() {
+        let close_authority = Some(Some(Pubkey::new_unique()));
+        let (mut data, _) = pack_mint_with_extensions(
+            Mint {
+                is_initialized: true,
+                ..Default::default()
+            },
+            &MintExtensions {
+                mint_close_authority: close_authority,
+                ..Default::default()
+            },
+        );
+        // an extension type newer than ExtensionType, before the MintCloseAuthority entry
+        let unknown_type = ExtensionType::VARIANTS.len() as u16;
+        let unknown_entry = [
+            &unknown_type.to_le_bytes()[..],
+            &3u16.to_le_bytes(),
+            &[1, 2, 3],
+        ]
+        .concat();
+        data.splice(
+            TOKEN_2022_TLV_DATA_OFFSET..TOKEN_2022_TLV_DATA_OFFSET,
+            unknown_entry,
+        );
+
+        let account = ReadonlyMintAccount(AccountData(&data))
+            .try_into_valid()
+            .unwrap()
+            .try_into_initialized()
+            .unwrap();
+        assert_eq!(
+            account
+                .mint_extension_types()
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap(),
+            vec![
+                TlvExtensionType::Unknown(unknown_type),
+                TlvExtensionType::Known(ExtensionType::MintCloseAuthority),
+            ]
+        );
+        assert_eq!(
+            account.mint_mint_close_authority().unwrap(),
+            close_authority
+        );
+        assert_eq!(account.mint_permanent_delegate().unwrap(), None);
+    }
+}
@@ -0,0 +1,302 @@
+//! Token-2022 extensions.
+//!
+//! Token-2022 accounts with extensions have their base state zero-padded to
+//! [`SPL_TOKEN_ACCOUNT_PACKED_LEN`], followed by a 1-byte account type
+//! and then the extensions as type-length-value (TLV) entries:
+//! `type: u16 | length: u16 | value: [u8; length]`
+
+use core::ops::Deref;
+
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{SPL_MULTISIG_ACCOUNT_PACKED_LEN, SPL_TOKEN_ACCOUNT_PACKED_LEN};
+
+use super::unpack_pubkey;
+
+mod mint;
+mod token_account;
+
+pub use mint::*;
+pub use token_account::*;
+
+pub const TOKEN_2022_ACCOUNT_TYPE_OFFSET: usize = SPL_TOKEN_ACCOUNT_PACKED_LEN;
+pub const TOKEN_2022_TLV_DATA_OFFSET: usize = TOKEN_2022_ACCOUNT_TYPE_OFFSET + 1;
+
+pub const TOKEN_2022_ACCOUNT_TYPE_MINT_DISCM: u8 = 1;
+pub const TOKEN_2022_ACCOUNT_TYPE_ACCOUNT_DISCM: u8 = 2;
+
+pub const TLV_TYPE_LEN: usize = 2;
+pub const TLV_LENGTH_LEN: usize = 2;
+
+/// Token-2022 extension types, with the same discriminants as `spl_token_2022::extension::ExtensionType`
+#[repr(u16)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ExtensionType {
+    Uninitialized,
+    TransferFeeConfig,
+    TransferFeeAmount,
+    MintCloseAuthority,
+    ConfidentialTransferMint,
+    ConfidentialTransferAccount,
+    DefaultAccountState,
+    ImmutableOwner,
+    MemoTransfer,
+    NonTransferable,
+    InterestBearingConfig,
+    CpiGuard,
+    PermanentDelegate,
+    NonTransferableAccount,
+    TransferHook,
+    TransferHookAccount,
+    ConfidentialTransferFeeConfig,
+    ConfidentialTransferFeeAmount,
+    MetadataPointer,
+    TokenMetadata,
+    GroupPointer,
+    TokenGroup,
+    GroupMemberPointer,
+    TokenGroupMember,
+}
+
+impl ExtensionType {
+    pub const VARIANTS: [Self; 24] = [
+        Self::Uninitialized,
+        Self::TransferFeeConfig,
+        Self::TransferFeeAmount,
+        Self::MintCloseAuthority,
+        Self::ConfidentialTransferMint,
+        Self::ConfidentialTransferAccount,
+        Self::DefaultAccountState,
+        Self::ImmutableOwner,
+        Self::MemoTransfer,
+        Self::NonTransferable,
+        Self::InterestBearingConfig,
+        Self::CpiGuard,
+        Self::PermanentDelegate,
+        Self::NonTransferableAccount,
+        Self::TransferHook,
+        Self::TransferHookAccount,
+        Self::ConfidentialTransferFeeConfig,
+        Self::ConfidentialTransferFeeAmount,
+        Self::MetadataPointer,
+        Self::TokenMetadata,
+        Self::GroupPointer,
+        Self::TokenGroup,
+        Self::GroupMemberPointer,
+        Self::TokenGroupMember,
+    ];
+}
+
+impl From<ExtensionType> for u16 {
+    fn from(value: ExtensionType) -> Self {
+        value as u16
+    }
+}
+
+impl TryFrom<u16> for ExtensionType {
+    type Error = ProgramError;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        Self::VARIANTS
+            .get(usize::from(value))
+            .copied()
+            .ok_or(ProgramError::InvalidAccountData)
+    }
+}
+
+/// The type of a TLV entry, which may be an extension type newer than [`ExtensionType`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TlvExtensionType {
+    Known(ExtensionType),
+    Unknown(u16),
+}
+
+impl From<u16> for TlvExtensionType {
+    fn from(value: u16) -> Self {
+        ExtensionType::try_from(value).map_or(Self::Unknown(value), Self::Known)
+    }
+}
+
+impl From<TlvExtensionType> for u16 {
+    fn from(value: TlvExtensionType) -> Self {
+        match value {
+            TlvExtensionType::Known(t) => t.into(),
+            TlvExtensionType::Unknown(t) => t,
+        }
+    }
+}
+
+/// Iterator over the extension types of a Token-2022 account, in TLV order.
+///
+/// Yields an `InvalidAccountData` error and stops if the TLV data is malformed.
+/// Extension types newer than [`ExtensionType`] are yielded as [`TlvExtensionType::Unknown`]
+#[derive(Clone, Copy, Debug)]
+pub struct ExtensionTypeIter<D> {
+    data: D,
+    offset: usize,
+    is_done: bool,
+}
+
+impl<D: Deref<Target = [u8]>> Iterator for ExtensionTypeIter<D> {
+    type Item = Result<TlvExtensionType, ProgramError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_done {
+            return None;
+        }
+        match next_tlv_entry(&self.data, self.offset) {
+            None => {
+                self.is_done = true;
+                None
+            }
+            Some(Err(e)) => {
+                self.is_done = true;
+                Some(Err(e))
+            }
+            Some(Ok(entry)) => {
+                self.offset = entry.value_end();
+                Some(Ok(entry.extension_type))
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct TlvEntry {
+    extension_type: TlvExtensionType,
+    value_offset: usize,
+    value_len: usize,
+}
+
+impl TlvEntry {
+    const fn value_end(&self) -> usize {
+        self.value_offset + self.value_len
+    }
+}
+
+/// Returns the offset of the TLV data in `data`,
+/// or `data.len()` if the account has no extensions.
+///
+/// Follows the checks of `spl_token_2022::extension::StateWithExtensions::unpack()`
+fn tlv_data_offset(data: &[u8], base_len: usize, account_type: u8) -> Result<usize, ProgramError> {
+    let len = data.len();
+    if len == base_len {
+        return Ok(len);
+    }
+    if len == SPL_MULTISIG_ACCOUNT_PACKED_LEN
+        || len <= TOKEN_2022_TLV_DATA_OFFSET
+        || data[base_len..TOKEN_2022_ACCOUNT_TYPE_OFFSET]
+            .iter()
+            .any(|b| *b != 0)
+        || data[TOKEN_2022_ACCOUNT_TYPE_OFFSET] != account_type
+    {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    Ok(TOKEN_2022_TLV_DATA_OFFSET)
+}
+
+/// Returns None if there are no more entries starting at `offset`
+fn next_tlv_entry(data: &[u8], offset: usize) -> Option<Result<TlvEntry, ProgramError>> {
+    let length_offset = offset + TLV_TYPE_LEN;
+    // not enough bytes left for a type, remaining bytes are unused
+    if data.len() < length_offset {
+        return None;
+    }
+    let extension_type = unpack_le_u16(data, offset);
+    // nothing is written after an uninitialized entry
+    if extension_type == u16::from(ExtensionType::Uninitialized) {
+        return None;
+    }
+    let value_offset = length_offset + TLV_LENGTH_LEN;
+    if data.len() < value_offset {
+        return Some(Err(ProgramError::InvalidAccountData));
+    }
+    let entry = TlvEntry {
+        extension_type: extension_type.into(),
+        value_offset,
+        value_len: usize::from(unpack_le_u16(data, length_offset)),
+    };
+    if entry.value_end() > data.len() {
+        return Some(Err(ProgramError::InvalidAccountData));
+    }
+    Some(Ok(entry))
+}
+
+/// Returns the value of the `extension_type` entry in the TLV data starting at `offset`,
+/// if it exists.
+///
+/// Entries of unknown extension types are skipped.
+///
+/// Errors if the TLV data before the entry is malformed
+/// or if the entry's value is not `value_len` bytes long
+fn find_extension_value(
+    data: &[u8],
+    mut offset: usize,
+    extension_type: ExtensionType,
+    value_len: usize,
+) -> Result<Option<&[u8]>, ProgramError> {
+    while let Some(entry) = next_tlv_entry(data, offset) {
+        let entry = entry?;
+        if entry.extension_type == TlvExtensionType::Known(extension_type) {
+            if entry.value_len != value_len {
+                return Err(ProgramError::InvalidAccountData);
+            }
+            return Ok(Some(&data[entry.value_offset..entry.value_end()]));
+        }
+        offset = entry.value_end();
+    }
+    Ok(None)
+}
+
+/// Token-2022 extensions use the zero pubkey for `None`
+fn unpack_optional_non_zero_pubkey(slice: &[u8], offset: usize) -> Option<Pubkey> {
+    let pk = unpack_pubkey(slice, offset);
+    if pk == Pubkey::default() {
+        None
+    } else {
+        Some(pk)
+    }
+}
+
+fn unpack_le_u16(slice: &[u8], offset: usize) -> u16 {
+    let b: &[u8; 2] = &slice[offset..offset + 2].try_into().unwrap();
+    u16::from_le_bytes(*b)
+}
+
+fn unpack_le_i16(slice: &[u8], offset: usize) -> i16 {
+    let b: &[u8; 2] = &slice[offset..offset + 2].try_into().unwrap();
+    i16::from_le_bytes(*b)
+}
+
+fn unpack_le_i64(slice: &[u8], offset: usize) -> i64 {
+    let b: &[u8; 8] = &slice[offset..offset + 8].try_into().unwrap();
+    i64::from_le_bytes(*b)
+}
+
+/// Token-2022 `PodBool`: any nonzero byte is true
+fn unpack_pod_bool(slice: &[u8], offset: usize) -> bool {
+    slice[offset] != 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extension_type_discms_match_token_2022() {
+        for t in ExtensionType::VARIANTS {
+            let expected =
+                spl_token_2022::extension::ExtensionType::try_from(u16::from(t)).unwrap();
+            assert_eq!(format!("{t:?}"), format!("{expected:?}"));
+            assert_eq!(ExtensionType::try_from(u16::from(expected)).unwrap(), t);
+        }
+        assert_eq!(
+            ExtensionType::try_from(ExtensionType::VARIANTS.len() as u16),
+            Err(ProgramError::InvalidAccountData)
+        );
+        assert_eq!(
+            TlvExtensionType::from(ExtensionType::VARIANTS.len() as u16),
+            TlvExtensionType::Unknown(ExtensionType::VARIANTS.len() as u16)
+        );
+    }
+}
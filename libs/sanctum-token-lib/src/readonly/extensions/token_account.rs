@@ -0,0 +1,212 @@
+use solana_program::program_error::ProgramError;
+use solana_readonly_account::ReadonlyAccountData;
+
+use crate::{readonly::unpack_le_u64, InitializedTokenAccount, SPL_TOKEN_ACCOUNT_PACKED_LEN};
+
+use super::{
+    find_extension_value, tlv_data_offset, unpack_pod_bool, ExtensionType, ExtensionTypeIter,
+    TOKEN_2022_ACCOUNT_TYPE_ACCOUNT_DISCM,
+};
+
+pub const TRANSFER_FEE_AMOUNT_LEN: usize = 8;
+
+pub const IMMUTABLE_OWNER_LEN: usize = 0;
+
+pub const CPI_GUARD_LEN: usize = 1;
+
+impl<T: ReadonlyAccountData> InitializedTokenAccount<T> {
+    /// Errors if the Token-2022 account type or the TLV data before the returned extension is malformed
+    fn token_account_extension_value<R>(
+        &self,
+        extension_type: ExtensionType,
+        value_len: usize,
+        f: impl FnOnce(&[u8]) -> R,
+    ) -> Result<Option<R>, ProgramError> {
+        let d = self.as_valid().as_readonly().as_inner().data();
+        let offset = tlv_data_offset(
+            &d,
+            SPL_TOKEN_ACCOUNT_PACKED_LEN,
+            TOKEN_2022_ACCOUNT_TYPE_ACCOUNT_DISCM,
+        )?;
+        Ok(find_extension_value(&d, offset, extension_type, value_len)?.map(f))
+    }
+
+    /// Errors if the Token-2022 account type is malformed.
+    /// Empty for token accounts without extensions, including all tokenkeg token accounts
+    pub fn token_account_extension_types(
+        &self,
+    ) -> Result<ExtensionTypeIter<T::DataDeref<'_>>, ProgramError> {
+        let data = self.as_valid().as_readonly().as_inner().data();
+        let offset = tlv_data_offset(
+            &data,
+            SPL_TOKEN_ACCOUNT_PACKED_LEN,
+            TOKEN_2022_ACCOUNT_TYPE_ACCOUNT_DISCM,
+        )?;
+        Ok(ExtensionTypeIter {
+            data,
+            offset,
+            is_done: false,
+        })
+    }
+
+    /// Amount withheld from transfers into this account as transfer fees.
+    /// Present for all token accounts of mints with the `TransferFeeConfig` extension
+    pub fn token_account_transfer_fee_amount(&self) -> Result<Option<u64>, ProgramError> {
+        self.token_account_extension_value(
+            ExtensionType::TransferFeeAmount,
+            TRANSFER_FEE_AMOUNT_LEN,
+            |v| unpack_le_u64(v, 0),
+        )
+    }
+
+    pub fn token_account_has_immutable_owner(&self) -> Result<bool, ProgramError> {
+        self.token_account_extension_value(
+            ExtensionType::ImmutableOwner,
+            IMMUTABLE_OWNER_LEN,
+            |_| (),
+        )
+        .map(|o| o.is_some())
+    }
+
+    /// Returns:
+    /// - `None` if the token account does not have the `CpiGuard` extension
+    /// - `Some(lock_cpi)` otherwise. Privileged operations on the account are not allowed in CPIs if `lock_cpi`
+    pub fn token_account_cpi_guard(&self) -> Result<Option<bool>, ProgramError> {
+        self.token_account_extension_value(ExtensionType::CpiGuard, CPI_GUARD_LEN, |v| {
+            unpack_pod_bool(v, 0)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{collection::vec, option, prelude::*};
+    use sanctum_solana_test_utils::token::proptest_utils::token_2022::token22_account_no_extensions;
+    use solana_program::program_pack::Pack;
+    use spl_token_2022::{
+        extension::{
+            cpi_guard, immutable_owner, transfer_fee, BaseStateWithExtensions,
+            BaseStateWithExtensionsMut, ExtensionType as SplExtensionType, StateWithExtensions,
+            StateWithExtensionsMut,
+        },
+        state::{Account, AccountState},
+    };
+
+    use crate::{readonly::test_utils::AccountData, ReadonlyTokenAccount};
+
+    use super::{
+        super::{TlvExtensionType, TOKEN_2022_ACCOUNT_TYPE_OFFSET},
+        *,
+    };
+
+    proptest! {
+        #[test]
+        fn token_account_extensions_match_full_deser(
+            account in token22_account_no_extensions(),
+            transfer_fee_amount in option::of(any::<u64>()),
+            immutable_owner: bool,
+            cpi_guard in option::of(any::<bool>()),
+        ) {
+            let account = Account {
+                state: match account.state {
+                    AccountState::Uninitialized => AccountState::Initialized,
+                    s => s,
+                },
+                ..account
+            };
+            let mut expected_types = vec![];
+            if transfer_fee_amount.is_some() {
+                expected_types.push(SplExtensionType::TransferFeeAmount);
+            }
+            if immutable_owner {
+                expected_types.push(SplExtensionType::ImmutableOwner);
+            }
+            if cpi_guard.is_some() {
+                expected_types.push(SplExtensionType::CpiGuard);
+            }
+            let len = SplExtensionType::try_calculate_account_len::<Account>(&expected_types).unwrap();
+            let mut data = vec![0u8; len];
+            let mut state = StateWithExtensionsMut::<Account>::unpack_uninitialized(&mut data).unwrap();
+            if let Some(withheld_amount) = transfer_fee_amount {
+                state
+                    .init_extension::<transfer_fee::TransferFeeAmount>(true)
+                    .unwrap()
+                    .withheld_amount = withheld_amount.into();
+            }
+            if immutable_owner {
+                state.init_extension::<immutable_owner::ImmutableOwner>(true).unwrap();
+            }
+            if let Some(lock_cpi) = cpi_guard {
+                state
+                    .init_extension::<cpi_guard::CpiGuard>(true)
+                    .unwrap()
+                    .lock_cpi = lock_cpi.into();
+            }
+            state.base = account;
+            state.pack_base();
+            state.init_account_type().unwrap();
+            prop_assert_eq!(
+                StateWithExtensions::<Account>::unpack(&data).unwrap().get_extension_types().unwrap(),
+                expected_types.clone()
+            );
+
+            let account = ReadonlyTokenAccount(AccountData(&data))
+                .try_into_valid()
+                .unwrap()
+                .try_into_initialized()
+                .unwrap();
+            let types: Vec<u16> = account
+                .token_account_extension_types()
+                .unwrap()
+                .map(|t| u16::from(t.unwrap()))
+                .collect();
+            let expected_types: Vec<u16> = expected_types.into_iter().map(u16::from).collect();
+            prop_assert_eq!(types, expected_types);
+            prop_assert_eq!(account.token_account_transfer_fee_amount().unwrap(), transfer_fee_amount);
+            prop_assert_eq!(account.token_account_has_immutable_owner().unwrap(), immutable_owner);
+            prop_assert_eq!(account.token_account_cpi_guard().unwrap(), cpi_guard);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn token_account_extension_types_matches_full_deser_invalid(
+            account in token22_account_no_extensions(),
+            account_type in 0..=3u8,
+            rest in vec(any::<u8>(), 0..=256),
+        ) {
+            let account = Account {
+                state: AccountState::Initialized,
+                ..account
+            };
+            let mut data = vec![0u8; SPL_TOKEN_ACCOUNT_PACKED_LEN];
+            account.pack_into_slice(&mut data);
+            if !rest.is_empty() {
+                data.resize(TOKEN_2022_ACCOUNT_TYPE_OFFSET, 0);
+                data.push(account_type);
+                data.extend_from_slice(&rest);
+            }
+            let expected = StateWithExtensions::<Account>::unpack(&data)
+                .and_then(|s| s.get_extension_types())
+                .map(|types| types.into_iter().map(u16::from).collect::<Vec<_>>());
+            let account = ReadonlyTokenAccount(AccountData(&data))
+                .try_into_valid()
+                .unwrap()
+                .try_into_initialized()
+                .unwrap();
+            let actual = account
+                .token_account_extension_types()
+                .and_then(|iter| iter.collect::<Result<Vec<_>, _>>());
+            match actual {
+                // spl_token_2022 fails on extension types it does not know, we skip them
+                Ok(actual) if actual.iter().any(|t| matches!(t, TlvExtensionType::Unknown(_))) => {
+                    prop_assert!(expected.is_err());
+                }
+                Ok(actual) => {
+                    prop_assert_eq!(Ok(actual.into_iter().map(u16::from).collect()), expected);
+                }
+                Err(_) => prop_assert!(expected.is_err()),
+            }
+        }
+    }
+}
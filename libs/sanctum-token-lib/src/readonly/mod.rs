@@ -1,7 +1,11 @@
+mod extensions;
 mod mint;
+mod multisig;
 mod token_account;
 
+pub use extensions::*;
 pub use mint::*;
+pub use multisig::*;
 use solana_program::pubkey::{Pubkey, PUBKEY_BYTES};
 pub use token_account::*;
 
@@ -44,13 +48,17 @@ fn is_coption_discm_valid(discm: &[u8; 4]) -> bool {
 }
 
 #[cfg(test)]
-mod test_utils {
+pub(crate) mod test_utils {
+    use proptest::{option, strategy::Strategy};
+    use sanctum_solana_test_utils::proptest_utils::pubkey;
+    use solana_program::pubkey::Pubkey;
     use solana_readonly_account::ReadonlyAccountData;
 
     pub struct AccountData<'a>(pub &'a [u8]);
 
     impl<'a> ReadonlyAccountData for AccountData<'a> {
-        type DataDeref<'d> = &'d [u8]
+        type DataDeref<'d>
+            = &'d [u8]
         where
             Self: 'd;
 
@@ -58,4 +66,9 @@ mod test_utils {
             self.0
         }
     }
+
+    /// Token-2022 extensions use the zero pubkey for `None`
+    pub fn optional_non_zero_pubkey() -> impl Strategy<Value = Option<Pubkey>> {
+        option::of(pubkey()).prop_map(|pk| pk.filter(|pk| *pk != Pubkey::default()))
+    }
 }
@@ -0,0 +1,328 @@
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use solana_readonly_account::ReadonlyAccountData;
+
+use crate::{
+    is_is_initialized_valid, SPL_MINT_IS_INITIALIZED_FALSE, SPL_MINT_IS_INITIALIZED_TRUE,
+    SPL_MULTISIG_ACCOUNT_PACKED_LEN,
+};
+
+use super::unpack_pubkey;
+
+pub const SPL_MULTISIG_M_OFFSET: usize = 0;
+pub const SPL_MULTISIG_N_OFFSET: usize = SPL_MULTISIG_M_OFFSET + 1;
+pub const SPL_MULTISIG_IS_INITIALIZED_OFFSET: usize = SPL_MULTISIG_N_OFFSET + 1;
+pub const SPL_MULTISIG_SIGNERS_OFFSET: usize = SPL_MULTISIG_IS_INITIALIZED_OFFSET + 1;
+
+pub const SPL_MULTISIG_MAX_SIGNERS: usize = 11;
+
+/// A possible multisig account
+///
+/// ## Example
+///
+/// ```rust
+/// use sanctum_token_lib::ReadonlyMultisigAccount;
+/// use solana_program::{
+///     account_info::AccountInfo,
+///     entrypoint::ProgramResult
+/// };
+///
+/// pub fn process(account: &AccountInfo) -> ProgramResult {
+///     let account = ReadonlyMultisigAccount(account);
+///     let account = account.try_into_valid()?;
+///     let account = account.try_into_initialized()?;
+///     solana_program::msg!("{} of {}", account.multisig_m(), account.multisig_n());
+///     Ok(())
+/// }
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ReadonlyMultisigAccount<T>(pub T);
+
+impl<T> ReadonlyMultisigAccount<T> {
+    pub fn as_inner(&self) -> &T {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: ReadonlyAccountData> ReadonlyMultisigAccount<T> {
+    pub fn multisig_data_is_valid(&self) -> bool {
+        let d = self.0.data();
+        d.len() >= SPL_MULTISIG_ACCOUNT_PACKED_LEN
+            && is_is_initialized_valid(d[SPL_MULTISIG_IS_INITIALIZED_OFFSET])
+    }
+
+    pub fn try_into_valid(self) -> Result<ValidMultisigAccount<T>, ProgramError> {
+        match self.multisig_data_is_valid() {
+            true => Ok(ValidMultisigAccount(self)),
+            false => Err(ProgramError::InvalidAccountData),
+        }
+    }
+}
+
+impl<T> AsRef<T> for ReadonlyMultisigAccount<T> {
+    fn as_ref(&self) -> &T {
+        self.as_inner()
+    }
+}
+
+// can't impl From<ReadonlyMultisigAccount<T>> for T due to orphan rules
+
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ValidMultisigAccount<T>(ReadonlyMultisigAccount<T>);
+
+impl<T> ValidMultisigAccount<T> {
+    pub fn as_readonly(&self) -> &ReadonlyMultisigAccount<T> {
+        &self.0
+    }
+
+    pub fn into_readonly(self) -> ReadonlyMultisigAccount<T> {
+        self.0
+    }
+}
+
+impl<T: ReadonlyAccountData> ValidMultisigAccount<T> {
+    pub fn multisig_is_initialized(&self) -> bool {
+        let d = self.0.as_inner().data();
+        let b = d[SPL_MULTISIG_IS_INITIALIZED_OFFSET];
+        match b {
+            SPL_MINT_IS_INITIALIZED_FALSE => false,
+            SPL_MINT_IS_INITIALIZED_TRUE => true,
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn try_into_initialized(self) -> Result<InitializedMultisigAccount<T>, ProgramError> {
+        match self.multisig_is_initialized() {
+            true => Ok(InitializedMultisigAccount(self)),
+            false => Err(ProgramError::InvalidAccountData),
+        }
+    }
+}
+
+impl<T> AsRef<ReadonlyMultisigAccount<T>> for ValidMultisigAccount<T> {
+    fn as_ref(&self) -> &ReadonlyMultisigAccount<T> {
+        self.as_readonly()
+    }
+}
+
+impl<T> From<ValidMultisigAccount<T>> for ReadonlyMultisigAccount<T> {
+    fn from(value: ValidMultisigAccount<T>) -> Self {
+        value.into_readonly()
+    }
+}
+
+impl<T: ReadonlyAccountData> TryFrom<ReadonlyMultisigAccount<T>> for ValidMultisigAccount<T> {
+    type Error = ProgramError;
+
+    fn try_from(value: ReadonlyMultisigAccount<T>) -> Result<Self, Self::Error> {
+        value.try_into_valid()
+    }
+}
+
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct InitializedMultisigAccount<T>(ValidMultisigAccount<T>);
+
+impl<T> InitializedMultisigAccount<T> {
+    pub fn as_valid(&self) -> &ValidMultisigAccount<T> {
+        &self.0
+    }
+
+    pub fn into_valid(self) -> ValidMultisigAccount<T> {
+        self.0
+    }
+}
+
+impl<T: ReadonlyAccountData> InitializedMultisigAccount<T> {
+    /// Number of signers required
+    pub fn multisig_m(&self) -> u8 {
+        let d = self.0.as_readonly().as_inner().data();
+        d[SPL_MULTISIG_M_OFFSET]
+    }
+
+    /// Number of valid signers
+    pub fn multisig_n(&self) -> u8 {
+        let d = self.0.as_readonly().as_inner().data();
+        d[SPL_MULTISIG_N_OFFSET]
+    }
+
+    /// Returns `None` if `index >= n`
+    pub fn multisig_signer(&self, index: usize) -> Option<Pubkey> {
+        let d = self.0.as_readonly().as_inner().data();
+        (index < valid_signers_len(&d))
+            .then(|| unpack_pubkey(&d, SPL_MULTISIG_SIGNERS_OFFSET + index * 32))
+    }
+
+    /// The first `n` entries of the signer array
+    pub fn multisig_signers(&self) -> impl Iterator<Item = Pubkey> + '_ {
+        let d = self.0.as_readonly().as_inner().data();
+        (0..valid_signers_len(&d))
+            .map(move |i| unpack_pubkey(&d, SPL_MULTISIG_SIGNERS_OFFSET + i * 32))
+    }
+
+    pub fn multisig_is_signer(&self, pubkey: &Pubkey) -> bool {
+        self.multisig_signers().any(|s| s == *pubkey)
+    }
+
+    /// Selects, in order, the signers in `candidates` to pass as `signatories`
+    /// to the `*_multisig_*` instruction functions, stopping as soon as `m` is reached.
+    ///
+    /// Like the token program, a candidate counts once for every position in the
+    /// signer array it occupies, and duplicate candidates are ignored.
+    pub fn multisig_signatories(
+        &self,
+        candidates: impl IntoIterator<Item = Pubkey>,
+    ) -> MultisigSignatories {
+        let m = usize::from(self.multisig_m());
+        let mut signatories = Vec::new();
+        let mut num_signers = 0;
+        for candidate in candidates {
+            if num_signers >= m {
+                break;
+            }
+            if signatories.contains(&candidate) {
+                continue;
+            }
+            let positions = self.multisig_signers().filter(|s| *s == candidate).count();
+            if positions > 0 {
+                signatories.push(candidate);
+                num_signers += positions;
+            }
+        }
+        MultisigSignatories {
+            is_threshold_met: num_signers >= m,
+            signatories,
+        }
+    }
+}
+
+impl<T> AsRef<ValidMultisigAccount<T>> for InitializedMultisigAccount<T> {
+    fn as_ref(&self) -> &ValidMultisigAccount<T> {
+        self.as_valid()
+    }
+}
+
+impl<T> From<InitializedMultisigAccount<T>> for ValidMultisigAccount<T> {
+    fn from(value: InitializedMultisigAccount<T>) -> Self {
+        value.into_valid()
+    }
+}
+
+impl<T: ReadonlyAccountData> TryFrom<ValidMultisigAccount<T>> for InitializedMultisigAccount<T> {
+    type Error = ProgramError;
+
+    fn try_from(value: ValidMultisigAccount<T>) -> Result<Self, Self::Error> {
+        value.try_into_initialized()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct MultisigSignatories {
+    /// Whether `signatories` satisfy the multisig's `m`
+    pub is_threshold_met: bool,
+
+    /// Subset of the candidates that are signers of the multisig, in candidate order.
+    /// If `!is_threshold_met`, this is every candidate that is a signer.
+    pub signatories: Vec<Pubkey>,
+}
+
+/// `n` clamped to [`SPL_MULTISIG_MAX_SIGNERS`] so that corrupt data does not cause out-of-bounds reads
+fn valid_signers_len(d: &[u8]) -> usize {
+    usize::from(d[SPL_MULTISIG_N_OFFSET]).min(SPL_MULTISIG_MAX_SIGNERS)
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{collection::vec, prelude::*};
+    use sanctum_solana_test_utils::proptest_utils::pubkey;
+    use solana_program::program_pack::Pack;
+    use spl_token_2022::state::Multisig;
+
+    use crate::readonly::test_utils::AccountData;
+
+    use super::*;
+
+    fn multisig() -> impl Strategy<Value = Multisig> {
+        (
+            any::<u8>(),
+            0..=SPL_MULTISIG_MAX_SIGNERS as u8,
+            any::<bool>(),
+            vec(pubkey(), SPL_MULTISIG_MAX_SIGNERS),
+        )
+            .prop_map(|(m, n, is_initialized, signers)| Multisig {
+                m,
+                n,
+                is_initialized,
+                signers: signers.try_into().unwrap(),
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn multisig_readonly_matches_full_deser_invalid(bytes in vec(any::<u8>(), SPL_MULTISIG_ACCOUNT_PACKED_LEN)) {
+            let account = ReadonlyMultisigAccount(AccountData(&bytes));
+            let unpack_res = Multisig::unpack_unchecked(&bytes);
+            if !account.multisig_data_is_valid() {
+                prop_assert!(unpack_res.is_err());
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn multisig_readonly_matches_full_deser_valid(expected in multisig()) {
+            let mut data = vec![0u8; SPL_MULTISIG_ACCOUNT_PACKED_LEN];
+            Multisig::pack_into_slice(&expected, &mut data);
+            let account = ReadonlyMultisigAccount(AccountData(&data)).try_into_valid().unwrap();
+            prop_assert_eq!(account.multisig_is_initialized(), expected.is_initialized);
+            if let Ok(account) = account.try_into_initialized() {
+                let n = usize::from(expected.n);
+                prop_assert_eq!(account.multisig_m(), expected.m);
+                prop_assert_eq!(account.multisig_n(), expected.n);
+                prop_assert_eq!(account.multisig_signers().collect::<Vec<_>>(), expected.signers[..n].to_vec());
+                prop_assert_eq!(account.multisig_signer(n), None);
+            }
+        }
+    }
+
+    #[test]
+    fn multisig_signatories_threshold() {
+        let signers: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        let mut expected = Multisig {
+            m: 2,
+            n: 3,
+            is_initialized: true,
+            ..Default::default()
+        };
+        expected.signers[..3].copy_from_slice(&signers);
+        let mut data = vec![0u8; SPL_MULTISIG_ACCOUNT_PACKED_LEN];
+        Multisig::pack_into_slice(&expected, &mut data);
+        let account = ReadonlyMultisigAccount(AccountData(&data))
+            .try_into_valid()
+            .unwrap()
+            .try_into_initialized()
+            .unwrap();
+
+        let stranger = Pubkey::new_unique();
+        assert_eq!(
+            account
+                .multisig_signatories([stranger, signers[2], signers[2], signers[0], signers[1]]),
+            MultisigSignatories {
+                is_threshold_met: true,
+                signatories: vec![signers[2], signers[0]],
+            }
+        );
+        assert_eq!(
+            account.multisig_signatories([stranger, signers[1]]),
+            MultisigSignatories {
+                is_threshold_met: false,
+                signatories: vec![signers[1]],
+            }
+        );
+    }
+}
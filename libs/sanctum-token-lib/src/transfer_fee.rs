@@ -0,0 +1,308 @@
+use sanctum_token_ratio::{
+    AmtsAfterFee, AmtsAfterFeeBuilder, CeilDiv, MathError, ReversibleFee, U64BpsFee, U64ValueRange,
+    BPS_DENOMINATOR,
+};
+use solana_program::clock::Epoch;
+
+use crate::{TransferFee, TransferFeeConfig};
+
+impl TransferFeeConfig {
+    /// The transfer fee in effect at `epoch`
+    pub const fn epoch_fee(&self, epoch: Epoch) -> &TransferFee {
+        if epoch >= self.newer_transfer_fee.epoch {
+            &self.newer_transfer_fee
+        } else {
+            &self.older_transfer_fee
+        }
+    }
+}
+
+impl TransferFee {
+    /// The fee before it is capped at `maximum_fee`.
+    ///
+    /// Errors if `transfer_fee_basis_points > 10_000`
+    #[inline]
+    pub const fn bps_fee(&self) -> Result<CeilDiv<U64BpsFee>, MathError> {
+        match U64BpsFee::try_new(self.transfer_fee_basis_points) {
+            Ok(fee) => Ok(CeilDiv(fee)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Largest amount whose fee before the cap is <= `maximum_fee`.
+    /// Must only be called with nonzero `transfer_fee_basis_points`
+    fn max_uncapped_amt(&self) -> u128 {
+        u128::from(self.maximum_fee) * u128::from(BPS_DENOMINATOR)
+            / u128::from(self.transfer_fee_basis_points)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.transfer_fee_basis_points == 0 || self.maximum_fee == 0
+    }
+}
+
+/// Token-2022's transfer fee:
+/// `fee_charged = min(ceil(amt * transfer_fee_basis_points / 10_000), maximum_fee)`.
+///
+/// `fee_charged` is withheld in the destination token account,
+/// so `amt_after_fee` is the amount received for a transfer of `amt_before_fee`.
+///
+/// Unlike the other [`ReversibleFee`]s, the reversed ranges are exact:
+/// every amount in them, and no amount outside them, results in the given value.
+impl ReversibleFee for TransferFee {
+    fn apply(&self, amt_before_fee: u64) -> Result<AmtsAfterFee, MathError> {
+        let uncapped = self.bps_fee()?.apply(amt_before_fee)?.fee_charged();
+        AmtsAfterFeeBuilder::new_amt_bef_fee(amt_before_fee)
+            .with_fee_charged(uncapped.min(self.maximum_fee))
+    }
+
+    /// Returns the range of amounts to transfer for `amt_after_fee` to be received.
+    /// `get_min()` is the amount to send.
+    ///
+    /// # Returns:
+    /// - [`U64ValueRange::single(amt_after_fee)`] if zero fee
+    ///
+    /// # Errors:
+    /// - if `transfer_fee_basis_points > 10_000`
+    /// - if the amount to transfer overflows u64
+    ///
+    /// # Derivation
+    ///
+    /// ```md
+    /// let y = amt_after_fee, x = amt_before_fee, b = transfer_fee_basis_points, d = 10_000, m = maximum_fee
+    ///
+    /// uncapped, x <= floor(md/b):
+    /// y = x - ceil(bx/d) = floor(x(d - b)/d)
+    /// y <= x(d - b)/d < y + 1
+    /// ceil(yd/(d - b)) <= x <= ceil((y + 1)d/(d - b)) - 1
+    ///
+    /// or if b == d, y = 0 for all x
+    ///
+    /// capped, ceil(bx/d) >= m:
+    /// x = y + m
+    ///
+    /// y is nondecreasing in x, so the union of both is a single range
+    /// ```
+    fn reverse_from_amt_after_fee(&self, amt_after_fee: u64) -> Result<U64ValueRange, MathError> {
+        let bps_fee = self.bps_fee()?;
+        if self.is_zero() {
+            return Ok(U64ValueRange::single(amt_after_fee));
+        }
+        let y = u128::from(amt_after_fee);
+        let d = u128::from(BPS_DENOMINATOR);
+        let d_minus_b = d - u128::from(self.transfer_fee_basis_points);
+        let uncapped = if d_minus_b == 0 {
+            (y == 0).then_some((0, u128::MAX))
+        } else {
+            Some((
+                (y * d).div_ceil(d_minus_b),
+                ((y + 1) * d).div_ceil(d_minus_b) - 1,
+            ))
+        };
+        let uncapped = uncapped
+            .map(|(min, max)| (min, max.min(self.max_uncapped_amt())))
+            .filter(|(min, max)| min <= max);
+        let capped = amt_after_fee.checked_add(self.maximum_fee).filter(|amt| {
+            bps_fee
+                .apply(*amt)
+                .is_ok_and(|a| a.fee_charged() >= self.maximum_fee)
+        });
+        let (min, max) = match (uncapped, capped.map(u128::from)) {
+            (Some((min, max)), Some(c)) => (min.min(c), max.max(c)),
+            (Some(r), None) => r,
+            (None, Some(c)) => (c, c),
+            (None, None) => return Err(MathError),
+        };
+        let min = u64::try_from(min).map_err(|_e| MathError)?;
+        let max = u64::try_from(max).unwrap_or(u64::MAX);
+        U64ValueRange::try_from_min_max(min, max)
+    }
+
+    /// # Returns:
+    /// - [`U64ValueRange::FULL`] if zero fee and fee_charged == 0
+    /// - `[min, u64::MAX]` if `fee_charged == maximum_fee`
+    ///
+    /// # Errors:
+    /// - if `transfer_fee_basis_points > 10_000`
+    /// - if `fee_charged > maximum_fee`
+    /// - if zero fee but fee_charged != 0
+    ///
+    /// # Derivation
+    ///
+    /// ```md
+    /// let y = fee_charged, x = amt_before_fee, b = transfer_fee_basis_points, d = 10_000, m = maximum_fee
+    ///
+    /// y = ceil(bx/d)
+    /// y - 1 < bx/d <= y
+    /// floor((y - 1)d/b) + 1 <= x <= floor(yd/b)
+    ///
+    /// or x = 0 if y = 0
+    ///
+    /// if y == m, all larger x are capped to m
+    /// ```
+    fn reverse_from_fee_charged(&self, fee_charged: u64) -> Result<U64ValueRange, MathError> {
+        // validate transfer_fee_basis_points
+        self.bps_fee()?;
+        if self.is_zero() {
+            return match fee_charged {
+                0 => Ok(U64ValueRange::FULL),
+                _ => Err(MathError),
+            };
+        }
+        if fee_charged > self.maximum_fee {
+            return Err(MathError);
+        }
+        let y = u128::from(fee_charged);
+        let b = u128::from(self.transfer_fee_basis_points);
+        let d = u128::from(BPS_DENOMINATOR);
+        let (min, max) = match y {
+            0 => (0, 0),
+            _ => ((y - 1) * d / b + 1, y * d / b),
+        };
+        let max = if fee_charged == self.maximum_fee {
+            u128::MAX
+        } else {
+            max
+        };
+        let min = u64::try_from(min).map_err(|_e| MathError)?;
+        let max = u64::try_from(max).unwrap_or(u64::MAX);
+        U64ValueRange::try_from_min_max(min, max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    prop_compose! {
+        fn valid_transfer_fee()
+            (
+                epoch: u64,
+                // small maximum fees so that amounts are capped more often
+                maximum_fee in prop_oneof![0..=u64::MAX, 0..=1_000_000u64],
+                transfer_fee_basis_points in 0..=BPS_DENOMINATOR,
+            ) -> TransferFee {
+                TransferFee { epoch, maximum_fee, transfer_fee_basis_points }
+            }
+    }
+
+    /// Small amounts so that fees are below the cap more often
+    fn amt() -> impl Strategy<Value = u64> {
+        prop_oneof![0..=u64::MAX, 0..=1_000_000_000u64]
+    }
+
+    fn conv_transfer_fee(
+        fee: &TransferFee,
+    ) -> spl_token_2022::extension::transfer_fee::TransferFee {
+        spl_token_2022::extension::transfer_fee::TransferFee {
+            epoch: fee.epoch.into(),
+            maximum_fee: fee.maximum_fee.into(),
+            transfer_fee_basis_points: fee.transfer_fee_basis_points.into(),
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn apply_matches_token_2022(amt in amt(), fee in valid_transfer_fee()) {
+            let a = fee.apply(amt).unwrap();
+            prop_assert_eq!(
+                Some(a.fee_charged()),
+                conv_transfer_fee(&fee).calculate_fee(amt)
+            );
+            prop_assert_eq!(amt, a.amt_after_fee() + a.fee_charged());
+            prop_assert!(a.fee_charged() <= fee.maximum_fee);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn amt_after_fee_round_trip(amt in amt(), fee in valid_transfer_fee()) {
+            let amt_after_fee = fee.apply(amt).unwrap().amt_after_fee();
+            let r = fee.reverse_from_amt_after_fee(amt_after_fee).unwrap();
+            prop_assert_eq!(fee.apply(r.get_min()).unwrap().amt_after_fee(), amt_after_fee);
+            prop_assert_eq!(fee.apply(r.get_max()).unwrap().amt_after_fee(), amt_after_fee);
+            prop_assert!(r.get_min() <= amt && amt <= r.get_max());
+            if r.get_min() > 0 {
+                prop_assert!(fee.apply(r.get_min() - 1).unwrap().amt_after_fee() < amt_after_fee);
+            }
+            if r.get_max() < u64::MAX {
+                prop_assert!(fee.apply(r.get_max() + 1).unwrap().amt_after_fee() > amt_after_fee);
+            }
+            // token-2022 returns 0 for 100% fees regardless of maximum_fee
+            if let Some(pre_fee_amt) = conv_transfer_fee(&fee).calculate_pre_fee_amount(amt_after_fee) {
+                if fee.apply(pre_fee_amt).unwrap().amt_after_fee() == amt_after_fee {
+                    prop_assert_eq!(r.get_min(), pre_fee_amt);
+                }
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn fee_charged_round_trip(amt in amt(), fee in valid_transfer_fee()) {
+            let fee_charged = fee.apply(amt).unwrap().fee_charged();
+            let r = fee.reverse_from_fee_charged(fee_charged).unwrap();
+            prop_assert!(r.get_min() <= amt && amt <= r.get_max());
+            prop_assert_eq!(fee.apply(r.get_min()).unwrap().fee_charged(), fee_charged);
+            prop_assert_eq!(fee.apply(r.get_max()).unwrap().fee_charged(), fee_charged);
+            if r.get_min() > 0 {
+                prop_assert!(fee.apply(r.get_min() - 1).unwrap().fee_charged() < fee_charged);
+            }
+            if r.get_max() < u64::MAX {
+                prop_assert!(fee.apply(r.get_max() + 1).unwrap().fee_charged() > fee_charged);
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn capped_fee_charged_reverse_unbounded(fee in valid_transfer_fee()) {
+            if let Ok(r) = fee.reverse_from_fee_charged(fee.maximum_fee) {
+                prop_assert_eq!(r.get_max(), u64::MAX);
+            }
+            if fee.maximum_fee < u64::MAX {
+                prop_assert_eq!(fee.reverse_from_fee_charged(fee.maximum_fee + 1), Err(MathError));
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn invalid_bps_err(
+            amt: u64,
+            fee in valid_transfer_fee(),
+            transfer_fee_basis_points in BPS_DENOMINATOR + 1..=u16::MAX,
+        ) {
+            let fee = TransferFee { transfer_fee_basis_points, ..fee };
+            prop_assert_eq!(fee.apply(amt), Err(MathError));
+            prop_assert_eq!(fee.reverse_from_amt_after_fee(amt), Err(MathError));
+            prop_assert_eq!(fee.reverse_from_fee_charged(amt), Err(MathError));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn epoch_fee_matches_token_2022(
+            epoch: u64,
+            older_transfer_fee in valid_transfer_fee(),
+            newer_transfer_fee in valid_transfer_fee(),
+        ) {
+            let config = TransferFeeConfig {
+                older_transfer_fee,
+                newer_transfer_fee,
+                ..Default::default()
+            };
+            let spl_config = spl_token_2022::extension::transfer_fee::TransferFeeConfig {
+                older_transfer_fee: conv_transfer_fee(&older_transfer_fee),
+                newer_transfer_fee: conv_transfer_fee(&newer_transfer_fee),
+                ..Default::default()
+            };
+            prop_assert_eq!(
+                conv_transfer_fee(config.epoch_fee(epoch)),
+                *spl_config.get_epoch_fee(epoch)
+            );
+        }
+    }
+}
@@ -0,0 +1,322 @@
+//! Conversions between raw token amounts and their UI representations,
+//! replicating the token programs' `AmountToUiAmount` and `UiAmountToAmount` instructions
+
+use solana_program::program_error::ProgramError;
+use solana_readonly_account::ReadonlyAccountData;
+
+use crate::{InitializedMintAccount, InterestBearingConfig};
+
+const ONE_IN_BASIS_POINTS: f64 = 10_000.;
+
+/// 365.24 days, as used by token-2022's interest-bearing extension
+const SECONDS_PER_YEAR: f64 = 60. * 60. * 24. * 365.24;
+
+/// Converts a raw amount to its UI representation with excess zeroes
+/// and unneeded decimal point trimmed, e.g. `1_500_000, 6 -> "1.5"`
+pub fn amount_to_ui_amount(amount: u64, decimals: u8) -> String {
+    let decimals = usize::from(decimals);
+    if decimals == 0 {
+        return amount.to_string();
+    }
+    // Left-pad zeros to decimals + 1, so we at least have an integer zero
+    let mut s = format!("{:01$}", amount, decimals + 1);
+    s.insert(s.len() - decimals, '.');
+    let trimmed_len = s.trim_end_matches('0').trim_end_matches('.').len();
+    s.truncate(trimmed_len);
+    s
+}
+
+/// Converts a UI representation of an amount to its raw amount.
+///
+/// Errors with [`ProgramError::InvalidArgument`] if `ui_amount` is malformed,
+/// has more than `decimals` significant decimal places or overflows u64.
+pub fn ui_amount_to_amount(ui_amount: &str, decimals: u8) -> Result<u64, ProgramError> {
+    let decimals = usize::from(decimals);
+    let mut parts = ui_amount.split('.');
+    // splitting a string, even an empty one, always yields at least 1 part
+    let integer = parts.next().unwrap();
+    let fraction = parts.next().unwrap_or("").trim_end_matches('0');
+    if (integer.is_empty() && fraction.is_empty())
+        || parts.next().is_some()
+        || fraction.len() > decimals
+    {
+        return Err(ProgramError::InvalidArgument);
+    }
+    let mut amount_str = String::with_capacity(integer.len() + decimals);
+    amount_str.push_str(integer);
+    amount_str.push_str(fraction);
+    (fraction.len()..decimals).for_each(|_| amount_str.push('0'));
+    amount_str
+        .parse()
+        .map_err(|_e| ProgramError::InvalidArgument)
+}
+
+impl InterestBearingConfig {
+    /// Continuously compounded interest accrued from initialization to `unix_timestamp`,
+    /// divided by `10^decimals`.
+    ///
+    /// Returns `None` on timestamp overflow
+    pub fn total_scale(&self, decimals: u8, unix_timestamp: i64) -> Option<f64> {
+        let pre_update_timespan = self
+            .last_update_timestamp
+            .checked_sub(self.initialization_timestamp)?;
+        let post_update_timespan = unix_timestamp.checked_sub(self.last_update_timestamp)?;
+        Some(
+            rate_exp(self.pre_update_average_rate, pre_update_timespan)?
+                * rate_exp(self.current_rate, post_update_timespan)?
+                / 10_f64.powi(i32::from(decimals)),
+        )
+    }
+
+    /// UI representation of `amount` with interest accrued up to `unix_timestamp`.
+    ///
+    /// Returns `None` on timestamp overflow
+    pub fn amount_to_ui_amount(
+        &self,
+        amount: u64,
+        decimals: u8,
+        unix_timestamp: i64,
+    ) -> Option<String> {
+        let scaled = (amount as f64) * self.total_scale(decimals, unix_timestamp)?;
+        Some(scaled.to_string())
+    }
+
+    /// Raw amount that `ui_amount` with interest accrued up to `unix_timestamp` corresponds to,
+    /// rounded to the nearest integer.
+    ///
+    /// Errors with [`ProgramError::InvalidArgument`] if `ui_amount` is not a valid f64,
+    /// on timestamp overflow or if the result is out of u64 range
+    pub fn ui_amount_to_amount(
+        &self,
+        ui_amount: &str,
+        decimals: u8,
+        unix_timestamp: i64,
+    ) -> Result<u64, ProgramError> {
+        let scaled: f64 = ui_amount
+            .parse()
+            .map_err(|_e| ProgramError::InvalidArgument)?;
+        let amount = scaled
+            / self
+                .total_scale(decimals, unix_timestamp)
+                .ok_or(ProgramError::InvalidArgument)?;
+        if amount > (u64::MAX as f64) || amount < (u64::MIN as f64) || amount.is_nan() {
+            return Err(ProgramError::InvalidArgument);
+        }
+        // round only at the end, else results may be wrongly "inf"
+        Ok(amount.round() as u64)
+    }
+}
+
+/// `exp(rate * timespan)`, `rate` in basis points per year
+fn rate_exp(rate: i16, timespan: i64) -> Option<f64> {
+    let numerator = i128::from(rate).checked_mul(i128::from(timespan))? as f64;
+    Some((numerator / SECONDS_PER_YEAR / ONE_IN_BASIS_POINTS).exp())
+}
+
+impl<T: ReadonlyAccountData> InitializedMintAccount<T> {
+    /// Same result as the `AmountToUiAmount` instruction executed at `unix_timestamp`.
+    ///
+    /// `unix_timestamp` is only used if the mint has the `InterestBearingConfig` extension.
+    pub fn mint_amount_to_ui_amount(
+        &self,
+        amount: u64,
+        unix_timestamp: i64,
+    ) -> Result<String, ProgramError> {
+        let decimals = self.mint_decimals();
+        match self.mint_interest_bearing_config()? {
+            Some(config) => config
+                .amount_to_ui_amount(amount, decimals, unix_timestamp)
+                .ok_or(ProgramError::InvalidArgument),
+            None => Ok(amount_to_ui_amount(amount, decimals)),
+        }
+    }
+
+    /// Same result as the `UiAmountToAmount` instruction executed at `unix_timestamp`.
+    ///
+    /// `unix_timestamp` is only used if the mint has the `InterestBearingConfig` extension.
+    pub fn mint_ui_amount_to_amount(
+        &self,
+        ui_amount: &str,
+        unix_timestamp: i64,
+    ) -> Result<u64, ProgramError> {
+        let decimals = self.mint_decimals();
+        match self.mint_interest_bearing_config()? {
+            Some(config) => config.ui_amount_to_amount(ui_amount, decimals, unix_timestamp),
+            None => ui_amount_to_amount(ui_amount, decimals),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use sanctum_solana_test_utils::token::proptest_utils::token_2022::token22_mint_no_extensions;
+    use solana_program::program_pack::Pack;
+    use spl_token_2022::{
+        extension::{
+            interest_bearing_mint, BaseStateWithExtensionsMut, ExtensionType,
+            StateWithExtensionsMut,
+        },
+        state::Mint,
+    };
+
+    use crate::{readonly::test_utils::AccountData, ReadonlyMintAccount};
+
+    use super::*;
+
+    fn interest_bearing_config() -> impl Strategy<Value = InterestBearingConfig> {
+        (any::<i64>(), any::<i16>(), any::<i64>(), any::<i16>()).prop_map(
+            |(
+                initialization_timestamp,
+                pre_update_average_rate,
+                last_update_timestamp,
+                current_rate,
+            )| InterestBearingConfig {
+                rate_authority: None,
+                initialization_timestamp,
+                pre_update_average_rate,
+                last_update_timestamp,
+                current_rate,
+            },
+        )
+    }
+
+    fn conv_config(c: InterestBearingConfig) -> interest_bearing_mint::InterestBearingConfig {
+        interest_bearing_mint::InterestBearingConfig {
+            rate_authority: Default::default(),
+            initialization_timestamp: c.initialization_timestamp.into(),
+            pre_update_average_rate: c.pre_update_average_rate.into(),
+            last_update_timestamp: c.last_update_timestamp.into(),
+            current_rate: c.current_rate.into(),
+        }
+    }
+
+    fn pack_mint(mint: Mint, config: Option<InterestBearingConfig>) -> Vec<u8> {
+        let Some(c) = config else {
+            let mut data = vec![0u8; Mint::LEN];
+            mint.pack_into_slice(&mut data);
+            return data;
+        };
+        let len = ExtensionType::try_calculate_account_len::<Mint>(&[
+            ExtensionType::InterestBearingConfig,
+        ])
+        .unwrap();
+        let mut data = vec![0u8; len];
+        let mut state = StateWithExtensionsMut::<Mint>::unpack_uninitialized(&mut data).unwrap();
+        *state
+            .init_extension::<interest_bearing_mint::InterestBearingConfig>(true)
+            .unwrap() = conv_config(c);
+        state.base = mint;
+        state.pack_base();
+        state.init_account_type().unwrap();
+        data
+    }
+
+    proptest! {
+        #[test]
+        fn amount_to_ui_amount_matches_spl(amount: u64, decimals: u8) {
+            prop_assert_eq!(
+                amount_to_ui_amount(amount, decimals),
+                spl_token_2022::amount_to_ui_amount_string_trimmed(amount, decimals)
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn ui_amount_to_amount_matches_spl(ui_amount in "[+\\-0-9.]{0,24}", decimals in 0..=12u8) {
+            prop_assert_eq!(
+                ui_amount_to_amount(&ui_amount, decimals),
+                spl_token_2022::try_ui_amount_into_amount(ui_amount, decimals)
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn ui_amount_round_trip(amount: u64, decimals: u8) {
+            let ui_amount = amount_to_ui_amount(amount, decimals);
+            prop_assert_eq!(ui_amount_to_amount(&ui_amount, decimals), Ok(amount));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn interest_bearing_matches_spl(
+            config in interest_bearing_config(),
+            amount: u64,
+            decimals: u8,
+            unix_timestamp: i64,
+            ui_amount in "[+\\-0-9.e]{0,24}",
+        ) {
+            let spl_config = conv_config(config);
+            let actual = config.amount_to_ui_amount(amount, decimals, unix_timestamp);
+            let expected = spl_config.amount_to_ui_amount(amount, decimals, unix_timestamp);
+            prop_assert_eq!(&actual, &expected);
+            for ui_amount in actual.iter().chain(std::iter::once(&ui_amount)) {
+                prop_assert_eq!(
+                    config.ui_amount_to_amount(ui_amount, decimals, unix_timestamp),
+                    spl_config.try_ui_amount_into_amount(ui_amount, decimals, unix_timestamp)
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn interest_bearing_recent_matches_spl(
+            config in interest_bearing_config().prop_map(|c| InterestBearingConfig {
+                initialization_timestamp: c.initialization_timestamp % 1_000_000_000,
+                last_update_timestamp: c.initialization_timestamp % 1_000_000_000 + 1_000_000,
+                ..c
+            }),
+            amount: u64,
+            decimals in 0..=9u8,
+            elapsed in 0..100_000_000i64,
+        ) {
+            let unix_timestamp = config.last_update_timestamp + elapsed;
+            let spl_config = conv_config(config);
+            let actual = config.amount_to_ui_amount(amount, decimals, unix_timestamp).unwrap();
+            prop_assert_eq!(
+                &actual,
+                &spl_config.amount_to_ui_amount(amount, decimals, unix_timestamp).unwrap()
+            );
+            prop_assert_eq!(
+                config.ui_amount_to_amount(&actual, decimals, unix_timestamp),
+                spl_config.try_ui_amount_into_amount(&actual, decimals, unix_timestamp)
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn mint_ui_amount_uses_interest_bearing_config(
+            mint in token22_mint_no_extensions(),
+            config in proptest::option::of(interest_bearing_config()),
+            amount: u64,
+            unix_timestamp: i64,
+        ) {
+            let data = pack_mint(Mint { is_initialized: true, ..mint }, config);
+            let account = ReadonlyMintAccount(AccountData(&data))
+                .try_into_valid()
+                .unwrap()
+                .try_into_initialized()
+                .unwrap();
+            let expected = match config {
+                Some(c) => conv_config(c)
+                    .amount_to_ui_amount(amount, mint.decimals, unix_timestamp)
+                    .ok_or(ProgramError::InvalidArgument),
+                None => Ok(spl_token_2022::amount_to_ui_amount_string_trimmed(amount, mint.decimals)),
+            };
+            let actual = account.mint_amount_to_ui_amount(amount, unix_timestamp);
+            prop_assert_eq!(&actual, &expected);
+            if let Ok(ui_amount) = actual {
+                let expected = match config {
+                    Some(c) => conv_config(c).try_ui_amount_into_amount(&ui_amount, mint.decimals, unix_timestamp),
+                    None => spl_token_2022::try_ui_amount_into_amount(ui_amount.clone(), mint.decimals),
+                };
+                prop_assert_eq!(account.mint_ui_amount_to_amount(&ui_amount, unix_timestamp), expected);
+            }
+        }
+    }
+}
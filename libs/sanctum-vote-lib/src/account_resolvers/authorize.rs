@@ -0,0 +1,94 @@
+use solana_program::{
+    clock::Epoch, program_error::ProgramError, pubkey::Pubkey, sysvar, vote::state::VoteAuthorize,
+};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkeyBytes};
+use vote_program_interface::AuthorizeKeys;
+
+use crate::ReadonlyVoteAccount;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AuthorizeFreeAccounts<V> {
+    pub vote: V,
+}
+
+impl<V: ReadonlyAccountData + ReadonlyAccountPubkeyBytes> AuthorizeFreeAccounts<V> {
+    /// The authorized voter of `current_epoch` signs for [`VoteAuthorize::Voter`].
+    /// Use [`Self::resolve_withdrawer`] if the authorized withdrawer is changing the voter instead.
+    pub fn resolve(
+        &self,
+        vote_authorize: VoteAuthorize,
+        current_epoch: Epoch,
+    ) -> Result<AuthorizeKeys, ProgramError> {
+        self.resolve_to_free_keys(vote_authorize, current_epoch)
+            .map(Into::into)
+    }
+
+    pub fn resolve_to_free_keys(
+        &self,
+        vote_authorize: VoteAuthorize,
+        current_epoch: Epoch,
+    ) -> Result<AuthorizeFreeKeys, ProgramError> {
+        match vote_authorize {
+            VoteAuthorize::Voter => self.resolve_to_free_keys_voter(current_epoch),
+            VoteAuthorize::Withdrawer => self.resolve_to_free_keys_withdrawer(),
+        }
+    }
+
+    pub fn resolve_voter(&self, current_epoch: Epoch) -> Result<AuthorizeKeys, ProgramError> {
+        self.resolve_to_free_keys_voter(current_epoch)
+            .map(Into::into)
+    }
+
+    pub fn resolve_to_free_keys_voter(
+        &self,
+        current_epoch: Epoch,
+    ) -> Result<AuthorizeFreeKeys, ProgramError> {
+        let Self { vote } = self;
+        let v = ReadonlyVoteAccount(vote);
+        let v = v.try_into_valid()?;
+        let authority = v
+            .vote_authorized_voter(current_epoch)
+            .ok_or(ProgramError::InvalidAccountData)?;
+        Ok(AuthorizeFreeKeys {
+            vote: Pubkey::new_from_array(vote.pubkey_bytes()),
+            authority,
+        })
+    }
+
+    pub fn resolve_withdrawer(&self) -> Result<AuthorizeKeys, ProgramError> {
+        self.resolve_to_free_keys_withdrawer().map(Into::into)
+    }
+
+    pub fn resolve_to_free_keys_withdrawer(&self) -> Result<AuthorizeFreeKeys, ProgramError> {
+        let Self { vote } = self;
+        let v = ReadonlyVoteAccount(vote);
+        let v = v.try_into_valid()?;
+        Ok(AuthorizeFreeKeys {
+            vote: Pubkey::new_from_array(vote.pubkey_bytes()),
+            authority: v.vote_authorized_withdrawer(),
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AuthorizeFreeKeys {
+    pub vote: Pubkey,
+    pub authority: Pubkey,
+}
+
+impl AuthorizeFreeKeys {
+    pub fn resolve(&self) -> AuthorizeKeys {
+        let Self { vote, authority } = self;
+        AuthorizeKeys {
+            vote: *vote,
+            authority: *authority,
+            clock: sysvar::clock::ID,
+        }
+    }
+}
+
+impl From<AuthorizeFreeKeys> for AuthorizeKeys {
+    fn from(value: AuthorizeFreeKeys) -> Self {
+        value.resolve()
+    }
+}
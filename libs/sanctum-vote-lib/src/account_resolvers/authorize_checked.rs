@@ -0,0 +1,103 @@
+use solana_program::{
+    clock::Epoch, program_error::ProgramError, pubkey::Pubkey, sysvar, vote::state::VoteAuthorize,
+};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkeyBytes};
+use vote_program_interface::AuthorizeCheckedKeys;
+
+use crate::{AuthorizeFreeAccounts, AuthorizeFreeKeys};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AuthorizeCheckedFreeAccounts<V> {
+    pub vote: V,
+    pub new_authority: Pubkey,
+}
+
+impl<V: ReadonlyAccountData + ReadonlyAccountPubkeyBytes> AuthorizeCheckedFreeAccounts<V> {
+    /// The authorized voter of `current_epoch` signs for [`VoteAuthorize::Voter`].
+    /// Use [`Self::resolve_withdrawer`] if the authorized withdrawer is changing the voter instead.
+    pub fn resolve(
+        &self,
+        vote_authorize: VoteAuthorize,
+        current_epoch: Epoch,
+    ) -> Result<AuthorizeCheckedKeys, ProgramError> {
+        self.resolve_to_free_keys(vote_authorize, current_epoch)
+            .map(Into::into)
+    }
+
+    pub fn resolve_to_free_keys(
+        &self,
+        vote_authorize: VoteAuthorize,
+        current_epoch: Epoch,
+    ) -> Result<AuthorizeCheckedFreeKeys, ProgramError> {
+        match vote_authorize {
+            VoteAuthorize::Voter => self.resolve_to_free_keys_voter(current_epoch),
+            VoteAuthorize::Withdrawer => self.resolve_to_free_keys_withdrawer(),
+        }
+    }
+
+    pub fn resolve_voter(
+        &self,
+        current_epoch: Epoch,
+    ) -> Result<AuthorizeCheckedKeys, ProgramError> {
+        self.resolve_to_free_keys_voter(current_epoch)
+            .map(Into::into)
+    }
+
+    pub fn resolve_to_free_keys_voter(
+        &self,
+        current_epoch: Epoch,
+    ) -> Result<AuthorizeCheckedFreeKeys, ProgramError> {
+        let AuthorizeFreeKeys { vote, authority } =
+            AuthorizeFreeAccounts { vote: &self.vote }.resolve_to_free_keys_voter(current_epoch)?;
+        Ok(AuthorizeCheckedFreeKeys {
+            vote,
+            authority,
+            new_authority: self.new_authority,
+        })
+    }
+
+    pub fn resolve_withdrawer(&self) -> Result<AuthorizeCheckedKeys, ProgramError> {
+        self.resolve_to_free_keys_withdrawer().map(Into::into)
+    }
+
+    pub fn resolve_to_free_keys_withdrawer(
+        &self,
+    ) -> Result<AuthorizeCheckedFreeKeys, ProgramError> {
+        let AuthorizeFreeKeys { vote, authority } =
+            AuthorizeFreeAccounts { vote: &self.vote }.resolve_to_free_keys_withdrawer()?;
+        Ok(AuthorizeCheckedFreeKeys {
+            vote,
+            authority,
+            new_authority: self.new_authority,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AuthorizeCheckedFreeKeys {
+    pub vote: Pubkey,
+    pub authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+impl AuthorizeCheckedFreeKeys {
+    pub fn resolve(&self) -> AuthorizeCheckedKeys {
+        let Self {
+            vote,
+            authority,
+            new_authority,
+        } = self;
+        AuthorizeCheckedKeys {
+            vote: *vote,
+            authority: *authority,
+            new_authority: *new_authority,
+            clock: sysvar::clock::ID,
+        }
+    }
+}
+
+impl From<AuthorizeCheckedFreeKeys> for AuthorizeCheckedKeys {
+    fn from(value: AuthorizeCheckedFreeKeys) -> Self {
+        value.resolve()
+    }
+}
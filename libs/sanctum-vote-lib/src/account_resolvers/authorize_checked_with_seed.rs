@@ -0,0 +1,31 @@
+use solana_program::{pubkey::Pubkey, sysvar};
+use vote_program_interface::AuthorizeCheckedWithSeedKeys;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AuthorizeCheckedWithSeedFreeKeys {
+    pub vote: Pubkey,
+    pub base: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+impl AuthorizeCheckedWithSeedFreeKeys {
+    pub fn resolve(&self) -> AuthorizeCheckedWithSeedKeys {
+        let Self {
+            vote,
+            base,
+            new_authority,
+        } = self;
+        AuthorizeCheckedWithSeedKeys {
+            vote: *vote,
+            base: *base,
+            new_authority: *new_authority,
+            clock: sysvar::clock::ID,
+        }
+    }
+}
+
+impl From<AuthorizeCheckedWithSeedFreeKeys> for AuthorizeCheckedWithSeedKeys {
+    fn from(value: AuthorizeCheckedWithSeedFreeKeys) -> Self {
+        value.resolve()
+    }
+}
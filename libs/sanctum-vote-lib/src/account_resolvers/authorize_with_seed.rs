@@ -0,0 +1,25 @@
+use solana_program::{pubkey::Pubkey, sysvar};
+use vote_program_interface::AuthorizeWithSeedKeys;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AuthorizeWithSeedFreeKeys {
+    pub vote: Pubkey,
+    pub base: Pubkey,
+}
+
+impl AuthorizeWithSeedFreeKeys {
+    pub fn resolve(&self) -> AuthorizeWithSeedKeys {
+        let Self { vote, base } = self;
+        AuthorizeWithSeedKeys {
+            vote: *vote,
+            base: *base,
+            clock: sysvar::clock::ID,
+        }
+    }
+}
+
+impl From<AuthorizeWithSeedFreeKeys> for AuthorizeWithSeedKeys {
+    fn from(value: AuthorizeWithSeedFreeKeys) -> Self {
+        value.resolve()
+    }
+}
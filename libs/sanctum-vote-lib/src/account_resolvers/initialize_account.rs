@@ -0,0 +1,26 @@
+use solana_program::{pubkey::Pubkey, sysvar};
+use vote_program_interface::InitializeAccountKeys;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct InitializeAccountFreeKeys {
+    pub vote: Pubkey,
+    pub node: Pubkey,
+}
+
+impl InitializeAccountFreeKeys {
+    pub fn resolve(&self) -> InitializeAccountKeys {
+        let Self { vote, node } = self;
+        InitializeAccountKeys {
+            vote: *vote,
+            node: *node,
+            rent: sysvar::rent::ID,
+            clock: sysvar::clock::ID,
+        }
+    }
+}
+
+impl From<InitializeAccountFreeKeys> for InitializeAccountKeys {
+    fn from(value: InitializeAccountFreeKeys) -> Self {
+        value.resolve()
+    }
+}
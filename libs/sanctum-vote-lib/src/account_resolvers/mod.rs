@@ -0,0 +1,17 @@
+mod authorize;
+mod authorize_checked;
+mod authorize_checked_with_seed;
+mod authorize_with_seed;
+mod initialize_account;
+mod update_commission;
+mod update_validator_identity;
+mod withdraw;
+
+pub use authorize::*;
+pub use authorize_checked::*;
+pub use authorize_checked_with_seed::*;
+pub use authorize_with_seed::*;
+pub use initialize_account::*;
+pub use update_commission::*;
+pub use update_validator_identity::*;
+pub use withdraw::*;
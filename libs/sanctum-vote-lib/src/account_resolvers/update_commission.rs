@@ -0,0 +1,32 @@
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkeyBytes};
+use vote_program_interface::UpdateCommissionKeys;
+
+use crate::ReadonlyVoteAccount;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct UpdateCommissionFreeAccounts<V> {
+    pub vote: V,
+}
+
+impl<V: ReadonlyAccountData + ReadonlyAccountPubkeyBytes> UpdateCommissionFreeAccounts<V> {
+    pub fn resolve(&self) -> Result<UpdateCommissionKeys, ProgramError> {
+        let Self { vote } = self;
+        let v = ReadonlyVoteAccount(vote);
+        let v = v.try_into_valid()?;
+        Ok(UpdateCommissionKeys {
+            vote: Pubkey::new_from_array(vote.pubkey_bytes()),
+            withdraw_authority: v.vote_authorized_withdrawer(),
+        })
+    }
+}
+
+impl<V: ReadonlyAccountData + ReadonlyAccountPubkeyBytes> TryFrom<UpdateCommissionFreeAccounts<V>>
+    for UpdateCommissionKeys
+{
+    type Error = ProgramError;
+
+    fn try_from(value: UpdateCommissionFreeAccounts<V>) -> Result<Self, Self::Error> {
+        value.resolve()
+    }
+}
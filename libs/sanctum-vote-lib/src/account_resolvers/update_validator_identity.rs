@@ -0,0 +1,34 @@
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkeyBytes};
+use vote_program_interface::UpdateValidatorIdentityKeys;
+
+use crate::ReadonlyVoteAccount;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct UpdateValidatorIdentityFreeAccounts<V> {
+    pub vote: V,
+    pub new_node: Pubkey,
+}
+
+impl<V: ReadonlyAccountData + ReadonlyAccountPubkeyBytes> UpdateValidatorIdentityFreeAccounts<V> {
+    pub fn resolve(&self) -> Result<UpdateValidatorIdentityKeys, ProgramError> {
+        let Self { vote, new_node } = self;
+        let v = ReadonlyVoteAccount(vote);
+        let v = v.try_into_valid()?;
+        Ok(UpdateValidatorIdentityKeys {
+            vote: Pubkey::new_from_array(vote.pubkey_bytes()),
+            new_node: *new_node,
+            withdraw_authority: v.vote_authorized_withdrawer(),
+        })
+    }
+}
+
+impl<V: ReadonlyAccountData + ReadonlyAccountPubkeyBytes>
+    TryFrom<UpdateValidatorIdentityFreeAccounts<V>> for UpdateValidatorIdentityKeys
+{
+    type Error = ProgramError;
+
+    fn try_from(value: UpdateValidatorIdentityFreeAccounts<V>) -> Result<Self, Self::Error> {
+        value.resolve()
+    }
+}
@@ -0,0 +1,34 @@
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkeyBytes};
+use vote_program_interface::WithdrawKeys;
+
+use crate::ReadonlyVoteAccount;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct WithdrawFreeAccounts<V> {
+    pub vote: V,
+    pub to: Pubkey,
+}
+
+impl<V: ReadonlyAccountData + ReadonlyAccountPubkeyBytes> WithdrawFreeAccounts<V> {
+    pub fn resolve(&self) -> Result<WithdrawKeys, ProgramError> {
+        let Self { vote, to } = self;
+        let v = ReadonlyVoteAccount(vote);
+        let v = v.try_into_valid()?;
+        Ok(WithdrawKeys {
+            vote: Pubkey::new_from_array(vote.pubkey_bytes()),
+            to: *to,
+            withdraw_authority: v.vote_authorized_withdrawer(),
+        })
+    }
+}
+
+impl<V: ReadonlyAccountData + ReadonlyAccountPubkeyBytes> TryFrom<WithdrawFreeAccounts<V>>
+    for WithdrawKeys
+{
+    type Error = ProgramError;
+
+    fn try_from(value: WithdrawFreeAccounts<V>) -> Result<Self, Self::Error> {
+        value.resolve()
+    }
+}
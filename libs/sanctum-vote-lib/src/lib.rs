@@ -0,0 +1,13 @@
+mod account_resolvers;
+mod readonly;
+mod typeconv;
+mod utils;
+
+pub use account_resolvers::*;
+pub use readonly::*;
+pub use typeconv::*;
+pub use utils::*;
+
+// This const is available in VoteState::size_of(),
+// but define it here to avoid depending on the vote state version
+pub const VOTE_ACCOUNT_LEN: usize = 3762;
@@ -0,0 +1,662 @@
+use core::iter::FusedIterator;
+
+use solana_program::{
+    clock::{Epoch, Slot},
+    program_error::ProgramError,
+    pubkey::{Pubkey, PUBKEY_BYTES},
+    stake::MINIMUM_DELINQUENT_EPOCHS_FOR_DEACTIVATION,
+    vote::state::BlockTimestamp,
+};
+use solana_readonly_account::ReadonlyAccountData;
+
+pub const VOTE_STATE_V0_23_5_DISCM: [u8; 4] = 0u32.to_le_bytes();
+pub const VOTE_STATE_V1_14_11_DISCM: [u8; 4] = 1u32.to_le_bytes();
+pub const VOTE_STATE_CURRENT_DISCM: [u8; 4] = 2u32.to_le_bytes();
+
+// bincode-serialized VoteStateVersions.
+// Fields up to the first variable-length field, votes, have fixed offsets for each version.
+pub const VOTE_DISCM_OFFSET: usize = 0;
+// VoteStateVersions serializes the discriminant as a u32
+pub const VOTE_NODE_PUBKEY_OFFSET: usize = VOTE_DISCM_OFFSET + 4;
+// V0_23_5
+pub const VOTE_V0_23_5_AUTHORIZED_VOTER_OFFSET: usize = VOTE_NODE_PUBKEY_OFFSET + PUBKEY_BYTES;
+pub const VOTE_V0_23_5_AUTHORIZED_VOTER_EPOCH_OFFSET: usize =
+    VOTE_V0_23_5_AUTHORIZED_VOTER_OFFSET + PUBKEY_BYTES;
+pub const VOTE_V0_23_5_PRIOR_VOTERS_OFFSET: usize = VOTE_V0_23_5_AUTHORIZED_VOTER_EPOCH_OFFSET + 8;
+/// CircBuf<(Pubkey, Epoch, Epoch, Slot)> of 32 items + idx: usize
+pub const VOTE_V0_23_5_PRIOR_VOTERS_LEN: usize = 32 * (PUBKEY_BYTES + 24) + 8;
+pub const VOTE_V0_23_5_AUTHORIZED_WITHDRAWER_OFFSET: usize =
+    VOTE_V0_23_5_PRIOR_VOTERS_OFFSET + VOTE_V0_23_5_PRIOR_VOTERS_LEN;
+pub const VOTE_V0_23_5_COMMISSION_OFFSET: usize =
+    VOTE_V0_23_5_AUTHORIZED_WITHDRAWER_OFFSET + PUBKEY_BYTES;
+pub const VOTE_V0_23_5_VOTES_OFFSET: usize = VOTE_V0_23_5_COMMISSION_OFFSET + 1;
+// V1_14_11 and Current
+pub const VOTE_AUTHORIZED_WITHDRAWER_OFFSET: usize = VOTE_NODE_PUBKEY_OFFSET + PUBKEY_BYTES;
+pub const VOTE_COMMISSION_OFFSET: usize = VOTE_AUTHORIZED_WITHDRAWER_OFFSET + PUBKEY_BYTES;
+pub const VOTE_VOTES_OFFSET: usize = VOTE_COMMISSION_OFFSET + 1;
+/// CircBuf<(Pubkey, Epoch, Epoch)> of 32 items + idx: usize + is_empty: bool
+pub const VOTE_PRIOR_VOTERS_LEN: usize = 32 * (PUBKEY_BYTES + 16) + 8 + 1;
+// element sizes of the variable-length fields
+/// Lockout { slot: u64, confirmation_count: u32 }
+pub const VOTE_LOCKOUT_LEN: usize = 12;
+/// LandedVote { latency: u8, lockout: Lockout }
+pub const VOTE_LANDED_VOTE_LEN: usize = 1 + VOTE_LOCKOUT_LEN;
+/// (Epoch, Pubkey)
+pub const VOTE_AUTHORIZED_VOTER_LEN: usize = 8 + PUBKEY_BYTES;
+/// (Epoch, credits, prev_credits)
+pub const VOTE_EPOCH_CREDITS_ENTRY_LEN: usize = 24;
+/// BlockTimestamp { slot: u64, timestamp: i64 }
+pub const VOTE_LAST_TIMESTAMP_LEN: usize = 16;
+
+/// A possible vote account
+///
+/// ## Example
+///
+/// ```rust
+/// use sanctum_vote_lib::ReadonlyVoteAccount;
+/// use solana_program::{
+///     account_info::AccountInfo,
+///     entrypoint::ProgramResult
+/// };
+///
+/// pub fn process(account: &AccountInfo) -> ProgramResult {
+///     let account = ReadonlyVoteAccount(account);
+///     let account = account.try_into_valid()?;
+///     solana_program::msg!("{}", account.vote_node_pubkey());
+///     Ok(())
+/// }
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ReadonlyVoteAccount<T>(pub T);
+
+impl<T> ReadonlyVoteAccount<T> {
+    pub fn as_inner(&self) -> &T {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: ReadonlyAccountData> ReadonlyVoteAccount<T> {
+    /// Checks that the data is a known `VoteStateVersions` variant
+    /// that is long enough to contain all its variable-length fields.
+    pub fn vote_data_is_valid(&self) -> bool {
+        VoteStateLayout::of(&self.0.data()).is_some()
+    }
+
+    pub fn try_into_valid(self) -> Result<ValidVoteAccount<T>, ProgramError> {
+        let layout =
+            VoteStateLayout::of(&self.0.data()).ok_or(ProgramError::InvalidAccountData)?;
+        Ok(ValidVoteAccount {
+            account: self,
+            layout,
+        })
+    }
+}
+
+impl<T> AsRef<T> for ReadonlyVoteAccount<T> {
+    fn as_ref(&self) -> &T {
+        self.as_inner()
+    }
+}
+
+/// A vote account that has been checked to contain valid data.
+///
+/// The only safe way to create this struct is via [`TryFrom<ReadonlyVoteAccount>`].
+///
+/// The offsets of the variable-length fields are computed once on creation,
+/// so this must be recreated if the account's data may have changed since.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ValidVoteAccount<T> {
+    account: ReadonlyVoteAccount<T>,
+    layout: VoteStateLayout,
+}
+
+impl<T> ValidVoteAccount<T> {
+    pub fn as_readonly(&self) -> &ReadonlyVoteAccount<T> {
+        &self.account
+    }
+
+    pub fn into_readonly(self) -> ReadonlyVoteAccount<T> {
+        self.account
+    }
+}
+
+impl<T: ReadonlyAccountData> ValidVoteAccount<T> {
+    fn layout(&self) -> &VoteStateLayout {
+        &self.layout
+    }
+
+    pub fn vote_state_marker(&self) -> VoteStateMarker {
+        let d = self.account.as_inner().data();
+        let b: &[u8; 4] = d[VOTE_DISCM_OFFSET..VOTE_DISCM_OFFSET + 4]
+            .try_into()
+            .unwrap();
+        VoteStateMarker::try_from(*b).unwrap()
+    }
+
+    /// Mirrors `VoteStateVersions::is_uninitialized()`
+    pub fn vote_is_uninitialized(&self) -> bool {
+        let d = self.account.as_inner().data();
+        match self.vote_state_marker() {
+            VoteStateMarker::V0_23_5 => {
+                deser_pubkey_unchecked(&d, VOTE_V0_23_5_AUTHORIZED_VOTER_OFFSET)
+                    == Pubkey::default()
+            }
+            VoteStateMarker::V1_14_11 | VoteStateMarker::Current => {
+                deser_u64_le_unchecked(&d, self.layout().authorized_voters_offset) == 0
+            }
+        }
+    }
+
+    pub fn vote_node_pubkey(&self) -> Pubkey {
+        deser_pubkey_unchecked(&self.account.as_inner().data(), VOTE_NODE_PUBKEY_OFFSET)
+    }
+
+    pub fn vote_authorized_withdrawer(&self) -> Pubkey {
+        let offset = match self.vote_state_marker() {
+            VoteStateMarker::V0_23_5 => VOTE_V0_23_5_AUTHORIZED_WITHDRAWER_OFFSET,
+            VoteStateMarker::V1_14_11 | VoteStateMarker::Current => {
+                VOTE_AUTHORIZED_WITHDRAWER_OFFSET
+            }
+        };
+        deser_pubkey_unchecked(&self.account.as_inner().data(), offset)
+    }
+
+    /// Returns the authorized voter for `epoch`, the one with the latest epoch not after it.
+    ///
+    /// Mirrors `AuthorizedVoters::get_authorized_voter()`
+    pub fn vote_authorized_voter(&self, epoch: Epoch) -> Option<Pubkey> {
+        let d = self.account.as_inner().data();
+        match self.vote_state_marker() {
+            VoteStateMarker::V0_23_5 => {
+                (deser_u64_le_unchecked(&d, VOTE_V0_23_5_AUTHORIZED_VOTER_EPOCH_OFFSET) <= epoch)
+                    .then(|| deser_pubkey_unchecked(&d, VOTE_V0_23_5_AUTHORIZED_VOTER_OFFSET))
+            }
+            VoteStateMarker::V1_14_11 | VoteStateMarker::Current => {
+                let offset = self.layout().authorized_voters_offset;
+                // valid data guarantees this fits in usize
+                let len = deser_u64_le_unchecked(&d, offset) as usize;
+                // BTreeMap<Epoch, Pubkey>, serialized in ascending epoch order
+                (0..len)
+                    .rev()
+                    .map(|i| offset + 8 + i * VOTE_AUTHORIZED_VOTER_LEN)
+                    .find(|entry_offset| deser_u64_le_unchecked(&d, *entry_offset) <= epoch)
+                    .map(|entry_offset| deser_pubkey_unchecked(&d, entry_offset + 8))
+            }
+        }
+    }
+
+    pub fn vote_commission(&self) -> u8 {
+        let offset = match self.vote_state_marker() {
+            VoteStateMarker::V0_23_5 => VOTE_V0_23_5_COMMISSION_OFFSET,
+            VoteStateMarker::V1_14_11 | VoteStateMarker::Current => VOTE_COMMISSION_OFFSET,
+        };
+        self.account.as_inner().data()[offset]
+    }
+
+    pub fn vote_root_slot(&self) -> Option<Slot> {
+        let d = self.account.as_inner().data();
+        let offset = self.layout().root_slot_offset;
+        match d[offset] {
+            0 => None,
+            _ => Some(deser_u64_le_unchecked(&d, offset + 1)),
+        }
+    }
+
+    pub fn vote_epoch_credits_len(&self) -> usize {
+        // valid data guarantees this fits in usize
+        deser_u64_le_unchecked(
+            &self.account.as_inner().data(),
+            self.layout().epoch_credits_offset,
+        ) as usize
+    }
+
+    /// Returns the `index`-th `(epoch, credits, prev_credits)` entry, entries being ordered by ascending epoch
+    pub fn vote_epoch_credits_at(&self, index: usize) -> Option<(Epoch, u64, u64)> {
+        if index >= self.vote_epoch_credits_len() {
+            return None;
+        }
+        let d = self.account.as_inner().data();
+        let offset = self.layout().epoch_credits_offset + 8 + index * VOTE_EPOCH_CREDITS_ENTRY_LEN;
+        Some((
+            deser_u64_le_unchecked(&d, offset),
+            deser_u64_le_unchecked(&d, offset + 8),
+            deser_u64_le_unchecked(&d, offset + 16),
+        ))
+    }
+
+    /// Iterates through all `(epoch, credits, prev_credits)` entries in ascending epoch order
+    pub fn vote_epoch_credits_iter(&self) -> VoteEpochCreditsIter<'_, T> {
+        VoteEpochCreditsIter {
+            vote_account: self,
+            front: 0,
+            back: self.vote_epoch_credits_len(),
+        }
+    }
+
+    /// Total credits earned, mirrors `VoteState::credits()`
+    pub fn vote_credits(&self) -> u64 {
+        self.vote_epoch_credits_iter()
+            .next_back()
+            .map_or(0, |(_epoch, credits, _prev_credits)| credits)
+    }
+
+    pub fn vote_last_timestamp(&self) -> BlockTimestamp {
+        let d = self.account.as_inner().data();
+        let offset = self.layout().last_timestamp_offset;
+        BlockTimestamp {
+            slot: deser_u64_le_unchecked(&d, offset),
+            timestamp: deser_i64_le_unchecked(&d, offset + 8),
+        }
+    }
+
+    /// Returns true if this vote account has not voted in the last
+    /// `MINIMUM_DELINQUENT_EPOCHS_FOR_DEACTIVATION` epochs, including `current_epoch`,
+    /// so stake delegated to it can be deactivated with `DeactivateDelinquent`.
+    ///
+    /// Mirrors `solana_program::stake::tools::eligible_for_deactivate_delinquent()`
+    pub fn vote_is_delinquent(&self, current_epoch: Epoch) -> bool {
+        match self.vote_epoch_credits_iter().next_back() {
+            None => true,
+            Some((epoch, ..)) => current_epoch
+                .checked_sub(MINIMUM_DELINQUENT_EPOCHS_FOR_DEACTIVATION as Epoch)
+                .is_some_and(|minimum_epoch| epoch <= minimum_epoch),
+        }
+    }
+
+    /// Returns true if this vote account has voted in every one of the last
+    /// `MINIMUM_DELINQUENT_EPOCHS_FOR_DEACTIVATION` epochs, including `current_epoch`,
+    /// so it can be used as the reference vote account of `DeactivateDelinquent`.
+    ///
+    /// Mirrors `solana_program::stake::tools::acceptable_reference_epoch_credits()`
+    pub fn vote_is_acceptable_reference(&self, current_epoch: Epoch) -> bool {
+        if self.vote_epoch_credits_len() < MINIMUM_DELINQUENT_EPOCHS_FOR_DEACTIVATION {
+            return false;
+        }
+        let mut epoch = current_epoch;
+        for (vote_epoch, ..) in self
+            .vote_epoch_credits_iter()
+            .rev()
+            .take(MINIMUM_DELINQUENT_EPOCHS_FOR_DEACTIVATION)
+        {
+            if vote_epoch != epoch {
+                return false;
+            }
+            epoch = epoch.saturating_sub(1);
+        }
+        true
+    }
+}
+
+impl<T: ReadonlyAccountData> TryFrom<ReadonlyVoteAccount<T>> for ValidVoteAccount<T> {
+    type Error = ProgramError;
+
+    fn try_from(value: ReadonlyVoteAccount<T>) -> Result<Self, Self::Error> {
+        value.try_into_valid()
+    }
+}
+
+impl<T> AsRef<ReadonlyVoteAccount<T>> for ValidVoteAccount<T> {
+    fn as_ref(&self) -> &ReadonlyVoteAccount<T> {
+        self.as_readonly()
+    }
+}
+
+impl<T> From<ValidVoteAccount<T>> for ReadonlyVoteAccount<T> {
+    fn from(value: ValidVoteAccount<T>) -> Self {
+        value.into_readonly()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum VoteStateMarker {
+    V0_23_5,
+    V1_14_11,
+    Current,
+}
+
+impl TryFrom<[u8; 4]> for VoteStateMarker {
+    type Error = ProgramError;
+
+    fn try_from(value: [u8; 4]) -> Result<Self, Self::Error> {
+        match value {
+            VOTE_STATE_V0_23_5_DISCM => Ok(Self::V0_23_5),
+            VOTE_STATE_V1_14_11_DISCM => Ok(Self::V1_14_11),
+            VOTE_STATE_CURRENT_DISCM => Ok(Self::Current),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+}
+
+/// Iterator over a [`ValidVoteAccount`]'s `(epoch, credits, prev_credits)`s, in ascending epoch order
+#[derive(Clone, Copy, Debug)]
+pub struct VoteEpochCreditsIter<'a, T> {
+    vote_account: &'a ValidVoteAccount<T>,
+    front: usize,
+    back: usize,
+}
+
+impl<'a, T: ReadonlyAccountData> Iterator for VoteEpochCreditsIter<'a, T> {
+    type Item = (Epoch, u64, u64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        let res = self.vote_account.vote_epoch_credits_at(self.front);
+        self.front += 1;
+        res
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T: ReadonlyAccountData> DoubleEndedIterator for VoteEpochCreditsIter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        self.vote_account.vote_epoch_credits_at(self.back)
+    }
+}
+
+impl<'a, T: ReadonlyAccountData> ExactSizeIterator for VoteEpochCreditsIter<'a, T> {}
+
+impl<'a, T: ReadonlyAccountData> FusedIterator for VoteEpochCreditsIter<'a, T> {}
+
+/// Offsets of the fields that come after the first variable-length field
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct VoteStateLayout {
+    root_slot_offset: usize,
+    /// Only for V1_14_11 and Current
+    authorized_voters_offset: usize,
+    epoch_credits_offset: usize,
+    last_timestamp_offset: usize,
+}
+
+impl VoteStateLayout {
+    /// Returns None if the data is too short or contains invalid enum tags
+    fn of(d: &[u8]) -> Option<Self> {
+        let b: [u8; 4] = d
+            .get(VOTE_DISCM_OFFSET..VOTE_DISCM_OFFSET + 4)?
+            .try_into()
+            .ok()?;
+        let marker = VoteStateMarker::try_from(b).ok()?;
+        let (votes_offset, vote_len) = match marker {
+            VoteStateMarker::V0_23_5 => (VOTE_V0_23_5_VOTES_OFFSET, VOTE_LOCKOUT_LEN),
+            VoteStateMarker::V1_14_11 => (VOTE_VOTES_OFFSET, VOTE_LOCKOUT_LEN),
+            VoteStateMarker::Current => (VOTE_VOTES_OFFSET, VOTE_LANDED_VOTE_LEN),
+        };
+        let root_slot_offset = skip_vec(d, votes_offset, vote_len)?;
+        let authorized_voters_offset = match d.get(root_slot_offset)? {
+            0 => root_slot_offset + 1,
+            1 => root_slot_offset + 1 + 8,
+            _ => return None,
+        };
+        let epoch_credits_offset = if marker == VoteStateMarker::V0_23_5 {
+            authorized_voters_offset
+        } else {
+            let prior_voters_offset =
+                skip_vec(d, authorized_voters_offset, VOTE_AUTHORIZED_VOTER_LEN)?;
+            let is_empty_offset = prior_voters_offset + VOTE_PRIOR_VOTERS_LEN - 1;
+            if *d.get(is_empty_offset)? > 1 {
+                return None;
+            }
+            is_empty_offset + 1
+        };
+        let last_timestamp_offset =
+            skip_vec(d, epoch_credits_offset, VOTE_EPOCH_CREDITS_ENTRY_LEN)?;
+        if last_timestamp_offset.checked_add(VOTE_LAST_TIMESTAMP_LEN)? > d.len() {
+            return None;
+        }
+        Some(Self {
+            root_slot_offset,
+            authorized_voters_offset,
+            epoch_credits_offset,
+            last_timestamp_offset,
+        })
+    }
+}
+
+/// Returns the offset after the bincode-serialized collection of `elem_len`-sized elements at `offset`,
+/// or None if it doesn't fit in `d`
+fn skip_vec(d: &[u8], offset: usize, elem_len: usize) -> Option<usize> {
+    let len: [u8; 8] = d.get(offset..offset.checked_add(8)?)?.try_into().ok()?;
+    let end = usize::try_from(u64::from_le_bytes(len))
+        .ok()?
+        .checked_mul(elem_len)?
+        .checked_add(offset + 8)?;
+    (end <= d.len()).then_some(end)
+}
+
+fn deser_pubkey_unchecked(d: &[u8], offset: usize) -> Pubkey {
+    let b: &[u8; 32] = d[offset..offset + PUBKEY_BYTES].try_into().unwrap();
+    Pubkey::from(*b)
+}
+
+fn deser_u64_le_unchecked(d: &[u8], offset: usize) -> u64 {
+    let b: &[u8; 8] = d[offset..offset + 8].try_into().unwrap();
+    u64::from_le_bytes(*b)
+}
+
+fn deser_i64_le_unchecked(d: &[u8], offset: usize) -> i64 {
+    let b: &[u8; 8] = d[offset..offset + 8].try_into().unwrap();
+    i64::from_le_bytes(*b)
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{collection::vec, prelude::*};
+    use sanctum_solana_test_utils::proptest_utils::pubkey;
+    use solana_program::{
+        clock::Clock,
+        stake::tools::{acceptable_reference_epoch_credits, eligible_for_deactivate_delinquent},
+        vote::state::{
+            LandedVote, Lockout, VoteInit, VoteState, VoteState1_14_11, VoteStateVersions,
+        },
+    };
+
+    use super::*;
+
+    const MAX_EPOCH: Epoch = 20;
+
+    struct AccountData<'a>(pub &'a [u8]);
+
+    impl<'a> ReadonlyAccountData for AccountData<'a> {
+        type DataDeref<'d>
+            = &'d [u8]
+        where
+            Self: 'd;
+
+        fn data(&self) -> Self::DataDeref<'_> {
+            self.0
+        }
+    }
+
+    prop_compose! {
+        fn vote_state()
+            (
+                node_pubkey in pubkey(),
+                authorized_voter in pubkey(),
+                authorized_withdrawer in pubkey(),
+                commission: u8,
+                initialized: bool,
+                authorized_voter_epoch in 0..=MAX_EPOCH,
+                new_authorized_voters in vec((pubkey(), 1..=3u64), 0..=3),
+                votes in vec((any::<u64>(), any::<u32>(), any::<u8>()), 0..=31),
+                root_slot: Option<u64>,
+                mut epoch_credits in vec((0..=MAX_EPOCH, any::<u64>(), any::<u64>()), 0..=64),
+                last_timestamp: (u64, i64),
+            ) -> VoteState {
+                let mut res = if initialized {
+                    let mut initialized = VoteState::new(
+                        &VoteInit {
+                            node_pubkey,
+                            authorized_voter,
+                            authorized_withdrawer,
+                            commission,
+                        },
+                        &Clock {
+                            epoch: authorized_voter_epoch,
+                            ..Default::default()
+                        },
+                    );
+                    let mut target_epoch = authorized_voter_epoch;
+                    for (new_authorized_voter, epochs_later) in new_authorized_voters {
+                        target_epoch += epochs_later;
+                        initialized
+                            .set_new_authorized_voter(
+                                &new_authorized_voter,
+                                authorized_voter_epoch,
+                                target_epoch,
+                                |_| Ok(()),
+                            )
+                            .unwrap();
+                    }
+                    initialized
+                } else {
+                    let mut uninitialized = VoteState::default();
+                    uninitialized.node_pubkey = node_pubkey;
+                    uninitialized.authorized_withdrawer = authorized_withdrawer;
+                    uninitialized.commission = commission;
+                    uninitialized
+                };
+                res.votes = votes
+                    .into_iter()
+                    .map(|(slot, confirmation_count, latency)| LandedVote {
+                        latency,
+                        lockout: Lockout::new_with_confirmation_count(slot, confirmation_count),
+                    })
+                    .collect();
+                res.root_slot = root_slot;
+                epoch_credits.sort_by_key(|(epoch, ..)| *epoch);
+                res.epoch_credits = epoch_credits;
+                res.last_timestamp = BlockTimestamp {
+                    slot: last_timestamp.0,
+                    timestamp: last_timestamp.1,
+                };
+                res
+            }
+    }
+
+    /// `VoteState0_23_5` is private, so serialize a tuple with the same layout
+    fn serialize_v0_23_5(
+        vote_state: &VoteState,
+        (authorized_voter, authorized_voter_epoch): (Pubkey, Epoch),
+    ) -> Vec<u8> {
+        bincode::serialize(&(
+            (
+                0u32,
+                vote_state.node_pubkey,
+                authorized_voter,
+                authorized_voter_epoch,
+            ),
+            [(Pubkey::default(), 0u64, 0u64, 0u64); 32],
+            0u64,
+            vote_state.authorized_withdrawer,
+            vote_state.commission,
+            vote_state
+                .votes
+                .iter()
+                .map(|v| (v.slot(), v.confirmation_count()))
+                .collect::<Vec<_>>(),
+            vote_state.root_slot,
+            &vote_state.epoch_credits,
+            (
+                vote_state.last_timestamp.slot,
+                vote_state.last_timestamp.timestamp,
+            ),
+        ))
+        .unwrap()
+    }
+
+    fn all_versions(
+        vote_state: &VoteState,
+        v0_23_5_authorized_voter: (Pubkey, Epoch),
+    ) -> [Vec<u8>; 3] {
+        [
+            serialize_v0_23_5(vote_state, v0_23_5_authorized_voter),
+            bincode::serialize(&VoteStateVersions::V1_14_11(Box::new(
+                VoteState1_14_11::from(vote_state.clone()),
+            )))
+            .unwrap(),
+            bincode::serialize(&VoteStateVersions::new_current(vote_state.clone())).unwrap(),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn vote_readonly_matches_full_deser(
+            vote_state in vote_state(),
+            v0_23_5_authorized_voter in (pubkey(), 0..=MAX_EPOCH),
+            current_epoch in 0..=MAX_EPOCH + 6,
+        ) {
+            for (data, expected_marker) in all_versions(&vote_state, v0_23_5_authorized_voter)
+                .into_iter()
+                .zip([VoteStateMarker::V0_23_5, VoteStateMarker::V1_14_11, VoteStateMarker::Current])
+            {
+                let versions: VoteStateVersions = bincode::deserialize(&data).unwrap();
+                let expected_uninitialized = versions.is_uninitialized();
+                let expected = versions.convert_to_current();
+
+                let account = ReadonlyVoteAccount(AccountData(&data));
+                prop_assert!(account.vote_data_is_valid());
+                let account = account.try_into_valid().unwrap();
+
+                prop_assert_eq!(account.vote_state_marker(), expected_marker);
+                prop_assert_eq!(account.vote_is_uninitialized(), expected_uninitialized);
+                prop_assert_eq!(account.vote_node_pubkey(), expected.node_pubkey);
+                prop_assert_eq!(account.vote_authorized_withdrawer(), expected.authorized_withdrawer);
+                prop_assert_eq!(account.vote_commission(), expected.commission);
+                for epoch in 0..=MAX_EPOCH + 12 {
+                    prop_assert_eq!(account.vote_authorized_voter(epoch), expected.get_authorized_voter(epoch));
+                }
+                prop_assert_eq!(account.vote_root_slot(), expected.root_slot);
+                prop_assert_eq!(account.vote_epoch_credits_len(), expected.epoch_credits.len());
+                prop_assert!(account.vote_epoch_credits_iter().eq(expected.epoch_credits.iter().copied()));
+                prop_assert!(account.vote_epoch_credits_iter().rev().eq(expected.epoch_credits.iter().rev().copied()));
+                prop_assert_eq!(account.vote_credits(), expected.credits());
+                prop_assert_eq!(account.vote_last_timestamp(), expected.last_timestamp.clone());
+                prop_assert_eq!(
+                    account.vote_is_delinquent(current_epoch),
+                    eligible_for_deactivate_delinquent(&expected.epoch_credits, current_epoch)
+                );
+                prop_assert_eq!(
+                    account.vote_is_acceptable_reference(current_epoch),
+                    acceptable_reference_epoch_credits(&expected.epoch_credits, current_epoch)
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn vote_readonly_rejects_truncated(
+            vote_state in vote_state(),
+            v0_23_5_authorized_voter in (pubkey(), 0..=MAX_EPOCH),
+            truncate_by in 1..=VOTE_LAST_TIMESTAMP_LEN,
+        ) {
+            for data in all_versions(&vote_state, v0_23_5_authorized_voter) {
+                let data = &data[..data.len() - truncate_by];
+                prop_assert!(!ReadonlyVoteAccount(AccountData(data)).vote_data_is_valid());
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn vote_readonly_rejects_unknown_version(discm in 3..=u32::MAX, vote_state in vote_state()) {
+            let mut data = bincode::serialize(&VoteStateVersions::new_current(vote_state)).unwrap();
+            data[..4].copy_from_slice(&discm.to_le_bytes());
+            prop_assert!(!ReadonlyVoteAccount(AccountData(&data)).vote_data_is_valid());
+        }
+    }
+}
@@ -0,0 +1,60 @@
+//! For types repeated in IDL
+
+use solana_program::vote::state::{VoteAuthorize, VoteInit};
+use vote_program_interface::InitializeAccountIxArgs;
+
+pub struct NativeConv<T>(pub T);
+
+impl From<NativeConv<VoteAuthorize>> for vote_program_interface::VoteAuthorize {
+    fn from(NativeConv(a): NativeConv<VoteAuthorize>) -> Self {
+        match a {
+            VoteAuthorize::Voter => Self::Voter,
+            VoteAuthorize::Withdrawer => Self::Withdrawer,
+        }
+    }
+}
+
+impl From<NativeConv<vote_program_interface::VoteAuthorize>> for VoteAuthorize {
+    fn from(NativeConv(a): NativeConv<vote_program_interface::VoteAuthorize>) -> Self {
+        match a {
+            vote_program_interface::VoteAuthorize::Voter => Self::Voter,
+            vote_program_interface::VoteAuthorize::Withdrawer => Self::Withdrawer,
+        }
+    }
+}
+
+impl From<NativeConv<VoteInit>> for InitializeAccountIxArgs {
+    fn from(
+        NativeConv(VoteInit {
+            node_pubkey,
+            authorized_voter,
+            authorized_withdrawer,
+            commission,
+        }): NativeConv<VoteInit>,
+    ) -> Self {
+        Self {
+            node_pubkey,
+            authorized_voter,
+            authorized_withdrawer,
+            commission,
+        }
+    }
+}
+
+impl From<NativeConv<InitializeAccountIxArgs>> for VoteInit {
+    fn from(
+        NativeConv(InitializeAccountIxArgs {
+            node_pubkey,
+            authorized_voter,
+            authorized_withdrawer,
+            commission,
+        }): NativeConv<InitializeAccountIxArgs>,
+    ) -> Self {
+        Self {
+            node_pubkey,
+            authorized_voter,
+            authorized_withdrawer,
+            commission,
+        }
+    }
+}
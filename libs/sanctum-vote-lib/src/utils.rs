@@ -0,0 +1,7 @@
+use solana_program::{program_error::ProgramError, rent::Rent, sysvar::Sysvar};
+
+use crate::VOTE_ACCOUNT_LEN;
+
+pub fn onchain_rent_exempt_lamports_for_vote_account() -> Result<u64, ProgramError> {
+    Ok(Rent::get()?.minimum_balance(VOTE_ACCOUNT_LEN))
+}
@@ -0,0 +1,316 @@
+use sanctum_solana_test_utils::{est_rent_exempt_lamports, ExtendedProgramTest};
+use sanctum_vote_lib::{
+    AuthorizeCheckedFreeAccounts, AuthorizeCheckedWithSeedFreeKeys, AuthorizeFreeAccounts,
+    AuthorizeWithSeedFreeKeys, InitializeAccountFreeKeys, NativeConv, ReadonlyVoteAccount,
+    UpdateCommissionFreeAccounts, UpdateValidatorIdentityFreeAccounts, WithdrawFreeAccounts,
+    VOTE_ACCOUNT_LEN,
+};
+use solana_program::{
+    clock::Clock,
+    hash::Hash,
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    vote::{
+        self, instruction as vote_instruction,
+        state::{Vote, VoteAuthorize, VoteInit, VoteState, VoteStateVersions},
+    },
+};
+use solana_program_test::{BanksClient, ProgramTest};
+use solana_readonly_account::keyed::Keyed;
+use solana_sdk::{account::Account, signature::Keypair, signer::Signer, transaction::Transaction};
+use vote_program_interface::{
+    authorize_checked_ix, authorize_checked_with_seed_ix, authorize_ix, authorize_with_seed_ix,
+    initialize_account_ix, update_commission_ix, update_validator_identity_ix, vote_ix,
+    withdraw_ix, AuthorizeCheckedIxArgs, AuthorizeCheckedWithSeedIxArgs, AuthorizeIxArgs,
+    AuthorizeWithSeedIxArgs, UpdateCommissionIxArgs, VoteIxArgs, VoteKeys, WithdrawIxArgs,
+};
+
+const CURRENT_EPOCH: u64 = 0;
+
+fn vote_account(vote_init: &VoteInit) -> Account {
+    let vote_state = VoteState::new(vote_init, &Clock::default());
+    let mut data = vec![0u8; VOTE_ACCOUNT_LEN];
+    VoteState::serialize(&VoteStateVersions::new_current(vote_state), &mut data).unwrap();
+    Account {
+        lamports: est_rent_exempt_lamports(VOTE_ACCOUNT_LEN) + LAMPORTS_PER_SOL,
+        data,
+        owner: vote::program::ID,
+        executable: false,
+        rent_epoch: u64::MAX,
+    }
+}
+
+fn random_vote_init() -> VoteInit {
+    VoteInit {
+        node_pubkey: Pubkey::new_unique(),
+        authorized_voter: Pubkey::new_unique(),
+        authorized_withdrawer: Pubkey::new_unique(),
+        commission: 10,
+    }
+}
+
+#[test]
+fn ixs_match_solana_program() {
+    let vote_init = random_vote_init();
+    let vote = Keyed {
+        pubkey: Pubkey::new_unique(),
+        account: vote_account(&vote_init),
+    };
+    let new_authority = Pubkey::new_unique();
+    let base = Pubkey::new_unique();
+    let derived_key_owner = Pubkey::new_unique();
+    let seed = "seed";
+    let to = Pubkey::new_unique();
+
+    let [_create, initialize] = <[_; 2]>::try_from(vote_instruction::create_account_with_config(
+        &Pubkey::new_unique(),
+        &vote.pubkey,
+        &vote_init,
+        1,
+        Default::default(),
+    ))
+    .unwrap();
+    assert_eq!(
+        initialize_account_ix(
+            InitializeAccountFreeKeys {
+                vote: vote.pubkey,
+                node: vote_init.node_pubkey,
+            }
+            .resolve(),
+            NativeConv(vote_init).into(),
+        ),
+        initialize
+    );
+
+    for (vote_authorize, authority) in [
+        (VoteAuthorize::Voter, vote_init.authorized_voter),
+        (VoteAuthorize::Withdrawer, vote_init.authorized_withdrawer),
+    ] {
+        assert_eq!(
+            authorize_ix(
+                AuthorizeFreeAccounts { vote: &vote }
+                    .resolve(vote_authorize, CURRENT_EPOCH)
+                    .unwrap(),
+                AuthorizeIxArgs {
+                    new_authority,
+                    vote_authorize: NativeConv(vote_authorize).into(),
+                },
+            ),
+            vote_instruction::authorize(&vote.pubkey, &authority, &new_authority, vote_authorize)
+        );
+        assert_eq!(
+            authorize_checked_ix(
+                AuthorizeCheckedFreeAccounts {
+                    vote: &vote,
+                    new_authority,
+                }
+                .resolve(vote_authorize, CURRENT_EPOCH)
+                .unwrap(),
+                AuthorizeCheckedIxArgs {
+                    vote_authorize: NativeConv(vote_authorize).into(),
+                },
+            ),
+            vote_instruction::authorize_checked(
+                &vote.pubkey,
+                &authority,
+                &new_authority,
+                vote_authorize
+            )
+        );
+        assert_eq!(
+            authorize_with_seed_ix(
+                AuthorizeWithSeedFreeKeys {
+                    vote: vote.pubkey,
+                    base,
+                }
+                .resolve(),
+                AuthorizeWithSeedIxArgs {
+                    vote_authorize: NativeConv(vote_authorize).into(),
+                    current_authority_derived_key_owner: derived_key_owner,
+                    current_authority_derived_key_seed: seed.to_owned(),
+                    new_authority,
+                },
+            ),
+            vote_instruction::authorize_with_seed(
+                &vote.pubkey,
+                &base,
+                &derived_key_owner,
+                seed,
+                &new_authority,
+                vote_authorize
+            )
+        );
+        assert_eq!(
+            authorize_checked_with_seed_ix(
+                AuthorizeCheckedWithSeedFreeKeys {
+                    vote: vote.pubkey,
+                    base,
+                    new_authority,
+                }
+                .resolve(),
+                AuthorizeCheckedWithSeedIxArgs {
+                    vote_authorize: NativeConv(vote_authorize).into(),
+                    current_authority_derived_key_owner: derived_key_owner,
+                    current_authority_derived_key_seed: seed.to_owned(),
+                },
+            ),
+            vote_instruction::authorize_checked_with_seed(
+                &vote.pubkey,
+                &base,
+                &derived_key_owner,
+                seed,
+                &new_authority,
+                vote_authorize
+            )
+        );
+    }
+
+    assert_eq!(
+        withdraw_ix(
+            WithdrawFreeAccounts { vote: &vote, to }.resolve().unwrap(),
+            WithdrawIxArgs { lamports: 1 },
+        ),
+        vote_instruction::withdraw(&vote.pubkey, &vote_init.authorized_withdrawer, 1, &to)
+    );
+    assert_eq!(
+        update_commission_ix(
+            UpdateCommissionFreeAccounts { vote: &vote }
+                .resolve()
+                .unwrap(),
+            UpdateCommissionIxArgs { commission: 5 },
+        ),
+        vote_instruction::update_commission(&vote.pubkey, &vote_init.authorized_withdrawer, 5)
+    );
+    assert_eq!(
+        update_validator_identity_ix(
+            UpdateValidatorIdentityFreeAccounts {
+                vote: &vote,
+                new_node: new_authority,
+            }
+            .resolve()
+            .unwrap(),
+        ),
+        vote_instruction::update_validator_identity(
+            &vote.pubkey,
+            &vote_init.authorized_withdrawer,
+            &new_authority
+        )
+    );
+
+    // checks discriminants of the voting instructions before the ones above
+    let hash = Hash::new_unique();
+    assert_eq!(
+        vote_ix(
+            VoteKeys {
+                vote: vote.pubkey,
+                slot_hashes: solana_program::sysvar::slot_hashes::ID,
+                clock: solana_program::sysvar::clock::ID,
+                vote_authority: vote_init.authorized_voter,
+            },
+            VoteIxArgs {
+                slots: vec![1, 2],
+                hash: hash.to_bytes(),
+                timestamp: Some(3),
+            },
+        ),
+        vote_instruction::vote(
+            &vote.pubkey,
+            &vote_init.authorized_voter,
+            Vote {
+                slots: vec![1, 2],
+                hash,
+                timestamp: Some(3),
+            }
+        )
+    );
+}
+
+async fn keyed_account(banks_client: &mut BanksClient, pubkey: Pubkey) -> Keyed<Account> {
+    Keyed {
+        pubkey,
+        account: banks_client.get_account(pubkey).await.unwrap().unwrap(),
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn withdrawer_ops_onchain() {
+    const WITHDRAW_LAMPORTS: u64 = LAMPORTS_PER_SOL / 2;
+
+    let withdrawer = Keypair::new();
+    let new_withdrawer = Keypair::new();
+    let new_node = Keypair::new();
+    let to = Pubkey::new_unique();
+    let vote_init = VoteInit {
+        authorized_withdrawer: withdrawer.pubkey(),
+        ..random_vote_init()
+    };
+    let vote = Pubkey::new_unique();
+    let pt = ProgramTest::default()
+        .add_account_chained(vote, vote_account(&vote_init))
+        .add_system_account(to, 0);
+    let mut ctx = pt.start_with_context().await;
+
+    let vote_acc = keyed_account(&mut ctx.banks_client, vote).await;
+    let ixs = [
+        update_commission_ix(
+            UpdateCommissionFreeAccounts { vote: &vote_acc }
+                .resolve()
+                .unwrap(),
+            UpdateCommissionIxArgs { commission: 7 },
+        ),
+        update_validator_identity_ix(
+            UpdateValidatorIdentityFreeAccounts {
+                vote: &vote_acc,
+                new_node: new_node.pubkey(),
+            }
+            .resolve()
+            .unwrap(),
+        ),
+        withdraw_ix(
+            WithdrawFreeAccounts {
+                vote: &vote_acc,
+                to,
+            }
+            .resolve()
+            .unwrap(),
+            WithdrawIxArgs {
+                lamports: WITHDRAW_LAMPORTS,
+            },
+        ),
+        authorize_checked_ix(
+            AuthorizeCheckedFreeAccounts {
+                vote: &vote_acc,
+                new_authority: new_withdrawer.pubkey(),
+            }
+            .resolve_withdrawer()
+            .unwrap(),
+            AuthorizeCheckedIxArgs {
+                vote_authorize: NativeConv(VoteAuthorize::Withdrawer).into(),
+            },
+        ),
+    ];
+    let mut tx = Transaction::new_with_payer(&ixs, Some(&ctx.payer.pubkey()));
+    let blockhash = ctx.get_new_latest_blockhash().await.unwrap();
+    tx.sign(
+        &[&ctx.payer, &withdrawer, &new_node, &new_withdrawer],
+        blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.unwrap();
+
+    let vote_acc = keyed_account(&mut ctx.banks_client, vote).await;
+    assert_eq!(
+        vote_acc.account.lamports,
+        est_rent_exempt_lamports(VOTE_ACCOUNT_LEN) + LAMPORTS_PER_SOL - WITHDRAW_LAMPORTS
+    );
+    let v = ReadonlyVoteAccount(&vote_acc).try_into_valid().unwrap();
+    assert_eq!(v.vote_commission(), 7);
+    assert_eq!(v.vote_node_pubkey(), new_node.pubkey());
+    assert_eq!(v.vote_authorized_withdrawer(), new_withdrawer.pubkey());
+    assert_eq!(
+        keyed_account(&mut ctx.banks_client, to)
+            .await
+            .account
+            .lamports,
+        WITHDRAW_LAMPORTS
+    );
+}
@@ -35,6 +35,10 @@ pub mod proptest_utils;
 #[cfg_attr(docsrs, doc(cfg(feature = "stake")))]
 pub mod stake;
 
+#[cfg(feature = "stake-pool")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stake-pool")))]
+pub mod stake_pool;
+
 #[cfg(any(feature = "token", feature = "token-2022"))]
 #[cfg_attr(docsrs, doc(cfg(any(feature = "token", feature = "token-2022"))))]
 pub mod token;
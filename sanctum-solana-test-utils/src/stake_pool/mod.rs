@@ -0,0 +1,468 @@
+use std::num::NonZeroU32;
+
+use borsh::BorshSerialize;
+use solana_program::{
+    clock::Clock,
+    pubkey::Pubkey,
+    stake::state::{Meta, StakeStateV2},
+    stake_history::Epoch,
+    vote::{
+        self,
+        state::{VoteInit, VoteState, VoteStateVersions},
+    },
+};
+use solana_readonly_account::keyed::Keyed;
+use solana_sdk::account::Account;
+use spl_stake_pool_interface::{
+    AccountType, Fee, FutureEpochFee, Lockup, StakePool, StakeStatus, ValidatorList,
+    ValidatorListHeader, ValidatorStakeInfo,
+};
+
+use crate::{
+    est_rent_exempt_lamports,
+    stake::{
+        LiveStakeAccountParams, SingleAuthorityAuthorized, StakeProgramTest, StakeStateAndLamports,
+    },
+    token::{tokenkeg::TokenkegProgramTest, MockMintArgs, MockTokenAccountArgs},
+    ExtendedProgramTest,
+};
+
+#[cfg(feature = "proptest")]
+pub mod proptest_utils;
+
+/// These might change in the future
+pub const STAKE_POOL_ACCOUNT_LEN: usize = 611;
+
+pub const STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS: u64 =
+    est_rent_exempt_lamports(StakeStateV2::size_of());
+
+pub const MOCK_POOL_TOKEN_DECIMALS: u8 = 9;
+
+pub const ZERO_FEE: Fee = Fee {
+    denominator: 1,
+    numerator: 0,
+};
+
+/// Account data length of a validator list with space for `max_validators` validators
+pub const fn validator_list_account_len(max_validators: usize) -> usize {
+    5 + 4 + 73 * max_validators
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct MockStakePoolKeys {
+    pub program_id: Pubkey,
+    pub stake_pool: Pubkey,
+    pub validator_list: Pubkey,
+    pub reserve_stake: Pubkey,
+    pub pool_mint: Pubkey,
+    /// Also the pool's staker
+    pub manager: Pubkey,
+    pub manager_fee_account: Pubkey,
+    pub withdraw_authority: Pubkey,
+    pub withdraw_authority_bump: u8,
+    /// The default stake deposit authority PDA, which makes the pool permissionless
+    pub deposit_authority: Pubkey,
+}
+
+impl MockStakePoolKeys {
+    /// Unique keys for a pool of the SPL stake pool program
+    pub fn new_unique() -> Self {
+        Self::new_unique_for_prog(spl_stake_pool_interface::ID)
+    }
+
+    pub fn new_unique_for_prog(program_id: Pubkey) -> Self {
+        let stake_pool = Pubkey::new_unique();
+        let (withdraw_authority, withdraw_authority_bump) =
+            find_withdraw_authority(&program_id, &stake_pool);
+        let (deposit_authority, _bump) = find_deposit_authority(&program_id, &stake_pool);
+        Self {
+            program_id,
+            stake_pool,
+            validator_list: Pubkey::new_unique(),
+            reserve_stake: Pubkey::new_unique(),
+            pool_mint: Pubkey::new_unique(),
+            manager: Pubkey::new_unique(),
+            manager_fee_account: Pubkey::new_unique(),
+            withdraw_authority,
+            withdraw_authority_bump,
+            deposit_authority,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct MockValidatorArgs {
+    pub vote: Pubkey,
+
+    /// 0 for no seed
+    pub validator_seed_suffix: u32,
+
+    pub transient_seed_suffix: u64,
+
+    /// Staked lamports of the validator stake account, activated at epoch 0.
+    /// Excludes its rent-exempt reserve
+    pub staked_lamports: u64,
+
+    /// Staked lamports of the transient stake account, activating at the pool's `last_update_epoch`.
+    /// Excludes its rent-exempt reserve. No transient stake account is created if 0
+    pub transient_staked_lamports: u64,
+}
+
+impl MockValidatorArgs {
+    /// Same address as `sanctum_spl_stake_pool_lib::FindValidatorStakeAccount`
+    pub fn validator_stake_account(&self, keys: &MockStakePoolKeys) -> Pubkey {
+        let seed = NonZeroU32::new(self.validator_seed_suffix).map(|s| s.get().to_le_bytes());
+        Pubkey::find_program_address(
+            &[
+                self.vote.as_ref(),
+                keys.stake_pool.as_ref(),
+                seed.as_ref().map(|s| s.as_slice()).unwrap_or(&[]),
+            ],
+            &keys.program_id,
+        )
+        .0
+    }
+
+    /// Same address as `sanctum_spl_stake_pool_lib::FindTransientStakeAccount`
+    pub fn transient_stake_account(&self, keys: &MockStakePoolKeys) -> Pubkey {
+        Pubkey::find_program_address(
+            &[
+                b"transient",
+                self.vote.as_ref(),
+                keys.stake_pool.as_ref(),
+                &self.transient_seed_suffix.to_le_bytes(),
+            ],
+            &keys.program_id,
+        )
+        .0
+    }
+
+    pub const fn validator_stake_lamports(&self) -> u64 {
+        self.staked_lamports + STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS
+    }
+
+    pub const fn transient_stake_lamports(&self) -> u64 {
+        if self.transient_staked_lamports == 0 {
+            0
+        } else {
+            self.transient_staked_lamports + STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct MockStakePoolArgs {
+    pub keys: MockStakePoolKeys,
+
+    /// Lamports in the reserve stake account, excluding its rent-exempt reserve
+    pub reserve_lamports: u64,
+
+    pub pool_token_supply: u64,
+    pub last_update_epoch: Epoch,
+    pub validators: Vec<MockValidatorArgs>,
+}
+
+impl MockStakePoolArgs {
+    /// The pool's `total_lamports` as computed by `UpdateStakePoolBalance`
+    pub fn total_lamports(&self) -> u64 {
+        self.validators
+            .iter()
+            .map(|v| v.validator_stake_lamports() + v.transient_stake_lamports())
+            .sum::<u64>()
+            + self.reserve_lamports
+    }
+}
+
+/// An up-to-date, permissionless stake pool with zero fees and no lockup
+pub fn mock_stake_pool(args: &MockStakePoolArgs) -> StakePool {
+    let MockStakePoolArgs {
+        keys:
+            MockStakePoolKeys {
+                validator_list,
+                reserve_stake,
+                pool_mint,
+                manager,
+                manager_fee_account,
+                withdraw_authority_bump,
+                deposit_authority,
+                ..
+            },
+        pool_token_supply,
+        last_update_epoch,
+        ..
+    } = args;
+    let total_lamports = args.total_lamports();
+    StakePool {
+        account_type: AccountType::StakePool,
+        manager: *manager,
+        staker: *manager,
+        stake_deposit_authority: *deposit_authority,
+        stake_withdraw_bump_seed: *withdraw_authority_bump,
+        validator_list: *validator_list,
+        reserve_stake: *reserve_stake,
+        pool_mint: *pool_mint,
+        manager_fee_account: *manager_fee_account,
+        token_program: spl_token::ID,
+        total_lamports,
+        pool_token_supply: *pool_token_supply,
+        last_update_epoch: *last_update_epoch,
+        lockup: Lockup {
+            unix_timestamp: 0,
+            epoch: 0,
+            custodian: Pubkey::default(),
+        },
+        epoch_fee: ZERO_FEE,
+        next_epoch_fee: FutureEpochFee::None,
+        preferred_deposit_validator_vote_address: None,
+        preferred_withdraw_validator_vote_address: None,
+        stake_deposit_fee: ZERO_FEE,
+        stake_withdrawal_fee: ZERO_FEE,
+        next_stake_withdrawal_fee: FutureEpochFee::None,
+        stake_referral_fee: 0,
+        sol_deposit_authority: None,
+        sol_deposit_fee: ZERO_FEE,
+        sol_referral_fee: 0,
+        sol_withdraw_authority: None,
+        sol_withdrawal_fee: ZERO_FEE,
+        next_sol_withdrawal_fee: FutureEpochFee::None,
+        last_epoch_pool_token_supply: *pool_token_supply,
+        last_epoch_total_lamports: total_lamports,
+    }
+}
+
+pub fn mock_validator_list(
+    MockStakePoolArgs {
+        last_update_epoch,
+        validators,
+        ..
+    }: &MockStakePoolArgs,
+) -> ValidatorList {
+    ValidatorList {
+        header: ValidatorListHeader {
+            account_type: AccountType::ValidatorList,
+            max_validators: validators.len().max(1).try_into().unwrap(),
+        },
+        validators: validators
+            .iter()
+            .map(|v| ValidatorStakeInfo {
+                active_stake_lamports: v.validator_stake_lamports(),
+                transient_stake_lamports: v.transient_stake_lamports(),
+                last_update_epoch: *last_update_epoch,
+                transient_seed_suffix: v.transient_seed_suffix,
+                unused: 0,
+                validator_seed_suffix: v.validator_seed_suffix,
+                status: StakeStatus::Active,
+                vote_account_address: v.vote,
+            })
+            .collect(),
+    }
+}
+
+/// The stake pool program-owned account containing `stake_pool`
+pub fn stake_pool_account(stake_pool: &StakePool, program_id: Pubkey) -> Account {
+    let mut data = vec![0u8; STAKE_POOL_ACCOUNT_LEN];
+    stake_pool.serialize(&mut data.as_mut_slice()).unwrap();
+    program_owned_account(data, program_id)
+}
+
+/// A vote account with `vote` as its node, voter and withdrawer
+pub fn mock_vote_account(vote: Pubkey) -> Account {
+    let mut data = vec![0u8; VoteState::size_of()];
+    VoteState::serialize(
+        &VoteStateVersions::new_current(VoteState::new(
+            &VoteInit {
+                node_pubkey: vote,
+                authorized_voter: vote,
+                authorized_withdrawer: vote,
+                commission: 0,
+            },
+            &Clock::default(),
+        )),
+        &mut data,
+    )
+    .unwrap();
+    Account {
+        lamports: est_rent_exempt_lamports(data.len()),
+        data,
+        owner: vote::program::ID,
+        executable: false,
+        rent_epoch: u64::MAX,
+    }
+}
+
+pub trait StakePoolProgramTest {
+    fn add_stake_pool_account(
+        self,
+        addr: Pubkey,
+        stake_pool: &StakePool,
+        program_id: Pubkey,
+    ) -> Self;
+
+    /// Allocates space for exactly `validator_list.header.max_validators` validators
+    fn add_validator_list_account(
+        self,
+        addr: Pubkey,
+        validator_list: &ValidatorList,
+        program_id: Pubkey,
+    ) -> Self;
+
+    /// Adds `stake_pool`, `validator_list`, the reserve with `reserve_lamports` excluding its rent-exempt reserve,
+    /// the pool mint with `stake_pool.pool_token_supply` supply and an empty manager fee account,
+    /// e.g. to set up a [`mock_stake_pool`] with modified fields.
+    ///
+    /// Does not add the stake pool program or any of the validators' accounts.
+    fn add_stake_pool_accounts(
+        self,
+        keys: &MockStakePoolKeys,
+        stake_pool: &StakePool,
+        validator_list: &ValidatorList,
+        reserve_lamports: u64,
+    ) -> Self;
+
+    /// Adds the stake pool, its validator list, reserve, pool mint, manager fee account,
+    /// and for each validator, its vote account, validator stake account and transient stake account, if any.
+    ///
+    /// Does not add the stake pool program.
+    fn add_stake_pool_from_args(self, args: &MockStakePoolArgs) -> Self;
+}
+
+impl<T: ExtendedProgramTest> StakePoolProgramTest for T {
+    fn add_stake_pool_account(
+        self,
+        addr: Pubkey,
+        stake_pool: &StakePool,
+        program_id: Pubkey,
+    ) -> Self {
+        self.add_keyed_account(Keyed {
+            pubkey: addr,
+            account: stake_pool_account(stake_pool, program_id),
+        })
+    }
+
+    fn add_validator_list_account(
+        self,
+        addr: Pubkey,
+        validator_list: &ValidatorList,
+        program_id: Pubkey,
+    ) -> Self {
+        let mut data = vec![
+            0u8;
+            validator_list_account_len(
+                validator_list.header.max_validators.try_into().unwrap()
+            )
+        ];
+        validator_list.serialize(&mut data.as_mut_slice()).unwrap();
+        self.add_keyed_account(Keyed {
+            pubkey: addr,
+            account: program_owned_account(data, program_id),
+        })
+    }
+
+    fn add_stake_pool_accounts(
+        self,
+        keys: &MockStakePoolKeys,
+        stake_pool: &StakePool,
+        validator_list: &ValidatorList,
+        reserve_lamports: u64,
+    ) -> Self {
+        self.add_stake_pool_account(keys.stake_pool, stake_pool, keys.program_id)
+            .add_validator_list_account(keys.validator_list, validator_list, keys.program_id)
+            .add_stake_account(
+                keys.reserve_stake,
+                StakeStateAndLamports {
+                    stake_state: StakeStateV2::Initialized(Meta {
+                        rent_exempt_reserve: STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS,
+                        authorized: SingleAuthorityAuthorized(keys.withdraw_authority).into(),
+                        lockup: Default::default(),
+                    }),
+                    total_lamports: reserve_lamports + STAKE_ACCOUNT_RENT_EXEMPT_LAMPORTS,
+                },
+            )
+            .add_tokenkeg_mint_from_args(
+                keys.pool_mint,
+                MockMintArgs {
+                    mint_authority: Some(keys.withdraw_authority),
+                    freeze_authority: None,
+                    supply: stake_pool.pool_token_supply,
+                    decimals: MOCK_POOL_TOKEN_DECIMALS,
+                },
+            )
+            .add_tokenkeg_account_from_args(
+                keys.manager_fee_account,
+                MockTokenAccountArgs {
+                    mint: keys.pool_mint,
+                    authority: keys.manager,
+                    amount: 0,
+                },
+            )
+    }
+
+    fn add_stake_pool_from_args(self, args: &MockStakePoolArgs) -> Self {
+        let MockStakePoolArgs {
+            keys,
+            reserve_lamports,
+            last_update_epoch,
+            validators,
+            ..
+        } = args;
+        let authorized = SingleAuthorityAuthorized(keys.withdraw_authority).into();
+        let mut res = self.add_stake_pool_accounts(
+            keys,
+            &mock_stake_pool(args),
+            &mock_validator_list(args),
+            *reserve_lamports,
+        );
+
+        for v in validators {
+            res = res
+                .add_account_chained(v.vote, mock_vote_account(v.vote))
+                .add_live_stake_account(
+                    v.validator_stake_account(keys),
+                    LiveStakeAccountParams {
+                        staked_lamports: v.staked_lamports,
+                        voter: v.vote,
+                        authorized,
+                        activation_epoch: 0,
+                        deactivation_epoch: u64::MAX,
+                        lockup: Default::default(),
+                        credits_observed: 0,
+                    },
+                );
+            if v.transient_staked_lamports > 0 {
+                res = res.add_live_stake_account(
+                    v.transient_stake_account(keys),
+                    LiveStakeAccountParams {
+                        staked_lamports: v.transient_staked_lamports,
+                        voter: v.vote,
+                        authorized,
+                        activation_epoch: *last_update_epoch,
+                        deactivation_epoch: u64::MAX,
+                        lockup: Default::default(),
+                        credits_observed: 0,
+                    },
+                );
+            }
+        }
+        res
+    }
+}
+
+/// Same address as `sanctum_spl_stake_pool_lib::FindWithdrawAuthority`
+fn find_withdraw_authority(program_id: &Pubkey, stake_pool: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[stake_pool.as_ref(), b"withdraw"], program_id)
+}
+
+/// Same address as `sanctum_spl_stake_pool_lib::FindDepositAuthority`
+fn find_deposit_authority(program_id: &Pubkey, stake_pool: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[stake_pool.as_ref(), b"deposit"], program_id)
+}
+
+fn program_owned_account(data: Vec<u8>, program_id: Pubkey) -> Account {
+    Account {
+        lamports: est_rent_exempt_lamports(data.len()),
+        data,
+        owner: program_id,
+        executable: false,
+        rent_epoch: u64::MAX,
+    }
+}
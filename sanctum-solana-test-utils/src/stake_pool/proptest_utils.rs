@@ -0,0 +1,284 @@
+use proptest::{collection::vec, option, prelude::*, strategy::Union};
+use solana_program::native_token::LAMPORTS_PER_SOL;
+use spl_stake_pool_interface::{
+    AccountType, Fee, FutureEpochFee, Lockup, StakePool, StakeStatus, ValidatorStakeInfo,
+};
+
+use crate::proptest_utils::pubkey;
+
+use super::{
+    find_deposit_authority, find_withdraw_authority, MockStakePoolArgs, MockStakePoolKeys,
+    MockValidatorArgs, ZERO_FEE,
+};
+
+/// Upper bound for the staked lamports of each generated stake account,
+/// small enough that summing them for a pool never overflows
+pub const MAX_MOCK_STAKED_LAMPORTS: u64 = 100_000_000 * LAMPORTS_PER_SOL;
+
+prop_compose! {
+    /// A valid fee: nonzero denominator, numerator <= denominator
+    pub fn fee()
+        (denominator in 1..=u64::MAX)
+        (numerator in 0..=denominator, denominator in Just(denominator)) -> Fee {
+            Fee { denominator, numerator }
+        }
+}
+
+pub fn future_epoch_fee() -> impl Strategy<Value = FutureEpochFee> {
+    Union::new([
+        Just(FutureEpochFee::None).boxed(),
+        fee().prop_map(|fee| FutureEpochFee::One { fee }).boxed(),
+        fee().prop_map(|fee| FutureEpochFee::Two { fee }).boxed(),
+    ])
+}
+
+pub fn stake_status() -> impl Strategy<Value = StakeStatus> {
+    Union::new([
+        Just(StakeStatus::Active),
+        Just(StakeStatus::DeactivatingTransient),
+        Just(StakeStatus::ReadyForRemoval),
+        Just(StakeStatus::DeactivatingValidator),
+        Just(StakeStatus::DeactivatingAll),
+    ])
+}
+
+prop_compose! {
+    pub fn lockup()
+        (unix_timestamp: i64, epoch: u64, custodian in pubkey()) -> Lockup {
+            Lockup { unix_timestamp, epoch, custodian }
+        }
+}
+
+prop_compose! {
+    pub fn validator_stake_info()
+        (
+            active_stake_lamports: u64,
+            transient_stake_lamports: u64,
+            last_update_epoch: u64,
+            transient_seed_suffix: u64,
+            validator_seed_suffix: u32,
+            status in stake_status(),
+            vote_account_address in pubkey(),
+        ) -> ValidatorStakeInfo {
+            ValidatorStakeInfo {
+                active_stake_lamports,
+                transient_stake_lamports,
+                last_update_epoch,
+                transient_seed_suffix,
+                unused: 0,
+                validator_seed_suffix,
+                status,
+                vote_account_address,
+            }
+        }
+}
+
+prop_compose! {
+    fn stake_pool_keys_and_authorities()
+        (
+            manager in pubkey(),
+            staker in pubkey(),
+            stake_deposit_authority in pubkey(),
+            stake_withdraw_bump_seed: u8,
+            validator_list in pubkey(),
+            reserve_stake in pubkey(),
+            pool_mint in pubkey(),
+            manager_fee_account in pubkey(),
+            token_program in pubkey(),
+            sol_deposit_authority in option::of(pubkey()),
+            sol_withdraw_authority in option::of(pubkey()),
+        ) -> StakePool {
+            StakePool {
+                manager,
+                staker,
+                stake_deposit_authority,
+                stake_withdraw_bump_seed,
+                validator_list,
+                reserve_stake,
+                pool_mint,
+                manager_fee_account,
+                token_program,
+                sol_deposit_authority,
+                sol_withdraw_authority,
+                ..default_stake_pool()
+            }
+        }
+}
+
+prop_compose! {
+    fn stake_pool_fees()
+        (
+            epoch_fee in fee(),
+            next_epoch_fee in future_epoch_fee(),
+            stake_deposit_fee in fee(),
+            stake_withdrawal_fee in fee(),
+            next_stake_withdrawal_fee in future_epoch_fee(),
+            stake_referral_fee in 0..=100u8,
+            sol_deposit_fee in fee(),
+            sol_referral_fee in 0..=100u8,
+            sol_withdrawal_fee in fee(),
+            next_sol_withdrawal_fee in future_epoch_fee(),
+        ) -> StakePool {
+            StakePool {
+                epoch_fee,
+                next_epoch_fee,
+                stake_deposit_fee,
+                stake_withdrawal_fee,
+                next_stake_withdrawal_fee,
+                stake_referral_fee,
+                sol_deposit_fee,
+                sol_referral_fee,
+                sol_withdrawal_fee,
+                next_sol_withdrawal_fee,
+                ..default_stake_pool()
+            }
+        }
+}
+
+prop_compose! {
+    /// An arbitrary initialized stake pool with valid fees.
+    /// Its accounts and balances are not consistent with any validator list
+    pub fn stake_pool()
+        (
+            keys in stake_pool_keys_and_authorities(),
+            fees in stake_pool_fees(),
+            total_lamports: u64,
+            pool_token_supply: u64,
+            last_update_epoch: u64,
+            lockup in lockup(),
+            preferred_deposit_validator_vote_address in option::of(pubkey()),
+            preferred_withdraw_validator_vote_address in option::of(pubkey()),
+            last_epoch_pool_token_supply: u64,
+            last_epoch_total_lamports: u64,
+        ) -> StakePool {
+            StakePool {
+                total_lamports,
+                pool_token_supply,
+                last_update_epoch,
+                lockup,
+                preferred_deposit_validator_vote_address,
+                preferred_withdraw_validator_vote_address,
+                last_epoch_pool_token_supply,
+                last_epoch_total_lamports,
+                epoch_fee: fees.epoch_fee,
+                next_epoch_fee: fees.next_epoch_fee,
+                stake_deposit_fee: fees.stake_deposit_fee,
+                stake_withdrawal_fee: fees.stake_withdrawal_fee,
+                next_stake_withdrawal_fee: fees.next_stake_withdrawal_fee,
+                stake_referral_fee: fees.stake_referral_fee,
+                sol_deposit_fee: fees.sol_deposit_fee,
+                sol_referral_fee: fees.sol_referral_fee,
+                sol_withdrawal_fee: fees.sol_withdrawal_fee,
+                next_sol_withdrawal_fee: fees.next_sol_withdrawal_fee,
+                ..keys
+            }
+        }
+}
+
+prop_compose! {
+    pub fn mock_validator_args()
+        (
+            vote in pubkey(),
+            validator_seed_suffix: u32,
+            transient_seed_suffix: u64,
+            staked_lamports in 0..=MAX_MOCK_STAKED_LAMPORTS,
+            transient_staked_lamports in 0..=MAX_MOCK_STAKED_LAMPORTS,
+        ) -> MockValidatorArgs {
+            MockValidatorArgs {
+                vote,
+                validator_seed_suffix,
+                transient_seed_suffix,
+                staked_lamports,
+                transient_staked_lamports,
+            }
+        }
+}
+
+prop_compose! {
+    /// Keys for a pool of the SPL stake pool program
+    pub fn mock_stake_pool_keys()
+        (
+            stake_pool in pubkey(),
+            validator_list in pubkey(),
+            reserve_stake in pubkey(),
+            pool_mint in pubkey(),
+            manager in pubkey(),
+            manager_fee_account in pubkey(),
+        ) -> MockStakePoolKeys {
+            let program_id = spl_stake_pool_interface::ID;
+            let (withdraw_authority, withdraw_authority_bump) =
+                find_withdraw_authority(&program_id, &stake_pool);
+            let (deposit_authority, _bump) = find_deposit_authority(&program_id, &stake_pool);
+            MockStakePoolKeys {
+                program_id,
+                stake_pool,
+                validator_list,
+                reserve_stake,
+                pool_mint,
+                manager,
+                manager_fee_account,
+                withdraw_authority,
+                withdraw_authority_bump,
+                deposit_authority,
+            }
+        }
+}
+
+prop_compose! {
+    /// A randomized but fully consistent stake pool with up to `max_validators` validators
+    pub fn mock_stake_pool_args(max_validators: usize)
+        (
+            keys in mock_stake_pool_keys(),
+            reserve_lamports in 0..=MAX_MOCK_STAKED_LAMPORTS,
+            pool_token_supply: u64,
+            last_update_epoch: u64,
+            validators in vec(mock_validator_args(), 0..=max_validators),
+        ) -> MockStakePoolArgs {
+            MockStakePoolArgs {
+                keys,
+                reserve_lamports,
+                pool_token_supply,
+                last_update_epoch,
+                validators,
+            }
+        }
+}
+
+fn default_stake_pool() -> StakePool {
+    StakePool {
+        account_type: AccountType::StakePool,
+        manager: Default::default(),
+        staker: Default::default(),
+        stake_deposit_authority: Default::default(),
+        stake_withdraw_bump_seed: 0,
+        validator_list: Default::default(),
+        reserve_stake: Default::default(),
+        pool_mint: Default::default(),
+        manager_fee_account: Default::default(),
+        token_program: Default::default(),
+        total_lamports: 0,
+        pool_token_supply: 0,
+        last_update_epoch: 0,
+        lockup: Lockup {
+            unix_timestamp: 0,
+            epoch: 0,
+            custodian: Default::default(),
+        },
+        epoch_fee: ZERO_FEE,
+        next_epoch_fee: FutureEpochFee::None,
+        preferred_deposit_validator_vote_address: None,
+        preferred_withdraw_validator_vote_address: None,
+        stake_deposit_fee: ZERO_FEE,
+        stake_withdrawal_fee: ZERO_FEE,
+        next_stake_withdrawal_fee: FutureEpochFee::None,
+        stake_referral_fee: 0,
+        sol_deposit_authority: None,
+        sol_deposit_fee: ZERO_FEE,
+        sol_referral_fee: 0,
+        sol_withdraw_authority: None,
+        sol_withdrawal_fee: ZERO_FEE,
+        next_sol_withdrawal_fee: FutureEpochFee::None,
+        last_epoch_pool_token_supply: 0,
+        last_epoch_total_lamports: 0,
+    }
+}